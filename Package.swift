@@ -0,0 +1,17 @@
+// swift-tools-version:5.6
+
+import PackageDescription
+
+let package = Package(
+    name: "swift-bridge",
+    products: [
+        .plugin(
+            name: "SwiftBridgePlugin",
+            targets: ["SwiftBridgePlugin"]),
+    ],
+    targets: [
+        .plugin(
+            name: "SwiftBridgePlugin",
+            capability: .buildTool()),
+    ]
+)
@@ -0,0 +1,95 @@
+import Foundation
+import PackagePlugin
+
+/// Generates the Swift code for a Rust crate's bridge modules during `swift build`, using
+/// `swift-bridge-cli parse-bridges`.
+///
+/// The target that uses the plugin needs a `swift-bridge.json` file that says which crate to
+/// generate the code for:
+///
+/// ```json
+/// {
+///     "crateName": "my_crate",
+///     "crateDir": "../../rust/my_crate",
+///     "swiftImports": ["RustXcframework"],
+///     "features": ["metrics"]
+/// }
+/// ```
+///
+/// `crateDir` is relative to the target's directory. The code gets re-generated whenever one of
+/// the crate's Rust source files changes.
+@main
+struct SwiftBridgePlugin: BuildToolPlugin {
+    func createBuildCommands(context: PluginContext, target: Target) async throws -> [Command] {
+        let configPath = target.directory.appending("swift-bridge.json")
+        let config = try Config.read(from: configPath)
+
+        let crateDir = config.crateDir.hasPrefix("/")
+            ? Path(config.crateDir)
+            : target.directory.appending(subpath: config.crateDir)
+        let outDir = context.pluginWorkDirectory.appending("generated")
+
+        var arguments = [
+            "parse-bridges",
+            "--crate-name", config.crateName,
+            "--crate-dir", crateDir.string,
+            "--out-dir", outDir.string,
+        ]
+        for module in config.swiftImports ?? [] {
+            arguments += ["--swift-import", module]
+        }
+        for feature in config.features ?? [] {
+            arguments += ["--features", feature]
+        }
+        if config.libraryEvolution ?? false {
+            arguments.append("--library-evolution")
+        }
+
+        return [
+            .buildCommand(
+                displayName: "Generating Swift code for \(config.crateName)'s bridge modules",
+                executable: try context.tool(named: "swift-bridge-cli").path,
+                arguments: arguments,
+                inputFiles: [configPath] + rustSourceFiles(in: crateDir.appending("src")),
+                outputFiles: [
+                    outDir.appending("SwiftBridgeCore.swift"),
+                    outDir.appending(config.crateName, "\(config.crateName).swift"),
+                ]
+            ),
+        ]
+    }
+}
+
+/// The contents of a target's `swift-bridge.json`.
+struct Config: Decodable {
+    /// The name of the Rust crate, such as `my_crate`.
+    let crateName: String
+    /// The directory that holds the crate's `Cargo.toml`.
+    let crateDir: String
+    /// Modules to import in the generated Swift files, such as the `RustXcframework` module of an
+    /// xcframework that was created with `swift-bridge-cli create-xcframework`.
+    let swiftImports: [String]?
+    /// The crate's enabled features, used for bridge items with a `#[cfg(feature = "...")]`.
+    let features: [String]?
+    /// Whether to generate code for a framework that is built for distribution.
+    let libraryEvolution: Bool?
+
+    static func read(from path: Path) throws -> Config {
+        let contents = try Data(contentsOf: URL(fileURLWithPath: path.string))
+        return try JSONDecoder().decode(Config.self, from: contents)
+    }
+}
+
+/// All of the `.rs` files in a directory and its subdirectories.
+func rustSourceFiles(in directory: Path) -> [Path] {
+    let enumerator = FileManager.default.enumerator(atPath: directory.string)
+
+    var files: [Path] = []
+    while let file = enumerator?.nextObject() as? String {
+        if file.hasSuffix(".rs") {
+            files.append(directory.appending(subpath: file))
+        }
+    }
+
+    return files
+}
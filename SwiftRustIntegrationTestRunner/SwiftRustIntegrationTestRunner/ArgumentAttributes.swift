@@ -0,0 +1,10 @@
+//
+//  ArgumentAttributes.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+func swift_fn_with_argument_labels(from start: Int32, _ end: Int32) -> Int32 {
+    end - start
+}
@@ -0,0 +1,8 @@
+//
+//  Array.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+func swift_reverse_u16_array(arg: (UInt16, UInt16, UInt16)) -> (UInt16, UInt16, UInt16) {
+    (arg.2, arg.1, arg.0)
+}
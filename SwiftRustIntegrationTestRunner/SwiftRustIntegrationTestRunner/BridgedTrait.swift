@@ -0,0 +1,20 @@
+//
+//  BridgedTrait.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+/// Implements the protocol generated for the `Greeter` trait in `bridged_trait.rs`.
+class CountingGreeter: Greeter {
+    var count: UInt32 = 0
+
+    func greet(name: RustString) -> String {
+        count += 1
+        return "Hello, \(name.toString())!"
+    }
+
+    func greetCount() -> UInt32 {
+        count
+    }
+}
@@ -46,6 +46,23 @@ func swift_calls_rust_fnonce_callback_twice(arg: () -> ()) {
     arg()
 }
 
+/// Calls a Rust `Fn` callback more than once.
+func swift_takes_fn_callback_primitive(arg: (UInt32) -> UInt32) -> UInt32 {
+    arg(1) + arg(2)
+}
+
+/// Calls a Rust `extern "C" fn` function pointer.
+func swift_takes_extern_c_fn(arg: @convention(c) (UInt32) -> UInt32) -> UInt32 {
+    arg(10)
+}
+
+/// Calls a Rust `FnMut` callback more than once.
+func swift_takes_fnmut_callback_no_args_no_return(arg: () -> ()) {
+    arg()
+    arg()
+    arg()
+}
+
 class SwiftMethodCallbackTester {
     func method_with_fnonce_callback(callback: () -> ()) {
         callback()
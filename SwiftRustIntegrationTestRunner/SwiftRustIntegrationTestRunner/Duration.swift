@@ -0,0 +1,10 @@
+//
+//  Duration.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+func swift_double_duration(arg: TimeInterval) -> TimeInterval {
+    arg * 2
+}
@@ -10,3 +10,11 @@ import Foundation
 func testCallSwiftFromRustByNameAttribute() -> RustString {
     return "StringFromSwift".intoRustString()
 }
+
+func swiftFnRenamedToCamelCase() -> UInt8 {
+    return 10
+}
+
+func swiftFnWithRustName() -> UInt8 {
+    return 55
+}
@@ -0,0 +1,13 @@
+//
+//  OpaqueTypeAttributes.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+/// See crates/swift-integration-tests/src/opaque_type_attributes/already_declared.rs
+public class AlreadyDeclaredSwiftTypeTest {
+    init() {}
+
+    func aRefMethod() -> Bool {
+        true
+    }
+}
@@ -47,3 +47,27 @@ func swift_reflect_option_bool(arg: Optional<Bool>) -> Optional<Bool> {
     arg
 }
 
+func swift_reflect_option_string(arg: Optional<RustString>) -> Optional<String> {
+    arg?.toString()
+}
+func swift_option_str_is_some(arg: Optional<RustStr>) -> Bool {
+    arg != nil
+}
+func swift_reflect_option_vector_rust_type(arg: Optional<RustVec<UInt16>>) -> Optional<RustVec<UInt16>> {
+    arg
+}
+
+public class OptTestOpaqueSwiftType {
+    let value: UInt8
+
+    init(field: UInt8) {
+        self.value = field
+    }
+
+    func field() -> UInt8 {
+        value
+    }
+}
+func swift_reflect_option_opaque_swift_type(arg: Optional<OptTestOpaqueSwiftType>) -> Optional<OptTestOpaqueSwiftType> {
+    arg
+}
@@ -0,0 +1,10 @@
+//
+//  Path.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+func swift_path_parent(arg: String) -> String {
+    (arg as NSString).deletingLastPathComponent
+}
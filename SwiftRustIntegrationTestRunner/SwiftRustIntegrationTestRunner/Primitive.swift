@@ -40,6 +40,14 @@ func swift_double_i64(arg: Int64) -> Int64 {
     arg * 2
 }
 
+func swift_double_usize(arg: UInt) -> UInt {
+    arg * 2
+}
+
+func swift_double_isize(arg: Int) -> Int {
+    arg * 2
+}
+
 func swift_double_f32(arg: Float) -> Float {
     arg * 2.0
 }
@@ -52,6 +60,22 @@ func swift_negate_bool(arg: Bool) -> Bool {
     !arg
 }
 
+func swift_reflect_u128(arg: RustUInt128) -> RustUInt128 {
+    arg
+}
+
+func swift_reflect_i128(arg: RustInt128) -> RustInt128 {
+    arg
+}
+
+func swift_double_non_zero_u32(arg: UInt32) -> UInt32 {
+    arg * 2
+}
+
+func swift_uppercase_char(arg: Unicode.Scalar) -> Unicode.Scalar {
+    arg.properties.uppercaseMapping.unicodeScalars.first!
+}
+
 func swift_reflect_null(arg: ()) -> () {
     arg
 }
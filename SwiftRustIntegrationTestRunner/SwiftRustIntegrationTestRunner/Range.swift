@@ -0,0 +1,8 @@
+//
+//  Range.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+func swift_shift_range(arg: Range<Int32>, by: Int32) -> Range<Int32> {
+    (arg.lowerBound + by)..<(arg.upperBound + by)
+}
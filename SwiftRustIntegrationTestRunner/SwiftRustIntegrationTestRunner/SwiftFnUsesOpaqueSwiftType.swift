@@ -27,3 +27,6 @@ func call_swift_fn_reflects_owned_opaque_swift_type(arg: ASwiftType) -> ASwiftTy
     arg
 }
 
+func call_swift_fn_reads_ref_opaque_swift_type(arg: ASwiftType) -> UInt32 {
+    arg.amount()
+}
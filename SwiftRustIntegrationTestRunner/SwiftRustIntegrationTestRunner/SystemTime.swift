@@ -0,0 +1,10 @@
+//
+//  SystemTime.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+func swift_add_one_day_to_system_time(arg: TimeInterval) -> TimeInterval {
+    Date(timeIntervalSince1970: arg).addingTimeInterval(60 * 60 * 24).timeIntervalSince1970
+}
@@ -0,0 +1,10 @@
+//
+//  Uuid.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+func swift_uuid_from_string(arg: RustString) -> __private__FfiUuid {
+    UUID(uuidString: arg.toString())!.intoFfiRepr()
+}
@@ -15,3 +15,7 @@ func swift_return_vec_u8() -> RustVec<UInt8> {
     }
     return vec
 }
+
+func swift_reflect_vec_opaque_rust_type(arg: RustVec<ARustTypeInsideVecT>) -> RustVec<ARustTypeInsideVecT> {
+    arg
+}
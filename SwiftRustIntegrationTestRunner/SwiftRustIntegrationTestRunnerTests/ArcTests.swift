@@ -0,0 +1,48 @@
+//
+//  ArcTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for bridging `std::sync::Arc<T>`.
+class ArcTests: XCTestCase {
+    /// Verify that many Swift instances can share the same Rust object.
+    func testSwiftInstancesShareRustObject() throws {
+        let counter = new_shared_counter()
+
+        withExtendedLifetime(ArcSharedCounter(counter)) { other in
+            other.increment()
+            XCTAssertEqual(counter.value(), 1)
+            XCTAssertEqual(shared_counter_other_owners(counter), 2)
+        }
+
+        XCTAssertEqual(shared_counter_other_owners(counter), 1)
+    }
+
+    /// Verify that passing an `Arc` to Rust and getting it back does not leak or release a
+    /// strong count.
+    func testReflectArc() throws {
+        let counter = new_shared_counter()
+
+        withExtendedLifetime(rust_reflect_shared_counter(counter)) { reflected in
+            reflected.increment()
+            XCTAssertEqual(counter.value(), 1)
+            XCTAssertEqual(shared_counter_other_owners(counter), 2)
+        }
+
+        XCTAssertEqual(rust_reflect_option_shared_counter(counter)!.value(), 1)
+        XCTAssertNil(rust_reflect_option_shared_counter(nil))
+        XCTAssertEqual(shared_counter_other_owners(counter), 1)
+    }
+
+    /// Verify that a method that takes a `self: Arc<Self>` gets its own strong count for the
+    /// duration of the call, and that Rust releases it once the call is done.
+    func testArcSelfMethod() throws {
+        let counter = new_shared_counter()
+
+        XCTAssertEqual(counter.owners(), 2)
+        XCTAssertEqual(shared_counter_other_owners(counter), 1)
+    }
+}
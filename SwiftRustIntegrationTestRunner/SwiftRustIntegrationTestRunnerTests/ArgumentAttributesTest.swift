@@ -18,4 +18,22 @@ final class ArgumentAttributesTest: XCTestCase {
         XCTAssertEqual(test_argument_label(someArg: 10, 100), 110)
     }
 
+    /// Verify that the `swift_bridge(labeled_args)` function attribute uses the parameter names
+    /// as argument labels.
+    func testLabeledArgs() throws {
+        XCTAssertEqual(test_labeled_args(base: 4, times: 5), 20)
+    }
+
+    /// Verify that the `swift_bridge(default = 2)` attribute lets Swift callers omit the argument.
+    func testArgumentDefault() throws {
+        XCTAssertEqual(test_argument_default(5), 10)
+        XCTAssertEqual(test_argument_default(5, times: 3), 15)
+        XCTAssertEqual(test_argument_default(5, negate: true), -10)
+    }
+
+    /// Verify that Rust calls an extern Swift function using its argument labels.
+    func testRustCallsSwiftWithArgumentLabels() throws {
+        XCTAssertEqual(test_rust_calls_swift_with_argument_labels(), 7)
+    }
+
 }
@@ -0,0 +1,42 @@
+//
+//  ArrayTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for bridging fixed-size arrays such as `[u8; 32]`.
+class ArrayTests: XCTestCase {
+    /// Verify that Swift can pass a fixed-size array to Rust and get one back.
+    func testSwiftCallsRustArray() throws {
+        var hash: (
+            UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8,
+            UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8,
+            UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8,
+            UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8
+        ) = (
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0
+        )
+        hash.0 = 1
+        hash.31 = 255
+
+        let reflected = rust_reflect_u8_array(hash)
+        XCTAssertEqual(reflected.0, 1)
+        XCTAssertEqual(reflected.31, 255)
+
+        let bytes = withUnsafeBytes(of: reflected) { Array($0) }
+        XCTAssertEqual(bytes.count, 32)
+        XCTAssertEqual(bytes[0], 1)
+        XCTAssertEqual(bytes[31], 255)
+    }
+
+    func testSwiftCallsRustSumArray() throws {
+        XCTAssertEqual(rust_sum_f64_array((1.5, 2.5, 3.0)), 7.0)
+    }
+
+    func testRustCallsSwiftArray() throws {
+        rust_run_swift_array_tests()
+    }
+}
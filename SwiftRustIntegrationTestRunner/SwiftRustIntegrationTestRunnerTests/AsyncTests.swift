@@ -40,6 +40,12 @@ class AsyncTests: XCTestCase {
         XCTAssertEqual(num, 567)
     }
 
+    /// Verify that we can return an opaque Rust type from an async Rust function
+    func testSwiftCallsRustAsyncFnReturnOpaqueRustType() async throws {
+        let value = await rust_async_return_opaque_rust_type(789)
+        XCTAssertEqual(value.val(), 789)
+    }
+
     
     /// Verify that we can pass and return a Result<OpaqueRust, OpaqueRust> to an async Rust function
     func testSwiftCallsRustAsyncFnReflectResultOpaqueRust() async throws {
@@ -0,0 +1,36 @@
+//
+//  BoxTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for bridging `Box<T>`.
+class BoxTests: XCTestCase {
+    /// Verify that Swift can take ownership of a `Box` and hand it back to Rust.
+    func testSwiftCallsRustBox() throws {
+        let counter = new_boxed_counter(5)
+        XCTAssertEqual(counter.value(), 5)
+
+        let incremented = rust_increment_boxed_counter(counter)
+        XCTAssertEqual(incremented.value(), 6)
+    }
+
+    /// Verify that Swift can pass an `Option<Box<T>>` to Rust and get one back.
+    func testSwiftCallsRustOptionBox() throws {
+        let counter = rust_reflect_option_boxed_counter(new_boxed_counter(7))
+        XCTAssertEqual(counter!.value(), 7)
+
+        XCTAssertNil(rust_reflect_option_boxed_counter(nil))
+    }
+
+    /// Verify that Swift can call a method that takes a `self: Box<Self>`, and that the Swift
+    /// instance no longer frees the value once it has been moved into Rust.
+    func testSwiftCallsRustBoxedSelfMethod() throws {
+        let counter = new_boxed_counter(3)
+
+        XCTAssertEqual(counter.into_value(), 3)
+        XCTAssertFalse(counter.isOwned)
+    }
+}
@@ -0,0 +1,27 @@
+//
+//  BridgedTraitTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+class BridgedTraitTests: XCTestCase {
+    /// Verify that a Swift class that implements a bridged trait's protocol can be passed to Rust
+    /// as a `Box<dyn Trait>`, and that Rust releases it when the box is dropped.
+    func testPassSwiftClassToRustAsBoxedTrait() throws {
+        weak var weakGreeter: CountingGreeter?
+
+        do {
+            let greeter = CountingGreeter()
+            weakGreeter = greeter
+
+            XCTAssertEqual(
+                rust_greet_twice(greeter, "world").toString(),
+                "Hello, world! Hello, world! (2)"
+            )
+        }
+
+        XCTAssertNil(weakGreeter)
+    }
+}
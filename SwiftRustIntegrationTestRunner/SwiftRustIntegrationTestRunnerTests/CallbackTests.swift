@@ -14,4 +14,56 @@ class CallbackTests: XCTestCase {
     func testRustCallsSwift() throws {
         test_callbacks_rust_calls_swift()
     }
+
+    /// Verify that we can pass Swift closures to Rust functions.
+    func testSwiftCallsRust() throws {
+        var called = false
+        rust_takes_swift_closure_no_args_no_return({ called = true })
+        XCTAssertTrue(called)
+
+        XCTAssertEqual(rust_takes_swift_closure_primitive({ num in num * 2 }), 12)
+
+        let quadrupled = rust_takes_swift_closure_opaque_rust({ rustTy in
+            rustTy.double()
+            return rustTy
+        })
+        XCTAssertEqual(quadrupled, 400)
+
+        let greeting = rust_takes_swift_closure_string({ name in "Hello, \(name.toString())" })
+        XCTAssertEqual(greeting.toString(), "Hello, world")
+    }
+
+    /// Verify that we can pass `@convention(c)` functions to Rust and get Rust function pointers
+    /// back.
+    func testExternCFnPointers() throws {
+        XCTAssertEqual(rust_takes_extern_c_fn({ val in val * 2 }, 5), 10)
+
+        let addOne = rust_returns_extern_c_fn()
+        XCTAssertEqual(addOne(1), 2)
+        XCTAssertEqual(rust_takes_extern_c_fn(addOne, 5), 6)
+    }
+
+    /// Verify that we can pass optional `@convention(c)` functions to Rust and get optional Rust
+    /// function pointers back.
+    func testOptionalExternCFnPointers() throws {
+        XCTAssertEqual(rust_takes_optional_extern_c_fn({ val in val * 2 }, 5), 10)
+        XCTAssertNil(rust_takes_optional_extern_c_fn(nil, 5))
+
+        XCTAssertEqual(rust_returns_optional_extern_c_fn(true)!(1), 2)
+        XCTAssertNil(rust_returns_optional_extern_c_fn(false))
+    }
+
+    /// Verify that a Swift closure that was passed to Rust gets released once Rust drops it.
+    func testSwiftClosureReleasedWhenRustDropsIt() throws {
+        weak var weakCaptured: NSObject?
+
+        do {
+            let captured = NSObject()
+            weakCaptured = captured
+
+            rust_takes_swift_closure_no_args_no_return({ _ = captured })
+        }
+
+        XCTAssertNil(weakCaptured)
+    }
 }
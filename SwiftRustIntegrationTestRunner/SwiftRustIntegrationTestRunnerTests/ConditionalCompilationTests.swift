@@ -25,4 +25,10 @@ class ConditionalCompilationTests: XCTestCase {
     func testConditionalCompilation() throws {
         XCTAssertEqual(conditionally_exposed_fn(), 123)
     }
+
+    /// Call a function that has a `#[cfg(feature = "this_is_enabled")]` attribute, from a bridge
+    /// module that also has functions and types for a feature that isn't enabled.
+    func testConditionallyCompiledItem() throws {
+        XCTAssertEqual(conditionally_exposed_item_fn(), 124)
+    }
 }
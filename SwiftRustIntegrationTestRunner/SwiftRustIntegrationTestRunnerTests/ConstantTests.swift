@@ -0,0 +1,24 @@
+//
+//  ConstantTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for bridging `const` and `static` items.
+class ConstantTests: XCTestCase {
+    /// Verify that constants whose values are literals have the same values in Swift.
+    func testLiteralConstants() throws {
+        XCTAssertEqual(CONSTANT_U32, 1024)
+        XCTAssertEqual(CONSTANT_NEGATIVE_F64, -2.5)
+        XCTAssertEqual(CONSTANT_BOOL, true)
+        XCTAssertEqual(STATIC_STR, "hello \"world\"")
+    }
+
+    /// Verify that Swift reads the values of constants that Rust has to evaluate.
+    func testComputedConstants() throws {
+        XCTAssertEqual(COMPUTED_CONSTANT_U32, 2048)
+        XCTAssertEqual(COMPUTED_STATIC_STR, "hello world")
+    }
+}
@@ -0,0 +1,37 @@
+//
+//  CowStrTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for bridging `Cow<'static, str>`.
+class CowStrTests: XCTestCase {
+    /// Verify that Swift can receive both borrowed and owned strings from Rust.
+    func testSwiftCallsRustCowStr() throws {
+        let borrowed = rust_cow_str_greeting("")
+        XCTAssertTrue(borrowed.isBorrowed)
+        XCTAssertEqual(borrowed.toString(), "Hello")
+
+        let owned = rust_cow_str_greeting("world")
+        XCTAssertFalse(owned.isBorrowed)
+        XCTAssertEqual(owned.toString(), "Hello, world")
+    }
+
+    /// Verify that a `Cow<'static, str>` keeps track of its ownership when Swift passes it back
+    /// to Rust.
+    func testSwiftPassesCowStrBackToRust() throws {
+        XCTAssertFalse(rust_cow_str_is_owned(rust_cow_str_greeting("")))
+        XCTAssertTrue(rust_cow_str_is_owned(rust_cow_str_greeting("world")))
+    }
+
+    /// Verify that Swift can pass an `Option<Cow<'static, str>>` to Rust and get one back.
+    func testSwiftCallsRustOptionCowStr() throws {
+        XCTAssertEqual(
+            rust_reflect_option_cow_str(rust_cow_str_greeting("world"))!.toString(),
+            "Hello, world"
+        )
+        XCTAssertNil(rust_reflect_option_cow_str(nil))
+    }
+}
@@ -0,0 +1,24 @@
+//
+//  DurationTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for bridging `std::time::Duration`.
+class DurationTests: XCTestCase {
+    /// Verify that Swift can pass a `TimeInterval` to Rust and get one back.
+    func testSwiftCallsRustDuration() throws {
+        let interval: TimeInterval = 1.5
+        XCTAssertEqual(rust_double_duration(interval), 3.0)
+
+        XCTAssertEqual(rust_reflect_option_duration(0.25), 0.25)
+        XCTAssertNil(rust_reflect_option_duration(nil))
+    }
+
+    /// Verify that Rust can pass a `Duration` to Swift and get one back.
+    func testRustCallsSwiftDuration() throws {
+        test_rust_calls_swift_duration()
+    }
+}
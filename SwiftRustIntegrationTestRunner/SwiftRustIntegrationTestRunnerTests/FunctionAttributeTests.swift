@@ -5,6 +5,7 @@
 // Created by Erik Živković on 2022-12-17.
 //
 
+import Combine
 import Foundation
 
 import XCTest
@@ -24,4 +25,87 @@ class FunctionAttributeTests: XCTestCase {
     func testSwiftNameAttribute() throws {
         XCTAssertEqual(testCallRustFromSwiftByNameAttribute().toString(), "StringFromRust")
     }
+
+    /// Verify that Rust can call an extern Swift function through its `rust_name`.
+    func testRustNameAttributeOnSwiftFunction() throws {
+        XCTAssertEqual(test_call_swift_fn_with_rust_name(), 55)
+    }
+
+    /// Verify that the `rename_functions = "camelCase"` module argument renames both the
+    /// Rust functions that Swift calls and the Swift functions that Rust calls.
+    func testRenameFunctionsCamelCase() throws {
+        XCTAssertEqual(rustFnRenamedToCamelCase(), 11)
+    }
+
+    /// Verify that the `swift_namespace` module argument makes the module's Rust functions static
+    /// functions of the namespace enum.
+    func testSwiftNamespace() throws {
+        XCTAssertEqual(NamespacedFunctions.namespaced_sum(4, 5), 9)
+    }
+
+    /// Verify that an `init` function that returns `Option<Self>` becomes a failable initializer.
+    func testFailableInit() throws {
+        XCTAssertNil(FailableInitType(false))
+        XCTAssertEqual(FailableInitType(true)!.count(), 132)
+    }
+
+    /// Verify that the `property` attribute merges a getter and a setter into a single Swift
+    /// computed property.
+    func testPropertyAttribute() throws {
+        let book = PropertyTestBook("Dune")
+        XCTAssertEqual(book.title, "Dune")
+        XCTAssertEqual(book.pages, 400)
+
+        book.title = "Emma"
+        XCTAssertEqual(book.title, "Emma")
+    }
+
+    /// Verify that the `subscript` attribute exposes a method as a Swift subscript.
+    func testSubscriptAttribute() throws {
+        let bytes = SubscriptTestBytes()
+        XCTAssertEqual(bytes[0], 10)
+        XCTAssertEqual(bytes[3], 30)
+
+        XCTAssertEqual(bytes["20"], 2)
+    }
+
+    /// Verify that a `discardable_result` method can be called without using its return value.
+    func testDiscardableResultAttribute() throws {
+        let set = DiscardableResultTestSet()
+        set.insert(5)
+        set.insert(5)
+
+        XCTAssertEqual(set.len(), 1)
+        XCTAssertTrue(set.insert(6))
+    }
+
+    /// Verify that a `#[deprecated]` function can still be called from Swift.
+    ///
+    /// The test is itself deprecated so that calling the deprecated function doesn't warn.
+    @available(*, deprecated)
+    func testDeprecatedAttribute() throws {
+        XCTAssertEqual(deprecated_add(1, 2), 3)
+    }
+
+    /// Verify that the `publisher` attribute wraps a Rust event source into a Combine publisher,
+    /// and that cancelling the subscription unregisters its callback.
+    func testPublisherAttribute() throws {
+        let source = PublisherTestEventSource()
+        var received: [UInt32] = []
+
+        let cancellable = source.events.sink { event in
+            received.append(event)
+        }
+        XCTAssertEqual(source.subscriber_count(), 1)
+
+        source.emit(1)
+        source.emit(2)
+        XCTAssertEqual(received, [1, 2])
+
+        cancellable.cancel()
+        XCTAssertEqual(source.subscriber_count(), 0)
+
+        source.emit(3)
+        XCTAssertEqual(received, [1, 2])
+    }
 }
@@ -32,5 +32,18 @@ class GenericTests: XCTestCase {
         let val = new_generic_with_inner_opaque_type()
         let _: GenericWithOpaqueRustInnerTy<InnerTy> = reflect_generic_with_inner_opaque_type(val)
     }
+
+    /// Verify that we can call initializers and methods on each of a generic opaque Rust type's
+    /// monomorphizations.
+    func testGenericOpaqueRustTypeMethods() {
+        let u32Stack = GenericStack<UInt32>()
+        u32Stack.push(5)
+        u32Stack.push(10)
+        XCTAssertEqual(u32Stack.len(), 2)
+
+        let u8Stack = GenericStack<UInt8>()
+        u8Stack.push(1)
+        XCTAssertEqual(u8Stack.len(), 1)
+    }
 }
 
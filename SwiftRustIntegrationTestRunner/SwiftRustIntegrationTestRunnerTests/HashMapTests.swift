@@ -0,0 +1,61 @@
+//
+//  HashMapTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for bridging `HashMap<K, V>`.
+class HashMapTests: XCTestCase {
+    /// Verify that we can create a RustMap and use its methods.
+    func testRustMapMethods() throws {
+        let map = RustMap<UInt32, RustString>()
+        XCTAssertEqual(map.count, 0)
+        XCTAssertNil(map.get(1))
+
+        XCTAssertNil(map.insert(1, "one"))
+        XCTAssertEqual(map.insert(1, "uno")!.toString(), "one")
+        XCTAssertEqual(map.get(1)!.toString(), "uno")
+        XCTAssertEqual(map.count, 1)
+
+        XCTAssertEqual(map.remove(1)!.toString(), "uno")
+        XCTAssertNil(map.remove(1))
+        XCTAssertEqual(map.count, 0)
+    }
+
+    /// Verify that we can create a RustMap from a Swift Dictionary and pass it to and from Rust.
+    func testReflectRustMap() throws {
+        XCTContext.runActivity(named: "Verify that we can pass and return a HashMap<u32, String>.") {
+            _ in
+            let map = rust_reflect_hash_map_u32_string(RustMap([1: "one", 2: "two"]))
+            XCTAssertEqual(map.count, 2)
+            XCTAssertEqual(map.get(1)!.toString(), "one")
+            XCTAssertEqual(map.get(2)!.toString(), "two")
+        }
+        XCTContext.runActivity(named: "Verify that we can pass and return a HashMap<String, bool>.") {
+            _ in
+            let map = rust_reflect_hash_map_string_bool(RustMap(["yes": true, "no": false]))
+            XCTAssertEqual(map.count, 2)
+            XCTAssertEqual(map.get("yes"), true)
+            XCTAssertEqual(map.get("no"), false)
+            XCTAssertNil(map.get("maybe"))
+        }
+        XCTContext.runActivity(named: "Verify that we can return a HashMap<u8, f64>.") {
+            _ in
+            let map = rust_make_hash_map_u8_f64()
+            XCTAssertEqual(map.count, 2)
+            XCTAssertEqual(map.get(1), 1.5)
+            XCTAssertEqual(map.get(2), 2.5)
+        }
+    }
+
+    /// Verify that we can pass and return an Option<HashMap<K, V>>.
+    func testReflectOptionRustMap() throws {
+        let map = rust_reflect_option_hash_map_u8_u16(RustMap([1: 10]))
+        XCTAssertEqual(map!.count, 1)
+        XCTAssertEqual(map!.get(1), 10)
+
+        XCTAssertNil(rust_reflect_option_hash_map_u8_u16(nil))
+    }
+}
@@ -0,0 +1,51 @@
+//
+//  HashSetTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for bridging `HashSet<T>`.
+class HashSetTests: XCTestCase {
+    /// Verify that we can create a RustSet and use its methods.
+    func testRustSetMethods() throws {
+        let set = RustSet<RustString>()
+        XCTAssertEqual(set.count, 0)
+        XCTAssertFalse(set.contains("hello"))
+
+        XCTAssertTrue(set.insert("hello"))
+        XCTAssertFalse(set.insert("hello"))
+        XCTAssertTrue(set.contains("hello"))
+        XCTAssertEqual(set.count, 1)
+
+        XCTAssertTrue(set.remove("hello"))
+        XCTAssertFalse(set.remove("hello"))
+        XCTAssertEqual(set.count, 0)
+    }
+
+    /// Verify that we can convert between a RustSet and a Swift Set, and pass a RustSet to and
+    /// from Rust.
+    func testReflectRustSet() throws {
+        XCTContext.runActivity(named: "Verify that we can pass and return a HashSet<String>.") {
+            _ in
+            let set = rust_reflect_hash_set_string(RustSet(["a", "b"]))
+            XCTAssertEqual(set.toSet(), ["a", "b"])
+        }
+        XCTContext.runActivity(named: "Verify that we can return a HashSet<i32>.") {
+            _ in
+            let set = rust_make_hash_set_i32()
+            XCTAssertEqual(set.count, 3)
+            XCTAssertTrue(set.contains(-1))
+            XCTAssertEqual(set.toSet(), [-1, 0, 1])
+        }
+    }
+
+    /// Verify that we can pass and return an Option<HashSet<T>>.
+    func testReflectOptionRustSet() throws {
+        let set = rust_reflect_option_hash_set_u8(RustSet([1, 2]))
+        XCTAssertEqual(set!.toSet(), [1, 2])
+
+        XCTAssertNil(rust_reflect_option_hash_set_u8(nil))
+    }
+}
@@ -57,6 +57,33 @@ class OpaqueRustStructTests: XCTestCase {
         XCTAssertEqual(ref1.len(), ref2.len())
     }
     
+    /// Verify that an owned opaque Rust type gets dropped on the Rust side when its Swift class
+    /// instance is deinitialized.
+    func testOwnedOpaqueRustTypeDroppedOnDeinit() throws {
+        let counter = DropCounter()
+
+        do {
+            let _counted = counter.make_counted()
+            XCTAssertEqual(counter.drop_count(), 0)
+        }
+
+        XCTAssertEqual(counter.drop_count(), 1)
+    }
+
+    /// Verify that passing an owned opaque Rust type to Rust hands over ownership, so the value
+    /// is dropped by Rust and not freed again when the Swift class instance is deinitialized.
+    func testOwnedOpaqueRustTypeMovedToRustDroppedOnce() throws {
+        let counter = DropCounter()
+
+        do {
+            let counted = counter.make_counted()
+            consume_drop_counted(counted)
+            XCTAssertEqual(counter.drop_count(), 1)
+        }
+
+        XCTAssertEqual(counter.drop_count(), 1)
+    }
+
     /// Verify that we can pass a Copy opaque Rust type between Rust and Swift.
     func testOpaqueRustTypeImplCopy() throws {
         let val = RustCopyType()
@@ -69,6 +96,30 @@ class OpaqueRustStructTests: XCTestCase {
         XCTAssert(val.eq(val2))
     }
 
+    /// Verify that an init function on a Copy opaque Rust type that returns `Option<Self>`
+    /// becomes a failable initializer.
+    func testOpaqueRustTypeImplCopyFailableInit() throws {
+        XCTAssertNil(RustCopyType(0))
+        XCTAssertEqual(RustCopyType(5)!.first(), 5)
+    }
+
+    /// Verify that we can use a Copy opaque Rust type within a RustVec.
+    func testVecOfOpaqueRustTypeImplCopy() throws {
+        let vec = make_vec_of_rust_copy_type(3)
+
+        XCTAssertEqual(vec.len(), 3)
+        XCTAssertEqual(vec.get(index: 1)!.first(), 2)
+
+        vec.push(value: RustCopyType(10)!)
+        XCTAssertEqual(vec.pop()!.first(), 10)
+
+        vec.get_mut(index: 0)!.pointee = RustCopyType(7)!
+        XCTAssertEqual(vec.get(index: 0)!.first(), 7)
+
+        vec.push(value: RustCopyType(4)!)
+        XCTAssertEqual(sum_vec_of_rust_copy_type(vec), 16)
+    }
+
     func testOpaqueRustTypeImplEquatable() throws {
         XCTContext.runActivity(named: "Should be equal"){
             _ in
@@ -90,6 +141,77 @@ class OpaqueRustStructTests: XCTestCase {
         }
     }
 
+    func testOpaqueRustCopyTypeImplEquatable() throws {
+        XCTAssertEqual(RustCopyEquatableType(5), RustCopyEquatableType(5))
+        XCTAssertNotEqual(RustCopyEquatableType(5), RustCopyEquatableType(6))
+    }
+
+    func testOpaqueRustTypeImplCustomStringConvertible() throws {
+        XCTAssertEqual(RustDisplayType("world").description, "Hello, world!")
+        XCTAssertEqual("\(RustCopyDisplayType(5))", "#5")
+    }
+
+    func testOpaqueRustTypeImplCustomDebugStringConvertible() throws {
+        XCTAssertEqual(RustDebugType("world").debugDescription, #"RustDebugType { name: "world" }"#)
+        XCTAssertEqual(String(reflecting: RustCopyDebugType(5)), "RustCopyDebugType(5)")
+    }
+
+    /// Verify that a Sendable opaque Rust type can be shared across concurrent tasks.
+    func testOpaqueRustTypeSendable() async throws {
+        let val = RustSendableType()
+
+        await withTaskGroup(of: Void.self) { group in
+            for _ in 0..<10 {
+                group.addTask {
+                    val.increment()
+                }
+            }
+        }
+
+        XCTAssertEqual(val.count(), 10)
+    }
+
+    /// Verify that a Swift actor serializes mutable access to the opaque Rust type that it wraps.
+    func testOpaqueRustTypeActor() async throws {
+        let counter = RustActorCounterActor(RustActorCounter())
+
+        await withTaskGroup(of: Void.self) { group in
+            for _ in 0..<10 {
+                group.addTask {
+                    await counter.increment()
+                }
+            }
+        }
+
+        let count = await counter.count()
+        XCTAssertEqual(count, 10)
+
+        let description: String = await counter.description()
+        XCTAssertEqual(description, "Counted to 10")
+    }
+
+    func testOpaqueRustTypeImplComparable() throws {
+        let sorted = [RustComparableType(3), RustComparableType(1), RustComparableType(2)].sorted()
+        XCTAssertEqual(sorted.map { $0.value() }, [1, 2, 3])
+
+        XCTAssert(RustCopyComparableType(1) < RustCopyComparableType(2))
+        XCTAssertFalse(RustCopyComparableType(2) < RustCopyComparableType(1))
+    }
+
+    func testOpaqueRustTypeOperators() throws {
+        let a = RustOperatorsType(5)
+        let b = RustOperatorsType(3)
+        XCTAssertEqual((a + b).value(), 8)
+        XCTAssertEqual((a - b).value(), 2)
+        XCTAssertEqual((-a).value(), -5)
+
+        let c = RustCopyOperatorsType(7)
+        let d = RustCopyOperatorsType(2)
+        XCTAssertEqual((c * d).value(), 14)
+        XCTAssertEqual((c / d).value(), 3)
+        XCTAssertEqual((c % d).value(), 1)
+    }
+
     func testOpaqueRustTypeImplHashable() throws {
         XCTContext.runActivity(named: "Same hash value"){
             _ in
@@ -130,5 +252,15 @@ class OpaqueRustStructTests: XCTestCase {
             }
         }
     }
+
+    func testOpaqueRustCopyTypeImplHashable() throws {
+        var set: Set<RustCopyHashableType> = []
+        set.insert(RustCopyHashableType(10))
+        set.insert(RustCopyHashableType(10))
+        set.insert(RustCopyHashableType(20))
+
+        XCTAssertEqual(set.count, 2)
+        XCTAssert(set.contains(RustCopyHashableType(20)))
+    }
 }
 
@@ -5,6 +5,7 @@
 //  Created by Frankie Nwafili on 1/6/22.
 //
 
+import Combine
 import XCTest
 @testable import SwiftRustIntegrationTestRunner
 
@@ -42,6 +43,80 @@ class OpaqueTypeAttributeTests: XCTestCase {
         XCTAssert(AlreadyDeclaredCopyTypeTest.an_associated_function())
     }
 
+    /// Verify that a bridge module can call the methods of an opaque Swift type that another
+    /// bridge module declared.
+    func testExternSwiftAlreadyDeclaredOpaqueSwiftType() throws {
+        XCTAssert(rust_calls_already_declared_swift_type_method())
+    }
+
+    /// Verify that we can read and write an opaque Rust type's fields through the Swift computed
+    /// properties that the `get` and `set` attributes generate.
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/fields.rs
+    func testOpaqueRustTypeFieldProperties() throws {
+        let rectangle = RustRectangle(2, 3)
+
+        XCTAssertEqual(rectangle.label, "rectangle")
+        XCTAssertEqual(rectangle.width, 2)
+        XCTAssertEqual(rectangle.height, 3)
+
+        rectangle.width = 4
+        rectangle.height += 1
+
+        XCTAssertEqual(rectangle.width, 4)
+        XCTAssertEqual(rectangle.area(), 16)
+    }
+
+    /// Verify that we can iterate over an `Iterator` opaque Rust type with a `for` loop.
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/iterator.rs
+    func testOpaqueRustTypeIterator() throws {
+        var values: [UInt32] = []
+        for value in RustCountdown(3) {
+            values.append(value)
+        }
+
+        XCTAssertEqual(values, [3, 2, 1])
+        XCTAssertEqual(Array(RustCountdown(2)), [2, 1])
+    }
+
+    /// Verify that a `#[swift_bridge(ObservableObject = "...")]` type's `ObservableObject` wrapper
+    /// sends `objectWillChange` whenever the Rust type calls its change callback.
+    func testOpaqueRustTypeObservableObject() throws {
+        let counter = RustObservableCounterObservable(RustObservableCounter())
+        var changes = 0
+
+        let cancellable = counter.objectWillChange.sink { _ in
+            changes += 1
+        }
+
+        counter.inner.increment()
+        counter.inner.increment()
+
+        XCTAssertEqual(changes, 2)
+        XCTAssertEqual(counter.inner.count(), 2)
+
+        cancellable.cancel()
+    }
+
+    /// Verify that a `#[swift_bridge(Stream(Item = T))]` type can be used as an `AsyncSequence`.
+    func testOpaqueRustTypeStream() async throws {
+        var values: [UInt32] = []
+        for await value in RustCountdownStream(3) {
+            values.append(value)
+        }
+
+        XCTAssertEqual(values, [3, 2, 1])
+    }
+
+    /// Verify that the declarations of a bridge module with the `swift_access = "internal"`
+    /// argument are usable within the Swift module.
+    func testSwiftAccess() throws {
+        let counter = RustInternalCounter()
+        XCTAssertEqual(counter.increment(), rust_internal_counter_start() + 1)
+
+        let snapshot = RustPublicCounterSnapshot.snapshot(counter)
+        XCTAssertEqual(snapshot.count(), 11)
+    }
+
     
     func testPerformanceExample() throws {
         // This is an example of a performance test case.
@@ -52,6 +52,31 @@ class OptionTests: XCTestCase {
     func testRustCallSwiftOptionPrimitive() throws {
         test_rust_calls_swift_option_primitive()
     }
+
+    /// Verify that Rust can call Swift functions that accept and return Option<String>,
+    /// as well as Swift functions that accept Option<&str>.
+    func testRustCallSwiftOptionStringAndStr() throws {
+        test_rust_calls_swift_option_string_and_str()
+    }
+
+    /// Verify that Rust can call Swift functions that accept and return Option<OpaqueSwiftType>.
+    func testRustCallSwiftOptionOpaqueSwiftType() throws {
+        test_rust_calls_swift_option_opaque_swift_type()
+    }
+
+    /// Verify that Rust can call Swift functions that accept and return Option<Vec<T>>.
+    func testRustCallSwiftOptionVector() throws {
+        test_rust_calls_swift_option_vector()
+    }
+
+    /// Verify that Swift can call a Rust function that accepts and returns an
+    /// Option<OpaqueSwiftType>.
+    func testSwiftCallRustOptionOpaqueSwiftType() throws {
+        let val = rust_reflect_option_opaque_swift_type(OptTestOpaqueSwiftType(field: 55))
+        XCTAssertEqual(val!.field(), 55)
+
+        XCTAssertNil(rust_reflect_option_opaque_swift_type(nil))
+    }
     
     /// Verify that Swift can call a Rust function that accepts and returns an Option<T>
     /// where T is a String.
@@ -114,6 +139,15 @@ class OptionTests: XCTestCase {
         reflect = rust_reflect_option_ref_opaque_rust_type(opt_ref)
         XCTAssertEqual(reflect!.field(), 123)
     }
+
+    /// Verify that we can pass and receive an `Option<&mut RustType>`.
+    func testSwiftCallRustWithOptionMutRefOpaqueRustType() throws {
+        let val = OptTestOpaqueRustType(123)
+
+        let reflect = rust_reflect_option_mut_ref_opaque_rust_type(val)
+        XCTAssertEqual(reflect!.field(), 123)
+        XCTAssertNil(rust_reflect_option_mut_ref_opaque_rust_type(nil))
+    }
     
     func testSwiftCallRustWithOptionOpaqueRustCopyType() throws {
         let val = new_opaque_rust_copy_type(123)
@@ -0,0 +1,23 @@
+//
+//  PanicTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for the `panic` module argument.
+class PanicTests: XCTestCase {
+    /// Verify that a panic in a Rust function that returns a `Result` gets thrown as a
+    /// `RustPanic`, in a module that uses `panic = "throw"`.
+    func testPanicIsThrown() throws {
+        XCTAssertNoThrow(try rust_func_panics_or_returns_result(false))
+
+        do {
+            try rust_func_panics_or_returns_result(true)
+            XCTFail("The function should have thrown")
+        } catch let error as RustPanic {
+            XCTAssertEqual(error.message, "Panicked on purpose")
+        }
+    }
+}
@@ -0,0 +1,31 @@
+//
+//  PathTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for bridging `PathBuf` and `&Path`.
+class PathTests: XCTestCase {
+    /// Verify that Swift can pass a path to Rust as a `String` and get one back.
+    func testSwiftCallsRustPathString() throws {
+        XCTAssertEqual(rust_join_path("/tmp", "file.txt"), "/tmp/file.txt")
+
+        XCTAssertEqual(rust_reflect_option_path("/tmp/file.txt"), "/tmp/file.txt")
+        XCTAssertNil(rust_reflect_option_path(nil))
+    }
+
+    /// Verify that Swift can pass a path to Rust as a file `URL` and get one back.
+    func testSwiftCallsRustPathUrl() throws {
+        let url = URL(fileURLWithPath: "/tmp/some/file.txt")
+
+        XCTAssertEqual(rust_path_file_name(url).toString(), "file.txt")
+        XCTAssertEqual(rust_reflect_path_url(url), url)
+    }
+
+    /// Verify that Rust can pass a path to Swift and get one back.
+    func testRustCallsSwiftPath() throws {
+        test_rust_calls_swift_path()
+    }
+}
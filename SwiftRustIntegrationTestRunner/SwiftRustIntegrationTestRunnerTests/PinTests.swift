@@ -0,0 +1,22 @@
+//
+//  PinTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for methods that take a `self: Pin<&mut Self>`.
+class PinTests: XCTestCase {
+    /// Verify that Swift can call a method that takes a pinned mutable reference to an opaque
+    /// Rust type, and that the Rust value does not move between calls.
+    func testCallPinnedMethod() throws {
+        let counter = PinnedCounter()
+
+        counter.increment()
+        counter.increment()
+
+        XCTAssertEqual(counter.value(), 2)
+        XCTAssertTrue(counter.points_to_itself())
+    }
+}
@@ -30,6 +30,28 @@ class PointerTests: XCTestCase {
         XCTAssertEqual(pointer_mut, pointer_mut_copy)
     }
     
+    func testSwiftCallRustStdFfiCvoid() throws {
+        let value = [1, 2, 3]
+
+        let pointer_mut = UnsafeMutableRawPointer(mutating: value)
+
+        XCTAssertEqual(rust_echo_std_ffi_c_void(pointer_mut), pointer_mut)
+    }
+
+    /// Verify that optional pointers are passed as nullable pointers.
+    func testSwiftCallRustOptionalPointers() throws {
+        let value: [UInt8] = [1, 2, 3]
+
+        let pointer = UnsafeRawPointer(value)
+        let pointer_mut = UnsafeMutablePointer<UInt8>(mutating: value)
+
+        XCTAssertEqual(rust_echo_option_const_c_void(pointer), pointer)
+        XCTAssertNil(rust_echo_option_const_c_void(nil))
+
+        XCTAssertEqual(rust_echo_option_mut_u8(pointer_mut), pointer_mut)
+        XCTAssertNil(rust_echo_option_mut_u8(nil))
+    }
+
     func testRustCallSwiftCvoid() throws {
         rust_run_opaque_pointer_tests()
     }
@@ -25,10 +25,25 @@ class PrimitiveTests: XCTestCase {
         XCTAssertEqual(rust_double_i32(10), 20);
         XCTAssertEqual(rust_double_u64(10), 20);
         XCTAssertEqual(rust_double_i64(10), 20);
+        XCTAssertEqual(rust_double_usize(10), 20);
+        XCTAssertEqual(rust_double_isize(-10), -20);
         XCTAssertEqual(rust_double_f32(10.0), 20.0);
         XCTAssertEqual(rust_double_f64(10.0), 20.0);
         XCTAssertEqual(rust_negate_bool(true), false);
         XCTAssertEqual(rust_negate_bool(false), true);
+        XCTAssertEqual(rust_double_u128(10), 20);
+        XCTAssertEqual(rust_double_u128(RustUInt128(UInt64.max)), RustUInt128(low: UInt64.max - 1, high: 1));
+        XCTAssertEqual(rust_double_i128(10), 20);
+        XCTAssertEqual(rust_double_i128(-10), -20);
+        XCTAssertEqual(rust_reflect_option_u128(5), 5);
+        XCTAssertNil(rust_reflect_option_u128(nil));
+        XCTAssertEqual(rust_double_non_zero_u32(5), 10);
+        XCTAssertEqual(rust_reflect_option_non_zero_u32(5), 5);
+        XCTAssertNil(rust_reflect_option_non_zero_u32(nil));
+        XCTAssertEqual(rust_uppercase_char("a"), "A");
+        XCTAssertEqual(rust_uppercase_char("é"), "É");
+        XCTAssertEqual(rust_reflect_option_char("🦀"), "🦀");
+        XCTAssertNil(rust_reflect_option_char(nil));
     }
 }
 
@@ -0,0 +1,27 @@
+//
+//  RangeTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for bridging `std::ops::Range<T>`.
+class RangeTests: XCTestCase {
+    /// Verify that Swift can pass a range to Rust and get one back.
+    func testSwiftCallsRustRange() throws {
+        XCTAssertEqual(rust_reflect_range_usize(3..<7), 3..<7)
+        XCTAssertEqual(rust_range_i64_len(-5..<5), 10)
+    }
+
+    /// Verify that a Rust range whose start is greater than its end is an empty Swift range.
+    func testRustReversedRangeIsEmpty() throws {
+        let range = rust_empty_reversed_range()
+        XCTAssertTrue(range.isEmpty)
+        XCTAssertEqual(range.lowerBound, 10)
+    }
+
+    func testRustCallsSwiftRange() throws {
+        rust_run_swift_range_tests()
+    }
+}
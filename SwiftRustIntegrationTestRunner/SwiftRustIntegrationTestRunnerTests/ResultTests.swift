@@ -13,6 +13,18 @@ class ResultTests: XCTestCase {
         rust_func_takes_result_string(.Ok("Success Message"))
         rust_func_takes_result_string(.Err("Error Message"))
     }
+
+    /// Verify that we can receive a Result<String, String> from Rust and catch the error String.
+    func testSwiftCallRustReturnResultString() throws {
+        XCTAssertEqual(try! rust_func_return_result_string(true).toString(), "Success Message")
+
+        do {
+            let _ = try rust_func_return_result_string(false)
+            XCTFail("The function should have returned an error.")
+        } catch let error as RustString {
+            XCTAssertEqual(error.toString(), "Error Message")
+        }
+    }
     
     /// Verify that we can pass a Result<OpaqueRust, OpaqueRust> from Swift -> Rust
     func testSwiftCallRustResultOpaqueRust() throws {
@@ -53,6 +65,24 @@ class ResultTests: XCTestCase {
         }
     }
 
+    /// Verify that we can pass a Result<(), String> from Swift -> Rust
+    func testSwiftCallRustTakesResultNullString() throws {
+        XCTAssertTrue(rust_func_takes_result_null_string(.Ok(())))
+        XCTAssertFalse(rust_func_takes_result_null_string(.Err("Error Message")))
+    }
+
+    /// Verify that we can receive a Result<(), String> from Rust
+    func testSwiftCallRustReturnResultNullString() throws {
+        try! rust_func_return_result_null_string(true)
+
+        do {
+            try rust_func_return_result_null_string(false)
+            XCTFail("The function should have returned an error.")
+        } catch let error as RustString {
+            XCTAssertEqual(error.toString(), "Error Message")
+        }
+    }
+
     /// Verify that we can receive a Result<UnitStruct, OpaqueRust> from Rust
     func testSwiftCallRustResultUnitStructOpaqueRust() throws {
         try! rust_func_return_result_unit_struct_opaque_rust(true)
@@ -32,6 +32,19 @@ class SharedEnumTests: XCTestCase {
         }
     }
 
+    /// Verify that an enum's explicit discriminants become Swift raw values that match the
+    /// Rust discriminants.
+    func testEnumWithDiscriminants() {
+        XCTAssertEqual(EnumWithDiscriminants.Variant1.rawValue, 10)
+        XCTAssertEqual(EnumWithDiscriminants.Variant2.rawValue, 11)
+        XCTAssertEqual(EnumWithDiscriminants.Variant3.rawValue, -5)
+        XCTAssertEqual(EnumWithDiscriminants(rawValue: 11), .Variant2)
+
+        for variant in [EnumWithDiscriminants.Variant1, .Variant2, .Variant3] {
+            XCTAssertEqual(enum_with_discriminants_as_isize(variant), variant.rawValue)
+        }
+    }
+
     func testEnumWithUnnamedData() {
         let enumWithUnnamedData1 = EnumWithUnnamedData.TwoFields(create_string("hello"), OpaqueRustForEnumTest())
         switch reflect_enum_with_unnamed_data(enumWithUnnamedData1) {
@@ -104,6 +117,34 @@ class SharedEnumTests: XCTestCase {
         }
     }
 
+    func testEnumWithOptionalData() {
+        let named = EnumWithOptionalData.Named(label: "circle".intoRustString(), radius: 1.5)
+        switch reflect_enum_with_optional_data(named) {
+        case .Named(let label, let radius):
+            XCTAssertEqual(label!.toString(), "circle")
+            XCTAssertEqual(radius, 1.5)
+        case .Unnamed(_):
+            XCTFail()
+        }
+
+        let namedNone = EnumWithOptionalData.Named(label: nil, radius: nil)
+        switch reflect_enum_with_optional_data(namedNone) {
+        case .Named(let label, let radius):
+            XCTAssertNil(label)
+            XCTAssertNil(radius)
+        case .Unnamed(_):
+            XCTFail()
+        }
+
+        let unnamed = EnumWithOptionalData.Unnamed("hello".intoRustString())
+        switch reflect_enum_with_optional_data(unnamed) {
+        case .Named(_, _):
+            XCTFail()
+        case .Unnamed(let value):
+            XCTAssertEqual(value!.toString(), "hello")
+        }
+    }
+
     func testEnumWithGenericOpaqueRust() {
         let named = EnumWithGenericOpaqueRust.Named(data: new_generic_opaque_rust_for_enum_test())
         switch reflect_enum_with_generic_opaque_type(named) {
@@ -52,4 +52,54 @@ class SharedStructTests: XCTestCase {
         XCTAssertEqual(val._0, reflected._0)
         XCTAssertEqual(val._1, reflected._1)
     }
+
+    /// Verify that we can pass a transparent struct that has other transparent structs as fields.
+    func testStructWithStructFields() {
+        let line = StructReprStructLine(
+            start: StructReprStructPoint(x: 1.5, y: 2.5),
+            end: StructReprStructPoint(x: -3.0, y: 4.0)
+        )
+        let reflected = swift_calls_rust_struct_with_struct_fields(line)
+
+        XCTAssertEqual(reflected.start.x, 1.5)
+        XCTAssertEqual(reflected.start.y, 2.5)
+        XCTAssertEqual(reflected.end.x, -3.0)
+        XCTAssertEqual(reflected.end.y, 4.0)
+    }
+
+    /// Verify that we can call a Rust function that is associated to a transparent struct.
+    func testStaticFunctionAssociatedToStruct() {
+        let origin = StructReprStructPoint.origin()
+
+        XCTAssertEqual(origin.x, 0.0)
+        XCTAssertEqual(origin.y, 0.0)
+    }
+
+    /// Verify that a shared struct that derives serde's traits can be encoded in Swift and
+    /// decoded in Rust, and vice versa.
+    func testCodableStruct() throws {
+        let value = CodableStruct(id: 5, score: 1.5, active: true, nickname: nil, status: .Online)
+
+        let json = String(data: try JSONEncoder().encode(value), encoding: .utf8)!
+        let fromRust = rust_deserialize_codable_struct(json)
+        XCTAssertEqual(fromRust.id, 5)
+        XCTAssertEqual(fromRust.score, 1.5)
+        XCTAssertEqual(fromRust.active, true)
+        XCTAssertNil(fromRust.nickname)
+
+        let rustJson = rust_serialize_codable_struct(
+            CodableStruct(id: 6, score: 2.5, active: false, nickname: 3, status: .Offline)
+        ).toString()
+        let fromSwift = try JSONDecoder().decode(CodableStruct.self, from: rustJson.data(using: .utf8)!)
+        XCTAssertEqual(fromSwift.id, 6)
+        XCTAssertEqual(fromSwift.score, 2.5)
+        XCTAssertEqual(fromSwift.active, false)
+        XCTAssertEqual(fromSwift.nickname, 3)
+        switch fromSwift.status {
+        case .Offline:
+            break
+        case .Online:
+            XCTFail()
+        }
+    }
 }
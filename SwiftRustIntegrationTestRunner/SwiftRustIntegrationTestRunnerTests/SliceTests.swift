@@ -0,0 +1,61 @@
+//
+//  SliceTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+class SliceTests: XCTestCase {
+    /// Verify that we can pass a Swift Array to Rust as a slice, without copying it.
+    func testPassArrayAsSlice() throws {
+        let array: [UInt8] = [1, 2, 3]
+
+        array.withUnsafeBufferPointer({ arrayPtr in
+            let slice = rust_reflect_slice_u8(arrayPtr)
+
+            XCTAssertEqual(slice.baseAddress, arrayPtr.baseAddress)
+            XCTAssertEqual(Array(slice), array)
+        })
+    }
+
+    /// Verify that we can pass Foundation Data to Rust as a slice.
+    func testPassDataAsSlice() throws {
+        let data = Data([4, 5, 6])
+
+        data.withUnsafeBytes({ (bytes: UnsafeRawBufferPointer) in
+            let slice = rust_reflect_slice_u8(bytes.bindMemory(to: UInt8.self))
+
+            XCTAssertEqual(Data(slice), data)
+        })
+    }
+
+    /// Verify that we can pass an empty Array to Rust as a slice.
+    func testPassEmptyArrayAsSlice() throws {
+        let array: [UInt8] = []
+
+        array.withUnsafeBufferPointer({ arrayPtr in
+            XCTAssertEqual(rust_reflect_slice_u8(arrayPtr).count, 0)
+        })
+    }
+
+    /// Verify that we can pass a slice of a non-u8 primitive to Rust.
+    func testPassF64Slice() throws {
+        let array: [Double] = [1.5, 2.5, 3.0]
+
+        array.withUnsafeBufferPointer({ arrayPtr in
+            XCTAssertEqual(rust_sum_slice_f64(arrayPtr), 7.0)
+        })
+    }
+
+    /// Verify that Rust can write into a mutable Swift buffer in place.
+    func testPassMutableArrayAsMutSlice() throws {
+        var array: [Float] = [1.0, 2.5, -3.0]
+
+        array.withUnsafeMutableBufferPointer({ arrayPtr in
+            rust_double_mut_slice_f32(arrayPtr)
+        })
+
+        XCTAssertEqual(array, [2.0, 5.0, -6.0])
+    }
+}
@@ -47,6 +47,21 @@ class StringTests: XCTestCase {
         )
     }
 
+    /// Verify that empty and non-ASCII Swift Strings can be passed to Rust as a `&str`.
+    func testPassSwiftStringToRustStr() throws {
+        XCTAssertEqual(create_string("").toString(), "")
+        XCTAssertEqual(create_string("héllo 🌍").toString(), "héllo 🌍")
+    }
+
+    /// Verify that we can call `toString()` on a borrowed `RustStringRef`.
+    func testRustStringRefToString() throws {
+        let vec = RustVec<RustString>()
+        vec.push(value: create_string("hello"))
+
+        let stringRef: RustStringRef = vec.get(index: 0)!
+        XCTAssertEqual(stringRef.toString(), "hello")
+    }
+
     func testRustStrEqualityOperator() throws {
         XCTContext.runActivity(named: "Should be equal"){
             _ in
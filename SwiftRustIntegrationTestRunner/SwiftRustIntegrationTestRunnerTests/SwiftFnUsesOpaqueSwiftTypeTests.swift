@@ -25,4 +25,16 @@ class SwiftFnReturnOpaqueSwiftTypeTests: XCTestCase {
     func testSwiftMethodReturnOpaqueSwiftType() throws {
         test_rust_calls_swift_method_reflects_owned_opaque_swift_type()
     }
+
+    func testSwiftFnWithRefOpaqueSwiftType() throws {
+        test_rust_calls_swift_fn_with_ref_opaque_swift_type()
+    }
+
+    /// Verify that Rust can borrow a Swift object without taking ownership of it.
+    func testRustFnWithRefOpaqueSwiftType() throws {
+        let swiftType = ASwiftType(amount: 456)
+
+        XCTAssertEqual(rust_fn_reads_ref_opaque_swift_type(swiftType), 456)
+        XCTAssertEqual(rust_fn_reads_ref_opaque_swift_type(swiftType), 456)
+    }
 }
@@ -0,0 +1,25 @@
+//
+//  SystemTimeTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for bridging `std::time::SystemTime`.
+class SystemTimeTests: XCTestCase {
+    /// Verify that Swift can pass a `Date` to Rust and get one back.
+    func testSwiftCallsRustSystemTime() throws {
+        let date = Date(timeIntervalSince1970: 1_700_000_000)
+        XCTAssertEqual(rust_add_one_day_to_system_time(date), date.addingTimeInterval(60 * 60 * 24))
+
+        let beforeEpoch = Date(timeIntervalSince1970: -1.5)
+        XCTAssertEqual(rust_reflect_option_system_time(beforeEpoch), beforeEpoch)
+        XCTAssertNil(rust_reflect_option_system_time(nil))
+    }
+
+    /// Verify that Rust can pass a `SystemTime` to Swift and get one back.
+    func testRustCallsSwiftSystemTime() throws {
+        test_rust_calls_swift_system_time()
+    }
+}
@@ -34,6 +34,17 @@ final class TupleTest: XCTestCase {
             XCTAssertEqual(tuple.1, 123)
             XCTAssertEqual(tuple.2, true)
         }
+        XCTContext.runActivity(named: "Verify that we can return a (F64, F64, F64, F64) from functions and methods.") {
+            _ in
+            let bounds = TupleTestOpaqueRustType(2).bounds()
+            XCTAssertEqual(bounds.0, -2)
+            XCTAssertEqual(bounds.1, -2)
+            XCTAssertEqual(bounds.2, 2)
+            XCTAssertEqual(bounds.3, 2)
+
+            let (minX, minY, maxX, maxY) = rust_default_bounds()
+            XCTAssertEqual([minX, minY, maxX, maxY], [0, 0, 1, 1])
+        }
     }
     
     /// Verify that Rust can call Swift functions that accept and return Tuples.
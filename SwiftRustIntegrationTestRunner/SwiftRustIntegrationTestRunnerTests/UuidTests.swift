@@ -0,0 +1,30 @@
+//
+//  UuidTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests for bridging `uuid::Uuid`.
+class UuidTests: XCTestCase {
+    /// Verify that Swift can pass a `UUID` to Rust and get one back.
+    func testSwiftCallsRustUuid() throws {
+        let uuid = UUID()
+        XCTAssertEqual(rust_reflect_uuid(uuid), uuid)
+
+        XCTAssertEqual(rust_reflect_option_uuid(uuid), uuid)
+        XCTAssertNil(rust_reflect_option_uuid(nil))
+    }
+
+    /// Verify that the bytes of a `UUID` are in the same order on both sides.
+    func testUuidByteOrder() throws {
+        let uuid = UUID(uuidString: "4A3C7C8E-5B7D-4F3E-9F7A-0C2D1E6B8A90")!
+        XCTAssertEqual(rust_uuid_string(uuid).toString(), "4a3c7c8e-5b7d-4f3e-9f7a-0c2d1e6b8a90")
+    }
+
+    /// Verify that Rust can pass a `Uuid` to Swift and get one back.
+    func testRustCallsSwiftUuid() throws {
+        test_rust_calls_swift_uuid()
+    }
+}
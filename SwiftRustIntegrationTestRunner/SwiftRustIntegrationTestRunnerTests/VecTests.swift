@@ -36,6 +36,13 @@ class VecTests: XCTestCase {
         vec.push(value: 222)
         XCTAssertEqual(vec.get(index: 1), 222)
     }
+    func testRustVecU8GetMut() throws {
+        let vec = RustVec<UInt8>()
+        vec.push(value: 111)
+        vec.push(value: 222)
+        XCTAssertEqual(vec.get_mut(index: 1), 222)
+        XCTAssertNil(vec.get_mut(index: 2))
+    }
     func testRustVecU8AsPtr() throws {
         let vec = RustVec<UInt8>()
         vec.push(value: 10)
@@ -99,6 +106,25 @@ class VecTests: XCTestCase {
         XCTAssertEqual(reflected.pop()!, TransparentEnumInsideVecT.VariantB)
     }
     
+    /// Verify that a Vec<Vec<T>> of primitives can be used as an argument and return
+    /// type for extern "Rust" functions.
+    func testReflectVecOfVecU8() throws {
+        let inner: RustVec<UInt8> = RustVec()
+        inner.push(value: 1)
+        inner.push(value: 2)
+
+        let vec: RustVec<RustVec<UInt8>> = RustVec()
+        vec.push(value: inner)
+        vec.push(value: RustVec())
+
+        let reflected = rust_reflect_vec_of_vec_u8(vec)
+        XCTAssertEqual(reflected.len(), 2)
+        XCTAssertEqual(reflected.get(index: 0)!.len(), 2)
+        XCTAssertEqual(reflected.get(index: 0)!.get(index: 1), 2)
+        XCTAssertEqual(reflected.pop()!.len(), 0)
+        XCTAssertEqual(reflected.len(), 1)
+    }
+    
     /// Verify that we can construct a RustVec of every primitive type.
     /// We tested all of the methods on  two different primitives above to be sure that our
     /// functions that generate the pieces of the RustVec support aren't accidentally hard coded to
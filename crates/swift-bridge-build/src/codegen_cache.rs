@@ -0,0 +1,202 @@
+//! Cache the generated code in the build script's `OUT_DIR`, so that the bridge modules are only
+//! parsed and generated again when one of them, or something else that the generated code depends
+//! on, changes.
+
+use crate::BridgeModuleSource;
+use proc_macro2::{Delimiter, TokenStream, TokenTree};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
+use swift_bridge_ir::trace;
+use syn::__private::ToTokens;
+
+const STAMP_FILE: &str = "swift-bridge-codegen.stamp";
+const SWIFT_FILE: &str = "swift-bridge-codegen.swift";
+const C_HEADER_FILE: &str = "swift-bridge-codegen.h";
+
+/// The hashes of the inputs of each bridge module, along with the directory that the code that
+/// was last generated for them is cached in.
+pub(crate) struct CodegenCache {
+    dir: PathBuf,
+    /// The file that each module was found in, along with the hash of its inputs.
+    module_hashes: Vec<(String, u64)>,
+}
+
+impl CodegenCache {
+    /// Hash the bridge modules and the cfg options and features that they get generated with.
+    ///
+    /// Returns `None` when we aren't running in a build script, since there is no `OUT_DIR` to
+    /// cache the generated code in.
+    pub(crate) fn in_out_dir(
+        modules: &[BridgeModuleSource],
+        cfg_fingerprint: &str,
+    ) -> Option<Self> {
+        let out_dir = std::env::var("OUT_DIR").ok()?;
+
+        Some(Self::new(
+            PathBuf::from(out_dir),
+            modules,
+            &format!("{}\n{}", generator_fingerprint(), cfg_fingerprint),
+        ))
+    }
+
+    fn new(dir: PathBuf, modules: &[BridgeModuleSource], fingerprint: &str) -> Self {
+        let module_hashes = modules
+            .iter()
+            .map(|module| {
+                let mut hasher = DefaultHasher::new();
+                fingerprint.hash(&mut hasher);
+                module.source_file.hash(&mut hasher);
+                hash_tokens(module.module.to_token_stream(), &mut hasher);
+
+                (
+                    module.source_file.to_string_lossy().to_string(),
+                    hasher.finish(),
+                )
+            })
+            .collect();
+
+        CodegenCache { dir, module_hashes }
+    }
+
+    /// The Swift code and C header that were generated for the same inputs, if there are any.
+    pub(crate) fn load(&self) -> Option<(String, String)> {
+        let stamp = std::fs::read_to_string(self.dir.join(STAMP_FILE)).ok()?;
+
+        if stamp != self.stamp() {
+            for (source_file, hash) in &self.module_hashes {
+                if !stamp.lines().any(|line| line == format!("{:016x}", hash)) {
+                    trace(|| format!("bridge module in {:?} changed", source_file));
+                }
+            }
+            return None;
+        }
+
+        let swift = std::fs::read_to_string(self.dir.join(SWIFT_FILE)).ok()?;
+        let c_header = std::fs::read_to_string(self.dir.join(C_HEADER_FILE)).ok()?;
+
+        trace(|| "bridge modules are unchanged, reusing the generated code".to_string());
+        Some((swift, c_header))
+    }
+
+    /// Cache the code that was generated for the current inputs.
+    pub(crate) fn store(&self, swift: &str, c_header: &str) {
+        let write = || -> std::io::Result<()> {
+            std::fs::write(self.dir.join(SWIFT_FILE), swift)?;
+            std::fs::write(self.dir.join(C_HEADER_FILE), c_header)?;
+            // Written last so that the stamp never points at code from a different build.
+            std::fs::write(self.dir.join(STAMP_FILE), self.stamp())
+        };
+
+        // The cache is only an optimization, so not being able to write it isn't an error.
+        if let Err(e) = write() {
+            trace(|| format!("could not cache the generated code: {}", e));
+        }
+    }
+
+    fn stamp(&self) -> String {
+        self.module_hashes
+            .iter()
+            .map(|(_, hash)| format!("{:016x}\n", hash))
+            .collect()
+    }
+}
+
+/// Identifies the code generator itself.
+///
+/// Cargo relinks the build script whenever swift-bridge-build, or anything else that the build
+/// script depends on such as a registered `CustomTypeBridge`, changes.
+fn generator_fingerprint() -> String {
+    let modified = std::env::current_exe()
+        .and_then(|exe| exe.metadata())
+        .and_then(|metadata| metadata.modified())
+        .ok();
+
+    format!("{} {:?}", env!("CARGO_PKG_VERSION"), modified)
+}
+
+/// Hash the tokens along with the line that each of them is on, since the generated Swift points
+/// back at the lines of the bridge module's functions.
+fn hash_tokens(tokens: TokenStream, hasher: &mut impl Hasher) {
+    for token in tokens {
+        token.span().start().line.hash(hasher);
+
+        match token {
+            TokenTree::Group(group) => {
+                let delimiter = match group.delimiter() {
+                    Delimiter::Parenthesis => '(',
+                    Delimiter::Brace => '{',
+                    Delimiter::Bracket => '[',
+                    Delimiter::None => ' ',
+                };
+                delimiter.hash(hasher);
+                hash_tokens(group.stream(), hasher);
+                group.span_close().start().line.hash(hasher);
+            }
+            token => token.to_string().hash(hasher),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_file_contents;
+
+    fn modules(file: &str) -> Vec<BridgeModuleSource> {
+        parse_file_contents(file)
+            .unwrap()
+            .into_iter()
+            .map(|module| BridgeModuleSource {
+                source_file: PathBuf::from("src/lib.rs"),
+                module,
+            })
+            .collect()
+    }
+
+    const FILE: &str = r#"
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn a();
+    }
+}
+"#;
+
+    /// Verify that we reuse the cached code when none of the modules changed.
+    #[test]
+    fn reuses_code_for_unchanged_modules() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let cache = CodegenCache::new(dir.path().to_path_buf(), &modules(FILE), "");
+        assert!(cache.load().is_none());
+        cache.store("swift", "c header");
+
+        let cache = CodegenCache::new(dir.path().to_path_buf(), &modules(FILE), "");
+        assert_eq!(
+            cache.load(),
+            Some(("swift".to_string(), "c header".to_string()))
+        );
+    }
+
+    /// Verify that we don't reuse the cached code when a module's tokens, the lines that they are
+    /// on, or the cfg options and features that it gets generated with changed.
+    #[test]
+    fn regenerates_code_for_changed_inputs() {
+        let changed_tokens = FILE.replace("fn a();", "fn b();");
+        let changed_lines = format!("\n{}", FILE);
+
+        for (file, fingerprint) in [
+            (changed_tokens.as_str(), ""),
+            (changed_lines.as_str(), ""),
+            (FILE, "CARGO_FEATURE_SOME_FEATURE=1"),
+        ] {
+            let dir = tempfile::tempdir().unwrap();
+
+            CodegenCache::new(dir.path().to_path_buf(), &modules(FILE), "").store("swift", "c");
+
+            let cache = CodegenCache::new(dir.path().to_path_buf(), &modules(file), fingerprint);
+            assert!(cache.load().is_none());
+        }
+    }
+}
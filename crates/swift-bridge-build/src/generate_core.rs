@@ -4,7 +4,9 @@ use crate::generate_core::boxed_fn_support::{
 use crate::generate_core::option_support::{
     swift_option_primitive_support, C_OPTION_PRIMITIVE_SUPPORT,
 };
+use crate::generate_core::panic_support::SWIFT_PANIC_SUPPORT;
 use crate::generate_core::result_support::{C_RESULT_SUPPORT, SWIFT_RUST_RESULT};
+use crate::{import_if_available, write_if_changed};
 use std::path::Path;
 
 const RUST_STRING_SWIFT: &'static str = include_str!("./generate_core/rust_string.swift");
@@ -12,14 +14,42 @@ const RUST_STRING_C: &'static str = include_str!("./generate_core/rust_string.c.
 
 const STRING_SWIFT: &'static str = include_str!("./generate_core/string.swift");
 const RUST_VEC_SWIFT: &'static str = include_str!("./generate_core/rust_vec.swift");
+const RUST_MAP_SWIFT: &str = include_str!("./generate_core/rust_map.swift");
+const RUST_SET_SWIFT: &str = include_str!("./generate_core/rust_set.swift");
+const INT128_SWIFT: &str = include_str!("./generate_core/int128.swift");
+const UUID_SWIFT: &str = include_str!("./generate_core/uuid.swift");
+const UUID_C: &str = r#"
+typedef struct __private__FfiUuid { uint8_t bytes[16]; } __private__FfiUuid;
+typedef struct __private__OptionFfiUuid { __private__FfiUuid val; bool is_some; } __private__OptionFfiUuid;
+"#;
+
+/// Lets the `SwiftBridgeCore.h` header be imported as the `SwiftBridgeCore` Clang module.
+const CORE_MODULE_MAP: &str = r#"module SwiftBridgeCore {
+    header "SwiftBridgeCore.h"
+    export *
+}
+"#;
 
 mod boxed_fn_support;
 mod option_support;
+mod panic_support;
 mod result_support;
 
-pub(super) fn write_core_swift_and_c(out_dir: &Path) {
+/// The `Uuid` support is only written when one of the bridge modules uses a `Uuid`.
+pub(super) fn write_core_swift_and_c(
+    out_dir: &Path,
+    library_evolution: bool,
+    extra_imports: &str,
+    uses_uuid: bool,
+) {
     let core_swift_out = out_dir.join("SwiftBridgeCore.swift");
-    let mut swift = core_swift();
+    let mut swift = if library_evolution {
+        "import SwiftBridgeCore\n".to_string()
+    } else {
+        import_if_available("SwiftBridgeCore")
+    };
+    swift += extra_imports;
+    swift += &core_swift();
     swift += "\n";
     swift += &RUST_STRING_SWIFT;
     swift += "\n";
@@ -27,9 +57,15 @@ pub(super) fn write_core_swift_and_c(out_dir: &Path) {
     swift += "\n";
     swift += &SWIFT_RUST_RESULT;
     swift += "\n";
+    swift += SWIFT_PANIC_SUPPORT;
+    swift += "\n";
     swift += &swift_option_primitive_support();
+    if uses_uuid {
+        swift += "\n";
+        swift += UUID_SWIFT;
+    }
 
-    std::fs::write(core_swift_out, swift).unwrap();
+    write_if_changed(core_swift_out, &swift);
 
     let core_c_header_out = out_dir.join("SwiftBridgeCore.h");
     let mut c_header = core_c_header().to_string();
@@ -39,8 +75,13 @@ pub(super) fn write_core_swift_and_c(out_dir: &Path) {
     c_header += &C_CALLBACK_SUPPORT_NO_ARGS_NO_RETURN;
     c_header += "\n";
     c_header += &C_RESULT_SUPPORT;
+    if uses_uuid {
+        c_header += UUID_C;
+    }
+
+    write_if_changed(core_c_header_out, &c_header);
 
-    std::fs::write(core_c_header_out, c_header).unwrap();
+    write_if_changed(out_dir.join("module.modulemap"), CORE_MODULE_MAP);
 }
 
 fn core_swift() -> String {
@@ -48,6 +89,9 @@ fn core_swift() -> String {
 
     core_swift += STRING_SWIFT;
     core_swift += RUST_VEC_SWIFT;
+    core_swift += RUST_MAP_SWIFT;
+    core_swift += RUST_SET_SWIFT;
+    core_swift += INT128_SWIFT;
 
     for (swift_ty, rust_ty) in vec![
         ("UInt8", "u8"),
@@ -68,9 +112,9 @@ fn core_swift() -> String {
         ("Double", "f64"),
     ] {
         core_swift += &conform_to_vectorizable(swift_ty, rust_ty);
+        core_swift += &conform_to_nested_vectorizable(swift_ty, rust_ty);
     }
 
-    core_swift += &generic_freer();
     core_swift += &generic_copy_type_ffi_repr();
 
     core_swift
@@ -80,7 +124,10 @@ fn core_c_header() -> String {
     let mut header = r#"#include <stdint.h>
 #include <stdbool.h> 
 typedef struct RustStr { uint8_t* const start; uintptr_t len; } RustStr;
+typedef struct __private__FfiCowStr { struct RustStr str; void* owned; } __private__FfiCowStr;
 typedef struct __private__FfiSlice { void* const start; uintptr_t len; } __private__FfiSlice;
+typedef struct __private__U128 { uint64_t low; uint64_t high; } __private__U128;
+typedef struct __private__I128 { uint64_t low; int64_t high; } __private__I128;
 void* __swift_bridge__null_pointer(void);
 
 "#
@@ -106,6 +153,7 @@ void* __swift_bridge__null_pointer(void);
         ("f64", "double"),
     ] {
         header += &vec_of_primitive_headers(rust_ty, c_ty);
+        header += &vec_of_vec_of_primitive_headers(rust_ty);
     }
 
     header
@@ -139,6 +187,23 @@ void __swift_bridge__$Vec_{rust_ty}$push(void* const vec, {c_ty} val);
     )
 }
 
+/// Headers for Vec<Vec<T>> where T is a primitive such as u8, i32, bool
+fn vec_of_vec_of_primitive_headers(rust_ty: &str) -> String {
+    format!(
+        r#"
+void* __swift_bridge__$Vec_Vec_{rust_ty}$new();
+void __swift_bridge__$Vec_Vec_{rust_ty}$_free(void* const vec);
+uintptr_t __swift_bridge__$Vec_Vec_{rust_ty}$len(void* const vec);
+void __swift_bridge__$Vec_Vec_{rust_ty}$push(void* const vec, void* val);
+void* __swift_bridge__$Vec_Vec_{rust_ty}$pop(void* const vec);
+void* __swift_bridge__$Vec_Vec_{rust_ty}$get(void* const vec, uintptr_t index);
+void* __swift_bridge__$Vec_Vec_{rust_ty}$get_mut(void* const vec, uintptr_t index);
+void const * __swift_bridge__$Vec_Vec_{rust_ty}$as_ptr(void* const vec);
+"#,
+        rust_ty = rust_ty,
+    )
+}
+
 fn conform_to_vectorizable(swift_ty: &str, rust_ty: &str) -> String {
     format!(
         r#"
@@ -196,13 +261,46 @@ extension {swift_ty}: Vectorizable {{
     )
 }
 
-/// Used to free memory for generic Opaque Rust types such as `type SomeType<u32>`
-fn generic_freer() -> &'static str {
-    r#"
-protocol SwiftBridgeGenericFreer {
-    func rust_free();
-}
-    "#
+fn conform_to_nested_vectorizable(swift_ty: &str, rust_ty: &str) -> String {
+    format!(
+        r#"
+extension {swift_ty}: NestedVectorizable {{
+    public static func vecOfVecOfSelfNew() -> UnsafeMutableRawPointer {{
+        __swift_bridge__$Vec_Vec_{rust_ty}$new()
+    }}
+
+    public static func vecOfVecOfSelfFree(vecPtr: UnsafeMutableRawPointer) {{
+        __swift_bridge__$Vec_Vec_{rust_ty}$_free(vecPtr)
+    }}
+
+    public static func vecOfVecOfSelfPush(vecPtr: UnsafeMutableRawPointer, value: UnsafeMutableRawPointer) {{
+        __swift_bridge__$Vec_Vec_{rust_ty}$push(vecPtr, value)
+    }}
+
+    public static func vecOfVecOfSelfPop(vecPtr: UnsafeMutableRawPointer) -> Optional<UnsafeMutableRawPointer> {{
+        __swift_bridge__$Vec_Vec_{rust_ty}$pop(vecPtr)
+    }}
+
+    public static func vecOfVecOfSelfGet(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<UnsafeMutableRawPointer> {{
+        __swift_bridge__$Vec_Vec_{rust_ty}$get(vecPtr, index)
+    }}
+
+    public static func vecOfVecOfSelfGetMut(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<UnsafeMutableRawPointer> {{
+        __swift_bridge__$Vec_Vec_{rust_ty}$get_mut(vecPtr, index)
+    }}
+
+    public static func vecOfVecOfSelfAsPtr(vecPtr: UnsafeMutableRawPointer) -> UnsafeRawPointer {{
+        __swift_bridge__$Vec_Vec_{rust_ty}$as_ptr(vecPtr)
+    }}
+
+    public static func vecOfVecOfSelfLen(vecPtr: UnsafeMutableRawPointer) -> UInt {{
+        __swift_bridge__$Vec_Vec_{rust_ty}$len(vecPtr)
+    }}
+}}
+    "#,
+        rust_ty = rust_ty,
+        swift_ty = swift_ty
+    )
 }
 
 /// A Swift protocol that is implemented for the FFI representation of all generic Copy types
@@ -212,3 +310,22 @@ fn generic_copy_type_ffi_repr() -> &'static str {
 protocol SwiftBridgeGenericCopyTypeFfiRepr {}
 "#
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify that we only write the `Uuid` support when a bridge module uses a `Uuid`.
+    #[test]
+    fn only_writes_uuid_support_when_used() {
+        for uses_uuid in [false, true] {
+            let dir = tempfile::tempdir().unwrap();
+            write_core_swift_and_c(dir.path(), false, "", uses_uuid);
+
+            let swift = std::fs::read_to_string(dir.path().join("SwiftBridgeCore.swift")).unwrap();
+            let c_header = std::fs::read_to_string(dir.path().join("SwiftBridgeCore.h")).unwrap();
+            assert_eq!(swift.contains("extension UUID"), uses_uuid);
+            assert_eq!(c_header.contains("__private__FfiUuid"), uses_uuid);
+        }
+    }
+}
@@ -0,0 +1,103 @@
+/// A Rust `u128`.
+///
+/// The value is stored as two 64-bit limbs, since `Swift.UInt128` is only available on newer
+/// toolchains and operating systems.
+public struct RustUInt128: Equatable, Hashable {
+    public var low: UInt64
+    public var high: UInt64
+
+    public init(low: UInt64, high: UInt64) {
+        self.low = low
+        self.high = high
+    }
+
+    public init(_ value: UInt64) {
+        self.init(low: value, high: 0)
+    }
+
+    @inline(__always)
+    func intoFfiRepr() -> __private__U128 {
+        __private__U128(low: low, high: high)
+    }
+}
+extension RustUInt128: Comparable {
+    public static func < (lhs: RustUInt128, rhs: RustUInt128) -> Bool {
+        (lhs.high, lhs.low) < (rhs.high, rhs.low)
+    }
+}
+extension RustUInt128: ExpressibleByIntegerLiteral {
+    public init(integerLiteral value: UInt64) {
+        self.init(value)
+    }
+}
+extension __private__U128 {
+    @inline(__always)
+    func intoSwiftRepr() -> RustUInt128 {
+        RustUInt128(low: low, high: high)
+    }
+}
+
+/// A Rust `i128`.
+///
+/// The value is stored as two 64-bit limbs. The sign lives in the high limb.
+public struct RustInt128: Equatable, Hashable {
+    public var low: UInt64
+    public var high: Int64
+
+    public init(low: UInt64, high: Int64) {
+        self.low = low
+        self.high = high
+    }
+
+    public init(_ value: Int64) {
+        self.init(low: UInt64(bitPattern: value), high: value < 0 ? -1 : 0)
+    }
+
+    @inline(__always)
+    func intoFfiRepr() -> __private__I128 {
+        __private__I128(low: low, high: high)
+    }
+}
+extension RustInt128: Comparable {
+    public static func < (lhs: RustInt128, rhs: RustInt128) -> Bool {
+        (lhs.high, lhs.low) < (rhs.high, rhs.low)
+    }
+}
+extension RustInt128: ExpressibleByIntegerLiteral {
+    public init(integerLiteral value: Int64) {
+        self.init(value)
+    }
+}
+extension __private__I128 {
+    @inline(__always)
+    func intoSwiftRepr() -> RustInt128 {
+        RustInt128(low: low, high: high)
+    }
+}
+
+#if compiler(>=6.0)
+@available(macOS 15.0, iOS 18.0, watchOS 11.0, tvOS 18.0, visionOS 2.0, *)
+extension RustUInt128 {
+    public init(_ value: UInt128) {
+        self.init(low: UInt64(truncatingIfNeeded: value), high: UInt64(truncatingIfNeeded: value >> 64))
+    }
+}
+@available(macOS 15.0, iOS 18.0, watchOS 11.0, tvOS 18.0, visionOS 2.0, *)
+extension UInt128 {
+    public init(_ value: RustUInt128) {
+        self = UInt128(value.high) << 64 | UInt128(value.low)
+    }
+}
+@available(macOS 15.0, iOS 18.0, watchOS 11.0, tvOS 18.0, visionOS 2.0, *)
+extension RustInt128 {
+    public init(_ value: Int128) {
+        self.init(low: UInt64(truncatingIfNeeded: value), high: Int64(truncatingIfNeeded: value >> 64))
+    }
+}
+@available(macOS 15.0, iOS 18.0, watchOS 11.0, tvOS 18.0, visionOS 2.0, *)
+extension Int128 {
+    public init(_ value: RustInt128) {
+        self = Int128(value.high) << 64 | Int128(value.low)
+    }
+}
+#endif
@@ -59,6 +59,8 @@ typedef struct __private__OptionU32 { uint32_t val; bool is_some; } __private__O
 typedef struct __private__OptionI32 { int32_t val; bool is_some; } __private__OptionI32;
 typedef struct __private__OptionU64 { uint64_t val; bool is_some; } __private__OptionU64;
 typedef struct __private__OptionI64 { int64_t val; bool is_some; } __private__OptionI64;
+typedef struct __private__OptionU128 { __private__U128 val; bool is_some; } __private__OptionU128;
+typedef struct __private__OptionI128 { __private__I128 val; bool is_some; } __private__OptionI128;
 typedef struct __private__OptionUsize { uintptr_t val; bool is_some; } __private__OptionUsize;
 typedef struct __private__OptionIsize { intptr_t val; bool is_some; } __private__OptionIsize;
 typedef struct __private__OptionF32 { float val; bool is_some; } __private__OptionF32;
@@ -0,0 +1,33 @@
+pub const SWIFT_PANIC_SUPPORT: &str = r#"
+/// The error that a Rust function that returns a `Result` throws when it panics, in bridge modules
+/// that use `#[swift_bridge::bridge(panic = "throw")]`.
+public struct RustPanic: Error, CustomStringConvertible {
+    /// The panic's message.
+    public let message: String
+
+    public var description: String {
+        "Rust panicked: \(message)"
+    }
+}
+
+private let rustPanicKey = "__swift_bridge__RustPanic"
+
+@_cdecl("__swift_bridge__$rust_panic_store")
+func __swift_bridge__rust_panic_store(_ message: UnsafeMutableRawPointer) {
+    Thread.current.threadDictionary[rustPanicKey] = RustString(ptr: message).toString()
+}
+
+@_cdecl("__swift_bridge__$rust_panic_fatal_error")
+func __swift_bridge__rust_panic_fatal_error(_ message: UnsafeMutableRawPointer) {
+    fatalError(RustString(ptr: message).toString())
+}
+
+func __swift_bridge__throwIfRustPanicked<T>(_ val: T) throws -> T {
+    let threadDictionary = Thread.current.threadDictionary
+    if let message = threadDictionary[rustPanicKey] as? String {
+        threadDictionary.removeObject(forKey: rustPanicKey)
+        throw RustPanic(message: message)
+    }
+    return val
+}
+"#;
@@ -0,0 +1,20 @@
+/// A Rust `HashMap<K, V>`.
+///
+/// The initializers, `count`, `get`, `insert` and `remove` are generated alongside the bridge
+/// module for each `HashMap<K, V>` that the bridge module uses.
+public class RustMap<K, V> {
+    var ptr: UnsafeMutableRawPointer
+    var isOwned: Bool = true
+    let free: (UnsafeMutableRawPointer) -> ()
+
+    public init(ptr: UnsafeMutableRawPointer, free: @escaping (UnsafeMutableRawPointer) -> ()) {
+        self.ptr = ptr
+        self.free = free
+    }
+
+    deinit {
+        if isOwned {
+            free(ptr)
+        }
+    }
+}
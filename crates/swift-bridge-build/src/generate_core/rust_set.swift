@@ -0,0 +1,20 @@
+/// A Rust `HashSet<T>`.
+///
+/// The initializers, `count`, `contains`, `insert`, `remove` and `toSet` are generated alongside
+/// the bridge module for each `HashSet<T>` that the bridge module uses.
+public class RustSet<T> {
+    var ptr: UnsafeMutableRawPointer
+    var isOwned: Bool = true
+    let free: (UnsafeMutableRawPointer) -> ()
+
+    public init(ptr: UnsafeMutableRawPointer, free: @escaping (UnsafeMutableRawPointer) -> ()) {
+        self.ptr = ptr
+        self.free = free
+    }
+
+    deinit {
+        if isOwned {
+            free(ptr)
+        }
+    }
+}
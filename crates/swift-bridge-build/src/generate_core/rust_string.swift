@@ -22,6 +22,8 @@ extension RustString {
         }))
     }
 }
+// Allows a `Result<T, String>` returned from Rust to be thrown as a Swift `Error`.
+extension RustString: Error {}
 public class RustStringRefMut: RustStringRef {
     public override init(ptr: UnsafeMutableRawPointer) {
         super.init(ptr: ptr)
@@ -23,6 +23,10 @@ public class RustVec<T: Vectorizable> {
          T.vecOfSelfGet(vecPtr: ptr, index: index)
     }
 
+    public func get_mut(index: UInt) -> Optional<T.SelfRefMut> {
+         T.vecOfSelfGetMut(vecPtr: ptr, index: index)
+    }
+
     public func as_ptr() -> UnsafePointer<T.SelfRef> {
         UnsafePointer<T.SelfRef>(OpaquePointer(T.vecOfSelfAsPtr(vecPtr: ptr)))
     }
@@ -89,6 +93,12 @@ extension UnsafeBufferPointer {
     }
 }
 
+extension UnsafeMutableBufferPointer {
+    func toFfiSlice () -> __private__FfiSlice {
+        __private__FfiSlice(start: self.baseAddress, len: UInt(self.count))
+    }
+}
+
 public protocol Vectorizable {
     associatedtype SelfRef
     associatedtype SelfRefMut
@@ -109,3 +119,78 @@ public protocol Vectorizable {
 
     static func vecOfSelfLen(vecPtr: UnsafeMutableRawPointer) -> UInt
 }
+
+/// A type whose `RustVec` can itself be stored in a `RustVec`, such as the `RustVec<UInt8>`s
+/// in a `RustVec<RustVec<UInt8>>`.
+public protocol NestedVectorizable: Vectorizable {
+    static func vecOfVecOfSelfNew() -> UnsafeMutableRawPointer;
+
+    static func vecOfVecOfSelfFree(vecPtr: UnsafeMutableRawPointer)
+
+    static func vecOfVecOfSelfPush(vecPtr: UnsafeMutableRawPointer, value: UnsafeMutableRawPointer)
+
+    static func vecOfVecOfSelfPop(vecPtr: UnsafeMutableRawPointer) -> Optional<UnsafeMutableRawPointer>
+
+    static func vecOfVecOfSelfGet(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<UnsafeMutableRawPointer>
+
+    static func vecOfVecOfSelfGetMut(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<UnsafeMutableRawPointer>
+
+    static func vecOfVecOfSelfAsPtr(vecPtr: UnsafeMutableRawPointer) -> UnsafeRawPointer
+
+    static func vecOfVecOfSelfLen(vecPtr: UnsafeMutableRawPointer) -> UInt
+}
+
+extension RustVec: Vectorizable where T: NestedVectorizable {
+    public static func vecOfSelfNew() -> UnsafeMutableRawPointer {
+        T.vecOfVecOfSelfNew()
+    }
+
+    public static func vecOfSelfFree(vecPtr: UnsafeMutableRawPointer) {
+        T.vecOfVecOfSelfFree(vecPtr: vecPtr)
+    }
+
+    public static func vecOfSelfPush(vecPtr: UnsafeMutableRawPointer, value: RustVec<T>) {
+        T.vecOfVecOfSelfPush(vecPtr: vecPtr, value: {value.isOwned = false; return value.ptr;}())
+    }
+
+    public static func vecOfSelfPop(vecPtr: UnsafeMutableRawPointer) -> Optional<Self> {
+        let pointer = T.vecOfVecOfSelfPop(vecPtr: vecPtr)
+        if pointer == nil {
+            return nil
+        } else {
+            return (RustVec<T>(ptr: pointer!) as! Self)
+        }
+    }
+
+    /// The returned `RustVec` is borrowed from the outer `RustVec`, so it does not free the inner
+    /// vector when it gets deinitialized.
+    public static func vecOfSelfGet(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<RustVec<T>> {
+        let pointer = T.vecOfVecOfSelfGet(vecPtr: vecPtr, index: index)
+        if pointer == nil {
+            return nil
+        } else {
+            let vec = RustVec<T>(ptr: pointer!)
+            vec.isOwned = false
+            return vec
+        }
+    }
+
+    public static func vecOfSelfGetMut(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<RustVec<T>> {
+        let pointer = T.vecOfVecOfSelfGetMut(vecPtr: vecPtr, index: index)
+        if pointer == nil {
+            return nil
+        } else {
+            let vec = RustVec<T>(ptr: pointer!)
+            vec.isOwned = false
+            return vec
+        }
+    }
+
+    public static func vecOfSelfAsPtr(vecPtr: UnsafeMutableRawPointer) -> UnsafePointer<RustVec<T>> {
+        UnsafePointer<RustVec<T>>(OpaquePointer(T.vecOfVecOfSelfAsPtr(vecPtr: vecPtr)))
+    }
+
+    public static func vecOfSelfLen(vecPtr: UnsafeMutableRawPointer) -> UInt {
+        T.vecOfVecOfSelfLen(vecPtr: vecPtr)
+    }
+}
@@ -1,6 +1,6 @@
 import Foundation
 
-extension RustString {
+extension RustStringRef {
     public func toString() -> String {
         let str = self.as_str()
         let string = str.toString()
@@ -73,14 +73,19 @@ func optionalStringIntoRustString<S: IntoRustString>(_ string: Optional<S>) -> R
 }
 
 extension String: ToRustStr {
-    /// Safely get a scoped pointer to the String and then call the callback with a RustStr
-    /// that uses that pointer.
+    /// Safely get a scoped pointer to the String's UTF-8 bytes and then call the callback with a
+    /// RustStr that uses that pointer.
+    ///
+    /// Native Swift Strings are already stored as contiguous UTF-8, so this does not copy.
     public func toRustStr<T> (_ withUnsafeRustStr: (RustStr) -> T) -> T {
-        return self.utf8CString.withUnsafeBufferPointer({ bufferPtr in
+        var string = self
+        return string.withUTF8({ bufferPtr in
+            // An empty buffer may not have a base address. Rust requires a non-null pointer
+            // even for zero length slices, so we fall back to a dangling pointer.
+            let start = bufferPtr.baseAddress ?? UnsafePointer(bitPattern: 1)!
             let rustStr = RustStr(
-                start: UnsafeMutableRawPointer(mutating: bufferPtr.baseAddress!).assumingMemoryBound(to: UInt8.self),
-                // Subtract 1 because of the null termination character at the end
-                len: UInt(bufferPtr.count - 1)
+                start: UnsafeMutablePointer(mutating: start),
+                len: UInt(bufferPtr.count)
             )
             return withUnsafeRustStr(rustStr)
         })
@@ -100,3 +105,66 @@ func optionalRustStrToRustStr<S: ToRustStr, T>(_ str: Optional<S>, _ withUnsafeR
         return withUnsafeRustStr(RustStr(start: nil, len: 0))
     }
 }
+
+/// A Rust `Cow<'static, str>`.
+///
+/// A borrowed string lives for the rest of the program, so its bytes are never freed. An owned
+/// string's bytes are freed when the `RustCowStr` that owns them is deinitialized.
+public class RustCowStr {
+    let str: RustStr
+    /// The `RustString` that owns the bytes, or `nil` if the string is borrowed.
+    let owned: RustString?
+
+    init(_ ffi: __private__FfiCowStr) {
+        self.str = ffi.str
+        if let owned = ffi.owned {
+            self.owned = RustString(ptr: owned)
+        } else {
+            self.owned = nil
+        }
+    }
+
+    /// Whether or not this is a borrowed `&'static str`.
+    public var isBorrowed: Bool {
+        owned == nil
+    }
+
+    public func as_str() -> RustStr {
+        str
+    }
+
+    public func toString() -> String {
+        str.toString()
+    }
+
+    func intoFfiRepr() -> __private__FfiCowStr {
+        if let owned = owned {
+            owned.isOwned = false
+            return __private__FfiCowStr(str: str, owned: owned.ptr)
+        } else {
+            return __private__FfiCowStr(str: str, owned: nil)
+        }
+    }
+}
+extension __private__FfiCowStr {
+    func intoSwiftRepr() -> RustCowStr {
+        RustCowStr(self)
+    }
+
+    func intoOptionalSwiftRepr() -> Optional<RustCowStr> {
+        if self.str.start == nil {
+            return nil
+        } else {
+            return RustCowStr(self)
+        }
+    }
+}
+extension Optional where Wrapped == RustCowStr {
+    func intoFfiRepr() -> __private__FfiCowStr {
+        if let val = self {
+            return val.intoFfiRepr()
+        } else {
+            return __private__FfiCowStr(str: RustStr(start: nil, len: 0), owned: nil)
+        }
+    }
+}
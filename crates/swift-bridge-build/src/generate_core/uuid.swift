@@ -0,0 +1,35 @@
+import Foundation
+
+extension UUID {
+    @inline(__always)
+    func intoFfiRepr() -> __private__FfiUuid {
+        __private__FfiUuid(bytes: self.uuid)
+    }
+}
+extension __private__FfiUuid {
+    @inline(__always)
+    func intoSwiftRepr() -> UUID {
+        UUID(uuid: self.bytes)
+    }
+}
+
+extension __private__OptionFfiUuid {
+    @inline(__always)
+    func intoSwiftRepr() -> Optional<UUID> {
+        if self.is_some {
+            return self.val.intoSwiftRepr()
+        } else {
+            return nil
+        }
+    }
+}
+extension Optional where Wrapped == UUID {
+    @inline(__always)
+    func intoFfiRepr() -> __private__OptionFfiUuid {
+        if let val = self {
+            return __private__OptionFfiUuid(val: val.intoFfiRepr(), is_some: true)
+        } else {
+            return __private__OptionFfiUuid(val: __private__FfiUuid(), is_some: false)
+        }
+    }
+}
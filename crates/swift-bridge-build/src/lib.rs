@@ -3,158 +3,550 @@
 
 #![deny(missing_docs)]
 
+mod codegen_cache;
 mod package;
+mod xcode;
+use crate::codegen_cache::CodegenCache;
 use crate::generate_core::write_core_swift_and_c;
 pub use package::*;
-use std::path::Path;
-use swift_bridge_ir::{CodegenConfig, SwiftBridgeModule};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+pub use swift_bridge_ir::{register_custom_type_bridge, CustomTypeBridge};
+use swift_bridge_ir::{trace, CodegenConfig, SwiftBridgeModule};
 use syn::__private::ToTokens;
-use syn::{File, Item};
+use syn::{File, Item, ItemMod};
+pub use xcode::*;
 
 mod generate_core;
 
 /// Parse rust sources files for `#\[swift_bridge::bridge\]` headers and generate the corresponding
 /// Swift files.
+///
+/// The code for all of the bridge modules in all of the files is merged, so helpers that more
+/// than one module needs are only generated once.
+///
+/// The crate's enabled features and cfg options are read from the environment variables that
+/// Cargo sets when running a build script. Use [`parse_bridges_with_cfg`] to pass them in
+/// explicitly instead.
+///
+/// When the build script runs with `BUILD_LIBRARY_FOR_DISTRIBUTION=YES`, which Xcode sets for
+/// targets that build a framework for distribution, the code is generated in library evolution
+/// mode. See [`CrateCfg::library_evolution`].
+///
+/// The generated code is cached in `OUT_DIR`, and the bridge modules are only generated again
+/// when one of them, or the crate's features and cfg options, changed.
 pub fn parse_bridges(
     rust_source_files: impl IntoIterator<Item = impl AsRef<Path>>,
 ) -> GeneratedCode {
-    let mut generated_code = GeneratedCode::new();
+    let config = CodegenConfig {
+        crate_feature_lookup: Box::new(|feature_name| {
+            let normalized_feature_name = feature_name.replace("-", "_");
+            let normalized_feature_name = normalized_feature_name.to_uppercase();
+
+            let env_var_name = format!("CARGO_FEATURE_{}", normalized_feature_name);
+            std::env::var(env_var_name).is_ok()
+        }),
+        crate_cfg_lookup: Box::new(|name, value| {
+            let env_var_name = format!("CARGO_CFG_{}", name.to_uppercase());
+
+            match (std::env::var(env_var_name), value) {
+                (Ok(_), None) => true,
+                (Ok(values), Some(value)) => values.split(',').any(|v| v == value),
+                (Err(_), _) => false,
+            }
+        }),
+    };
+
+    let library_evolution = std::env::var("BUILD_LIBRARY_FOR_DISTRIBUTION")
+        .map(|value| value == "YES")
+        .unwrap_or(false);
+
+    let mut cfg_fingerprint: Vec<String> = std::env::vars()
+        .filter(|(name, _)| name.starts_with("CARGO_FEATURE_") || name.starts_with("CARGO_CFG_"))
+        .map(|(name, value)| format!("{}={}", name, value))
+        .collect();
+    cfg_fingerprint.sort();
+
+    generate(
+        find_modules(rust_source_files),
+        config,
+        &cfg_fingerprint.join("\n"),
+        library_evolution,
+    )
+}
+
+/// Parse rust sources files for `#\[swift_bridge::bridge\]` headers and generate the corresponding
+/// Swift files, using the given features and cfg options to decide which `#[cfg(...)]` items to
+/// generate code for.
+///
+/// This is useful when generating code outside of a build script, such as from a build system
+/// other than Cargo.
+pub fn parse_bridges_with_cfg(
+    rust_source_files: impl IntoIterator<Item = impl AsRef<Path>>,
+    cfg: &CrateCfg,
+) -> GeneratedCode {
+    let cfg_fingerprint = format!("{:?} {:?}", cfg.features, cfg.options);
+
+    let features = cfg.features.clone();
+    let options: Vec<(String, Option<String>)> = cfg
+        .options
+        .iter()
+        .map(|option| parse_cfg_option(option))
+        .collect();
+
+    let config = CodegenConfig {
+        crate_feature_lookup: Box::new(move |feature_name| {
+            features.iter().any(|feature| feature == feature_name)
+        }),
+        crate_cfg_lookup: Box::new(move |name, value| {
+            options
+                .iter()
+                .any(|(option, option_value)| option == name && option_value.as_deref() == value)
+        }),
+    };
+
+    generate(
+        find_modules(rust_source_files),
+        config,
+        &cfg_fingerprint,
+        cfg.library_evolution,
+    )
+}
+
+/// The features and cfg options that a crate gets compiled with.
+#[derive(Debug, Default, Clone)]
+pub struct CrateCfg {
+    /// The enabled features, such as `metrics`.
+    pub features: Vec<String>,
+    /// The set cfg options, in the format that `rustc --print cfg` uses, such as `unix` or
+    /// `target_os="ios"`.
+    pub options: Vec<String>,
+    /// Whether the generated Swift gets compiled into a framework that is built for distribution,
+    /// with `BUILD_LIBRARY_FOR_DISTRIBUTION=YES` or `-enable-library-evolution`.
+    ///
+    /// Swift can't emit a module interface for code that uses a bridging header, so the generated
+    /// Swift always imports the C declarations as Clang modules. The crate's module is imported
+    /// with `@_implementationOnly`, which keeps the C types of the bridge modules, and so their
+    /// layout, out of the framework's public interface.
+    pub library_evolution: bool,
+}
+
+/// `target_os="ios"` -> `("target_os", Some("ios"))`, `unix` -> `("unix", None)`
+fn parse_cfg_option(option: &str) -> (String, Option<String>) {
+    match option.split_once('=') {
+        Some((name, value)) => (
+            name.trim().to_string(),
+            Some(value.trim().trim_matches('"').to_string()),
+        ),
+        None => (option.trim().to_string(), None),
+    }
+}
+
+/// A `#\[swift_bridge::bridge\]` module, along with the file that it was found in.
+struct BridgeModuleSource {
+    source_file: PathBuf,
+    module: ItemMod,
+}
+
+fn find_modules(
+    rust_source_files: impl IntoIterator<Item = impl AsRef<Path>>,
+) -> Vec<BridgeModuleSource> {
+    let mut modules = vec![];
 
     for rust_file in rust_source_files.into_iter() {
         let rust_file: &Path = rust_file.as_ref();
 
         let file = std::fs::read_to_string(rust_file).unwrap();
-        let gen = match parse_file_contents(&file) {
-            Ok(generated) => generated,
-            Err(e) => {
-                // TODO: Return an error...
-                panic!(
-                    r#"
+        match parse_file_contents(&file) {
+            Ok(file_modules) => {
+                trace(|| {
+                    format!(
+                        "found {} bridge module(s) in {:?}",
+                        file_modules.len(),
+                        rust_file
+                    )
+                });
+
+                // The generated Swift points back at the file using `#sourceLocation`, so we use
+                // an absolute path that Xcode can find no matter where the Swift gets compiled.
+                let source_file =
+                    std::fs::canonicalize(rust_file).unwrap_or_else(|_| rust_file.to_path_buf());
+
+                for module in file_modules {
+                    modules.push(BridgeModuleSource {
+                        source_file: source_file.clone(),
+                        module,
+                    });
+                }
+            }
+            Err(e) => panic_with_parse_error(rust_file, e),
+        };
+    }
+
+    modules
+}
+
+fn parse_modules(sources: Vec<BridgeModuleSource>) -> Vec<SwiftBridgeModule> {
+    sources
+        .into_iter()
+        .map(|source| {
+            let mut module: SwiftBridgeModule = match syn::parse2(source.module.to_token_stream()) {
+                Ok(module) => module,
+                Err(e) => panic_with_parse_error(&source.source_file, e),
+            };
+            module.set_source_file(source.source_file.to_string_lossy());
+            module
+        })
+        .collect()
+}
+
+fn panic_with_parse_error(rust_file: &Path, e: syn::Error) -> ! {
+    // TODO: Return an error...
+    panic!(
+        r#"
 Error while parsing {:?}
 {}
 "#,
-                    rust_file, e
-                )
+        rust_file, e
+    )
+}
+
+fn generate(
+    sources: Vec<BridgeModuleSource>,
+    config: CodegenConfig,
+    cfg_fingerprint: &str,
+    library_evolution: bool,
+) -> GeneratedCode {
+    let cache = CodegenCache::in_out_dir(&sources, cfg_fingerprint);
+
+    let (swift, c_header) = match cache.as_ref().and_then(|cache| cache.load()) {
+        Some(cached) => cached,
+        None => {
+            let swift_and_c = SwiftBridgeModule::generate_swift_code_and_c_header_for_modules(
+                &parse_modules(sources),
+                config,
+            );
+            if let Some(cache) = cache {
+                cache.store(&swift_and_c.swift, &swift_and_c.c_header);
             }
-        };
+            (swift_and_c.swift, swift_and_c.c_header)
+        }
+    };
 
-        generated_code.generated.push(gen);
+    GeneratedCode {
+        swift,
+        c_header,
+        library_evolution,
+        swift_imports: vec![],
     }
+}
+
+/// Parse all of the Rust source files that match a glob pattern, such as `"src/**/*.rs"`, for
+/// `#\[swift_bridge::bridge\]` modules and generate the corresponding Swift files.
+///
+/// A build script still needs to tell Cargo to re-run it when any of the matched files change.
+pub fn parse_bridges_in_glob(pattern: &str) -> GeneratedCode {
+    parse_bridges(glob_paths(pattern))
+}
+
+/// Like [`parse_bridges_in_glob`], but with the crate's features and cfg options passed in
+/// explicitly, like [`parse_bridges_with_cfg`].
+pub fn parse_bridges_in_glob_with_cfg(pattern: &str, cfg: &CrateCfg) -> GeneratedCode {
+    parse_bridges_with_cfg(glob_paths(pattern), cfg)
+}
 
-    generated_code
+fn glob_paths(pattern: &str) -> Vec<PathBuf> {
+    let paths = match glob::glob(pattern) {
+        Ok(paths) => paths,
+        Err(e) => panic!("Invalid glob pattern {:?}: {}", pattern, e),
+    };
+
+    paths
+        .map(|path| match path {
+            Ok(path) => path,
+            Err(e) => panic!("Could not read {:?}: {}", e.path(), e.error()),
+        })
+        .collect()
 }
 
 /// Generated Swift files and C headers.
 pub struct GeneratedCode {
-    generated: Vec<GeneratedFromSwiftBridgeModule>,
+    swift: String,
+    c_header: String,
+    library_evolution: bool,
+    swift_imports: Vec<String>,
 }
 
 impl GeneratedCode {
-    fn new() -> Self {
-        GeneratedCode { generated: vec![] }
+    /// Import a module at the top of each of the generated Swift files, in addition to the Clang
+    /// modules that swift-bridge generates.
+    ///
+    /// This is useful when the C declarations are provided by another module, such as the
+    /// `RustXcframework` module of an xcframework that was created with [`create_xcframework`].
+    pub fn import_swift_module(&mut self, module_name: impl Into<String>) {
+        self.swift_imports.push(module_name.into());
     }
-}
 
-impl GeneratedCode {
     /// Write all of the generated Swift to a single Swift file and all of the generated C headers
     /// to a single header file.
     pub fn write_all_concatenated(&self, swift_bridge_out_dir: impl AsRef<Path>, crate_name: &str) {
         let swift_bridge_out_dir = swift_bridge_out_dir.as_ref();
 
-        let mut concatenated_swift = "".to_string();
-        let mut concatenated_c = "".to_string();
-
-        for gen in &self.generated {
-            concatenated_swift += &gen.swift;
-            concatenated_c += &gen.c_header;
-        }
-
         let out = swift_bridge_out_dir.join(&crate_name);
         match std::fs::create_dir_all(&out) {
             Ok(_) => {}
             Err(_) => {}
         };
 
-        std::fs::write(out.join(format!("{}.h", crate_name)), concatenated_c).unwrap();
-        std::fs::write(
+        let module_name = clang_module_name(crate_name);
+        let mut imports = if self.library_evolution {
+            format!("import SwiftBridgeCore\n@_implementationOnly import {module_name}\n")
+        } else {
+            import_if_available(&module_name)
+        };
+        imports += &self.extra_swift_imports();
+
+        write_if_changed(out.join(format!("{}.h", crate_name)), &self.c_header);
+        write_if_changed(out.join("BridgingHeader.h"), &bridging_header(crate_name));
+        write_if_changed(out.join("module.modulemap"), &module_map(&module_name));
+        write_if_changed(
             out.join(format!("{}.swift", crate_name)),
-            concatenated_swift,
-        )
-        .unwrap();
+            &format!("{}{}", imports, self.swift),
+        );
 
-        write_core_swift_and_c(swift_bridge_out_dir.as_ref());
+        write_core_swift_and_c(
+            swift_bridge_out_dir.as_ref(),
+            self.library_evolution,
+            &self.extra_swift_imports(),
+            self.uses_uuid(),
+        );
     }
 
-    /// Concatenate all of the generated Swift code into one file.
-    pub fn concat_swift(&self) -> String {
-        let mut swift = "".to_string();
+    /// Write all of the generated Swift and C code to the build script's `OUT_DIR`, in the same
+    /// layout as [`GeneratedCode::write_all_concatenated`].
+    ///
+    /// This can only be called from a build script, since Cargo only sets `OUT_DIR` when it runs
+    /// one.
+    pub fn write_all_concatenated_to_out_dir(&self, crate_name: &str) {
+        let out_dir = match std::env::var("OUT_DIR") {
+            Ok(out_dir) => out_dir,
+            Err(_) => panic!("OUT_DIR is only set when running a build script."),
+        };
 
-        for gen in &self.generated {
-            swift += &gen.swift;
-        }
+        self.write_all_concatenated(out_dir, crate_name);
+    }
 
-        swift
+    /// Concatenate all of the generated Swift code into one file.
+    pub fn concat_swift(&self) -> String {
+        self.swift.clone()
     }
 
     /// Concatenate all of the generated C code into one file.
     pub fn concat_c(&self) -> String {
-        let mut c_header = "".to_string();
+        self.c_header.clone()
+    }
 
-        for gen in &self.generated {
-            c_header += &gen.c_header;
-        }
+    /// Whether or not any of the bridge modules use a `Uuid`, in which case the generated C refers
+    /// to its FFI representation.
+    fn uses_uuid(&self) -> bool {
+        self.c_header.contains("__private__FfiUuid")
+    }
 
-        c_header
+    fn extra_swift_imports(&self) -> String {
+        self.swift_imports
+            .iter()
+            .map(|module_name| format!("import {module_name}\n"))
+            .collect()
     }
 }
 
-fn parse_file_contents(file: &str) -> syn::Result<GeneratedFromSwiftBridgeModule> {
+/// Write a generated file, unless it already holds the same contents.
+///
+/// Leaving unchanged files alone keeps their modification times, so that tools such as Xcode don't
+/// recompile everything that depends on the generated code after every build.
+fn write_if_changed(path: impl AsRef<Path>, contents: &str) {
+    let path = path.as_ref();
+
+    if let Ok(existing) = std::fs::read(path) {
+        if existing == contents.as_bytes() {
+            trace(|| format!("unchanged {:?}", path));
+            return;
+        }
+    }
+
+    std::fs::write(path, contents).unwrap();
+    trace(|| format!("wrote {:?}", path));
+}
+
+/// `my-crate` -> `my_crate`
+///
+/// Clang module names must be identifiers, while crate names can contain dashes.
+fn clang_module_name(crate_name: &str) -> String {
+    crate_name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+/// A module map that turns the crate's bridging header into the umbrella header of a Clang module
+/// that is named after the crate, so that Swift code can `import my_crate` instead of needing to
+/// be configured to use the bridging header.
+///
+/// The `SwiftBridgeCore` module that the bridging header includes is re-exported.
+fn module_map(module_name: &str) -> String {
+    format!(
+        r#"module {module_name} {{
+    umbrella header "BridgingHeader.h"
+    export *
+}}
+"#
+    )
+}
+
+/// Import a Clang module into a generated Swift file when the module is on the import path.
+///
+/// Swift code that is compiled with a bridging header instead can already see the C declarations,
+/// so the import is skipped.
+fn import_if_available(module_name: &str) -> String {
+    format!("#if canImport({module_name})\nimport {module_name}\n#endif\n")
+}
+
+/// A header that includes both the `SwiftBridgeCore.h` header and the crate's generated header,
+/// so that it can be used as a Swift bridging header without declaring anything by hand.
+fn bridging_header(crate_name: &str) -> String {
+    let include_guard = format!(
+        "{}_BRIDGING_HEADER_H",
+        clang_module_name(crate_name).to_uppercase()
+    );
+
+    format!(
+        r#"// File automatically generated by swift-bridge.
+#ifndef {include_guard}
+#define {include_guard}
+
+#include "../SwiftBridgeCore.h"
+#include "{crate_name}.h"
+
+#endif /* {include_guard} */
+"#
+    )
+}
+
+/// Find all of the `#\[swift_bridge::bridge\]` modules in a file, including the ones that are
+/// nested within other inline modules.
+fn parse_file_contents(file: &str) -> syn::Result<Vec<ItemMod>> {
     let file: File = syn::parse_str(file)?;
 
-    let mut generated = GeneratedFromSwiftBridgeModule {
-        c_header: "".to_string(),
-        swift: "".to_string(),
-    };
+    let mut modules = vec![];
+    parse_bridge_modules(file.items, &mut modules)?;
+
+    Ok(modules)
+}
+
+static BRIDGE_MACROS: Mutex<Vec<String>> = Mutex::new(Vec::new());
 
-    for item in file.items {
+/// Also parse the modules that are annotated with the given attribute macro, such as
+/// `my_bridge::bridge`, in addition to the ones annotated with `#[swift_bridge::bridge]`.
+///
+/// This is meant for crates that wrap `swift_bridge_ir::expand_bridge_macro` in their own macro
+/// in order to use [`CustomTypeBridge`]s.
+pub fn register_bridge_macro(path: &str) {
+    BRIDGE_MACROS
+        .lock()
+        .unwrap()
+        .push(path.chars().filter(|c| !c.is_whitespace()).collect());
+}
+
+fn is_bridge_macro(path: &syn::Path) -> bool {
+    let path: String = path
+        .to_token_stream()
+        .to_string()
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect();
+
+    // `bridge` on its own is `use swift_bridge::bridge` followed by `#[bridge]`.
+    path == "swift_bridge::bridge"
+        || path == "swift_bridge_macro::bridge"
+        || path == "bridge"
+        || BRIDGE_MACROS.lock().unwrap().contains(&path)
+}
+
+fn parse_bridge_modules(items: Vec<Item>, modules: &mut Vec<ItemMod>) -> syn::Result<()> {
+    for item in items {
         match item {
             Item::Mod(module) => {
                 // TODO: Move this check into the `impl Parse for SwiftBridgeModule`.. Modify our
                 //  tests in swift-bridge-ir to annotate modules with `#[swift_bridge::bridge]`
-                if module.attrs.iter().any(|a| {
-                    let attrib = a.path.to_token_stream().to_string();
-                    attrib == "swift_bridge :: bridge" || attrib == "swift_bridge_macro :: bridge"
-                }) {
-                    let module: SwiftBridgeModule = syn::parse2(module.to_token_stream())?;
-
-                    let config = CodegenConfig {
-                        crate_feature_lookup: Box::new(|feature_name| {
-                            let normalized_feature_name = feature_name.replace("-", "_");
-                            let normalized_feature_name = normalized_feature_name.to_uppercase();
-
-                            let env_var_name = format!("CARGO_FEATURE_{}", normalized_feature_name);
-                            std::env::var(env_var_name).is_ok()
-                        }),
-                    };
-                    let swift_and_c = module.generate_swift_code_and_c_header(config);
-
-                    generated.c_header += &swift_and_c.c_header;
-                    generated.c_header += "\n\n";
-
-                    let swift = &swift_and_c.swift;
-                    generated.swift += &swift;
-                    generated.swift += "\n\n";
+                if module.attrs.iter().any(|a| is_bridge_macro(&a.path)) {
+                    modules.push(module);
+                } else if let Some((_, items)) = module.content {
+                    parse_bridge_modules(items, modules)?;
                 }
             }
             _ => {}
         }
     }
 
-    Ok(generated)
+    Ok(())
 }
 
-#[derive(Debug)]
-struct GeneratedFromSwiftBridgeModule {
-    c_header: String,
-    swift: String,
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, SystemTime};
+
+    /// Verify that we find the modules that use a bridge macro that was imported with
+    /// `use swift_bridge::bridge`.
+    #[test]
+    fn parses_modules_of_imported_bridge_macro() {
+        let file = r#"
+use swift_bridge::bridge;
+
+#[bridge(rename_functions = "camelCase")]
+mod ffi {
+    extern "Rust" {
+        fn some_function();
+    }
+}
+"#;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("lib.rs");
+        std::fs::write(&path, file).unwrap();
+
+        let generated = parse_bridges([&path]);
+        assert!(generated.swift.contains("func someFunction()"));
+    }
+
+    /// Verify that we leave a file that already holds the generated contents alone, so that its
+    /// modification time doesn't change.
+    #[test]
+    fn only_writes_changed_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("generated.swift");
+
+        write_if_changed(&path, "a");
+
+        let an_hour_ago = SystemTime::now() - Duration::from_secs(60 * 60);
+        std::fs::File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(an_hour_ago)
+            .unwrap();
+
+        write_if_changed(&path, "a");
+        assert_eq!(
+            std::fs::metadata(&path).unwrap().modified().unwrap(),
+            an_hour_ago
+        );
+
+        write_if_changed(&path, "b");
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "b");
+        assert_ne!(
+            std::fs::metadata(&path).unwrap().modified().unwrap(),
+            an_hour_ago
+        );
+    }
 }
@@ -18,6 +18,9 @@ pub struct CreatePackageConfig {
     pub out_dir: PathBuf,
     /// The name for the Swift package
     pub package_name: String,
+    /// The system frameworks and libraries that the Rust library needs to be linked against,
+    /// such as the `Security` framework.
+    pub linker_settings: Vec<LinkerSetting>,
 }
 
 impl CreatePackageConfig {
@@ -33,6 +36,28 @@ impl CreatePackageConfig {
             paths,
             out_dir,
             package_name,
+            linker_settings: vec![],
+        }
+    }
+}
+
+/// A framework or library that the Swift Package's target gets linked against.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinkerSetting {
+    /// A system framework, such as `Security`.
+    /// `.linkedFramework("Security")`
+    Framework(String),
+    /// A system library, such as `resolv` for `libresolv`.
+    /// `.linkedLibrary("resolv")`
+    Library(String),
+}
+
+impl LinkerSetting {
+    /// The setting as it is written in a `Package.swift`, such as `.linkedFramework("Security")`.
+    fn to_package_swift(&self) -> String {
+        match self {
+            LinkerSetting::Framework(name) => format!(r#".linkedFramework("{}")"#, name),
+            LinkerSetting::Library(name) => format!(r#".linkedLibrary("{}")"#, name),
         }
     }
 }
@@ -48,15 +73,22 @@ pub enum ApplePlatform {
     /// iOS simulator for debugging in XCode's simulator.
     Simulator,
     /// `x86_64-apple-darwin`
+    /// `aarch64-apple-darwin`
     MacOS,
-    /// no official Rust target for this platform
+    /// `x86_64-apple-ios-macabi`
+    /// `aarch64-apple-ios-macabi`
     MacCatalyst,
     /// `aarch64-apple-tvos`
-    /// `x86_64-apple-tvos`
     TvOS,
-    /// no official Rust target for this platform
+    /// `x86_64-apple-tvos`
+    /// `aarch64-apple-tvos-sim`
+    TvOSSimulator,
+    /// `aarch64-apple-watchos`
+    /// `arm64_32-apple-watchos`
+    /// `armv7k-apple-watchos`
     WatchOS,
-    /// no official Rust target for this platform
+    /// `x86_64-apple-watchos-sim`
+    /// `aarch64-apple-watchos-sim`
     WatchOSSimulator,
     /// no official Rust target for this platform
     CarPlayOS,
@@ -73,6 +105,7 @@ impl ApplePlatform {
             ApplePlatform::MacOS => "macos",
             ApplePlatform::MacCatalyst => "mac-catalyst",
             ApplePlatform::TvOS => "tvos",
+            ApplePlatform::TvOSSimulator => "tvos-simulator",
             ApplePlatform::WatchOS => "watchos",
             ApplePlatform::WatchOSSimulator => "watchos-simulator",
             ApplePlatform::CarPlayOS => "carplay",
@@ -80,6 +113,58 @@ impl ApplePlatform {
         }
     }
 
+    /// The Rust target triples that build a library for the platform.
+    ///
+    /// A platform with more than one target triple has a library for each of its architectures,
+    /// which can be combined into one with [`create_universal_library`].
+    pub fn target_triples(&self) -> &'static [&'static str] {
+        match self {
+            ApplePlatform::IOS => &["aarch64-apple-ios"],
+            ApplePlatform::Simulator => &["aarch64-apple-ios-sim", "x86_64-apple-ios"],
+            ApplePlatform::MacOS => &["aarch64-apple-darwin", "x86_64-apple-darwin"],
+            ApplePlatform::MacCatalyst => &["aarch64-apple-ios-macabi", "x86_64-apple-ios-macabi"],
+            ApplePlatform::TvOS => &["aarch64-apple-tvos"],
+            ApplePlatform::TvOSSimulator => &["aarch64-apple-tvos-sim", "x86_64-apple-tvos"],
+            ApplePlatform::WatchOS => &[
+                "aarch64-apple-watchos",
+                "arm64_32-apple-watchos",
+                "armv7k-apple-watchos",
+            ],
+            ApplePlatform::WatchOSSimulator => {
+                &["aarch64-apple-watchos-sim", "x86_64-apple-watchos-sim"]
+            }
+            ApplePlatform::CarPlayOS => &[],
+            ApplePlatform::CarPlayOSSimulator => &[],
+        }
+    }
+
+    /// The platform that a Rust target triple builds a library for, such as
+    /// `ApplePlatform::TvOSSimulator` for `aarch64-apple-tvos-sim`.
+    pub fn from_target_triple(target_triple: &str) -> Option<Self> {
+        ApplePlatform::ALL
+            .iter()
+            .find(|platform| platform.target_triples().contains(&target_triple))
+            .copied()
+    }
+
+    /// The name of the SDK that Xcode builds the platform with, such as `appletvsimulator`.
+    ///
+    /// Mac Catalyst apps are built with the `macosx` SDK.
+    pub fn sdk_name(&self) -> &str {
+        match self {
+            ApplePlatform::IOS => "iphoneos",
+            ApplePlatform::Simulator => "iphonesimulator",
+            ApplePlatform::MacOS => "macosx",
+            ApplePlatform::MacCatalyst => "macosx",
+            ApplePlatform::TvOS => "appletvos",
+            ApplePlatform::TvOSSimulator => "appletvsimulator",
+            ApplePlatform::WatchOS => "watchos",
+            ApplePlatform::WatchOSSimulator => "watchsimulator",
+            ApplePlatform::CarPlayOS => "iphoneos",
+            ApplePlatform::CarPlayOSSimulator => "iphonesimulator",
+        }
+    }
+
     /// Array containing all `ApplePlatform` variants
     pub const ALL: &'static [Self] = &[
         ApplePlatform::IOS,
@@ -87,6 +172,7 @@ impl ApplePlatform {
         ApplePlatform::MacOS,
         ApplePlatform::MacCatalyst,
         ApplePlatform::TvOS,
+        ApplePlatform::TvOSSimulator,
         ApplePlatform::WatchOS,
         ApplePlatform::WatchOSSimulator,
         ApplePlatform::CarPlayOS,
@@ -100,19 +186,93 @@ impl ApplePlatform {
 pub fn create_package(config: CreatePackageConfig) {
     // Create output directory //
     let output_dir: &Path = config.out_dir.as_ref();
-    if !&output_dir.exists() {
-        fs::create_dir_all(&output_dir).expect("Couldn't create output directory");
+    if !output_dir.exists() {
+        fs::create_dir_all(output_dir).expect("Couldn't create output directory");
     }
 
     // Generate RustXcframework //
-    gen_xcframework(&output_dir, &config);
+    gen_xcframework(output_dir, &config.bridge_dir, &config.paths);
 
     // Generate Swift Package //
-    gen_package(&output_dir, &config);
+    gen_package(output_dir, &config);
+}
+
+/// Config for generating an xcframework
+pub struct CreateXcframeworkConfig {
+    /// The directory containing the generated bridges
+    pub bridge_dir: PathBuf,
+    /// The static libraries per platform. When a platform has more than one library, such as an
+    /// `aarch64-apple-ios-sim` and an `x86_64-apple-ios` library for the iOS Simulator, they get
+    /// combined into one universal library.
+    pub libraries: HashMap<ApplePlatform, Vec<PathBuf>>,
+    /// The directory where the `RustXcframework.xcframework` will be saved
+    pub out_dir: PathBuf,
+}
+
+/// Generates a `RustXcframework.xcframework` from the Rust project's static libraries and
+/// generated bridges.
+pub fn create_xcframework(config: CreateXcframeworkConfig) {
+    let output_dir: &Path = config.out_dir.as_ref();
+    if !output_dir.exists() {
+        fs::create_dir_all(output_dir).expect("Couldn't create output directory");
+    }
+
+    let temp_dir = tempdir().expect("Couldn't create temporary directory");
+
+    let mut paths = HashMap::new();
+    for (platform, libraries) in &config.libraries {
+        let path = match libraries.as_slice() {
+            [] => panic!("No libraries given for platform {:?}", platform),
+            [library] => library.clone(),
+            libraries => {
+                let universal_dir = temp_dir.path().join(platform.dir_name());
+                fs::create_dir(&universal_dir).unwrap_or_else(|_| {
+                    panic!(
+                        "Couldn't create directory for the universal library of {:?}",
+                        platform
+                    )
+                });
+
+                let universal_library = universal_dir.join(libraries[0].file_name().unwrap());
+                create_universal_library(libraries, &universal_library);
+                universal_library
+            }
+        };
+        paths.insert(*platform, path);
+    }
+
+    gen_xcframework(output_dir, &config.bridge_dir, &paths);
+}
+
+/// Combines static libraries that were built for different architectures of the same platform
+/// into one universal library, using `lipo`.
+///
+/// For example, a library built for `aarch64-apple-ios-sim` and one built for `x86_64-apple-ios`
+/// can be combined into one library for the iOS Simulator.
+pub fn create_universal_library(libraries: &[PathBuf], universal_library: &Path) {
+    let mut args: Vec<String> = Vec::new();
+    args.push("-create".to_string());
+    for library in libraries {
+        args.push(library.to_str().unwrap().to_string());
+    }
+    args.push("-output".to_string());
+    args.push(universal_library.to_str().unwrap().to_string());
+
+    let output = Command::new("lipo")
+        .args(args)
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("Failed to spawn lipo")
+        .wait_with_output()
+        .expect("Failed to execute lipo");
+    if !output.status.success() {
+        let stderr = std::str::from_utf8(&output.stderr).unwrap();
+        panic!("{}", stderr);
+    }
 }
 
 /// Generates the RustXcframework
-fn gen_xcframework(output_dir: &Path, config: &CreatePackageConfig) {
+fn gen_xcframework(output_dir: &Path, bridge_dir: &Path, paths: &HashMap<ApplePlatform, PathBuf>) {
     // Create directories
     let temp_dir = tempdir().expect("Couldn't create temporary directory");
     let tmp_framework_path = &temp_dir.path().join("swiftbridge._tmp_framework");
@@ -137,7 +297,6 @@ fn gen_xcframework(output_dir: &Path, config: &CreatePackageConfig) {
         .expect("Couldn't open modulemap file for writing");
 
     // copy headers
-    let bridge_dir: &Path = config.bridge_dir.as_ref();
     fs::copy(
         bridge_dir.join("SwiftBridgeCore.h"),
         &include_dir.join("SwiftBridgeCore.h"),
@@ -158,7 +317,8 @@ fn gen_xcframework(output_dir: &Path, config: &CreatePackageConfig) {
         .expect("Couldn't read generated directory")
         .find_map(|file| {
             let file = file.unwrap().path();
-            if file.extension().unwrap() == "h" {
+            // The bridging header only includes the project's header and the core header.
+            if file.extension().unwrap() == "h" && file.file_name().unwrap() != "BridgingHeader.h" {
                 Some(file)
             } else {
                 None
@@ -183,20 +343,17 @@ fn gen_xcframework(output_dir: &Path, config: &CreatePackageConfig) {
     writeln!(modulemap_file, "    export *\n}}").expect("Couldn't write to modulemap");
 
     // Copy libraries
-    for platform in &config.paths {
+    for platform in paths {
         let platform_path = &tmp_framework_path.join(platform.0.dir_name());
         if !platform_path.exists() {
-            fs::create_dir(&platform_path).expect(&format!(
-                "Couldn't create directory for target {:?}",
-                platform.0
-            ));
+            fs::create_dir(platform_path).unwrap_or_else(|_| {
+                panic!("Couldn't create directory for target {:?}", platform.0)
+            });
         }
 
         let lib_path: &Path = platform.1.as_ref();
-        fs::copy(lib_path, platform_path.join(lib_path.file_name().unwrap())).expect(&format!(
-            "Couldn't copy library for platform {:?}",
-            platform.0
-        ));
+        fs::copy(lib_path, platform_path.join(lib_path.file_name().unwrap()))
+            .unwrap_or_else(|_| panic!("Couldn't copy library for platform {:?}", platform.0));
     }
 
     // build xcframework
@@ -208,7 +365,7 @@ fn gen_xcframework(output_dir: &Path, config: &CreatePackageConfig) {
 
     let mut args: Vec<String> = Vec::new();
     args.push("-create-xcframework".to_string());
-    for platform in &config.paths {
+    for platform in paths {
         let file_path = Path::new(platform.0.dir_name())
             .join((platform.1.as_ref() as &Path).file_name().unwrap());
 
@@ -311,6 +468,16 @@ fn gen_package(output_dir: &Path, config: &CreatePackageConfig) {
 
     // Generate Package.swift
     let package_name = &config.package_name;
+    let maybe_linker_settings = if config.linker_settings.is_empty() {
+        "".to_string()
+    } else {
+        let settings: Vec<String> = config
+            .linker_settings
+            .iter()
+            .map(|setting| setting.to_package_swift())
+            .collect();
+        format!(",\n\t\t\tlinkerSettings: [{}]", settings.join(", "))
+    };
     let package_swift = format!(
         r#"// swift-tools-version:5.5.0
 import PackageDescription
@@ -329,7 +496,7 @@ let package = Package(
 		),
 		.target(
 			name: "{package_name}",
-			dependencies: ["RustXcframework"])
+			dependencies: ["RustXcframework"]{maybe_linker_settings})
 	]
 )
 	"#
@@ -0,0 +1,194 @@
+//! Generate the files that are needed to build a Rust crate from an Xcode project
+
+use crate::ApplePlatform;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Config for generating the build script and xcconfig for an Xcode project
+pub struct XcodeBuildPhaseConfig {
+    /// The name of the Rust crate, such as `my-rust-lib`.
+    pub crate_name: String,
+    /// The path to the crate's `Cargo.toml`, relative to the Xcode project's directory, such as
+    /// `../Cargo.toml`.
+    pub manifest_path: PathBuf,
+    /// The directory that the crate's build script writes the generated Swift and C code to,
+    /// relative to the Xcode project's directory, such as `Generated`.
+    pub generated_dir: PathBuf,
+    /// The directory where the `build-rust.sh` and `SwiftBridge.xcconfig` will be saved
+    pub out_dir: PathBuf,
+}
+
+/// Generates a `build-rust.sh` to call from an Xcode run script build phase, along with a
+/// `SwiftBridge.xcconfig` holding the build settings that the Xcode target needs in order to use
+/// the generated code and link the Rust library.
+pub fn create_xcode_build_phase(config: XcodeBuildPhaseConfig) {
+    let out_dir: &Path = config.out_dir.as_ref();
+    if !out_dir.exists() {
+        fs::create_dir_all(out_dir).expect("Couldn't create output directory");
+    }
+
+    let build_script_path = out_dir.join("build-rust.sh");
+    fs::write(&build_script_path, xcode_build_script(&config))
+        .expect("Couldn't write build-rust.sh");
+    make_executable(&build_script_path);
+
+    fs::write(
+        out_dir.join("SwiftBridge.xcconfig"),
+        xcode_xcconfig(&config),
+    )
+    .expect("Couldn't write SwiftBridge.xcconfig");
+}
+
+/// A bash script that builds the crate for every architecture of the platform that Xcode is
+/// building for, then combines the static libraries into one universal library using `lipo`.
+///
+/// `SWIFT_BRIDGE_OUT_DIR` is exported for the crate's build script to write the generated code
+/// to, and `SWIFT_BRIDGE_LIB_DIR` is where the universal library is saved. Both default to paths
+/// next to the generated code, unless they are set by the `SwiftBridge.xcconfig`.
+pub fn xcode_build_script(config: &XcodeBuildPhaseConfig) -> String {
+    BUILD_SCRIPT_TEMPLATE
+        .replace("{manifest_path}", config.manifest_path.to_str().unwrap())
+        .replace("{generated_dir}", config.generated_dir.to_str().unwrap())
+        .replace("{library_name}", &library_name(&config.crate_name))
+        .replace("{target_cases}", &target_cases())
+}
+
+/// The build settings for an Xcode target that uses the Rust crate.
+///
+/// The generated code gets imported as a Clang module, so that no bridging header is needed, and
+/// the universal library that the `build-rust.sh` saves gets linked.
+pub fn xcode_xcconfig(config: &XcodeBuildPhaseConfig) -> String {
+    let generated_dir = config.generated_dir.to_str().unwrap();
+    let link_name = config.crate_name.replace('-', "_");
+
+    format!(
+        r#"// Generated by swift-bridge. Set this as your target's base configuration.
+
+SWIFT_BRIDGE_OUT_DIR = $(PROJECT_DIR)/{generated_dir}
+SWIFT_BRIDGE_LIB_DIR = $(SWIFT_BRIDGE_OUT_DIR)/lib/$(CONFIGURATION)$(EFFECTIVE_PLATFORM_NAME)
+
+SWIFT_INCLUDE_PATHS = $(inherited) $(SWIFT_BRIDGE_OUT_DIR) $(SWIFT_BRIDGE_OUT_DIR)/{crate_name}
+LIBRARY_SEARCH_PATHS = $(inherited) $(SWIFT_BRIDGE_LIB_DIR)
+OTHER_LDFLAGS = $(inherited) -l{link_name}
+"#,
+        crate_name = config.crate_name,
+    )
+}
+
+/// The `case` arms that pick the Rust target triple for each of the platform and architecture
+/// pairs that Xcode builds for, such as `appletvsimulator:arm64) TARGETS+=("aarch64-apple-tvos-sim") ;;`.
+fn target_cases() -> String {
+    let mut cases = String::new();
+
+    for platform in ApplePlatform::ALL {
+        // Xcode builds Mac Catalyst apps with the `macosx` SDK, so the build script tells them
+        // apart using the `EFFECTIVE_PLATFORM_NAME`.
+        let platform_name = match platform {
+            ApplePlatform::MacCatalyst => "maccatalyst",
+            _ => platform.sdk_name(),
+        };
+
+        for target_triple in platform.target_triples() {
+            cases += &format!(
+                "        {}:{}) TARGETS+=(\"{}\") ;;\n",
+                platform_name,
+                xcode_arch(target_triple),
+                target_triple
+            );
+        }
+    }
+
+    cases
+}
+
+/// `aarch64-apple-ios-sim` -> `arm64`, `x86_64-apple-darwin` -> `x86_64`
+fn xcode_arch(target_triple: &str) -> &str {
+    match target_triple.split('-').next().unwrap() {
+        "aarch64" => "arm64",
+        arch => arch,
+    }
+}
+
+/// `my-rust-lib` -> `libmy_rust_lib.a`
+fn library_name(crate_name: &str) -> String {
+    format!("lib{}.a", crate_name.replace('-', "_"))
+}
+
+#[cfg(unix)]
+fn make_executable(path: &Path) {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mut permissions = fs::metadata(path)
+        .expect("Couldn't read build-rust.sh permissions")
+        .permissions();
+    permissions.set_mode(0o755);
+    fs::set_permissions(path, permissions).expect("Couldn't make build-rust.sh executable");
+}
+
+#[cfg(not(unix))]
+fn make_executable(_path: &Path) {}
+
+const BUILD_SCRIPT_TEMPLATE: &str = r#"#!/bin/bash
+
+##################################################
+# Generated by swift-bridge.
+# We call this from an Xcode run script.
+##################################################
+
+set -e
+
+if [[ -z "$PROJECT_DIR" ]]; then
+    echo "Must provide PROJECT_DIR environment variable set to the Xcode project directory." 1>&2
+    exit 1
+fi
+
+export PATH="$HOME/.cargo/bin:$PATH"
+
+# Without this we can't compile on MacOS Big Sur
+# https://github.com/TimNN/cargo-lipo/issues/41#issuecomment-774793892
+if [[ -n "${DEVELOPER_SDK_DIR:-}" ]]; then
+  export LIBRARY_PATH="${DEVELOPER_SDK_DIR}/MacOSX.sdk/usr/lib:${LIBRARY_PATH:-}"
+fi
+
+MANIFEST_PATH="$PROJECT_DIR/{manifest_path}"
+TARGET_DIR="${CARGO_TARGET_DIR:-$(dirname "$MANIFEST_PATH")/target}"
+
+# The crate's build script writes the generated Swift and C code here.
+export SWIFT_BRIDGE_OUT_DIR="${SWIFT_BRIDGE_OUT_DIR:-$PROJECT_DIR/{generated_dir}}"
+SWIFT_BRIDGE_LIB_DIR="${SWIFT_BRIDGE_LIB_DIR:-$SWIFT_BRIDGE_OUT_DIR/lib/$CONFIGURATION${EFFECTIVE_PLATFORM_NAME:-}}"
+
+if [[ $CONFIGURATION == "Release" ]]; then
+    PROFILE="release"
+    CARGO_FLAGS="--release"
+else
+    PROFILE="debug"
+    CARGO_FLAGS=""
+fi
+
+if [[ "${EFFECTIVE_PLATFORM_NAME:-}" == "-maccatalyst" ]]; then
+    PLATFORM="maccatalyst"
+else
+    PLATFORM="$PLATFORM_NAME"
+fi
+
+TARGETS=()
+for ARCH in $ARCHS; do
+    case "$PLATFORM:$ARCH" in
+{target_cases}        *)
+            echo "Unsupported platform and architecture: $PLATFORM $ARCH" 1>&2
+            exit 1
+            ;;
+    esac
+done
+
+LIBRARIES=()
+for TARGET in "${TARGETS[@]}"; do
+    echo "BUILDING FOR $PROFILE ($TARGET)"
+
+    cargo build $CARGO_FLAGS --manifest-path "$MANIFEST_PATH" --target "$TARGET"
+    LIBRARIES+=("$TARGET_DIR/$TARGET/$PROFILE/{library_name}")
+done
+
+mkdir -p "$SWIFT_BRIDGE_LIB_DIR"
+lipo -create "${LIBRARIES[@]}" -output "$SWIFT_BRIDGE_LIB_DIR/{library_name}"
+"#;
@@ -1,4 +1,4 @@
-use clap::{Arg, Command};
+use clap::{Arg, ArgGroup, Command};
 
 /// The CLI application
 pub fn cli() -> Command<'static> {
@@ -6,7 +6,86 @@ pub fn cli() -> Command<'static> {
         .about("facilitates Rust and Swift interop.")
         .version(env!("CARGO_PKG_VERSION"))
         .subcommand_required(true)
+        .subcommand(parse_bridges_command())
         .subcommand(create_package_command())
+        .subcommand(create_xcframework_command())
+        .subcommand(create_xcode_build_phase_command())
+}
+
+/// The command for generating the Swift and C code for a crate's bridge modules
+fn parse_bridges_command() -> Command<'static> {
+    Command::new("parse-bridges")
+        .about(
+            "Parse Rust files for bridge modules and generate the corresponding Swift and C code.",
+        )
+        .arg(
+            Arg::new("crate-name")
+                .long("crate-name")
+                .takes_value(true)
+                .value_name("NAME")
+                .required(true)
+                .help("The name of the crate, used to name the generated files"),
+        )
+        .arg(
+            Arg::new("file")
+                .long("file")
+                .short('f')
+                .takes_value(true)
+                .multiple_occurrences(true)
+                .value_name("PATH")
+                .help("A Rust source file that contains bridge modules"),
+        )
+        .arg(
+            Arg::new("crate-dir")
+                .long("crate-dir")
+                .takes_value(true)
+                .value_name("PATH")
+                .help("The path to a crate, all of whose Rust files in `src/` get parsed"),
+        )
+        .group(
+            ArgGroup::new("sources")
+                .args(&["file", "crate-dir"])
+                .required(true),
+        )
+        .arg(
+            Arg::new("out-dir")
+                .long("out-dir")
+                .short('o')
+                .takes_value(true)
+                .value_name("PATH")
+                .required(true)
+                .help("The directory that the generated Swift and C code gets written to"),
+        )
+        .arg(
+            Arg::new("features")
+                .long("features")
+                .takes_value(true)
+                .multiple_occurrences(true)
+                .use_value_delimiter(true)
+                .value_name("FEATURES")
+                .help("The crate's enabled features, used for items with a #[cfg(feature = \"...\")]"),
+        )
+        .arg(
+            Arg::new("cfg")
+                .long("cfg")
+                .takes_value(true)
+                .multiple_occurrences(true)
+                .value_name("SPEC")
+                .help("A set cfg option, such as unix or target_os=\"ios\", used for items with a #[cfg(...)]"),
+        )
+        .arg(
+            Arg::new("library-evolution")
+                .long("library-evolution")
+                .help("Generate Swift for a framework that is built with BUILD_LIBRARY_FOR_DISTRIBUTION=YES"),
+        )
+        .arg(
+            Arg::new("swift-import")
+                .long("swift-import")
+                .takes_value(true)
+                .multiple_occurrences(true)
+                .value_name("MODULE")
+                .help("A module to import in the generated Swift files, such as RustXcframework"),
+        )
 }
 
 /// The command for creating a Swift Package
@@ -56,6 +135,13 @@ fn create_package_command() -> Command<'static> {
                 .value_name("PATH")
                 .help("The path to the compiled Rust library for tvOS"),
         )
+        .arg(
+            Arg::new("tvos-simulator")
+                .long("tvos-simulator")
+                .takes_value(true)
+                .value_name("PATH")
+                .help("The path to the compiled Rust library for the tvOS Simulator"),
+        )
         .arg(
             Arg::new("watchos")
                 .long("watchos")
@@ -100,4 +186,160 @@ fn create_package_command() -> Command<'static> {
                 .required(true)
                 .help("The name for the Swift Package"),
         )
+        .arg(
+            Arg::new("link-framework")
+                .long("link-framework")
+                .takes_value(true)
+                .multiple_occurrences(true)
+                .value_name("NAME")
+                .help("A system framework that the Rust library needs to be linked against"),
+        )
+        .arg(
+            Arg::new("link-library")
+                .long("link-library")
+                .takes_value(true)
+                .multiple_occurrences(true)
+                .value_name("NAME")
+                .help("A system library that the Rust library needs to be linked against"),
+        )
+}
+
+/// The command for creating an xcframework
+fn create_xcframework_command() -> Command<'static> {
+    Command::new("create-xcframework")
+        .about("Create an xcframework from Rust code. Libraries that are given for the same platform get combined using lipo.")
+        .arg(
+            Arg::new("bridges-dir")
+                .long("bridges-dir")
+                .takes_value(true)
+                .value_name("PATH")
+                .required(true)
+                .help("The path to the generated bridge files"),
+        )
+        .arg(
+            Arg::new("ios")
+                .long("ios")
+                .takes_value(true)
+                .multiple_occurrences(true)
+                .value_name("PATH")
+                .help("A path to a compiled Rust library for iOS"),
+        )
+        .arg(
+            Arg::new("simulator")
+                .long("simulator")
+                .takes_value(true)
+                .multiple_occurrences(true)
+                .value_name("PATH")
+                .help("A path to a compiled Rust library for the iOS Simulator"),
+        )
+        .arg(
+            Arg::new("macos")
+                .long("macos")
+                .takes_value(true)
+                .multiple_occurrences(true)
+                .value_name("PATH")
+                .help("A path to a compiled Rust library for MacOS"),
+        )
+        .arg(
+            Arg::new("mac-catalyst")
+                .long("mac-catalyst")
+                .takes_value(true)
+                .multiple_occurrences(true)
+                .value_name("PATH")
+                .help("A path to a compiled Rust library for MacCatalyst"),
+        )
+        .arg(
+            Arg::new("tvos")
+                .long("tvos")
+                .takes_value(true)
+                .multiple_occurrences(true)
+                .value_name("PATH")
+                .help("A path to a compiled Rust library for tvOS"),
+        )
+        .arg(
+            Arg::new("tvos-simulator")
+                .long("tvos-simulator")
+                .takes_value(true)
+                .multiple_occurrences(true)
+                .value_name("PATH")
+                .help("A path to a compiled Rust library for the tvOS Simulator"),
+        )
+        .arg(
+            Arg::new("watchos")
+                .long("watchos")
+                .takes_value(true)
+                .multiple_occurrences(true)
+                .value_name("PATH")
+                .help("A path to a compiled Rust library for WatchOS"),
+        )
+        .arg(
+            Arg::new("watchos-simulator")
+                .long("watchos-simulator")
+                .takes_value(true)
+                .multiple_occurrences(true)
+                .value_name("PATH")
+                .help("A path to a compiled Rust library for WatchOSSimulator"),
+        )
+        .arg(
+            Arg::new("carplay")
+                .long("carplay")
+                .takes_value(true)
+                .multiple_occurrences(true)
+                .value_name("PATH")
+                .help("A path to a compiled Rust library for AppleCarplay"),
+        )
+        .arg(
+            Arg::new("carplay-simulator")
+                .long("carplay-simulator")
+                .takes_value(true)
+                .multiple_occurrences(true)
+                .value_name("PATH")
+                .help("A path to a compiled Rust library for AppleCarplaySimulator"),
+        )
+        .arg(
+            Arg::new("out-dir")
+                .long("out-dir")
+                .takes_value(true)
+                .value_name("PATH")
+                .required(true)
+                .help("The directory where the xcframework will be saved"),
+        )
+}
+
+/// The command for creating the files that an Xcode project needs to build a Rust crate
+fn create_xcode_build_phase_command() -> Command<'static> {
+    Command::new("create-xcode-build-phase")
+        .about("Create a build-rust.sh to call from an Xcode run script build phase, along with an xcconfig with the build settings for using the Rust crate.")
+        .arg(
+            Arg::new("crate-name")
+                .long("crate-name")
+                .takes_value(true)
+                .value_name("NAME")
+                .required(true)
+                .help("The name of the Rust crate"),
+        )
+        .arg(
+            Arg::new("manifest-path")
+                .long("manifest-path")
+                .takes_value(true)
+                .value_name("PATH")
+                .default_value("../Cargo.toml")
+                .help("The path to the crate's Cargo.toml, relative to the Xcode project directory"),
+        )
+        .arg(
+            Arg::new("generated-dir")
+                .long("generated-dir")
+                .takes_value(true)
+                .value_name("PATH")
+                .default_value("Generated")
+                .help("The directory that the generated Swift and C code gets written to, relative to the Xcode project directory"),
+        )
+        .arg(
+            Arg::new("out-dir")
+                .long("out-dir")
+                .takes_value(true)
+                .value_name("PATH")
+                .required(true)
+                .help("The directory where the build-rust.sh and SwiftBridge.xcconfig will be saved"),
+        )
 }
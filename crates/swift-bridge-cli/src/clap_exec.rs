@@ -1,18 +1,69 @@
 use clap::ArgMatches;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
-use swift_bridge_build::{create_package, ApplePlatform, CreatePackageConfig};
+use swift_bridge_build::{
+    create_package, create_xcframework, create_xcode_build_phase, parse_bridges_in_glob_with_cfg,
+    parse_bridges_with_cfg, ApplePlatform, CrateCfg, CreatePackageConfig, CreateXcframeworkConfig,
+    LinkerSetting, XcodeBuildPhaseConfig,
+};
 
 /// Executes the correct function depending on the cli input
 pub fn handle_matches(matches: ArgMatches) {
     match matches.subcommand_name() {
+        Some("parse-bridges") => {
+            handle_parse_bridges(matches.subcommand_matches("parse-bridges").unwrap())
+        }
         Some("create-package") => {
             handle_create_package(matches.subcommand_matches("create-package").unwrap())
         }
+        Some("create-xcframework") => {
+            handle_create_xcframework(matches.subcommand_matches("create-xcframework").unwrap())
+        }
+        Some("create-xcode-build-phase") => handle_create_xcode_build_phase(
+            matches
+                .subcommand_matches("create-xcode-build-phase")
+                .unwrap(),
+        ),
         _ => unreachable!("No subcommand or unknown subcommand given"), // Shouldn't happen
     }
 }
 
+/// Executes the `parse-bridges` command
+fn handle_parse_bridges(matches: &ArgMatches) {
+    let crate_name = matches.value_of("crate-name").unwrap(); // required
+    let out_dir = matches.value_of("out-dir").map(|p| Path::new(p)).unwrap(); // required
+
+    let cfg = CrateCfg {
+        features: values_of(matches, "features"),
+        options: values_of(matches, "cfg"),
+        library_evolution: matches.is_present("library-evolution"),
+    };
+
+    let mut generated_code = match matches.value_of("crate-dir") {
+        Some(crate_dir) => {
+            let pattern = Path::new(crate_dir).join("src").join("**").join("*.rs");
+            parse_bridges_in_glob_with_cfg(pattern.to_str().unwrap(), &cfg)
+        }
+        // One of `crate-dir` or `file` is required
+        None => parse_bridges_with_cfg(matches.values_of("file").unwrap(), &cfg),
+    };
+
+    for module_name in values_of(matches, "swift-import") {
+        generated_code.import_swift_module(module_name);
+    }
+
+    generated_code.write_all_concatenated(out_dir, crate_name);
+}
+
+fn values_of(matches: &ArgMatches, name: &str) -> Vec<String> {
+    matches
+        .values_of(name)
+        .into_iter()
+        .flatten()
+        .map(|value| value.to_string())
+        .collect()
+}
+
 /// Executes the `create-package` command
 fn handle_create_package(matches: &ArgMatches) {
     let bridges_dir = matches.value_of("bridges-dir").unwrap(); // required
@@ -24,6 +75,7 @@ fn handle_create_package(matches: &ArgMatches) {
         paths: HashMap::new(),
         out_dir: out_dir.to_path_buf(),
         package_name: name.to_string(),
+        linker_settings: vec![],
     };
 
     for platform in ApplePlatform::ALL {
@@ -32,5 +84,53 @@ fn handle_create_package(matches: &ArgMatches) {
         }
     }
 
+    for framework in matches.values_of("link-framework").into_iter().flatten() {
+        config
+            .linker_settings
+            .push(LinkerSetting::Framework(framework.to_string()));
+    }
+    for library in matches.values_of("link-library").into_iter().flatten() {
+        config
+            .linker_settings
+            .push(LinkerSetting::Library(library.to_string()));
+    }
+
     create_package(config);
 }
+
+/// Executes the `create-xcframework` command
+fn handle_create_xcframework(matches: &ArgMatches) {
+    let bridges_dir = matches.value_of("bridges-dir").unwrap(); // required
+    let out_dir = matches.value_of("out-dir").map(|p| Path::new(p)).unwrap(); // required
+
+    let mut config = CreateXcframeworkConfig {
+        bridge_dir: PathBuf::from(bridges_dir),
+        libraries: HashMap::new(),
+        out_dir: out_dir.to_path_buf(),
+    };
+
+    for platform in ApplePlatform::ALL {
+        if let Some(paths) = matches.values_of(platform.dir_name()) {
+            config
+                .libraries
+                .insert(*platform, paths.map(PathBuf::from).collect());
+        }
+    }
+
+    create_xcframework(config);
+}
+
+/// Executes the `create-xcode-build-phase` command
+fn handle_create_xcode_build_phase(matches: &ArgMatches) {
+    let crate_name = matches.value_of("crate-name").unwrap(); // required
+    let manifest_path = matches.value_of("manifest-path").unwrap(); // has a default
+    let generated_dir = matches.value_of("generated-dir").unwrap(); // has a default
+    let out_dir = matches.value_of("out-dir").unwrap(); // required
+
+    create_xcode_build_phase(XcodeBuildPhaseConfig {
+        crate_name: crate_name.to_string(),
+        manifest_path: PathBuf::from(manifest_path),
+        generated_dir: PathBuf::from(generated_dir),
+        out_dir: PathBuf::from(out_dir),
+    });
+}
@@ -1,6 +1,6 @@
 use proc_macro2::Ident;
 use syn::parse::{Parse, ParseStream};
-use syn::{Path, Token};
+use syn::{LitStr, Path, Token};
 
 /// The `...` in
 /// `#\[swift_bridge::bridge(...)\]`
@@ -15,6 +15,164 @@ pub enum SwiftBridgeModuleAttr {
     /// as `RustString`.
     /// `#\[swift_bridge::bridge(swift_bridge_path = swift_bridge)\]`
     SwiftBridgePath(Path),
+    /// Converts the Swift names of the module's functions to the given style, unless they set
+    /// their own `swift_name`.
+    /// `#\[swift_bridge::bridge(rename_functions = "camelCase")\]`
+    RenameFunctions(FunctionNameStyle),
+    /// Sets the Swift type that path types such as `PathBuf` and `&Path` are seen as.
+    /// `#\[swift_bridge::bridge(swift_path_type = "URL")\]`
+    SwiftPathType(SwiftPathType),
+    /// Sets the access level of the module's generated Swift declarations, unless they set
+    /// their own `swift_access`.
+    /// `#\[swift_bridge::bridge(swift_access = "internal")\]`
+    SwiftAccess(SwiftAccess),
+    /// Emits the module's free Rust functions as static functions of a caseless Swift enum with
+    /// the given name, instead of as global functions.
+    /// `#\[swift_bridge::bridge(swift_namespace = "Ffi")\]`
+    SwiftNamespace(String),
+    /// Adds the given prefix to the names of the module's FFI symbols, so that they do not
+    /// collide with the symbols of another crate's bridge modules when both crates get linked
+    /// into the same binary.
+    /// `#\[swift_bridge::bridge(symbol_prefix = "my_crate")\]`
+    SymbolPrefix(String),
+    /// Sets what happens when one of the module's Rust functions panics.
+    /// `#\[swift_bridge::bridge(panic = "throw")\]`
+    Panic(PanicPolicy),
+}
+
+/// What happens when a Rust function that Swift called panics.
+///
+/// A panic can't unwind across the FFI boundary into Swift.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum PanicPolicy {
+    /// Abort the process, which Rust does for any panic that tries to unwind out of an
+    /// `extern "C"` function.
+    #[default]
+    Abort,
+    /// Catch the panic and call Swift's `fatalError` with the panic's message.
+    FatalError,
+    /// Catch the panic and throw it as a Swift `RustPanic` error from functions that return a
+    /// `Result`. Functions that don't return a `Result` call `fatalError` instead.
+    Throw,
+}
+
+/// A naming style for the Swift side of bridged functions.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum FunctionNameStyle {
+    /// `some_function` becomes `someFunction`.
+    CamelCase,
+}
+
+/// The Swift type that path types such as `PathBuf` and `&Path` are seen as.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum SwiftPathType {
+    /// A `String`, such as "/tmp/file.txt".
+    #[default]
+    String,
+    /// A file `URL`, such as "file:///tmp/file.txt".
+    Url,
+}
+
+/// The access level of generated Swift declarations.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum SwiftAccess {
+    /// `public`
+    #[default]
+    Public,
+    /// `package`
+    Package,
+    /// `internal`
+    Internal,
+}
+
+impl SwiftAccess {
+    /// Parse an access level such as `"internal"`.
+    pub(crate) fn from_lit_str(access: &LitStr) -> syn::Result<Self> {
+        match access.value().as_str() {
+            "public" => Ok(SwiftAccess::Public),
+            "package" => Ok(SwiftAccess::Package),
+            "internal" => Ok(SwiftAccess::Internal),
+            _ => Err(syn::Error::new_spanned(
+                access,
+                r#"Unknown Swift access level. Supported levels: "public", "package", "internal"."#,
+            )),
+        }
+    }
+
+    /// The Swift keyword for this access level, such as `internal`.
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            SwiftAccess::Public => "public",
+            SwiftAccess::Package => "package",
+            SwiftAccess::Internal => "internal",
+        }
+    }
+
+    /// Replace the `public` modifier of each of the generated Swift declarations in `swift` with
+    /// this access level.
+    pub(crate) fn apply(&self, swift: &str) -> String {
+        if *self == SwiftAccess::Public {
+            return swift.to_string();
+        }
+
+        swift
+            .split('\n')
+            .map(|line| {
+                let trimmed = line.trim_start();
+                let indentation = &line[..line.len() - trimmed.len()];
+
+                // Methods on a type can be declared as `class public func` or
+                // `static public func`.
+                for modifier in ["", "class ", "static "] {
+                    if let Some(declaration) = trimmed
+                        .strip_prefix(modifier)
+                        .and_then(|rest| rest.strip_prefix("public "))
+                    {
+                        return format!(
+                            "{}{}{} {}",
+                            indentation,
+                            modifier,
+                            self.as_str(),
+                            declaration
+                        );
+                    }
+                }
+
+                line.to_string()
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+impl FunctionNameStyle {
+    /// Convert a snake_case Rust function name into this style.
+    pub fn apply(&self, name: &str) -> String {
+        match self {
+            FunctionNameStyle::CamelCase => {
+                let mut converted = String::with_capacity(name.len());
+                let mut uppercase_next = false;
+
+                for c in name.chars() {
+                    if c == '_' {
+                        // Leading underscores are kept so that `_private_fn` becomes `_privateFn`.
+                        if converted.chars().all(|c| c == '_') {
+                            converted.push(c);
+                        } else {
+                            uppercase_next = true;
+                        }
+                    } else if uppercase_next {
+                        converted.extend(c.to_uppercase());
+                        uppercase_next = false;
+                    } else {
+                        converted.push(c);
+                    }
+                }
+
+                converted
+            }
+        }
+    }
 }
 
 impl Parse for SwiftBridgeModuleAttrs {
@@ -38,6 +196,76 @@ impl Parse for SwiftBridgeModuleAttr {
 
         let attr = match key.to_string().as_str() {
             "swift_bridge_path" => SwiftBridgeModuleAttr::SwiftBridgePath(input.parse()?),
+            "rename_functions" => {
+                let style: LitStr = input.parse()?;
+
+                match style.value().as_str() {
+                    "camelCase" => {
+                        SwiftBridgeModuleAttr::RenameFunctions(FunctionNameStyle::CamelCase)
+                    }
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            style,
+                            r#"Unknown function name style. Supported styles: "camelCase"."#,
+                        ));
+                    }
+                }
+            }
+            "swift_path_type" => {
+                let ty: LitStr = input.parse()?;
+
+                match ty.value().as_str() {
+                    "String" => SwiftBridgeModuleAttr::SwiftPathType(SwiftPathType::String),
+                    "URL" => SwiftBridgeModuleAttr::SwiftPathType(SwiftPathType::Url),
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            ty,
+                            r#"Unknown Swift path type. Supported types: "String", "URL"."#,
+                        ));
+                    }
+                }
+            }
+            "swift_access" => {
+                let access: LitStr = input.parse()?;
+                SwiftBridgeModuleAttr::SwiftAccess(SwiftAccess::from_lit_str(&access)?)
+            }
+            "swift_namespace" => {
+                let namespace: LitStr = input.parse()?;
+                if syn::parse_str::<Ident>(&namespace.value()).is_err() {
+                    return Err(syn::Error::new_spanned(
+                        namespace,
+                        "The Swift namespace must be a valid identifier.",
+                    ));
+                }
+                SwiftBridgeModuleAttr::SwiftNamespace(namespace.value())
+            }
+            "symbol_prefix" => {
+                let prefix: LitStr = input.parse()?;
+                let value = prefix.value();
+                if value.is_empty() || !value.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+                {
+                    return Err(syn::Error::new_spanned(
+                        prefix,
+                        "The symbol prefix can only contain ASCII letters, digits and underscores.",
+                    ));
+                }
+                SwiftBridgeModuleAttr::SymbolPrefix(value)
+            }
+            "panic" => {
+                let policy: LitStr = input.parse()?;
+
+                match policy.value().as_str() {
+                    "abort" => SwiftBridgeModuleAttr::Panic(PanicPolicy::Abort),
+                    "fatal_error" => SwiftBridgeModuleAttr::Panic(PanicPolicy::FatalError),
+                    "throw" => SwiftBridgeModuleAttr::Panic(PanicPolicy::Throw),
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            policy,
+                            r#"Unknown panic policy. Supported policies: "abort", "fatal_error", "throw"."#,
+                        ));
+                    }
+                }
+            }
             _ => {
                 return Err(syn::Error::new(input.span(), "Unknown attribute."));
             }
@@ -46,3 +274,151 @@ impl Parse for SwiftBridgeModuleAttr {
         Ok(attr)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify that we can parse the `rename_functions` module attribute.
+    #[test]
+    fn parse_rename_functions() {
+        let attrs: SwiftBridgeModuleAttrs = syn::parse_quote!(rename_functions = "camelCase");
+
+        assert!(matches!(
+            attrs.attributes[0],
+            SwiftBridgeModuleAttr::RenameFunctions(FunctionNameStyle::CamelCase)
+        ));
+    }
+
+    /// Verify that we get an error for an unknown function name style.
+    #[test]
+    fn error_unknown_function_name_style() {
+        let attrs: syn::Result<SwiftBridgeModuleAttrs> =
+            syn::parse_str(r#"rename_functions = "kebab-case""#);
+
+        assert!(attrs.is_err());
+    }
+
+    /// Verify that we can parse the `swift_path_type` module attribute.
+    #[test]
+    fn parse_swift_path_type() {
+        let attrs: SwiftBridgeModuleAttrs = syn::parse_quote!(swift_path_type = "URL");
+
+        assert!(matches!(
+            attrs.attributes[0],
+            SwiftBridgeModuleAttr::SwiftPathType(SwiftPathType::Url)
+        ));
+    }
+
+    /// Verify that we get an error for an unknown Swift path type.
+    #[test]
+    fn error_unknown_swift_path_type() {
+        let attrs: syn::Result<SwiftBridgeModuleAttrs> =
+            syn::parse_str(r#"swift_path_type = "NSString""#);
+
+        assert!(attrs.is_err());
+    }
+
+    /// Verify that we can parse the `swift_access` module attribute.
+    #[test]
+    fn parse_swift_access() {
+        let attrs: SwiftBridgeModuleAttrs = syn::parse_quote!(swift_access = "internal");
+
+        assert!(matches!(
+            attrs.attributes[0],
+            SwiftBridgeModuleAttr::SwiftAccess(SwiftAccess::Internal)
+        ));
+    }
+
+    /// Verify that we get an error for an unknown Swift access level.
+    #[test]
+    fn error_unknown_swift_access() {
+        let attrs: syn::Result<SwiftBridgeModuleAttrs> = syn::parse_str(r#"swift_access = "open""#);
+
+        assert!(attrs.is_err());
+    }
+
+    /// Verify that we can parse the `swift_namespace` module attribute.
+    #[test]
+    fn parse_swift_namespace() {
+        let attrs: SwiftBridgeModuleAttrs = syn::parse_quote!(swift_namespace = "Ffi");
+
+        assert!(matches!(
+            &attrs.attributes[0],
+            SwiftBridgeModuleAttr::SwiftNamespace(namespace) if namespace == "Ffi"
+        ));
+    }
+
+    /// Verify that we get an error for a Swift namespace that is not an identifier.
+    #[test]
+    fn error_invalid_swift_namespace() {
+        let attrs: syn::Result<SwiftBridgeModuleAttrs> =
+            syn::parse_str(r#"swift_namespace = "My Ffi""#);
+
+        assert!(attrs.is_err());
+    }
+
+    /// Verify that we can parse the `symbol_prefix` module attribute.
+    #[test]
+    fn parse_symbol_prefix() {
+        let attrs: SwiftBridgeModuleAttrs = syn::parse_quote!(symbol_prefix = "my_crate");
+
+        assert!(matches!(
+            &attrs.attributes[0],
+            SwiftBridgeModuleAttr::SymbolPrefix(prefix) if prefix == "my_crate"
+        ));
+    }
+
+    /// Verify that we get an error for a symbol prefix that can't be part of a C identifier.
+    #[test]
+    fn error_invalid_symbol_prefix() {
+        let attrs: syn::Result<SwiftBridgeModuleAttrs> =
+            syn::parse_str(r#"symbol_prefix = "my-crate""#);
+
+        assert!(attrs.is_err());
+    }
+
+    /// Verify that we can parse the `panic` module attribute.
+    #[test]
+    fn parse_panic() {
+        let attrs: SwiftBridgeModuleAttrs = syn::parse_quote!(panic = "throw");
+
+        assert!(matches!(
+            attrs.attributes[0],
+            SwiftBridgeModuleAttr::Panic(PanicPolicy::Throw)
+        ));
+    }
+
+    /// Verify that we get an error for an unknown panic policy.
+    #[test]
+    fn error_unknown_panic_policy() {
+        let attrs: syn::Result<SwiftBridgeModuleAttrs> = syn::parse_str(r#"panic = "unwind""#);
+
+        assert!(attrs.is_err());
+    }
+
+    /// Verify that we replace the `public` modifier of each declaration, and only at the start of
+    /// a line.
+    #[test]
+    fn apply_swift_access() {
+        let swift =
+            "public class Foo {\n    class public func new() {}\n    let s = \"public \"\n}";
+
+        assert_eq!(
+            SwiftAccess::Internal.apply(swift),
+            "internal class Foo {\n    class internal func new() {}\n    let s = \"public \"\n}"
+        );
+        assert_eq!(SwiftAccess::Public.apply(swift), swift);
+    }
+
+    /// Verify that we convert snake_case names to camelCase.
+    #[test]
+    fn camel_case() {
+        let style = FunctionNameStyle::CamelCase;
+
+        assert_eq!(style.apply("some_function"), "someFunction");
+        assert_eq!(style.apply("a_b_c"), "aBC");
+        assert_eq!(style.apply("already"), "already");
+        assert_eq!(style.apply("_private_fn"), "_privateFn");
+    }
+}
@@ -8,23 +8,59 @@ use quote::{quote, quote_spanned};
 use syn::{FnArg, Pat, PatType, Path, ReturnType, Type};
 
 pub(crate) use self::bridged_opaque_type::OpaqueForeignType;
-use crate::bridged_type::boxed_fn::BridgeableBoxedFnOnce;
+use crate::bridged_type::boxed_fn::{BoxedFnKind, BridgeableBoxedFn};
+use crate::bridged_type::bridgeable_arc::BridgeableArc;
+use crate::bridged_type::bridgeable_array::BridgeableArray;
+use crate::bridged_type::bridgeable_box::BridgeableBox;
+use crate::bridged_type::bridgeable_char::BridgeableChar;
+use crate::bridged_type::bridgeable_cow_str::BridgeableCowStr;
+use crate::bridged_type::bridgeable_custom_type::BridgeableCustomType;
+use crate::bridged_type::bridgeable_duration::BridgeableDuration;
+use crate::bridged_type::bridgeable_extern_c_fn::BridgeableExternCFn;
+use crate::bridged_type::bridgeable_hash_map::BridgeableHashMap;
+use crate::bridged_type::bridgeable_hash_set::BridgeableHashSet;
+use crate::bridged_type::bridgeable_int128::BridgeableInt128;
+use crate::bridged_type::bridgeable_non_zero::BridgeableNonZero;
+use crate::bridged_type::bridgeable_path::BridgeablePath;
 use crate::bridged_type::bridgeable_pointer::{BuiltInPointer, Pointee, PointerKind};
+use crate::bridged_type::bridgeable_range::BridgeableRange;
 use crate::bridged_type::bridgeable_result::BuiltInResult;
 use crate::bridged_type::bridgeable_string::BridgedString;
+use crate::bridged_type::bridgeable_system_time::BridgeableSystemTime;
+use crate::bridged_type::bridgeable_uuid::BridgeableUuid;
+use crate::bridged_type::built_in_primitive::{
+    is_pointer_sized_integer, pointer_sized_integer_width_assertion_c,
+    pointer_sized_integer_width_assertion_rust,
+};
 use crate::bridged_type::built_in_tuple::BuiltInTuple;
 
 use crate::parse::{HostLang, TypeDeclaration, TypeDeclarations};
 
 use self::bridged_option::BridgedOption;
-pub(crate) use self::shared_enum::{DeriveAttrs, EnumVariant, SharedEnum};
+pub(crate) use self::shared_enum::{DeriveAttrs, EnumDiscriminant, EnumVariant, SharedEnum};
 pub(crate) use self::shared_struct::{SharedStruct, StructFields, StructSwiftRepr};
 
 pub(crate) mod boxed_fn;
+mod bridgeable_arc;
+mod bridgeable_array;
+mod bridgeable_box;
+mod bridgeable_char;
+mod bridgeable_cow_str;
+mod bridgeable_custom_type;
+mod bridgeable_duration;
+pub(crate) mod bridgeable_extern_c_fn;
+mod bridgeable_hash_map;
+mod bridgeable_hash_set;
+mod bridgeable_int128;
+mod bridgeable_non_zero;
+mod bridgeable_path;
 mod bridgeable_pointer;
+pub(crate) mod bridgeable_range;
 mod bridgeable_result;
 pub mod bridgeable_str;
 pub mod bridgeable_string;
+mod bridgeable_system_time;
+pub(crate) mod bridgeable_uuid;
 pub mod bridged_opaque_type;
 mod bridged_option;
 mod built_in_primitive;
@@ -110,6 +146,15 @@ pub(crate) trait BridgeableType: Debug {
     /// Some(vec![typedef struct __swift_bridge__$ResultVoidAndTransparentEnum { //... };])
     fn generate_custom_c_ffi_types(&self, types: &TypeDeclarations) -> Option<CFfiStruct>;
 
+    /// Generate the Swift code that the type needs, if any.
+    ///
+    /// # Examples
+    /// String -> None
+    /// HashMap<u32, String> -> Some("extension RustMap where K == UInt32, V == RustString { //... }")
+    fn generate_custom_swift_code(&self, _types: &TypeDeclarations) -> Option<String> {
+        None
+    }
+
     /// Get the Rust representation of this type.
     /// For a string this might be `std::string::String`.
     fn to_rust_type_path(&self, types: &TypeDeclarations) -> TokenStream;
@@ -144,11 +189,7 @@ pub(crate) trait BridgeableType: Debug {
     ) -> TokenStream;
 
     /// Get the FFI compatible Option<Self> representation.
-    fn to_ffi_compatible_option_swift_type(
-        &self,
-        swift_bridge_path: &Path,
-        types: &TypeDeclarations,
-    ) -> String;
+    fn to_ffi_compatible_option_swift_type(&self, types: &TypeDeclarations) -> String;
 
     /// Get the FFI compatible Option<Self> representation.
     fn to_ffi_compatible_option_c_type(&self) -> String;
@@ -323,10 +364,85 @@ pub(crate) fn bridgeable_type_from_token_stream_str(
 ) -> Option<Box<dyn BridgeableType>> {
     // TODO: Try all types before falling back to opaque types below
 
+    // A registered custom type bridge takes precedence over the built-in types.
+    if BridgeableCustomType::can_parse_token_stream_str(tokens) {
+        if let Some(custom) = BridgeableCustomType::parse_token_stream_str(tokens, types) {
+            return Some(Box::new(custom));
+        }
+    }
+
     if BridgedString::can_parse_token_stream_str(tokens) {
         return BridgedString::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
     }
 
+    if BridgeableChar::can_parse_token_stream_str(tokens) {
+        return BridgeableChar::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
+    }
+
+    if BridgeableInt128::can_parse_token_stream_str(tokens) {
+        return BridgeableInt128::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
+    }
+
+    if BridgeableNonZero::can_parse_token_stream_str(tokens) {
+        return BridgeableNonZero::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
+    }
+
+    if BridgeableCowStr::can_parse_token_stream_str(tokens) {
+        return BridgeableCowStr::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
+    }
+
+    // A bridge module's own `type Duration` takes precedence over `std::time::Duration`.
+    if BridgeableDuration::can_parse_token_stream_str(tokens) {
+        if let Some(duration) = BridgeableDuration::parse_token_stream_str(tokens, types) {
+            return Some(Box::new(duration));
+        }
+    }
+
+    // A bridge module's own `type SystemTime` takes precedence over `std::time::SystemTime`.
+    if BridgeableSystemTime::can_parse_token_stream_str(tokens) {
+        if let Some(system_time) = BridgeableSystemTime::parse_token_stream_str(tokens, types) {
+            return Some(Box::new(system_time));
+        }
+    }
+
+    // A bridge module's own `type PathBuf` takes precedence over `std::path::PathBuf`.
+    if BridgeablePath::can_parse_token_stream_str(tokens) {
+        if let Some(path) = BridgeablePath::parse_token_stream_str(tokens, types) {
+            return Some(Box::new(path));
+        }
+    }
+
+    // A bridge module's own `type Uuid` takes precedence over `uuid::Uuid`.
+    if BridgeableUuid::can_parse_token_stream_str(tokens) {
+        if let Some(uuid) = BridgeableUuid::parse_token_stream_str(tokens, types) {
+            return Some(Box::new(uuid));
+        }
+    }
+
+    if BridgeableArc::can_parse_token_stream_str(tokens) {
+        return BridgeableArc::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
+    }
+
+    if BridgeableBox::can_parse_token_stream_str(tokens) {
+        return BridgeableBox::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
+    }
+
+    if BridgeableHashMap::can_parse_token_stream_str(tokens) {
+        return BridgeableHashMap::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
+    }
+
+    if BridgeableHashSet::can_parse_token_stream_str(tokens) {
+        return BridgeableHashSet::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
+    }
+
+    if BridgeableRange::can_parse_token_stream_str(tokens) {
+        return BridgeableRange::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
+    }
+
+    if BridgeableArray::can_parse_token_stream_str(tokens) {
+        return BridgeableArray::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
+    }
+
     OpaqueForeignType::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _)
 }
 
@@ -345,6 +461,8 @@ pub(crate) fn bridgeable_type_from_fn_arg(
 
 // TODO: We're gradually replacing `BridgedType` with `Box<dyn BridgeableType>`.
 //  So continue to move more functionality into that trait.
+// The `span-locations` feature of `proc-macro2` makes the spans that `StdLibType` holds larger.
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug)]
 pub(crate) enum BridgedType {
     StdLib(StdLibType),
@@ -386,7 +504,7 @@ pub(crate) enum StdLibType {
     /// &str
     Str,
     Vec(BuiltInVec),
-    BoxedFnOnce(BridgeableBoxedFnOnce),
+    BoxedFn(BridgeableBoxedFn),
     Option(BridgedOption),
     Result(BuiltInResult),
     Tuple(BuiltInTuple),
@@ -400,12 +518,19 @@ pub(crate) enum TypePosition {
     FnReturn(HostLang),
     SharedStructField,
     SwiftCallsRustAsyncOnCompleteReturnTy,
+    /// A value that Swift code returns to Rust, such as from a bridged trait's protocol
+    /// requirement or from a Swift closure that Rust calls.
+    ///
+    /// This is the same as a `FnArg(HostLang::Rust, _)`, except that strings are returned as native
+    /// Swift `String`s instead of as a generic `IntoRustString`.
+    SwiftReturnsToRust,
 }
 
-/// &[T]
+/// &[T] or &mut [T]
 #[derive(Debug)]
 pub(crate) struct BuiltInRefSlice {
     pub ty: Box<BridgedType>,
+    pub mutable: bool,
 }
 
 /// Vec<T>
@@ -418,6 +543,10 @@ impl BridgedType {
     pub fn is_null(&self) -> bool {
         matches!(self, BridgedType::StdLib(StdLibType::Null))
     }
+
+    pub fn is_boxed_fn(&self) -> bool {
+        matches!(self, BridgedType::StdLib(StdLibType::BoxedFn(_)))
+    }
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -507,6 +636,12 @@ impl BridgeableType for BridgedType {
                 StdLibType::Tuple(ty) => {
                     ty.generate_custom_rust_ffi_types(swift_bridge_path, types)
                 }
+                StdLibType::Option(opt) => opt
+                    .ty
+                    .generate_custom_rust_ffi_types(swift_bridge_path, types),
+                ty if is_pointer_sized_integer(ty) => {
+                    Some(vec![pointer_sized_integer_width_assertion_rust()])
+                }
                 _ => None,
             },
             BridgedType::Foreign(_) => None,
@@ -521,10 +656,24 @@ impl BridgeableType for BridgedType {
             BridgedType::StdLib(ty) => match ty {
                 StdLibType::Result(ty) => ty.generate_custom_c_ffi_types(types),
                 StdLibType::Tuple(ty) => ty.generate_custom_c_ffi_types(types),
+                StdLibType::Option(opt) => opt.ty.generate_custom_c_ffi_types(types),
+                ty if is_pointer_sized_integer(ty) => {
+                    Some(pointer_sized_integer_width_assertion_c())
+                }
                 _ => None,
             },
             BridgedType::Foreign(_) => None,
-            BridgedType::Bridgeable(_) => None,
+            BridgedType::Bridgeable(ty) => ty.generate_custom_c_ffi_types(types),
+        }
+    }
+
+    fn generate_custom_swift_code(&self, types: &TypeDeclarations) -> Option<String> {
+        match self {
+            BridgedType::Bridgeable(ty) => ty.generate_custom_swift_code(types),
+            BridgedType::StdLib(StdLibType::Option(opt)) => {
+                opt.ty.generate_custom_swift_code(types)
+            }
+            _ => None,
         }
     }
 
@@ -560,11 +709,7 @@ impl BridgeableType for BridgedType {
         todo!()
     }
 
-    fn to_ffi_compatible_option_swift_type(
-        &self,
-        _swift_bridge_path: &Path,
-        _types: &TypeDeclarations,
-    ) -> String {
+    fn to_ffi_compatible_option_swift_type(&self, _types: &TypeDeclarations) -> String {
         todo!()
     }
 
@@ -609,12 +754,12 @@ impl BridgeableType for BridgedType {
 
     fn convert_ffi_expression_to_rust_type(
         &self,
-        _expression: &TokenStream,
-        _span: Span,
-        _swift_bridge_path: &Path,
-        _types: &TypeDeclarations,
+        expression: &TokenStream,
+        span: Span,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
     ) -> TokenStream {
-        todo!()
+        self.convert_ffi_expression_to_rust_type(expression, span, swift_bridge_path, types)
     }
 
     fn convert_ffi_option_expression_to_rust_type(&self, _expression: &TokenStream) -> TokenStream {
@@ -728,7 +873,19 @@ impl BridgedType {
                     PointerKind::Mut
                 };
 
-                let ty = if let Some(ty) = Self::new_with_type(&ptr.elem, types) {
+                // A pointer to an opaque type is passed along as is, so Swift sees it as a raw
+                // pointer.
+                let points_to_opaque_type = match ptr.elem.deref() {
+                    Type::Path(path) => matches!(
+                        types.get_with_type_path(path),
+                        Some(TypeDeclaration::Opaque(_))
+                    ),
+                    _ => false,
+                };
+
+                let ty = if let Some(ty) =
+                    Self::new_with_type(&ptr.elem, types).filter(|_| !points_to_opaque_type)
+                {
                     BridgedType::StdLib(StdLibType::Pointer(BuiltInPointer {
                         kind,
                         pointee: Pointee::BuiltIn(Box::new(ty)),
@@ -750,12 +907,18 @@ impl BridgedType {
                         if path == "str" {
                             return Some(BridgedType::StdLib(StdLibType::Str));
                         }
+                        if let Some(path) = BridgeablePath::from_type(ty, types) {
+                            return Some(BridgedType::Bridgeable(Box::new(path)));
+                        }
 
                         None
                     }
                 }
                 Type::Slice(slice) => Self::new_with_type(&slice.elem, types).map(|ty| {
-                    BridgedType::StdLib(StdLibType::RefSlice(BuiltInRefSlice { ty: Box::new(ty) }))
+                    BridgedType::StdLib(StdLibType::RefSlice(BuiltInRefSlice {
+                        ty: Box::new(ty),
+                        mutable: ty_ref.mutability.is_some(),
+                    }))
                 }),
                 _ => None,
             },
@@ -769,6 +932,11 @@ impl BridgedType {
                     )));
                 }
             }
+            Type::Array(_) => {
+                BridgeableArray::from_type(ty, types).map(|a| BridgedType::Bridgeable(Box::new(a)))
+            }
+            Type::BareFn(_) => BridgeableExternCFn::from_type(ty, types)
+                .map(|f| BridgedType::Bridgeable(Box::new(f))),
             _ => None,
         }
     }
@@ -791,8 +959,9 @@ impl BridgedType {
         let tokens = tokens.replace("\n", " ");
         let tokens = tokens.as_str();
         if tokens.starts_with("Vec < ") {
-            let inner = tokens.trim_start_matches("Vec < ");
-            let inner = inner.trim_end_matches(" >");
+            let inner = tokens.strip_prefix("Vec < ")?;
+            let last_bracket = inner.rfind(">")?;
+            let inner = inner[0..last_bracket].trim_end();
 
             let inner = if let Some(declared_ty) = types.get(inner) {
                 declared_ty.to_bridged_type(false, false)
@@ -827,9 +996,9 @@ impl BridgedType {
             return Some(BridgedType::StdLib(StdLibType::Result(
                 BuiltInResult::from_str_tokens(&tokens, types)?,
             )));
-        } else if tokens.starts_with("Box < dyn FnOnce") {
-            return Some(BridgedType::StdLib(StdLibType::BoxedFnOnce(
-                BridgeableBoxedFnOnce::from_str_tokens(&tokens, types)?,
+        } else if BoxedFnKind::from_str_tokens(tokens).is_some() {
+            return Some(BridgedType::StdLib(StdLibType::BoxedFn(
+                BridgeableBoxedFn::from_str_tokens(&tokens, types)?,
             )));
         } else if tokens.starts_with("(") {
             let tuple: Type = syn::parse2(TokenStream::from_str(&tokens).unwrap()).unwrap();
@@ -891,7 +1060,11 @@ impl BridgedType {
                 StdLibType::Pointer(ptr) => ptr.to_rust_type_path(types),
                 StdLibType::RefSlice(ref_slice) => {
                     let ty = ref_slice.ty.to_rust_type_path(types);
-                    quote! { &[#ty]}
+                    if ref_slice.mutable {
+                        quote! { &mut [#ty]}
+                    } else {
+                        quote! { &[#ty]}
+                    }
                 }
                 StdLibType::Str => quote! { &str },
                 StdLibType::Vec(v) => {
@@ -903,7 +1076,7 @@ impl BridgedType {
                     quote! { Option<#ty> }
                 }
                 StdLibType::Result(result) => result.to_rust_type_path(types),
-                StdLibType::BoxedFnOnce(fn_once) => fn_once.to_rust_type_path(types),
+                StdLibType::BoxedFn(fn_once) => fn_once.to_rust_type_path(types),
                 StdLibType::Tuple(tuple) => tuple.to_rust_type_path(types),
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
@@ -984,7 +1157,7 @@ impl BridgedType {
                     }
                     BridgedType::StdLib(stdlib_ty) => match stdlib_ty {
                         StdLibType::Null => {
-                            todo!("Option<()> is not yet supported")
+                            quote! { bool }
                         }
                         StdLibType::U8 => {
                             quote! { #swift_bridge_path::option::OptionU8 }
@@ -1025,8 +1198,8 @@ impl BridgedType {
                         StdLibType::Bool => {
                             quote! { #swift_bridge_path::option::OptionBool }
                         }
-                        StdLibType::Pointer(_) => {
-                            todo!("Option<*const T> and Option<*mut T> are not yet supported")
+                        StdLibType::Pointer(ptr) => {
+                            ptr.to_ffi_compatible_rust_type(swift_bridge_path, types)
                         }
                         StdLibType::RefSlice(_) => {
                             todo!("Option<&[T]> is not yet supported")
@@ -1044,7 +1217,7 @@ impl BridgedType {
                         StdLibType::Result(_) => {
                             todo!("Option<Result<T, E>> is not yet supported")
                         }
-                        StdLibType::BoxedFnOnce(_) => {
+                        StdLibType::BoxedFn(_) => {
                             todo!("Support Box<dyn FnOnce(A, B) -> C>")
                         }
                         StdLibType::Tuple(_) => todo!(),
@@ -1065,7 +1238,7 @@ impl BridgedType {
                 StdLibType::Result(result) => {
                     result.to_ffi_compatible_rust_type(swift_bridge_path, types)
                 }
-                StdLibType::BoxedFnOnce(fn_once) => fn_once.to_ffi_compatible_rust_type(types),
+                StdLibType::BoxedFn(fn_once) => fn_once.to_ffi_compatible_rust_type(types),
                 StdLibType::Tuple(tuple) => {
                     tuple.to_ffi_compatible_rust_type(swift_bridge_path, types)
                 }
@@ -1135,7 +1308,8 @@ impl BridgedType {
                                 "__private__FfiSlice".to_string()
                             } else {
                                 format!(
-                                    "UnsafeBufferPointer<{}>",
+                                    "Unsafe{}BufferPointer<{}>",
+                                    if slice.mutable { "Mutable" } else { "" },
                                     slice.ty.to_swift_type(type_pos, types)
                                 )
                             }
@@ -1147,6 +1321,9 @@ impl BridgedType {
                         TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
                             unimplemented!()
                         }
+                        TypePosition::SwiftReturnsToRust => {
+                            self.to_swift_type(TypePosition::FnArg(HostLang::Rust, 0), types)
+                        }
                     }
                 }
                 StdLibType::Null => "()".to_string(),
@@ -1163,6 +1340,9 @@ impl BridgedType {
                     TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
                         unimplemented!()
                     }
+                    TypePosition::SwiftReturnsToRust => {
+                        self.to_swift_type(TypePosition::FnArg(HostLang::Rust, 0), types)
+                    }
                 },
                 StdLibType::Vec(ty) => match type_pos {
                     TypePosition::FnArg(func_host_lang, _) => {
@@ -1185,7 +1365,7 @@ impl BridgedType {
                 },
                 StdLibType::Option(opt) => opt.to_swift_type(type_pos, types),
                 StdLibType::Result(result) => result.to_swift_type(type_pos, types),
-                StdLibType::BoxedFnOnce(boxed_fn) => boxed_fn.to_swift_type().to_string(),
+                StdLibType::BoxedFn(boxed_fn) => boxed_fn.to_swift_type(type_pos, types),
                 StdLibType::Tuple(tuple) => tuple.to_swift_type(type_pos, types),
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
@@ -1202,6 +1382,9 @@ impl BridgedType {
                     TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
                         shared_struct.ffi_name_string()
                     }
+                    TypePosition::SwiftReturnsToRust => {
+                        self.to_swift_type(TypePosition::FnArg(HostLang::Rust, 0), types)
+                    }
                 }
             }
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Enum(shared_enum))) => {
@@ -1218,6 +1401,9 @@ impl BridgedType {
                     TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
                         unimplemented!()
                     }
+                    TypePosition::SwiftReturnsToRust => {
+                        self.to_swift_type(TypePosition::FnArg(HostLang::Rust, 0), types)
+                    }
                 }
             }
         }
@@ -1257,9 +1443,9 @@ impl BridgedType {
                 StdLibType::Str => "struct RustStr".to_string(),
                 StdLibType::Null => "void".to_string(),
                 StdLibType::Vec(_) => "void*".to_string(),
-                StdLibType::Option(opt) => opt.to_c(),
+                StdLibType::Option(opt) => opt.to_c(types),
                 StdLibType::Result(result) => result.to_c(types).to_string(),
-                StdLibType::BoxedFnOnce(_) => "void*".to_string(),
+                StdLibType::BoxedFn(_) => "void*".to_string(),
                 StdLibType::Tuple(tuple) => tuple.to_c_type(types),
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
@@ -1340,9 +1526,15 @@ impl BridgedType {
                         #expression
                     }
                 }
-                StdLibType::RefSlice(_) => {
-                    quote! {
-                        #swift_bridge_path::FfiSlice::from_slice( #expression )
+                StdLibType::RefSlice(slice) => {
+                    if slice.mutable {
+                        quote! {
+                            #swift_bridge_path::FfiSlice::from_mut_slice( #expression )
+                        }
+                    } else {
+                        quote! {
+                            #swift_bridge_path::FfiSlice::from_slice( #expression )
+                        }
                     }
                 }
                 StdLibType::Str => {
@@ -1362,7 +1554,7 @@ impl BridgedType {
                     types,
                     span,
                 ),
-                StdLibType::BoxedFnOnce(fn_once) => {
+                StdLibType::BoxedFn(fn_once) => {
                     fn_once.convert_rust_value_to_ffi_compatible_value(expression, types)
                 }
                 StdLibType::Tuple(tuple) => tuple.convert_rust_expression_to_ffi_type(
@@ -1428,8 +1620,12 @@ impl BridgedType {
                 StdLibType::Pointer(_) => {
                     quote_spanned! {span=> #value }
                 }
-                StdLibType::RefSlice(_reference) => {
-                    quote_spanned! {span=> #value.as_slice() }
+                StdLibType::RefSlice(slice) => {
+                    if slice.mutable {
+                        quote_spanned! {span=> unsafe { #value.as_mut_slice() } }
+                    } else {
+                        quote_spanned! {span=> #value.as_slice() }
+                    }
                 }
                 StdLibType::Str => {
                     quote_spanned! {span=> #value.to_str() }
@@ -1445,7 +1641,7 @@ impl BridgedType {
                 StdLibType::Result(result) => {
                     result.convert_ffi_value_to_rust_value(value, span, swift_bridge_path, types)
                 }
-                StdLibType::BoxedFnOnce(_) => {
+                StdLibType::BoxedFn(_) => {
                     todo!("Support Box<dyn FnOnce(A, B) -> C>")
                 }
                 StdLibType::Tuple(tuple) => {
@@ -1522,14 +1718,21 @@ impl BridgedType {
                             TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
                                 unimplemented!()
                             }
+                            TypePosition::SwiftReturnsToRust => self
+                                .convert_ffi_value_to_swift_value(
+                                    expression,
+                                    TypePosition::FnArg(HostLang::Rust, 0),
+                                    types,
+                                ),
                         },
                         PointerKind::Mut => expression.to_string(),
                     },
                 },
                 StdLibType::RefSlice(ty) => {
                     format!(
-                        "let slice = {value}; return UnsafeBufferPointer(start: slice.start.assumingMemoryBound(to: {ty}.self), count: Int(slice.len));",
+                        "let slice = {value}; return Unsafe{maybe_mutable}BufferPointer(start: slice.start.assumingMemoryBound(to: {ty}.self), count: Int(slice.len));",
                         value = expression,
+                        maybe_mutable = if ty.mutable { "Mutable" } else { "" },
                         ty = ty.ty.to_swift_type(type_pos,types)
                        )
                 }
@@ -1541,9 +1744,7 @@ impl BridgedType {
                 StdLibType::Result(result) => {
                     result.convert_ffi_value_to_swift_value(expression, type_pos, types)
                 }
-                StdLibType::BoxedFnOnce(fn_once) => {
-                    fn_once.convert_ffi_value_to_swift_value(type_pos)
-                }
+                StdLibType::BoxedFn(fn_once) => fn_once.convert_ffi_value_to_swift_value(type_pos),
                 StdLibType::Tuple(tuple) => {
                     tuple.convert_ffi_expression_to_swift_type(expression, type_pos, types)
                 }
@@ -1609,6 +1810,12 @@ impl BridgedType {
                         TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
                             unimplemented!()
                         }
+                        TypePosition::SwiftReturnsToRust => self
+                            .convert_swift_expression_to_ffi_type(
+                                expression,
+                                types,
+                                TypePosition::FnArg(HostLang::Rust, 0),
+                            ),
                     },
                 },
                 StdLibType::Str => match type_pos {
@@ -1626,6 +1833,11 @@ impl BridgedType {
                     TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
                         unimplemented!()
                     }
+                    TypePosition::SwiftReturnsToRust => self.convert_swift_expression_to_ffi_type(
+                        expression,
+                        types,
+                        TypePosition::FnArg(HostLang::Rust, 0),
+                    ),
                 },
                 StdLibType::Vec(_) => {
                     format!(
@@ -1639,7 +1851,7 @@ impl BridgedType {
                 StdLibType::Result(result) => {
                     result.convert_swift_expression_to_ffi_compatible(expression, types, type_pos)
                 }
-                StdLibType::BoxedFnOnce(_) => {
+                StdLibType::BoxedFn(_) => {
                     todo!("Support Box<dyn FnOnce(A, B) -> C>")
                 }
                 StdLibType::Tuple(tuple) => {
@@ -1665,6 +1877,7 @@ impl BridgedType {
             BridgedType::Bridgeable(b) => {
                 b.convert_ffi_result_ok_value_to_rust_value(ok_ffi_value, swift_bridge_path, types)
             }
+            BridgedType::StdLib(StdLibType::Null) => quote! { () },
             _ => unimplemented!(),
         }
     }
@@ -1681,6 +1894,7 @@ impl BridgedType {
                 swift_bridge_path,
                 types,
             ),
+            BridgedType::StdLib(StdLibType::Null) => quote! { () },
             _ => unimplemented!(),
         }
     }
@@ -1707,6 +1921,7 @@ impl BridgedType {
                 StdLibType::RefSlice(slice) => slice.ty.to_c_include(types),
                 StdLibType::Vec(_vec) => Some(vec!["stdint.h"]),
                 StdLibType::Tuple(tuple) => tuple.to_c_include(types),
+                StdLibType::Option(opt) if opt.ty.is_null() => Some(vec!["stdbool.h"]),
                 _ => None,
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(_shared_struct))) => {
@@ -1751,9 +1966,10 @@ impl BridgedType {
                     rust: quote! { bool },
                     swift: "bool".into(),
                 },
-                StdLibType::Pointer(_) => {
-                    todo!("Support Option<*const T> and Option<*mut T>")
-                }
+                StdLibType::Pointer(ptr) => UnusedOptionNoneValue {
+                    rust: ptr.null_pointer(),
+                    swift: "nil".into(),
+                },
                 StdLibType::RefSlice(_) => {
                     todo!("Support Option<&[T]>")
                 }
@@ -1777,7 +1993,7 @@ impl BridgedType {
                 StdLibType::Result(_) => {
                     todo!("Result<T, E> is not yet supported")
                 }
-                StdLibType::BoxedFnOnce(_) => {
+                StdLibType::BoxedFn(_) => {
                     todo!("Support Box<dyn FnOnce(A, B) -> C>")
                 }
                 StdLibType::Tuple(_tuple) => todo!(),
@@ -1892,10 +2108,12 @@ impl BridgedType {
                 StdLibType::U8 => "U8".to_string(),
                 StdLibType::U16 => "U16".to_string(),
                 StdLibType::U32 => "U32".to_string(),
+                StdLibType::U64 => "U64".to_string(),
                 StdLibType::Usize => "UInt".to_string(),
                 StdLibType::I8 => "I8".to_string(),
                 StdLibType::I16 => "I16".to_string(),
                 StdLibType::I32 => "I32".to_string(),
+                StdLibType::I64 => "I64".to_string(),
                 StdLibType::Isize => "Int".to_string(),
                 StdLibType::Bool => "Bool".to_string(),
                 StdLibType::F32 => "F32".to_string(),
@@ -1942,7 +2160,7 @@ mod tests {
 
         let parsed = BridgedType::new_with_str(tokens, &TypeDeclarations::default()).unwrap();
         match parsed {
-            BridgedType::StdLib(StdLibType::BoxedFnOnce(_)) => {}
+            BridgedType::StdLib(StdLibType::BoxedFn(_)) => {}
             _ => panic!(),
         };
     }
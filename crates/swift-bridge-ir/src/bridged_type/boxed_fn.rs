@@ -4,21 +4,72 @@ use crate::parsed_extern_fn::SwiftFuncGenerics;
 use crate::TypeDeclarations;
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{quote, ToTokens};
-use std::collections::HashSet;
+use std::collections::BTreeSet;
 use std::str::FromStr;
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::{Path, Type};
 
-/// Box<dyn FnOnce(A, B, C) -> ()>
+/// Box<dyn FnOnce(A, B, C) -> ()>, Box<dyn FnMut(A, B, C) -> ()> or Box<dyn Fn(A, B, C) -> ()>
 #[derive(Debug)]
-pub(crate) struct BridgeableBoxedFnOnce {
+pub(crate) struct BridgeableBoxedFn {
+    /// Whether this is an `Fn`, `FnMut` or `FnOnce`.
+    pub kind: BoxedFnKind,
     /// The functions parameters.
     pub params: Vec<BridgedType>,
     /// The functions return type.
     pub ret: Box<BridgedType>,
 }
 
+/// The closure trait that a boxed function implements.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum BoxedFnKind {
+    Fn,
+    FnMut,
+    FnOnce,
+}
+
+impl BoxedFnKind {
+    /// Parses the kind from the start of a stringified `Box<dyn Fn..>`, returning the kind along
+    /// with the rest of the signature.
+    ///
+    /// "Box < dyn FnMut (u8) -> u8 >" would give us `(FnMut, " (u8) -> u8 >")`.
+    pub fn from_str_tokens(string: &str) -> Option<(Self, &str)> {
+        let signature = string.strip_prefix("Box < dyn ")?;
+
+        // `FnOnce` and `FnMut` need to be checked before `Fn` since `Fn` is a prefix of both.
+        let (kind, rest) = if let Some(rest) = signature.strip_prefix("FnOnce") {
+            (BoxedFnKind::FnOnce, rest)
+        } else if let Some(rest) = signature.strip_prefix("FnMut") {
+            (BoxedFnKind::FnMut, rest)
+        } else if let Some(rest) = signature.strip_prefix("Fn") {
+            (BoxedFnKind::Fn, rest)
+        } else {
+            return None;
+        };
+
+        if rest.trim_start().starts_with("(") {
+            Some((kind, rest))
+        } else {
+            None
+        }
+    }
+
+    /// Fn, FnMut or FnOnce
+    pub fn to_ident(self) -> Ident {
+        let kind = match self {
+            BoxedFnKind::Fn => "Fn",
+            BoxedFnKind::FnMut => "FnMut",
+            BoxedFnKind::FnOnce => "FnOnce",
+        };
+        Ident::new(kind, Span::call_site())
+    }
+
+    pub fn is_fn_once(self) -> bool {
+        matches!(self, BoxedFnKind::FnOnce)
+    }
+}
+
 /// example: Vec<SomeType, AnotherType, u32>
 pub(crate) struct FunctionArguments(pub Vec<Type>);
 impl Parse for FunctionArguments {
@@ -28,13 +79,28 @@ impl Parse for FunctionArguments {
     }
 }
 
-impl BridgeableBoxedFnOnce {
+impl BridgeableBoxedFn {
     pub fn does_not_have_params_or_return(&self) -> bool {
         self.params.is_empty() && self.ret.is_null()
     }
 
+    /// Whether or not this function can use the `__private__RustFnOnceCallbackNoArgsNoRet`
+    /// support class that ships with `swift-bridge`, instead of needing its own generated
+    /// call and free functions.
+    pub fn uses_no_args_no_return_support(&self) -> bool {
+        self.kind.is_fn_once() && self.does_not_have_params_or_return()
+    }
+
+    /// The name of the Swift class that wraps the boxed function.
+    ///
+    /// For example: "__private__RustFnMutCallback"
+    pub fn swift_callback_class_prefix(&self) -> String {
+        format!("__private__Rust{}Callback", self.kind.to_ident())
+    }
+
     /// Box<dyn FnOnce(A, B) -> C>
     pub fn to_rust_type_path(&self, types: &TypeDeclarations) -> TokenStream {
+        let kind = self.kind.to_ident();
         let args: Vec<TokenStream> = self
             .params
             .iter()
@@ -42,7 +108,7 @@ impl BridgeableBoxedFnOnce {
             .collect();
         let ret = &self.ret.to_rust_type_path(types);
         quote! {
-            Box<dyn FnOnce(#(#args),*) -> #ret>
+            Box<dyn #kind(#(#args),*) -> #ret>
         }
     }
 
@@ -57,9 +123,10 @@ impl BridgeableBoxedFnOnce {
             .map(|a| a.to_rust_type_path(types))
             .collect();
         let ret = &self.ret.to_rust_type_path(types);
+        let kind = self.kind.to_ident();
 
         quote! {
-            Box::into_raw(Box::new(#expression)) as *mut Box<dyn FnOnce(#(#args),*) -> #ret>
+            Box::into_raw(Box::new(#expression)) as *mut Box<dyn #kind(#(#args),*) -> #ret>
         }
     }
 
@@ -70,8 +137,9 @@ impl BridgeableBoxedFnOnce {
             .map(|a| a.to_rust_type_path(types))
             .collect();
         let ret = &self.ret.to_rust_type_path(types);
+        let kind = self.kind.to_ident();
         quote! {
-            *mut Box<dyn FnOnce(#(#params),*) -> #ret>
+            *mut Box<dyn #kind(#(#params),*) -> #ret>
         }
     }
 
@@ -188,8 +256,37 @@ impl BridgeableBoxedFnOnce {
         args
     }
 
-    pub fn to_swift_type(&self) -> &'static str {
-        "UnsafeMutableRawPointer"
+    pub fn to_swift_type(&self, type_pos: TypePosition, types: &TypeDeclarations) -> String {
+        match type_pos {
+            // Swift passes a native closure to a Rust function.
+            TypePosition::FnArg(HostLang::Rust, _) => {
+                format!("@escaping {}", self.to_swift_closure_type(types))
+            }
+            _ => "UnsafeMutableRawPointer".to_string(),
+        }
+    }
+
+    /// The type of a Swift closure that gets passed to a Rust function.
+    ///
+    /// For example, `Box<dyn Fn(u8, String) -> String>` would give us:
+    /// "(UInt8, RustString) -> String"
+    pub fn to_swift_closure_type(&self, types: &TypeDeclarations) -> String {
+        // The closure receives the same types that a Rust function returns to Swift.
+        let params = self
+            .params
+            .iter()
+            .map(|ty| ty.to_swift_type(TypePosition::FnReturn(HostLang::Rust), types))
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        let ret = if self.ret.is_null() {
+            "()".to_string()
+        } else {
+            self.ret
+                .to_swift_type(TypePosition::SwiftReturnsToRust, types)
+        };
+
+        format!("({params}) -> {ret}")
     }
 
     pub fn convert_ffi_value_to_swift_value(&self, type_pos: TypePosition) -> String {
@@ -212,7 +309,7 @@ impl BridgeableBoxedFnOnce {
     /// For example:
     /// "<GenericRustString: IntoRustString>"
     pub fn maybe_swift_generics(&self, types: &TypeDeclarations) -> String {
-        let mut maybe_generics = HashSet::new();
+        let mut maybe_generics = BTreeSet::new();
 
         for bridged_arg in &self.params {
             if bridged_arg.contains_owned_string_recursive(types) {
@@ -240,32 +337,35 @@ impl BridgeableBoxedFnOnce {
     }
 }
 
-impl BridgeableBoxedFnOnce {
+impl BridgeableBoxedFn {
     pub fn from_str_tokens(string: &str, types: &TypeDeclarations) -> Option<Self> {
         // ( A , B , C ) -> D >
         //   OR
         // ( A , B , C ) >
-        let signature = string.trim_start_matches("Box < dyn FnOnce");
+        let (kind, signature) = BoxedFnKind::from_str_tokens(string)?;
 
-        let open_parens = signature.find("(").unwrap();
-        let closing_parens = signature.find(")").unwrap();
+        let open_parens = signature.find("(")?;
+        // The arguments can hold parentheses of their own, such as `extern "C" fn(u8)`.
+        let closing_parens = matching_closing_parens(signature, open_parens)?;
         // A, B, C
         let args = &signature[open_parens + 1..closing_parens];
 
-        let return_idx = signature.rfind("->");
+        let return_idx = signature[closing_parens..]
+            .find("->")
+            .map(|idx| closing_parens + idx);
 
         // D
         let ret = return_idx.map(|idx| &signature[(idx + 3)..signature.len() - 2]);
 
-        let args = TokenStream::from_str(args).unwrap();
-        let args: FunctionArguments = syn::parse2(args).unwrap();
+        let args = TokenStream::from_str(args).ok()?;
+        let args: FunctionArguments = syn::parse2(args).ok()?;
 
         let ret = if let Some(ret) = ret {
             // Parse out the comma in:
             //   Box<dyn FnOnce() -> (),>
             let ret = ret.trim_end_matches(",");
 
-            let ret = syn::parse2::<Type>(TokenStream::from_str(ret).unwrap()).unwrap();
+            let ret = syn::parse2::<Type>(TokenStream::from_str(ret).ok()?).ok()?;
             BridgedType::new_with_type(&ret, types)?
         } else {
             BridgedType::StdLib(StdLibType::Null)
@@ -276,13 +376,36 @@ impl BridgeableBoxedFnOnce {
             args_bridged_tys.push(BridgedType::new_with_type(&arg, types)?);
         }
 
-        return Some(BridgeableBoxedFnOnce {
+        return Some(BridgeableBoxedFn {
+            kind,
             params: args_bridged_tys,
             ret: Box::new(ret),
         });
     }
 }
 
+/// The index of the `)` that closes the `(` at the given index.
+fn matching_closing_parens(string: &str, open_parens: usize) -> Option<usize> {
+    let mut depth = 0;
+    for (idx, char) in string
+        .char_indices()
+        .skip_while(|(idx, _)| *idx < open_parens)
+    {
+        match char {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(idx);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -293,7 +416,7 @@ mod tests {
         let tokens = quote! {Box<dyn FnOnce()>}.to_token_stream().to_string();
 
         assert!(
-            BridgeableBoxedFnOnce::from_str_tokens(&tokens, &TypeDeclarations::default())
+            BridgeableBoxedFn::from_str_tokens(&tokens, &TypeDeclarations::default())
                 .unwrap()
                 .ret
                 .is_null()
@@ -308,7 +431,7 @@ mod tests {
             .to_string();
 
         assert!(matches!(
-            *BridgeableBoxedFnOnce::from_str_tokens(&tokens, &TypeDeclarations::default())
+            *BridgeableBoxedFn::from_str_tokens(&tokens, &TypeDeclarations::default())
                 .unwrap()
                 .ret,
             BridgedType::StdLib(StdLibType::U8)
@@ -323,13 +446,28 @@ mod tests {
             .to_string();
 
         assert!(
-            BridgeableBoxedFnOnce::from_str_tokens(&tokens, &TypeDeclarations::default())
+            BridgeableBoxedFn::from_str_tokens(&tokens, &TypeDeclarations::default())
                 .unwrap()
                 .ret
                 .is_null(),
         );
     }
 
+    /// Verify that we can parse a boxed fn whose argument has parentheses of its own, and that the
+    /// argument's `->` isn't mistaken for the boxed fn's return type.
+    #[test]
+    fn boxed_fn_with_extern_c_fn_arg() {
+        let tokens = quote! {Box<dyn Fn(extern "C" fn(u8) -> u8)>}
+            .to_token_stream()
+            .to_string();
+
+        let boxed_fn =
+            BridgeableBoxedFn::from_str_tokens(&tokens, &TypeDeclarations::default()).unwrap();
+
+        assert_eq!(boxed_fn.params.len(), 1);
+        assert!(boxed_fn.ret.is_null());
+    }
+
     /// Verify that we can parse a boxed fn that does not have a space before the argument
     /// parentheses.
     /// Not sure what leads to this case.. but if we don't handle it the test suite will fail so
@@ -339,13 +477,41 @@ mod tests {
         let tokens = "Box < dyn FnOnce() -> () >";
 
         assert!(
-            BridgeableBoxedFnOnce::from_str_tokens(tokens, &TypeDeclarations::default())
+            BridgeableBoxedFn::from_str_tokens(tokens, &TypeDeclarations::default())
                 .unwrap()
                 .ret
                 .is_null(),
         );
     }
 
+    /// Verify that we parse the kind of a boxed `Fn`, `FnMut` or `FnOnce`.
+    #[test]
+    fn boxed_fn_kind_from_string() {
+        let tests = vec![
+            (quote! {Box<dyn Fn(u32) -> u32>}, BoxedFnKind::Fn),
+            (quote! {Box<dyn FnMut(u32) -> u32>}, BoxedFnKind::FnMut),
+            (quote! {Box<dyn FnOnce(u32) -> u32>}, BoxedFnKind::FnOnce),
+        ];
+
+        for (tokens, expected_kind) in tests {
+            let tokens = tokens.to_token_stream().to_string();
+
+            let boxed_fn =
+                BridgeableBoxedFn::from_str_tokens(&tokens, &TypeDeclarations::default()).unwrap();
+            assert_eq!(boxed_fn.kind, expected_kind);
+            assert_eq!(boxed_fn.params.len(), 1);
+        }
+    }
+
+    /// Verify that we do not treat a boxed trait object whose name starts with `Fn` as a boxed
+    /// function.
+    #[test]
+    fn boxed_trait_starting_with_fn_is_not_a_boxed_fn() {
+        let tokens = quote! {Box<dyn Fnord>}.to_token_stream().to_string();
+
+        assert!(BoxedFnKind::from_str_tokens(&tokens).is_none());
+    }
+
     /// Verify that we can parse a boxed fn that has a comma after the FnOnce.
     /// rustfmt adds a trailing comma when it puts a long function signature on its own line.
     #[test]
@@ -364,7 +530,7 @@ mod tests {
             let tokens = test.to_token_stream().to_string();
 
             assert!(
-                BridgeableBoxedFnOnce::from_str_tokens(&tokens, &TypeDeclarations::default())
+                BridgeableBoxedFn::from_str_tokens(&tokens, &TypeDeclarations::default())
                     .unwrap()
                     .ret
                     .is_null(),
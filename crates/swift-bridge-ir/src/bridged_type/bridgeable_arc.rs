@@ -0,0 +1,441 @@
+use crate::bridged_type::{
+    BridgeableType, BuiltInResult, CFfiStruct, OnlyEncoding, TypePosition, UnusedOptionNoneValue,
+};
+use crate::parse::{HostLang, TypeDeclaration};
+use crate::{TypeDeclarations, SWIFT_BRIDGE_PREFIX};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{GenericArgument, Path, PathArguments, Type};
+
+/// std::sync::Arc<SomeOpaqueRustType>
+///
+/// An `Arc<Foo>` is seen on the Swift side as an `ArcFoo` class, which is a `FooRef` that owns
+/// one strong count of the `Arc`. Every `ArcFoo` that shares the same Rust `Foo` holds its own
+/// strong count, which it clones in `init` and releases in `deinit`.
+///
+/// An `Arc<Foo>` is passed over FFI as the pointer from `Arc::into_raw`, and every pointer that
+/// crosses the boundary carries one strong count with it.
+#[derive(Debug)]
+pub(crate) struct BridgeableArc {
+    /// The opaque Rust type inside of the `Arc`, such as `Foo` for an `Arc<Foo>`.
+    ty: Ident,
+}
+
+impl BridgeableArc {
+    /// `*const super::Foo`
+    fn ffi_pointer(&self) -> TokenStream {
+        let ty = &self.ty;
+        quote! { *const super::#ty }
+    }
+
+    /// "ArcFoo"
+    fn swift_class_name(&self) -> String {
+        format!("Arc{}", self.ty)
+    }
+
+    /// "__swift_bridge__$Foo$_arc_clone"
+    fn clone_ffi_name(&self) -> String {
+        format!("{}${}$_arc_clone", SWIFT_BRIDGE_PREFIX, self.ty)
+    }
+
+    /// "__swift_bridge__$Foo$_arc_drop"
+    fn drop_ffi_name(&self) -> String {
+        format!("{}${}$_arc_drop", SWIFT_BRIDGE_PREFIX, self.ty)
+    }
+
+    /// `__swift_bridge__Foo__arc_clone`
+    fn clone_ffi_ident(&self) -> Ident {
+        Ident::new(
+            &format!("{}{}__arc_clone", SWIFT_BRIDGE_PREFIX, self.ty),
+            self.ty.span(),
+        )
+    }
+
+    /// `__swift_bridge__Foo__arc_drop`
+    fn drop_ffi_ident(&self) -> Ident {
+        Ident::new(
+            &format!("{}{}__arc_drop", SWIFT_BRIDGE_PREFIX, self.ty),
+            self.ty.span(),
+        )
+    }
+
+    /// Convert an `Arc<Foo>` pointer expression into a Rust `Arc<Foo>`.
+    fn arc_from_raw(expression: &TokenStream) -> TokenStream {
+        quote! { unsafe { std::sync::Arc::from_raw(#expression) } }
+    }
+
+    /// Convert a Swift `ArcFoo` expression into a pointer that carries its own strong count.
+    fn swift_arc_to_ffi(&self, expression: &str) -> String {
+        format!("{}({expression}.ptr)", self.clone_ffi_name())
+    }
+
+    /// Get the name of the type inside of an `Arc<Foo>` or a `std::sync::Arc<Foo>`.
+    fn arc_inner_type_name(ty: &Type) -> Option<String> {
+        let path = match ty {
+            Type::Path(path) if path.qself.is_none() => &path.path,
+            _ => return None,
+        };
+
+        let segments: Vec<_> = path.segments.iter().collect();
+        let (last, prefix) = segments.split_last()?;
+
+        let prefix: Vec<String> = prefix
+            .iter()
+            .map(|segment| match segment.arguments {
+                PathArguments::None => Some(segment.ident.to_string()),
+                _ => None,
+            })
+            .collect::<Option<Vec<String>>>()?;
+        if !(prefix.is_empty() || prefix == ["std", "sync"] || prefix == ["alloc", "sync"]) {
+            return None;
+        }
+
+        match &last.arguments {
+            PathArguments::AngleBracketed(generics)
+                if last.ident == "Arc" && generics.args.len() == 1 =>
+            {
+                match &generics.args[0] {
+                    GenericArgument::Type(Type::Path(inner)) => {
+                        Some(inner.path.to_token_stream().to_string())
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+impl BridgeableType for BridgeableArc {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        true
+    }
+
+    /// The functions that Swift uses to clone and release its strong counts.
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        let ptr = self.ffi_pointer();
+        let clone_ffi_name = self.clone_ffi_name();
+        let clone_ffi_ident = self.clone_ffi_ident();
+        let drop_ffi_name = self.drop_ffi_name();
+        let drop_ffi_ident = self.drop_ffi_ident();
+
+        Some(vec![
+            quote! {
+                #[export_name = #clone_ffi_name]
+                pub extern "C" fn #clone_ffi_ident(this: #ptr) -> #ptr {
+                    unsafe { std::sync::Arc::increment_strong_count(this) };
+                    this
+                }
+            },
+            quote! {
+                #[export_name = #drop_ffi_name]
+                pub extern "C" fn #drop_ffi_ident(this: #ptr) {
+                    unsafe { std::sync::Arc::decrement_strong_count(this) };
+                }
+            },
+        ])
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        Some(CFfiStruct {
+            c_ffi_type: format!(
+                "void* {}(void* self);\nvoid {}(void* self);",
+                self.clone_ffi_name(),
+                self.drop_ffi_name()
+            ),
+            fields: vec![],
+        })
+    }
+
+    fn generate_custom_swift_code(&self, _types: &TypeDeclarations) -> Option<String> {
+        Some(format!(
+            r#"public class {class}: {ty}Ref {{
+    public override init(ptr: UnsafeMutableRawPointer) {{
+        super.init(ptr: ptr)
+    }}
+
+    public convenience init(_ other: {class}) {{
+        self.init(ptr: {clone}(other.ptr))
+    }}
+
+    deinit {{
+        {drop}(ptr)
+    }}
+}}"#,
+            class = self.swift_class_name(),
+            ty = self.ty,
+            clone = self.clone_ffi_name(),
+            drop = self.drop_ffi_name(),
+        ))
+    }
+
+    fn to_rust_type_path(&self, _types: &TypeDeclarations) -> TokenStream {
+        let ty = &self.ty;
+        quote! { std::sync::Arc<super::#ty> }
+    }
+
+    fn to_swift_type(&self, type_pos: TypePosition, _types: &TypeDeclarations) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
+                if func_host_lang.is_rust() {
+                    self.swift_class_name()
+                } else {
+                    "UnsafeMutableRawPointer".to_string()
+                }
+            }
+            TypePosition::SharedStructField | TypePosition::SwiftReturnsToRust => {
+                self.swift_class_name()
+            }
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                "UnsafeMutableRawPointer?".to_string()
+            }
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        "void*".to_string()
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        None
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        self.ffi_pointer()
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        self.ffi_pointer()
+    }
+
+    fn to_ffi_compatible_option_swift_type(&self, _types: &TypeDeclarations) -> String {
+        "UnsafeMutableRawPointer?".to_string()
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        "void*".to_string()
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        _span: Span,
+    ) -> TokenStream {
+        quote! { std::sync::Arc::into_raw(#expression) }
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+    ) -> TokenStream {
+        let unused_none_value = self.unused_option_none_val(swift_bridge_path).rust;
+
+        quote! {
+            if let Some(val) = #expression {
+                std::sync::Arc::into_raw(val)
+            } else {
+                #unused_none_value
+            }
+        }
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        self.swift_arc_to_ffi(expression)
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        let arc = self.swift_arc_to_ffi("val");
+        format!("{{ if let val = {expression} {{ return {arc} }} else {{ return nil }} }}()")
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        _span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        Self::arc_from_raw(expression)
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(&self, expression: &TokenStream) -> TokenStream {
+        let arc = Self::arc_from_raw(&quote! { val });
+
+        quote! {
+            {
+                let val = #expression;
+                if val.is_null() {
+                    None
+                } else {
+                    Some(#arc)
+                }
+            }
+        }
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        type_pos: TypePosition,
+        _types: &TypeDeclarations,
+    ) -> String {
+        let class = self.swift_class_name();
+        match type_pos {
+            TypePosition::FnArg(_, _)
+            | TypePosition::FnReturn(_)
+            | TypePosition::SharedStructField
+            | TypePosition::SwiftReturnsToRust => format!("{class}(ptr: {expression})"),
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                format!("{class}(ptr: {expression}!)")
+            }
+        }
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, expression: &str) -> String {
+        format!(
+            "{{ let val = {expression}; if val != nil {{ return {}(ptr: val!) }} else {{ return nil }} }}()",
+            self.swift_class_name()
+        )
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        result: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ptr = self.ffi_pointer();
+        Self::arc_from_raw(&quote! { #result.ok_or_err as #ptr })
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        result: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ptr = self.ffi_pointer();
+        Self::arc_from_raw(&quote! { #result.ok_or_err as #ptr })
+    }
+
+    fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        let ty = &self.ty;
+        UnusedOptionNoneValue {
+            rust: quote! { std::ptr::null::<super::#ty>() },
+            swift: "nil".to_string(),
+        }
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        if !tokens.contains("Arc") {
+            return false;
+        }
+
+        match syn::parse_str::<Type>(tokens) {
+            Ok(ty) => Self::arc_inner_type_name(&ty).is_some(),
+            Err(_) => false,
+        }
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        Self::parse_token_stream_str(ty.to_token_stream().to_string().as_str(), types)
+    }
+
+    /// Only an `Arc` of an opaque Rust type that is declared in the bridge module can be bridged,
+    /// since the Swift class that holds the `Arc` is built on top of that type's Swift class.
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if !tokens.contains("Arc") {
+            return None;
+        }
+
+        let ty: Type = syn::parse_str(tokens).ok()?;
+        let inner = Self::arc_inner_type_name(&ty)?;
+
+        match types.get(&inner)? {
+            TypeDeclaration::Opaque(opaque)
+                if opaque.host_lang == HostLang::Rust
+                    && opaque.attributes.copy.is_none()
+                    && opaque.generics.generics.is_empty()
+                    && !opaque.attributes.swift_protocol =>
+            {
+                Some(BridgeableArc {
+                    ty: opaque.ty.clone(),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, _types: &TypeDeclarations) -> String {
+        format!("Arc{}", self.ty)
+    }
+}
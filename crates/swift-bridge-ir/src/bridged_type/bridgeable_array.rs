@@ -0,0 +1,330 @@
+use crate::bridged_type::built_in_primitive::BuiltInPrimitive;
+use crate::bridged_type::{
+    BridgeableType, BridgedType, BuiltInResult, CFfiStruct, OnlyEncoding, StdLibType, TypePosition,
+    UnusedOptionNoneValue,
+};
+use crate::parse::TypeDeclarations;
+use crate::SWIFT_BRIDGE_PREFIX;
+use proc_macro2::{Ident, Literal, Span, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+use syn::{Expr, ExprLit, Lit, Path, Type};
+
+/// [T; N], such as `[u8; 32]`.
+///
+/// A fixed-size array is passed over FFI by value inside of a `#[repr(C)]` struct, since C does
+/// not allow arrays to be passed or returned by value.
+///
+/// C arrays are imported into Swift as homogeneous tuples, so a `[u8; 4]` is seen on the Swift
+/// side as a `(UInt8, UInt8, UInt8, UInt8)`.
+///
+/// Only arrays of primitive types such as `u8`, `i32` and `f64` are supported.
+#[derive(Debug)]
+pub(crate) struct BridgeableArray {
+    elem: Box<BridgedType>,
+    len: usize,
+}
+
+impl BridgeableArray {
+    /// `U8$32`
+    fn ffi_name_suffix(&self, types: &TypeDeclarations) -> String {
+        format!(
+            "{}${}",
+            self.elem.to_alpha_numeric_underscore_name(types),
+            self.len
+        )
+    }
+
+    /// `__swift_bridge__array_U8_32`
+    fn prefixed_rust_ty_name(&self, types: &TypeDeclarations) -> Ident {
+        format_ident!(
+            "{}array_{}_{}",
+            SWIFT_BRIDGE_PREFIX,
+            self.elem.to_alpha_numeric_underscore_name(types),
+            self.len
+        )
+    }
+
+    /// `__swift_bridge__$array$U8$32`
+    fn prefixed_c_ty_name(&self, types: &TypeDeclarations) -> String {
+        format!(
+            "{}$array${}",
+            SWIFT_BRIDGE_PREFIX,
+            self.ffi_name_suffix(types)
+        )
+    }
+
+    /// `(UInt8, UInt8, UInt8, UInt8)`
+    fn swift_tuple(&self, type_pos: TypePosition, types: &TypeDeclarations) -> String {
+        let elem = self.elem.to_swift_type(type_pos, types);
+        format!("({})", vec![elem; self.len].join(", "))
+    }
+}
+
+impl BridgeableType for BridgeableArray {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        false
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        let ty_name = self.prefixed_rust_ty_name(types);
+        let array = self.to_rust_type_path(types);
+
+        Some(vec![quote! {
+            #[repr(C)]
+            #[doc(hidden)]
+            pub struct #ty_name {
+                pub elements: #array
+            }
+        }])
+    }
+
+    fn generate_custom_c_ffi_types(&self, types: &TypeDeclarations) -> Option<CFfiStruct> {
+        let ty_name = self.prefixed_c_ty_name(types);
+        let elem = self.elem.to_c(types);
+        let len = self.len;
+
+        Some(CFfiStruct {
+            c_ffi_type: format!(
+                "typedef struct {ty_name} {{ {elem} elements[{len}]; }} {ty_name};"
+            ),
+            fields: vec![],
+        })
+    }
+
+    fn to_rust_type_path(&self, types: &TypeDeclarations) -> TokenStream {
+        let elem = self.elem.to_rust_type_path(types);
+        let len = Literal::usize_unsuffixed(self.len);
+        quote! { [#elem; #len] }
+    }
+
+    fn to_swift_type(&self, type_pos: TypePosition, types: &TypeDeclarations) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
+                if func_host_lang.is_rust() {
+                    self.swift_tuple(type_pos, types)
+                } else {
+                    self.prefixed_c_ty_name(types)
+                }
+            }
+            TypePosition::SharedStructField | TypePosition::SwiftReturnsToRust => {
+                self.swift_tuple(type_pos, types)
+            }
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => self.prefixed_c_ty_name(types),
+        }
+    }
+
+    fn to_c_type(&self, types: &TypeDeclarations) -> String {
+        format!("struct {}", self.prefixed_c_ty_name(types))
+    }
+
+    fn to_c_include(&self, types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        self.elem.to_c_include(types)
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        self.prefixed_rust_ty_name(types).to_token_stream()
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Option<[T; N]> is rejected when the bridge module is parsed")
+    }
+
+    fn to_ffi_compatible_option_swift_type(&self, _types: &TypeDeclarations) -> String {
+        unreachable!("Option<[T; N]> is rejected when the bridge module is parsed")
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        unreachable!("Option<[T; N]> is rejected when the bridge module is parsed")
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+        _span: Span,
+    ) -> TokenStream {
+        let ty_name = self.prefixed_rust_ty_name(types);
+        quote! { #ty_name { elements: #expression } }
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        _expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        unreachable!("Option<[T; N]> is rejected when the bridge module is parsed")
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!("{}(elements: {expression})", self.prefixed_c_ty_name(types))
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        _expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        unreachable!("Option<[T; N]> is rejected when the bridge module is parsed")
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        _span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote! { #expression.elements }
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(&self, _expression: &TokenStream) -> TokenStream {
+        unreachable!("Option<[T; N]> is rejected when the bridge module is parsed")
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+        _types: &TypeDeclarations,
+    ) -> String {
+        format!("{expression}.elements")
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, _expression: &str) -> String {
+        unreachable!("Option<[T; N]> is rejected when the bridge module is parsed")
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        _ok_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Result<[T; N], E> is rejected when it would be passed to Rust")
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        _err_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Result<T, [E; N]> is rejected when it would be passed to Rust")
+    }
+
+    fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        unreachable!("Option<[T; N]> is rejected when the bridge module is parsed")
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        tokens.starts_with('[')
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let array = match ty {
+            Type::Array(array) => array,
+            _ => return None,
+        };
+
+        let len = match &array.len {
+            Expr::Lit(ExprLit {
+                lit: Lit::Int(len), ..
+            }) => len.base10_parse::<usize>().ok()?,
+            _ => return None,
+        };
+        if len == 0 {
+            return None;
+        }
+
+        let elem = BridgedType::new_with_type(&array.elem, types)?;
+        match &elem {
+            BridgedType::StdLib(StdLibType::Null) => return None,
+            BridgedType::StdLib(stdlib_type) => {
+                BuiltInPrimitive::new_with_stdlib_type(stdlib_type)?;
+            }
+            _ => return None,
+        };
+
+        Some(BridgeableArray {
+            elem: Box::new(elem),
+            len,
+        })
+    }
+
+    /// The length of the array must be an integer literal, such as the `32` in `[u8; 32]`.
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let ty: Type = syn::parse_str(tokens).ok()?;
+        Self::from_type(&ty, types)
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, types: &TypeDeclarations) -> String {
+        format!(
+            "Array{}_{}",
+            self.elem.to_alpha_numeric_underscore_name(types),
+            self.len
+        )
+    }
+}
@@ -0,0 +1,325 @@
+use crate::bridged_type::{
+    BridgeableType, BuiltInResult, CFfiStruct, OnlyEncoding, OpaqueForeignType, TypePosition,
+    UnusedOptionNoneValue,
+};
+use crate::parse::HostLang;
+use crate::TypeDeclarations;
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{GenericArgument, Path, PathArguments, Type};
+
+/// Box<SomeOpaqueRustType>
+///
+/// A `Box<Foo>` is seen on the Swift side the same way that a `Foo` is, as an owned `Foo` class.
+///
+/// An opaque Rust type is already passed over FFI as a pointer to a boxed value, so a `Box<Foo>`
+/// is handed over as is instead of being moved into a new box. Returning a `Box<Foo>` to Swift
+/// consumes the box, and a `Box<Foo>` that Swift passes back to Rust is reconstituted from the
+/// same pointer.
+#[derive(Debug)]
+pub(crate) struct BridgeableBox {
+    /// The opaque Rust type inside of the `Box`, such as `Foo` for a `Box<Foo>`.
+    inner: OpaqueForeignType,
+}
+
+impl BridgeableBox {
+    /// `*mut super::Foo`
+    fn ffi_pointer(&self) -> TokenStream {
+        let ty = &self.inner.ty;
+        quote! { *mut super::#ty }
+    }
+
+    /// Convert a `*mut super::Foo` expression into a `Box<Foo>`.
+    fn box_from_raw(expression: &TokenStream) -> TokenStream {
+        quote! { unsafe { Box::from_raw(#expression) } }
+    }
+
+    /// Get the name of the type inside of a `Box<Foo>` or a `std::boxed::Box<Foo>`.
+    fn box_inner_type_name(ty: &Type) -> Option<String> {
+        let path = match ty {
+            Type::Path(path) if path.qself.is_none() => &path.path,
+            _ => return None,
+        };
+
+        let segments: Vec<_> = path.segments.iter().collect();
+        let (last, prefix) = segments.split_last()?;
+
+        let prefix: Vec<String> = prefix
+            .iter()
+            .map(|segment| match segment.arguments {
+                PathArguments::None => Some(segment.ident.to_string()),
+                _ => None,
+            })
+            .collect::<Option<Vec<String>>>()?;
+        if !(prefix.is_empty() || prefix == ["std", "boxed"] || prefix == ["alloc", "boxed"]) {
+            return None;
+        }
+
+        match &last.arguments {
+            PathArguments::AngleBracketed(generics)
+                if last.ident == "Box" && generics.args.len() == 1 =>
+            {
+                match &generics.args[0] {
+                    GenericArgument::Type(Type::Path(inner)) => {
+                        Some(inner.path.to_token_stream().to_string())
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+impl BridgeableType for BridgeableBox {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        true
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    fn to_rust_type_path(&self, _types: &TypeDeclarations) -> TokenStream {
+        let ty = &self.inner.ty;
+        quote! { Box<super::#ty> }
+    }
+
+    fn to_swift_type(&self, type_pos: TypePosition, types: &TypeDeclarations) -> String {
+        self.inner.to_swift_type(type_pos, types)
+    }
+
+    fn to_c_type(&self, types: &TypeDeclarations) -> String {
+        self.inner.to_c_type(types)
+    }
+
+    fn to_c_include(&self, types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        self.inner.to_c_include(types)
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        self.ffi_pointer()
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        self.ffi_pointer()
+    }
+
+    fn to_ffi_compatible_option_swift_type(&self, types: &TypeDeclarations) -> String {
+        self.inner.to_ffi_compatible_option_swift_type(types)
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        self.inner.to_ffi_compatible_option_c_type()
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        _span: Span,
+    ) -> TokenStream {
+        quote! { Box::into_raw(#expression) }
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        quote! {
+            if let Some(val) = #expression {
+                Box::into_raw(val)
+            } else {
+                std::ptr::null_mut()
+            }
+        }
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        types: &TypeDeclarations,
+        type_pos: TypePosition,
+    ) -> String {
+        self.inner
+            .convert_swift_expression_to_ffi_type(expression, types, type_pos)
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        type_pos: TypePosition,
+    ) -> String {
+        self.inner
+            .convert_option_swift_expression_to_ffi_type(expression, type_pos)
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        _span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        Self::box_from_raw(expression)
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(&self, expression: &TokenStream) -> TokenStream {
+        let boxed = Self::box_from_raw(&quote! { val });
+
+        quote! {
+            {
+                let val = #expression;
+                if val.is_null() {
+                    None
+                } else {
+                    Some(#boxed)
+                }
+            }
+        }
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        type_pos: TypePosition,
+        types: &TypeDeclarations,
+    ) -> String {
+        self.inner
+            .convert_ffi_expression_to_swift_type(expression, type_pos, types)
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, expression: &str) -> String {
+        self.inner
+            .convert_ffi_option_expression_to_swift_type(expression)
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        result: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ptr = self.ffi_pointer();
+        Self::box_from_raw(&quote! { #result.ok_or_err as #ptr })
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        result: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ptr = self.ffi_pointer();
+        Self::box_from_raw(&quote! { #result.ok_or_err as #ptr })
+    }
+
+    fn unused_option_none_val(&self, swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        self.inner.unused_option_none_val(swift_bridge_path)
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        if !tokens.contains("Box") || tokens.contains("dyn") {
+            return false;
+        }
+
+        match syn::parse_str::<Type>(tokens) {
+            Ok(ty) => Self::box_inner_type_name(&ty).is_some(),
+            Err(_) => false,
+        }
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        Self::parse_token_stream_str(ty.to_token_stream().to_string().as_str(), types)
+    }
+
+    /// Only a `Box` of a non-generic opaque Rust type that is declared in the bridge module can be
+    /// bridged. A `Copy` opaque Rust type is passed over FFI by value, so it has no box to hand
+    /// over.
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if !tokens.contains("Box") || tokens.contains("dyn") {
+            return None;
+        }
+
+        let ty: Type = syn::parse_str(tokens).ok()?;
+        let inner = Self::box_inner_type_name(&ty)?;
+        let inner = types.get(&inner)?.to_opaque_type(false, false)?;
+
+        if inner.host_lang == HostLang::Rust
+            && !inner.has_swift_bridge_copy_annotation
+            && !inner.swift_protocol
+            && inner.generics.generics.is_empty()
+        {
+            Some(BridgeableBox { inner })
+        } else {
+            None
+        }
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, _types: &TypeDeclarations) -> String {
+        format!("Box{}", self.inner.ty)
+    }
+}
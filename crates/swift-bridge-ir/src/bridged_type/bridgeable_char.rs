@@ -0,0 +1,260 @@
+use crate::bridged_type::{
+    BridgeableType, BuiltInResult, CFfiStruct, OnlyEncoding, TypePosition, UnusedOptionNoneValue,
+};
+use crate::TypeDeclarations;
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{Path, Type};
+
+/// char
+///
+/// A Rust `char` is seen on the Swift side as a `Unicode.Scalar`. Both are a Unicode scalar value,
+/// so they get passed over FFI as a `u32`.
+///
+/// Converting a `u32` back into a `char` or a `Unicode.Scalar` is checked, so an invalid scalar
+/// value panics instead of producing an invalid `char`.
+#[derive(Debug)]
+pub(crate) struct BridgeableChar;
+
+impl BridgeableType for BridgeableChar {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        false
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    fn to_rust_type_path(&self, _types: &TypeDeclarations) -> TokenStream {
+        quote! { char }
+    }
+
+    fn to_swift_type(&self, type_pos: TypePosition, _types: &TypeDeclarations) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
+                if func_host_lang.is_rust() {
+                    "Unicode.Scalar".to_string()
+                } else {
+                    "UInt32".to_string()
+                }
+            }
+            TypePosition::SharedStructField | TypePosition::SwiftReturnsToRust => {
+                "Unicode.Scalar".to_string()
+            }
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => "UInt32".to_string(),
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        "uint32_t".to_string()
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        Some(vec!["stdint.h"])
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote! { u32 }
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote! { #swift_bridge_path::option::OptionU32 }
+    }
+
+    fn to_ffi_compatible_option_swift_type(&self, _types: &TypeDeclarations) -> String {
+        "__private__OptionU32".to_string()
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        "struct __private__OptionU32".to_string()
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        _span: Span,
+    ) -> TokenStream {
+        quote! { u32::from(#expression) }
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+    ) -> TokenStream {
+        quote! {
+            if let Some(val) = #expression {
+                #swift_bridge_path::option::OptionU32 { val: u32::from(val), is_some: true }
+            } else {
+                #swift_bridge_path::option::OptionU32 { val: 123, is_some: false }
+            }
+        }
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!("{expression}.value")
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!("{{ let val = {expression}; return __private__OptionU32(val: val?.value ?? 123, is_some: val != nil) }}()")
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        _span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote! { char::from_u32(#expression).unwrap() }
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(&self, expression: &TokenStream) -> TokenStream {
+        quote! {
+            {
+                let val = #expression;
+                if val.is_some {
+                    Some(char::from_u32(val.val).unwrap())
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+        _types: &TypeDeclarations,
+    ) -> String {
+        format!("Unicode.Scalar({expression})!")
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, expression: &str) -> String {
+        format!("{{ let val = {expression}; if val.is_some {{ return Unicode.Scalar(val.val)! }} else {{ return nil }} }}()")
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        ok_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote! { char::from_u32(#ok_ffi_value).unwrap() }
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        err_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote! { char::from_u32(#err_ffi_value).unwrap() }
+    }
+
+    fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        UnusedOptionNoneValue {
+            rust: quote! { 123 },
+            swift: "123".into(),
+        }
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        tokens == "char"
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => Self::parse_token_stream_str(
+                path.path.segments.to_token_stream().to_string().as_str(),
+                types,
+            ),
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, _types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_parse_token_stream_str(tokens) {
+            Some(BridgeableChar)
+        } else {
+            None
+        }
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, _types: &TypeDeclarations) -> String {
+        "Char".to_string()
+    }
+}
@@ -0,0 +1,256 @@
+use crate::bridged_type::{
+    BridgeableType, BuiltInResult, CFfiStruct, OnlyEncoding, TypePosition, UnusedOptionNoneValue,
+};
+use crate::TypeDeclarations;
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{Path, Type};
+
+/// std::borrow::Cow<'static, str>
+///
+/// A `Cow<'static, str>` is seen on the Swift side as a `RustCowStr`, and is passed over FFI as a
+/// `swift_bridge::string::FfiCowStr`. The `FfiCowStr` records whether or not the string is owned,
+/// so that only an owned string's bytes get freed once Swift is done with them.
+#[derive(Debug)]
+pub(crate) struct BridgeableCowStr;
+
+impl BridgeableCowStr {
+    /// `swift_bridge::string::FfiCowStr`
+    fn ffi_struct(swift_bridge_path: &Path) -> TokenStream {
+        quote! { #swift_bridge_path::string::FfiCowStr }
+    }
+}
+
+impl BridgeableType for BridgeableCowStr {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        false
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    fn to_rust_type_path(&self, _types: &TypeDeclarations) -> TokenStream {
+        quote! { std::borrow::Cow<'static, str> }
+    }
+
+    fn to_swift_type(&self, type_pos: TypePosition, _types: &TypeDeclarations) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
+                if func_host_lang.is_rust() {
+                    "RustCowStr".to_string()
+                } else {
+                    "__private__FfiCowStr".to_string()
+                }
+            }
+            TypePosition::SharedStructField | TypePosition::SwiftReturnsToRust => {
+                "RustCowStr".to_string()
+            }
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                "__private__FfiCowStr".to_string()
+            }
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        "struct __private__FfiCowStr".to_string()
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        None
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        Self::ffi_struct(swift_bridge_path)
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        Self::ffi_struct(swift_bridge_path)
+    }
+
+    fn to_ffi_compatible_option_swift_type(&self, _types: &TypeDeclarations) -> String {
+        "__private__FfiCowStr".to_string()
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        "struct __private__FfiCowStr".to_string()
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        _span: Span,
+    ) -> TokenStream {
+        let ffi_struct = Self::ffi_struct(swift_bridge_path);
+        quote! { #ffi_struct::from_cow(#expression) }
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+    ) -> TokenStream {
+        let ffi_struct = Self::ffi_struct(swift_bridge_path);
+        quote! { #ffi_struct::from_option_cow(#expression) }
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!("{expression}.intoFfiRepr()")
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!("{expression}.intoFfiRepr()")
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        _span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote! { #expression.into_cow() }
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(&self, expression: &TokenStream) -> TokenStream {
+        quote! { #expression.into_option_cow() }
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+        _types: &TypeDeclarations,
+    ) -> String {
+        format!("{expression}.intoSwiftRepr()")
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, expression: &str) -> String {
+        format!("{expression}.intoOptionalSwiftRepr()")
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        ok_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote! { #ok_ffi_value.into_cow() }
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        err_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote! { #err_ffi_value.into_cow() }
+    }
+
+    fn unused_option_none_val(&self, swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        let ffi_struct = Self::ffi_struct(swift_bridge_path);
+        UnusedOptionNoneValue {
+            rust: quote! { #ffi_struct::from_option_cow(None) },
+            swift: "Optional<RustCowStr>.none.intoFfiRepr()".to_string(),
+        }
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        let tokens = tokens.replace(' ', "");
+        tokens == "Cow<'static,str>" || tokens == "std::borrow::Cow<'static,str>"
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => Self::parse_token_stream_str(
+                path.path.to_token_stream().to_string().as_str(),
+                types,
+            ),
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, _types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_parse_token_stream_str(tokens) {
+            Some(BridgeableCowStr)
+        } else {
+            None
+        }
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, _types: &TypeDeclarations) -> String {
+        "CowStr".to_string()
+    }
+}
@@ -0,0 +1,340 @@
+use crate::bridged_type::bridged_option::BridgedOption;
+use crate::bridged_type::{
+    BridgeableType, BridgedType, BuiltInResult, CFfiStruct, OnlyEncoding, StdLibType, TypePosition,
+    UnusedOptionNoneValue,
+};
+use crate::custom_type_bridge::{find_custom_type_bridge, CustomTypeBridge};
+use crate::parse::HostLang;
+use crate::TypeDeclarations;
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use std::fmt::{Debug, Formatter};
+use std::ops::Deref;
+use std::sync::Arc;
+use syn::{Path, Type};
+
+/// A third-party type that is bridged using a registered [`CustomTypeBridge`].
+///
+/// The type is passed over FFI as its FFI representation, so everything other than converting
+/// between the type and its representation is handled by the representation's own
+/// `BridgeableType` implementation. An `Option` of the type is passed as an `Option` of the
+/// representation.
+pub(crate) struct BridgeableCustomType {
+    bridge: Arc<dyn CustomTypeBridge>,
+    repr: Box<BridgedType>,
+    /// `Option<Repr>`
+    option_repr: Box<BridgedType>,
+}
+
+impl BridgeableCustomType {
+    fn option_repr(&self) -> &BridgedOption {
+        match self.option_repr.deref() {
+            BridgedType::StdLib(StdLibType::Option(option)) => option,
+            _ => unreachable!("The option representation is always an Option."),
+        }
+    }
+}
+
+impl Debug for BridgeableCustomType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BridgeableCustomType")
+            .field("rust_type", &self.bridge.rust_type().to_string())
+            .field("repr", &self.repr)
+            .finish()
+    }
+}
+
+impl BridgeableType for BridgeableCustomType {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        self.repr.is_passed_via_pointer()
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        self.repr
+            .generate_custom_rust_ffi_types(swift_bridge_path, types)
+    }
+
+    fn generate_custom_c_ffi_types(&self, types: &TypeDeclarations) -> Option<CFfiStruct> {
+        self.repr.generate_custom_c_ffi_types(types)
+    }
+
+    fn generate_custom_swift_code(&self, types: &TypeDeclarations) -> Option<String> {
+        self.repr.generate_custom_swift_code(types)
+    }
+
+    fn to_rust_type_path(&self, _types: &TypeDeclarations) -> TokenStream {
+        self.bridge.rust_type()
+    }
+
+    /// Swift code that Rust calls converts to and from the FFI representation itself, so it is
+    /// declared using the representation's Swift type.
+    fn to_swift_type(&self, type_pos: TypePosition, types: &TypeDeclarations) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
+                if func_host_lang.is_rust() {
+                    self.bridge.swift_type()
+                } else {
+                    self.repr.to_swift_type(type_pos, types)
+                }
+            }
+            TypePosition::SharedStructField | TypePosition::SwiftReturnsToRust => {
+                self.bridge.swift_type()
+            }
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                self.repr.to_swift_type(type_pos, types)
+            }
+        }
+    }
+
+    fn to_c_type(&self, types: &TypeDeclarations) -> String {
+        self.repr.to_c_type(types)
+    }
+
+    fn to_c_include(&self, types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        self.repr.to_c_include(types)
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        self.repr
+            .to_ffi_compatible_rust_type(swift_bridge_path, types)
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        self.option_repr
+            .to_ffi_compatible_rust_type(swift_bridge_path, types)
+    }
+
+    fn to_ffi_compatible_option_swift_type(&self, types: &TypeDeclarations) -> String {
+        self.option_repr()
+            .to_ffi_compatible_swift_type(TypePosition::FnReturn(HostLang::Swift), types)
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        self.option_repr().to_c(&TypeDeclarations::default())
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+        span: Span,
+    ) -> TokenStream {
+        let repr = self.bridge.rust_to_ffi_repr(expression);
+        self.repr
+            .convert_rust_expression_to_ffi_type(&repr, swift_bridge_path, types, span)
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+    ) -> TokenStream {
+        let repr = self.bridge.rust_to_ffi_repr(&quote! { val });
+        self.option_repr().convert_rust_expression_to_ffi_type(
+            &quote! { (#expression).map(|val| #repr) },
+            swift_bridge_path,
+        )
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        types: &TypeDeclarations,
+        type_pos: TypePosition,
+    ) -> String {
+        let repr = self.bridge.swift_to_ffi_repr(expression);
+        self.repr
+            .convert_swift_expression_to_ffi_type(&repr, types, type_pos)
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        type_pos: TypePosition,
+    ) -> String {
+        let repr = self.bridge.swift_to_ffi_repr("$0");
+        self.option_repr().convert_swift_expression_to_ffi_type(
+            &format!("{expression}.map {{ {repr} }}"),
+            type_pos,
+        )
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        span: Span,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let repr = self.repr.convert_ffi_expression_to_rust_type(
+            expression,
+            span,
+            swift_bridge_path,
+            types,
+        );
+        self.bridge.rust_from_ffi_repr(&repr)
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(&self, expression: &TokenStream) -> TokenStream {
+        let repr = self
+            .option_repr()
+            .convert_ffi_expression_to_rust_type(expression);
+        let value = self.bridge.rust_from_ffi_repr(&quote! { val });
+        quote! { (#repr).map(|val| #value) }
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        type_pos: TypePosition,
+        types: &TypeDeclarations,
+    ) -> String {
+        let repr = self
+            .repr
+            .convert_ffi_expression_to_swift_type(expression, type_pos, types);
+        self.bridge.swift_from_ffi_repr(&repr)
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, expression: &str) -> String {
+        let repr = self
+            .option_repr()
+            .convert_ffi_expression_to_swift_type(expression);
+        let value = self.bridge.swift_from_ffi_repr("$0");
+        format!("{repr}.map {{ {value} }}")
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        ok_ffi_value: &TokenStream,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let repr = self.repr.convert_ffi_result_ok_value_to_rust_value(
+            ok_ffi_value,
+            swift_bridge_path,
+            types,
+        );
+        self.bridge.rust_from_ffi_repr(&repr)
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        err_ffi_value: &TokenStream,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let repr = self.repr.convert_ffi_result_err_value_to_rust_value(
+            err_ffi_value,
+            swift_bridge_path,
+            types,
+        );
+        self.bridge.rust_from_ffi_repr(&repr)
+    }
+
+    fn unused_option_none_val(&self, swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        self.repr.unused_option_none_val(swift_bridge_path)
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        find_custom_type_bridge(tokens).is_some()
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => Self::parse_token_stream_str(
+                path.path.to_token_stream().to_string().as_str(),
+                types,
+            ),
+            _ => None,
+        }
+    }
+
+    /// A bridge module's own declaration of a type takes precedence over a registered bridge.
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if types.get(tokens).is_some() {
+            return None;
+        }
+
+        let bridge = find_custom_type_bridge(tokens)?;
+        let ffi_repr = bridge.ffi_repr();
+        let repr: Type = syn::parse2(ffi_repr.clone()).ok()?;
+        let repr = BridgedType::new_with_type(&repr, &TypeDeclarations::default())?;
+        let option_repr: Type = syn::parse2(quote! { Option<#ffi_repr> }).ok()?;
+        let option_repr = BridgedType::new_with_type(&option_repr, &TypeDeclarations::default())?;
+
+        Some(BridgeableCustomType {
+            bridge,
+            repr: Box::new(repr),
+            option_repr: Box::new(option_repr),
+        })
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, types: &TypeDeclarations) -> bool {
+        self.repr.contains_owned_string_recursive(types)
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        self.repr.contains_ref_string_recursive()
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    /// `chrono::DateTime<chrono::Utc>` -> `chrono_DateTime_chrono_Utc`
+    fn to_alpha_numeric_underscore_name(&self, _types: &TypeDeclarations) -> String {
+        self.bridge
+            .rust_type()
+            .to_string()
+            .split(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+            .filter(|segment| !segment.is_empty())
+            .collect::<Vec<_>>()
+            .join("_")
+    }
+}
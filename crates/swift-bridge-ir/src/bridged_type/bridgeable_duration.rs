@@ -0,0 +1,270 @@
+use crate::bridged_type::{
+    BridgeableType, BuiltInResult, CFfiStruct, OnlyEncoding, TypePosition, UnusedOptionNoneValue,
+};
+use crate::TypeDeclarations;
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{Path, Type};
+
+/// std::time::Duration
+///
+/// A `Duration` is seen on the Swift side as a `Double` number of seconds, which is what
+/// Foundation's `TimeInterval` is, and is passed over FFI as an `f64`.
+///
+/// Converting the `f64` back into a `Duration` saturates, since panicking would abort the process
+/// when we're called from Swift. A negative or NaN number of seconds becomes `Duration::ZERO`, and
+/// a number of seconds that is too large to fit, such as infinity, becomes `Duration::MAX`.
+#[derive(Debug)]
+pub(crate) struct BridgeableDuration;
+
+impl BridgeableDuration {
+    /// Convert an `f64` number of seconds expression into a `Duration`.
+    fn from_secs_f64(expression: &TokenStream) -> TokenStream {
+        quote! {
+            {
+                let secs: f64 = #expression;
+                std::time::Duration::try_from_secs_f64(secs).unwrap_or(if secs > 0. {
+                    std::time::Duration::MAX
+                } else {
+                    std::time::Duration::ZERO
+                })
+            }
+        }
+    }
+}
+
+impl BridgeableType for BridgeableDuration {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        false
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    fn to_rust_type_path(&self, _types: &TypeDeclarations) -> TokenStream {
+        quote! { std::time::Duration }
+    }
+
+    fn to_swift_type(&self, _type_pos: TypePosition, _types: &TypeDeclarations) -> String {
+        "Double".to_string()
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        "double".to_string()
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        None
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote! { f64 }
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote! { #swift_bridge_path::option::OptionF64 }
+    }
+
+    fn to_ffi_compatible_option_swift_type(&self, _types: &TypeDeclarations) -> String {
+        "__private__OptionF64".to_string()
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        "struct __private__OptionF64".to_string()
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        _span: Span,
+    ) -> TokenStream {
+        quote! { #expression.as_secs_f64() }
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+    ) -> TokenStream {
+        quote! {
+            if let Some(val) = #expression {
+                #swift_bridge_path::option::OptionF64 { val: val.as_secs_f64(), is_some: true }
+            } else {
+                #swift_bridge_path::option::OptionF64 { val: 123.4, is_some: false }
+            }
+        }
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        expression.to_string()
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!("{expression}.intoFfiRepr()")
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        _span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        Self::from_secs_f64(expression)
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(&self, expression: &TokenStream) -> TokenStream {
+        let duration = Self::from_secs_f64(&quote! { val.val });
+        quote! {
+            {
+                let val = #expression;
+                if val.is_some {
+                    Some(#duration)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+        _types: &TypeDeclarations,
+    ) -> String {
+        expression.to_string()
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, expression: &str) -> String {
+        format!("{expression}.intoSwiftRepr()")
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        ok_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        Self::from_secs_f64(ok_ffi_value)
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        err_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        Self::from_secs_f64(err_ffi_value)
+    }
+
+    fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        UnusedOptionNoneValue {
+            rust: quote! { 123.4 },
+            swift: "123.4".into(),
+        }
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        tokens == "Duration"
+            || tokens == "std :: time :: Duration"
+            || tokens == "core :: time :: Duration"
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => Self::parse_token_stream_str(
+                path.path.to_token_stream().to_string().as_str(),
+                types,
+            ),
+            _ => None,
+        }
+    }
+
+    /// A bridge module can declare its own `type Duration`, in which case a `Duration` refers to
+    /// that type instead of the standard library's.
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_parse_token_stream_str(tokens) && types.get(tokens).is_none() {
+            Some(BridgeableDuration)
+        } else {
+            None
+        }
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, _types: &TypeDeclarations) -> String {
+        "Duration".to_string()
+    }
+}
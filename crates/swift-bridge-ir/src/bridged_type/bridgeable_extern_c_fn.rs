@@ -0,0 +1,403 @@
+use crate::bridged_type::built_in_primitive::BuiltInPrimitive;
+use crate::bridged_type::{
+    BridgeableType, BridgedType, BuiltInResult, CFfiStruct, OnlyEncoding, StdLibType, TypePosition,
+    UnusedOptionNoneValue,
+};
+use crate::parse::HostLang;
+use crate::TypeDeclarations;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{BareFnArg, Path, ReturnType, Type, TypeBareFn};
+
+/// extern "C" fn(A, B) -> C
+///
+/// A plain function pointer gets passed over FFI as is, so we only support function pointers whose
+/// parameters and return type are primitives that have the same representation in Rust, Swift and
+/// C.
+#[derive(Debug)]
+pub(crate) struct BridgeableExternCFn {
+    /// The function's parameters.
+    pub params: Vec<BridgedType>,
+    /// The function's return type.
+    pub ret: Box<BridgedType>,
+}
+
+impl BridgeableExternCFn {
+    /// Parse an `extern "C" fn(A, B) -> C`.
+    ///
+    /// Returns `None` if the function pointer is not `extern "C"`, is `unsafe`, is variadic, is
+    /// generic, or if any of its parameters or its return type are not primitives.
+    pub fn from_bare_fn(bare_fn: &TypeBareFn, types: &TypeDeclarations) -> Option<Self> {
+        let abi_is_c = bare_fn
+            .abi
+            .as_ref()
+            .and_then(|abi| abi.name.as_ref())
+            .map(|name| name.value() == "C")
+            .unwrap_or(false);
+        if !abi_is_c
+            || bare_fn.unsafety.is_some()
+            || bare_fn.variadic.is_some()
+            || bare_fn.lifetimes.is_some()
+        {
+            return None;
+        }
+
+        let params = bare_fn
+            .inputs
+            .iter()
+            .map(|BareFnArg { ty, .. }| primitive_with_type(ty, types))
+            .collect::<Option<Vec<BridgedType>>>()?;
+
+        let ret = match &bare_fn.output {
+            ReturnType::Default => BridgedType::StdLib(StdLibType::Null),
+            ReturnType::Type(_, ty) => primitive_with_type(ty, types)?,
+        };
+
+        Some(BridgeableExternCFn {
+            params,
+            ret: Box::new(ret),
+        })
+    }
+}
+
+/// Declare a C variable, parameter or function with the given C type.
+///
+/// `c_declaration("uint8_t", "arg")` -> `uint8_t arg`
+/// `c_declaration("uint8_t (*)(uint16_t)", "arg")` -> `uint8_t (*arg)(uint16_t)`
+pub(crate) fn c_declaration(c_type: &str, declarator: &str) -> String {
+    if c_type.contains("(*)") {
+        c_type.replacen("(*)", &format!("(*{})", declarator), 1)
+    } else {
+        format!("{} {}", c_type, declarator)
+    }
+}
+
+/// Parse the type if it is a primitive such as a `u8` or a `bool`.
+fn primitive_with_type(ty: &Type, types: &TypeDeclarations) -> Option<BridgedType> {
+    match BridgedType::new_with_type(ty, types)? {
+        BridgedType::StdLib(stdlib_type) => {
+            BuiltInPrimitive::new_with_stdlib_type(&stdlib_type)?;
+            Some(BridgedType::StdLib(stdlib_type))
+        }
+        _ => None,
+    }
+}
+
+impl BridgeableType for BridgeableExternCFn {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        false
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    fn to_rust_type_path(&self, types: &TypeDeclarations) -> TokenStream {
+        let params = self.params.iter().map(|p| p.to_rust_type_path(types));
+        let ret = if self.ret.is_null() {
+            quote! {}
+        } else {
+            let ret = self.ret.to_rust_type_path(types);
+            quote! { -> #ret }
+        };
+
+        quote! { extern "C" fn(#(#params),*) #ret }
+    }
+
+    fn to_swift_type(&self, type_pos: TypePosition, types: &TypeDeclarations) -> String {
+        let params = self
+            .params
+            .iter()
+            .map(|p| p.to_swift_type(type_pos, types))
+            .collect::<Vec<String>>()
+            .join(", ");
+        let ret = if self.ret.is_null() {
+            "()".to_string()
+        } else {
+            self.ret.to_swift_type(type_pos, types)
+        };
+
+        format!("@convention(c) ({params}) -> {ret}")
+    }
+
+    /// `uint8_t (*)(uint16_t, uint32_t)`
+    ///
+    /// The name of a function pointer parameter goes inside of the `(*)`.
+    fn to_c_type(&self, types: &TypeDeclarations) -> String {
+        let params = if self.params.is_empty() {
+            "void".to_string()
+        } else {
+            self.params
+                .iter()
+                .map(|p| p.to_c(types))
+                .collect::<Vec<String>>()
+                .join(", ")
+        };
+        let ret = self.ret.to_c(types);
+
+        format!("{ret} (*)({params})")
+    }
+
+    fn to_c_include(&self, types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        let mut includes = vec![];
+        for ty in self.params.iter().chain(std::iter::once(self.ret.as_ref())) {
+            for include in ty.to_c_include(types).into_iter().flatten() {
+                if !includes.contains(&include) {
+                    includes.push(include);
+                }
+            }
+        }
+
+        if includes.is_empty() {
+            None
+        } else {
+            Some(includes)
+        }
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        self.to_rust_type_path(types)
+    }
+
+    /// An `Option<extern "C" fn>` has the same representation as a nullable C function pointer.
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = self.to_rust_type_path(types);
+        quote! { Option<#ty> }
+    }
+
+    fn to_ffi_compatible_option_swift_type(&self, types: &TypeDeclarations) -> String {
+        format!(
+            "Optional<{}>",
+            self.to_swift_type(TypePosition::SharedStructField, types)
+        )
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        // The parameters and return type are primitives, which don't need the module's types.
+        self.to_c_type(&TypeDeclarations::default())
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        _span: Span,
+    ) -> TokenStream {
+        expression.clone()
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        expression.clone()
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        expression.to_string()
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        expression.to_string()
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        _span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        expression.clone()
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(&self, expression: &TokenStream) -> TokenStream {
+        expression.clone()
+    }
+
+    /// Swift imports C function pointers as optionals, so function pointers that we get from a C
+    /// function's return value or from a C struct's field need to be unwrapped.
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        type_pos: TypePosition,
+        _types: &TypeDeclarations,
+    ) -> String {
+        match type_pos {
+            TypePosition::FnReturn(HostLang::Rust) | TypePosition::SharedStructField => {
+                format!("{}!", expression)
+            }
+            _ => expression.to_string(),
+        }
+    }
+
+    /// Swift imports a nullable C function pointer as an optional.
+    fn convert_ffi_option_expression_to_swift_type(&self, expression: &str) -> String {
+        expression.to_string()
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        _ok_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Result<extern \"C\" fn, E> is not yet supported")
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        _err_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Result<T, extern \"C\" fn> is not yet supported")
+    }
+
+    fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        UnusedOptionNoneValue {
+            rust: quote! { None },
+            swift: "nil".into(),
+        }
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        tokens.starts_with("extern \"C\" fn")
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::BareFn(bare_fn) => Self::from_bare_fn(bare_fn, types),
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let ty: Type = syn::parse_str(tokens).ok()?;
+        Self::from_type(&ty, types)
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, _types: &TypeDeclarations) -> String {
+        todo!("Generic types that contain an extern \"C\" fn are not yet supported")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::quote;
+
+    /// Verify that we parse an `extern "C" fn` that has primitive params and a primitive return
+    /// type.
+    #[test]
+    fn parse_extern_c_fn() {
+        let ty: Type = syn::parse2(quote! { extern "C" fn(u8, bool) -> u32 }).unwrap();
+
+        let extern_c_fn =
+            BridgeableExternCFn::from_type(&ty, &TypeDeclarations::default()).unwrap();
+
+        assert_eq!(extern_c_fn.params.len(), 2);
+        assert!(matches!(
+            *extern_c_fn.ret,
+            BridgedType::StdLib(StdLibType::U32)
+        ));
+        assert_eq!(
+            extern_c_fn.to_c_type(&TypeDeclarations::default()),
+            "uint32_t (*)(uint8_t, bool)"
+        );
+    }
+
+    /// Verify that we only support safe `extern "C"` function pointers that use primitive types.
+    #[test]
+    fn unsupported_fn_pointers() {
+        let tests = vec![
+            quote! { fn(u8) -> u8 },
+            quote! { extern "Rust" fn(u8) -> u8 },
+            quote! { unsafe extern "C" fn(u8) -> u8 },
+            quote! { extern "C" fn(String) },
+            quote! { extern "C" fn() -> Vec<u8> },
+        ];
+
+        for test in tests {
+            let ty: Type = syn::parse2(test.clone()).unwrap();
+
+            assert!(
+                BridgeableExternCFn::from_type(&ty, &TypeDeclarations::default()).is_none(),
+                "{}",
+                test
+            );
+        }
+    }
+}
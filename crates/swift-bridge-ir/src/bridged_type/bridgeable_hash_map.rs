@@ -0,0 +1,649 @@
+use crate::bridged_type::built_in_primitive::BuiltInPrimitive;
+use crate::bridged_type::{
+    BridgeableType, BridgedOption, BridgedType, BuiltInResult, CFfiStruct, OnlyEncoding,
+    StdLibType, TypePosition, UnusedOptionNoneValue,
+};
+use crate::parse::HostLang;
+use crate::TypeDeclarations;
+use crate::SWIFT_BRIDGE_PREFIX;
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{GenericArgument, Path, PathArguments, Type};
+
+/// HashMap<K, V>
+///
+/// On the Swift side a `HashMap<K, V>` is a `RustMap<K, V>`. `RustMap` is a generic class that
+/// lives in the core Swift code, and each bridge module that uses a `HashMap<K, V>` generates the
+/// FFI functions and the `extension RustMap where K == .., V == ..` that power it.
+///
+/// Keys and values can be primitives or `String`s. Floats cannot be used as keys since they do not
+/// implement `Eq` and `Hash`.
+#[derive(Debug)]
+pub(crate) struct BridgeableHashMap {
+    /// The map's key type.
+    pub key: Box<BridgedType>,
+    /// The map's value type.
+    pub value: Box<BridgedType>,
+    /// `Option<V>`, which is what `get`, `insert` and `remove` return.
+    optional_value: Box<BridgedType>,
+}
+
+impl BridgeableHashMap {
+    /// Parse a `HashMap<K, V>` or a `std::collections::HashMap<K, V>`.
+    pub fn from_type_path(ty: &syn::TypePath, types: &TypeDeclarations) -> Option<Self> {
+        if ty.qself.is_some() {
+            return None;
+        }
+
+        let segments = ty
+            .path
+            .segments
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .collect::<Vec<String>>();
+        if segments != ["HashMap"] && segments != ["std", "collections", "HashMap"] {
+            return None;
+        }
+
+        let generics = match &ty.path.segments.last()?.arguments {
+            PathArguments::AngleBracketed(generics) => generics,
+            _ => return None,
+        };
+        let mut generic_types = generics.args.iter().map(|arg| match arg {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        });
+        let key_ty = generic_types.next()??;
+        let value_ty = generic_types.next()??;
+        if generic_types.next().is_some() {
+            return None;
+        }
+
+        let key = map_item_with_type(key_ty, types)?;
+        if matches!(
+            key,
+            BridgedType::StdLib(StdLibType::F32) | BridgedType::StdLib(StdLibType::F64)
+        ) {
+            return None;
+        }
+
+        let value = map_item_with_type(value_ty, types)?;
+        let optional_value = BridgedType::StdLib(StdLibType::Option(BridgedOption {
+            ty: Box::new(map_item_with_type(value_ty, types)?),
+        }));
+
+        Some(BridgeableHashMap {
+            key: Box::new(key),
+            value: Box::new(value),
+            optional_value: Box::new(optional_value),
+        })
+    }
+
+    /// HashMap<u32, String> -> "HashMap_u32_String"
+    fn ffi_name(&self, types: &TypeDeclarations) -> String {
+        format!(
+            "HashMap_{}_{}",
+            self.key.to_rust_type_path(types),
+            self.value.to_rust_type_path(types)
+        )
+    }
+
+    /// HashMap<u32, String>, "get" -> "__swift_bridge__$HashMap_u32_String$get"
+    fn ffi_func_name(&self, func: &str, types: &TypeDeclarations) -> String {
+        format!("{}${}${}", SWIFT_BRIDGE_PREFIX, self.ffi_name(types), func)
+    }
+
+    /// The name of the Swift type when it is used as a `RustMap` generic argument.
+    fn swift_generic_arg(ty: &BridgedType, types: &TypeDeclarations) -> String {
+        ty.to_swift_type(TypePosition::FnReturn(HostLang::Rust), types)
+    }
+
+    /// The name of the Swift type when it is passed to one of the `RustMap`'s methods.
+    ///
+    /// `String`s are passed to Rust as Swift `String`s.
+    fn swift_param_ty(ty: &BridgedType, types: &TypeDeclarations) -> String {
+        if ty.contains_owned_string_recursive(types) {
+            "String".to_string()
+        } else {
+            ty.to_swift_type(TypePosition::FnArg(HostLang::Rust, 0), types)
+        }
+    }
+}
+
+/// Parse a type that we support as a key or a value, which is either a primitive or a `String`.
+///
+/// This is also used for the elements of a `HashSet<T>`.
+pub(super) fn map_item_with_type(ty: &Type, types: &TypeDeclarations) -> Option<BridgedType> {
+    let item = BridgedType::new_with_type(ty, types)?;
+
+    let is_supported = match &item {
+        BridgedType::StdLib(StdLibType::Null) => false,
+        BridgedType::StdLib(stdlib_type) => {
+            BuiltInPrimitive::new_with_stdlib_type(stdlib_type).is_some()
+        }
+        BridgedType::Bridgeable(b) => {
+            b.is_built_in_type() && b.contains_owned_string_recursive(types)
+        }
+        BridgedType::Foreign(_) => false,
+    };
+
+    if is_supported {
+        Some(item)
+    } else {
+        None
+    }
+}
+
+impl BridgeableType for BridgeableHashMap {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        true
+    }
+
+    /// Generate the functions that Swift uses to create and work with the map.
+    fn generate_custom_rust_ffi_types(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        let map_ty = self.to_rust_type_path(types);
+        let span = Span::call_site();
+
+        let ffi_name = self.ffi_name(types);
+        let export_name = |func: &str| self.ffi_func_name(func, types);
+        let func_ident = |func: &str| format_ident!("{}{}_{}", SWIFT_BRIDGE_PREFIX, ffi_name, func);
+
+        let new_export_name = export_name("new");
+        let free_export_name = export_name("_free");
+        let len_export_name = export_name("len");
+        let get_export_name = export_name("get");
+        let insert_export_name = export_name("insert");
+        let remove_export_name = export_name("remove");
+
+        let new_ident = func_ident("new");
+        let free_ident = func_ident("_free");
+        let len_ident = func_ident("len");
+        let get_ident = func_ident("get");
+        let insert_ident = func_ident("insert");
+        let remove_ident = func_ident("remove");
+
+        let ffi_key_ty = self
+            .key
+            .to_ffi_compatible_rust_type(swift_bridge_path, types);
+        let ffi_value_ty = self
+            .value
+            .to_ffi_compatible_rust_type(swift_bridge_path, types);
+        let ffi_optional_value_ty = self
+            .optional_value
+            .to_ffi_compatible_rust_type(swift_bridge_path, types);
+
+        let key = self.key.convert_ffi_expression_to_rust_type(
+            &quote! { key },
+            span,
+            swift_bridge_path,
+            types,
+        );
+        let value = self.value.convert_ffi_expression_to_rust_type(
+            &quote! { value },
+            span,
+            swift_bridge_path,
+            types,
+        );
+        let convert_optional_value = |expression: TokenStream| {
+            self.optional_value.convert_rust_expression_to_ffi_type(
+                &expression,
+                swift_bridge_path,
+                types,
+                span,
+            )
+        };
+        let got = convert_optional_value(quote! { map.get(&#key).cloned() });
+        let inserted = convert_optional_value(quote! { map.insert(#key, #value) });
+        let removed = convert_optional_value(quote! { map.remove(&#key) });
+
+        Some(vec![quote! {
+            #[export_name = #new_export_name]
+            #[doc(hidden)]
+            pub extern "C" fn #new_ident() -> *mut #map_ty {
+                Box::into_raw(Box::new(std::collections::HashMap::new()))
+            }
+
+            #[export_name = #free_export_name]
+            #[doc(hidden)]
+            pub extern "C" fn #free_ident(map: *mut #map_ty) {
+                let _ = unsafe { Box::from_raw(map) };
+            }
+
+            #[export_name = #len_export_name]
+            #[doc(hidden)]
+            pub extern "C" fn #len_ident(map: *const #map_ty) -> usize {
+                unsafe { &*map }.len()
+            }
+
+            #[export_name = #get_export_name]
+            #[doc(hidden)]
+            pub extern "C" fn #get_ident(map: *const #map_ty, key: #ffi_key_ty) -> #ffi_optional_value_ty {
+                let map = unsafe { &*map };
+                #got
+            }
+
+            #[export_name = #insert_export_name]
+            #[doc(hidden)]
+            pub extern "C" fn #insert_ident(
+                map: *mut #map_ty,
+                key: #ffi_key_ty,
+                value: #ffi_value_ty
+            ) -> #ffi_optional_value_ty {
+                let map = unsafe { &mut *map };
+                #inserted
+            }
+
+            #[export_name = #remove_export_name]
+            #[doc(hidden)]
+            pub extern "C" fn #remove_ident(map: *mut #map_ty, key: #ffi_key_ty) -> #ffi_optional_value_ty {
+                let map = unsafe { &mut *map };
+                #removed
+            }
+        }])
+    }
+
+    fn generate_custom_c_ffi_types(&self, types: &TypeDeclarations) -> Option<CFfiStruct> {
+        let key = self.key.to_c(types);
+        let value = self.value.to_c(types);
+        let optional_value = self.optional_value.to_c(types);
+
+        let new = self.ffi_func_name("new", types);
+        let free = self.ffi_func_name("_free", types);
+        let len = self.ffi_func_name("len", types);
+        let get = self.ffi_func_name("get", types);
+        let insert = self.ffi_func_name("insert", types);
+        let remove = self.ffi_func_name("remove", types);
+
+        let c_decl = format!(
+            r#"void* {new}(void);
+void {free}(void* map);
+uintptr_t {len}(void* map);
+{optional_value} {get}(void* map, {key} key);
+{optional_value} {insert}(void* map, {key} key, {value} value);
+{optional_value} {remove}(void* map, {key} key);"#
+        );
+
+        Some(CFfiStruct {
+            c_ffi_type: c_decl,
+            fields: vec![],
+        })
+    }
+
+    /// Generate the `extension RustMap where K == .., V == ..` that holds the map's methods.
+    fn generate_custom_swift_code(&self, types: &TypeDeclarations) -> Option<String> {
+        let key_generic = Self::swift_generic_arg(&self.key, types);
+        let value_generic = Self::swift_generic_arg(&self.value, types);
+        let key_param = Self::swift_param_ty(&self.key, types);
+        let value_param = Self::swift_param_ty(&self.value, types);
+        let optional_value = self
+            .optional_value
+            .to_swift_type(TypePosition::FnReturn(HostLang::Rust), types);
+
+        let new = self.ffi_func_name("new", types);
+        let free = self.ffi_func_name("_free", types);
+        let len = self.ffi_func_name("len", types);
+        let get = self.ffi_func_name("get", types);
+        let insert = self.ffi_func_name("insert", types);
+        let remove = self.ffi_func_name("remove", types);
+
+        let key_arg = self.key.convert_swift_expression_to_ffi_type(
+            "key",
+            types,
+            TypePosition::FnArg(HostLang::Rust, 0),
+        );
+        let value_arg = self.value.convert_swift_expression_to_ffi_type(
+            "value",
+            types,
+            TypePosition::FnArg(HostLang::Rust, 1),
+        );
+        let convert_optional_value = |expression: &str| {
+            self.optional_value.convert_ffi_expression_to_swift_type(
+                expression,
+                TypePosition::FnReturn(HostLang::Rust),
+                types,
+            )
+        };
+        let got = convert_optional_value(&format!("{get}(ptr, {key_arg})"));
+        let inserted = convert_optional_value(&format!("{insert}(ptr, {key_arg}, {value_arg})"));
+        let removed = convert_optional_value(&format!("{remove}(ptr, {key_arg})"));
+
+        Some(format!(
+            r#"extension RustMap
+where K == {key_generic}, V == {value_generic} {{
+    public convenience init(ptr: UnsafeMutableRawPointer) {{
+        self.init(ptr: ptr, free: {{ {free}($0) }})
+    }}
+
+    public convenience init() {{
+        self.init(ptr: {new}())
+    }}
+
+    public convenience init(_ dictionary: [{key_param}: {value_param}]) {{
+        self.init()
+        for (key, value) in dictionary {{
+            self.insert(key, value)
+        }}
+    }}
+
+    /// Rust returns a UInt, but we cast to an Int to match Swift's `Dictionary.count`.
+    public var count: Int {{
+        Int({len}(ptr))
+    }}
+
+    public func get(_ key: {key_param}) -> {optional_value} {{
+        {got}
+    }}
+
+    @discardableResult
+    public func insert(_ key: {key_param}, _ value: {value_param}) -> {optional_value} {{
+        {inserted}
+    }}
+
+    @discardableResult
+    public func remove(_ key: {key_param}) -> {optional_value} {{
+        {removed}
+    }}
+}}
+"#
+        ))
+    }
+
+    fn to_rust_type_path(&self, types: &TypeDeclarations) -> TokenStream {
+        let key = self.key.to_rust_type_path(types);
+        let value = self.value.to_rust_type_path(types);
+
+        quote! { std::collections::HashMap<#key, #value> }
+    }
+
+    fn to_swift_type(&self, type_pos: TypePosition, types: &TypeDeclarations) -> String {
+        let rust_map = format!(
+            "RustMap<{}, {}>",
+            Self::swift_generic_arg(&self.key, types),
+            Self::swift_generic_arg(&self.value, types)
+        );
+
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
+                if func_host_lang.is_rust() {
+                    rust_map
+                } else {
+                    "UnsafeMutableRawPointer".to_string()
+                }
+            }
+            _ => rust_map,
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        "void*".to_string()
+    }
+
+    fn to_c_include(&self, types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        let mut includes = vec!["stdint.h"];
+        for ty in [&self.key, &self.optional_value] {
+            for include in ty.to_c_include(types).into_iter().flatten() {
+                if !includes.contains(&include) {
+                    includes.push(include);
+                }
+            }
+        }
+
+        Some(includes)
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = self.to_rust_type_path(types);
+        quote! { *mut #ty }
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        self.to_ffi_compatible_rust_type(swift_bridge_path, types)
+    }
+
+    fn to_ffi_compatible_option_swift_type(&self, _types: &TypeDeclarations) -> String {
+        "UnsafeMutableRawPointer?".to_string()
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        "void*".to_string()
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        _span: Span,
+    ) -> TokenStream {
+        quote! { Box::into_raw(Box::new( #expression )) }
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        quote! {
+            if let Some(val) = #expression {
+                Box::into_raw(Box::new(val))
+            } else {
+                std::ptr::null_mut()
+            }
+        }
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!(
+            "{{ let val = {value}; val.isOwned = false; return val.ptr }}()",
+            value = expression
+        )
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!(
+            "{{ if let val = {expression} {{ val.isOwned = false; return val.ptr }} else {{ return nil }} }}()"
+        )
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        _span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote! { unsafe { * Box::from_raw(#expression) } }
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(&self, expression: &TokenStream) -> TokenStream {
+        quote! {
+            {
+                let val = #expression;
+                if val.is_null() {
+                    None
+                } else {
+                    Some(unsafe { * Box::from_raw(val) })
+                }
+            }
+        }
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+        types: &TypeDeclarations,
+    ) -> String {
+        format!(
+            "{}(ptr: {})",
+            self.to_swift_type(TypePosition::FnReturn(HostLang::Rust), types),
+            expression
+        )
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, expression: &str) -> String {
+        format!(
+            "{{ let val = {expression}; if val != nil {{ return RustMap(ptr: val!) }} else {{ return nil }} }}()"
+        )
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        _ok_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Result<HashMap<K, V>, E> is rejected when it would be passed to Rust")
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        _err_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Result<T, HashMap<K, V>> is rejected when it would be passed to Rust")
+    }
+
+    fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        UnusedOptionNoneValue {
+            rust: quote! { std::ptr::null_mut() },
+            swift: "nil".to_string(),
+        }
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        tokens.starts_with("HashMap < ") || tokens.starts_with("std :: collections :: HashMap < ")
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => Self::from_type_path(path, types),
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let ty: Type = syn::parse_str(tokens).ok()?;
+        Self::from_type(&ty, types)
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, types: &TypeDeclarations) -> String {
+        self.ffi_name(types)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::quote;
+
+    /// Verify that we parse a `HashMap<K, V>` with or without its full path.
+    #[test]
+    fn parse_hash_map() {
+        let tests = vec![
+            quote! { HashMap<u32, String> },
+            quote! { std::collections::HashMap<u32, String> },
+        ];
+
+        for test in tests {
+            let ty: Type = syn::parse2(test.clone()).unwrap();
+
+            let map = BridgeableHashMap::from_type(&ty, &TypeDeclarations::default()).unwrap();
+
+            assert!(matches!(*map.key, BridgedType::StdLib(StdLibType::U32)));
+            assert!(map
+                .value
+                .contains_owned_string_recursive(&TypeDeclarations::default()));
+        }
+    }
+
+    /// Verify that we only support primitive and `String` keys and values, and that we do not
+    /// support float keys.
+    #[test]
+    fn unsupported_hash_maps() {
+        let tests = vec![
+            quote! { HashMap<f32, u8> },
+            quote! { HashMap<u8, Vec<u8>> },
+            quote! { HashMap<(), u8> },
+            quote! { HashMap<u8> },
+            quote! { some_crate::HashMap<u8, u8> },
+        ];
+
+        for test in tests {
+            let ty: Type = syn::parse2(test.clone()).unwrap();
+
+            assert!(
+                BridgeableHashMap::from_type(&ty, &TypeDeclarations::default()).is_none(),
+                "{}",
+                test
+            );
+        }
+    }
+}
@@ -0,0 +1,578 @@
+use crate::bridged_type::bridgeable_hash_map::map_item_with_type;
+use crate::bridged_type::{
+    BridgeableType, BridgedType, BuiltInResult, CFfiStruct, OnlyEncoding, StdLibType, TypePosition,
+    UnusedOptionNoneValue,
+};
+use crate::parse::HostLang;
+use crate::TypeDeclarations;
+use crate::SWIFT_BRIDGE_PREFIX;
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{GenericArgument, Path, PathArguments, Type};
+
+/// HashSet<T>
+///
+/// On the Swift side a `HashSet<T>` is a `RustSet<T>`. Like a `RustMap`, `RustSet` is a generic
+/// class that lives in the core Swift code, and each bridge module that uses a `HashSet<T>`
+/// generates the FFI functions and the `extension RustSet where T == ..` that power it.
+///
+/// Elements can be primitives or `String`s. Floats cannot be used since they do not implement `Eq`
+/// and `Hash`.
+#[derive(Debug)]
+pub(crate) struct BridgeableHashSet {
+    /// The set's element type.
+    pub ty: Box<BridgedType>,
+}
+
+impl BridgeableHashSet {
+    /// Parse a `HashSet<T>` or a `std::collections::HashSet<T>`.
+    pub fn from_type_path(ty: &syn::TypePath, types: &TypeDeclarations) -> Option<Self> {
+        if ty.qself.is_some() {
+            return None;
+        }
+
+        let segments = ty
+            .path
+            .segments
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .collect::<Vec<String>>();
+        if segments != ["HashSet"] && segments != ["std", "collections", "HashSet"] {
+            return None;
+        }
+
+        let generics = match &ty.path.segments.last()?.arguments {
+            PathArguments::AngleBracketed(generics) => generics,
+            _ => return None,
+        };
+        if generics.args.len() != 1 {
+            return None;
+        }
+        let elem_ty = match generics.args.first()? {
+            GenericArgument::Type(ty) => ty,
+            _ => return None,
+        };
+
+        let elem = map_item_with_type(elem_ty, types)?;
+        if matches!(
+            elem,
+            BridgedType::StdLib(StdLibType::F32) | BridgedType::StdLib(StdLibType::F64)
+        ) {
+            return None;
+        }
+
+        Some(BridgeableHashSet { ty: Box::new(elem) })
+    }
+
+    /// HashSet<String> -> "HashSet_String"
+    fn ffi_name(&self, types: &TypeDeclarations) -> String {
+        format!("HashSet_{}", self.ty.to_rust_type_path(types))
+    }
+
+    /// HashSet<String>, "insert" -> "__swift_bridge__$HashSet_String$insert"
+    fn ffi_func_name(&self, func: &str, types: &TypeDeclarations) -> String {
+        format!("{}${}${}", SWIFT_BRIDGE_PREFIX, self.ffi_name(types), func)
+    }
+
+    /// The name of the Swift type when it is used as a `RustSet` generic argument.
+    fn swift_generic_arg(&self, types: &TypeDeclarations) -> String {
+        self.ty
+            .to_swift_type(TypePosition::FnReturn(HostLang::Rust), types)
+    }
+
+    /// The name of the Swift type when it is passed to one of the `RustSet`'s methods, or stored
+    /// in a Swift `Set`.
+    ///
+    /// `String`s are passed to Rust as Swift `String`s.
+    fn swift_elem_ty(&self, types: &TypeDeclarations) -> String {
+        if self.ty.contains_owned_string_recursive(types) {
+            "String".to_string()
+        } else {
+            self.ty
+                .to_swift_type(TypePosition::FnArg(HostLang::Rust, 0), types)
+        }
+    }
+}
+
+impl BridgeableType for BridgeableHashSet {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        true
+    }
+
+    /// Generate the functions that Swift uses to create and work with the set.
+    ///
+    /// `to_vec` copies the set's elements into a `Vec` so that Swift can read them out of a
+    /// `RustVec`. `String`s are wrapped in a `RustString` since that is what a `RustVec` holds.
+    fn generate_custom_rust_ffi_types(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        let set_ty = self.to_rust_type_path(types);
+        let span = Span::call_site();
+
+        let ffi_name = self.ffi_name(types);
+        let export_name = |func: &str| self.ffi_func_name(func, types);
+        let func_ident = |func: &str| format_ident!("{}{}_{}", SWIFT_BRIDGE_PREFIX, ffi_name, func);
+
+        let new_export_name = export_name("new");
+        let free_export_name = export_name("_free");
+        let len_export_name = export_name("len");
+        let contains_export_name = export_name("contains");
+        let insert_export_name = export_name("insert");
+        let remove_export_name = export_name("remove");
+        let to_vec_export_name = export_name("to_vec");
+
+        let new_ident = func_ident("new");
+        let free_ident = func_ident("_free");
+        let len_ident = func_ident("len");
+        let contains_ident = func_ident("contains");
+        let insert_ident = func_ident("insert");
+        let remove_ident = func_ident("remove");
+        let to_vec_ident = func_ident("to_vec");
+
+        let ffi_elem_ty = self
+            .ty
+            .to_ffi_compatible_rust_type(swift_bridge_path, types);
+        let value = self.ty.convert_ffi_expression_to_rust_type(
+            &quote! { value },
+            span,
+            swift_bridge_path,
+            types,
+        );
+
+        let (vec_elem_ty, to_vec_elem) = if self.ty.contains_owned_string_recursive(types) {
+            (
+                quote! { #swift_bridge_path::string::RustString },
+                quote! { #swift_bridge_path::string::RustString(elem.clone()) },
+            )
+        } else {
+            (self.ty.to_rust_type_path(types), quote! { *elem })
+        };
+
+        Some(vec![quote! {
+            #[export_name = #new_export_name]
+            #[doc(hidden)]
+            pub extern "C" fn #new_ident() -> *mut #set_ty {
+                Box::into_raw(Box::new(std::collections::HashSet::new()))
+            }
+
+            #[export_name = #free_export_name]
+            #[doc(hidden)]
+            pub extern "C" fn #free_ident(set: *mut #set_ty) {
+                let _ = unsafe { Box::from_raw(set) };
+            }
+
+            #[export_name = #len_export_name]
+            #[doc(hidden)]
+            pub extern "C" fn #len_ident(set: *const #set_ty) -> usize {
+                unsafe { &*set }.len()
+            }
+
+            #[export_name = #contains_export_name]
+            #[doc(hidden)]
+            pub extern "C" fn #contains_ident(set: *const #set_ty, value: #ffi_elem_ty) -> bool {
+                unsafe { &*set }.contains(&#value)
+            }
+
+            #[export_name = #insert_export_name]
+            #[doc(hidden)]
+            pub extern "C" fn #insert_ident(set: *mut #set_ty, value: #ffi_elem_ty) -> bool {
+                unsafe { &mut *set }.insert(#value)
+            }
+
+            #[export_name = #remove_export_name]
+            #[doc(hidden)]
+            pub extern "C" fn #remove_ident(set: *mut #set_ty, value: #ffi_elem_ty) -> bool {
+                unsafe { &mut *set }.remove(&#value)
+            }
+
+            #[export_name = #to_vec_export_name]
+            #[doc(hidden)]
+            pub extern "C" fn #to_vec_ident(set: *const #set_ty) -> *mut Vec<#vec_elem_ty> {
+                let vec: Vec<#vec_elem_ty> = unsafe { &*set }.iter().map(|elem| #to_vec_elem).collect();
+                Box::into_raw(Box::new(vec))
+            }
+        }])
+    }
+
+    fn generate_custom_c_ffi_types(&self, types: &TypeDeclarations) -> Option<CFfiStruct> {
+        let elem = self.ty.to_c(types);
+
+        let new = self.ffi_func_name("new", types);
+        let free = self.ffi_func_name("_free", types);
+        let len = self.ffi_func_name("len", types);
+        let contains = self.ffi_func_name("contains", types);
+        let insert = self.ffi_func_name("insert", types);
+        let remove = self.ffi_func_name("remove", types);
+        let to_vec = self.ffi_func_name("to_vec", types);
+
+        let c_decl = format!(
+            r#"void* {new}(void);
+void {free}(void* set);
+uintptr_t {len}(void* set);
+bool {contains}(void* set, {elem} value);
+bool {insert}(void* set, {elem} value);
+bool {remove}(void* set, {elem} value);
+void* {to_vec}(void* set);"#
+        );
+
+        Some(CFfiStruct {
+            c_ffi_type: c_decl,
+            fields: vec![],
+        })
+    }
+
+    /// Generate the `extension RustSet where T == ..` that holds the set's methods.
+    fn generate_custom_swift_code(&self, types: &TypeDeclarations) -> Option<String> {
+        let generic = self.swift_generic_arg(types);
+        let elem = self.swift_elem_ty(types);
+
+        let new = self.ffi_func_name("new", types);
+        let free = self.ffi_func_name("_free", types);
+        let len = self.ffi_func_name("len", types);
+        let contains = self.ffi_func_name("contains", types);
+        let insert = self.ffi_func_name("insert", types);
+        let remove = self.ffi_func_name("remove", types);
+        let to_vec = self.ffi_func_name("to_vec", types);
+
+        let value_arg = self.ty.convert_swift_expression_to_ffi_type(
+            "value",
+            types,
+            TypePosition::FnArg(HostLang::Rust, 0),
+        );
+        let to_vec_elems = if self.ty.contains_owned_string_recursive(types) {
+            ".map { $0.toString() }"
+        } else {
+            ""
+        };
+
+        Some(format!(
+            r#"extension RustSet
+where T == {generic} {{
+    public convenience init(ptr: UnsafeMutableRawPointer) {{
+        self.init(ptr: ptr, free: {{ {free}($0) }})
+    }}
+
+    public convenience init() {{
+        self.init(ptr: {new}())
+    }}
+
+    public convenience init(_ set: Set<{elem}>) {{
+        self.init()
+        for value in set {{
+            self.insert(value)
+        }}
+    }}
+
+    /// Rust returns a UInt, but we cast to an Int to match Swift's `Set.count`.
+    public var count: Int {{
+        Int({len}(ptr))
+    }}
+
+    public func contains(_ value: {elem}) -> Bool {{
+        {contains}(ptr, {value_arg})
+    }}
+
+    @discardableResult
+    public func insert(_ value: {elem}) -> Bool {{
+        {insert}(ptr, {value_arg})
+    }}
+
+    @discardableResult
+    public func remove(_ value: {elem}) -> Bool {{
+        {remove}(ptr, {value_arg})
+    }}
+
+    public func toSet() -> Set<{elem}> {{
+        Set(RustVec<{generic}>(ptr: {to_vec}(ptr)){to_vec_elems})
+    }}
+}}
+"#
+        ))
+    }
+
+    fn to_rust_type_path(&self, types: &TypeDeclarations) -> TokenStream {
+        let ty = self.ty.to_rust_type_path(types);
+
+        quote! { std::collections::HashSet<#ty> }
+    }
+
+    fn to_swift_type(&self, type_pos: TypePosition, types: &TypeDeclarations) -> String {
+        let rust_set = format!("RustSet<{}>", self.swift_generic_arg(types));
+
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
+                if func_host_lang.is_rust() {
+                    rust_set
+                } else {
+                    "UnsafeMutableRawPointer".to_string()
+                }
+            }
+            _ => rust_set,
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        "void*".to_string()
+    }
+
+    fn to_c_include(&self, types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        let mut includes = vec!["stdint.h", "stdbool.h"];
+        for include in self.ty.to_c_include(types).into_iter().flatten() {
+            if !includes.contains(&include) {
+                includes.push(include);
+            }
+        }
+
+        Some(includes)
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = self.to_rust_type_path(types);
+        quote! { *mut #ty }
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        self.to_ffi_compatible_rust_type(swift_bridge_path, types)
+    }
+
+    fn to_ffi_compatible_option_swift_type(&self, _types: &TypeDeclarations) -> String {
+        "UnsafeMutableRawPointer?".to_string()
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        "void*".to_string()
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        _span: Span,
+    ) -> TokenStream {
+        quote! { Box::into_raw(Box::new( #expression )) }
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        quote! {
+            if let Some(val) = #expression {
+                Box::into_raw(Box::new(val))
+            } else {
+                std::ptr::null_mut()
+            }
+        }
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!(
+            "{{ let val = {value}; val.isOwned = false; return val.ptr }}()",
+            value = expression
+        )
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!(
+            "{{ if let val = {expression} {{ val.isOwned = false; return val.ptr }} else {{ return nil }} }}()"
+        )
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        _span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote! { unsafe { * Box::from_raw(#expression) } }
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(&self, expression: &TokenStream) -> TokenStream {
+        quote! {
+            {
+                let val = #expression;
+                if val.is_null() {
+                    None
+                } else {
+                    Some(unsafe { * Box::from_raw(val) })
+                }
+            }
+        }
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+        types: &TypeDeclarations,
+    ) -> String {
+        format!(
+            "{}(ptr: {})",
+            self.to_swift_type(TypePosition::FnReturn(HostLang::Rust), types),
+            expression
+        )
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, expression: &str) -> String {
+        format!(
+            "{{ let val = {expression}; if val != nil {{ return RustSet(ptr: val!) }} else {{ return nil }} }}()"
+        )
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        _ok_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Result<HashSet<T>, E> is rejected when it would be passed to Rust")
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        _err_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Result<T, HashSet<T>> is rejected when it would be passed to Rust")
+    }
+
+    fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        UnusedOptionNoneValue {
+            rust: quote! { std::ptr::null_mut() },
+            swift: "nil".to_string(),
+        }
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        tokens.starts_with("HashSet < ") || tokens.starts_with("std :: collections :: HashSet < ")
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => Self::from_type_path(path, types),
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let ty: Type = syn::parse_str(tokens).ok()?;
+        Self::from_type(&ty, types)
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, types: &TypeDeclarations) -> String {
+        self.ffi_name(types)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::quote;
+
+    /// Verify that we parse a `HashSet<T>` with or without its full path.
+    #[test]
+    fn parse_hash_set() {
+        let tests = vec![
+            quote! { HashSet<u16> },
+            quote! { std::collections::HashSet<u16> },
+        ];
+
+        for test in tests {
+            let ty: Type = syn::parse2(test.clone()).unwrap();
+
+            let set = BridgeableHashSet::from_type(&ty, &TypeDeclarations::default()).unwrap();
+
+            assert!(matches!(*set.ty, BridgedType::StdLib(StdLibType::U16)));
+        }
+    }
+
+    /// Verify that we only support primitive and `String` elements, and that we do not support
+    /// float elements.
+    #[test]
+    fn unsupported_hash_sets() {
+        let tests = vec![
+            quote! { HashSet<f64> },
+            quote! { HashSet<Vec<u8>> },
+            quote! { HashSet<u8, u8> },
+            quote! { some_crate::HashSet<u8> },
+        ];
+
+        for test in tests {
+            let ty: Type = syn::parse2(test.clone()).unwrap();
+
+            assert!(
+                BridgeableHashSet::from_type(&ty, &TypeDeclarations::default()).is_none(),
+                "{}",
+                test
+            );
+        }
+    }
+}
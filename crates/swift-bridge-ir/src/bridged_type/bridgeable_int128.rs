@@ -0,0 +1,305 @@
+use crate::bridged_type::{
+    BridgeableType, BuiltInResult, CFfiStruct, OnlyEncoding, TypePosition, UnusedOptionNoneValue,
+};
+use crate::TypeDeclarations;
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{Path, Type};
+
+/// u128 or i128
+///
+/// C has no 128-bit integer, so the value gets passed over FFI as a struct that holds two 64-bit
+/// limbs. On the Swift side it is a `RustUInt128` or a `RustInt128`.
+#[derive(Debug, Copy, Clone)]
+pub(crate) enum BridgeableInt128 {
+    U128,
+    I128,
+}
+
+impl BridgeableInt128 {
+    /// "U128" or "I128"
+    fn name(&self) -> &'static str {
+        match self {
+            BridgeableInt128::U128 => "U128",
+            BridgeableInt128::I128 => "I128",
+        }
+    }
+
+    /// `swift_bridge::int128::U128`
+    fn ffi_struct(&self, swift_bridge_path: &Path) -> TokenStream {
+        match self {
+            BridgeableInt128::U128 => quote! { #swift_bridge_path::int128::U128 },
+            BridgeableInt128::I128 => quote! { #swift_bridge_path::int128::I128 },
+        }
+    }
+
+    /// `swift_bridge::option::OptionU128`
+    fn ffi_option_struct(&self, swift_bridge_path: &Path) -> TokenStream {
+        match self {
+            BridgeableInt128::U128 => quote! { #swift_bridge_path::option::OptionU128 },
+            BridgeableInt128::I128 => quote! { #swift_bridge_path::option::OptionI128 },
+        }
+    }
+}
+
+impl BridgeableType for BridgeableInt128 {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        false
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    fn to_rust_type_path(&self, _types: &TypeDeclarations) -> TokenStream {
+        match self {
+            BridgeableInt128::U128 => quote! { u128 },
+            BridgeableInt128::I128 => quote! { i128 },
+        }
+    }
+
+    fn to_swift_type(&self, type_pos: TypePosition, _types: &TypeDeclarations) -> String {
+        let swift_ty = match self {
+            BridgeableInt128::U128 => "RustUInt128",
+            BridgeableInt128::I128 => "RustInt128",
+        };
+        let ffi_ty = format!("__private__{}", self.name());
+
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
+                if func_host_lang.is_rust() {
+                    swift_ty.to_string()
+                } else {
+                    ffi_ty
+                }
+            }
+            TypePosition::SharedStructField | TypePosition::SwiftReturnsToRust => {
+                swift_ty.to_string()
+            }
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => ffi_ty,
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        format!("struct __private__{}", self.name())
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        Some(vec!["stdint.h"])
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        self.ffi_struct(swift_bridge_path)
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        self.ffi_option_struct(swift_bridge_path)
+    }
+
+    fn to_ffi_compatible_option_swift_type(&self, _types: &TypeDeclarations) -> String {
+        format!("__private__Option{}", self.name())
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        format!("struct __private__Option{}", self.name())
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        _span: Span,
+    ) -> TokenStream {
+        let ffi_struct = self.ffi_struct(swift_bridge_path);
+        quote! { #ffi_struct::from(#expression) }
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+    ) -> TokenStream {
+        let ffi_struct = self.ffi_struct(swift_bridge_path);
+        let ffi_option_struct = self.ffi_option_struct(swift_bridge_path);
+
+        quote! {
+            if let Some(val) = #expression {
+                #ffi_option_struct { val: #ffi_struct::from(val), is_some: true }
+            } else {
+                #ffi_option_struct { val: #ffi_struct { low: 123, high: 0 }, is_some: false }
+            }
+        }
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!("{expression}.intoFfiRepr()")
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        let name = self.name();
+        format!("{{ let val = {expression}; return __private__Option{name}(val: (val ?? 123).intoFfiRepr(), is_some: val != nil) }}()")
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        _span: Span,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = self.to_rust_type_path(types);
+        quote! { #ty::from(#expression) }
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(&self, expression: &TokenStream) -> TokenStream {
+        let ty = self.to_rust_type_path(&TypeDeclarations::default());
+        quote! {
+            {
+                let val = #expression;
+                if val.is_some {
+                    Some(#ty::from(val.val))
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+        _types: &TypeDeclarations,
+    ) -> String {
+        format!("{expression}.intoSwiftRepr()")
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, expression: &str) -> String {
+        format!("{{ let val = {expression}; if val.is_some {{ return val.val.intoSwiftRepr() }} else {{ return nil }} }}()")
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        ok_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = self.to_rust_type_path(types);
+        quote! { #ty::from(#ok_ffi_value) }
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        err_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = self.to_rust_type_path(types);
+        quote! { #ty::from(#err_ffi_value) }
+    }
+
+    fn unused_option_none_val(&self, swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        let ffi_struct = self.ffi_struct(swift_bridge_path);
+        UnusedOptionNoneValue {
+            rust: quote! { #ffi_struct { low: 123, high: 0 } },
+            swift: "123".into(),
+        }
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        tokens == "u128" || tokens == "i128"
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => Self::parse_token_stream_str(
+                path.path.segments.to_token_stream().to_string().as_str(),
+                types,
+            ),
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, _types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match tokens {
+            "u128" => Some(BridgeableInt128::U128),
+            "i128" => Some(BridgeableInt128::I128),
+            _ => None,
+        }
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, _types: &TypeDeclarations) -> String {
+        self.name().to_string()
+    }
+}
@@ -0,0 +1,378 @@
+use crate::bridged_type::{
+    BridgeableType, BridgedType, BuiltInResult, CFfiStruct, OnlyEncoding, StdLibType, TypePosition,
+    UnusedOptionNoneValue,
+};
+use crate::TypeDeclarations;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{GenericArgument, Path, PathArguments, Type};
+
+/// std::num::NonZeroU32, std::num::NonZero<u32>, etc
+///
+/// A non-zero integer is passed over FFI as its underlying integer, so on the Swift side a
+/// `NonZeroU32` is a `UInt32`.
+///
+/// Converting the integer back into a non-zero integer on the Rust side is checked, so a zero
+/// that Swift passes to Rust panics instead of producing an invalid non-zero integer.
+#[derive(Debug)]
+pub(crate) struct BridgeableNonZero {
+    /// The underlying integer, such as a `u32` for a `NonZeroU32`.
+    inner: Box<BridgedType>,
+    /// "U32" for a `NonZeroU32`.
+    name: &'static str,
+}
+
+impl BridgeableNonZero {
+    /// `std::num::NonZeroU32`
+    fn non_zero_type(&self) -> TokenStream {
+        let ident = Ident::new(&format!("NonZero{}", self.name), Span::call_site());
+        quote! { std::num::#ident }
+    }
+
+    /// `swift_bridge::option::OptionU32`
+    fn ffi_option_struct(&self, swift_bridge_path: &Path) -> TokenStream {
+        let ident = Ident::new(&format!("Option{}", self.name), Span::call_site());
+        quote! { #swift_bridge_path::option::#ident }
+    }
+
+    /// Parse a `NonZeroU32`, `std::num::NonZeroU32`, `NonZero<u32>` or `std::num::NonZero<u32>`.
+    fn from_type_path(ty: &syn::TypePath) -> Option<Self> {
+        if ty.qself.is_some() {
+            return None;
+        }
+
+        let segments: Vec<_> = ty.path.segments.iter().collect();
+        let (last, prefix) = segments.split_last()?;
+
+        let prefix: Vec<String> = prefix
+            .iter()
+            .map(|segment| match segment.arguments {
+                PathArguments::None => Some(segment.ident.to_string()),
+                _ => None,
+            })
+            .collect::<Option<Vec<String>>>()?;
+        if !(prefix.is_empty() || prefix == ["std", "num"] || prefix == ["core", "num"]) {
+            return None;
+        }
+
+        let integer = match &last.arguments {
+            PathArguments::None => last
+                .ident
+                .to_string()
+                .strip_prefix("NonZero")?
+                .to_lowercase(),
+            PathArguments::AngleBracketed(generics)
+                if last.ident == "NonZero" && generics.args.len() == 1 =>
+            {
+                match &generics.args[0] {
+                    GenericArgument::Type(ty) => ty.to_token_stream().to_string(),
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        };
+
+        let (inner, name) = match integer.as_str() {
+            "u8" => (StdLibType::U8, "U8"),
+            "i8" => (StdLibType::I8, "I8"),
+            "u16" => (StdLibType::U16, "U16"),
+            "i16" => (StdLibType::I16, "I16"),
+            "u32" => (StdLibType::U32, "U32"),
+            "i32" => (StdLibType::I32, "I32"),
+            "u64" => (StdLibType::U64, "U64"),
+            "i64" => (StdLibType::I64, "I64"),
+            "usize" => (StdLibType::Usize, "Usize"),
+            "isize" => (StdLibType::Isize, "Isize"),
+            _ => return None,
+        };
+
+        Some(BridgeableNonZero {
+            inner: Box::new(BridgedType::StdLib(inner)),
+            name,
+        })
+    }
+}
+
+impl BridgeableType for BridgeableNonZero {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        false
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        self.inner
+            .generate_custom_rust_ffi_types(swift_bridge_path, types)
+    }
+
+    fn generate_custom_c_ffi_types(&self, types: &TypeDeclarations) -> Option<CFfiStruct> {
+        self.inner.generate_custom_c_ffi_types(types)
+    }
+
+    fn to_rust_type_path(&self, _types: &TypeDeclarations) -> TokenStream {
+        self.non_zero_type()
+    }
+
+    fn to_swift_type(&self, type_pos: TypePosition, types: &TypeDeclarations) -> String {
+        self.inner.to_swift_type(type_pos, types)
+    }
+
+    fn to_c_type(&self, types: &TypeDeclarations) -> String {
+        self.inner.to_c(types)
+    }
+
+    fn to_c_include(&self, types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        self.inner.to_c_include(types)
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        self.inner
+            .to_ffi_compatible_rust_type(swift_bridge_path, types)
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        self.ffi_option_struct(swift_bridge_path)
+    }
+
+    fn to_ffi_compatible_option_swift_type(&self, _types: &TypeDeclarations) -> String {
+        format!("__private__Option{}", self.name)
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        format!("struct __private__Option{}", self.name)
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        _span: Span,
+    ) -> TokenStream {
+        quote! { #expression.get() }
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+    ) -> TokenStream {
+        let ffi_option_struct = self.ffi_option_struct(swift_bridge_path);
+
+        quote! {
+            if let Some(val) = #expression {
+                #ffi_option_struct { val: val.get(), is_some: true }
+            } else {
+                #ffi_option_struct { val: 123, is_some: false }
+            }
+        }
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        expression.to_string()
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!("{expression}.intoFfiRepr()")
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        _span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        let non_zero = self.non_zero_type();
+        quote! { #non_zero::new(#expression).unwrap() }
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(&self, expression: &TokenStream) -> TokenStream {
+        let non_zero = self.non_zero_type();
+        quote! {
+            {
+                let val = #expression;
+                if val.is_some {
+                    Some(#non_zero::new(val.val).unwrap())
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+        _types: &TypeDeclarations,
+    ) -> String {
+        expression.to_string()
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, expression: &str) -> String {
+        format!("{expression}.intoSwiftRepr()")
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        ok_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        let non_zero = self.non_zero_type();
+        quote! { #non_zero::new(#ok_ffi_value).unwrap() }
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        err_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        let non_zero = self.non_zero_type();
+        quote! { #non_zero::new(#err_ffi_value).unwrap() }
+    }
+
+    fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        UnusedOptionNoneValue {
+            rust: quote! { 123 },
+            swift: "123".into(),
+        }
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        Self::parse_token_stream_str(tokens, &TypeDeclarations::default()).is_some()
+    }
+
+    fn from_type(ty: &Type, _types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => Self::from_type_path(path),
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if !tokens.contains("NonZero") {
+            return None;
+        }
+
+        let ty: Type = syn::parse_str(tokens).ok()?;
+        Self::from_type(&ty, types)
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, _types: &TypeDeclarations) -> String {
+        format!("NonZero{}", self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::quote;
+
+    /// Verify that we parse the different ways of writing a non-zero integer type.
+    #[test]
+    fn parse_non_zero() {
+        let tests = vec![
+            (quote! { NonZeroU32 }, "U32"),
+            (quote! { std::num::NonZeroI64 }, "I64"),
+            (quote! { core::num::NonZeroUsize }, "Usize"),
+            (quote! { NonZero<u8> }, "U8"),
+            (quote! { std::num::NonZero<isize> }, "Isize"),
+        ];
+
+        for (tokens, expected_name) in tests {
+            let ty: Type = syn::parse2(tokens.clone()).unwrap();
+
+            let non_zero = BridgeableNonZero::from_type(&ty, &TypeDeclarations::default())
+                .unwrap_or_else(|| panic!("{}", tokens));
+            assert_eq!(non_zero.name, expected_name);
+        }
+    }
+
+    /// Verify that we do not parse types that are not non-zero integers.
+    #[test]
+    fn unsupported_non_zero() {
+        let tests = vec![
+            quote! { NonZeroU128 },
+            quote! { NonZeroFoo },
+            quote! { NonZero<f32> },
+            quote! { some_crate::NonZeroU32 },
+        ];
+
+        for tokens in tests {
+            let ty: Type = syn::parse2(tokens.clone()).unwrap();
+
+            assert!(
+                BridgeableNonZero::from_type(&ty, &TypeDeclarations::default()).is_none(),
+                "{}",
+                tokens
+            );
+        }
+    }
+}
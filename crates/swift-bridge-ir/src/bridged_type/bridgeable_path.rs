@@ -0,0 +1,387 @@
+use crate::bridged_type::{
+    BridgeableType, BuiltInResult, CFfiStruct, OnlyEncoding, TypePosition, UnusedOptionNoneValue,
+};
+use crate::{SwiftPathType, TypeDeclarations};
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned, ToTokens};
+use syn::{Path, Type};
+
+/// std::path::PathBuf or &std::path::Path
+///
+/// A path is passed over FFI the same way that a `String` is. On the Swift side it is either a
+/// `String` or a file `URL`, depending on the bridge module's `swift_path_type` attribute.
+///
+/// Paths that are not valid UTF-8 are converted lossily when they are passed from Rust to Swift.
+#[derive(Debug)]
+pub(crate) struct BridgeablePath {
+    kind: PathKind,
+    swift_path_type: SwiftPathType,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum PathKind {
+    /// PathBuf
+    Owned,
+    /// &Path
+    Ref,
+}
+
+impl BridgeablePath {
+    /// Create a `&Path`.
+    fn new_ref(types: &TypeDeclarations) -> Self {
+        BridgeablePath {
+            kind: PathKind::Ref,
+            swift_path_type: types.swift_path_type(),
+        }
+    }
+
+    /// Whether or not a `&T` of the given type is a `&Path`.
+    fn is_path_ref_pointee(tokens: &str) -> bool {
+        tokens == "Path" || tokens == "std :: path :: Path"
+    }
+
+    /// "String" or "URL"
+    fn swift_type(&self) -> &'static str {
+        match self.swift_path_type {
+            SwiftPathType::String => "String",
+            SwiftPathType::Url => "URL",
+        }
+    }
+
+    /// Convert a path expression into a `*mut swift_bridge::string::RustString`.
+    fn rust_path_to_ffi(expression: &TokenStream, swift_bridge_path: &Path) -> TokenStream {
+        quote! {
+            #swift_bridge_path::string::RustString(#expression.to_string_lossy().into_owned()).box_into_raw()
+        }
+    }
+
+    /// Convert a `String` expression into a `PathBuf` or a `&Path`.
+    fn string_to_rust_path(&self, string: TokenStream) -> TokenStream {
+        match self.kind {
+            PathKind::Owned => quote! { std::path::PathBuf::from(#string) },
+            PathKind::Ref => quote! { std::path::Path::new(&#string) },
+        }
+    }
+
+    /// Convert a Swift `String` or `URL` expression into a `RustString` pointer.
+    fn swift_path_to_ffi(&self, expression: &str) -> String {
+        let string = match self.swift_path_type {
+            SwiftPathType::String => expression.to_string(),
+            SwiftPathType::Url => format!("{expression}.path"),
+        };
+
+        format!("{{ let rustString = {string}.intoRustString(); rustString.isOwned = false; return rustString.ptr }}()")
+    }
+
+    /// Convert a `RustString` expression into a Swift `String` or `URL`.
+    fn rust_string_to_swift_path(&self, rust_string: &str) -> String {
+        match self.swift_path_type {
+            SwiftPathType::String => format!("{rust_string}.toString()"),
+            SwiftPathType::Url => format!("URL(fileURLWithPath: {rust_string}.toString())"),
+        }
+    }
+}
+
+impl BridgeableType for BridgeablePath {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        true
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    /// `URL` lives in Foundation, which the generated Swift does not otherwise import.
+    fn generate_custom_swift_code(&self, _types: &TypeDeclarations) -> Option<String> {
+        match self.swift_path_type {
+            SwiftPathType::String => None,
+            SwiftPathType::Url => Some("import Foundation".to_string()),
+        }
+    }
+
+    fn to_rust_type_path(&self, _types: &TypeDeclarations) -> TokenStream {
+        match self.kind {
+            PathKind::Owned => quote! { std::path::PathBuf },
+            PathKind::Ref => quote! { &std::path::Path },
+        }
+    }
+
+    fn to_swift_type(&self, type_pos: TypePosition, _types: &TypeDeclarations) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
+                if func_host_lang.is_rust() {
+                    self.swift_type().to_string()
+                } else {
+                    "UnsafeMutableRawPointer".to_string()
+                }
+            }
+            TypePosition::SharedStructField | TypePosition::SwiftReturnsToRust => {
+                self.swift_type().to_string()
+            }
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                "UnsafeMutableRawPointer?".to_string()
+            }
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        "void*".to_string()
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        None
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote! { *mut #swift_bridge_path::string::RustString }
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        self.to_ffi_compatible_rust_type(swift_bridge_path, types)
+    }
+
+    fn to_ffi_compatible_option_swift_type(&self, _types: &TypeDeclarations) -> String {
+        "UnsafeMutableRawPointer?".to_string()
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        "void*".to_string()
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        _span: Span,
+    ) -> TokenStream {
+        Self::rust_path_to_ffi(expression, swift_bridge_path)
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+    ) -> TokenStream {
+        let path = Self::rust_path_to_ffi(&quote! { val }, swift_bridge_path);
+        let unused_none_value = self.unused_option_none_val(swift_bridge_path).rust;
+
+        quote! {
+            if let Some(val) = #expression {
+                #path
+            } else {
+                #unused_none_value
+            }
+        }
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        self.swift_path_to_ffi(expression)
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        let path = self.swift_path_to_ffi("val");
+        format!("{{ if let val = {expression} {{ return {path} }} else {{ return nil }} }}()")
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        self.string_to_rust_path(quote_spanned! {span=>
+            unsafe { Box::from_raw(#expression).0 }
+        })
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(&self, expression: &TokenStream) -> TokenStream {
+        let path = self.string_to_rust_path(quote! { unsafe { Box::from_raw(val).0 } });
+
+        quote! {
+            {
+                let val = #expression;
+                if val.is_null() {
+                    None
+                } else {
+                    Some(#path)
+                }
+            }
+        }
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        type_pos: TypePosition,
+        _types: &TypeDeclarations,
+    ) -> String {
+        match type_pos {
+            TypePosition::FnArg(_, _)
+            | TypePosition::FnReturn(_)
+            | TypePosition::SharedStructField
+            | TypePosition::SwiftReturnsToRust => {
+                self.rust_string_to_swift_path(&format!("RustString(ptr: {expression})"))
+            }
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                self.rust_string_to_swift_path(&format!("RustString(ptr: {expression}!)"))
+            }
+        }
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, expression: &str) -> String {
+        let path = self.rust_string_to_swift_path("RustString(ptr: val!)");
+        format!("{{ let val = {expression}; if val != nil {{ return {path} }} else {{ return nil }} }}()")
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        result: &TokenStream,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        self.string_to_rust_path(quote! {
+            unsafe {
+                Box::from_raw(#result.ok_or_err as *mut #swift_bridge_path::string::RustString).0
+            }
+        })
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        result: &TokenStream,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        self.string_to_rust_path(quote! {
+            unsafe {
+                Box::from_raw(#result.ok_or_err as *mut #swift_bridge_path::string::RustString).0
+            }
+        })
+    }
+
+    fn unused_option_none_val(&self, swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        UnusedOptionNoneValue {
+            rust: quote! {
+                std::ptr::null::<#swift_bridge_path::string::RustString>() as *mut #swift_bridge_path::string::RustString
+            },
+            swift: "nil".to_string(),
+        }
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        tokens == "PathBuf" || tokens == "std :: path :: PathBuf"
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => Self::parse_token_stream_str(
+                path.path.to_token_stream().to_string().as_str(),
+                types,
+            ),
+            Type::Reference(reference) if reference.mutability.is_none() => {
+                match reference.elem.as_ref() {
+                    Type::Path(path)
+                        if Self::is_path_ref_pointee(&path.path.to_token_stream().to_string())
+                            && types.get("Path").is_none() =>
+                    {
+                        Some(Self::new_ref(types))
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// A bridge module can declare its own `type PathBuf`, in which case a `PathBuf` refers to
+    /// that type instead of the standard library's.
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_parse_token_stream_str(tokens) && types.get(tokens).is_none() {
+            Some(BridgeablePath {
+                kind: PathKind::Owned,
+                swift_path_type: types.swift_path_type(),
+            })
+        } else {
+            None
+        }
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, _types: &TypeDeclarations) -> String {
+        match self.kind {
+            PathKind::Owned => "PathBuf".to_string(),
+            PathKind::Ref => "RefPath".to_string(),
+        }
+    }
+}
@@ -64,11 +64,11 @@ impl BridgeableType for BuiltInPointer {
                 let ty = ty.to_rust_type_path(types);
                 quote! { #pointer_kind #ty}
             }
-            Pointee::Void(_ty) => {
+            Pointee::Void(ty) => {
                 let pointer_kind = self.kind.to_ffi_compatible_rust_type();
-                let pointee = self.pointee.to_rust_type_path(types);
+                let pointee = Pointee::void_pointee_path(ty);
 
-                quote! { #pointer_kind super:: #pointee }
+                quote! { #pointer_kind #pointee }
             }
         }
     }
@@ -94,9 +94,7 @@ impl BridgeableType for BuiltInPointer {
 
         let ty = match &self.pointee {
             Pointee::BuiltIn(ty) => ty.to_ffi_compatible_rust_type(swift_bridge_path, types),
-            Pointee::Void(ty) => {
-                quote! { super::#ty }
-            }
+            Pointee::Void(ty) => Pointee::void_pointee_path(ty),
         };
 
         quote! { #kind #ty}
@@ -110,11 +108,7 @@ impl BridgeableType for BuiltInPointer {
         todo!()
     }
 
-    fn to_ffi_compatible_option_swift_type(
-        &self,
-        _swift_bridge_path: &Path,
-        _types: &TypeDeclarations,
-    ) -> String {
+    fn to_ffi_compatible_option_swift_type(&self, _types: &TypeDeclarations) -> String {
         todo!()
     }
 
@@ -269,11 +263,40 @@ impl PointerKind {
     }
 }
 
+impl BuiltInPointer {
+    /// `std::ptr::null()` or `std::ptr::null_mut()`
+    pub(crate) fn null_pointer(&self) -> TokenStream {
+        match self.kind {
+            PointerKind::Const => quote! { std::ptr::null() },
+            PointerKind::Mut => quote! { std::ptr::null_mut() },
+        }
+    }
+}
+
 impl Pointee {
-    fn to_rust_type_path(&self, types: &TypeDeclarations) -> TokenStream {
-        match self {
-            Pointee::BuiltIn(built_in) => built_in.to_rust_type_path(types),
-            Pointee::Void(ty) => ty.to_token_stream(),
+    /// The path to a pointee that we don't know anything about, such as `c_void`.
+    ///
+    /// Types in the bridge module are looked up in the parent module, so we prefix them with
+    /// `super::`. Paths that are already absolute, such as `std::ffi::c_void` or `::libc::c_void`,
+    /// are used as is.
+    fn void_pointee_path(ty: &Type) -> TokenStream {
+        let is_absolute = match ty {
+            Type::Path(path) => {
+                path.qself.is_none()
+                    && (path.path.leading_colon.is_some()
+                        || path.path.segments.first().is_some_and(|segment| {
+                            ["std", "core", "alloc", "crate"]
+                                .iter()
+                                .any(|root| segment.ident == root)
+                        }))
+            }
+            _ => false,
+        };
+
+        if is_absolute {
+            ty.to_token_stream()
+        } else {
+            quote! { super::#ty }
         }
     }
 }
@@ -0,0 +1,334 @@
+use crate::bridged_type::{
+    BridgeableType, BridgedType, BuiltInResult, CFfiStruct, OnlyEncoding, StdLibType, TypePosition,
+    UnusedOptionNoneValue,
+};
+use crate::parse::TypeDeclarations;
+use crate::SWIFT_BRIDGE_PREFIX;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+use syn::{GenericArgument, Path, PathArguments, Type};
+
+/// std::ops::Range<T>, such as `Range<usize>`.
+///
+/// A `Range<T>` is seen on the Swift side as a `Range<T>`, and is passed over FFI by value as a
+/// `#[repr(C)]` struct holding its start and its end.
+///
+/// Rust allows a range's start to be greater than its end, which Swift does not, so such a range
+/// is seen on the Swift side as an empty range that starts at the Rust range's start.
+///
+/// Only ranges of integers are supported.
+#[derive(Debug)]
+pub(crate) struct BridgeableRange {
+    ty: Box<BridgedType>,
+}
+
+impl BridgeableRange {
+    /// `__swift_bridge__range_UInt`
+    fn prefixed_rust_ty_name(&self, types: &TypeDeclarations) -> Ident {
+        format_ident!(
+            "{}range_{}",
+            SWIFT_BRIDGE_PREFIX,
+            self.ty.to_alpha_numeric_underscore_name(types)
+        )
+    }
+
+    /// `__swift_bridge__$range$UInt`
+    fn prefixed_c_ty_name(&self, types: &TypeDeclarations) -> String {
+        format!(
+            "{}$range${}",
+            SWIFT_BRIDGE_PREFIX,
+            self.ty.to_alpha_numeric_underscore_name(types)
+        )
+    }
+
+    /// Whether or not ranges of the given type can be bridged.
+    fn is_supported_bound(ty: &BridgedType) -> bool {
+        matches!(
+            ty,
+            BridgedType::StdLib(
+                StdLibType::U8
+                    | StdLibType::I8
+                    | StdLibType::U16
+                    | StdLibType::I16
+                    | StdLibType::U32
+                    | StdLibType::I32
+                    | StdLibType::U64
+                    | StdLibType::I64
+                    | StdLibType::Usize
+                    | StdLibType::Isize
+            )
+        )
+    }
+}
+
+impl BridgeableType for BridgeableRange {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        false
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        let ty_name = self.prefixed_rust_ty_name(types);
+        let bound = self.ty.to_rust_type_path(types);
+
+        Some(vec![quote! {
+            #[repr(C)]
+            #[doc(hidden)]
+            pub struct #ty_name {
+                pub start: #bound,
+                pub end: #bound
+            }
+        }])
+    }
+
+    fn generate_custom_c_ffi_types(&self, types: &TypeDeclarations) -> Option<CFfiStruct> {
+        let ty_name = self.prefixed_c_ty_name(types);
+        let bound = self.ty.to_c(types);
+
+        Some(CFfiStruct {
+            c_ffi_type: format!(
+                "typedef struct {ty_name} {{ {bound} start; {bound} end; }} {ty_name};"
+            ),
+            fields: vec![],
+        })
+    }
+
+    fn to_rust_type_path(&self, types: &TypeDeclarations) -> TokenStream {
+        let bound = self.ty.to_rust_type_path(types);
+        quote! { std::ops::Range<#bound> }
+    }
+
+    fn to_swift_type(&self, type_pos: TypePosition, types: &TypeDeclarations) -> String {
+        let range = format!("Range<{}>", self.ty.to_swift_type(type_pos, types));
+
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
+                if func_host_lang.is_rust() {
+                    range
+                } else {
+                    self.prefixed_c_ty_name(types)
+                }
+            }
+            TypePosition::SharedStructField | TypePosition::SwiftReturnsToRust => range,
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => self.prefixed_c_ty_name(types),
+        }
+    }
+
+    fn to_c_type(&self, types: &TypeDeclarations) -> String {
+        format!("struct {}", self.prefixed_c_ty_name(types))
+    }
+
+    fn to_c_include(&self, types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        self.ty.to_c_include(types)
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        self.prefixed_rust_ty_name(types).to_token_stream()
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Option<Range<T>> is rejected when the bridge module is parsed")
+    }
+
+    fn to_ffi_compatible_option_swift_type(&self, _types: &TypeDeclarations) -> String {
+        unreachable!("Option<Range<T>> is rejected when the bridge module is parsed")
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        unreachable!("Option<Range<T>> is rejected when the bridge module is parsed")
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+        _span: Span,
+    ) -> TokenStream {
+        let ty_name = self.prefixed_rust_ty_name(types);
+        quote! {
+            { let val = #expression; #ty_name { start: val.start, end: val.end } }
+        }
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        _expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        unreachable!("Option<Range<T>> is rejected when the bridge module is parsed")
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!(
+            "{{ let val = {expression}; return {}(start: val.lowerBound, end: val.upperBound); }}()",
+            self.prefixed_c_ty_name(types)
+        )
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        _expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        unreachable!("Option<Range<T>> is rejected when the bridge module is parsed")
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        _span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote! {
+            { let val = #expression; val.start..val.end }
+        }
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(&self, _expression: &TokenStream) -> TokenStream {
+        unreachable!("Option<Range<T>> is rejected when the bridge module is parsed")
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+        _types: &TypeDeclarations,
+    ) -> String {
+        format!("{{ let val = {expression}; return val.start..<max(val.start, val.end); }}()")
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, _expression: &str) -> String {
+        unreachable!("Option<Range<T>> is rejected when the bridge module is parsed")
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        _ok_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Result<Range<T>, E> is rejected when it would be passed to Rust")
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        _err_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Result<T, Range<E>> is rejected when it would be passed to Rust")
+    }
+
+    fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        unreachable!("Option<Range<T>> is rejected when the bridge module is parsed")
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        tokens.starts_with("Range < ") || tokens.starts_with("std :: ops :: Range < ")
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let path = match ty {
+            Type::Path(path) if path.qself.is_none() => &path.path,
+            _ => return None,
+        };
+
+        let segments = path
+            .segments
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .collect::<Vec<String>>();
+        if segments != ["Range"] && segments != ["std", "ops", "Range"] {
+            return None;
+        }
+
+        let generics = match &path.segments.last()?.arguments {
+            PathArguments::AngleBracketed(generics) if generics.args.len() == 1 => generics,
+            _ => return None,
+        };
+        let bound = match generics.args.first()? {
+            GenericArgument::Type(ty) => BridgedType::new_with_type(ty, types)?,
+            _ => return None,
+        };
+
+        if Self::is_supported_bound(&bound) {
+            Some(BridgeableRange {
+                ty: Box::new(bound),
+            })
+        } else {
+            None
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let ty: Type = syn::parse_str(tokens).ok()?;
+        Self::from_type(&ty, types)
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, types: &TypeDeclarations) -> String {
+        format!("Range{}", self.ty.to_alpha_numeric_underscore_name(types))
+    }
+}
@@ -2,7 +2,7 @@ use crate::bridged_type::{BridgeableType, BridgedType, CFfiStruct, TypePosition}
 use crate::{TypeDeclarations, SWIFT_BRIDGE_PREFIX};
 use proc_macro2::{Span, TokenStream};
 use quote::{format_ident, quote, quote_spanned};
-use syn::Path;
+use syn::{GenericArgument, Path, PathArguments, Type};
 
 /// Rust: Result<T, E>
 /// Swift: RustResult<T, E>
@@ -145,6 +145,27 @@ impl BuiltInResult {
         swift_bridge_path: &Path,
         types: &TypeDeclarations,
     ) -> TokenStream {
+        if self.ok_ty.can_be_encoded_with_zero_bytes() && !self.is_custom_result_type() {
+            let ok = self.ok_ty.only_encoding().unwrap().rust;
+            let convert_err = self.err_ty.convert_ffi_expression_to_rust_type(
+                &quote! { val },
+                span,
+                swift_bridge_path,
+                types,
+            );
+
+            return quote_spanned! {span=>
+                {
+                    let val = #expression;
+                    if val.is_null() {
+                        std::result::Result::Ok(#ok)
+                    } else {
+                        std::result::Result::Err(#convert_err)
+                    }
+                }
+            };
+        }
+
         let convert_ok = self.ok_ty.convert_ffi_result_ok_value_to_rust_value(
             expression,
             swift_bridge_path,
@@ -176,7 +197,9 @@ impl BuiltInResult {
     pub fn to_swift_type(&self, type_pos: TypePosition, types: &TypeDeclarations) -> String {
         match type_pos {
             TypePosition::FnReturn(_) => self.ok_ty.to_swift_type(type_pos, types),
-            TypePosition::FnArg(_, _) | TypePosition::SharedStructField => {
+            TypePosition::FnArg(_, _)
+            | TypePosition::SharedStructField
+            | TypePosition::SwiftReturnsToRust => {
                 format!(
                     "RustResult<{}, {}>",
                     self.ok_ty.to_swift_type(type_pos, types),
@@ -240,7 +263,7 @@ impl BuiltInResult {
                     ok_swift_type = ok_swift_type,
                     err_swift_type = err_swift_type
                 ),
-                TypePosition::SharedStructField => todo!(),
+                TypePosition::SharedStructField | TypePosition::SwiftReturnsToRust => todo!(),
                 TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => todo!(),
             };
         }
@@ -301,7 +324,7 @@ impl BuiltInResult {
 
         if self.ok_ty.can_be_encoded_with_zero_bytes() {
             format!(
-                "{{ switch {val} {{ case .Ok(let ok): return __private__ResultPtrAndPtr(is_ok: true, ok_or_err: {convert_ok}) case .Err(let err): return __private__ResultPtrAndPtr(is_ok: false, ok_or_err: {convert_err}) }} }}()",
+                "{{ () -> UnsafeMutableRawPointer? in switch {val} {{ case .Ok(_): return nil case .Err(let err): return {convert_err} }} }}()",
                 val = expression
             )
         } else {
@@ -336,7 +359,14 @@ impl BuiltInResult {
         types: &TypeDeclarations,
     ) -> Option<Vec<TokenStream>> {
         if !self.is_custom_result_type() {
-            return None;
+            // The `Result` itself is passed using one of the runtime's result types, but the types
+            // that it holds, such as a `HashMap<K, V>`, can still need their own FFI functions.
+            let inner_custom_rust_ffi_types: Vec<TokenStream> = [&self.ok_ty, &self.err_ty]
+                .into_iter()
+                .filter_map(|ty| ty.generate_custom_rust_ffi_types(swift_bridge_path, types))
+                .flatten()
+                .collect();
+            return Some(inner_custom_rust_ffi_types).filter(|inner| !inner.is_empty());
         }
         if self.err_ty.can_be_encoded_with_zero_bytes() {
             todo!()
@@ -383,7 +413,18 @@ impl BuiltInResult {
 
     pub fn generate_custom_c_ffi_types(&self, types: &TypeDeclarations) -> Option<CFfiStruct> {
         if !self.is_custom_result_type() {
-            return None;
+            // Like in `generate_custom_rust_ffi_types`, the types that the `Result` holds can
+            // still need their own declarations. When both do, the error type's declarations are
+            // nested in the ok type's so that both get declared.
+            let ok = self.ok_ty.generate_custom_c_ffi_types(types);
+            let err = self.err_ty.generate_custom_c_ffi_types(types);
+            return match (ok, err) {
+                (Some(mut ok), Some(err)) => {
+                    ok.fields.push(err);
+                    Some(ok)
+                }
+                (ok, err) => ok.or(err),
+            };
         }
         if self.err_ty.can_be_encoded_with_zero_bytes() {
             todo!();
@@ -506,18 +547,25 @@ typedef struct {c_enum_name}{{{c_tag_name} tag; union {c_fields_name} payload;}}
 impl BuiltInResult {
     /// Go from `Result < A , B >` to a `BuiltInResult`.
     pub fn from_str_tokens(string: &str, types: &TypeDeclarations) -> Option<Self> {
-        // A , B >
-        let trimmed = string.trim_start_matches("Result < ");
-        // A , B
-        let trimmed = trimmed.trim_end_matches(" >");
+        // Parsed instead of split on the last comma, since `B` can hold commas of its own, such as
+        // in `Result < () , HashMap < u8 , u8 > >`.
+        let ty: Type = syn::parse_str(string).ok()?;
+        let generics = match &ty {
+            Type::Path(path) => match &path.path.segments.last()?.arguments {
+                PathArguments::AngleBracketed(generics) => generics,
+                _ => return None,
+            },
+            _ => return None,
+        };
 
         // [A, B]
-        let ok_and_err = trimmed.rsplit_once(",")?;
-        let ok = ok_and_err.0.trim();
-        let err = ok_and_err.1.trim();
+        let (ok, err) = match generics.args.iter().collect::<Vec<_>>()[..] {
+            [GenericArgument::Type(ok), GenericArgument::Type(err)] => (ok, err),
+            _ => return None,
+        };
 
-        let ok = BridgedType::new_with_str(ok, types)?;
-        let err = BridgedType::new_with_str(err, types)?;
+        let ok = BridgedType::new_with_type(ok, types)?;
+        let err = BridgedType::new_with_type(err, types)?;
 
         Some(BuiltInResult {
             ok_ty: Box::new(ok),
@@ -568,4 +616,22 @@ mod tests {
         assert!(result.ok_ty.is_null());
         assert!(result.err_ty.is_null());
     }
+
+    /// Verify that we can parse a `Result<(), Vec<u8>>`, where the error type ends with a closing
+    /// angle bracket.
+    #[test]
+    fn result_from_null_and_generic_err_type() {
+        let tokens = quote! { Result<(), Vec<u8>> }.to_token_stream().to_string();
+
+        let result = BuiltInResult::from_str_tokens(&tokens, &TypeDeclarations::default()).unwrap();
+
+        assert!(result.ok_ty.is_null());
+        assert_eq!(
+            result
+                .err_ty
+                .to_rust_type_path(&TypeDeclarations::default())
+                .to_string(),
+            quote! { Vec<u8> }.to_string()
+        );
+    }
 }
@@ -67,6 +67,7 @@ impl BridgeableType for BridgedString {
             TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
                 "UnsafeMutableRawPointer?".to_string()
             }
+            TypePosition::SwiftReturnsToRust => "String".to_string(),
         }
     }
 
@@ -94,12 +95,8 @@ impl BridgeableType for BridgedString {
         self.to_ffi_compatible_rust_type(swift_bridge_path, types)
     }
 
-    fn to_ffi_compatible_option_swift_type(
-        &self,
-        _swift_bridge_path: &Path,
-        _types: &TypeDeclarations,
-    ) -> String {
-        todo!()
+    fn to_ffi_compatible_option_swift_type(&self, _types: &TypeDeclarations) -> String {
+        "UnsafeMutableRawPointer?".to_string()
     }
 
     fn to_ffi_compatible_option_c_type(&self) -> String {
@@ -152,18 +149,15 @@ impl BridgeableType for BridgedString {
         type_pos: TypePosition,
     ) -> String {
         match type_pos {
-            TypePosition::FnArg(_func_host_lang, _) => {
+            TypePosition::FnArg(_, _)
+            | TypePosition::FnReturn(_)
+            | TypePosition::SharedStructField
+            | TypePosition::SwiftReturnsToRust => {
                 format!(
                     "{{ if let rustString = optionalStringIntoRustString({expression}) {{ rustString.isOwned = false; return rustString.ptr }} else {{ return nil }} }}()",
                     expression = expression
                 )
             }
-            TypePosition::FnReturn(_) => {
-                todo!("Need to come back and think through what should happen here...")
-            }
-            TypePosition::SharedStructField => {
-                todo!("Option<String> fields in structs are not yet supported.")
-            }
             TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
                 unimplemented!()
             }
@@ -184,10 +178,13 @@ impl BridgeableType for BridgedString {
 
     fn convert_ffi_option_expression_to_rust_type(&self, expression: &TokenStream) -> TokenStream {
         quote! {
-            if #expression.is_null() {
-                None
-            } else {
-                Some(unsafe { Box::from_raw(#expression).0 } )
+            {
+                let val = #expression;
+                if val.is_null() {
+                    None
+                } else {
+                    Some(unsafe { Box::from_raw(val).0 })
+                }
             }
         }
     }
@@ -201,7 +198,8 @@ impl BridgeableType for BridgedString {
         match type_pos {
             TypePosition::FnArg(_, _)
             | TypePosition::FnReturn(_)
-            | TypePosition::SharedStructField => {
+            | TypePosition::SharedStructField
+            | TypePosition::SwiftReturnsToRust => {
                 format!("RustString(ptr: {})", expression)
             }
             TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
@@ -0,0 +1,306 @@
+use crate::bridged_type::{
+    BridgeableType, BuiltInResult, CFfiStruct, OnlyEncoding, TypePosition, UnusedOptionNoneValue,
+};
+use crate::TypeDeclarations;
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{Path, Type};
+
+/// std::time::SystemTime
+///
+/// A `SystemTime` is seen on the Swift side as a Foundation `Date`, and is passed over FFI as an
+/// `f64` number of seconds since the Unix epoch. Times before the epoch are negative.
+///
+/// Converting the `f64` back into a `SystemTime` saturates, since panicking would abort the
+/// process when we're called from Swift. A NaN number of seconds becomes the epoch, and times
+/// outside of Swift's `Date.distantPast` and `Date.distantFuture`, such as infinite ones, become
+/// the nearer of the two.
+#[derive(Debug)]
+pub(crate) struct BridgeableSystemTime;
+
+impl BridgeableSystemTime {
+    /// Convert a `SystemTime` expression into an `f64` number of seconds since the epoch.
+    fn to_secs_since_epoch(expression: &TokenStream) -> TokenStream {
+        quote! {
+            match #expression.duration_since(std::time::UNIX_EPOCH) {
+                Ok(after_epoch) => after_epoch.as_secs_f64(),
+                Err(before_epoch) => -before_epoch.duration().as_secs_f64(),
+            }
+        }
+    }
+
+    /// Convert an `f64` number of seconds since the epoch expression into a `SystemTime`.
+    fn from_secs_since_epoch(expression: &TokenStream) -> TokenStream {
+        quote! {
+            {
+                let secs: f64 = #expression;
+                // Swift's `Date.distantPast` and `Date.distantFuture`. A `SystemTime` can hold
+                // both of them on Apple platforms and Linux, so the conversions below can't panic.
+                let secs = if secs.is_nan() {
+                    0.
+                } else {
+                    secs.clamp(-62135769600., 64092211200.)
+                };
+                if secs >= 0. {
+                    std::time::UNIX_EPOCH + std::time::Duration::from_secs_f64(secs)
+                } else {
+                    std::time::UNIX_EPOCH - std::time::Duration::from_secs_f64(-secs)
+                }
+            }
+        }
+    }
+}
+
+impl BridgeableType for BridgeableSystemTime {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        false
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    /// `Date` lives in Foundation, which the generated Swift does not otherwise import.
+    fn generate_custom_swift_code(&self, _types: &TypeDeclarations) -> Option<String> {
+        Some("import Foundation".to_string())
+    }
+
+    fn to_rust_type_path(&self, _types: &TypeDeclarations) -> TokenStream {
+        quote! { std::time::SystemTime }
+    }
+
+    fn to_swift_type(&self, type_pos: TypePosition, _types: &TypeDeclarations) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
+                if func_host_lang.is_rust() {
+                    "Date".to_string()
+                } else {
+                    "Double".to_string()
+                }
+            }
+            TypePosition::SharedStructField | TypePosition::SwiftReturnsToRust => {
+                "Date".to_string()
+            }
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => "Double".to_string(),
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        "double".to_string()
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        None
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote! { f64 }
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote! { #swift_bridge_path::option::OptionF64 }
+    }
+
+    fn to_ffi_compatible_option_swift_type(&self, _types: &TypeDeclarations) -> String {
+        "__private__OptionF64".to_string()
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        "struct __private__OptionF64".to_string()
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        _span: Span,
+    ) -> TokenStream {
+        Self::to_secs_since_epoch(expression)
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+    ) -> TokenStream {
+        let secs = Self::to_secs_since_epoch(&quote! { val });
+
+        quote! {
+            if let Some(val) = #expression {
+                #swift_bridge_path::option::OptionF64 { val: #secs, is_some: true }
+            } else {
+                #swift_bridge_path::option::OptionF64 { val: 123.4, is_some: false }
+            }
+        }
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!("{expression}.timeIntervalSince1970")
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!("{{ let val = {expression}; return __private__OptionF64(val: val?.timeIntervalSince1970 ?? 123.4, is_some: val != nil) }}()")
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        _span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        Self::from_secs_since_epoch(expression)
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(&self, expression: &TokenStream) -> TokenStream {
+        let system_time = Self::from_secs_since_epoch(&quote! { val.val });
+
+        quote! {
+            {
+                let val = #expression;
+                if val.is_some {
+                    Some(#system_time)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+        _types: &TypeDeclarations,
+    ) -> String {
+        format!("Date(timeIntervalSince1970: {expression})")
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, expression: &str) -> String {
+        format!("{{ let val = {expression}; if val.is_some {{ return Date(timeIntervalSince1970: val.val) }} else {{ return nil }} }}()")
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        ok_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        Self::from_secs_since_epoch(ok_ffi_value)
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        err_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        Self::from_secs_since_epoch(err_ffi_value)
+    }
+
+    fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        UnusedOptionNoneValue {
+            rust: quote! { 123.4 },
+            swift: "123.4".into(),
+        }
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        tokens == "SystemTime" || tokens == "std :: time :: SystemTime"
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => Self::parse_token_stream_str(
+                path.path.to_token_stream().to_string().as_str(),
+                types,
+            ),
+            _ => None,
+        }
+    }
+
+    /// A bridge module can declare its own `type SystemTime`, in which case a `SystemTime`
+    /// refers to that type instead of the standard library's.
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_parse_token_stream_str(tokens) && types.get(tokens).is_none() {
+            Some(BridgeableSystemTime)
+        } else {
+            None
+        }
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, _types: &TypeDeclarations) -> String {
+        "SystemTime".to_string()
+    }
+}
@@ -0,0 +1,286 @@
+use crate::bridged_type::{
+    BridgeableType, BuiltInResult, CFfiStruct, OnlyEncoding, TypePosition, UnusedOptionNoneValue,
+};
+use crate::TypeDeclarations;
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{Path, Type};
+
+/// uuid::Uuid
+///
+/// A `Uuid` is seen on the Swift side as a Foundation `UUID`, and is passed over FFI by value as
+/// its 16 bytes.
+///
+/// The Rust side of the FFI glue lives behind the `swift-bridge` crate's `uuid` feature.
+#[derive(Debug)]
+pub(crate) struct BridgeableUuid;
+
+impl BridgeableUuid {
+    /// `swift_bridge::uuid_support::FfiUuid`
+    fn ffi_struct(swift_bridge_path: &Path) -> TokenStream {
+        quote! { #swift_bridge_path::uuid_support::FfiUuid }
+    }
+
+    /// `swift_bridge::uuid_support::OptionFfiUuid`
+    fn ffi_option_struct(swift_bridge_path: &Path) -> TokenStream {
+        quote! { #swift_bridge_path::uuid_support::OptionFfiUuid }
+    }
+}
+
+impl BridgeableType for BridgeableUuid {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        false
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    /// `UUID` lives in Foundation, which the generated Swift does not otherwise import.
+    fn generate_custom_swift_code(&self, _types: &TypeDeclarations) -> Option<String> {
+        Some("import Foundation".to_string())
+    }
+
+    fn to_rust_type_path(&self, _types: &TypeDeclarations) -> TokenStream {
+        quote! { uuid::Uuid }
+    }
+
+    fn to_swift_type(&self, type_pos: TypePosition, _types: &TypeDeclarations) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
+                if func_host_lang.is_rust() {
+                    "UUID".to_string()
+                } else {
+                    "__private__FfiUuid".to_string()
+                }
+            }
+            TypePosition::SharedStructField | TypePosition::SwiftReturnsToRust => {
+                "UUID".to_string()
+            }
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => "__private__FfiUuid".to_string(),
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        "struct __private__FfiUuid".to_string()
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        Some(vec!["stdint.h"])
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        Self::ffi_struct(swift_bridge_path)
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        Self::ffi_option_struct(swift_bridge_path)
+    }
+
+    fn to_ffi_compatible_option_swift_type(&self, _types: &TypeDeclarations) -> String {
+        "__private__OptionFfiUuid".to_string()
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        "struct __private__OptionFfiUuid".to_string()
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        _span: Span,
+    ) -> TokenStream {
+        let ffi_struct = Self::ffi_struct(swift_bridge_path);
+        quote! { #ffi_struct::from(#expression) }
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+    ) -> TokenStream {
+        let ffi_struct = Self::ffi_struct(swift_bridge_path);
+        let ffi_option_struct = Self::ffi_option_struct(swift_bridge_path);
+
+        quote! {
+            if let Some(val) = #expression {
+                #ffi_option_struct { val: #ffi_struct::from(val), is_some: true }
+            } else {
+                #ffi_option_struct { val: #ffi_struct { bytes: [0; 16] }, is_some: false }
+            }
+        }
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!("{expression}.intoFfiRepr()")
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!("{expression}.intoFfiRepr()")
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        _span: Span,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ffi_struct = Self::ffi_struct(swift_bridge_path);
+        quote! { #ffi_struct::into_uuid(#expression) }
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(&self, expression: &TokenStream) -> TokenStream {
+        quote! {
+            {
+                let val = #expression;
+                if val.is_some {
+                    Some(val.val.into_uuid())
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+        _types: &TypeDeclarations,
+    ) -> String {
+        format!("{expression}.intoSwiftRepr()")
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, expression: &str) -> String {
+        format!("{expression}.intoSwiftRepr()")
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        ok_ffi_value: &TokenStream,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ffi_struct = Self::ffi_struct(swift_bridge_path);
+        quote! { #ffi_struct::into_uuid(#ok_ffi_value) }
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        err_ffi_value: &TokenStream,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ffi_struct = Self::ffi_struct(swift_bridge_path);
+        quote! { #ffi_struct::into_uuid(#err_ffi_value) }
+    }
+
+    fn unused_option_none_val(&self, swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        let ffi_struct = Self::ffi_struct(swift_bridge_path);
+        UnusedOptionNoneValue {
+            rust: quote! { #ffi_struct { bytes: [0; 16] } },
+            swift: "__private__FfiUuid()".into(),
+        }
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        tokens == "Uuid" || tokens == "uuid :: Uuid"
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => Self::parse_token_stream_str(
+                path.path.to_token_stream().to_string().as_str(),
+                types,
+            ),
+            _ => None,
+        }
+    }
+
+    /// A bridge module can declare its own `type Uuid`, in which case a `Uuid` refers to that
+    /// type instead of the `uuid` crate's.
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_parse_token_stream_str(tokens) && types.get(tokens).is_none() {
+            Some(BridgeableUuid)
+        } else {
+            None
+        }
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, _types: &TypeDeclarations) -> String {
+        "Uuid".to_string()
+    }
+}
@@ -1,7 +1,7 @@
 use crate::bridged_type::{
     BridgeableType, CFfiStruct, OnlyEncoding, TypePosition, UnusedOptionNoneValue,
 };
-use crate::parse::{HostLang, OpaqueRustTypeGenerics};
+use crate::parse::{swift_protocol_handle_ident, HostLang, OpaqueRustTypeGenerics};
 use crate::{TypeDeclarations, SWIFT_BRIDGE_PREFIX};
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{quote, quote_spanned, ToTokens};
@@ -16,6 +16,9 @@ pub(crate) struct OpaqueForeignType {
     pub reference: bool,
     pub mutable: bool,
     pub has_swift_bridge_copy_annotation: bool,
+    /// Whether or not this is a bridged trait, which is implemented in Swift as a protocol
+    /// and used in Rust as a `Box<dyn Trait>`.
+    pub swift_protocol: bool,
     pub generics: OpaqueRustTypeGenerics,
 }
 
@@ -62,6 +65,20 @@ impl BridgeableType for OpaqueForeignType {
             quote! {
                 super:: #ty_name #generics
             }
+        } else if self.reference {
+            let maybe_mut = if self.mutable {
+                quote! { mut }
+            } else {
+                quote! {}
+            };
+
+            quote! {
+                & #maybe_mut #ty_name
+            }
+        } else if self.swift_protocol {
+            quote! {
+                Box<dyn #ty_name>
+            }
         } else {
             quote! {
                 #ty_name
@@ -112,7 +129,14 @@ impl BridgeableType for OpaqueForeignType {
                     )
                 }
                 TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
-                    unimplemented!()
+                    if self.has_swift_bridge_copy_annotation {
+                        self.copy_ffi_repr_type_string()
+                    } else {
+                        "UnsafeMutableRawPointer?".to_string()
+                    }
+                }
+                TypePosition::SwiftReturnsToRust => {
+                    self.to_swift_type(TypePosition::FnArg(HostLang::Rust, 0), types)
                 }
             }
         } else {
@@ -131,6 +155,9 @@ impl BridgeableType for OpaqueForeignType {
                 TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
                     unimplemented!()
                 }
+                TypePosition::SwiftReturnsToRust => {
+                    self.to_swift_type(TypePosition::FnArg(HostLang::Rust, 0), types)
+                }
             }
         }
     }
@@ -153,7 +180,7 @@ impl BridgeableType for OpaqueForeignType {
 
     fn to_ffi_compatible_rust_type(
         &self,
-        _swift_bridge_path: &Path,
+        swift_bridge_path: &Path,
         types: &TypeDeclarations,
     ) -> TokenStream {
         let ty_name = &self.ty;
@@ -178,6 +205,11 @@ impl BridgeableType for OpaqueForeignType {
                 } else {
                     quote! { *mut super::#ty_name #generics }
                 }
+            } else if self.reference {
+                quote! { #swift_bridge_path::PointerToSwiftType }
+            } else if self.swift_protocol {
+                let handle = swift_protocol_handle_ident(&self.ty);
+                quote! { #handle }
             } else {
                 quote! { #ty_name }
             }
@@ -194,12 +226,14 @@ impl BridgeableType for OpaqueForeignType {
         if self.has_swift_bridge_copy_annotation {
             let option_ty = self.option_copy_rust_repr_type();
             quote! { #option_ty }
+        } else if self.host_lang.is_swift() {
+            quote! { *mut std::ffi::c_void }
         } else {
             let generics = self
                 .generics
                 .angle_bracketed_concrete_generics_tokens(types);
 
-            if self.reference {
+            if self.reference && !self.mutable {
                 quote! { *const super::#type_name #generics }
             } else {
                 quote! { *mut super::#type_name #generics }
@@ -207,12 +241,12 @@ impl BridgeableType for OpaqueForeignType {
         }
     }
 
-    fn to_ffi_compatible_option_swift_type(
-        &self,
-        _swift_bridge_path: &Path,
-        _types: &TypeDeclarations,
-    ) -> String {
-        todo!()
+    fn to_ffi_compatible_option_swift_type(&self, _types: &TypeDeclarations) -> String {
+        if self.has_swift_bridge_copy_annotation {
+            self.option_copy_ffi_repr_type_string()
+        } else {
+            "UnsafeMutableRawPointer?".to_string()
+        }
     }
 
     fn to_ffi_compatible_option_c_type(&self) -> String {
@@ -226,7 +260,7 @@ impl BridgeableType for OpaqueForeignType {
     fn convert_rust_expression_to_ffi_type(
         &self,
         expression: &TokenStream,
-        _swift_bridge_path: &Path,
+        swift_bridge_path: &Path,
         types: &TypeDeclarations,
         span: Span,
     ) -> TokenStream {
@@ -259,6 +293,10 @@ impl BridgeableType for OpaqueForeignType {
                     })) as *mut super::#ty_name #generics
                 }
             }
+        } else if self.reference {
+            quote! {
+                #swift_bridge_path::PointerToSwiftType(#expression.0)
+            }
         } else {
             quote! {
                 #expression
@@ -291,11 +329,29 @@ impl BridgeableType for OpaqueForeignType {
         } else if self.reference {
             let ty = &self.ty;
 
+            if self.mutable {
+                quote! {
+                    if let Some(val) = #expression {
+                        val as *mut super::#ty
+                    } else {
+                        std::ptr::null_mut()
+                    }
+                }
+            } else {
+                quote! {
+                    if let Some(val) = #expression {
+                        val as *const super::#ty
+                    } else {
+                        std::ptr::null()
+                    }
+                }
+            }
+        } else if self.host_lang.is_swift() {
             quote! {
                 if let Some(val) = #expression {
-                    val as *const super::#ty
+                    std::mem::ManuallyDrop::new(val).0
                 } else {
-                    std::ptr::null()
+                    std::ptr::null_mut()
                 }
             }
         } else {
@@ -315,8 +371,6 @@ impl BridgeableType for OpaqueForeignType {
         _types: &TypeDeclarations,
         type_pos: TypePosition,
     ) -> String {
-        let ty_name = &self.ty;
-
         if self.host_lang.is_rust() {
             if self.has_swift_bridge_copy_annotation {
                 format!("{}.intoFfiRepr()", expression)
@@ -355,23 +409,30 @@ impl BridgeableType for OpaqueForeignType {
                     TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
                         unimplemented!()
                     }
+                    TypePosition::SwiftReturnsToRust => self.convert_swift_expression_to_ffi_type(
+                        expression,
+                        _types,
+                        TypePosition::FnArg(HostLang::Rust, 0),
+                    ),
                 }
             }
         } else {
             match type_pos {
                 TypePosition::FnArg(func_host_lang, _) => {
-                    if func_host_lang.is_rust() {
-                        format!("Unmanaged.passRetained({}).toOpaque()", expression)
+                    let instance = self.swift_unmanaged_instance(expression);
+                    if func_host_lang.is_rust() && self.reference {
+                        format!("Unmanaged.passUnretained({}).toOpaque()", instance)
+                    } else if func_host_lang.is_rust() {
+                        format!("Unmanaged.passRetained({}).toOpaque()", instance)
                     } else {
-                        format!(
-                            "Unmanaged<{type_name}>.fromOpaque({value}).takeRetainedValue()",
-                            type_name = ty_name,
-                            value = expression
-                        )
+                        self.swift_take_unmanaged(expression, "takeRetainedValue")
                     }
                 }
                 TypePosition::FnReturn(_func_host_lang) => {
-                    format!("Unmanaged.passRetained({}).toOpaque()", expression)
+                    format!(
+                        "Unmanaged.passRetained({}).toOpaque()",
+                        self.swift_unmanaged_instance(expression)
+                    )
                 }
                 TypePosition::SharedStructField => {
                     todo!("Opaque types in shared struct fields are not yet supported")
@@ -379,6 +440,11 @@ impl BridgeableType for OpaqueForeignType {
                 TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
                     unimplemented!()
                 }
+                TypePosition::SwiftReturnsToRust => self.convert_swift_expression_to_ffi_type(
+                    expression,
+                    _types,
+                    TypePosition::FnArg(HostLang::Rust, 0),
+                ),
             }
         }
     }
@@ -401,6 +467,8 @@ impl BridgeableType for OpaqueForeignType {
                 "{{ if let val = {expression} {{ return val.ptr }} else {{ return nil }} }}()",
                 expression = expression,
             )
+        } else if self.host_lang.is_swift() {
+            format!("{{ if let val = {expression} {{ return Unmanaged.passRetained(val).toOpaque() }} else {{ return nil }} }}()", expression = expression,)
         } else {
             format!("{{ if let val = {expression} {{ val.isOwned = false; return val.ptr }} else {{ return nil }} }}()", expression = expression,)
         }
@@ -440,7 +508,22 @@ impl BridgeableType for OpaqueForeignType {
             }
         } else {
             if self.reference {
-                todo!("Handle referenced self Swift types")
+                let ty_name = &self.ty;
+                let maybe_mut = if self.mutable {
+                    quote! { mut }
+                } else {
+                    quote! {}
+                };
+
+                // The Swift side did not retain the object for us, so we must not release it when
+                // the borrow ends.
+                quote! {
+                    & #maybe_mut *std::mem::ManuallyDrop::new(#ty_name(#expression.0))
+                }
+            } else if self.swift_protocol {
+                quote! {
+                    Box::new(#expression)
+                }
             } else {
                 quote! {
                     #expression
@@ -459,11 +542,30 @@ impl BridgeableType for OpaqueForeignType {
                 }
             }
         } else if self.reference {
+            let maybe_mut = if self.mutable {
+                quote! { mut }
+            } else {
+                quote! {}
+            };
+
             quote! {
                 if #expression.is_null() {
                     None
                 } else {
-                    Some(unsafe {& * #expression} )
+                    Some(unsafe {& #maybe_mut * #expression} )
+                }
+            }
+        } else if self.host_lang.is_swift() {
+            let ty = &self.ty;
+
+            quote! {
+                {
+                    let val = #expression;
+                    if val.is_null() {
+                        None
+                    } else {
+                        Some(#ty(val))
+                    }
                 }
             }
         } else {
@@ -485,6 +587,16 @@ impl BridgeableType for OpaqueForeignType {
     ) -> String {
         let mut ty_name = self.ty.to_string();
 
+        if self.host_lang.is_swift() {
+            let take_value = if self.reference {
+                "takeUnretainedValue"
+            } else {
+                "takeRetainedValue"
+            };
+
+            return self.swift_take_unmanaged(expression, take_value);
+        }
+
         if self.reference {
             ty_name += "Ref";
         }
@@ -504,6 +616,9 @@ impl BridgeableType for OpaqueForeignType {
                     TypePosition::FnReturn(fn_host_lang) if fn_host_lang.is_swift() => {
                         format!("Unmanaged.passRetained({expression}).toOpaque()")
                     }
+                    TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                        format!("{ty_name}(ptr: {expression}!)")
+                    }
                     _ => {
                         format!(
                             "{ty_name}(ptr: {value})",
@@ -530,6 +645,13 @@ impl BridgeableType for OpaqueForeignType {
                 expression = expression,
                 type_name = type_name
             )
+        } else if self.host_lang.is_swift() {
+            let type_name = self.swift_name();
+            format!(
+                "{{ let val = {expression}; if val != nil {{ return Unmanaged<{type_name}>.fromOpaque(val!).takeRetainedValue() }} else {{ return nil }} }}()",
+                expression = expression,
+                type_name = type_name
+            )
         } else {
             let type_name = self.swift_name();
             format!(
@@ -587,8 +709,16 @@ impl BridgeableType for OpaqueForeignType {
     fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
         let ty_name = &self.ty;
 
-        if self.reference {
-            todo!("Support returning Option<&T> where T is an opaque type")
+        if self.reference && self.mutable {
+            UnusedOptionNoneValue {
+                rust: quote! { std::ptr::null_mut::<super::#ty_name>() },
+                swift: "nil".into(),
+            }
+        } else if self.reference {
+            UnusedOptionNoneValue {
+                rust: quote! { std::ptr::null::<super::#ty_name>() },
+                swift: "nil".into(),
+            }
         } else {
             UnusedOptionNoneValue {
                 rust: quote! { std::ptr::null::<#ty_name>() as *mut super::#ty_name },
@@ -640,6 +770,15 @@ impl BridgeableType for OpaqueForeignType {
     where
         Self: Sized,
     {
+        // `Box<dyn SomeTrait>` where `SomeTrait` is a bridged trait.
+        if let Some(trait_name) = tokens
+            .strip_prefix("Box < dyn ")
+            .and_then(|tokens| tokens.strip_suffix(" >"))
+        {
+            let ty = types.get(trait_name)?.to_opaque_type(false, false)?;
+            return if ty.swift_protocol { Some(ty) } else { None };
+        }
+
         let bridged_type = types.get(tokens)?;
         bridged_type.to_opaque_type(false, false)
     }
@@ -673,8 +812,42 @@ impl BridgeableType for OpaqueForeignType {
 }
 
 impl OpaqueForeignType {
+    /// The Swift instance to pass to `Unmanaged`.
+    ///
+    /// `Unmanaged` only works with class types, so protocol instances get passed as `AnyObject`s.
+    fn swift_unmanaged_instance(&self, expression: &str) -> String {
+        if self.swift_protocol {
+            format!("{} as AnyObject", expression)
+        } else {
+            expression.to_string()
+        }
+    }
+
+    /// Take the Swift instance that a pointer points to.
+    ///
+    /// Unmanaged<SomeType>.fromOpaque(ptr).takeRetainedValue()
+    pub fn swift_take_unmanaged(&self, expression: &str, take_value: &str) -> String {
+        if self.swift_protocol {
+            format!(
+                "(Unmanaged<AnyObject>.fromOpaque({expression}).{take_value}() as! {ty_name})",
+                expression = expression,
+                take_value = take_value,
+                ty_name = self.ty
+            )
+        } else {
+            format!(
+                "Unmanaged<{ty_name}>.fromOpaque({expression}).{take_value}()",
+                ty_name = self.ty,
+                expression = expression,
+                take_value = take_value
+            )
+        }
+    }
+
     pub fn swift_name(&self) -> String {
-        if self.reference {
+        if self.reference && self.mutable {
+            format!("{}RefMut", self.ty)
+        } else if self.reference {
             format!("{}Ref", self.ty)
         } else {
             format!("{}", self.ty)
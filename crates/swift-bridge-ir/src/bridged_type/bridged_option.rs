@@ -1,6 +1,7 @@
+use crate::bridged_type::bridgeable_pointer::{Pointee, PointerKind};
 use crate::bridged_type::built_in_primitive::BuiltInPrimitive;
 use crate::bridged_type::{BridgedType, CustomBridgedType, SharedType, StdLibType, TypePosition};
-use crate::parse::TypeDeclarations;
+use crate::parse::{HostLang, TypeDeclarations};
 use proc_macro2::TokenStream;
 use quote::quote;
 use std::ops::Deref;
@@ -35,7 +36,7 @@ impl BridgedOption {
             }
             BridgedType::StdLib(stdlib_type) => match stdlib_type {
                 StdLibType::Null => {
-                    todo!("Option<()> is not yet supported")
+                    quote! { #expression.is_some() }
                 }
                 StdLibType::U8 => {
                     option_rust_primitive_to_ffi_primitive(quote! {OptionU8}, quote! {123})
@@ -76,8 +77,15 @@ impl BridgedOption {
                 StdLibType::Bool => {
                     option_rust_primitive_to_ffi_primitive(quote! {OptionBool}, quote! {false})
                 }
-                StdLibType::Pointer(_) => {
-                    todo!("Support Option<*const T> and Option<*mut T>")
+                StdLibType::Pointer(ptr) => {
+                    let null = ptr.null_pointer();
+                    quote! {
+                        if let Some(val) = #expression {
+                            val
+                        } else {
+                            #null
+                        }
+                    }
                 }
                 StdLibType::RefSlice(_) => {
                     todo!("Support Option<&[T]> and Option<&mut [T]>")
@@ -106,7 +114,7 @@ impl BridgedOption {
                 StdLibType::Result(_) => {
                     todo!("Support Option<Result<T, E>>")
                 }
-                StdLibType::BoxedFnOnce(_) => {
+                StdLibType::BoxedFn(_) => {
                     todo!("Option<Box<dyn FnOnce(A, B) -> C>> is not yet supported")
                 }
                 StdLibType::Tuple(_) => todo!(),
@@ -134,7 +142,9 @@ impl BridgedOption {
             BridgedType::Bridgeable(b) => b.convert_ffi_option_expression_to_rust_type(expression),
             BridgedType::StdLib(stdlib_ty) => match stdlib_ty {
                 StdLibType::Null => {
-                    todo!("Option<()> is not yet supported")
+                    quote! {
+                        if #expression { Some(()) } else { None }
+                    }
                 }
                 StdLibType::U8
                 | StdLibType::I8
@@ -161,19 +171,30 @@ impl BridgedOption {
                     }
                 }
                 StdLibType::Pointer(_) => {
-                    todo!("Option<*const T> and Option<*mut T> are not yet supported.")
+                    quote! {
+                        {
+                            let val = #expression;
+                            if val.is_null() { None } else { Some(val) }
+                        }
+                    }
                 }
                 StdLibType::RefSlice(_) => {
                     todo!("Option<*const T> and Option<*mut T> are not yet supported.")
                 }
                 StdLibType::Str => {
                     quote! {
-                        if #expression.start.is_null() { None } else { Some(#expression.to_str()) }
+                        {
+                            let val = #expression;
+                            if val.start.is_null() { None } else { Some(val.to_str()) }
+                        }
                     }
                 }
                 StdLibType::Vec(_) => {
                     quote! {
-                        if #expression.is_null() { None } else { Some( unsafe { * Box::from_raw(#expression) } ) }
+                        {
+                            let val = #expression;
+                            if val.is_null() { None } else { Some( unsafe { * Box::from_raw(val) } ) }
+                        }
                     }
                 }
                 StdLibType::Option(_) => {
@@ -182,7 +203,7 @@ impl BridgedOption {
                 StdLibType::Result(_) => {
                     todo!("Option<Result<T, E>> is not yet supported")
                 }
-                StdLibType::BoxedFnOnce(_) => {
+                StdLibType::BoxedFn(_) => {
                     todo!("Option<Box<dyn FnOnce(A, B) -> C>> is not yet supported")
                 }
                 StdLibType::Tuple(_) => todo!(),
@@ -205,7 +226,7 @@ impl BridgedOption {
             BridgedType::Bridgeable(b) => b.convert_ffi_option_expression_to_swift_type(expression),
             BridgedType::StdLib(stdlib_type) => match stdlib_type {
                 StdLibType::Null => {
-                    todo!("Option<()> is not yet supported")
+                    format!("{{ () -> Optional<()> in if {expression} {{ return () }} else {{ return nil }} }}()")
                 }
                 StdLibType::U8
                 | StdLibType::I8
@@ -222,9 +243,13 @@ impl BridgedOption {
                 | StdLibType::Bool => {
                     format!("{expression}.intoSwiftRepr()")
                 }
-                StdLibType::Pointer(_) => {
-                    todo!("Support Option<*const T> and Option<*mut T>")
-                }
+                StdLibType::Pointer(ptr) => match (&ptr.pointee, ptr.kind) {
+                    // `void*` is imported into Swift as an `UnsafeMutableRawPointer?`.
+                    (Pointee::Void(_), PointerKind::Const) => {
+                        format!("{expression}.map {{ UnsafeRawPointer($0) }}")
+                    }
+                    _ => expression.to_string(),
+                },
                 StdLibType::RefSlice(_) => {
                     todo!("Support Option<&[T]>")
                 }
@@ -247,7 +272,7 @@ impl BridgedOption {
                 StdLibType::Result(_) => {
                     todo!("Option<Result<T, E>> is not yet supported")
                 }
-                StdLibType::BoxedFnOnce(_) => {
+                StdLibType::BoxedFn(_) => {
                     todo!("Option<Box<dyn FnOnce(A, B) -> C>> is not yet supported")
                 }
                 StdLibType::Tuple(_) => todo!(),
@@ -272,7 +297,7 @@ impl BridgedOption {
             }
             BridgedType::StdLib(stdlib_type) => match stdlib_type {
                 StdLibType::Null => {
-                    todo!("Option<()> is not yet supported")
+                    format!("({expression} != nil)")
                 }
                 StdLibType::U8
                 | StdLibType::I8
@@ -289,9 +314,17 @@ impl BridgedOption {
                 | StdLibType::Bool => {
                     format!("{expression}.intoFfiRepr()")
                 }
-                StdLibType::Pointer(_) => {
-                    todo!("Option<*const T> and Option<*mut T> are not yet supported")
-                }
+                StdLibType::Pointer(ptr) => match (&ptr.pointee, ptr.kind, type_pos) {
+                    (
+                        Pointee::Void(_),
+                        PointerKind::Const,
+                        TypePosition::FnArg(func_host_lang, _)
+                        | TypePosition::FnReturn(func_host_lang),
+                    ) if func_host_lang.is_rust() => {
+                        format!("UnsafeMutableRawPointer(mutating: {expression})")
+                    }
+                    _ => expression.to_string(),
+                },
                 StdLibType::RefSlice(_) => {
                     todo!("Option<&[T]> is not yet supported")
                 }
@@ -310,7 +343,7 @@ impl BridgedOption {
                 StdLibType::Result(_) => {
                     todo!("Option<Result<T, E>> is not yet supported")
                 }
-                StdLibType::BoxedFnOnce(_) => {
+                StdLibType::BoxedFn(_) => {
                     todo!("Option<Box<dyn FnOnce(A, B) -> C>> is not yet supported")
                 }
                 StdLibType::Tuple(_) => todo!(),
@@ -338,14 +371,14 @@ impl BridgedOption {
         match type_pos {
             TypePosition::FnArg(func_host_lang, _) => {
                 if func_host_lang.is_swift() {
-                    self.to_ffi_compatible_swift_type(&types)
+                    self.to_ffi_compatible_swift_type(type_pos, types)
                 } else {
                     format!("Optional<{}>", self.ty.to_swift_type(type_pos, types))
                 }
             }
             TypePosition::FnReturn(func_host_lang) => {
                 if func_host_lang.is_swift() {
-                    self.to_ffi_compatible_swift_type(&types)
+                    self.to_ffi_compatible_swift_type(type_pos, types)
                 } else {
                     format!("Optional<{}>", self.ty.to_swift_type(type_pos, types))
                 }
@@ -354,17 +387,22 @@ impl BridgedOption {
                 format!("Optional<{}>", self.ty.to_swift_type(type_pos, types))
             }
             TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
-                unimplemented!()
+                self.to_ffi_compatible_swift_type(type_pos, types)
+            }
+            TypePosition::SwiftReturnsToRust => {
+                self.to_swift_type(TypePosition::FnArg(HostLang::Rust, 0), types)
             }
         }
     }
 
-    fn to_ffi_compatible_swift_type(&self, _types: &TypeDeclarations) -> String {
+    pub(super) fn to_ffi_compatible_swift_type(
+        &self,
+        type_pos: TypePosition,
+        types: &TypeDeclarations,
+    ) -> String {
         match self.ty.deref() {
             BridgedType::StdLib(stdlib_type) => match stdlib_type {
-                StdLibType::Null => {
-                    todo!()
-                }
+                StdLibType::Null => "Bool".to_string(),
                 StdLibType::U8
                 | StdLibType::I8
                 | StdLibType::U16
@@ -382,18 +420,14 @@ impl BridgedOption {
                     .to_option_ffi_repr_name()
                     .to_string(),
                 StdLibType::Pointer(_) => {
-                    todo!()
+                    format!("Optional<{}>", self.ty.to_swift_type(type_pos, types))
                 }
                 StdLibType::RefSlice(_) => {
                     todo!()
                 }
-                StdLibType::Str => {
-                    todo!()
-                }
-                StdLibType::Vec(_) => {
-                    todo!()
-                }
-                StdLibType::BoxedFnOnce(_) => {
+                StdLibType::Str => "RustStr".to_string(),
+                StdLibType::Vec(_) => "UnsafeMutableRawPointer?".to_string(),
+                StdLibType::BoxedFn(_) => {
                     todo!()
                 }
                 StdLibType::Option(_) => {
@@ -409,21 +443,17 @@ impl BridgedOption {
             BridgedType::Foreign(_) => {
                 todo!()
             }
-            BridgedType::Bridgeable(_) => {
-                todo!()
-            }
+            BridgedType::Bridgeable(b) => b.to_ffi_compatible_option_swift_type(types),
         }
     }
 }
 
 impl BridgedOption {
-    pub fn to_c(&self) -> String {
+    pub fn to_c(&self, types: &TypeDeclarations) -> String {
         match self.ty.deref() {
             BridgedType::Bridgeable(b) => b.to_ffi_compatible_option_c_type(),
             BridgedType::StdLib(stdlib_type) => match stdlib_type {
-                StdLibType::Null => {
-                    todo!("Option<()> is not yet supported")
-                }
+                StdLibType::Null => "bool".to_string(),
                 StdLibType::U8 => "struct __private__OptionU8".to_string(),
                 StdLibType::I8 => "struct __private__OptionI8".to_string(),
                 StdLibType::U16 => "struct __private__OptionU16".to_string(),
@@ -437,9 +467,7 @@ impl BridgedOption {
                 StdLibType::F32 => "struct __private__OptionF32".to_string(),
                 StdLibType::F64 => "struct __private__OptionF64".to_string(),
                 StdLibType::Bool => "struct __private__OptionBool".to_string(),
-                StdLibType::Pointer(_) => {
-                    todo!("Option<*const T> and Option<*mut T> are not yet supported")
-                }
+                StdLibType::Pointer(_) => self.ty.to_c(types),
                 StdLibType::RefSlice(_) => {
                     todo!("Option<&[T]> is not yet supported")
                 }
@@ -451,7 +479,7 @@ impl BridgedOption {
                 StdLibType::Result(_) => {
                     todo!("Option<Result<T, E>> is not yet supported")
                 }
-                StdLibType::BoxedFnOnce(_) => {
+                StdLibType::BoxedFn(_) => {
                     todo!("Option<Box<dyn FnOnce(A, B) -> C>> is not yet supported")
                 }
                 StdLibType::Tuple(_) => todo!(),
@@ -1,4 +1,6 @@
-use crate::bridged_type::StdLibType;
+use crate::bridged_type::{BridgedType, CFfiStruct, StdLibType};
+use proc_macro2::TokenStream;
+use quote::quote;
 
 /// Primitive types such as `()`, `u8` and `bool`.
 pub(crate) enum BuiltInPrimitive {
@@ -62,3 +64,41 @@ impl BuiltInPrimitive {
         Some(ty)
     }
 }
+
+/// Whether the type is a pointer sized integer (`usize`, `isize`, or an `Option` of one).
+///
+/// These are passed over FFI as C's `uintptr_t` and `intptr_t`, which Swift sees as `UInt` and
+/// `Int`.
+pub(crate) fn is_pointer_sized_integer(ty: &StdLibType) -> bool {
+    match ty {
+        StdLibType::Usize | StdLibType::Isize => true,
+        StdLibType::Option(opt) => matches!(
+            opt.ty.as_ref(),
+            BridgedType::StdLib(StdLibType::Usize | StdLibType::Isize)
+        ),
+        _ => false,
+    }
+}
+
+/// Fail to compile if `usize` and `isize` are not the same width as a pointer.
+///
+/// Swift's `UInt` and `Int` and C's `uintptr_t` and `intptr_t` are all pointer sized, so we
+/// check that Rust's `usize` and `isize` match them on the target platform.
+pub(crate) fn pointer_sized_integer_width_assertion_rust() -> TokenStream {
+    quote! {
+        const _: () = {
+            let _: [u8; std::mem::size_of::<usize>()] = [0; std::mem::size_of::<*const std::ffi::c_void>()];
+            let _: [u8; std::mem::size_of::<isize>()] = [0; std::mem::size_of::<*const std::ffi::c_void>()];
+        };
+    }
+}
+
+/// The C header's counterpart to [`pointer_sized_integer_width_assertion_rust`].
+pub(crate) fn pointer_sized_integer_width_assertion_c() -> CFfiStruct {
+    CFfiStruct {
+        c_ffi_type: r#"_Static_assert(sizeof(uintptr_t) == sizeof(void*), "uintptr_t must be pointer sized to match Rust's usize and Swift's UInt");
+_Static_assert(sizeof(intptr_t) == sizeof(void*), "intptr_t must be pointer sized to match Rust's isize and Swift's Int");"#
+            .to_string(),
+        fields: vec![],
+    }
+}
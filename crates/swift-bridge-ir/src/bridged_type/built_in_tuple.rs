@@ -2,7 +2,7 @@ use crate::bridged_type::shared_struct::UnnamedStructFields;
 use crate::bridged_type::{
     BridgeableType, BuiltInResult, CFfiStruct, OnlyEncoding, TypePosition, UnusedOptionNoneValue,
 };
-use crate::parse::TypeDeclarations;
+use crate::parse::{HostLang, TypeDeclarations};
 use crate::SWIFT_BRIDGE_PREFIX;
 use std::fmt::Debug;
 
@@ -124,6 +124,9 @@ impl BridgeableType for BuiltInTuple {
             }
             TypePosition::SharedStructField => todo!(),
             TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => todo!(),
+            TypePosition::SwiftReturnsToRust => {
+                self.to_swift_type(TypePosition::FnArg(HostLang::Rust, 0), types)
+            }
         }
     }
 
@@ -159,11 +162,7 @@ impl BridgeableType for BuiltInTuple {
         todo!()
     }
 
-    fn to_ffi_compatible_option_swift_type(
-        &self,
-        _swift_bridge_path: &Path,
-        _types: &TypeDeclarations,
-    ) -> String {
+    fn to_ffi_compatible_option_swift_type(&self, _types: &TypeDeclarations) -> String {
         todo!()
     }
 
@@ -215,6 +214,11 @@ impl BridgeableType for BuiltInTuple {
             }
             TypePosition::SharedStructField => todo!(),
             TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => todo!(),
+            TypePosition::SwiftReturnsToRust => self.convert_swift_expression_to_ffi_type(
+                expression,
+                types,
+                TypePosition::FnArg(HostLang::Rust, 0),
+            ),
         }
     }
 
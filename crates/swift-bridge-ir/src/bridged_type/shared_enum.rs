@@ -5,13 +5,17 @@ use std::fmt::{Debug, Formatter};
 use syn::LitStr;
 
 mod enum_variant;
-pub(crate) use self::enum_variant::EnumVariant;
+pub(crate) use self::enum_variant::{EnumDiscriminant, EnumVariant};
 
 use super::StructFields;
 
 #[derive(Default, Clone)]
 pub(crate) struct DeriveAttrs {
     pub debug: bool,
+    /// `serde::Serialize`, which also makes the Swift enum `Encodable`.
+    pub serialize: bool,
+    /// `serde::Deserialize`, which also makes the Swift enum `Decodable`.
+    pub deserialize: bool,
 }
 
 #[derive(Clone)]
@@ -111,6 +115,13 @@ impl SharedEnum {
     pub fn all_variants_empty(&self) -> bool {
         self.variants.iter().all(|v| v.fields.is_empty())
     }
+
+    /// Returns true if one or more of the variants has an explicit discriminant.
+    ///
+    /// `enum { VariantA = 1, VariantB }` would return true.
+    pub fn has_explicit_discriminants(&self) -> bool {
+        self.variants.iter().any(|v| v.discriminant.is_some())
+    }
 }
 
 impl PartialEq for SharedEnum {
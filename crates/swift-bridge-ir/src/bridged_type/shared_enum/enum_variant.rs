@@ -2,19 +2,78 @@ use crate::bridged_type::{BridgedType, StructFields, TypePosition};
 use crate::parse::TypeDeclarations;
 use proc_macro2::Ident;
 use proc_macro2::TokenStream;
-use quote::{format_ident, quote};
+use quote::{format_ident, quote, ToTokens};
 use std::fmt::{Debug, Formatter};
 use syn::spanned::Spanned;
-use syn::Path;
+use syn::{Expr, ExprLit, ExprUnary, Lit, LitInt, Path, UnOp};
 
 #[derive(Clone)]
 pub(crate) struct EnumVariant {
     pub name: Ident,
     #[allow(unused)]
     pub fields: StructFields,
+    /// The explicit discriminant, such as the `5` in `enum SomeEnum { Variant = 5 }`.
+    pub discriminant: Option<EnumDiscriminant>,
+}
+
+/// An integer literal discriminant, optionally negated, such as the `5` in `Variant = 5` or the
+/// `-1` in `Variant = -1`.
+#[derive(Clone)]
+pub(crate) struct EnumDiscriminant {
+    pub is_negative: bool,
+    pub value: LitInt,
+}
+
+impl EnumDiscriminant {
+    /// Returns `None` if the expression is not an integer literal with an optional leading `-`.
+    pub(crate) fn from_expr(expr: &Expr) -> Option<Self> {
+        match expr {
+            Expr::Lit(ExprLit {
+                lit: Lit::Int(value),
+                ..
+            }) => Some(EnumDiscriminant {
+                is_negative: false,
+                value: value.clone(),
+            }),
+            Expr::Unary(ExprUnary {
+                op: UnOp::Neg(_),
+                expr,
+                ..
+            }) => match expr.as_ref() {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Int(value),
+                    ..
+                }) => Some(EnumDiscriminant {
+                    is_negative: true,
+                    value: value.clone(),
+                }),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+impl ToTokens for EnumDiscriminant {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        if self.is_negative {
+            tokens.extend(quote! { - });
+        }
+        self.value.to_tokens(tokens);
+    }
 }
 
 impl EnumVariant {
+    /// The variant's discriminant as a Swift raw value, such as `5` or `-1`.
+    ///
+    /// Suffixes and other bases are dropped, so the `0x10u8` in `Variant = 0x10u8` becomes `16`.
+    pub(crate) fn swift_raw_value(&self) -> Option<String> {
+        self.discriminant.as_ref().map(|discriminant| {
+            let sign = if discriminant.is_negative { "-" } else { "" };
+            format!("{}{}", sign, discriminant.value.base10_digits())
+        })
+    }
+
     pub(crate) fn convert_rust_expression_to_ffi_repr(
         &self,
         types: &TypeDeclarations,
@@ -247,6 +306,13 @@ impl Debug for EnumVariant {
         f.debug_struct("EnumVariant")
             .field("name", &self.name.to_string())
             .field("fields", &self.fields)
+            .field(
+                "discriminant",
+                &self
+                    .discriminant
+                    .as_ref()
+                    .map(|d| d.to_token_stream().to_string()),
+            )
             .finish()
     }
 }
@@ -257,6 +257,10 @@ pub(crate) struct SharedStruct {
 pub(crate) struct StructDerives {
     pub copy: bool,
     pub clone: bool,
+    /// `serde::Serialize`, which also makes the Swift struct `Encodable`.
+    pub serialize: bool,
+    /// `serde::Deserialize`, which also makes the Swift struct `Decodable`.
+    pub deserialize: bool,
 }
 
 impl SharedStruct {
@@ -1,9 +1,11 @@
 use crate::bridge_module_attributes::CfgAttr;
 use crate::SwiftBridgeModule;
+use std::collections::{BTreeSet, HashMap, HashSet};
 
 mod generate_c_header;
 mod generate_rust_tokens;
 mod generate_swift;
+mod symbol_prefix;
 
 #[cfg(test)]
 mod codegen_tests;
@@ -16,19 +18,40 @@ pub struct SwiftCodeAndCHeader {
     pub c_header: String,
 }
 
+/// Declarations that more than one bridge module might need, such as the C struct of a
+/// `(u8, u16)` tuple. When we generate the code for several modules we only emit these once.
+#[derive(Default)]
+pub(crate) struct SharedDeclarations {
+    /// Custom Swift code, such as the extension that converts a tuple's FFI representation.
+    pub swift_code: HashSet<String>,
+    /// C type declarations, such as `typedef struct __swift_bridge__$tuple$...`.
+    pub c_types: HashSet<String>,
+    /// System headers, such as `stdint.h`.
+    pub c_includes: BTreeSet<&'static str>,
+}
+
 /// Configuration for how we will generate our Swift code.
 pub struct CodegenConfig {
     /// Look up whether or not a feature is enabled for the crate that holds the bridge module.
     /// This helps us decide whether or not to generate code for parts of the module
     /// that are annotated with `#[cfg(feature = "some-feature")]`
     pub crate_feature_lookup: Box<dyn Fn(&str) -> bool>,
+    /// Look up whether or not a cfg option other than a feature is set for the crate that holds
+    /// the bridge module, such as `unix` or `target_os = "ios"`.
+    /// This helps us decide whether or not to generate code for the functions and types that are
+    /// annotated with `#[cfg(...)]`
+    pub crate_cfg_lookup: CfgLookup,
 }
 
+/// Looks up a cfg option's name, along with its value if it has one.
+type CfgLookup = Box<dyn Fn(&str, Option<&str>) -> bool>;
+
 #[cfg(test)]
 impl CodegenConfig {
     pub(crate) fn no_features_enabled() -> Self {
         CodegenConfig {
             crate_feature_lookup: Box::new(|_| false),
+            crate_cfg_lookup: Box::new(|_, _| false),
         }
     }
 }
@@ -42,6 +65,43 @@ impl SwiftBridgeModule {
         }
     }
 
+    /// Generate the Swift code and C header for all of the bridge modules in a crate.
+    ///
+    /// Each module's code is concatenated, but helpers that several modules need, such as the
+    /// Swift and C representations of a tuple type that more than one module uses, are only
+    /// emitted once.
+    pub fn generate_swift_code_and_c_header_for_modules(
+        modules: &[SwiftBridgeModule],
+        config: CodegenConfig,
+    ) -> SwiftCodeAndCHeader {
+        // Modules with different symbol prefixes give their helpers different names, so only the
+        // modules that use the same prefix can share them.
+        let mut shared: HashMap<Option<&str>, SharedDeclarations> = HashMap::new();
+
+        let mut swift = "".to_string();
+        let mut c_declarations = "".to_string();
+        for module in modules {
+            let shared = shared.entry(module.symbol_prefix.as_deref()).or_default();
+
+            swift += &module.generate_swift_with_shared_declarations(&config, shared);
+            swift += "\n\n";
+
+            c_declarations +=
+                &module.generate_c_declarations_with_shared_declarations(&config, shared);
+            c_declarations += "\n\n";
+        }
+
+        let c_includes: BTreeSet<&'static str> = shared
+            .values()
+            .flat_map(|shared| shared.c_includes.iter().copied())
+            .collect();
+
+        SwiftCodeAndCHeader {
+            swift,
+            c_header: generate_c_header::c_header_file(&c_includes, c_declarations),
+        }
+    }
+
     /// Whether or not the module's conditional compilation flags willl lead it to being included
     /// in the final binary.
     /// If not, when we won't generate any C or Swift code for it.
@@ -59,3 +119,130 @@ impl SwiftBridgeModule {
         true
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::ToTokens;
+    use syn::parse_quote;
+
+    /// Verify that when we generate the code for multiple modules we concatenate each module's
+    /// code, but only emit the helpers that both modules need once.
+    #[test]
+    fn merges_modules_and_deduplicates_shared_declarations() {
+        let first: SwiftBridgeModule = parse_quote! {
+            #[swift_bridge::bridge(swift_path_type = "URL")]
+            mod ffi1 {
+                extern "Rust" {
+                    fn first_function(path: PathBuf) -> (i32, u8);
+                }
+            }
+        };
+        let second: SwiftBridgeModule = parse_quote! {
+            #[swift_bridge::bridge(swift_path_type = "URL")]
+            mod ffi2 {
+                extern "Rust" {
+                    fn second_function(path: PathBuf) -> (i32, u8);
+                }
+            }
+        };
+        let config = CodegenConfig::no_features_enabled();
+
+        let generated = SwiftBridgeModule::generate_swift_code_and_c_header_for_modules(
+            &[first, second],
+            config,
+        );
+
+        assert!(generated.swift.contains("public func first_function("));
+        assert!(generated.swift.contains("public func second_function("));
+        assert_eq!(generated.swift.matches("import Foundation").count(), 1);
+
+        assert!(generated
+            .c_header
+            .contains("__swift_bridge__$first_function("));
+        assert!(generated
+            .c_header
+            .contains("__swift_bridge__$second_function("));
+        assert_eq!(
+            generated
+                .c_header
+                .matches("typedef struct __swift_bridge__$tuple$I32U8")
+                .count(),
+            1
+        );
+        assert_eq!(generated.c_header.matches("#include <stdint.h>").count(), 1);
+        assert_eq!(
+            generated
+                .c_header
+                .matches("// File automatically generated by swift-bridge.")
+                .count(),
+            1
+        );
+    }
+
+    /// Verify that modules with different symbol prefixes each get their own helpers, since the
+    /// prefix makes the helpers' names differ.
+    #[test]
+    fn modules_with_different_symbol_prefixes_do_not_share_declarations() {
+        let first: SwiftBridgeModule = parse_quote! {
+            #[swift_bridge::bridge(symbol_prefix = "first")]
+            mod ffi1 {
+                extern "Rust" {
+                    fn first_function() -> (i32, u8);
+                }
+            }
+        };
+        let second: SwiftBridgeModule = parse_quote! {
+            mod ffi2 {
+                extern "Rust" {
+                    fn second_function() -> (i32, u8);
+                }
+            }
+        };
+        let config = CodegenConfig::no_features_enabled();
+
+        let generated = SwiftBridgeModule::generate_swift_code_and_c_header_for_modules(
+            &[first, second],
+            config,
+        );
+
+        assert!(generated
+            .c_header
+            .contains("typedef struct __swift_bridge__$first$tuple$I32U8"));
+        assert!(generated
+            .c_header
+            .contains("typedef struct __swift_bridge__$tuple$I32U8"));
+        assert_eq!(generated.c_header.matches("#include <stdint.h>").count(), 1);
+    }
+
+    /// Verify that generating the code for the same module more than once always gives the same
+    /// output, so that generated files don't change between builds.
+    #[test]
+    fn generated_code_is_deterministic() {
+        let generate = || {
+            let module: SwiftBridgeModule = parse_quote! {
+                mod ffi {
+                    extern "Rust" {
+                        fn tuples(arg: (u8, u16)) -> (i32, String);
+                        fn more_tuples(arg: (bool, u64)) -> (f32, f64, u8);
+                        fn slices(a: &[u8], b: &[u16], c: &[i64]);
+                        fn strings(a: String, b: &str);
+                    }
+                }
+            };
+            let generated =
+                module.generate_swift_code_and_c_header(CodegenConfig::no_features_enabled());
+
+            (
+                module.to_token_stream().to_string(),
+                generated.swift,
+                generated.c_header,
+            )
+        };
+
+        let first = generate();
+        for _ in 0..10 {
+            assert!(first == generate());
+        }
+    }
+}
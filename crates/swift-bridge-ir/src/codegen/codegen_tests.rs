@@ -28,29 +28,60 @@ use crate::test_utils::{
 };
 
 mod already_declared_attribute_codegen_tests;
+mod arc_codegen_tests;
+mod argument_default_codegen_tests;
 mod argument_label_codegen_tests;
+mod array_codegen_tests;
 mod async_function_codegen_tests;
+mod box_codegen_tests;
+mod boxed_fn_codegen_tests;
 mod boxed_fnonce_codegen_tests;
+mod bridged_trait_codegen_tests;
 mod built_in_tuple_codegen_tests;
 mod c_header_declaration_order_codegen_tests;
+mod char_codegen_tests;
 mod conditional_compilation_codegen_tests;
+mod constant_codegen_tests;
+mod cow_str_codegen_tests;
+mod custom_type_bridge_codegen_tests;
 mod derive_attribute_codegen_tests;
 mod derive_struct_attribute_codegen_tests;
+mod doc_comment_codegen_tests;
+mod duration_codegen_tests;
+mod extern_c_fn_codegen_tests;
 mod extern_rust_function_opaque_rust_type_argument_codegen_tests;
 mod extern_rust_function_opaque_rust_type_return_codegen_tests;
 mod extern_rust_method_swift_class_placement_codegen_tests;
 mod extern_swift_function_opaque_swift_type_return_codegen_tests;
 mod function_attribute_codegen_tests;
 mod generic_opaque_rust_type_codegen_tests;
+mod hash_map_codegen_tests;
+mod hash_set_codegen_tests;
+mod int128_codegen_tests;
+mod non_zero_codegen_tests;
 mod opaque_rust_type_codegen_tests;
 mod opaque_swift_type_codegen_tests;
+mod opaque_type_field_codegen_tests;
 mod option_codegen_tests;
+mod panic_codegen_tests;
+mod path_codegen_tests;
+mod pointer_codegen_tests;
+mod pointer_sized_integer_codegen_tests;
+mod range_codegen_tests;
 mod result_codegen_tests;
 mod return_into_attribute_codegen_tests;
 mod single_representation_type_elision_codegen_tests;
+mod slice_codegen_tests;
 mod string_codegen_tests;
+mod swift_access_codegen_tests;
+mod swift_keyword_argument_codegen_tests;
+mod swift_namespace_codegen_tests;
+mod symbol_prefix_codegen_tests;
+mod system_time_codegen_tests;
 mod transparent_enum_codegen_tests;
 mod transparent_struct_codegen_tests;
+#[cfg(feature = "uuid")]
+mod uuid_codegen_tests;
 mod vec_codegen_tests;
 
 struct CodegenTest {
@@ -171,6 +202,7 @@ impl CodegenTest {
         let crate_feature_lookup = Box::new(lookup);
         let codegen_config = CodegenConfig {
             crate_feature_lookup,
+            crate_cfg_lookup: Box::new(|_, _| false),
         };
 
         let swift = module.generate_swift(&codegen_config);
@@ -321,3 +321,57 @@ mod already_declared_rust_copy_type_methods {
         .test();
     }
 }
+
+/// Verify that we use the Rust struct of an already declared opaque Swift type instead of
+/// declaring it again, and that we do not re-generate its Swift `_free` function.
+mod extern_swift_already_declared_type_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    #[swift_bridge(already_declared)]
+                    type MyType;
+
+                    fn some_function(arg: MyType);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub fn some_function (arg: MyType) {
+                unsafe { __swift_bridge__some_function (arg) }
+            }
+
+            use super::MyType;
+
+            #[allow(improper_ctypes)]
+            extern "C" {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function (arg: MyType);
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::DoesNotContainAfterTrim("__swift_bridge__$MyType$_free")
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim("")
+    }
+
+    #[test]
+    fn extern_swift_already_declared_type_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,358 @@
+//! Tests for bridging `std::sync::Arc<T>` where `T` is an opaque Rust type.
+//!
+//! An `Arc<Foo>` is an `ArcFoo` class on the Swift side. Every `ArcFoo` holds one strong count of
+//! the `Arc`, so many Swift instances can share the same Rust `Foo`.
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that a Rust function can return an `Arc` of an opaque Rust type, and that we generate
+/// the functions that Swift uses to clone and release the `Arc`.
+mod extern_rust_fn_return_arc {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type Foo;
+                    fn some_function() -> Arc<Foo>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$some_function"]
+                pub extern "C" fn __swift_bridge__some_function() -> *const super::Foo {
+                    std::sync::Arc::into_raw(super::some_function())
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$Foo$_arc_clone"]
+                pub extern "C" fn __swift_bridge__Foo__arc_clone(this: *const super::Foo) -> *const super::Foo {
+                    unsafe { std::sync::Arc::increment_strong_count(this) };
+                    this
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$Foo$_arc_drop"]
+                pub extern "C" fn __swift_bridge__Foo__arc_drop(this: *const super::Foo) {
+                    unsafe { std::sync::Arc::decrement_strong_count(this) };
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function() -> ArcFoo {
+    ArcFoo(ptr: __swift_bridge__$some_function())
+}
+"#,
+            r#"
+public class ArcFoo: FooRef {
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+
+    public convenience init(_ other: ArcFoo) {
+        self.init(ptr: __swift_bridge__$Foo$_arc_clone(other.ptr))
+    }
+
+    deinit {
+        __swift_bridge__$Foo$_arc_drop(ptr)
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+void* __swift_bridge__$some_function(void);
+"#,
+            r#"
+void* __swift_bridge__$Foo$_arc_clone(void* self);
+void __swift_bridge__$Foo$_arc_drop(void* self);
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_return_arc() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that Swift hands Rust its own strong count when it passes an `Arc` to a Rust function.
+mod extern_rust_fn_arc_arg {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type Foo;
+                    fn some_function(arg: std::sync::Arc<Foo>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(arg: *const super::Foo) {
+                super::some_function(unsafe { std::sync::Arc::from_raw(arg) })
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: ArcFoo) {
+    __swift_bridge__$some_function(__swift_bridge__$Foo$_arc_clone(arg.ptr))
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$some_function(void* arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_arc_arg() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a Rust function can take and return an `Option<Arc<T>>`, which is passed over FFI
+/// as a nullable pointer.
+mod extern_rust_fn_option_arc_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type Foo;
+                    fn some_function(arg: Option<Arc<Foo>>) -> Option<Arc<Foo>>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(arg: *const super::Foo) -> *const super::Foo {
+                if let Some(val) = super::some_function({
+                    let val = arg;
+                    if val.is_null() {
+                        None
+                    } else {
+                        Some(unsafe { std::sync::Arc::from_raw(val) })
+                    }
+                }) {
+                    std::sync::Arc::into_raw(val)
+                } else {
+                    std::ptr::null::<super::Foo>()
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: Optional<ArcFoo>) -> Optional<ArcFoo> {
+    { let val = __swift_bridge__$some_function({ if let val = arg { return __swift_bridge__$Foo$_arc_clone(val.ptr) } else { return nil } }()); if val != nil { return ArcFoo(ptr: val!) } else { return nil } }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$some_function(void* arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_option_arc_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a method that takes a `self: Arc<Self>` is added to the `ArcFoo` class, and that
+/// Swift hands Rust its own strong count for the duration of the call.
+///
+/// We only use the `Arc<Foo>` in the methods' receivers, to verify that the `ArcFoo` class and
+/// the functions that clone and release the `Arc` still get generated.
+mod extern_rust_method_arc_self {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type Foo;
+                    fn a(self: Arc<Self>);
+                    fn b(self: std::sync::Arc<Foo>, arg: u8) -> u8;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$Foo$_arc_clone"]
+                pub extern "C" fn __swift_bridge__Foo__arc_clone(this: *const super::Foo) -> *const super::Foo {
+                    unsafe { std::sync::Arc::increment_strong_count(this) };
+                    this
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$Foo$a"]
+                pub extern "C" fn __swift_bridge__Foo_a(this: *const super::Foo) {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::assert_valid_receiver(this, "Foo::a");
+                    (unsafe { std::sync::Arc::from_raw(this) }).a()
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$Foo$b"]
+                pub extern "C" fn __swift_bridge__Foo_b(this: *const super::Foo, arg: u8) -> u8 {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::assert_valid_receiver(this, "Foo::b");
+                    (unsafe { std::sync::Arc::from_raw(this) }).b(arg)
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+extension ArcFoo {
+    public func a() {
+        __swift_bridge__$Foo$a(__swift_bridge__$Foo$_arc_clone(self.ptr))
+    }
+
+    public func b(_ arg: UInt8) -> UInt8 {
+        __swift_bridge__$Foo$b(__swift_bridge__$Foo$_arc_clone(self.ptr), arg)
+    }
+}
+"#,
+            r#"
+public class ArcFoo: FooRef {
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "void __swift_bridge__$Foo$a(void* self);",
+            "uint8_t __swift_bridge__$Foo$b(void* self, uint8_t arg);",
+            "void* __swift_bridge__$Foo$_arc_clone(void* self);",
+            "void __swift_bridge__$Foo$_arc_drop(void* self);",
+        ])
+    }
+
+    #[test]
+    fn extern_rust_method_arc_self() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a Swift function can take and return an `Arc` of an opaque Rust type.
+mod extern_swift_fn_arc_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type Foo;
+                }
+
+                extern "Swift" {
+                    fn some_function(arg: Arc<Foo>) -> Arc<Foo>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub fn some_function(arg: std::sync::Arc<super::Foo>) -> std::sync::Arc<super::Foo> {
+                unsafe {
+                    std::sync::Arc::from_raw(unsafe {
+                        __swift_bridge__some_function(std::sync::Arc::into_raw(arg))
+                    })
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: UnsafeMutableRawPointer) -> UnsafeMutableRawPointer {
+    __swift_bridge__$Foo$_arc_clone(some_function(arg: ArcFoo(ptr: arg)).ptr)
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_arc_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
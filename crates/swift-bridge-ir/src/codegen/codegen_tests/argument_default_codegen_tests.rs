@@ -0,0 +1,116 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we declare default parameter values for `#[swift_bridge(default = ...)]`
+/// attributes.
+mod argument_default {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(
+                        #[swift_bridge(label = "count", default = 10)] count: u32,
+                        #[swift_bridge(default = -0.5)] offset: f64,
+                        #[swift_bridge(default = None)] maybe: Option<u8>,
+                    );
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(count: UInt32 = 10, _ offset: Double = -0.5, _ maybe: Optional<UInt8> = nil) {
+"#,
+        )
+    }
+
+    #[test]
+    fn argument_default() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that a method's default parameter values are declared on the Swift class's method.
+mod method_argument_default {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn greet(&self, #[swift_bridge(default = false)] loudly: bool);
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+    public func greet(_ loudly: Bool = false) {
+"#,
+        )
+    }
+
+    #[test]
+    fn method_argument_default() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we don't declare default parameter values on the Swift functions that Rust calls,
+/// since Rust always passes every argument.
+mod extern_swift_argument_default {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(#[swift_bridge(default = 10)] count: u32);
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func __swift_bridge__some_function (_ count: UInt32) {
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_argument_default() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -115,3 +115,88 @@ void __swift_bridge__$some_function(int32_t parameter_name1, uint32_t parameter_
         .test();
     }
 }
+
+/// Verify that the `#[swift_bridge(labeled_args)]` function attribute uses each parameter name
+/// as its argument label, unless the argument has its own label.
+mod labeled_args {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(labeled_args)]
+                    fn some_method(
+                        &self,
+                        width: i32,
+                        #[swift_bridge(label = "toHeight")] height: u32,
+                    );
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+    public func some_method(width: Int32, toHeight height: UInt32) {
+        __swift_bridge__$SomeType$some_method(ptr, width, height)
+    }
+"#,
+        )
+    }
+
+    #[test]
+    fn labeled_args() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we pass an extern "Swift" function's arguments to the Swift implementation using
+/// their argument labels.
+mod extern_swift_argument_label {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(
+                        #[swift_bridge(label = "argumentLabel1")] parameter_name1: i32,
+                        #[swift_bridge(label = "_")] parameter_name2: u32,
+                        parameter_name3: u8,
+                    );
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+    some_function(argumentLabel1: parameter_name1, parameter_name2, parameter_name3: parameter_name3)
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_argument_label() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -0,0 +1,141 @@
+//! Tests for bridging fixed-size arrays such as `[u8; 32]`.
+//!
+//! An array is passed over FFI inside of a `#[repr(C)]` struct, and is seen on the Swift side as
+//! a tuple.
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that a Rust function can take and return fixed-size arrays.
+mod extern_rust_fn_array_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: [u8; 4]) -> [f32; 2];
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__array_U8_4 {
+                    pub elements: [u8; 4]
+                }
+            },
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__array_F32_2 {
+                    pub elements: [f32; 2]
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$some_function"]
+                pub extern "C" fn __swift_bridge__some_function(
+                    arg: __swift_bridge__array_U8_4
+                ) -> __swift_bridge__array_F32_2 {
+                    __swift_bridge__array_F32_2 { elements: super::some_function(arg.elements) }
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: (UInt8, UInt8, UInt8, UInt8)) -> (Float, Float) {
+    __swift_bridge__$some_function(__swift_bridge__$array$U8$4(elements: arg)).elements
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+struct __swift_bridge__$array$F32$2 __swift_bridge__$some_function(struct __swift_bridge__$array$U8$4 arg);
+"#,
+            r#"
+typedef struct __swift_bridge__$array$F32$2 { float elements[2]; } __swift_bridge__$array$F32$2;
+"#,
+            r#"
+typedef struct __swift_bridge__$array$U8$4 { uint8_t elements[4]; } __swift_bridge__$array$U8$4;
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_array_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a Swift function can take and return fixed-size arrays.
+mod extern_swift_fn_array_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(arg: [i64; 2]) -> [bool; 3];
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: [i64; 2]) -> [bool; 3] {
+                    unsafe {
+                        __swift_bridge__some_function(__swift_bridge__array_I64_2 { elements: arg })
+                    }
+                    .elements
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(
+                    arg: __swift_bridge__array_I64_2
+                ) -> __swift_bridge__array_Bool_3;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: __swift_bridge__$array$I64$2) -> __swift_bridge__$array$Bool$3 {
+    __swift_bridge__$array$Bool$3(elements: some_function(arg: arg.elements))
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_array_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
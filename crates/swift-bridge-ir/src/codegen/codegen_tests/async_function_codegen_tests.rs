@@ -445,6 +445,98 @@ void __swift_bridge__$some_function(void* callback_wrapper, void __swift_bridge_
     }
 }
 
+/// Verify that we generate the correct code for extern "Rust" async functions that returns an
+/// opaque Rust type.
+mod extern_rust_async_function_returns_opaque_rust_type {
+    use super::*;
+
+    fn bridge_module() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                    async fn some_function() -> SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+             pub extern "C" fn __swift_bridge__some_function(
+                callback_wrapper: *mut std::ffi::c_void,
+                callback: extern "C" fn(*mut std::ffi::c_void, *mut super::SomeType) -> (),
+            ) {
+                let callback_wrapper = swift_bridge::async_support::SwiftCallbackWrapper(callback_wrapper);
+                let fut = super::some_function();
+                let task = async move {
+                    let val = Box::into_raw(Box::new({
+                        let val: super::SomeType = fut.await;
+                        val
+                    })) as *mut super::SomeType;
+
+                    let callback_wrapper = callback_wrapper;
+                    let callback_wrapper = callback_wrapper.0;
+
+                    (callback)(callback_wrapper, val)
+                };
+                swift_bridge::async_support::ASYNC_RUNTIME.spawn_task(Box::pin(task))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function() async -> SomeType {
+    func onComplete(cbWrapperPtr: UnsafeMutableRawPointer?, rustFnRetVal: UnsafeMutableRawPointer?) {
+        let wrapper = Unmanaged<CbWrapper$some_function>.fromOpaque(cbWrapperPtr!).takeRetainedValue()
+        wrapper.cb(.success(SomeType(ptr: rustFnRetVal!)))
+    }
+
+    return await withCheckedContinuation({ (continuation: CheckedContinuation<SomeType, Never>) in
+        let callback = { rustFnRetVal in
+            continuation.resume(with: rustFnRetVal)
+        }
+
+        let wrapper = CbWrapper$some_function(cb: callback)
+        let wrapperPtr = Unmanaged.passRetained(wrapper).toOpaque()
+
+        __swift_bridge__$some_function(wrapperPtr, onComplete)
+    })
+}
+class CbWrapper$some_function {
+    var cb: (Result<SomeType, Never>) -> ()
+
+    public init(cb: @escaping (Result<SomeType, Never>) -> ()) {
+        self.cb = cb
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$some_function(void* callback_wrapper, void __swift_bridge__$some_function$async(void* callback_wrapper, void* ret));
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_async_function_returns_opaque_rust_type() {
+        CodegenTest {
+            bridge_module: bridge_module().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
 /// Verify that we generate the correct code for extern "Rust" async method.
 mod extern_rust_async_method {
     use super::*;
@@ -468,6 +560,8 @@ mod extern_rust_async_method {
                 callback: extern "C" fn(*mut std::ffi::c_void) -> (),
                 this: *mut super::SomeType
             ) {
+                #[cfg(debug_assertions)]
+                swift_bridge::assert_valid_receiver(this, "SomeType::some_method");
                 let callback_wrapper = swift_bridge::async_support::SwiftCallbackWrapper(callback_wrapper);
                 let fut = (unsafe {&*this}).some_method();
                 let task = async move {
@@ -506,7 +600,7 @@ extension SomeTypeRef {
     }
     class CbWrapper$SomeType$some_method {
         var cb: (Result<(), Never>) -> ()
-    
+
         public init(cb: @escaping (Result<(), Never>) -> ()) {
             self.cb = cb
         }
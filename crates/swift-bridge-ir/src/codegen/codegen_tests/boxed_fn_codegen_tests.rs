@@ -0,0 +1,407 @@
+//! Tests for passing Box<dyn Fn(A, B) -> C> and Box<dyn FnMut(A, B) -> C> between languages.
+//!
+//! Unlike an `FnOnce`, these can be called any number of times, so the call function borrows the
+//! boxed function and it only gets dropped when Swift frees the callback.
+//!
+//! Swift closures that get passed to Rust functions are retained by Swift and released when the
+//! Rust `Box<dyn Fn..>` that wraps them is dropped.
+//!
+//! See `boxed_fnonce_codegen_tests.rs` for more on how boxed functions are passed over FFI.
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we can pass a `Box<dyn Fn>` with a primitive arg and return value from Rust to
+/// Swift.
+mod test_swift_takes_fn_callback_primitive {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(callback: Box<dyn Fn(u32) -> u32>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function (callback: Box<dyn Fn(u32) -> u32>) {
+                    unsafe {
+                        __swift_bridge__some_function(
+                            Box::into_raw(Box::new(callback)) as *mut Box<dyn Fn(u32) -> u32>
+                        )
+                    }
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$some_function$param0"]
+                pub extern "C" fn some_function_param0(some_function_callback: *mut Box<dyn Fn(u32) -> u32>, arg0: u32) -> u32 {
+                    unsafe { (*some_function_callback)(arg0) }
+                }
+
+                #[export_name = "__swift_bridge__$some_function$_free$param0"]
+                pub extern "C" fn free_some_function_param0(some_function_callback: *mut Box<dyn Fn(u32) -> u32>) {
+                    let _ = unsafe { Box::from_raw(some_function_callback) };
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(callback: *mut Box<dyn Fn(u32) -> u32>);
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+class __private__RustFnCallback$some_function$param0 {
+    var ptr: UnsafeMutableRawPointer
+
+    init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+    }
+
+    deinit {
+        __swift_bridge__$some_function$_free$param0(ptr)
+    }
+
+    func call(_ arg0: UInt32) -> UInt32 {
+        return __swift_bridge__$some_function$param0(ptr, arg0)
+    }
+}
+            "#,
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ callback: UnsafeMutableRawPointer) {
+    { let cb0 = __private__RustFnCallback$some_function$param0(ptr: callback); let _ = some_function(callback: { arg0 in cb0.call(arg0) }) }()
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+uint32_t __swift_bridge__$some_function$param0(void* some_function_callback, uint32_t arg0);
+void __swift_bridge__$some_function$_free$param0(void* some_function_callback);
+"#,
+        )
+    }
+
+    #[test]
+    fn test_swift_takes_fn_callback_primitive() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a `Box<dyn FnMut>` that has no args or return value gets its own call and free
+/// functions instead of using the `FnOnce` support class that ships with `swift-bridge`.
+mod test_swift_takes_fnmut_callback_no_args_no_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(callback: Box<dyn FnMut()>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$some_function$param0"]
+                pub extern "C" fn some_function_param0(some_function_callback: *mut Box<dyn FnMut() -> ()>) {
+                    unsafe { (*some_function_callback)() }
+                }
+
+                #[export_name = "__swift_bridge__$some_function$_free$param0"]
+                pub extern "C" fn free_some_function_param0(some_function_callback: *mut Box<dyn FnMut() -> ()>) {
+                    let _ = unsafe { Box::from_raw(some_function_callback) };
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(callback: *mut Box<dyn FnMut() -> ()>);
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+class __private__RustFnMutCallback$some_function$param0 {
+    var ptr: UnsafeMutableRawPointer
+
+    init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+    }
+
+    deinit {
+        __swift_bridge__$some_function$_free$param0(ptr)
+    }
+
+    func call() {
+        return __swift_bridge__$some_function$param0(ptr)
+    }
+}
+            "#,
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ callback: UnsafeMutableRawPointer) {
+    { let cb0 = __private__RustFnMutCallback$some_function$param0(ptr: callback); let _ = some_function(callback: { cb0.call() }) }()
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$some_function$param0(void* some_function_callback);
+void __swift_bridge__$some_function$_free$param0(void* some_function_callback);
+"#,
+        )
+    }
+
+    #[test]
+    fn test_swift_takes_fnmut_callback_no_args_no_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can pass a Swift closure with a primitive arg and return value to a Rust
+/// function.
+mod test_rust_takes_swift_closure_primitive {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(callback: Box<dyn Fn(u32) -> u32>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$some_function"]
+                pub extern "C" fn __swift_bridge__some_function(callback: *mut std::ffi::c_void) {
+                    super::some_function({
+                        let callback = swift_bridge::boxed_fn_support::SwiftCallback::new(
+                            callback,
+                            __swift_bridge__some_function_free_param0
+                        );
+                        Box::new(move |arg0: u32| -> u32 {
+                            unsafe { __swift_bridge__some_function_param0(callback.as_ptr(), arg0) }
+                        })
+                    })
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function$param0"]
+                fn __swift_bridge__some_function_param0(callback: *mut std::ffi::c_void, arg0: u32) -> u32;
+
+                #[link_name = "__swift_bridge__$some_function$_free$param0"]
+                fn __swift_bridge__some_function_free_param0(callback: *mut std::ffi::c_void);
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+class __private__SwiftCallback$some_function$param0 {
+    var callback: (UInt32) -> UInt32
+
+    init(_ callback: @escaping (UInt32) -> UInt32) {
+        self.callback = callback
+    }
+}
+@_cdecl("__swift_bridge__$some_function$param0")
+func __swift_bridge__some_function_param0 (_ callback: UnsafeMutableRawPointer, _ arg0: UInt32) -> UInt32 {
+    let value = Unmanaged<__private__SwiftCallback$some_function$param0>.fromOpaque(callback).takeUnretainedValue().callback(arg0)
+    return value
+}
+@_cdecl("__swift_bridge__$some_function$_free$param0")
+func __swift_bridge__some_function_free_param0 (_ callback: UnsafeMutableRawPointer) {
+    Unmanaged<__private__SwiftCallback$some_function$param0>.fromOpaque(callback).release()
+}
+"#,
+            r#"
+public func some_function(_ callback: @escaping (UInt32) -> UInt32) {
+    __swift_bridge__$some_function(Unmanaged.passRetained(__private__SwiftCallback$some_function$param0(callback)).toOpaque())
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+void __swift_bridge__$some_function(void* callback);
+"#,
+        )
+    }
+
+    #[test]
+    fn test_rust_takes_swift_closure_primitive() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a Swift closure that gets passed to a Rust function returns a native Swift
+/// `String`, which then gets converted into a `RustString` for Rust.
+mod test_rust_takes_swift_closure_returning_string {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(callback: Box<dyn Fn(String) -> String>);
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+    init(_ callback: @escaping (RustString) -> String) {
+"#,
+            r#"
+public func some_function(_ callback: @escaping (RustString) -> String) {
+"#,
+        ])
+    }
+
+    #[test]
+    fn test_rust_takes_swift_closure_returning_string() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we can pass a Swift closure to a Rust method.
+mod test_rust_method_takes_swift_closure {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn some_method(&self, callback: Box<dyn FnMut()>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$some_method"]
+                pub extern "C" fn __swift_bridge__SomeType_some_method(
+                    this: *mut super::SomeType,
+                    callback: *mut std::ffi::c_void
+                ) {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::assert_valid_receiver(this, "SomeType::some_method");
+                    (unsafe { &*this }).some_method({
+                        let callback = swift_bridge::boxed_fn_support::SwiftCallback::new(
+                            callback,
+                            __swift_bridge__SomeType_some_method_free_param1
+                        );
+                        Box::new(move || -> () {
+                            unsafe { __swift_bridge__SomeType_some_method_param1(callback.as_ptr()) }
+                        })
+                    })
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$SomeType$some_method$param1"]
+                fn __swift_bridge__SomeType_some_method_param1(callback: *mut std::ffi::c_void);
+
+                #[link_name = "__swift_bridge__$SomeType$some_method$_free$param1"]
+                fn __swift_bridge__SomeType_some_method_free_param1(callback: *mut std::ffi::c_void);
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+class __private__SwiftCallback$SomeType$some_method$param1 {
+    var callback: () -> ()
+
+    init(_ callback: @escaping () -> ()) {
+        self.callback = callback
+    }
+}
+@_cdecl("__swift_bridge__$SomeType$some_method$param1")
+func __swift_bridge__SomeType_some_method_param1 (_ callback: UnsafeMutableRawPointer) {
+    Unmanaged<__private__SwiftCallback$SomeType$some_method$param1>.fromOpaque(callback).takeUnretainedValue().callback()
+}
+"#,
+            r#"
+    public func some_method(_ callback: @escaping () -> ()) {
+        __swift_bridge__$SomeType$some_method(ptr, Unmanaged.passRetained(__private__SwiftCallback$SomeType$some_method$param1(callback)).toOpaque())
+    }
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$SomeType$some_method(void* self, void* callback);
+"#,
+        )
+    }
+
+    #[test]
+    fn test_rust_method_takes_swift_closure() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,156 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we generate a Rust trait that is implemented by a type that holds a Swift instance
+/// of the corresponding Swift protocol.
+mod bridged_trait {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                trait Greeter {
+                    fn greet(&self, name: String) -> String;
+                    fn reset(&mut self);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub trait Greeter {
+                    fn greet(&self, name: String) -> String;
+                    fn reset(&mut self);
+                }
+
+                #[doc(hidden)]
+                #[repr(C)]
+                pub struct __swift_bridge__Greeter(*mut std::ffi::c_void);
+
+                impl Greeter for __swift_bridge__Greeter {
+                    fn greet(&self, name: String) -> String {
+                        unsafe {
+                            Box::from_raw(unsafe {
+                                __swift_bridge__Greeter_greet(
+                                    swift_bridge::PointerToSwiftType(self.0),
+                                    swift_bridge::string::RustString(name).box_into_raw()
+                                )
+                            }).0
+                        }
+                    }
+
+                    fn reset(&mut self) {
+                        unsafe {
+                            __swift_bridge__Greeter_reset(swift_bridge::PointerToSwiftType(self.0))
+                        }
+                    }
+                }
+
+                impl Drop for __swift_bridge__Greeter {
+                    fn drop (&mut self) {
+                        unsafe { __swift_bridge__Greeter__free(self.0) }
+                    }
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$Greeter$_free"]
+                fn __swift_bridge__Greeter__free (this: *mut std::ffi::c_void);
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public protocol Greeter: AnyObject {
+    func greet(name: RustString) -> String
+    func reset()
+}
+"#,
+            r#"
+@_cdecl("__swift_bridge__$Greeter$greet")
+func __swift_bridge__Greeter_greet (_ this: UnsafeMutableRawPointer, _ name: UnsafeMutableRawPointer) -> UnsafeMutableRawPointer {
+    { let rustString = (Unmanaged<AnyObject>.fromOpaque(this).takeUnretainedValue() as! Greeter).greet(name: RustString(ptr: name)).intoRustString(); rustString.isOwned = false; return rustString.ptr }()
+}
+"#,
+            r#"
+@_cdecl("__swift_bridge__$Greeter$_free")
+func __swift_bridge__Greeter__free (ptr: UnsafeMutableRawPointer) {
+    let _ = Unmanaged<AnyObject>.fromOpaque(ptr).takeRetainedValue()
+}
+"#,
+        ])
+    }
+
+    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::ExactAfterTrim(r#""#);
+
+    #[test]
+    fn bridged_trait() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: EXPECTED_C_HEADER,
+        }
+        .test();
+    }
+}
+
+/// Verify that a Swift class that implements a bridged trait's protocol can be passed to Rust as
+/// a `Box<dyn Trait>`.
+mod extern_rust_fn_with_boxed_dyn_trait_arg {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                trait Greeter {
+                    fn greet(&self, name: String) -> String;
+                }
+
+                extern "Rust" {
+                    fn use_greeter(greeter: Box<dyn Greeter>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$use_greeter"]
+            pub extern "C" fn __swift_bridge__use_greeter(greeter: __swift_bridge__Greeter) {
+                super::use_greeter(Box::new(greeter))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func use_greeter(_ greeter: Greeter) {
+    __swift_bridge__$use_greeter(Unmanaged.passRetained(greeter as AnyObject).toOpaque())
+}
+"#,
+        )
+    }
+
+    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::ContainsAfterTrim(
+        r#"
+void __swift_bridge__$use_greeter(void* greeter);
+"#,
+    );
+
+    #[test]
+    fn extern_rust_fn_with_boxed_dyn_trait_arg() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: EXPECTED_C_HEADER,
+        }
+        .test();
+    }
+}
@@ -275,6 +275,87 @@ struct __swift_bridge__$tuple$F32IntBool __swift_bridge__$some_function(struct _
     }
 }
 
+/// Verify that we can return a tuple from a Rust method, and use the same tuple type in multiple
+/// functions.
+mod extern_rust_method_returns_tuple {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type Shape;
+                    fn bounds(&self) -> (f64, f64, f64, f64);
+                    fn default_bounds() -> (f64, f64, f64, f64);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![quote! {
+            #[repr(C)]
+            #[doc(hidden)]
+            pub struct __swift_bridge__tuple_F64F64F64F64(f64, f64, f64, f64);
+            #[export_name = "__swift_bridge__$Shape$bounds"]
+            pub extern "C" fn __swift_bridge__Shape_bounds (this: *mut super::Shape) -> __swift_bridge__tuple_F64F64F64F64 {
+                #[cfg(debug_assertions)]
+                swift_bridge::assert_valid_receiver(this, "Shape::bounds");
+                { let val = (unsafe { &*this }).bounds();
+                __swift_bridge__tuple_F64F64F64F64(val.0, val.1, val.2, val.3) }
+            }
+            #[export_name = "__swift_bridge__$default_bounds"]
+            pub extern "C" fn __swift_bridge__default_bounds () -> __swift_bridge__tuple_F64F64F64F64 {
+                { let val = super::default_bounds();
+                __swift_bridge__tuple_F64F64F64F64(val.0, val.1, val.2, val.3) }
+            }
+        }])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+extension ShapeRef {
+    public func bounds() -> (Double, Double, Double, Double) {
+        { let val = __swift_bridge__$Shape$bounds(ptr); return (val._0, val._1, val._2, val._3); }()
+    }
+}
+"#,
+            r#"
+public func default_bounds() -> (Double, Double, Double, Double) {
+    { let val = __swift_bridge__$default_bounds(); return (val._0, val._1, val._2, val._3); }()
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+struct __swift_bridge__$tuple$F64F64F64F64 __swift_bridge__$Shape$bounds(void* self);
+"#,
+            r#"
+struct __swift_bridge__$tuple$F64F64F64F64 __swift_bridge__$default_bounds(void);
+"#,
+            r#"
+typedef struct __swift_bridge__$tuple$F64F64F64F64 { double _0; double _1; double _2; double _3; } __swift_bridge__$tuple$F64F64F64F64;
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_method_returns_tuple() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
 /// Verify that we can use a (primitive type, primitive type) as Swift function arg and return type.
 mod extern_swift_tuple_primitives {
     use super::*;
@@ -54,3 +54,68 @@ typedef struct __swift_bridge__$ResultTupleI32ResultTestOpaqueRustTypeStringAndI
         .test();
     }
 }
+
+/// Verify that a shared struct that is used as a field of another shared struct gets declared
+/// first in the C header, even if it is declared after the struct that uses it.
+mod shared_struct_field_generated_before_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct Line {
+                    start: Point,
+                    end: Option<Point>
+                }
+
+                #[swift_bridge(swift_repr = "struct")]
+                struct Point {
+                    x: f64,
+                    y: f64
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {})
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public struct Line {
+    public var start: Point
+    public var end: Optional<Point>
+
+    public init(start: Point,end: Optional<Point>) {
+        self.start = start
+        self.end = end
+    }
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$Point { double x; double y; } __swift_bridge__$Point;
+typedef struct __swift_bridge__$Option$Point { bool is_some; __swift_bridge__$Point val; } __swift_bridge__$Option$Point;
+typedef struct __swift_bridge__$Line { struct __swift_bridge__$Point start; struct __swift_bridge__$Option$Point end; } __swift_bridge__$Line;
+typedef struct __swift_bridge__$Option$Line { bool is_some; __swift_bridge__$Line val; } __swift_bridge__$Option$Line;
+"#,
+        )
+    }
+
+    #[test]
+    fn shared_struct_field_generated_before_struct() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
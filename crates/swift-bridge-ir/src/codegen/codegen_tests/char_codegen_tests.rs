@@ -0,0 +1,233 @@
+//! Tests for bridging `char`.
+//!
+//! A Rust `char` is a Swift `Unicode.Scalar`. It is passed over FFI as a `u32`, and converting it
+//! back into a `char` or a `Unicode.Scalar` is checked.
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that a Rust function can take and return a `char`.
+mod extern_rust_fn_char_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: char) -> char;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(arg: u32) -> u32 {
+                u32::from(super::some_function(char::from_u32(arg).unwrap()))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: Unicode.Scalar) -> Unicode.Scalar {
+    Unicode.Scalar(__swift_bridge__$some_function(arg.value))!
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+uint32_t __swift_bridge__$some_function(uint32_t arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_char_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a Swift function can take and return a `char`.
+mod extern_swift_fn_char_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(arg: char) -> char;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub fn some_function(arg: char) -> char {
+                char::from_u32(unsafe { __swift_bridge__some_function(u32::from(arg)) }).unwrap()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: UInt32) -> UInt32 {
+    some_function(arg: Unicode.Scalar(arg)!).value
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_char_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that a Rust function can take and return an `Option<char>`.
+mod extern_rust_fn_option_char_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Option<char>) -> Option<char>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::option::OptionU32
+            ) -> swift_bridge::option::OptionU32 {
+                if let Some(val) = super::some_function({
+                    let val = arg;
+                    if val.is_some {
+                        Some(char::from_u32(val.val).unwrap())
+                    } else {
+                        None
+                    }
+                }) {
+                    swift_bridge::option::OptionU32 { val: u32::from(val), is_some: true }
+                } else {
+                    swift_bridge::option::OptionU32 { val: 123, is_some: false }
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: Optional<Unicode.Scalar>) -> Optional<Unicode.Scalar> {
+    { let val = __swift_bridge__$some_function({ let val = arg; return __private__OptionU32(val: val?.value ?? 123, is_some: val != nil) }()); if val.is_some { return Unicode.Scalar(val.val)! } else { return nil } }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__OptionU32 __swift_bridge__$some_function(struct __private__OptionU32 arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_option_char_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a shared struct can have a `char` field.
+mod shared_struct_char_field {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                struct SomeStruct {
+                    field: char
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__SomeStruct {
+                    field: u32
+                }
+            },
+            quote! {
+                SomeStruct { field: char::from_u32(val.field).unwrap() }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public struct SomeStruct {
+    public var field: Unicode.Scalar
+"#,
+            r#"
+{ let val = self; return SomeStruct(field: Unicode.Scalar(val.field)!); }()
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$SomeStruct { uint32_t field; } __swift_bridge__$SomeStruct;
+"#,
+        )
+    }
+
+    #[test]
+    fn shared_struct_char_field() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
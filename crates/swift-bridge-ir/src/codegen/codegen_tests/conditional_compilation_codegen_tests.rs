@@ -120,3 +120,257 @@ some_function
         .test();
     }
 }
+
+/// Verify that we keep a `#[cfg(feature = "foo")]` on an extern Rust function, and generate its
+/// Swift and C code when the feature is enabled.
+mod cfg_feature_function_feature_enabled {
+    use super::*;
+    use crate::codegen::codegen_tests::BridgeModule;
+
+    fn bridge_module() -> BridgeModule {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[cfg(feature = "some-feature")]
+                    fn some_function();
+                }
+            }
+        };
+        BridgeModule {
+            tokens,
+            enabled_crate_features: vec!["some-feature"],
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[cfg(feature = "some-feature")]
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function() {
+                super::some_function()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function()
+"#,
+        )
+    }
+
+    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::ExactAfterTrim(
+        r#"
+void __swift_bridge__$some_function(void);
+    "#,
+    );
+
+    #[test]
+    fn cfg_feature_function_feature_enabled() {
+        CodegenTest {
+            bridge_module: bridge_module(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: EXPECTED_C_HEADER,
+        }
+        .test();
+    }
+}
+
+/// Verify that we keep a `#[cfg(feature = "foo")]` on an extern Rust function, but don't
+/// generate its Swift and C code when the feature is disabled.
+mod cfg_feature_function_feature_disabled {
+    use super::*;
+    use crate::codegen::codegen_tests::BridgeModule;
+
+    fn bridge_module() -> BridgeModule {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[cfg(feature = "some-feature")]
+                    fn some_function();
+
+                    fn another_function();
+                }
+            }
+        };
+        BridgeModule {
+            tokens,
+            enabled_crate_features: vec![],
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[cfg(feature = "some-feature")]
+                #[export_name = "__swift_bridge__$some_function"]
+                pub extern "C" fn __swift_bridge__some_function() {
+                    super::some_function()
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$another_function"]
+                pub extern "C" fn __swift_bridge__another_function() {
+                    super::another_function()
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::DoesNotContainAfterTrim(
+            r#"
+func some_function
+"#,
+        )
+    }
+
+    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::ExactAfterTrim(
+        r#"
+void __swift_bridge__$another_function(void);
+    "#,
+    );
+
+    #[test]
+    fn cfg_feature_function_feature_disabled() {
+        CodegenTest {
+            bridge_module: bridge_module(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: EXPECTED_C_HEADER,
+        }
+        .test();
+    }
+}
+
+/// Verify that we keep a `#[cfg(target_os = "...")]` on both the Rust function that calls an
+/// extern Swift function and on its extern declaration, but don't generate its Swift and C code
+/// when the cfg option isn't set.
+mod cfg_option_extern_swift_function {
+    use super::*;
+
+    fn bridge_module_tokens() -> proc_macro2::TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Swift" {
+                    #[cfg(target_os = "ios")]
+                    fn some_function();
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[cfg(target_os = "ios")]
+                pub fn some_function() {
+                    unsafe { __swift_bridge__some_function() }
+                }
+            },
+            quote! {
+                #[cfg(target_os = "ios")]
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function();
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::DoesNotContainAfterTrim(
+            r#"
+some_function
+"#,
+        )
+    }
+
+    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::DoesNotContainAfterTrim(
+        r#"
+some_function
+    "#,
+    );
+
+    #[test]
+    fn cfg_option_extern_swift_function() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: EXPECTED_C_HEADER,
+        }
+        .test();
+    }
+}
+
+/// Verify that a `#[cfg(...)]` on an opaque Rust type gets kept on the type's generated Rust
+/// functions and methods, and that we don't generate the type's Swift and C code when the cfg is
+/// disabled.
+mod cfg_feature_opaque_rust_type_feature_disabled {
+    use super::*;
+
+    fn bridge_module_tokens() -> proc_macro2::TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[cfg(feature = "some-feature")]
+                    type SomeType;
+
+                    fn some_method(&self);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[cfg(feature = "some-feature")]
+                #[export_name = "__swift_bridge__$SomeType$_free"]
+                pub extern "C" fn __swift_bridge__SomeType__free(this: *mut super::SomeType) {
+                    let this = unsafe { Box::from_raw(this) };
+                    drop(this);
+                }
+            },
+            quote! {
+                #[cfg(feature = "some-feature")]
+                #[export_name = "__swift_bridge__$SomeType$some_method"]
+                pub extern "C" fn __swift_bridge__SomeType_some_method(this: *mut super::SomeType) {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::assert_valid_receiver(this, "SomeType::some_method");
+                    (unsafe { &*this }).some_method()
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::DoesNotContainAfterTrim(
+            r#"
+SomeType
+"#,
+        )
+    }
+
+    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::DoesNotContainAfterTrim(
+        r#"
+SomeType
+    "#,
+    );
+
+    #[test]
+    fn cfg_feature_opaque_rust_type_feature_disabled() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: EXPECTED_C_HEADER,
+        }
+        .test();
+    }
+}
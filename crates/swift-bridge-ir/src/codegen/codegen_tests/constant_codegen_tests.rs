@@ -0,0 +1,134 @@
+//! Tests for `const` and `static` items in the bridge module.
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that a constant whose value is a literal is kept in the Rust module and is exposed to
+/// Swift as a `public let` with the same value.
+mod literal_constants {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                pub const MAX_SIZE: u32 = 1024;
+                pub const MIN_TEMPERATURE: f64 = -273.15;
+                pub const IS_ENABLED: bool = true;
+                pub static GREETING: &str = "Hello \"world\"";
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[allow(dead_code)]
+                pub const MAX_SIZE: u32 = 1024;
+            },
+            quote! {
+                #[allow(dead_code)]
+                pub const MIN_TEMPERATURE: f64 = -273.15;
+            },
+            quote! {
+                #[allow(dead_code)]
+                pub const IS_ENABLED: bool = true;
+            },
+            quote! {
+                #[allow(dead_code)]
+                pub static GREETING: &str = "Hello \"world\"";
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public let MAX_SIZE: UInt32 = 1024
+public let MIN_TEMPERATURE: Double = -273.15
+public let IS_ENABLED: Bool = true
+public let GREETING: String = "Hello \"world\""
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim("")
+    }
+
+    #[test]
+    fn literal_constants() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a constant whose value isn't a literal is read by Swift through a generated Rust
+/// function.
+mod non_literal_constants {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                pub const MAX_SIZE: u32 = 1 << 10;
+                pub static VERSION: &'static str = env!("CARGO_PKG_VERSION");
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub const MAX_SIZE: u32 = 1 << 10;
+
+                #[export_name = "__swift_bridge__$MAX_SIZE"]
+                pub extern "C" fn __swift_bridge__MAX_SIZE() -> u32 {
+                    MAX_SIZE
+                }
+            },
+            quote! {
+                pub static VERSION: &'static str = env!("CARGO_PKG_VERSION");
+
+                #[export_name = "__swift_bridge__$VERSION"]
+                pub extern "C" fn __swift_bridge__VERSION() -> swift_bridge::string::RustStr {
+                    swift_bridge::string::RustStr::from_str(VERSION)
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public let MAX_SIZE: UInt32 = __swift_bridge__$MAX_SIZE()
+public let VERSION: String = __swift_bridge__$VERSION().toString()
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "uint32_t __swift_bridge__$MAX_SIZE(void);",
+            "struct RustStr __swift_bridge__$VERSION(void);",
+        ])
+    }
+
+    #[test]
+    fn non_literal_constants() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
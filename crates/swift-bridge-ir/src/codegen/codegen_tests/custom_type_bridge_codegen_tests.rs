@@ -0,0 +1,302 @@
+//! Tests for bridging third-party types using a registered `CustomTypeBridge`.
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use crate::{register_custom_type_bridge, CustomTypeBridge};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Bridges a `chrono::DateTime<chrono::Utc>` as an `i64` number of milliseconds since the Unix
+/// epoch, which Swift sees as a `Date`.
+struct DateTimeBridge;
+
+impl CustomTypeBridge for DateTimeBridge {
+    fn rust_type(&self) -> TokenStream {
+        quote! { chrono::DateTime<chrono::Utc> }
+    }
+
+    fn ffi_repr(&self) -> TokenStream {
+        quote! { i64 }
+    }
+
+    fn swift_type(&self) -> String {
+        "Date".to_string()
+    }
+
+    fn rust_to_ffi_repr(&self, value: &TokenStream) -> TokenStream {
+        quote! { #value.timestamp_millis() }
+    }
+
+    fn rust_from_ffi_repr(&self, value: &TokenStream) -> TokenStream {
+        quote! { chrono::TimeZone::timestamp_millis_opt(&chrono::Utc, #value).unwrap() }
+    }
+
+    fn swift_to_ffi_repr(&self, value: &str) -> String {
+        format!("Int64({value}.timeIntervalSince1970 * 1000)")
+    }
+
+    fn swift_from_ffi_repr(&self, value: &str) -> String {
+        format!("Date(timeIntervalSince1970: Double({value}) / 1000)")
+    }
+}
+
+/// Verify that a Rust function can take and return a type that has a registered bridge.
+mod extern_rust_fn_custom_type_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(
+                        arg: chrono::DateTime<chrono::Utc>
+                    ) -> chrono::DateTime<chrono::Utc>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(arg: i64) -> i64 {
+                super::some_function(
+                    chrono::TimeZone::timestamp_millis_opt(&chrono::Utc, arg).unwrap()
+                ).timestamp_millis()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: Date) -> Date {
+    Date(timeIntervalSince1970: Double(__swift_bridge__$some_function(Int64(arg.timeIntervalSince1970 * 1000))) / 1000)
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+int64_t __swift_bridge__$some_function(int64_t arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_custom_type_arg_and_return() {
+        register_custom_type_bridge(DateTimeBridge);
+
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a Swift function can take and return a type that has a registered bridge.
+mod extern_swift_fn_custom_type_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(
+                        arg: chrono::DateTime<chrono::Utc>
+                    ) -> chrono::DateTime<chrono::Utc>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub fn some_function(
+                arg: chrono::DateTime<chrono::Utc>
+            ) -> chrono::DateTime<chrono::Utc> {
+                chrono::TimeZone::timestamp_millis_opt(
+                    &chrono::Utc,
+                    unsafe { __swift_bridge__some_function(arg.timestamp_millis()) }
+                ).unwrap()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: Int64) -> Int64 {
+    Int64(some_function(arg: Date(timeIntervalSince1970: Double(arg) / 1000)).timeIntervalSince1970 * 1000)
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_custom_type_arg_and_return() {
+        register_custom_type_bridge(DateTimeBridge);
+
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that a Rust function can take and return an `Option` of a type that has a registered
+/// bridge.
+mod extern_rust_fn_option_custom_type_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(
+                        arg: Option<chrono::DateTime<chrono::Utc>>
+                    ) -> Option<chrono::DateTime<chrono::Utc>>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::option::OptionI64
+            ) -> swift_bridge::option::OptionI64 {
+                if let Some(val) = (super::some_function(
+                    ({
+                        let val = arg;
+                        if val.is_some {
+                            Some(val.val)
+                        } else {
+                            None
+                        }
+                    })
+                    .map(|val| chrono::TimeZone::timestamp_millis_opt(&chrono::Utc, val).unwrap())
+                ))
+                .map(|val| val.timestamp_millis())
+                {
+                    swift_bridge::option::OptionI64 { val, is_some: true }
+                } else {
+                    swift_bridge::option::OptionI64 { val: 123, is_some: false }
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: Optional<Date>) -> Optional<Date> {
+    __swift_bridge__$some_function(arg.map { Int64($0.timeIntervalSince1970 * 1000) }.intoFfiRepr()).intoSwiftRepr().map { Date(timeIntervalSince1970: Double($0) / 1000) }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__OptionI64 __swift_bridge__$some_function(struct __private__OptionI64 arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_option_custom_type_arg_and_return() {
+        register_custom_type_bridge(DateTimeBridge);
+
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a bridge module's own declaration of a type takes precedence over a registered
+/// bridge for the type.
+mod declared_type_takes_precedence_over_custom_type_bridge {
+    use super::*;
+
+    /// Bridges a `DeclaredType` as a `u8`, unless the bridge module declares its own
+    /// `DeclaredType`.
+    struct DeclaredTypeBridge;
+
+    impl CustomTypeBridge for DeclaredTypeBridge {
+        fn rust_type(&self) -> TokenStream {
+            quote! { DeclaredType }
+        }
+
+        fn ffi_repr(&self) -> TokenStream {
+            quote! { u8 }
+        }
+
+        fn swift_type(&self) -> String {
+            "UInt8".to_string()
+        }
+
+        fn rust_to_ffi_repr(&self, value: &TokenStream) -> TokenStream {
+            quote! { #value.0 }
+        }
+
+        fn rust_from_ffi_repr(&self, value: &TokenStream) -> TokenStream {
+            quote! { DeclaredType(#value) }
+        }
+
+        fn swift_to_ffi_repr(&self, value: &str) -> String {
+            value.to_string()
+        }
+
+        fn swift_from_ffi_repr(&self, value: &str) -> String {
+            value.to_string()
+        }
+    }
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type DeclaredType;
+                    fn some_function(arg: DeclaredType);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(arg: *mut super::DeclaredType) {
+                super::some_function(unsafe { *Box::from_raw(arg) })
+            }
+        })
+    }
+
+    #[test]
+    fn declared_type_takes_precedence_over_custom_type_bridge() {
+        register_custom_type_bridge(DeclaredTypeBridge);
+
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: ExpectedSwiftCode::SkipTest,
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -93,3 +93,67 @@ mod derive_clone_struct {
         .test();
     }
 }
+
+/// Verify that deriving serde's `Serialize` and `Deserialize` on a transparent struct makes the
+/// Swift struct `Codable`.
+mod derive_serde_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                #[derive(serde::Serialize, serde::Deserialize)]
+                struct SomeStruct {
+                    field: u8,
+                }
+
+                #[swift_bridge(swift_repr = "struct")]
+                #[derive(serde::Serialize)]
+                struct AnotherStruct {
+                    field: u8,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[derive(::serde::Serialize, ::serde::Deserialize)]
+                pub struct SomeStruct {
+                    pub field: u8
+                }
+            },
+            quote! {
+                #[derive(::serde::Serialize)]
+                pub struct AnotherStruct {
+                    pub field: u8
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            "extension SomeStruct: Codable {}",
+            "extension AnotherStruct: Encodable {}",
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn derive_serde_struct() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,165 @@
+//! Tests for emitting Rust doc comments as documentation on the generated Swift code.
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that a freestanding function's doc comment is emitted on the generated Swift function.
+mod function_doc_comment {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    /// Add two numbers.
+                    ///
+                    /// Overflows wrap around.
+                    fn add(a: u8, b: u8) -> u8;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$add"]
+            pub extern "C" fn __swift_bridge__add(a: u8, b: u8) -> u8 {
+                super::add(a, b)
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+/// Add two numbers.
+///
+/// Overflows wrap around.
+public func add(_ a: UInt8, _ b: UInt8) -> UInt8 {
+    __swift_bridge__$add(a, b)
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(r#"uint8_t __swift_bridge__$add(uint8_t a, uint8_t b);"#)
+    }
+
+    #[test]
+    fn function_doc_comment() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that an opaque Rust type's doc comment is emitted on its generated Swift class, and
+/// that its methods' doc comments are emitted on their generated Swift methods.
+mod opaque_rust_type_doc_comment {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    /// A counter.
+                    type SomeType;
+
+                    /// The current count.
+                    fn count(&self) -> u32;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::SkipTest
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+/// A counter.
+public class SomeType: SomeTypeRefMut {
+"#,
+            r#"
+extension SomeTypeRef {
+    /// The current count.
+    public func count() -> UInt32 {
+        __swift_bridge__$SomeType$count(ptr)
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn opaque_rust_type_doc_comment() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that an opaque Copy type's doc comment is emitted on its generated Swift struct.
+mod opaque_copy_type_doc_comment {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    /// A point.
+                    #[swift_bridge(Copy(8))]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::SkipTest
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+/// A point.
+public struct SomeType {
+    fileprivate var bytes: __swift_bridge__$SomeType
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn opaque_copy_type_doc_comment() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,230 @@
+//! Tests for bridging `std::time::Duration`.
+//!
+//! A `Duration` is a `Double` number of seconds on the Swift side, the same as a Foundation
+//! `TimeInterval`.
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that a Rust function can take and return a `Duration`.
+mod extern_rust_fn_duration_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Duration) -> std::time::Duration;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(arg: f64) -> f64 {
+                super::some_function({
+                        let secs: f64 = arg;
+                        std::time::Duration::try_from_secs_f64(secs).unwrap_or(if secs > 0. {
+                            std::time::Duration::MAX
+                        } else {
+                            std::time::Duration::ZERO
+                        })
+                    }).as_secs_f64()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: Double) -> Double {
+    __swift_bridge__$some_function(arg)
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+double __swift_bridge__$some_function(double arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_duration_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a Swift function can take and return a `Duration`.
+mod extern_swift_fn_duration_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(arg: Duration) -> Duration;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub fn some_function(arg: std::time::Duration) -> std::time::Duration {
+                {
+                    let secs: f64 = unsafe { __swift_bridge__some_function(arg.as_secs_f64()) };
+                    std::time::Duration::try_from_secs_f64(secs).unwrap_or(if secs > 0. {
+                        std::time::Duration::MAX
+                    } else {
+                        std::time::Duration::ZERO
+                    })
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: Double) -> Double {
+    some_function(arg: arg)
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_duration_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that a Rust function can take and return an `Option<Duration>`.
+mod extern_rust_fn_option_duration_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Option<Duration>) -> Option<Duration>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::option::OptionF64
+            ) -> swift_bridge::option::OptionF64 {
+                if let Some(val) = super::some_function({
+                    let val = arg;
+                    if val.is_some {
+                        Some({
+                            let secs: f64 = val.val;
+                            std::time::Duration::try_from_secs_f64(secs).unwrap_or(if secs > 0. {
+                                std::time::Duration::MAX
+                            } else {
+                                std::time::Duration::ZERO
+                            })
+                        })
+                    } else {
+                        None
+                    }
+                }) {
+                    swift_bridge::option::OptionF64 { val: val.as_secs_f64(), is_some: true }
+                } else {
+                    swift_bridge::option::OptionF64 { val: 123.4, is_some: false }
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: Optional<Double>) -> Optional<Double> {
+    __swift_bridge__$some_function(arg.intoFfiRepr()).intoSwiftRepr()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__OptionF64 __swift_bridge__$some_function(struct __private__OptionF64 arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_option_duration_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a bridge module's own `type Duration` is treated as an opaque type instead of as
+/// a `std::time::Duration`.
+mod declared_duration_type_is_opaque {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type Duration;
+                    fn some_function(arg: Duration);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(arg: *mut super::Duration) {
+                super::some_function(unsafe { *Box::from_raw(arg) })
+            }
+        })
+    }
+
+    #[test]
+    fn declared_duration_type_is_opaque() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: ExpectedSwiftCode::SkipTest,
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -0,0 +1,287 @@
+//! Tests for passing `extern "C" fn(A, B) -> C` function pointers between languages.
+//!
+//! Function pointers are passed over FFI as is, so on the Swift side they are
+//! `@convention(c)` functions.
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that a Rust function can take an `extern "C" fn` argument.
+mod extern_rust_fn_extern_c_fn_arg {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(callback: extern "C" fn(u32) -> u32, val: u32) -> u32;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                callback: extern "C" fn(u32) -> u32,
+                val: u32
+            ) -> u32 {
+                super::some_function(callback, val)
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ callback: @convention(c) (UInt32) -> UInt32, _ val: UInt32) -> UInt32 {
+    __swift_bridge__$some_function(callback, val)
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+uint32_t __swift_bridge__$some_function(uint32_t (*callback)(uint32_t), uint32_t val);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_extern_c_fn_arg() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a Rust function can return an `extern "C" fn`.
+///
+/// Swift imports C function pointers as optionals, so we unwrap the returned function pointer.
+mod extern_rust_fn_return_extern_c_fn {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function() -> extern "C" fn(u8, bool);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function() -> extern "C" fn(u8, bool) {
+                super::some_function()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function() -> @convention(c) (UInt8, Bool) -> () {
+    __swift_bridge__$some_function()!
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void (*__swift_bridge__$some_function(void))(uint8_t, bool);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_return_extern_c_fn() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a Swift function can take an `extern "C" fn` argument.
+mod extern_swift_fn_extern_c_fn_arg {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(callback: extern "C" fn() -> f64);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(callback: extern "C" fn() -> f64) {
+                    unsafe { __swift_bridge__some_function(callback) }
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(callback: extern "C" fn() -> f64);
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ callback: @convention(c) () -> Double) {
+    some_function(callback: callback)
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_extern_c_fn_arg() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that a Rust function can take and return an `Option<extern "C" fn>`.
+///
+/// An `Option<extern "C" fn>` has the same representation as a nullable C function pointer, which
+/// Swift imports as an optional, so it is passed over FFI as is.
+mod extern_rust_fn_option_extern_c_fn {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(
+                        callback: Option<extern "C" fn(u8) -> u8>
+                    ) -> Option<extern "C" fn()>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                callback: Option<extern "C" fn(u8) -> u8>
+            ) -> Option<extern "C" fn()> {
+                super::some_function(callback)
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ callback: Optional<@convention(c) (UInt8) -> UInt8>) -> Optional<@convention(c) () -> ()> {
+    __swift_bridge__$some_function(callback)
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void (*__swift_bridge__$some_function(uint8_t (*callback)(uint8_t)))(void);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_option_extern_c_fn() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a Swift function can take and return an `Option<extern "C" fn>`.
+mod extern_swift_fn_option_extern_c_fn {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(
+                        callback: Option<extern "C" fn(u8) -> u8>
+                    ) -> Option<extern "C" fn()>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(
+                    callback: Option<extern "C" fn(u8) -> u8>
+                ) -> Option<extern "C" fn()> {
+                    unsafe { __swift_bridge__some_function(callback) }
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(
+                    callback: Option<extern "C" fn(u8) -> u8>
+                ) -> Option<extern "C" fn()>;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ callback: Optional<@convention(c) (UInt8) -> UInt8>) -> Optional<@convention(c) () -> ()> {
+    some_function(callback: callback)
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_option_extern_c_fn() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
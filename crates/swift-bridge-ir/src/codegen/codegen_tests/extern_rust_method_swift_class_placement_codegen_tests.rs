@@ -51,11 +51,11 @@ public class SomeType: SomeTypeRefMut {
 }
 extension SomeType {
     public func a() {
-        __swift_bridge__$SomeType$a({isOwned = false; return ptr;}())
+        __swift_bridge__$SomeType$a({precondition(isOwned, "This value was already moved into Rust"); isOwned = false; return ptr;}())
     }
 
     public func b() {
-        __swift_bridge__$SomeType$b({isOwned = false; return ptr;}())
+        __swift_bridge__$SomeType$b({precondition(isOwned, "This value was already moved into Rust"); isOwned = false; return ptr;}())
     }
 }
 public class SomeTypeRefMut: SomeTypeRef {
@@ -107,3 +107,163 @@ extension SomeTypeRef {
         .test();
     }
 }
+
+/// Verify that extern "Rust" methods that take a pinned mutable reference to self get added to the
+/// `SomeTypeRefMut` class, and that the Rust side pins the pointer before calling the method.
+///
+/// self: Pin<&mut Self> -> SomeTypeRefMut
+/// self: Pin<&mut SomeType> -> SomeTypeRefMut
+mod extern_rust_method_pinned_self {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn a(self: Pin<&mut Self>);
+                    fn b(self: std::pin::Pin<&mut SomeType>, arg: u8) -> u8;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$a"]
+                pub extern "C" fn __swift_bridge__SomeType_a(this: *mut super::SomeType) {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::assert_valid_receiver(this, "SomeType::a");
+                    (unsafe { std::pin::Pin::new_unchecked(&mut *this) }).a()
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$b"]
+                pub extern "C" fn __swift_bridge__SomeType_b(
+                    this: *mut super::SomeType,
+                    arg: u8
+                ) -> u8 {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::assert_valid_receiver(this, "SomeType::b");
+                    (unsafe { std::pin::Pin::new_unchecked(&mut *this) }).b(arg)
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeTypeRefMut {
+    public func a() {
+        __swift_bridge__$SomeType$a(ptr)
+    }
+
+    public func b(_ arg: UInt8) -> UInt8 {
+        __swift_bridge__$SomeType$b(ptr, arg)
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "void __swift_bridge__$SomeType$a(void* self);",
+            "uint8_t __swift_bridge__$SomeType$b(void* self, uint8_t arg);",
+        ])
+    }
+
+    #[test]
+    fn extern_rust_method_pinned_self() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that extern "Rust" methods that take a boxed self get added to the owned `SomeType`
+/// class, and that the Rust side hands the box over without moving the value out of it.
+///
+/// self: Box<Self> -> SomeType
+/// self: Box<SomeType> -> SomeType
+mod extern_rust_method_boxed_self {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn a(self: Box<Self>);
+                    fn b(self: std::boxed::Box<SomeType>, arg: u8) -> u8;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$a"]
+                pub extern "C" fn __swift_bridge__SomeType_a(this: *mut super::SomeType) {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::assert_valid_receiver(this, "SomeType::a");
+                    (unsafe { Box::from_raw(this) }).a()
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$b"]
+                pub extern "C" fn __swift_bridge__SomeType_b(
+                    this: *mut super::SomeType,
+                    arg: u8
+                ) -> u8 {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::assert_valid_receiver(this, "SomeType::b");
+                    (unsafe { Box::from_raw(this) }).b(arg)
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeType {
+    public func a() {
+        __swift_bridge__$SomeType$a({precondition(isOwned, "This value was already moved into Rust"); isOwned = false; return ptr;}())
+    }
+
+    public func b(_ arg: UInt8) -> UInt8 {
+        __swift_bridge__$SomeType$b({precondition(isOwned, "This value was already moved into Rust"); isOwned = false; return ptr;}(), arg)
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "void __swift_bridge__$SomeType$a(void* self);",
+            "uint8_t __swift_bridge__$SomeType$b(void* self, uint8_t arg);",
+        ])
+    }
+
+    #[test]
+    fn extern_rust_method_boxed_self() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
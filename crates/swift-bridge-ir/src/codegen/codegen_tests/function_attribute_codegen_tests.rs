@@ -289,6 +289,8 @@ mod get {
                 pub extern "C" fn __swift_bridge__SomeType_some_function(
                     this: *mut super::SomeType
                 ) -> u16 {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::assert_valid_receiver(this, "SomeType::some_function");
                     (unsafe { &*this }).field
                 }
             },
@@ -296,6 +298,8 @@ mod get {
                 pub extern "C" fn __swift_bridge__SomeType_some_function_ref(
                     this: *mut super::SomeType
                 ) -> i16 {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::assert_valid_receiver(this, "SomeType::some_function_ref");
                     &(unsafe { &*this }).field
                 }
             },
@@ -303,6 +307,8 @@ mod get {
                 pub extern "C" fn __swift_bridge__SomeType_some_function_ref_mut(
                     this: *mut super::SomeType
                 ) -> u8 {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::assert_valid_receiver(this, "SomeType::some_function_ref_mut");
                     &mut (unsafe { &mut *this }).field
                 }
             },
@@ -359,6 +365,8 @@ mod get_with {
                 pub extern "C" fn __swift_bridge__SomeType_some_function(
                     this: *mut super::SomeType
                 ) {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::assert_valid_receiver(this, "SomeType::some_function");
                     super::a::b::c( (unsafe { &*this }).field )
                 }
             },
@@ -366,6 +374,8 @@ mod get_with {
                 pub extern "C" fn __swift_bridge__SomeType_some_function_ref(
                     this: *mut super::SomeType
                 ) {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::assert_valid_receiver(this, "SomeType::some_function_ref");
                     super::a::b::c( & (unsafe { &*this }).field )
                 }
             },
@@ -373,6 +383,8 @@ mod get_with {
                 pub extern "C" fn __swift_bridge__SomeType_some_function_ref_mut(
                     this: *mut super::SomeType
                 ) {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::assert_valid_receiver(this, "SomeType::some_function_ref_mut");
                     super::a::b::c( &mut (unsafe { &mut *this }).field )
                 }
             },
@@ -467,3 +479,568 @@ func __swift_bridge__call_swift_from_rust () -> UnsafeMutableRawPointer {
         .test();
     }
 }
+
+/// Verify that we can use `associated_to` to make a function a static method on a transparent
+/// struct.
+mod associated_to_shared_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    field: u8
+                }
+
+                extern "Rust" {
+                    #[swift_bridge(associated_to = SomeStruct)]
+                    fn some_function(arg: u8) -> SomeStruct;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeStruct$some_function"]
+            pub extern "C" fn __swift_bridge__SomeStruct_some_function(arg: u8) -> __swift_bridge__SomeStruct {
+                SomeStruct::some_function(arg).into_ffi_repr()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeStruct {
+    static public func some_function(_ arg: UInt8) -> SomeStruct {
+        __swift_bridge__$SomeStruct$some_function(arg).intoSwiftRepr()
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __swift_bridge__$SomeStruct __swift_bridge__$SomeStruct$some_function(uint8_t arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn associated_to_shared_struct() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can use `associated_to` to make a function a static method on a transparent
+/// enum.
+mod associated_to_shared_enum {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                enum SomeEnum {
+                    Variant
+                }
+
+                extern "Rust" {
+                    #[swift_bridge(associated_to = SomeEnum)]
+                    fn some_function() -> SomeEnum;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeEnum$some_function"]
+            pub extern "C" fn __swift_bridge__SomeEnum_some_function() -> __swift_bridge__SomeEnum {
+                SomeEnum::some_function().into_ffi_repr()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeEnum {
+    static public func some_function() -> SomeEnum {
+        __swift_bridge__$SomeEnum$some_function().intoSwiftRepr()
+    }
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn associated_to_shared_enum() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that the `property` attribute merges a getter and a setter into a single read-write
+/// Swift computed property.
+mod property_getter_and_setter {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(property = "title")]
+                    fn title(&self) -> &str;
+
+                    #[swift_bridge(property = "title")]
+                    fn set_title(&mut self, title: &str);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$title"]
+                pub extern "C" fn __swift_bridge__SomeType_title(
+                    this: *mut super::SomeType
+                ) -> swift_bridge::string::RustStr {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::assert_valid_receiver(this, "SomeType::title");
+                    swift_bridge::string::RustStr::from_str((unsafe { &*this }).title())
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$set_title"]
+                pub extern "C" fn __swift_bridge__SomeType_set_title(
+                    this: *mut super::SomeType,
+                    title: swift_bridge::string::RustStr
+                ) {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::assert_valid_receiver(this, "SomeType::set_title");
+                    (unsafe { &mut *this }).set_title(title.to_str())
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public class SomeTypeRefMut: SomeTypeRef {
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+
+    public override var title: String {
+        get {
+            super.title
+        }
+        set(title) {
+            title.toRustStr({ titleAsRustStr in
+                __swift_bridge__$SomeType$set_title(ptr, titleAsRustStr)
+            })
+        }
+    }
+}
+"#,
+            r#"
+public class SomeTypeRef {
+    var ptr: UnsafeMutableRawPointer
+
+    public init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+    }
+
+    public var title: String {
+        __swift_bridge__$SomeType$title(ptr).toString()
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"struct RustStr __swift_bridge__$SomeType$title(void* self);"#,
+            r#"void __swift_bridge__$SomeType$set_title(void* self, struct RustStr title);"#,
+        ])
+    }
+
+    #[test]
+    fn property_getter_and_setter() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that the `subscript` attribute exposes a method as a Swift subscript, and that a
+/// `usize` index is seen on the Swift side as an `Int`.
+mod subscript {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(subscript)]
+                    fn get(&self, index: usize) -> u8;
+
+                    #[swift_bridge(subscript)]
+                    fn named(&self, key: u32) -> i64;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$get"]
+            pub extern "C" fn __swift_bridge__SomeType_get(
+                this: *mut super::SomeType,
+                index: usize
+            ) -> u8 {
+                #[cfg(debug_assertions)]
+                swift_bridge::assert_valid_receiver(this, "SomeType::get");
+                (unsafe { &*this }).get(index)
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeTypeRef {
+    public subscript(index: Int) -> UInt8 {
+        let index = UInt(index)
+        return __swift_bridge__$SomeType$get(ptr, index)
+    }
+
+    public subscript(key: UInt32) -> Int64 {
+        __swift_bridge__$SomeType$named(ptr, key)
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"uint8_t __swift_bridge__$SomeType$get(void* self, uintptr_t index);"#,
+        )
+    }
+
+    #[test]
+    fn subscript() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that the `publisher` attribute wraps a method that registers an event callback into a
+/// Combine publisher that frees the returned subscription handle when it gets cancelled.
+#[cfg(feature = "combine")]
+mod publisher {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type Subscription;
+                }
+
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(publisher = "events")]
+                    fn subscribe(&self, callback: Box<dyn Fn(u32)>) -> Subscription;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$subscribe"]
+            pub extern "C" fn __swift_bridge__SomeType_subscribe(
+                this: *mut super::SomeType,
+                callback: *mut std::ffi::c_void
+            ) -> *mut super::Subscription {
+                #[cfg(debug_assertions)]
+                swift_bridge::assert_valid_receiver(this, "SomeType::subscribe");
+                Box::into_raw(Box::new({
+                    let val: super::Subscription = (unsafe { &*this }).subscribe({
+                        let callback = swift_bridge::boxed_fn_support::SwiftCallback::new(
+                            callback,
+                            __swift_bridge__SomeType_subscribe_free_param1
+                        );
+                        Box::new(move |arg0: u32| -> () {
+                            unsafe { __swift_bridge__SomeType_subscribe_param1(callback.as_ptr(), arg0) }
+                        })
+                    });
+                    val
+                })) as *mut super::Subscription
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+#if canImport(Combine)
+import Combine
+
+extension SomeTypeRef {
+    public var events: AnyPublisher<UInt32, Never> {
+        Deferred { () -> AnyPublisher<UInt32, Never> in
+            let subject = PassthroughSubject<UInt32, Never>()
+            var subscription: Subscription? = nil
+
+            return subject
+                .handleEvents(
+                    receiveSubscription: { _ in
+                        subscription = self.subscribe({ event in subject.send(event) })
+                    },
+                    receiveCancel: {
+                        subscription = nil
+                    }
+                )
+                .eraseToAnyPublisher()
+        }
+        .eraseToAnyPublisher()
+    }
+}
+#endif
+"#,
+            r#"
+    public func subscribe(_ callback: @escaping (UInt32) -> ()) -> Subscription {
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"void* __swift_bridge__$SomeType$subscribe(void* self, void* callback);"#,
+        )
+    }
+
+    #[test]
+    fn publisher() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that the `discardable_result` attribute marks functions and methods that return a value
+/// as `@discardableResult`.
+mod discardable_result {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(discardable_result)]
+                    fn insert(&mut self, value: u32) -> bool;
+
+                    #[swift_bridge(discardable_result)]
+                    fn some_function() -> u8;
+
+                    #[swift_bridge(discardable_result)]
+                    fn returns_nothing();
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+@discardableResult
+public func some_function() -> UInt8 {
+"#,
+            r#"
+public func returns_nothing() {
+"#,
+            r#"
+    @discardableResult
+    public func insert(_ value: UInt32) -> Bool {
+"#,
+        ])
+    }
+
+    #[test]
+    fn discardable_result() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that a `#[deprecated]` attribute marks the generated Swift function as deprecated, and
+/// that calling the deprecated Rust function from the generated Rust code doesn't warn.
+mod deprecated {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[deprecated(since = "0.2.0", note = "Use \"bar\" instead")]
+                    fn foo(&self);
+
+                    #[deprecated]
+                    fn some_function() -> u8;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            #[allow(deprecated)]
+            pub extern "C" fn __swift_bridge__some_function() -> u8 {
+                super::some_function()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+@available(*, deprecated)
+public func some_function() -> UInt8 {
+"#,
+            r#"
+    @available(*, deprecated, message: "Use \"bar\" instead")
+    public func foo() {
+"#,
+        ])
+    }
+
+    #[test]
+    fn deprecated() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that the `rename_functions = "camelCase"` module argument renames extern Rust and
+/// extern Swift functions on the Swift side while leaving their symbols unchanged.
+mod rename_functions_camel_case {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge(rename_functions = "camelCase")]
+            mod ffi {
+                extern "Rust" {
+                    fn some_function();
+                }
+
+                extern "Swift" {
+                    fn another_function();
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$some_function"]
+                pub extern "C" fn __swift_bridge__some_function() {
+                    super::some_function()
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$another_function"]
+                fn __swift_bridge__another_function();
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func someFunction() {
+    __swift_bridge__$some_function()
+}
+"#,
+            r#"
+@_cdecl("__swift_bridge__$another_function")
+func __swift_bridge__another_function () {
+    anotherFunction()
+}
+"#,
+        ])
+    }
+
+    #[test]
+    fn rename_functions_camel_case() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -38,7 +38,7 @@ public class SomeType<A>: SomeTypeRefMut<A> {
 
     deinit {
         if isOwned {
-            (self as! SwiftBridgeGenericFreer).rust_free()
+            // There are no monomorphizations of this generic type to free.
         }
     }
 }
@@ -76,6 +76,9 @@ public class SomeTypeRef<A> {
 }
 
 /// Verify that we can declare a generic with concrete types.
+///
+/// The generic class frees its Rust value using the free function of the monomorphization that
+/// matches its generic placeholders.
 mod monomorphized_generic_opaque_rust_type {
     use super::*;
     fn bridge_module() -> TokenStream {
@@ -87,6 +90,7 @@ mod monomorphized_generic_opaque_rust_type {
                     type SomeType<A>;
 
                     type SomeType<u32>;
+                    type SomeType<String>;
                 }
             }
         }
@@ -107,12 +111,17 @@ mod monomorphized_generic_opaque_rust_type {
     fn expected_swift_code() -> ExpectedSwiftCode {
         ExpectedSwiftCode::ContainsAfterTrim(
             r#"
-extension SomeType: SwiftBridgeGenericFreer
-where A == UInt32 {
-    public func rust_free() {
-        __swift_bridge__$SomeType$u32$_free(ptr)
+    deinit {
+        if isOwned {
+            if A.self == UInt32.self {
+                __swift_bridge__$SomeType$u32$_free(ptr)
+            } else if A.self == RustString.self {
+                __swift_bridge__$SomeType$String$_free(ptr)
+            } else {
+                fatalError("SomeType has no monomorphization for these generic types")
+            }
+        }
     }
-}
 "#,
         )
     }
@@ -256,6 +265,136 @@ void* __swift_bridge__$some_function(void);
     }
 }
 
+/// Verify that we can declare initializers and methods on a generic opaque Rust type's
+/// monomorphizations.
+///
+/// Each monomorphization gets its own FFI functions, and its Swift methods are declared in an
+/// extension that is constrained to the monomorphization's concrete types.
+mod generic_opaque_rust_type_methods {
+    use super::*;
+
+    fn bridge_module() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(declare_generic)]
+                    type SomeType<A>;
+
+                    type SomeType<u32>;
+                    #[swift_bridge(init)]
+                    fn new() -> SomeType<u32>;
+                    fn push(self: &mut SomeType<u32>, val: u32);
+                    fn len(self: &SomeType<u32>) -> usize;
+
+                    type SomeType<String>;
+                    fn len(self: &SomeType<String>) -> usize;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$u32$new"]
+                pub extern "C" fn __swift_bridge__SomeType_u32_new() -> *mut super::SomeType<u32> {
+                    Box::into_raw(Box::new({
+                        let val: super::SomeType<u32> = super::SomeType::new();
+                        val
+                    })) as *mut super::SomeType<u32>
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$u32$push"]
+                pub extern "C" fn __swift_bridge__SomeType_u32_push(
+                    this: *mut super::SomeType<u32>,
+                    val: u32
+                ) {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::assert_valid_receiver(this, "SomeType::push");
+                    (unsafe { &mut *this }).push(val)
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$u32$len"]
+                pub extern "C" fn __swift_bridge__SomeType_u32_len(
+                    this: *mut super::SomeType<u32>
+                ) -> usize {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::assert_valid_receiver(this, "SomeType::len");
+                    (unsafe { &*this }).len()
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$String$len"]
+                pub extern "C" fn __swift_bridge__SomeType_String_len(
+                    this: *mut super::SomeType<String>
+                ) -> usize {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::assert_valid_receiver(this, "SomeType::len");
+                    (unsafe { &*this }).len()
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+extension SomeType
+where A == UInt32 {
+    public convenience init() {
+        self.init(ptr: __swift_bridge__$SomeType$u32$new())
+    }
+}
+extension SomeTypeRefMut
+where A == UInt32 {
+    public func push(_ val: UInt32) {
+        __swift_bridge__$SomeType$u32$push(ptr, val)
+    }
+}
+extension SomeTypeRef
+where A == UInt32 {
+    public func len() -> UInt {
+        __swift_bridge__$SomeType$u32$len(ptr)
+    }
+}
+"#,
+            r#"
+extension SomeTypeRef
+where A == RustString {
+    public func len() -> UInt {
+        __swift_bridge__$SomeType$String$len(ptr)
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+void* __swift_bridge__$SomeType$u32$new(void);
+void __swift_bridge__$SomeType$u32$push(void* self, uint32_t val);
+uintptr_t __swift_bridge__$SomeType$u32$len(void* self);
+uintptr_t __swift_bridge__$SomeType$String$len(void* self);
+"#,
+        ])
+    }
+
+    #[test]
+    fn generic_opaque_rust_type_methods() {
+        CodegenTest {
+            bridge_module: bridge_module().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
 /// Verify that we can declare a generic opaque Rust type.
 mod generic_opaque_rust_type_copy {
     use super::*;
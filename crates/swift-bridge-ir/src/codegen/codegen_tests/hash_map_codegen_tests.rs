@@ -0,0 +1,392 @@
+//! Tests for bridging `HashMap<K, V>`.
+//!
+//! On the Swift side a `HashMap<K, V>` is a `RustMap<K, V>`, whose methods are generated alongside
+//! the bridge module that uses the map.
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that a Rust function can take and return a `HashMap<K, V>`.
+mod extern_rust_fn_hash_map_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: HashMap<String, u32>) -> HashMap<u32, String>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: *mut std::collections::HashMap<String, u32>
+            ) -> *mut std::collections::HashMap<u32, String> {
+                Box::into_raw(Box::new(super::some_function(unsafe { *Box::from_raw(arg) })))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: RustMap<RustString, UInt32>) -> RustMap<UInt32, RustString> {
+    RustMap<UInt32, RustString>(ptr: __swift_bridge__$some_function({ let val = arg; val.isOwned = false; return val.ptr }()))
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$some_function(void* arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_hash_map_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a Rust function can take and return an `Option<HashMap<K, V>>`.
+mod extern_rust_fn_option_hash_map_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Option<HashMap<String, u32>>) -> Option<HashMap<u32, String>>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$some_function"]
+                pub extern "C" fn __swift_bridge__some_function(
+                    arg: *mut std::collections::HashMap<String, u32>
+                ) -> *mut std::collections::HashMap<u32, String> {
+                    if let Some(val) = super::some_function(
+                        {
+                            let val = arg;
+                            if val.is_null() {
+                                None
+                            } else {
+                                Some(unsafe { *Box::from_raw(val) })
+                            }
+                        }
+                    ) {
+                        Box::into_raw(Box::new(val))
+                    } else {
+                        std::ptr::null_mut()
+                    }
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$HashMap_String_u32$new"]
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$HashMap_u32_String$new"]
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function(_ arg: Optional<RustMap<RustString, UInt32>>) -> Optional<RustMap<UInt32, RustString>> {
+    { let val = __swift_bridge__$some_function({ if let val = arg { val.isOwned = false; return val.ptr } else { return nil } }()); if val != nil { return RustMap(ptr: val!) } else { return nil } }()
+}
+"#,
+            r#"
+extension RustMap
+where K == RustString, V == UInt32 {
+"#,
+            r#"
+extension RustMap
+where K == UInt32, V == RustString {
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+void* __swift_bridge__$some_function(void* arg);
+"#,
+            r#"
+void* __swift_bridge__$HashMap_String_u32$new(void);
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_option_hash_map_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate the `RustMap<K, V>` support code for a `HashMap<K, V>` that is only
+/// held by a `Result`.
+mod extern_rust_fn_return_result_hash_map {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function() -> Result<HashMap<String, u8>, String>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$HashMap_String_u8$new"]
+                #[doc(hidden)]
+                pub extern "C" fn __swift_bridge__HashMap_String_u8_new() -> *mut std::collections::HashMap<String, u8> {
+                    Box::into_raw(Box::new(std::collections::HashMap::new()))
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$some_function"]
+                pub extern "C" fn __swift_bridge__some_function() -> swift_bridge::result::ResultPtrAndPtr {
+                    match super::some_function() {
+                        Ok(ok) => {
+                            swift_bridge::result::ResultPtrAndPtr {
+                                is_ok: true,
+                                ok_or_err: Box::into_raw(Box::new(ok)) as *mut std::ffi::c_void
+                            }
+                        }
+                        Err(err) => {
+                            swift_bridge::result::ResultPtrAndPtr {
+                                is_ok: false,
+                                ok_or_err: swift_bridge::string::RustString(err).box_into_raw() as *mut std::ffi::c_void
+                            }
+                        }
+                    }
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function() throws -> RustMap<RustString, UInt8> {
+    try { let val = __swift_bridge__$some_function(); if val.is_ok { return RustMap<RustString, UInt8>(ptr: val.ok_or_err!) } else { throw RustString(ptr: val.ok_or_err!) } }()
+}
+"#,
+            r#"
+extension RustMap
+where K == RustString, V == UInt8 {
+    public convenience init(ptr: UnsafeMutableRawPointer) {
+        self.init(ptr: ptr, free: { __swift_bridge__$HashMap_String_u8$_free($0) })
+    }
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+struct __private__ResultPtrAndPtr __swift_bridge__$some_function(void);
+"#,
+            r#"
+void* __swift_bridge__$HashMap_String_u8$new(void);
+void __swift_bridge__$HashMap_String_u8$_free(void* map);
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_return_result_hash_map() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate the FFI functions that power a `RustMap<K, V>`, along with the
+/// `RustMap` extension that holds its methods.
+mod hash_map_support {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function() -> HashMap<u32, String>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$HashMap_u32_String$new"]
+                #[doc(hidden)]
+                pub extern "C" fn __swift_bridge__HashMap_u32_String_new() -> *mut std::collections::HashMap<u32, String> {
+                    Box::into_raw(Box::new(std::collections::HashMap::new()))
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$HashMap_u32_String$_free"]
+                #[doc(hidden)]
+                pub extern "C" fn __swift_bridge__HashMap_u32_String__free(map: *mut std::collections::HashMap<u32, String>) {
+                    let _ = unsafe { Box::from_raw(map) };
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$HashMap_u32_String$len"]
+                #[doc(hidden)]
+                pub extern "C" fn __swift_bridge__HashMap_u32_String_len(map: *const std::collections::HashMap<u32, String>) -> usize {
+                    unsafe { &*map }.len()
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$HashMap_u32_String$get"]
+                #[doc(hidden)]
+                pub extern "C" fn __swift_bridge__HashMap_u32_String_get(
+                    map: *const std::collections::HashMap<u32, String>,
+                    key: u32
+                ) -> *mut swift_bridge::string::RustString {
+                    let map = unsafe { &*map };
+                    if let Some(val) = map.get(&key).cloned() {
+                        swift_bridge::string::RustString(val).box_into_raw()
+                    } else {
+                        std::ptr::null::<swift_bridge::string::RustString>() as *mut swift_bridge::string::RustString
+                    }
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$HashMap_u32_String$insert"]
+                #[doc(hidden)]
+                pub extern "C" fn __swift_bridge__HashMap_u32_String_insert(
+                    map: *mut std::collections::HashMap<u32, String>,
+                    key: u32,
+                    value: *mut swift_bridge::string::RustString
+                ) -> *mut swift_bridge::string::RustString {
+                    let map = unsafe { &mut *map };
+                    if let Some(val) = map.insert(key, unsafe { Box::from_raw(value).0 }) {
+                        swift_bridge::string::RustString(val).box_into_raw()
+                    } else {
+                        std::ptr::null::<swift_bridge::string::RustString>() as *mut swift_bridge::string::RustString
+                    }
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$HashMap_u32_String$remove"]
+                #[doc(hidden)]
+                pub extern "C" fn __swift_bridge__HashMap_u32_String_remove(
+                    map: *mut std::collections::HashMap<u32, String>,
+                    key: u32
+                ) -> *mut swift_bridge::string::RustString {
+                    let map = unsafe { &mut *map };
+                    if let Some(val) = map.remove(&key) {
+                        swift_bridge::string::RustString(val).box_into_raw()
+                    } else {
+                        std::ptr::null::<swift_bridge::string::RustString>() as *mut swift_bridge::string::RustString
+                    }
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension RustMap
+where K == UInt32, V == RustString {
+    public convenience init(ptr: UnsafeMutableRawPointer) {
+        self.init(ptr: ptr, free: { __swift_bridge__$HashMap_u32_String$_free($0) })
+    }
+
+    public convenience init() {
+        self.init(ptr: __swift_bridge__$HashMap_u32_String$new())
+    }
+
+    public convenience init(_ dictionary: [UInt32: String]) {
+        self.init()
+        for (key, value) in dictionary {
+            self.insert(key, value)
+        }
+    }
+
+    /// Rust returns a UInt, but we cast to an Int to match Swift's `Dictionary.count`.
+    public var count: Int {
+        Int(__swift_bridge__$HashMap_u32_String$len(ptr))
+    }
+
+    public func get(_ key: UInt32) -> Optional<RustString> {
+        { let val = __swift_bridge__$HashMap_u32_String$get(ptr, key); if val != nil { return RustString(ptr: val!) } else { return nil } }()
+    }
+
+    @discardableResult
+    public func insert(_ key: UInt32, _ value: String) -> Optional<RustString> {
+        { let val = __swift_bridge__$HashMap_u32_String$insert(ptr, key, { let rustString = value.intoRustString(); rustString.isOwned = false; return rustString.ptr }()); if val != nil { return RustString(ptr: val!) } else { return nil } }()
+    }
+
+    @discardableResult
+    public func remove(_ key: UInt32) -> Optional<RustString> {
+        { let val = __swift_bridge__$HashMap_u32_String$remove(ptr, key); if val != nil { return RustString(ptr: val!) } else { return nil } }()
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$HashMap_u32_String$new(void);
+void __swift_bridge__$HashMap_u32_String$_free(void* map);
+uintptr_t __swift_bridge__$HashMap_u32_String$len(void* map);
+void* __swift_bridge__$HashMap_u32_String$get(void* map, uint32_t key);
+void* __swift_bridge__$HashMap_u32_String$insert(void* map, uint32_t key, void* value);
+void* __swift_bridge__$HashMap_u32_String$remove(void* map, uint32_t key);
+"#,
+        )
+    }
+
+    #[test]
+    fn hash_map_support() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
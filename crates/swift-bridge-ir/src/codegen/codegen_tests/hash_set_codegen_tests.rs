@@ -0,0 +1,387 @@
+//! Tests for bridging `HashSet<T>`.
+//!
+//! On the Swift side a `HashSet<T>` is a `RustSet<T>`, whose methods are generated alongside the
+//! bridge module that uses the set.
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that a Rust function can take and return a `HashSet<T>`.
+mod extern_rust_fn_hash_set_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: HashSet<String>) -> HashSet<u16>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: *mut std::collections::HashSet<String>
+            ) -> *mut std::collections::HashSet<u16> {
+                Box::into_raw(Box::new(super::some_function(unsafe { *Box::from_raw(arg) })))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: RustSet<RustString>) -> RustSet<UInt16> {
+    RustSet<UInt16>(ptr: __swift_bridge__$some_function({ let val = arg; val.isOwned = false; return val.ptr }()))
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$some_function(void* arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_hash_set_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a Rust function can take and return an `Option<HashSet<T>>`.
+mod extern_rust_fn_option_hash_set_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Option<HashSet<u16>>) -> Option<HashSet<u16>>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$some_function"]
+                pub extern "C" fn __swift_bridge__some_function(
+                    arg: *mut std::collections::HashSet<u16>
+                ) -> *mut std::collections::HashSet<u16> {
+                    if let Some(val) = super::some_function(
+                        {
+                            let val = arg;
+                            if val.is_null() {
+                                None
+                            } else {
+                                Some(unsafe { *Box::from_raw(val) })
+                            }
+                        }
+                    ) {
+                        Box::into_raw(Box::new(val))
+                    } else {
+                        std::ptr::null_mut()
+                    }
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$HashSet_u16$new"]
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function(_ arg: Optional<RustSet<UInt16>>) -> Optional<RustSet<UInt16>> {
+    { let val = __swift_bridge__$some_function({ if let val = arg { val.isOwned = false; return val.ptr } else { return nil } }()); if val != nil { return RustSet(ptr: val!) } else { return nil } }()
+}
+"#,
+            r#"
+extension RustSet
+where T == UInt16 {
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+void* __swift_bridge__$some_function(void* arg);
+"#,
+            r#"
+void* __swift_bridge__$HashSet_u16$new(void);
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_option_hash_set_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate the `RustSet<T>` support code for a `HashSet<T>` that is only held by
+/// a `Result`.
+mod extern_rust_fn_return_result_hash_set {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function() -> Result<HashSet<u8>, String>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$HashSet_u8$new"]
+                #[doc(hidden)]
+                pub extern "C" fn __swift_bridge__HashSet_u8_new() -> *mut std::collections::HashSet<u8> {
+                    Box::into_raw(Box::new(std::collections::HashSet::new()))
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$some_function"]
+                pub extern "C" fn __swift_bridge__some_function() -> swift_bridge::result::ResultPtrAndPtr {
+                    match super::some_function() {
+                        Ok(ok) => {
+                            swift_bridge::result::ResultPtrAndPtr {
+                                is_ok: true,
+                                ok_or_err: Box::into_raw(Box::new(ok)) as *mut std::ffi::c_void
+                            }
+                        }
+                        Err(err) => {
+                            swift_bridge::result::ResultPtrAndPtr {
+                                is_ok: false,
+                                ok_or_err: swift_bridge::string::RustString(err).box_into_raw() as *mut std::ffi::c_void
+                            }
+                        }
+                    }
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function() throws -> RustSet<UInt8> {
+    try { let val = __swift_bridge__$some_function(); if val.is_ok { return RustSet<UInt8>(ptr: val.ok_or_err!) } else { throw RustString(ptr: val.ok_or_err!) } }()
+}
+"#,
+            r#"
+extension RustSet
+where T == UInt8 {
+    public convenience init(ptr: UnsafeMutableRawPointer) {
+        self.init(ptr: ptr, free: { __swift_bridge__$HashSet_u8$_free($0) })
+    }
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+struct __private__ResultPtrAndPtr __swift_bridge__$some_function(void);
+"#,
+            r#"
+void* __swift_bridge__$HashSet_u8$new(void);
+void __swift_bridge__$HashSet_u8$_free(void* set);
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_return_result_hash_set() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate the FFI functions that power a `RustSet<T>`, along with the `RustSet`
+/// extension that holds its methods.
+mod hash_set_support {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function() -> HashSet<String>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$HashSet_String$new"]
+                #[doc(hidden)]
+                pub extern "C" fn __swift_bridge__HashSet_String_new() -> *mut std::collections::HashSet<String> {
+                    Box::into_raw(Box::new(std::collections::HashSet::new()))
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$HashSet_String$_free"]
+                #[doc(hidden)]
+                pub extern "C" fn __swift_bridge__HashSet_String__free(set: *mut std::collections::HashSet<String>) {
+                    let _ = unsafe { Box::from_raw(set) };
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$HashSet_String$len"]
+                #[doc(hidden)]
+                pub extern "C" fn __swift_bridge__HashSet_String_len(set: *const std::collections::HashSet<String>) -> usize {
+                    unsafe { &*set }.len()
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$HashSet_String$contains"]
+                #[doc(hidden)]
+                pub extern "C" fn __swift_bridge__HashSet_String_contains(
+                    set: *const std::collections::HashSet<String>,
+                    value: *mut swift_bridge::string::RustString
+                ) -> bool {
+                    unsafe { &*set }.contains(&unsafe { Box::from_raw(value).0 })
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$HashSet_String$insert"]
+                #[doc(hidden)]
+                pub extern "C" fn __swift_bridge__HashSet_String_insert(
+                    set: *mut std::collections::HashSet<String>,
+                    value: *mut swift_bridge::string::RustString
+                ) -> bool {
+                    unsafe { &mut *set }.insert(unsafe { Box::from_raw(value).0 })
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$HashSet_String$remove"]
+                #[doc(hidden)]
+                pub extern "C" fn __swift_bridge__HashSet_String_remove(
+                    set: *mut std::collections::HashSet<String>,
+                    value: *mut swift_bridge::string::RustString
+                ) -> bool {
+                    unsafe { &mut *set }.remove(&unsafe { Box::from_raw(value).0 })
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$HashSet_String$to_vec"]
+                #[doc(hidden)]
+                pub extern "C" fn __swift_bridge__HashSet_String_to_vec(
+                    set: *const std::collections::HashSet<String>
+                ) -> *mut Vec<swift_bridge::string::RustString> {
+                    let vec: Vec<swift_bridge::string::RustString> = unsafe { &*set }
+                        .iter()
+                        .map(|elem| swift_bridge::string::RustString(elem.clone()))
+                        .collect();
+                    Box::into_raw(Box::new(vec))
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension RustSet
+where T == RustString {
+    public convenience init(ptr: UnsafeMutableRawPointer) {
+        self.init(ptr: ptr, free: { __swift_bridge__$HashSet_String$_free($0) })
+    }
+
+    public convenience init() {
+        self.init(ptr: __swift_bridge__$HashSet_String$new())
+    }
+
+    public convenience init(_ set: Set<String>) {
+        self.init()
+        for value in set {
+            self.insert(value)
+        }
+    }
+
+    /// Rust returns a UInt, but we cast to an Int to match Swift's `Set.count`.
+    public var count: Int {
+        Int(__swift_bridge__$HashSet_String$len(ptr))
+    }
+
+    public func contains(_ value: String) -> Bool {
+        __swift_bridge__$HashSet_String$contains(ptr, { let rustString = value.intoRustString(); rustString.isOwned = false; return rustString.ptr }())
+    }
+
+    @discardableResult
+    public func insert(_ value: String) -> Bool {
+        __swift_bridge__$HashSet_String$insert(ptr, { let rustString = value.intoRustString(); rustString.isOwned = false; return rustString.ptr }())
+    }
+
+    @discardableResult
+    public func remove(_ value: String) -> Bool {
+        __swift_bridge__$HashSet_String$remove(ptr, { let rustString = value.intoRustString(); rustString.isOwned = false; return rustString.ptr }())
+    }
+
+    public func toSet() -> Set<String> {
+        Set(RustVec<RustString>(ptr: __swift_bridge__$HashSet_String$to_vec(ptr)).map { $0.toString() })
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$HashSet_String$new(void);
+void __swift_bridge__$HashSet_String$_free(void* set);
+uintptr_t __swift_bridge__$HashSet_String$len(void* set);
+bool __swift_bridge__$HashSet_String$contains(void* set, void* value);
+bool __swift_bridge__$HashSet_String$insert(void* set, void* value);
+bool __swift_bridge__$HashSet_String$remove(void* set, void* value);
+void* __swift_bridge__$HashSet_String$to_vec(void* set);
+"#,
+        )
+    }
+
+    #[test]
+    fn hash_set_support() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
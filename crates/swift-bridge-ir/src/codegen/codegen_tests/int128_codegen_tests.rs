@@ -0,0 +1,242 @@
+//! Tests for bridging `u128` and `i128`.
+//!
+//! C has no 128-bit integer, so these are passed over FFI as a struct that holds two 64-bit limbs.
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that a Rust function can take a `u128` and return an `i128`.
+mod extern_rust_fn_int128_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: u128) -> i128;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::int128::U128
+            ) -> swift_bridge::int128::I128 {
+                swift_bridge::int128::I128::from(super::some_function(u128::from(arg)))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: RustUInt128) -> RustInt128 {
+    __swift_bridge__$some_function(arg.intoFfiRepr()).intoSwiftRepr()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__I128 __swift_bridge__$some_function(struct __private__U128 arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_int128_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a Swift function can take a `u128` and return an `i128`.
+mod extern_swift_fn_int128_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(arg: u128) -> i128;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub fn some_function(arg: u128) -> i128 {
+                i128::from(unsafe {
+                    __swift_bridge__some_function(swift_bridge::int128::U128::from(arg))
+                })
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: __private__U128) -> __private__I128 {
+    some_function(arg: arg.intoSwiftRepr()).intoFfiRepr()
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_int128_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that a Rust function can take and return an `Option<u128>`.
+mod extern_rust_fn_option_u128_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Option<u128>) -> Option<u128>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::option::OptionU128
+            ) -> swift_bridge::option::OptionU128 {
+                if let Some(val) = super::some_function({
+                    let val = arg;
+                    if val.is_some {
+                        Some(u128::from(val.val))
+                    } else {
+                        None
+                    }
+                }) {
+                    swift_bridge::option::OptionU128 {
+                        val: swift_bridge::int128::U128::from(val),
+                        is_some: true
+                    }
+                } else {
+                    swift_bridge::option::OptionU128 {
+                        val: swift_bridge::int128::U128 { low: 123, high: 0 },
+                        is_some: false
+                    }
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: Optional<RustUInt128>) -> Optional<RustUInt128> {
+    { let val = __swift_bridge__$some_function({ let val = arg; return __private__OptionU128(val: (val ?? 123).intoFfiRepr(), is_some: val != nil) }()); if val.is_some { return val.val.intoSwiftRepr() } else { return nil } }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__OptionU128 __swift_bridge__$some_function(struct __private__OptionU128 arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_option_u128_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a shared struct can have an `i128` field.
+mod shared_struct_i128_field {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                struct SomeStruct {
+                    field: i128
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__SomeStruct {
+                    field: swift_bridge::int128::I128
+                }
+            },
+            quote! {
+                SomeStruct { field: i128::from(val.field) }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public struct SomeStruct {
+    public var field: RustInt128
+"#,
+            r#"
+{ let val = self; return __swift_bridge__$SomeStruct(field: val.field.intoFfiRepr()); }()
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$SomeStruct { struct __private__I128 field; } __swift_bridge__$SomeStruct;
+"#,
+        )
+    }
+
+    #[test]
+    fn shared_struct_i128_field() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
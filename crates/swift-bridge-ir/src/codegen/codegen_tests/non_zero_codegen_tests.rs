@@ -0,0 +1,178 @@
+//! Tests for bridging `std::num::NonZero*` integers.
+//!
+//! A non-zero integer is its underlying integer on the Swift side, and converting it back into a
+//! non-zero integer on the Rust side is checked.
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that a Rust function can take and return non-zero integers.
+mod extern_rust_fn_non_zero_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: NonZeroU32) -> std::num::NonZero<i64>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(arg: u32) -> i64 {
+                super::some_function(std::num::NonZeroU32::new(arg).unwrap()).get()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: UInt32) -> Int64 {
+    __swift_bridge__$some_function(arg)
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+int64_t __swift_bridge__$some_function(uint32_t arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_non_zero_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a Swift function can take and return non-zero integers.
+mod extern_swift_fn_non_zero_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(arg: NonZeroU8) -> NonZeroI16;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: std::num::NonZeroU8) -> std::num::NonZeroI16 {
+                    std::num::NonZeroI16::new(unsafe { __swift_bridge__some_function(arg.get()) }).unwrap()
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(arg: u8) -> i16;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: UInt8) -> Int16 {
+    some_function(arg: arg)
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_non_zero_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that a Rust function can take and return an `Option` of a non-zero integer.
+mod extern_rust_fn_option_non_zero_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Option<NonZeroU32>) -> Option<NonZeroU32>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::option::OptionU32
+            ) -> swift_bridge::option::OptionU32 {
+                if let Some(val) = super::some_function({
+                    let val = arg;
+                    if val.is_some {
+                        Some(std::num::NonZeroU32::new(val.val).unwrap())
+                    } else {
+                        None
+                    }
+                }) {
+                    swift_bridge::option::OptionU32 { val: val.get(), is_some: true }
+                } else {
+                    swift_bridge::option::OptionU32 { val: 123, is_some: false }
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: Optional<UInt32>) -> Optional<UInt32> {
+    __swift_bridge__$some_function(arg.intoFfiRepr()).intoSwiftRepr()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__OptionU32 __swift_bridge__$some_function(struct __private__OptionU32 arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_option_non_zero_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
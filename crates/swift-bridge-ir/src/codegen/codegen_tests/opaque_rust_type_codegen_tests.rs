@@ -89,7 +89,7 @@ mod extern_rust_hashable_type {
         quote! {
             mod ffi {
                 extern "Rust" {
-                    #[swift_bridge(Hashable)]
+                    #[swift_bridge(Hashable, Equatable)]
                     type HashableType;
                 }
             }
@@ -145,6 +145,55 @@ uint64_t __swift_bridge__$HashableType$_hash(void* self);
     }
 }
 
+/// Test code generation for an extern "Rust" type that names its Identifiable getter.
+mod extern_rust_identifiable_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Identifiable = "user_id")]
+                    type User;
+
+                    fn user_id(&self) -> u64;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::SkipTest
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension UserRef: Identifiable {
+    public var id: UInt64 {
+        return self.user_id()
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn extern_rust_identifiable_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
 /// Test code generation for an extern "Rust" type that implements Equatable.
 mod extern_rust_equatable_type {
     use super::*;
@@ -153,8 +202,731 @@ mod extern_rust_equatable_type {
         quote! {
             mod ffi {
                 extern "Rust" {
-                    #[swift_bridge(Equatable)]
-                    type EquatableType;
+                    #[swift_bridge(Equatable)]
+                    type EquatableType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+        #[export_name = "__swift_bridge__$EquatableType$_partial_eq"]
+        pub extern "C" fn __swift_bridge__EquatableType__partial_eq (
+            lhs: *const super::EquatableType,
+            rhs: *const super::EquatableType
+        ) -> bool {
+            unsafe { &*lhs == &*rhs }
+        }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension EquatableTypeRef: Equatable {
+    public static func == (lhs: EquatableTypeRef, rhs: EquatableTypeRef) -> Bool {
+        __swift_bridge__$EquatableType$_partial_eq(rhs.ptr, lhs.ptr)
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+bool __swift_bridge__$EquatableType$_partial_eq(void* lhs, void* rhs);
+    "#,
+            r#"
+#include <stdint.h>
+#include <stdbool.h>
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_equatable_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for an extern "Rust" type that implements CustomStringConvertible.
+mod extern_rust_custom_string_convertible_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(CustomStringConvertible)]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$_description"]
+            pub extern "C" fn __swift_bridge__SomeType__description (
+                this: *const super::SomeType
+            ) -> *mut swift_bridge::string::RustString {
+                swift_bridge::string::RustString(format!("{}", unsafe { &*this })).box_into_raw()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeTypeRef: CustomStringConvertible {
+    public var description: String {
+        RustString(ptr: __swift_bridge__$SomeType$_description(self.ptr)).toString()
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$SomeType$_description(void* self);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_custom_string_convertible_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for an extern "Rust" Copy type that implements CustomStringConvertible.
+mod extern_rust_copy_custom_string_convertible_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Copy(4), CustomStringConvertible)]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$_description"]
+            pub extern "C" fn __swift_bridge__SomeType__description (
+                this: __swift_bridge__SomeType
+            ) -> *mut swift_bridge::string::RustString {
+                swift_bridge::string::RustString(format!("{}", this.into_rust_repr())).box_into_raw()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeType: CustomStringConvertible {
+    public var description: String {
+        RustString(ptr: __swift_bridge__$SomeType$_description(self.intoFfiRepr())).toString()
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$SomeType$_description(__swift_bridge__$SomeType self);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_copy_custom_string_convertible_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for an extern "Rust" type that implements CustomDebugStringConvertible.
+mod extern_rust_custom_debug_string_convertible_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(CustomDebugStringConvertible)]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$_debug_description"]
+            pub extern "C" fn __swift_bridge__SomeType__debug_description (
+                this: *const super::SomeType
+            ) -> *mut swift_bridge::string::RustString {
+                swift_bridge::string::RustString(format!("{:?}", unsafe { &*this })).box_into_raw()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeTypeRef: CustomDebugStringConvertible {
+    public var debugDescription: String {
+        RustString(ptr: __swift_bridge__$SomeType$_debug_description(self.ptr)).toString()
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$SomeType$_debug_description(void* self);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_custom_debug_string_convertible_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for an extern "Rust" Copy type that implements
+/// CustomDebugStringConvertible.
+mod extern_rust_copy_custom_debug_string_convertible_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Copy(4), CustomDebugStringConvertible)]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$_debug_description"]
+            pub extern "C" fn __swift_bridge__SomeType__debug_description (
+                this: __swift_bridge__SomeType
+            ) -> *mut swift_bridge::string::RustString {
+                swift_bridge::string::RustString(format!("{:?}", this.into_rust_repr())).box_into_raw()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeType: CustomDebugStringConvertible {
+    public var debugDescription: String {
+        RustString(ptr: __swift_bridge__$SomeType$_debug_description(self.intoFfiRepr())).toString()
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$SomeType$_debug_description(__swift_bridge__$SomeType self);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_copy_custom_debug_string_convertible_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for an extern "Rust" type that is Sendable.
+mod extern_rust_sendable_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Sendable)]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            const _: () = {
+                fn _assert_send_sync() {
+                    swift_bridge::sendable_support::assert_send_sync::<super::SomeType>();
+                }
+            };
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeTypeRef: @unchecked Sendable {}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn extern_rust_sendable_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for an extern "Rust" Copy type that is Sendable.
+mod extern_rust_copy_sendable_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Copy(4), Sendable)]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            const _: () = {
+                fn _assert_send_sync() {
+                    swift_bridge::sendable_support::assert_send_sync::<super::SomeType>();
+                }
+            };
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeType: @unchecked Sendable {}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn extern_rust_copy_sendable_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for an extern "Rust" type that is an Iterator.
+mod extern_rust_iterator_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Iterator)]
+                    type SomeType;
+
+                    fn next(&mut self) -> Option<u32>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$next"]
+            pub extern "C" fn __swift_bridge__SomeType_next(
+                this: *mut super::SomeType
+            ) -> swift_bridge::option::OptionU32 {
+                #[cfg(debug_assertions)]
+                swift_bridge::assert_valid_receiver(this, "SomeType::next");
+                if let Some(val) = (unsafe { &mut *this }).next() {
+                    swift_bridge::option::OptionU32 { val, is_some: true }
+                } else {
+                    swift_bridge::option::OptionU32 { val: 123, is_some: false }
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+extension SomeTypeRefMut {
+    public func next() -> Optional<UInt32> {
+        __swift_bridge__$SomeType$next(ptr).intoSwiftRepr()
+    }
+}
+"#,
+            r#"
+extension SomeTypeRefMut: IteratorProtocol, Sequence {}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn extern_rust_iterator_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for an extern "Rust" type that is a Stream.
+mod extern_rust_stream_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Stream(Item = u32))]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$next"]
+            pub extern "C" fn __swift_bridge__SomeType_next(
+                callback_wrapper: *mut std::ffi::c_void,
+                callback: extern "C" fn(*mut std::ffi::c_void, swift_bridge::option::OptionU32) -> (),
+                this: *mut super::SomeType
+            ) {
+                #[cfg(debug_assertions)]
+                swift_bridge::assert_valid_receiver(this, "SomeType::next");
+                let callback_wrapper = swift_bridge::async_support::SwiftCallbackWrapper(callback_wrapper);
+                let fut = swift_bridge::async_support::next_stream_item((unsafe { &mut *this }));
+                let task = async move {
+                    let val = if let Some(val) = fut.await {
+                        swift_bridge::option::OptionU32 { val, is_some: true }
+                    } else {
+                        swift_bridge::option::OptionU32 { val: 123, is_some: false }
+                    };
+
+                    let callback_wrapper = callback_wrapper;
+                    let callback_wrapper = callback_wrapper.0;
+
+                    (callback)(callback_wrapper, val)
+                };
+                swift_bridge::async_support::ASYNC_RUNTIME.spawn_task(Box::pin(task))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+extension SomeTypeRefMut {
+    public func next() async -> Optional<UInt32> {
+"#,
+            r#"
+extension SomeTypeRefMut: AsyncSequence, AsyncIteratorProtocol {
+    public typealias Element = UInt32
+
+    public func makeAsyncIterator() -> SomeTypeRefMut {
+        self
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"void __swift_bridge__$SomeType$next(void* callback_wrapper, void __swift_bridge__$SomeType$next$async(void* callback_wrapper, struct __private__OptionU32 ret), void* self);"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_stream_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for an extern "Rust" type that gets wrapped in an `ObservableObject`.
+mod extern_rust_observable_object_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(ObservableObject = "on_change")]
+                    type SomeType;
+
+                    fn on_change(&mut self, callback: Box<dyn Fn()>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::SkipTest
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+#if canImport(Combine)
+import Combine
+import Foundation
+
+public final class SomeTypeObservable: ObservableObject {
+    public let inner: SomeType
+
+    public init(_ inner: SomeType) {
+        self.inner = inner
+
+        inner.on_change({ [weak self] in
+            if Thread.isMainThread {
+                self?.objectWillChange.send()
+            } else {
+                DispatchQueue.main.async {
+                    self?.objectWillChange.send()
+                }
+            }
+        })
+    }
+}
+#endif
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn extern_rust_observable_object_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for an extern "Rust" type that gets wrapped in a Swift actor.
+mod extern_rust_actor_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Actor)]
+                    type SomeType;
+
+                    fn count(&self) -> u32;
+
+                    fn add(&mut self, #[swift_bridge(label = "amount")] value: u32);
+
+                    async fn fetch(&self) -> u8;
+
+                    fn name(&self) -> String;
+
+                    fn nickname(&self) -> Option<String>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            const _: () = {
+                fn _assert_send_sync() {
+                    swift_bridge::sendable_support::assert_send_sync::<super::SomeType>();
+                }
+            };
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeType: @unchecked Sendable {}
+public actor SomeTypeActor {
+    let inner: SomeType
+
+    public init(_ inner: SomeType) {
+        self.inner = inner
+    }
+
+    public func count() -> UInt32 {
+        inner.count()
+    }
+
+    public func add(amount value: UInt32) {
+        inner.add(amount: value)
+    }
+
+    public func fetch() async -> UInt8 {
+        await inner.fetch()
+    }
+
+    public func name() -> String {
+        inner.name().toString()
+    }
+
+    public func nickname() -> Optional<String> {
+        inner.nickname()?.toString()
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn extern_rust_actor_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that the class of a type that is both `Sendable` and an `Actor` doesn't get a second
+/// `Sendable` conformance, since it inherits its `Ref` class's conformance.
+mod extern_rust_sendable_actor_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Sendable, Actor)]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::DoesNotContainAfterTrim("extension SomeType: @unchecked Sendable {}")
+    }
+
+    #[test]
+    fn extern_rust_sendable_actor_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Test code generation for an extern "Rust" type that implements Comparable.
+mod extern_rust_comparable_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Equatable, Comparable)]
+                    type SomeType;
                 }
             }
         }
@@ -162,22 +934,81 @@ mod extern_rust_equatable_type {
 
     fn expected_rust_tokens() -> ExpectedRustTokens {
         ExpectedRustTokens::Contains(quote! {
-        #[export_name = "__swift_bridge__$EquatableType$_partial_eq"]
-        pub extern "C" fn __swift_bridge__EquatableType__partial_eq (
-            lhs: *const super::EquatableType,
-            rhs: *const super::EquatableType
-        ) -> bool {
-            unsafe { &*lhs == &*rhs }
+            #[export_name = "__swift_bridge__$SomeType$_less_than"]
+            pub extern "C" fn __swift_bridge__SomeType__less_than (
+                lhs: *const super::SomeType,
+                rhs: *const super::SomeType
+            ) -> bool {
+                unsafe { &*lhs < &*rhs }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeTypeRef: Comparable {
+    public static func < (lhs: SomeTypeRef, rhs: SomeTypeRef) -> Bool {
+        __swift_bridge__$SomeType$_less_than(lhs.ptr, rhs.ptr)
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+bool __swift_bridge__$SomeType$_less_than(void* lhs, void* rhs);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_comparable_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for an extern "Rust" Copy type that implements Comparable.
+mod extern_rust_copy_comparable_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Copy(4), Equatable, Comparable)]
+                    type SomeType;
+                }
+            }
         }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$_less_than"]
+            pub extern "C" fn __swift_bridge__SomeType__less_than (
+                lhs: __swift_bridge__SomeType,
+                rhs: __swift_bridge__SomeType
+            ) -> bool {
+                lhs.into_rust_repr() < rhs.into_rust_repr()
+            }
         })
     }
 
     fn expected_swift_code() -> ExpectedSwiftCode {
         ExpectedSwiftCode::ContainsAfterTrim(
             r#"
-extension EquatableTypeRef: Equatable {
-    public static func == (lhs: EquatableTypeRef, rhs: EquatableTypeRef) -> Bool {
-        __swift_bridge__$EquatableType$_partial_eq(rhs.ptr, lhs.ptr)
+extension SomeType: Comparable {
+    public static func < (lhs: SomeType, rhs: SomeType) -> Bool {
+        __swift_bridge__$SomeType$_less_than(lhs.intoFfiRepr(), rhs.intoFfiRepr())
     }
 }
 "#,
@@ -185,19 +1016,153 @@ extension EquatableTypeRef: Equatable {
     }
 
     fn expected_c_header() -> ExpectedCHeader {
-        ExpectedCHeader::ContainsManyAfterTrim(vec![
+        ExpectedCHeader::ContainsAfterTrim(
             r#"
-bool __swift_bridge__$EquatableType$_partial_eq(void* lhs, void* rhs);
-    "#,
+bool __swift_bridge__$SomeType$_less_than(__swift_bridge__$SomeType lhs, __swift_bridge__$SomeType rhs);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_copy_comparable_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for an extern "Rust" type that exposes `std::ops` traits as operators.
+mod extern_rust_operator_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Add, Neg)]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$_add"]
+                pub extern "C" fn __swift_bridge__SomeType__add (
+                    lhs: *const super::SomeType,
+                    rhs: *const super::SomeType
+                ) -> *mut super::SomeType {
+                    Box::into_raw(Box::new(
+                        std::ops::Add::add(unsafe { &*lhs }, unsafe { &*rhs })
+                    ))
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$_neg"]
+                pub extern "C" fn __swift_bridge__SomeType__neg (
+                    operand: *const super::SomeType
+                ) -> *mut super::SomeType {
+                    Box::into_raw(Box::new(
+                        std::ops::Neg::neg(unsafe { &*operand })
+                    ))
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
             r#"
-#include <stdint.h>
-#include <stdbool.h>
+extension SomeTypeRef {
+    public static func + (lhs: SomeTypeRef, rhs: SomeTypeRef) -> SomeType {
+        SomeType(ptr: __swift_bridge__$SomeType$_add(lhs.ptr, rhs.ptr))
+    }
+
+    public static prefix func - (operand: SomeTypeRef) -> SomeType {
+        SomeType(ptr: __swift_bridge__$SomeType$_neg(operand.ptr))
+    }
+}
 "#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "void* __swift_bridge__$SomeType$_add(void* lhs, void* rhs);",
+            "void* __swift_bridge__$SomeType$_neg(void* operand);",
         ])
     }
 
     #[test]
-    fn extern_rust_equatable_type() {
+    fn extern_rust_operator_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for an extern "Rust" Copy type that exposes `std::ops` traits as
+/// operators.
+mod extern_rust_copy_operator_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Copy(8), Mul)]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$_mul"]
+            pub extern "C" fn __swift_bridge__SomeType__mul (
+                lhs: __swift_bridge__SomeType,
+                rhs: __swift_bridge__SomeType
+            ) -> __swift_bridge__SomeType {
+                __swift_bridge__SomeType::from_rust_repr(
+                    std::ops::Mul::mul(lhs.into_rust_repr(), rhs.into_rust_repr())
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeType {
+    public static func * (lhs: SomeType, rhs: SomeType) -> SomeType {
+        __swift_bridge__$SomeType$_mul(lhs.intoFfiRepr(), rhs.intoFfiRepr()).intoSwiftRepr()
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+__swift_bridge__$SomeType __swift_bridge__$SomeType$_mul(__swift_bridge__$SomeType lhs, __swift_bridge__$SomeType rhs);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_copy_operator_type() {
         CodegenTest {
             bridge_module: bridge_module_tokens().into(),
             expected_rust_tokens: expected_rust_tokens(),
@@ -316,6 +1281,132 @@ typedef struct __swift_bridge__$Option$SomeType { bool is_some; __swift_bridge__
     }
 }
 
+/// Test code generation for an extern "Rust" Copy type that implements Equatable.
+mod extern_rust_copy_equatable_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Copy(4), Equatable)]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$_partial_eq"]
+            pub extern "C" fn __swift_bridge__SomeType__partial_eq (
+                lhs: __swift_bridge__SomeType,
+                rhs: __swift_bridge__SomeType
+            ) -> bool {
+                lhs.into_rust_repr() == rhs.into_rust_repr()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeType: Equatable {
+    public static func == (lhs: SomeType, rhs: SomeType) -> Bool {
+        __swift_bridge__$SomeType$_partial_eq(lhs.intoFfiRepr(), rhs.intoFfiRepr())
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$SomeType { uint8_t bytes[4]; } __swift_bridge__$SomeType;
+typedef struct __swift_bridge__$Option$SomeType { bool is_some; __swift_bridge__$SomeType val; } __swift_bridge__$Option$SomeType;
+bool __swift_bridge__$SomeType$_partial_eq(__swift_bridge__$SomeType lhs, __swift_bridge__$SomeType rhs);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_copy_equatable_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for an extern "Rust" Copy type that implements Hashable.
+mod extern_rust_copy_hashable_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Copy(4), Hashable, Equatable)]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$_hash"]
+            pub extern "C" fn __swift_bridge__SomeType__hash (
+                this: __swift_bridge__SomeType,
+            ) -> u64 {
+                use std::hash::{Hash, Hasher};
+                use std::collections::hash_map::DefaultHasher;
+                let mut s = DefaultHasher::new();
+                this.into_rust_repr().hash(&mut s);
+                s.finish()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeType: Hashable {
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(__swift_bridge__$SomeType$_hash(self.intoFfiRepr()))
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+typedef struct __swift_bridge__$Option$SomeType { bool is_some; __swift_bridge__$SomeType val; } __swift_bridge__$Option$SomeType;
+"#,
+            r#"
+uint64_t __swift_bridge__$SomeType$_hash(__swift_bridge__$SomeType self);
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_copy_hashable_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
 /// Verify that we properly generate a method for a Copy opaque Rust type.
 mod extern_rust_copy_type_method {
     use super::*;
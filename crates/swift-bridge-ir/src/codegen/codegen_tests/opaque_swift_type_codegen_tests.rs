@@ -24,7 +24,7 @@ mod extern_swift_freestanding_fn_with_owned_opaque_swift_type_arg {
             }
 
             #[repr(C)]
-            pub struct MyType(*mut std::ffi::c_void);
+            pub struct MyType(pub(crate) *mut std::ffi::c_void);
 
             impl Drop for MyType {
                 fn drop (&mut self) {
@@ -65,3 +65,110 @@ func __swift_bridge__some_function (_ arg: UnsafeMutableRawPointer) {
         .test();
     }
 }
+
+/// Test code generation for freestanding Swift function that takes a reference to an opaque
+/// Swift type.
+/// The Swift object is borrowed, so it should not be retained or released.
+mod extern_swift_freestanding_fn_with_ref_opaque_swift_type_arg {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Swift" {
+                    type MyType;
+                    fn some_function (arg: &MyType);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function (arg: &MyType) {
+                    unsafe { __swift_bridge__some_function (swift_bridge::PointerToSwiftType(arg.0)) }
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function (arg: swift_bridge::PointerToSwiftType);
+            },
+        ])
+    }
+
+    const EXPECTED_SWIFT_CODE: ExpectedSwiftCode = ExpectedSwiftCode::ContainsAfterTrim(
+        r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: UnsafeMutableRawPointer) {
+    some_function(arg: Unmanaged<MyType>.fromOpaque(arg).takeUnretainedValue())
+}
+"#,
+    );
+
+    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::ExactAfterTrim(r#""#);
+
+    #[test]
+    fn extern_swift_freestanding_fn_with_ref_opaque_swift_type_arg() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: EXPECTED_SWIFT_CODE,
+            expected_c_header: EXPECTED_C_HEADER,
+        }
+        .test();
+    }
+}
+
+/// Test code generation for a Rust function that takes a reference to an opaque Swift type.
+mod extern_rust_fn_with_ref_opaque_swift_type_arg {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function (arg: &MyType);
+                }
+
+                extern "Swift" {
+                    type MyType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function (arg: swift_bridge::PointerToSwiftType) {
+                super::some_function(&*std::mem::ManuallyDrop::new(MyType(arg.0)))
+            }
+        })
+    }
+
+    const EXPECTED_SWIFT_CODE: ExpectedSwiftCode = ExpectedSwiftCode::ContainsAfterTrim(
+        r#"
+public func some_function(_ arg: MyType) {
+    __swift_bridge__$some_function(Unmanaged.passUnretained(arg).toOpaque())
+}
+"#,
+    );
+
+    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::ContainsAfterTrim(
+        r#"
+void __swift_bridge__$some_function(void* arg);
+"#,
+    );
+
+    #[test]
+    fn extern_rust_fn_with_ref_opaque_swift_type_arg() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: EXPECTED_SWIFT_CODE,
+            expected_c_header: EXPECTED_C_HEADER,
+        }
+        .test();
+    }
+}
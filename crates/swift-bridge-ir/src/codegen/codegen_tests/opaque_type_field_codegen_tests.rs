@@ -0,0 +1,234 @@
+//! Tests for the `#[swift_bridge(get(..))]` and `#[swift_bridge(set(..))]` opaque type
+//! attributes.
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we generate a read-only Swift computed property for each field in a `get`
+/// attribute.
+mod opaque_rust_type_get_field {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(get(x: f64, &name: &str))]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$_get_x"]
+                pub extern "C" fn __swift_bridge__SomeType__get_x(
+                    this: *mut super::SomeType
+                ) -> f64 {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::assert_valid_receiver(this, "SomeType::_get_x");
+                    (unsafe { &*this }).x
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$_get_name"]
+                pub extern "C" fn __swift_bridge__SomeType__get_name(
+                    this: *mut super::SomeType
+                ) -> swift_bridge::string::RustStr {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::assert_valid_receiver(this, "SomeType::_get_name");
+                    swift_bridge::string::RustStr::from_str(&(unsafe { &*this }).name)
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public class SomeTypeRef {
+    var ptr: UnsafeMutableRawPointer
+
+    public init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+    }
+
+    public var x: Double {
+        __swift_bridge__$SomeType$_get_x(ptr)
+    }
+
+    public var name: String {
+        __swift_bridge__$SomeType$_get_name(ptr).toString()
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"double __swift_bridge__$SomeType$_get_x(void* self);"#,
+            r#"struct RustStr __swift_bridge__$SomeType$_get_name(void* self);"#,
+        ])
+    }
+
+    #[test]
+    fn opaque_rust_type_get_field() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a field in a `set` attribute gets a read-only Swift computed property on the
+/// `SomeTypeRef` class that the `SomeTypeRefMut` class overrides with a read-write one.
+mod opaque_rust_type_set_field {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(set(count: u32))]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$_get_count"]
+                pub extern "C" fn __swift_bridge__SomeType__get_count(
+                    this: *mut super::SomeType
+                ) -> u32 {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::assert_valid_receiver(this, "SomeType::_get_count");
+                    (unsafe { &*this }).count
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$_set_count"]
+                pub extern "C" fn __swift_bridge__SomeType__set_count(
+                    this: *mut super::SomeType,
+                    value: u32
+                ) {
+                    #[cfg(debug_assertions)]
+                    swift_bridge::assert_valid_receiver(this, "SomeType::_set_count");
+                    (unsafe { &mut *this }).count = value
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public class SomeTypeRefMut: SomeTypeRef {
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+
+    public override var count: UInt32 {
+        get {
+            super.count
+        }
+        set(value) {
+            __swift_bridge__$SomeType$_set_count(ptr, value)
+        }
+    }
+}
+"#,
+            r#"
+    public var count: UInt32 {
+        __swift_bridge__$SomeType$_get_count(ptr)
+    }
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"uint32_t __swift_bridge__$SomeType$_get_count(void* self);"#,
+            r#"void __swift_bridge__$SomeType$_set_count(void* self, uint32_t value);"#,
+        ])
+    }
+
+    #[test]
+    fn opaque_rust_type_set_field() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate a Swift computed property for a field of an opaque Copy type.
+mod opaque_copy_type_get_field {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Copy(8), get(x: f64))]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$_get_x"]
+            pub extern "C" fn __swift_bridge__SomeType__get_x(
+                this: __swift_bridge__SomeType
+            ) -> f64 {
+                this.into_rust_repr().x
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeType {
+    public var x: Double {
+        __swift_bridge__$SomeType$_get_x(self.bytes)
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"double __swift_bridge__$SomeType$_get_x(struct __swift_bridge__$SomeType this);"#,
+        )
+    }
+
+    #[test]
+    fn opaque_copy_type_get_field() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -140,6 +140,119 @@ func __swift_bridge__some_function (_ arg: __private__OptionU8) -> __private__Op
     }
 }
 
+/// Test code generation for Rust function that accepts and returns an Option<()>.
+///
+/// An `Option<()>` is passed over FFI as a bool.
+mod extern_rust_fn_option_unit {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function (arg: Option<()>) -> Option<()>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(arg: bool) -> bool {
+                super::some_function(
+                    if arg { Some(()) } else { None }
+                ).is_some()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: Optional<()>) -> Optional<()> {
+    { () -> Optional<()> in if __swift_bridge__$some_function((arg != nil)) { return () } else { return nil } }()
+}
+"#,
+        )
+    }
+
+    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::ExactAfterTrim(
+        r#"
+#include <stdbool.h>
+bool __swift_bridge__$some_function(bool arg);
+"#,
+    );
+
+    #[test]
+    fn extern_rust_fn_option_unit() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: EXPECTED_C_HEADER,
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Swift function that accepts and returns an Option<()>.
+mod extern_swift_fn_option_unit {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function (arg: Option<()>) -> Option<()>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: Option<()>) -> Option<()> {
+                    if unsafe { __swift_bridge__some_function(arg.is_some()) } {
+                        Some(())
+                    } else {
+                        None
+                    }
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(arg: bool) -> bool;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: Bool) -> Bool {
+    (some_function(arg: { () -> Optional<()> in if arg { return () } else { return nil } }()) != nil)
+}
+"#,
+        )
+    }
+
+    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::ExactAfterTrim(r#""#);
+
+    #[test]
+    fn extern_swift_fn_option_unit() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: EXPECTED_C_HEADER,
+        }
+        .test();
+    }
+}
+
 /// Test code generation for Rust function that accepts and returns an Option<Vec<T>> where T is a
 /// primitive.
 mod extern_rust_fn_option_vector_primitive {
@@ -162,10 +275,13 @@ mod extern_rust_fn_option_vector_primitive {
                 arg: *mut Vec<i32>
             ) -> *mut Vec<u32> {
                 if let Some(value) = super::some_function(
-                    if arg.is_null() {
-                        None
-                    } else {
-                        Some( unsafe { * Box::from_raw(arg) } )
+                    {
+                        let val = arg;
+                        if val.is_null() {
+                            None
+                        } else {
+                            Some( unsafe { * Box::from_raw(val) } )
+                        }
                     }
                 ) {
                     Box::into_raw(Box::new(value))
@@ -204,6 +320,122 @@ void* __swift_bridge__$some_function(void* arg);
     }
 }
 
+/// Test code generation for a Swift function that accepts and returns an Option<Vec<T>> where T is
+/// a primitive.
+mod extern_swift_fn_option_vector_primitive {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function (arg: Option<Vec<u8>>) -> Option<Vec<u8>>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub fn some_function(arg: Option<Vec<u8> >) -> Option<Vec<u8> > {
+                {
+                    let val = unsafe {
+                        __swift_bridge__some_function(
+                            if let Some(value) = arg {
+                                Box::into_raw(Box::new(value))
+                            } else {
+                                std::ptr::null_mut()
+                            }
+                        )
+                    };
+                    if val.is_null() {
+                        None
+                    } else {
+                        Some( unsafe { * Box::from_raw(val) } )
+                    }
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: UnsafeMutableRawPointer?) -> UnsafeMutableRawPointer? {
+    { if let val = some_function(arg: { let val = arg; if val != nil { return RustVec(ptr: val!) } else { return nil } }()) { val.isOwned = false; return val.ptr } else { return nil } }()
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_option_vector_primitive() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Test code generation for an async Rust function that returns an Option<Vec<T>>.
+mod extern_rust_async_fn_return_option_vector_primitive {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    async fn some_function () -> Option<Vec<u8>>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            let val = if let Some(value) = fut.await {
+                Box::into_raw(Box::new(value))
+            } else {
+                std::ptr::null_mut()
+            };
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+    func onComplete(cbWrapperPtr: UnsafeMutableRawPointer?, rustFnRetVal: UnsafeMutableRawPointer?) {
+        let wrapper = Unmanaged<CbWrapper$some_function>.fromOpaque(cbWrapperPtr!).takeRetainedValue()
+        wrapper.cb(.success({ let val = rustFnRetVal; if val != nil { return RustVec(ptr: val!) } else { return nil } }()))
+    }
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$some_function(void* callback_wrapper, void __swift_bridge__$some_function$async(void* callback_wrapper, void* ret));
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_async_fn_return_option_vector_primitive() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
 /// Test code generation for Rust function that accepts and returns Option<String>.
 mod extern_rust_fn_option_string {
     use super::*;
@@ -225,10 +457,13 @@ mod extern_rust_fn_option_string {
                 arg: *mut swift_bridge::string::RustString
             ) -> *mut swift_bridge::string::RustString {
                 if let Some(val) = super::some_function(
-                    if arg.is_null() {
-                        None
-                    } else {
-                        Some(unsafe { Box::from_raw(arg).0 })
+                    {
+                        let val = arg;
+                        if val.is_null() {
+                            None
+                        } else {
+                            Some(unsafe { Box::from_raw(val).0 })
+                        }
                     }
                 ) {
                     swift_bridge::string::RustString(val).box_into_raw()
@@ -362,10 +597,13 @@ mod extern_rust_fn_arg_option_str {
                arg: swift_bridge::string::RustStr
             ) {
                 super::some_function(
-                    if arg.start.is_null() {
-                        None
-                    } else {
-                        Some(arg.to_str())
+                    {
+                        let val = arg;
+                        if val.start.is_null() {
+                            None
+                        } else {
+                            Some(val.to_str())
+                        }
                     }
                 )
             }
@@ -402,83 +640,354 @@ void __swift_bridge__$some_function(struct RustStr arg);
     }
 }
 
-/// Test code generation for Rust function that returns an Option<OpaqueRustType>
-mod extern_rust_fn_return_option_opaque_rust_type {
+/// Test code generation for Swift function that accepts and returns Option<String>.
+mod extern_swift_fn_option_string {
     use super::*;
 
     fn bridge_module_tokens() -> TokenStream {
         quote! {
             mod ffi {
-                extern "Rust" {
-                    type SomeType;
-                    fn some_function () -> Option<SomeType>;
+                extern "Swift" {
+                    fn some_function (arg: Option<String>) -> Option<String>;
                 }
             }
         }
     }
 
     fn expected_rust_tokens() -> ExpectedRustTokens {
-        ExpectedRustTokens::Contains(quote! {
-            #[export_name = "__swift_bridge__$some_function"]
-            pub extern "C" fn __swift_bridge__some_function() -> *mut super::SomeType {
-                if let Some(val) = super::some_function() {
-                    Box::into_raw(Box::new(val))
-                } else {
-                    std::ptr::null_mut()
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: Option<String>) -> Option<String> {
+                    {
+                        let val = unsafe {
+                            __swift_bridge__some_function(
+                                if let Some(val) = arg {
+                                    swift_bridge::string::RustString(val).box_into_raw()
+                                } else {
+                                    std::ptr::null::<swift_bridge::string::RustString>() as *mut swift_bridge::string::RustString
+                                }
+                            )
+                        };
+                        if val.is_null() {
+                            None
+                        } else {
+                            Some(unsafe { Box::from_raw(val).0 })
+                        }
+                    }
                 }
-            }
-        })
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(
+                    arg: *mut swift_bridge::string::RustString
+                ) -> *mut swift_bridge::string::RustString;
+            },
+        ])
     }
 
     fn expected_swift_code() -> ExpectedSwiftCode {
         ExpectedSwiftCode::ContainsAfterTrim(
             r#"
-func some_function() -> Optional<SomeType> {
-    { let val = __swift_bridge__$some_function(); if val != nil { return SomeType(ptr: val!) } else { return nil } }()
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: UnsafeMutableRawPointer?) -> UnsafeMutableRawPointer? {
+    { if let rustString = optionalStringIntoRustString(some_function(arg: { let val = arg; if val != nil { return RustString(ptr: val!) } else { return nil } }())) { rustString.isOwned = false; return rustString.ptr } else { return nil } }()
 }
 "#,
         )
     }
 
-    fn expected_c_header() -> ExpectedCHeader {
-        ExpectedCHeader::ContainsAfterTrim(
-            r#"
-void* __swift_bridge__$some_function(void);
-    "#,
-        )
-    }
+    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::ExactAfterTrim(r#""#);
 
     #[test]
-    fn extern_rust_fn_return_option_opaque_rust_type() {
+    fn extern_swift_fn_option_string() {
         CodegenTest {
             bridge_module: bridge_module_tokens().into(),
             expected_rust_tokens: expected_rust_tokens(),
             expected_swift_code: expected_swift_code(),
-            expected_c_header: expected_c_header(),
+            expected_c_header: EXPECTED_C_HEADER,
         }
         .test();
     }
 }
 
-/// Test code generation for Rust function that returns an Option<&OpaqueRustType>
-mod extern_rust_fn_return_option_ref_opaque_rust_type {
+/// Test code generation for Swift function that takes an Option<&str> argument.
+mod extern_swift_fn_arg_option_str {
     use super::*;
 
     fn bridge_module_tokens() -> TokenStream {
         quote! {
             mod ffi {
-                extern "Rust" {
-                    type SomeType;
-                    fn some_function () -> Option<&SomeType>;
+                extern "Swift" {
+                    fn some_function (arg: Option<&str>);
                 }
             }
         }
     }
 
     fn expected_rust_tokens() -> ExpectedRustTokens {
-        ExpectedRustTokens::Contains(quote! {
-            #[export_name = "__swift_bridge__$some_function"]
-            pub extern "C" fn __swift_bridge__some_function() -> *const super::SomeType {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: Option<&str>) {
+                    unsafe {
+                        __swift_bridge__some_function(
+                            if let Some(val) = arg {
+                                swift_bridge::string::RustStr::from_str(val)
+                            } else {
+                                swift_bridge::string::RustStr { start: std::ptr::null::<u8>(), len: 0}
+                            }
+                        )
+                    }
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(arg: swift_bridge::string::RustStr);
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: RustStr) {
+    some_function(arg: { let val = arg; if val.start != nil { return val; } else { return nil; } }())
+}
+"#,
+        )
+    }
+
+    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::ExactAfterTrim(r#""#);
+
+    #[test]
+    fn extern_swift_fn_arg_option_str() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: EXPECTED_C_HEADER,
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Swift function that accepts and returns an Option<OpaqueSwiftType>.
+mod extern_swift_fn_option_opaque_swift_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    type SomeSwiftType;
+
+                    fn some_function (arg: Option<SomeSwiftType>) -> Option<SomeSwiftType>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: Option<SomeSwiftType>) -> Option<SomeSwiftType> {
+                    {
+                        let val = unsafe {
+                            __swift_bridge__some_function(
+                                if let Some(val) = arg {
+                                    std::mem::ManuallyDrop::new(val).0
+                                } else {
+                                    std::ptr::null_mut()
+                                }
+                            )
+                        };
+                        if val.is_null() {
+                            None
+                        } else {
+                            Some(SomeSwiftType(val))
+                        }
+                    }
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(arg: *mut std::ffi::c_void) -> *mut std::ffi::c_void;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: UnsafeMutableRawPointer?) -> UnsafeMutableRawPointer? {
+    { if let val = some_function(arg: { let val = arg; if val != nil { return Unmanaged<SomeSwiftType>.fromOpaque(val!).takeRetainedValue() } else { return nil } }()) { return Unmanaged.passRetained(val).toOpaque() } else { return nil } }()
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_option_opaque_swift_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that accepts and returns an Option<OpaqueSwiftType>.
+mod extern_rust_fn_option_opaque_swift_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function (arg: Option<SomeSwiftType>) -> Option<SomeSwiftType>;
+                }
+
+                extern "Swift" {
+                    type SomeSwiftType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: *mut std::ffi::c_void
+            ) -> *mut std::ffi::c_void {
+                if let Some(val) = super::some_function(
+                    {
+                        let val = arg;
+                        if val.is_null() {
+                            None
+                        } else {
+                            Some(SomeSwiftType(val))
+                        }
+                    }
+                ) {
+                    std::mem::ManuallyDrop::new(val).0
+                } else {
+                    std::ptr::null_mut()
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: Optional<SomeSwiftType>) -> Optional<SomeSwiftType> {
+    { let val = __swift_bridge__$some_function({ if let val = arg { return Unmanaged.passRetained(val).toOpaque() } else { return nil } }()); if val != nil { return Unmanaged<SomeSwiftType>.fromOpaque(val!).takeRetainedValue() } else { return nil } }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$some_function(void* arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_option_opaque_swift_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that returns an Option<OpaqueRustType>
+mod extern_rust_fn_return_option_opaque_rust_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                    fn some_function () -> Option<SomeType>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function() -> *mut super::SomeType {
+                if let Some(val) = super::some_function() {
+                    Box::into_raw(Box::new(val))
+                } else {
+                    std::ptr::null_mut()
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function() -> Optional<SomeType> {
+    { let val = __swift_bridge__$some_function(); if val != nil { return SomeType(ptr: val!) } else { return nil } }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$some_function(void);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_return_option_opaque_rust_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that returns an Option<&OpaqueRustType>
+mod extern_rust_fn_return_option_ref_opaque_rust_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                    fn some_function () -> Option<&SomeType>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function() -> *const super::SomeType {
                 if let Some(val) = super::some_function() {
                     val as *const super::SomeType
                 } else {
@@ -578,6 +1087,124 @@ void __swift_bridge__$some_function(void* arg);
     }
 }
 
+/// Test code generation for Rust function that takes an Option<&mut OpaqueRustType> argument.
+mod extern_rust_fn_arg_option_ref_mut_opaque_rust_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                    fn some_function (arg: Option<&mut SomeType>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(arg: *mut super::SomeType) {
+                super::some_function(
+                    if arg.is_null() {
+                        None
+                    } else {
+                        Some( unsafe { & mut * arg })
+                    }
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function(_ arg: Optional<SomeTypeRefMut>) {
+    __swift_bridge__$some_function({ if let val = arg { return val.ptr } else { return nil } }())
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$some_function(void* arg);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_arg_option_ref_mut_opaque_rust_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that returns an Option<&mut OpaqueRustType>
+mod extern_rust_fn_return_option_ref_mut_opaque_rust_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                    fn some_function (arg: &mut SomeType) -> Option<&mut SomeType>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(arg: *mut super::SomeType) -> *mut super::SomeType {
+                if let Some(val) = super::some_function(unsafe { & mut * arg }) {
+                    val as *mut super::SomeType
+                } else {
+                    std::ptr::null_mut()
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function(_ arg: SomeTypeRefMut) -> Optional<SomeTypeRefMut> {
+    { let val = __swift_bridge__$some_function(arg.ptr); if val != nil { return SomeTypeRefMut(ptr: val!) } else { return nil } }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$some_function(void* arg);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_return_option_ref_mut_opaque_rust_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
 /// Test code generation for Rust function that takes an Option<OpaqueRustType> argument.
 mod extern_rust_fn_with_option_opaque_rust_type_arg {
     use super::*;
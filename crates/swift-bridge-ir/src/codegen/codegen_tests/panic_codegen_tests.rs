@@ -0,0 +1,123 @@
+//! Tests for the `panic` module argument.
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that the `fatal_error` panic policy catches the Rust function's panics, and that the
+/// Swift function that calls it stays the same.
+mod panic_fatal_error {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge(panic = "fatal_error")]
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: u8) -> u8;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(arg: u8) -> u8 {
+                swift_bridge::panic_support::call_or_fatal_error(move || {
+                    super::some_function(arg)
+                })
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: UInt8) -> UInt8 {
+    __swift_bridge__$some_function(arg)
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn panic_fatal_error() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that the `throw` panic policy throws the panics of functions that return a `Result`,
+/// and calls `fatalError` for the panics of the other functions.
+mod panic_throw {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge(panic = "throw")]
+            mod ffi {
+                extern "Rust" {
+                    fn fallible() -> Result<(), String>;
+                    fn infallible();
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$fallible"]
+                pub extern "C" fn __swift_bridge__fallible() -> *mut swift_bridge::string::RustString {
+                    let body = move || {
+                        match super::fallible() {
+                            Ok(ok) => std::ptr::null_mut(),
+                            Err(err) => swift_bridge::string::RustString(err).box_into_raw()
+                        }
+                    };
+                    unsafe { swift_bridge::panic_support::call_or_throw(body) }
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$infallible"]
+                pub extern "C" fn __swift_bridge__infallible() {
+                    swift_bridge::panic_support::call_or_fatal_error(move || {
+                        super::infallible()
+                    })
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func fallible() throws -> () {
+    try { let val = try __swift_bridge__throwIfRustPanicked(__swift_bridge__$fallible()); if val != nil { throw RustString(ptr: val!) } else { return } }()
+}
+"#,
+            r#"
+public func infallible() {
+    __swift_bridge__$infallible()
+}
+"#,
+        ])
+    }
+
+    #[test]
+    fn panic_throw() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
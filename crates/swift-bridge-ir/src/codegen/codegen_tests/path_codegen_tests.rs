@@ -0,0 +1,225 @@
+//! Tests for bridging `PathBuf` and `&Path`.
+//!
+//! A path is passed over FFI the same way that a `String` is. On the Swift side it is a `String`,
+//! or a file `URL` when the module sets `swift_path_type = "URL"`.
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that a Rust function can take a `PathBuf` and a `&Path` and return a `PathBuf`, and
+/// that paths are a Swift `String` by default.
+mod extern_rust_fn_path_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg1: PathBuf, arg2: &Path) -> PathBuf;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg1: *mut swift_bridge::string::RustString,
+                arg2: *mut swift_bridge::string::RustString
+            ) -> *mut swift_bridge::string::RustString {
+                swift_bridge::string::RustString(
+                    super::some_function(
+                        std::path::PathBuf::from(unsafe { Box::from_raw(arg1).0 }),
+                        std::path::Path::new(&unsafe { Box::from_raw(arg2).0 })
+                    )
+                    .to_string_lossy()
+                    .into_owned()
+                )
+                .box_into_raw()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg1: String, _ arg2: String) -> String {
+    RustString(ptr: __swift_bridge__$some_function({ let rustString = arg1.intoRustString(); rustString.isOwned = false; return rustString.ptr }(), { let rustString = arg2.intoRustString(); rustString.isOwned = false; return rustString.ptr }())).toString()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$some_function(void* arg1, void* arg2);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_path_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that the `swift_path_type = "URL"` module attribute makes paths a Swift file `URL`.
+mod extern_rust_fn_path_as_url {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge(swift_path_type = "URL")]
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: PathBuf) -> PathBuf;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function(_ arg: URL) -> URL {
+    URL(fileURLWithPath: RustString(ptr: __swift_bridge__$some_function({ let rustString = arg.path.intoRustString(); rustString.isOwned = false; return rustString.ptr }())).toString())
+}
+"#,
+            r#"
+import Foundation
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_path_as_url() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that a Swift function can take a `&Path` and return a `PathBuf`.
+mod extern_swift_fn_path_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge(swift_path_type = "URL")]
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(arg: &Path) -> PathBuf;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub fn some_function(arg: &std::path::Path) -> std::path::PathBuf {
+                std::path::PathBuf::from(unsafe {
+                    Box::from_raw(unsafe {
+                        __swift_bridge__some_function(
+                            swift_bridge::string::RustString(arg.to_string_lossy().into_owned())
+                                .box_into_raw()
+                        )
+                    })
+                    .0
+                })
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: UnsafeMutableRawPointer) -> UnsafeMutableRawPointer {
+    { let rustString = some_function(arg: URL(fileURLWithPath: RustString(ptr: arg).toString())).path.intoRustString(); rustString.isOwned = false; return rustString.ptr }()
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_path_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that a Rust function can take and return an `Option<PathBuf>`.
+mod extern_rust_fn_option_path_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Option<PathBuf>) -> Option<PathBuf>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: *mut swift_bridge::string::RustString
+            ) -> *mut swift_bridge::string::RustString {
+                if let Some(val) = super::some_function({
+                    let val = arg;
+                    if val.is_null() {
+                        None
+                    } else {
+                        Some(std::path::PathBuf::from(unsafe { Box::from_raw(val).0 }))
+                    }
+                }) {
+                    swift_bridge::string::RustString(val.to_string_lossy().into_owned()).box_into_raw()
+                } else {
+                    std::ptr::null::<swift_bridge::string::RustString>() as *mut swift_bridge::string::RustString
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: Optional<String>) -> Optional<String> {
+    { let val = __swift_bridge__$some_function({ if let val = arg { return { let rustString = val.intoRustString(); rustString.isOwned = false; return rustString.ptr }() } else { return nil } }()); if val != nil { return RustString(ptr: val!).toString() } else { return nil } }()
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_option_path_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -0,0 +1,251 @@
+//! Tests for passing raw pointers such as `*const c_void` and `*mut T` between Rust and Swift.
+//!
+//! Raw pointers are passed over FFI as is, with no conversion on either side.
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that a fully qualified pointee such as `std::ffi::c_void` is not looked up in the
+/// parent module.
+mod extern_rust_fn_absolute_pointee_path {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: *mut std::ffi::c_void) -> *const core::ffi::c_void;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: *mut std::ffi::c_void
+            ) -> *const core::ffi::c_void {
+                super::some_function(arg)
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: UnsafeMutableRawPointer) -> UnsafeRawPointer {
+    UnsafeRawPointer(__swift_bridge__$some_function(arg)!)
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$some_function(void* arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_absolute_pointee_path() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a pointer to an opaque type is passed along as a raw pointer.
+mod extern_rust_fn_pointer_to_opaque_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn some_function(arg: *mut SomeType) -> *const SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: *mut super::SomeType
+            ) -> *const super::SomeType {
+                super::some_function(arg)
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: UnsafeMutableRawPointer) -> UnsafeRawPointer {
+    UnsafeRawPointer(__swift_bridge__$some_function(arg)!)
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$some_function(void* arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_pointer_to_opaque_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a Rust function can take and return optional pointers, which are passed over FFI
+/// as nullable pointers.
+mod extern_rust_fn_option_pointer {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(
+                        arg1: Option<*const c_void>,
+                        arg2: Option<*mut u8>
+                    ) -> Option<*const c_void>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg1: *const super::c_void,
+                arg2: *mut u8
+            ) -> *const super::c_void {
+                if let Some(val) = super::some_function(
+                    {
+                        let val = arg1;
+                        if val.is_null() { None } else { Some(val) }
+                    },
+                    {
+                        let val = arg2;
+                        if val.is_null() { None } else { Some(val) }
+                    }
+                ) {
+                    val
+                } else {
+                    std::ptr::null()
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg1: Optional<UnsafeRawPointer>, _ arg2: Optional<UnsafeMutablePointer<UInt8>>) -> Optional<UnsafeRawPointer> {
+    __swift_bridge__$some_function(UnsafeMutableRawPointer(mutating: arg1), arg2).map { UnsafeRawPointer($0) }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$some_function(void* arg1, uint8_t* arg2);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_option_pointer() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a Swift function can take and return optional pointers.
+mod extern_swift_fn_option_pointer {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(arg: Option<*mut c_void>) -> Option<*const c_void>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: Option<*mut super::c_void>) -> Option<*const super::c_void> {
+                    {
+                        let val = unsafe {
+                            __swift_bridge__some_function(
+                                if let Some(val) = arg { val } else { std::ptr::null_mut() }
+                            )
+                        };
+                        if val.is_null() { None } else { Some(val) }
+                    }
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(arg: *mut super::c_void) -> *const super::c_void;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: Optional<UnsafeMutableRawPointer>) -> Optional<UnsafeRawPointer> {
+    some_function(arg: arg)
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_option_pointer() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -0,0 +1,114 @@
+//! Tests for bridging `usize` and `isize`.
+//!
+//! These are `UInt` and `Int` in Swift, and we assert at compile time that they are pointer sized
+//! on the target platform.
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that a function that uses `usize` and `isize` gets bridged as `UInt` and `Int`, and that
+/// we emit a single width assertion in the Rust code and in the C header.
+mod pointer_sized_integer_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: usize) -> isize;
+                    fn another_function(arg: Option<isize>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                const _: () = {
+                    let _: [u8; std::mem::size_of::<usize>()] = [0; std::mem::size_of::<*const std::ffi::c_void>()];
+                    let _: [u8; std::mem::size_of::<isize>()] = [0; std::mem::size_of::<*const std::ffi::c_void>()];
+                };
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$some_function"]
+                pub extern "C" fn __swift_bridge__some_function(arg: usize) -> isize {
+                    super::some_function(arg)
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function(_ arg: UInt) -> Int {
+    __swift_bridge__$some_function(arg)
+}
+"#,
+            r#"
+public func another_function(_ arg: Optional<Int>) {
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+intptr_t __swift_bridge__$some_function(uintptr_t arg);
+"#,
+            r#"
+_Static_assert(sizeof(uintptr_t) == sizeof(void*), "uintptr_t must be pointer sized to match Rust's usize and Swift's UInt");
+_Static_assert(sizeof(intptr_t) == sizeof(void*), "intptr_t must be pointer sized to match Rust's isize and Swift's Int");
+"#,
+        ])
+    }
+
+    #[test]
+    fn pointer_sized_integer_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we do not emit the width assertion for modules that do not use `usize` or `isize`.
+mod no_pointer_sized_integer {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: u64) -> i64;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::DoesNotContain(quote! {
+            std::mem::size_of::<usize>()
+        })
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::DoesNotContainAfterTrim("_Static_assert")
+    }
+
+    #[test]
+    fn no_pointer_sized_integer() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: ExpectedSwiftCode::SkipTest,
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
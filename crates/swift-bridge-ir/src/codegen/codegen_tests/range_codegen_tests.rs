@@ -0,0 +1,143 @@
+//! Tests for bridging `std::ops::Range<T>`.
+//!
+//! A `Range<T>` is a Swift `Range<T>` on the Swift side. It is passed over FFI as a `#[repr(C)]`
+//! struct holding its start and its end.
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that a Rust function can take and return ranges.
+mod extern_rust_fn_range_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Range<usize>) -> std::ops::Range<i64>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__range_UInt {
+                    pub start: usize,
+                    pub end: usize
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$some_function"]
+                pub extern "C" fn __swift_bridge__some_function(
+                    arg: __swift_bridge__range_UInt
+                ) -> __swift_bridge__range_I64 {
+                    {
+                        let val = super::some_function({ let val = arg; val.start..val.end });
+                        __swift_bridge__range_I64 { start: val.start, end: val.end }
+                    }
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: Range<UInt>) -> Range<Int64> {
+    { let val = __swift_bridge__$some_function({ let val = arg; return __swift_bridge__$range$UInt(start: val.lowerBound, end: val.upperBound); }()); return val.start..<max(val.start, val.end); }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+struct __swift_bridge__$range$I64 __swift_bridge__$some_function(struct __swift_bridge__$range$UInt arg);
+"#,
+            r#"
+typedef struct __swift_bridge__$range$I64 { int64_t start; int64_t end; } __swift_bridge__$range$I64;
+"#,
+            r#"
+typedef struct __swift_bridge__$range$UInt { uintptr_t start; uintptr_t end; } __swift_bridge__$range$UInt;
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_range_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a Swift function can take and return ranges.
+mod extern_swift_fn_range_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(arg: Range<u32>) -> Range<isize>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: std::ops::Range<u32>) -> std::ops::Range<isize> {
+                    {
+                        let val = unsafe {
+                            __swift_bridge__some_function({
+                                let val = arg;
+                                __swift_bridge__range_U32 { start: val.start, end: val.end }
+                            })
+                        };
+                        val.start..val.end
+                    }
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(
+                    arg: __swift_bridge__range_U32
+                ) -> __swift_bridge__range_Int;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: __swift_bridge__$range$U32) -> __swift_bridge__$range$Int {
+    { let val = some_function(arg: { let val = arg; return val.start..<max(val.start, val.end); }()); return __swift_bridge__$range$Int(start: val.lowerBound, end: val.upperBound); }()
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_range_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
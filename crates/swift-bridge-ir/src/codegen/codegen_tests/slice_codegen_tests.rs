@@ -0,0 +1,114 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Test code generation for Rust function that takes a &mut [T] argument.
+mod extern_rust_fn_arg_mut_slice {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: &mut [f32]);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(arg: swift_bridge::FfiSlice<f32>) {
+                super::some_function(unsafe { arg.as_mut_slice() })
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function(_ arg: UnsafeMutableBufferPointer<Float>) {
+    __swift_bridge__$some_function(arg.toFfiSlice())
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+void __swift_bridge__$some_function(struct __private__FfiSlice arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_arg_mut_slice() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that returns a &mut [T].
+mod extern_rust_fn_return_mut_slice {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                    fn some_method(&mut self) -> &mut [u8];
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$some_method"]
+            pub extern "C" fn __swift_bridge__SomeType_some_method(
+                this: *mut super::SomeType
+            ) -> swift_bridge::FfiSlice<u8> {
+                #[cfg(debug_assertions)]
+                swift_bridge::assert_valid_receiver(this, "SomeType::some_method");
+                swift_bridge::FfiSlice::from_mut_slice((unsafe { &mut *this }).some_method())
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+    public func some_method() -> UnsafeMutableBufferPointer<UInt8> {
+        let slice = __swift_bridge__$SomeType$some_method(ptr); return UnsafeMutableBufferPointer(start: slice.start.assumingMemoryBound(to: UInt8.self), count: Int(slice.len));
+    }
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__FfiSlice __swift_bridge__$SomeType$some_method(void* self);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_return_mut_slice() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,132 @@
+//! Tests for the `swift_access` module argument and the `#[swift_bridge(swift_access = "..")]`
+//! type and function attributes.
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that the `swift_access` module argument sets the access level of the module's
+/// generated Swift declarations, and that a function can override it.
+mod module_swift_access {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge(swift_access = "internal")]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(associated_to = SomeType)]
+                    fn make() -> SomeType;
+
+                    fn some_function() -> u8;
+
+                    #[swift_bridge(swift_access = "public")]
+                    fn public_function();
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+internal func some_function() -> UInt8 {
+"#,
+            r#"
+public func public_function() {
+"#,
+            r#"
+internal class SomeType: SomeTypeRefMut {
+    var isOwned: Bool = true
+
+    internal override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+"#,
+            r#"
+    class internal func make() -> SomeType {
+"#,
+            r#"
+internal class SomeTypeRef {
+"#,
+            r#"
+extension SomeType: Vectorizable {
+    internal static func vecOfSelfNew() -> UnsafeMutableRawPointer {
+"#,
+        ])
+    }
+
+    #[test]
+    fn module_swift_access() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that the `swift_access` attribute sets the access level of an opaque type's generated
+/// Swift declarations, and of a single method.
+mod type_and_method_swift_access {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(swift_access = "package")]
+                    type SomeType;
+                }
+
+                extern "Rust" {
+                    type AnotherType;
+
+                    #[swift_bridge(swift_access = "internal")]
+                    fn plumbing(&self);
+
+                    fn api(&self);
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+package class SomeType: SomeTypeRefMut {
+"#,
+            r#"
+public class AnotherTypeRef {
+    var ptr: UnsafeMutableRawPointer
+
+    public init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+    }
+}
+extension AnotherTypeRef {
+    internal func plumbing() {
+        __swift_bridge__$AnotherType$plumbing(ptr)
+    }
+
+    public func api() {
+"#,
+        ])
+    }
+
+    #[test]
+    fn type_and_method_swift_access() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
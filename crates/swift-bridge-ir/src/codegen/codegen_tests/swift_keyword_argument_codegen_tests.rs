@@ -0,0 +1,130 @@
+//! Tests for function parameters that are named after Swift keywords.
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that parameters that are named after Swift keywords get renamed on the Swift side, while
+/// their argument labels are kept.
+mod swift_keyword_argument_names {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn some_function(r#in: u8, repeat: u32, func: &str);
+
+                    fn some_method(&self, class: u8);
+
+                    #[swift_bridge(labeled_args)]
+                    fn labeled_function(r#for: u8);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                r#in: u8,
+                repeat: u32,
+                func: swift_bridge::string::RustStr
+            ) {
+                super::some_function(r#in, repeat, func.to_str())
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function<GenericToRustStr: ToRustStr>(_ in_: UInt8, _ repeat_: UInt32, _ func_: GenericToRustStr) {
+    func_.toRustStr({ func_AsRustStr in
+        __swift_bridge__$some_function(in_, repeat_, func_AsRustStr)
+    })
+}
+"#,
+            r#"
+    public func some_method(_ class_: UInt8) {
+        __swift_bridge__$SomeType$some_method(ptr, class_)
+    }
+"#,
+            r#"
+public func labeled_function(for for_: UInt8) {
+    __swift_bridge__$labeled_function(for_)
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"void __swift_bridge__$some_function(uint8_t in, uint32_t repeat, struct RustStr func);"#,
+        )
+    }
+
+    #[test]
+    fn swift_keyword_argument_names() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that an extern Swift function's parameters that are named after Swift keywords get
+/// renamed in the generated Swift function that Rust calls, and are passed to the Swift function
+/// under their original argument labels.
+mod extern_swift_keyword_argument_names {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Swift" {
+                    #[swift_bridge(labeled_args)]
+                    fn some_function(repeat: u32);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::SkipTest
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (repeat repeat_: UInt32) {
+    some_function(repeat: repeat_)
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn extern_swift_keyword_argument_names() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
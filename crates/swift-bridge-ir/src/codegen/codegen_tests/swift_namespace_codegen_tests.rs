@@ -0,0 +1,117 @@
+//! Tests for the `swift_namespace` module argument.
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that the `swift_namespace` module argument emits the module's free Rust functions as
+/// static functions of a caseless Swift enum, while methods and Swift functions are unaffected.
+mod module_swift_namespace {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge(swift_namespace = "Ffi")]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn parse(text: &str) -> u8;
+                    fn reset();
+                    fn some_method(&self);
+                }
+
+                extern "Swift" {
+                    fn swift_function();
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public enum Ffi {
+    static public func parse<GenericToRustStr: ToRustStr>(_ text: GenericToRustStr) -> UInt8 {
+        return text.toRustStr({ textAsRustStr in
+            __swift_bridge__$parse(textAsRustStr)
+        })
+    }
+
+    static public func reset() {
+        __swift_bridge__$reset()
+    }
+}
+"#,
+            r#"
+@_cdecl("__swift_bridge__$swift_function")
+func __swift_bridge__swift_function () {
+    swift_function()
+}
+"#,
+            r#"
+    public func some_method() {
+        __swift_bridge__$SomeType$some_method(ptr)
+    }
+"#,
+        ])
+    }
+
+    #[test]
+    fn module_swift_namespace() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that the namespace enum uses the module's `swift_access`, and that its functions use
+/// their own.
+mod swift_namespace_with_swift_access {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge(swift_namespace = "Ffi", swift_access = "internal")]
+            mod ffi {
+                extern "Rust" {
+                    fn some_function() -> u8;
+
+                    #[swift_bridge(swift_access = "package")]
+                    fn package_function();
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+internal enum Ffi {
+    static internal func some_function() -> UInt8 {
+        __swift_bridge__$some_function()
+    }
+
+    static package func package_function() {
+        __swift_bridge__$package_function()
+    }
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn swift_namespace_with_swift_access() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -0,0 +1,142 @@
+//! Tests for the `symbol_prefix` module argument.
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that the `symbol_prefix` module argument adds the prefix to the symbols of the module's
+/// functions and opaque types on both the Rust and the Swift side.
+mod module_symbol_prefix {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge(symbol_prefix = "my_crate")]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn some_function();
+                    fn some_method(&self);
+                }
+
+                extern "Swift" {
+                    fn swift_function();
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$my_crate$some_function"]
+                pub extern "C" fn __swift_bridge__some_function() {
+                    super::some_function()
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$my_crate$SomeType$_free"]
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$my_crate$swift_function"]
+                fn __swift_bridge__swift_function();
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function() {
+    __swift_bridge__$my_crate$some_function()
+}
+"#,
+            r#"
+            __swift_bridge__$my_crate$SomeType$_free(ptr)
+"#,
+            r#"
+@_cdecl("__swift_bridge__$my_crate$swift_function")
+func __swift_bridge__swift_function () {
+    swift_function()
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "void __swift_bridge__$my_crate$SomeType$_free(void* self);",
+            "void __swift_bridge__$my_crate$some_function(void);",
+            "void __swift_bridge__$my_crate$SomeType$some_method(void* self);",
+        ])
+    }
+
+    #[test]
+    fn module_symbol_prefix() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that the C types of shared structs get the prefix as well, since they would otherwise
+/// collide when two crates' headers are imported into the same Swift target.
+mod shared_struct_symbol_prefix {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge(symbol_prefix = "my_crate")]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    field: u8,
+                }
+
+                extern "Rust" {
+                    fn some_function(arg: SomeStruct);
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+    func intoFfiRepr() -> __swift_bridge__$my_crate$SomeStruct {
+        { let val = self; return __swift_bridge__$my_crate$SomeStruct(field: val.field); }()
+"#,
+            r#"
+extension __swift_bridge__$my_crate$SomeStruct {
+"#,
+            r#"
+public func some_function(_ arg: SomeStruct) {
+    __swift_bridge__$my_crate$some_function(arg.intoFfiRepr())
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "typedef struct __swift_bridge__$my_crate$SomeStruct { uint8_t field; } __swift_bridge__$my_crate$SomeStruct;",
+            "void __swift_bridge__$my_crate$some_function(struct __swift_bridge__$my_crate$SomeStruct arg);",
+        ])
+    }
+
+    #[test]
+    fn shared_struct_symbol_prefix() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
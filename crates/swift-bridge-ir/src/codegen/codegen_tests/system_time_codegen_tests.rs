@@ -0,0 +1,271 @@
+//! Tests for bridging `std::time::SystemTime`.
+//!
+//! A `SystemTime` is a Foundation `Date` on the Swift side. It is passed over FFI as a number of
+//! seconds since the Unix epoch.
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that a Rust function can take and return a `SystemTime`.
+mod extern_rust_fn_system_time_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: SystemTime) -> SystemTime;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(arg: f64) -> f64 {
+                match super::some_function({
+                    let secs: f64 = arg;
+                    let secs = if secs.is_nan() {
+                        0.
+                    } else {
+                        secs.clamp(-62135769600., 64092211200.)
+                    };
+                    if secs >= 0. {
+                        std::time::UNIX_EPOCH + std::time::Duration::from_secs_f64(secs)
+                    } else {
+                        std::time::UNIX_EPOCH - std::time::Duration::from_secs_f64(-secs)
+                    }
+                })
+                .duration_since(std::time::UNIX_EPOCH)
+                {
+                    Ok(after_epoch) => after_epoch.as_secs_f64(),
+                    Err(before_epoch) => -before_epoch.duration().as_secs_f64(),
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function(_ arg: Date) -> Date {
+    Date(timeIntervalSince1970: __swift_bridge__$some_function(arg.timeIntervalSince1970))
+}
+"#,
+            r#"
+import Foundation
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+double __swift_bridge__$some_function(double arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_system_time_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a Swift function can take and return a `SystemTime`.
+mod extern_swift_fn_system_time_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(arg: SystemTime) -> SystemTime;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub fn some_function(arg: std::time::SystemTime) -> std::time::SystemTime {
+                {
+                    let secs: f64 = unsafe {
+                        __swift_bridge__some_function(match arg.duration_since(std::time::UNIX_EPOCH) {
+                            Ok(after_epoch) => after_epoch.as_secs_f64(),
+                            Err(before_epoch) => -before_epoch.duration().as_secs_f64(),
+                        })
+                    };
+                    let secs = if secs.is_nan() {
+                        0.
+                    } else {
+                        secs.clamp(-62135769600., 64092211200.)
+                    };
+                    if secs >= 0. {
+                        std::time::UNIX_EPOCH + std::time::Duration::from_secs_f64(secs)
+                    } else {
+                        std::time::UNIX_EPOCH - std::time::Duration::from_secs_f64(-secs)
+                    }
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: Double) -> Double {
+    some_function(arg: Date(timeIntervalSince1970: arg)).timeIntervalSince1970
+}
+"#,
+            r#"
+import Foundation
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_swift_fn_system_time_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that a Rust function can take and return an `Option<SystemTime>`.
+mod extern_rust_fn_option_system_time_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Option<SystemTime>) -> Option<SystemTime>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::option::OptionF64
+            ) -> swift_bridge::option::OptionF64 {
+                if let Some(val) = super::some_function({
+                    let val = arg;
+                    if val.is_some {
+                        Some({
+                            let secs: f64 = val.val;
+                            let secs = if secs.is_nan() {
+                                0.
+                            } else {
+                                secs.clamp(-62135769600., 64092211200.)
+                            };
+                            if secs >= 0. {
+                                std::time::UNIX_EPOCH + std::time::Duration::from_secs_f64(secs)
+                            } else {
+                                std::time::UNIX_EPOCH - std::time::Duration::from_secs_f64(-secs)
+                            }
+                        })
+                    } else {
+                        None
+                    }
+                }) {
+                    swift_bridge::option::OptionF64 {
+                        val: match val.duration_since(std::time::UNIX_EPOCH) {
+                            Ok(after_epoch) => after_epoch.as_secs_f64(),
+                            Err(before_epoch) => -before_epoch.duration().as_secs_f64(),
+                        },
+                        is_some: true
+                    }
+                } else {
+                    swift_bridge::option::OptionF64 { val: 123.4, is_some: false }
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: Optional<Date>) -> Optional<Date> {
+    { let val = __swift_bridge__$some_function({ let val = arg; return __private__OptionF64(val: val?.timeIntervalSince1970 ?? 123.4, is_some: val != nil) }()); if val.is_some { return Date(timeIntervalSince1970: val.val) } else { return nil } }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__OptionF64 __swift_bridge__$some_function(struct __private__OptionF64 arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_option_system_time_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a bridge module's own `type SystemTime` is treated as an opaque type instead of as
+/// a `std::time::SystemTime`.
+mod declared_system_time_type_is_opaque {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SystemTime;
+                    fn some_function(arg: SystemTime);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(arg: *mut super::SystemTime) {
+                super::some_function(unsafe { *Box::from_raw(arg) })
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::DoesNotContainAfterTrim("import Foundation")
+    }
+
+    #[test]
+    fn declared_system_time_type_is_opaque() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
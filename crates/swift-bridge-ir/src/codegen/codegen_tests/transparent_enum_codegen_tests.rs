@@ -118,6 +118,77 @@ typedef struct __swift_bridge__$Option$SomeEnum { bool is_some; __swift_bridge__
     }
 }
 
+/// Verify that we generate a Swift enum with raw values for an enum that has explicit
+/// discriminants.
+mod generates_enum_with_explicit_discriminants {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                enum SomeEnum {
+                    Variant1 = 5,
+                    Variant2,
+                    Variant3 = -1,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[derive(Copy, Clone)]
+                pub enum SomeEnum {
+                    Variant1 = 5,
+                    Variant2,
+                    Variant3 = -1
+                }
+            },
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub enum __swift_bridge__SomeEnum {
+                    Variant1,
+                    Variant2,
+                    Variant3
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public enum SomeEnum: Int {
+    case Variant1 = 5
+    case Variant2
+    case Variant3 = -1
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef enum __swift_bridge__$SomeEnumTag { __swift_bridge__$SomeEnum$Variant1, __swift_bridge__$SomeEnum$Variant2, __swift_bridge__$SomeEnum$Variant3, } __swift_bridge__$SomeEnumTag;
+"#,
+        )
+    }
+
+    #[test]
+    fn generates_enum_with_explicit_discriminants() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
 /// Verify that we generate the correct code for a function that has an enum as an argument and
 /// returns an enum.
 mod using_enum_in_extern_rust_fn {
@@ -847,3 +918,168 @@ typedef struct __swift_bridge__$Option$SomeEnum { bool is_some; __swift_bridge__
         .test();
     }
 }
+
+/// Verify that we can use Option<String> as the data of an enum variant.
+mod generates_enum_with_option_string_data {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                enum SomeEnum {
+                    A(Option<String>),
+                    B { name: Option<String> },
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub enum __swift_bridge__SomeEnum {
+                    A(*mut swift_bridge::string::RustString),
+                    B { name: *mut swift_bridge::string::RustString }
+                }
+            },
+            quote! {
+                SomeEnum::B { name } => __swift_bridge__SomeEnum::B {
+                    name: if let Some(val) = name {
+                        swift_bridge::string::RustString(val).box_into_raw()
+                    } else {
+                        std::ptr::null::<swift_bridge::string::RustString>() as *mut swift_bridge::string::RustString
+                    }
+                }
+            },
+            quote! {
+                __swift_bridge__SomeEnum::B { name } => SomeEnum::B {
+                    name: {
+                        let val = name;
+                        if val.is_null() {
+                            None
+                        } else {
+                            Some(unsafe { Box::from_raw(val).0 })
+                        }
+                    }
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public enum SomeEnum {
+    case A(Optional<RustString>)
+    case B(name: Optional<RustString>)
+}
+"#,
+            r#"
+            case SomeEnum.B(let name):
+                return __swift_bridge__$SomeEnum(tag: __swift_bridge__$SomeEnum$B, payload: __swift_bridge__$SomeEnumFields(B: __swift_bridge__$SomeEnum$FieldOfB(name: { if let rustString = optionalStringIntoRustString(name) { rustString.isOwned = false; return rustString.ptr } else { return nil } }())))
+"#,
+            r#"
+            case __swift_bridge__$SomeEnum$B:
+                return SomeEnum.B(name: { let val = self.payload.B.name; if val != nil { return RustString(ptr: val!) } else { return nil } }())
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$SomeEnum$FieldOfB {void* name;} __swift_bridge__$SomeEnum$FieldOfB;
+"#,
+        )
+    }
+
+    #[test]
+    fn generates_enum_with_option_string_data() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that deriving serde's `Serialize` and `Deserialize` on a transparent enum makes the
+/// Swift enum `Codable`, using the variant names as the serialized values.
+mod generates_enum_with_serde_derives {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[derive(serde::Serialize, serde::Deserialize)]
+                enum SomeEnum {
+                    Variant1,
+                    Variant2,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[derive(Copy, Clone, ::serde::Serialize, ::serde::Deserialize)]
+            pub enum SomeEnum {
+                Variant1,
+                Variant2
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeEnum: Encodable {
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        switch self {
+            case .Variant1:
+                try container.encode("Variant1")
+            case .Variant2:
+                try container.encode("Variant2")
+        }
+    }
+}
+extension SomeEnum: Decodable {
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        let variant = try container.decode(String.self)
+        switch variant {
+            case "Variant1":
+                self = .Variant1
+            case "Variant2":
+                self = .Variant2
+            default:
+                throw DecodingError.dataCorruptedError(in: container, debugDescription: "Unknown SomeEnum variant \(variant)")
+        }
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn generates_enum_with_serde_derives() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
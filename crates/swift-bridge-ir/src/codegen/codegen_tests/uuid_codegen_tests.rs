@@ -0,0 +1,223 @@
+//! Tests for bridging `uuid::Uuid`.
+//!
+//! A `Uuid` is a Foundation `UUID` on the Swift side. It is passed over FFI as its 16 bytes.
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that a Rust function can take and return a `Uuid`.
+mod extern_rust_fn_uuid_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Uuid) -> uuid::Uuid;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::uuid_support::FfiUuid
+            ) -> swift_bridge::uuid_support::FfiUuid {
+                swift_bridge::uuid_support::FfiUuid::from(super::some_function(
+                    swift_bridge::uuid_support::FfiUuid::into_uuid(arg)
+                ))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function(_ arg: UUID) -> UUID {
+    __swift_bridge__$some_function(arg.intoFfiRepr()).intoSwiftRepr()
+}
+"#,
+            r#"
+import Foundation
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__FfiUuid __swift_bridge__$some_function(struct __private__FfiUuid arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_uuid_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a Swift function can take and return a `Uuid`.
+mod extern_swift_fn_uuid_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function(arg: Uuid) -> Uuid;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub fn some_function(arg: uuid::Uuid) -> uuid::Uuid {
+                swift_bridge::uuid_support::FfiUuid::into_uuid(unsafe {
+                    __swift_bridge__some_function(swift_bridge::uuid_support::FfiUuid::from(arg))
+                })
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: __private__FfiUuid) -> __private__FfiUuid {
+    some_function(arg: arg.intoSwiftRepr()).intoFfiRepr()
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_uuid_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that a Rust function can take and return an `Option<Uuid>`.
+mod extern_rust_fn_option_uuid_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Option<Uuid>) -> Option<Uuid>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::uuid_support::OptionFfiUuid
+            ) -> swift_bridge::uuid_support::OptionFfiUuid {
+                if let Some(val) = super::some_function({
+                    let val = arg;
+                    if val.is_some {
+                        Some(val.val.into_uuid())
+                    } else {
+                        None
+                    }
+                }) {
+                    swift_bridge::uuid_support::OptionFfiUuid {
+                        val: swift_bridge::uuid_support::FfiUuid::from(val),
+                        is_some: true
+                    }
+                } else {
+                    swift_bridge::uuid_support::OptionFfiUuid {
+                        val: swift_bridge::uuid_support::FfiUuid { bytes: [0; 16] },
+                        is_some: false
+                    }
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: Optional<UUID>) -> Optional<UUID> {
+    __swift_bridge__$some_function(arg.intoFfiRepr()).intoSwiftRepr()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__OptionFfiUuid __swift_bridge__$some_function(struct __private__OptionFfiUuid arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_option_uuid_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a bridge module's own `type Uuid` is treated as an opaque type instead of as a
+/// `uuid::Uuid`.
+mod declared_uuid_type_is_opaque {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type Uuid;
+                    fn some_function(arg: Uuid);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(arg: *mut super::Uuid) {
+                super::some_function(unsafe { *Box::from_raw(arg) })
+            }
+        })
+    }
+
+    #[test]
+    fn declared_uuid_type_is_opaque() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: ExpectedSwiftCode::SkipTest,
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
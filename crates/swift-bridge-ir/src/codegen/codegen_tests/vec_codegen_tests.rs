@@ -430,6 +430,184 @@ void* __swift_bridge__$Vec_SomeEnum$as_ptr(void* vec_ptr);
     }
 }
 
+/// Verify that we emit Rust, Swift and C header code that allows a `Copy` opaque Rust type be
+/// used within a Vec<T>.
+mod opaque_copy_type_vec_support {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Copy(4))]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            const _: () = {
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_SomeType$new"]
+                pub extern "C" fn _new() -> *mut Vec<super::SomeType> {
+                    Box::into_raw(Box::new(Vec::new()))
+                }
+
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_SomeType$drop"]
+                pub extern "C" fn _drop(vec: *mut Vec<super::SomeType>) {
+                    let vec = unsafe { Box::from_raw(vec) };
+                    drop(vec)
+                }
+
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_SomeType$len"]
+                pub extern "C" fn _len(vec: *const Vec<super::SomeType>) -> usize {
+                    unsafe { &*vec }.len()
+                }
+
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_SomeType$get"]
+                pub extern "C" fn _get(vec: *const Vec<super::SomeType>, index: usize) -> __swift_bridge__Option_SomeType {
+                    let vec = unsafe { &*vec };
+                    _option_into_ffi_repr(vec.get(index).map(|v| *v))
+                }
+
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_SomeType$get_mut"]
+                pub extern "C" fn _get_mut(vec: *mut Vec<super::SomeType>, index: usize) -> *mut super::SomeType {
+                    let vec = unsafe { &mut *vec };
+                    if let Some(val) = vec.get_mut(index) {
+                        val as *mut super::SomeType
+                    } else {
+                        std::ptr::null_mut()
+                    }
+                }
+
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_SomeType$push"]
+                pub extern "C" fn _push(vec: *mut Vec<super::SomeType>, val: __swift_bridge__SomeType) {
+                    unsafe { &mut *vec }.push(val.into_rust_repr())
+                }
+
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_SomeType$pop"]
+                pub extern "C" fn _pop(vec: *mut Vec<super::SomeType>) -> __swift_bridge__Option_SomeType {
+                    let vec = unsafe { &mut *vec };
+                    _option_into_ffi_repr(vec.pop())
+                }
+
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$Vec_SomeType$as_ptr"]
+                pub extern "C" fn _as_ptr(vec: *const Vec<super::SomeType>) -> *const super::SomeType {
+                    unsafe { & *vec }.as_ptr()
+                }
+
+                #[inline(always)]
+                fn _option_into_ffi_repr(val: Option<super::SomeType>) -> __swift_bridge__Option_SomeType {
+                    if let Some(val) = val {
+                        __swift_bridge__Option_SomeType {
+                            is_some: true,
+                            val: std::mem::MaybeUninit::new(__swift_bridge__SomeType::from_rust_repr(val))
+                        }
+                    } else {
+                        __swift_bridge__Option_SomeType {
+                            is_some: false,
+                            val: std::mem::MaybeUninit::uninit()
+                        }
+                    }
+                }
+            };
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeType: Vectorizable {
+    public static func vecOfSelfNew() -> UnsafeMutableRawPointer {
+        __swift_bridge__$Vec_SomeType$new()
+    }
+
+    public static func vecOfSelfFree(vecPtr: UnsafeMutableRawPointer) {
+        __swift_bridge__$Vec_SomeType$drop(vecPtr)
+    }
+
+    public static func vecOfSelfPush(vecPtr: UnsafeMutableRawPointer, value: SomeType) {
+        __swift_bridge__$Vec_SomeType$push(vecPtr, value.intoFfiRepr())
+    }
+
+    public static func vecOfSelfPop(vecPtr: UnsafeMutableRawPointer) -> Optional<Self> {
+        let val = __swift_bridge__$Vec_SomeType$pop(vecPtr)
+        if val.is_some {
+            return SomeType(bytes: val.val)
+        } else {
+            return nil
+        }
+    }
+
+    public static func vecOfSelfGet(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<Self> {
+        let val = __swift_bridge__$Vec_SomeType$get(vecPtr, index)
+        if val.is_some {
+            return SomeType(bytes: val.val)
+        } else {
+            return nil
+        }
+    }
+
+    public static func vecOfSelfGetMut(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<UnsafeMutablePointer<Self>> {
+        let pointer = __swift_bridge__$Vec_SomeType$get_mut(vecPtr, index)
+        if pointer == nil {
+            return nil
+        } else {
+            return UnsafeMutablePointer<Self>(OpaquePointer(pointer!))
+        }
+    }
+
+    public static func vecOfSelfAsPtr(vecPtr: UnsafeMutableRawPointer) -> UnsafePointer<Self> {
+        UnsafePointer<Self>(OpaquePointer(__swift_bridge__$Vec_SomeType$as_ptr(vecPtr)))
+    }
+
+    public static func vecOfSelfLen(vecPtr: UnsafeMutableRawPointer) -> UInt {
+        __swift_bridge__$Vec_SomeType$len(vecPtr)
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$SomeType { uint8_t bytes[4]; } __swift_bridge__$SomeType;
+typedef struct __swift_bridge__$Option$SomeType { bool is_some; __swift_bridge__$SomeType val; } __swift_bridge__$Option$SomeType;
+
+void* __swift_bridge__$Vec_SomeType$new(void);
+void __swift_bridge__$Vec_SomeType$drop(void* vec_ptr);
+void __swift_bridge__$Vec_SomeType$push(void* vec_ptr, __swift_bridge__$SomeType item);
+__swift_bridge__$Option$SomeType __swift_bridge__$Vec_SomeType$pop(void* vec_ptr);
+__swift_bridge__$Option$SomeType __swift_bridge__$Vec_SomeType$get(void* vec_ptr, uintptr_t index);
+void* __swift_bridge__$Vec_SomeType$get_mut(void* vec_ptr, uintptr_t index);
+uintptr_t __swift_bridge__$Vec_SomeType$len(void* vec_ptr);
+void* __swift_bridge__$Vec_SomeType$as_ptr(void* vec_ptr);
+"#,
+        )
+    }
+
+    #[test]
+    fn opaque_copy_type_vec_support() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
 /// Test code generation for Rust function that returns a Vec<T> where T is a transparent enum.
 mod extern_rust_fn_return_vec_of_transparent_enum {
     use super::*;
@@ -661,3 +839,192 @@ func __swift_bridge__some_function (_ arg: UnsafeMutableRawPointer) {
         .test();
     }
 }
+
+/// Test code generation for Swift function that returns a Vec<T> where T is an opaque Rust type.
+mod extern_swift_fn_return_vec_of_opaque_rust_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type MyRustType;
+                }
+
+                extern "Swift" {
+                    fn some_function() -> Vec<MyRustType>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function() -> Vec<super::MyRustType> {
+                    unsafe { *Box::from_raw(unsafe { __swift_bridge__some_function() }) }
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function() -> *mut Vec<super::MyRustType>;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function () -> UnsafeMutableRawPointer {
+    { let val = some_function(); val.isOwned = false; return val.ptr }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+typedef struct MyRustType MyRustType;
+void __swift_bridge__$MyRustType$_free(void* self);
+
+void* __swift_bridge__$Vec_MyRustType$new(void);
+void __swift_bridge__$Vec_MyRustType$drop(void* vec_ptr);
+void __swift_bridge__$Vec_MyRustType$push(void* vec_ptr, void* item_ptr);
+void* __swift_bridge__$Vec_MyRustType$pop(void* vec_ptr);
+void* __swift_bridge__$Vec_MyRustType$get(void* vec_ptr, uintptr_t index);
+void* __swift_bridge__$Vec_MyRustType$get_mut(void* vec_ptr, uintptr_t index);
+uintptr_t __swift_bridge__$Vec_MyRustType$len(void* vec_ptr);
+void* __swift_bridge__$Vec_MyRustType$as_ptr(void* vec_ptr);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_return_vec_of_opaque_rust_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Swift function that has an argument
+/// Vec<T> where T is an opaque Rust type.
+mod extern_swift_fn_arg_vec_of_opaque_rust_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type MyRustType;
+                }
+
+                extern "Swift" {
+                    fn some_function(arg: Vec<MyRustType>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: Vec<super::MyRustType>) {
+                    unsafe { __swift_bridge__some_function(Box::into_raw(Box::new(arg))) }
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(arg: *mut Vec<super::MyRustType>);
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: UnsafeMutableRawPointer) {
+    some_function(arg: RustVec(ptr: arg))
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn extern_swift_fn_arg_vec_of_opaque_rust_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that accepts and returns a Vec<Vec<T>> where T is a
+/// primitive Rust type.
+mod extern_rust_fn_vec_of_vec_of_primitive_rust_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Vec<Vec<u8>>) -> Vec<Vec<u8>>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: *mut Vec<Vec<u8>>
+            ) -> *mut Vec<Vec<u8>> {
+                Box::into_raw(Box::new(super::some_function(unsafe { * Box::from_raw(arg) })))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: RustVec<RustVec<UInt8>>) -> RustVec<RustVec<UInt8>> {
+    RustVec(ptr: __swift_bridge__$some_function({ let val = arg; val.isOwned = false; return val.ptr }()))
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$some_function(void* arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_vec_of_vec_of_primitive_rust_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -1,19 +1,23 @@
 //! Tests can be found in src/codegen/codegen_tests.rs and its submodules.
 
+use crate::bridged_type::bridgeable_extern_c_fn::c_declaration;
 use crate::bridged_type::shared_struct::StructField;
-use crate::bridged_type::{BridgeableType, BridgedType, CFfiStruct, StdLibType, StructFields};
-use crate::codegen::CodegenConfig;
+use crate::bridged_type::{
+    pat_type_pat_is_self, BridgeableType, BridgedType, CFfiStruct, StdLibType, StructFields,
+};
+use crate::codegen::{CodegenConfig, SharedDeclarations};
+use crate::item_cfg::cfgs_are_enabled;
 use crate::parse::{SharedTypeDeclaration, TypeDeclaration, TypeDeclarations};
 use crate::parsed_extern_fn::ParsedExternFn;
 use crate::{SwiftBridgeModule, SWIFT_BRIDGE_PREFIX};
 use std::collections::{BTreeSet, HashSet};
-use syn::{FnArg, ReturnType};
+use syn::{FnArg, GenericArgument, PathArguments, ReturnType, Type};
 
 const NOTICE: &'static str = "// File automatically generated by swift-bridge.";
 
 struct Bookkeeping {
     includes: BTreeSet<&'static str>,
-    slice_types: HashSet<String>,
+    slice_types: BTreeSet<String>,
 }
 
 /// Used to manage the structures declaration order in a C header file. In the C header file, it is necessary to declare fields of a structure before declaring the structure itself.
@@ -40,6 +44,23 @@ impl SwiftBridgeModule {
     }
 
     pub(crate) fn generate_c_header_inner(&self, config: &CodegenConfig) -> String {
+        let mut shared = SharedDeclarations::default();
+        let declarations =
+            self.generate_c_declarations_with_shared_declarations(config, &mut shared);
+
+        c_header_with_includes(&shared.c_includes, declarations)
+    }
+
+    /// Generate the module's C declarations, skipping any of the custom C types that another
+    /// module already declared.
+    ///
+    /// The headers that the declarations need are added to the shared declarations' includes
+    /// instead of being included in the returned declarations.
+    pub(crate) fn generate_c_declarations_with_shared_declarations(
+        &self,
+        config: &CodegenConfig,
+        shared: &mut SharedDeclarations,
+    ) -> String {
         let mut header = "".to_string();
 
         if !self.module_will_be_compiled(config) {
@@ -50,10 +71,10 @@ impl SwiftBridgeModule {
             includes: BTreeSet::new(),
             // TODO: Delete this.
             //  Don't think we're using it.
-            slice_types: HashSet::new(),
+            slice_types: BTreeSet::new(),
         };
 
-        for ty in self.types.types() {
+        for ty in types_in_c_declaration_order(&self.types) {
             match ty {
                 TypeDeclaration::Shared(ty) => match ty {
                     SharedTypeDeclaration::Struct(ty_struct) => {
@@ -91,7 +112,7 @@ impl SwiftBridgeModule {
 
                                         let name = field.swift_name_string();
 
-                                        fields.push(format!("{} {}", ty.to_c(&self.types), name));
+                                        fields.push(c_declaration(&ty.to_c(&self.types), &name));
                                     }
                                 }
                                 StructFields::Unnamed(types) => {
@@ -106,7 +127,7 @@ impl SwiftBridgeModule {
 
                                         let name = format!("_{}", idx);
 
-                                        fields.push(format!("{} {}", ty.to_c(&self.types), name));
+                                        fields.push(c_declaration(&ty.to_c(&self.types), &name));
                                     }
                                 }
                                 StructFields::Unit => {
@@ -207,7 +228,10 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
                                             }
                                             let ty = ty.to_c(&self.types);
                                             let field_name = named_field.name.to_string();
-                                            params.push(format!("{} {};", ty, field_name));
+                                            params.push(format!(
+                                                "{};",
+                                                c_declaration(&ty, &field_name)
+                                            ));
                                         }
                                         let params = params.join(" ");
                                         let variant_field = format!("typedef struct {ffi_name}$FieldOf{variant_name} {{{params}}} {ffi_name}$FieldOf{variant_name};", ffi_name = ffi_name, variant_name = variant.name, params = params);
@@ -258,6 +282,7 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
                         }
                     }
                 },
+                TypeDeclaration::Opaque(ty) if !cfgs_are_enabled(&ty.attributes.cfgs, config) => {}
                 TypeDeclaration::Opaque(ty) => {
                     if ty.host_lang.is_swift() {
                         continue;
@@ -270,13 +295,14 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
                     if ty.attributes.declare_generic {
                         continue;
                     }
-                    if ty.attributes.hashable {
+                    // Copy types declare these after their FFI repr, since it is passed by value.
+                    if ty.attributes.hashable && ty.attributes.copy.is_none() {
                         let ty_name = ty.ty_name_ident();
                         let hash_ty =
                             format!("uint64_t __swift_bridge__${}$_hash(void* self);", ty_name);
                         header += &hash_ty;
                     }
-                    if ty.attributes.equatable {
+                    if ty.attributes.equatable && ty.attributes.copy.is_none() {
                         let ty_name = ty.ty_name_ident();
                         let equal_ty = format!(
                             "bool __swift_bridge__${}$_partial_eq(void* lhs, void* rhs);",
@@ -287,6 +313,49 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
                         header += &equal_ty;
                         header += "\n";
                     }
+                    if ty.attributes.comparable && ty.attributes.copy.is_none() {
+                        let less_than_ty = format!(
+                            "bool __swift_bridge__${}$_less_than(void* lhs, void* rhs);",
+                            ty.ty_name_ident()
+                        );
+                        bookkeeping.includes.insert("stdbool.h");
+                        header += &less_than_ty;
+                        header += "\n";
+                    }
+                    if ty.attributes.copy.is_none() {
+                        for operator in ty.attributes.operators.iter() {
+                            let params = if operator.is_unary() {
+                                "void* operand"
+                            } else {
+                                "void* lhs, void* rhs"
+                            };
+                            let operator_ty = format!(
+                                "void* __swift_bridge__${}$_{}({});",
+                                ty.ty_name_ident(),
+                                operator.method_name(),
+                                params
+                            );
+                            header += &operator_ty;
+                            header += "\n";
+                        }
+                    }
+                    if ty.attributes.custom_string_convertible && ty.attributes.copy.is_none() {
+                        let description_ty = format!(
+                            "void* __swift_bridge__${}$_description(void* self);",
+                            ty.ty_name_ident()
+                        );
+                        header += &description_ty;
+                        header += "\n";
+                    }
+                    if ty.attributes.custom_debug_string_convertible && ty.attributes.copy.is_none()
+                    {
+                        let debug_description_ty = format!(
+                            "void* __swift_bridge__${}$_debug_description(void* self);",
+                            ty.ty_name_ident()
+                        );
+                        header += &debug_description_ty;
+                        header += "\n";
+                    }
                     let ty_name = ty.to_string();
 
                     if let Some(copy) = ty.attributes.copy {
@@ -310,6 +379,71 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
                         header += "\n";
                         header += &option_ty_decl;
                         header += "\n";
+
+                        if ty.attributes.equatable {
+                            let equal_ty = format!(
+                                "bool __swift_bridge__${ty_name}$_partial_eq({copy_ffi_repr} lhs, {copy_ffi_repr} rhs);",
+                                ty_name = ty.ty_name_ident(),
+                                copy_ffi_repr = c_ty_name,
+                            );
+                            header += &equal_ty;
+                            header += "\n";
+                        }
+                        if ty.attributes.comparable {
+                            let less_than_ty = format!(
+                                "bool __swift_bridge__${ty_name}$_less_than({copy_ffi_repr} lhs, {copy_ffi_repr} rhs);",
+                                ty_name = ty.ty_name_ident(),
+                                copy_ffi_repr = c_ty_name,
+                            );
+                            header += &less_than_ty;
+                            header += "\n";
+                        }
+                        for operator in ty.attributes.operators.iter() {
+                            let params = if operator.is_unary() {
+                                format!("{} operand", c_ty_name)
+                            } else {
+                                format!(
+                                    "{copy_ffi_repr} lhs, {copy_ffi_repr} rhs",
+                                    copy_ffi_repr = c_ty_name
+                                )
+                            };
+                            let operator_ty = format!(
+                                "{copy_ffi_repr} __swift_bridge__${ty_name}$_{method}({params});",
+                                ty_name = ty.ty_name_ident(),
+                                copy_ffi_repr = c_ty_name,
+                                method = operator.method_name(),
+                                params = params,
+                            );
+                            header += &operator_ty;
+                            header += "\n";
+                        }
+                        if ty.attributes.hashable {
+                            let hash_ty = format!(
+                                "uint64_t __swift_bridge__${ty_name}$_hash({copy_ffi_repr} self);",
+                                ty_name = ty.ty_name_ident(),
+                                copy_ffi_repr = c_ty_name,
+                            );
+                            header += &hash_ty;
+                            header += "\n";
+                        }
+                        if ty.attributes.custom_string_convertible {
+                            let description_ty = format!(
+                                "void* __swift_bridge__${ty_name}$_description({copy_ffi_repr} self);",
+                                ty_name = ty.ty_name_ident(),
+                                copy_ffi_repr = c_ty_name,
+                            );
+                            header += &description_ty;
+                            header += "\n";
+                        }
+                        if ty.attributes.custom_debug_string_convertible {
+                            let debug_description_ty = format!(
+                                "void* __swift_bridge__${ty_name}$_debug_description({copy_ffi_repr} self);",
+                                ty_name = ty.ty_name_ident(),
+                                copy_ffi_repr = c_ty_name,
+                            );
+                            header += &debug_description_ty;
+                            header += "\n";
+                        }
                     } else {
                         let ty_decl =
                             format!("typedef struct {ty_name} {ty_name};", ty_name = ty_name);
@@ -327,11 +461,13 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
                         header += "\n";
                     }
 
-                    // TODO: Support Vec<OpaqueCopyType>. Add codegen tests and then
-                    //  make them pass.
                     // TODO: Support Vec<GenericOpaqueRustType
-                    if ty.attributes.copy.is_none() && ty.generics.len() == 0 {
-                        let vec_functions = vec_opaque_rust_type_c_support(&ty_name);
+                    if ty.generics.len() == 0 {
+                        let vec_functions = if ty.attributes.copy.is_some() {
+                            vec_opaque_copy_type_c_support(&ty_name)
+                        } else {
+                            vec_opaque_rust_type_c_support(&ty_name)
+                        };
 
                         header += &vec_functions;
                         header += "\n";
@@ -340,15 +476,19 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
             }
         }
         let mut c_ffi_struct_bookkeeping = CFfiStructDeclarationBookkeeping {
-            encountered_custom_type_declarations: HashSet::new(),
+            encountered_custom_type_declarations: std::mem::take(&mut shared.c_types),
             custom_type_declarations: Vec::new(),
         };
 
         for func in self.functions.iter() {
+            if !cfgs_are_enabled(&func.cfgs, config) {
+                continue;
+            }
+
             declare_custom_c_ffi_types(func, &self.types, &mut c_ffi_struct_bookkeeping);
             if func.host_lang.is_swift() {
                 for (idx, boxed_fn) in func.args_filtered_to_boxed_fns(&self.types) {
-                    if boxed_fn.params.is_empty() && boxed_fn.ret.is_null() {
+                    if boxed_fn.uses_no_args_no_return_support() {
                         continue;
                     }
 
@@ -362,32 +502,76 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
             header += &declare_func(&func, &mut bookkeeping, &self.types);
         }
 
-        for slice_ty in bookkeeping.slice_types.iter() {
-            header = format!(
-                r#"typedef struct FfiSlice_{slice_ty} {{ {slice_ty}* start; uintptr_t len; }} FfiSlice_{slice_ty};
-{header}"#,
-                slice_ty = slice_ty,
-                header = header
-            )
+        for constant in self.constants.iter() {
+            if constant.swift_literal.is_some() {
+                continue;
+            }
+
+            let ty = BridgedType::new_with_type(&constant.ty, &self.types).unwrap();
+            if let Some(includes) = ty.to_c_include(&self.types) {
+                for include in includes {
+                    bookkeeping.includes.insert(include);
+                }
+            }
+
+            header += &format!(
+                "{} {}(void);\n",
+                ty.to_c(&self.types),
+                constant.accessor_link_name()
+            );
         }
 
-        let mut includes = bookkeeping.includes.iter().collect::<Vec<_>>();
-        includes.sort();
-        for include in includes {
-            header = format!(
-                r#"#include <{}>
-{}"#,
-                include, header
+        for slice_ty in bookkeeping.slice_types.iter() {
+            let slice_typedef = format!(
+                r#"typedef struct FfiSlice_{slice_ty} {{ {slice_ty}* start; uintptr_t len; }} FfiSlice_{slice_ty};"#,
+                slice_ty = slice_ty,
             );
+            if !c_ffi_struct_bookkeeping
+                .encountered_custom_type_declarations
+                .insert(slice_typedef.clone())
+            {
+                continue;
+            }
+
+            header = format!("{}\n{}", slice_typedef, header);
         }
+
+        shared.c_includes.extend(bookkeeping.includes);
+        shared.c_types = c_ffi_struct_bookkeeping.encountered_custom_type_declarations;
+
         for custom_type_declaration in c_ffi_struct_bookkeeping.custom_type_declarations {
             header += &custom_type_declaration;
             header += "\n";
         }
-        header
+
+        self.prefix_symbols(&header)
     }
 }
 
+/// The contents of a C header file holding the given declarations, which might come from several
+/// bridge modules.
+pub(crate) fn c_header_file(includes: &BTreeSet<&'static str>, declarations: String) -> String {
+    format!(
+        r#"{notice}
+{header}"#,
+        notice = NOTICE,
+        header = c_header_with_includes(includes, declarations)
+    )
+}
+
+/// Put the `#include` of each of the given headers before the declarations.
+fn c_header_with_includes(includes: &BTreeSet<&'static str>, declarations: String) -> String {
+    let mut header = declarations;
+    for include in includes {
+        header = format!(
+            r#"#include <{}>
+{}"#,
+            include, header
+        );
+    }
+    header
+}
+
 fn vec_opaque_rust_type_c_support(ty_name: &str) -> String {
     format!(
         r#"
@@ -404,6 +588,24 @@ void* __swift_bridge__$Vec_{ty_name}$as_ptr(void* vec_ptr);
     )
 }
 
+/// `Copy` opaque types are copied out of the `Vec` by `get` and `pop`, while `get_mut` returns a
+/// pointer to the element so that Swift can write through it.
+fn vec_opaque_copy_type_c_support(ty_name: &str) -> String {
+    format!(
+        r#"
+void* __swift_bridge__$Vec_{ty_name}$new(void);
+void __swift_bridge__$Vec_{ty_name}$drop(void* vec_ptr);
+void __swift_bridge__$Vec_{ty_name}$push(void* vec_ptr, __swift_bridge__${ty_name} item);
+__swift_bridge__$Option${ty_name} __swift_bridge__$Vec_{ty_name}$pop(void* vec_ptr);
+__swift_bridge__$Option${ty_name} __swift_bridge__$Vec_{ty_name}$get(void* vec_ptr, uintptr_t index);
+void* __swift_bridge__$Vec_{ty_name}$get_mut(void* vec_ptr, uintptr_t index);
+uintptr_t __swift_bridge__$Vec_{ty_name}$len(void* vec_ptr);
+void* __swift_bridge__$Vec_{ty_name}$as_ptr(void* vec_ptr);
+"#,
+        ty_name = ty_name
+    )
+}
+
 fn vec_transparent_enum_c_support(enum_name: &str) -> String {
     format!(
         r#"
@@ -456,6 +658,13 @@ fn declare_custom_c_ffi_types(
     for arg in &func.sig.inputs {
         match arg {
             FnArg::Receiver(_receiver) => {}
+            FnArg::Typed(pat_ty) if pat_type_pat_is_self(pat_ty) => {
+                if let Some(ty) = func.arc_self_bridged_type(types) {
+                    if let Some(declaration) = ty.generate_custom_c_ffi_types(types) {
+                        push_custom_type_declarations(&declaration, c_ffi_struct_bookkeeping);
+                    }
+                }
+            }
             FnArg::Typed(pat_ty) => {
                 let ty = BridgedType::new_with_type(&pat_ty.ty, types).unwrap();
                 if let Some(declaration) = ty.generate_custom_c_ffi_types(types) {
@@ -466,6 +675,92 @@ fn declare_custom_c_ffi_types(
     }
 }
 
+/// Get the module's type declarations, ordered such that every shared struct or enum comes after
+/// any of the shared types that are used in its fields.
+///
+/// C does not allow a struct to have a field whose type has not been declared yet, so a
+/// `struct Line { start: Point }` needs `Point` to be declared first even if the bridge module
+/// declares `Line` before `Point`.
+fn types_in_c_declaration_order(types: &TypeDeclarations) -> Vec<&TypeDeclaration> {
+    let mut ordered = vec![];
+    let mut visited = HashSet::new();
+
+    for ty in types.types() {
+        push_type_after_field_types(ty, types, &mut visited, &mut ordered);
+    }
+
+    ordered
+}
+
+fn push_type_after_field_types<'a>(
+    ty: &'a TypeDeclaration,
+    types: &'a TypeDeclarations,
+    visited: &mut HashSet<*const TypeDeclaration>,
+    ordered: &mut Vec<&'a TypeDeclaration>,
+) {
+    if !visited.insert(ty as *const TypeDeclaration) {
+        return;
+    }
+
+    let fields = match ty {
+        TypeDeclaration::Shared(SharedTypeDeclaration::Struct(ty_struct)) => {
+            ty_struct.fields.normalized_fields()
+        }
+        TypeDeclaration::Shared(SharedTypeDeclaration::Enum(ty_enum)) => ty_enum
+            .variants
+            .iter()
+            .flat_map(|variant| variant.fields.normalized_fields())
+            .collect(),
+        TypeDeclaration::Opaque(_) => vec![],
+    };
+
+    for field in fields {
+        let mut field_types = vec![];
+        shared_types_within_type(&field.ty, types, &mut field_types);
+
+        for field_ty in field_types {
+            push_type_after_field_types(field_ty, types, visited, ordered);
+        }
+    }
+
+    ordered.push(ty);
+}
+
+/// Find all of the shared types that are used within a type, such as `SomeStruct` within
+/// `Option<SomeStruct>`.
+fn shared_types_within_type<'a>(
+    ty: &Type,
+    types: &'a TypeDeclarations,
+    found: &mut Vec<&'a TypeDeclaration>,
+) {
+    match ty {
+        Type::Path(ty_path) => {
+            for segment in ty_path.path.segments.iter() {
+                if let Some(decl @ TypeDeclaration::Shared(_)) =
+                    types.get(&segment.ident.to_string())
+                {
+                    found.push(decl);
+                }
+
+                if let PathArguments::AngleBracketed(args) = &segment.arguments {
+                    for arg in args.args.iter() {
+                        if let GenericArgument::Type(ty) = arg {
+                            shared_types_within_type(ty, types, found);
+                        }
+                    }
+                }
+            }
+        }
+        Type::Tuple(tuple) => {
+            for ty in tuple.elems.iter() {
+                shared_types_within_type(ty, types, found);
+            }
+        }
+        Type::Reference(reference) => shared_types_within_type(&reference.elem, types, found),
+        _ => {}
+    }
+}
+
 fn declare_func(
     func: &ParsedExternFn,
     bookkeeping: &mut Bookkeeping,
@@ -494,7 +789,7 @@ fn declare_func(
         let maybe_ret = if maybe_ret.is_null() {
             "".to_string()
         } else {
-            format!(", {} ret", maybe_ret.to_c(types))
+            format!(", {}", c_declaration(&maybe_ret.to_c(types), "ret"))
         };
 
         let maybe_params = if func.sig.inputs.is_empty() {
@@ -510,10 +805,11 @@ fn declare_func(
         )
     } else {
         format!(
-            "{ret} {name}({params});\n",
-            ret = ret,
-            name = name,
-            params = params
+            "{};\n",
+            c_declaration(
+                &ret,
+                &format!("{name}({params})", name = name, params = params)
+            )
         )
     };
 
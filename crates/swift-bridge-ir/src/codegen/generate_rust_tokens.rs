@@ -1,16 +1,19 @@
 //! More tests can be found in src/codegen/codegen_tests.rs and its submodules.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use proc_macro2::TokenStream;
 use quote::ToTokens;
 use quote::{quote, quote_spanned};
 
+use self::vec::vec_of_opaque_copy_type::generate_vec_of_opaque_copy_type_functions;
 use self::vec::vec_of_opaque_rust_type::generate_vec_of_opaque_rust_type_functions;
 use crate::bridge_module_attributes::CfgAttr;
-use crate::parse::{HostLang, SharedTypeDeclaration, TypeDeclaration};
+use crate::item_cfg::{add_cfgs_to_foreign_items, add_cfgs_to_items, cfg_attributes};
+use crate::parse::{swift_protocol_handle_ident, HostLang, SharedTypeDeclaration, TypeDeclaration};
 use crate::SwiftBridgeModule;
 
+mod constant;
 mod shared_enum;
 mod shared_struct;
 mod vec;
@@ -26,13 +29,21 @@ impl ToTokens for SwiftBridgeModule {
 
         let mut shared_struct_definitions = vec![];
         let mut shared_enum_definitions = vec![];
-        let mut custom_type_definitions: HashMap<String, TokenStream> = HashMap::new();
+        let mut custom_type_definitions: BTreeMap<String, TokenStream> = BTreeMap::new();
         let mut impl_fn_tokens: HashMap<String, Vec<TokenStream>> = HashMap::new();
+        let mut trait_method_tokens: HashMap<String, Vec<(TokenStream, TokenStream)>> =
+            HashMap::new();
         let mut callbacks_support = vec![];
         let mut freestanding_rust_call_swift_fn_tokens = vec![];
         let mut extern_swift_fn_tokens = vec![];
 
         for func in &self.functions {
+            let extern_rust_fn_tokens_start = extern_rust_fn_tokens.len();
+            let extern_swift_fn_tokens_start = extern_swift_fn_tokens.len();
+            let callbacks_support_start = callbacks_support.len();
+            let freestanding_start = freestanding_rust_call_swift_fn_tokens.len();
+            let cfg_attributes = cfg_attributes(&func.cfgs);
+
             match func.host_lang {
                 HostLang::Rust => {
                     extern_rust_fn_tokens.push(func.to_extern_c_function_tokens(
@@ -40,6 +51,9 @@ impl ToTokens for SwiftBridgeModule {
                         &self.types,
                         &mut custom_type_definitions,
                     ));
+                    extern_swift_fn_tokens.extend(
+                        func.swift_callbacks_extern_fns(&self.swift_bridge_path, &self.types),
+                    );
                 }
                 HostLang::Swift => {
                     let tokens = func
@@ -54,11 +68,26 @@ impl ToTokens for SwiftBridgeModule {
 
                                 todo!()
                             }
+                            TypeDeclaration::Opaque(ty) if ty.attributes.swift_protocol => {
+                                let sig =
+                                    func.to_rust_fn_sig_that_calls_a_swift_extern(&self.types);
+                                let body = func.to_rust_fn_body_that_calls_a_swift_extern(
+                                    &self.swift_bridge_path,
+                                    &self.types,
+                                );
+                                trait_method_tokens
+                                    .entry(ty.to_string())
+                                    .or_default()
+                                    .push((
+                                        quote! { #cfg_attributes #sig },
+                                        quote! { #cfg_attributes #body },
+                                    ));
+                            }
                             TypeDeclaration::Opaque(ty) => {
                                 impl_fn_tokens
                                     .entry(ty.to_string())
                                     .or_default()
-                                    .push(tokens);
+                                    .push(quote! { #cfg_attributes #tokens });
                             }
                         };
                     } else {
@@ -72,6 +101,17 @@ impl ToTokens for SwiftBridgeModule {
                     ));
                 }
             };
+
+            for tokens in extern_rust_fn_tokens[extern_rust_fn_tokens_start..]
+                .iter_mut()
+                .chain(callbacks_support[callbacks_support_start..].iter_mut())
+                .chain(freestanding_rust_call_swift_fn_tokens[freestanding_start..].iter_mut())
+            {
+                *tokens = add_cfgs_to_items(&func.cfgs, std::mem::take(tokens));
+            }
+            for tokens in extern_swift_fn_tokens[extern_swift_fn_tokens_start..].iter_mut() {
+                *tokens = add_cfgs_to_foreign_items(&func.cfgs, std::mem::take(tokens));
+            }
         }
 
         for ty in &self.types.types() {
@@ -93,6 +133,10 @@ impl ToTokens for SwiftBridgeModule {
                         continue;
                     }
 
+                    let extern_rust_fn_tokens_start = extern_rust_fn_tokens.len();
+                    let structs_for_swift_classes_start = structs_for_swift_classes.len();
+                    let extern_swift_fn_tokens_start = extern_swift_fn_tokens.len();
+
                     let link_name = ty.free_rust_opaque_type_ffi_name();
                     let free_mem_func_name = ty.free_rust_opaque_type_ident();
                     let this = &ty.ty;
@@ -106,17 +150,33 @@ impl ToTokens for SwiftBridgeModule {
                                     &format!("__swift_bridge__{}__hash", ty_name),
                                     ty.ty.span(),
                                 );
-                                let tokens = quote! {
-                                #[export_name = #export_name]
-                                pub extern "C" fn #function_name (
-                                    this: *const super::#ty_name,
-                                ) -> u64 {
-                                    use std::hash::{Hash, Hasher};
-                                    use std::collections::hash_map::DefaultHasher;
-                                    let mut s = DefaultHasher::new();
-                                    (unsafe {&*this}).hash(&mut s);
-                                    s.finish()
-                                }
+                                let tokens = if ty.attributes.copy.is_some() {
+                                    let copy_ty_name = ty.ffi_copy_repr_ident();
+                                    quote! {
+                                        #[export_name = #export_name]
+                                        pub extern "C" fn #function_name (
+                                            this: #copy_ty_name,
+                                        ) -> u64 {
+                                            use std::hash::{Hash, Hasher};
+                                            use std::collections::hash_map::DefaultHasher;
+                                            let mut s = DefaultHasher::new();
+                                            this.into_rust_repr().hash(&mut s);
+                                            s.finish()
+                                        }
+                                    }
+                                } else {
+                                    quote! {
+                                    #[export_name = #export_name]
+                                    pub extern "C" fn #function_name (
+                                        this: *const super::#ty_name,
+                                    ) -> u64 {
+                                        use std::hash::{Hash, Hasher};
+                                        use std::collections::hash_map::DefaultHasher;
+                                        let mut s = DefaultHasher::new();
+                                        (unsafe {&*this}).hash(&mut s);
+                                        s.finish()
+                                    }
+                                    }
                                 };
                                 extern_rust_fn_tokens.push(tokens);
                             }
@@ -127,13 +187,127 @@ impl ToTokens for SwiftBridgeModule {
                                     &format!("__swift_bridge__{}__partial_eq", ty_name),
                                     ty.ty.span(),
                                 );
-                                let tokens = quote! {
-                                    #[export_name = #export_name]
-                                    pub extern "C" fn #function_name (
-                                        lhs: *const super::#ty_name,
-                                        rhs: *const super::#ty_name
-                                    ) -> bool {
-                                        unsafe { &*lhs == &*rhs }
+                                let tokens = if ty.attributes.copy.is_some() {
+                                    let copy_ty_name = ty.ffi_copy_repr_ident();
+                                    quote! {
+                                        #[export_name = #export_name]
+                                        pub extern "C" fn #function_name (
+                                            lhs: #copy_ty_name,
+                                            rhs: #copy_ty_name
+                                        ) -> bool {
+                                            lhs.into_rust_repr() == rhs.into_rust_repr()
+                                        }
+                                    }
+                                } else {
+                                    quote! {
+                                        #[export_name = #export_name]
+                                        pub extern "C" fn #function_name (
+                                            lhs: *const super::#ty_name,
+                                            rhs: *const super::#ty_name
+                                        ) -> bool {
+                                            unsafe { &*lhs == &*rhs }
+                                        }
+                                    }
+                                };
+                                extern_rust_fn_tokens.push(tokens);
+                            }
+                            if ty.attributes.comparable {
+                                let export_name =
+                                    format!("__swift_bridge__${}$_less_than", ty_name);
+                                let function_name = syn::Ident::new(
+                                    &format!("__swift_bridge__{}__less_than", ty_name),
+                                    ty.ty.span(),
+                                );
+                                let tokens = if ty.attributes.copy.is_some() {
+                                    let copy_ty_name = ty.ffi_copy_repr_ident();
+                                    quote! {
+                                        #[export_name = #export_name]
+                                        pub extern "C" fn #function_name (
+                                            lhs: #copy_ty_name,
+                                            rhs: #copy_ty_name
+                                        ) -> bool {
+                                            lhs.into_rust_repr() < rhs.into_rust_repr()
+                                        }
+                                    }
+                                } else {
+                                    quote! {
+                                        #[export_name = #export_name]
+                                        pub extern "C" fn #function_name (
+                                            lhs: *const super::#ty_name,
+                                            rhs: *const super::#ty_name
+                                        ) -> bool {
+                                            unsafe { &*lhs < &*rhs }
+                                        }
+                                    }
+                                };
+                                extern_rust_fn_tokens.push(tokens);
+                            }
+                            for operator in ty.attributes.operators.iter() {
+                                let export_name = format!(
+                                    "__swift_bridge__${}$_{}",
+                                    ty_name,
+                                    operator.method_name()
+                                );
+                                let function_name = syn::Ident::new(
+                                    &format!(
+                                        "__swift_bridge__{}__{}",
+                                        ty_name,
+                                        operator.method_name()
+                                    ),
+                                    ty.ty.span(),
+                                );
+                                let trait_name =
+                                    syn::Ident::new(operator.trait_name(), ty.ty.span());
+                                let method_name =
+                                    syn::Ident::new(operator.method_name(), ty.ty.span());
+
+                                let tokens = if ty.attributes.copy.is_some() {
+                                    let copy_ty_name = ty.ffi_copy_repr_ident();
+                                    let (params, args) = if operator.is_unary() {
+                                        (
+                                            quote! { operand: #copy_ty_name },
+                                            quote! { operand.into_rust_repr() },
+                                        )
+                                    } else {
+                                        (
+                                            quote! { lhs: #copy_ty_name, rhs: #copy_ty_name },
+                                            quote! { lhs.into_rust_repr(), rhs.into_rust_repr() },
+                                        )
+                                    };
+                                    quote! {
+                                        #[export_name = #export_name]
+                                        pub extern "C" fn #function_name (
+                                            #params
+                                        ) -> #copy_ty_name {
+                                            #copy_ty_name::from_rust_repr(
+                                                std::ops::#trait_name::#method_name(#args)
+                                            )
+                                        }
+                                    }
+                                } else {
+                                    let (params, args) = if operator.is_unary() {
+                                        (
+                                            quote! { operand: *const super::#ty_name },
+                                            quote! { unsafe { &*operand } },
+                                        )
+                                    } else {
+                                        (
+                                            quote! {
+                                                lhs: *const super::#ty_name,
+                                                rhs: *const super::#ty_name
+                                            },
+                                            quote! { unsafe { &*lhs }, unsafe { &*rhs } },
+                                        )
+                                    };
+                                    quote! {
+                                        #[export_name = #export_name]
+                                        pub extern "C" fn #function_name (
+                                            #params
+                                        ) -> *mut super::#ty_name {
+                                            Box::into_raw(Box::new(
+                                                std::ops::#trait_name::#method_name(#args)
+                                            ))
+                                        }
                                     }
                                 };
                                 extern_rust_fn_tokens.push(tokens);
@@ -188,6 +362,68 @@ impl ToTokens for SwiftBridgeModule {
 
                                 extern_rust_fn_tokens.push(assert_size);
                                 extern_rust_fn_tokens.push(copy_ty);
+
+                                // TODO: Support Vec<GenericOpaqueRustType
+                                if !ty.attributes.already_declared && ty.generics.is_empty() {
+                                    extern_rust_fn_tokens
+                                        .push(generate_vec_of_opaque_copy_type_functions(ty));
+                                }
+                            }
+
+                            let description_fns = [
+                                (ty.attributes.custom_string_convertible, "description", "{}"),
+                                (
+                                    ty.attributes.custom_debug_string_convertible,
+                                    "debug_description",
+                                    "{:?}",
+                                ),
+                            ];
+                            for (_, fn_suffix, format_str) in
+                                description_fns.iter().filter(|(enabled, _, _)| *enabled)
+                            {
+                                let export_name =
+                                    format!("__swift_bridge__${}$_{}", ty_name, fn_suffix);
+                                let function_name = syn::Ident::new(
+                                    &format!("__swift_bridge__{}__{}", ty_name, fn_suffix),
+                                    ty.ty.span(),
+                                );
+                                let this = if ty.attributes.copy.is_some() {
+                                    let copy_ty_name = ty.ffi_copy_repr_ident();
+                                    quote! { this: #copy_ty_name }
+                                } else {
+                                    quote! { this: *const super::#ty_name }
+                                };
+                                let value = if ty.attributes.copy.is_some() {
+                                    quote! { this.into_rust_repr() }
+                                } else {
+                                    quote! { unsafe { &*this } }
+                                };
+                                let tokens = quote! {
+                                    #[export_name = #export_name]
+                                    pub extern "C" fn #function_name (
+                                        #this
+                                    ) -> *mut #swift_bridge_path::string::RustString {
+                                        #swift_bridge_path::string::RustString(format!(#format_str, #value)).box_into_raw()
+                                    }
+                                };
+                                extern_rust_fn_tokens.push(tokens);
+                            }
+                            if ty.attributes.sendable || ty.attributes.actor {
+                                let generics = ty
+                                    .generics
+                                    .angle_bracketed_concrete_generics_tokens(&self.types);
+
+                                // Swift will share the value across concurrency domains, so the
+                                // Rust type must be safe to send and share between threads. An
+                                // actor's methods can run on any of the Swift executor's threads.
+                                let assert_send_sync = quote_spanned! {ty.ty.span()=>
+                                    const _: () = {
+                                        fn _assert_send_sync() {
+                                            #swift_bridge_path::sendable_support::assert_send_sync::<super::#ty_name #generics>();
+                                        }
+                                    };
+                                };
+                                extern_rust_fn_tokens.push(assert_send_sync);
                             }
 
                             if !ty.attributes.already_declared {
@@ -206,8 +442,6 @@ impl ToTokens for SwiftBridgeModule {
 
                                     extern_rust_fn_tokens.push(free);
 
-                                    // TODO: Support Vec<OpaqueCopyType>. Add codegen tests and then
-                                    //  make them pass.
                                     // TODO: Support Vec<GenericOpaqueRustType
                                     if ty.generics.len() == 0 {
                                         let vec_functions =
@@ -217,6 +451,48 @@ impl ToTokens for SwiftBridgeModule {
                                 }
                             }
                         }
+                        HostLang::Swift if ty.attributes.swift_protocol => {
+                            let handle = swift_protocol_handle_ident(&ty.ty);
+
+                            let (sigs, bodies): (Vec<_>, Vec<_>) = trait_method_tokens
+                                .remove(&ty_name.to_string())
+                                .unwrap_or_default()
+                                .into_iter()
+                                .unzip();
+
+                            // The trait is implemented by a type that holds the Swift instance
+                            // and calls its protocol methods.
+                            let struct_tokens = quote! {
+                                pub trait #ty_name {
+                                    #(#sigs;)*
+                                }
+
+                                #[doc(hidden)]
+                                #[repr(C)]
+                                pub struct #handle(*mut std::ffi::c_void);
+
+                                impl #ty_name for #handle {
+                                    #(
+                                        #sigs {
+                                            #bodies
+                                        }
+                                    )*
+                                }
+
+                                impl Drop for #handle {
+                                    fn drop (&mut self) {
+                                        unsafe { #free_mem_func_name(self.0) }
+                                    }
+                                }
+                            };
+                            structs_for_swift_classes.push(struct_tokens);
+
+                            let free = quote! {
+                                #[link_name = #link_name]
+                                fn #free_mem_func_name (this: *mut std::ffi::c_void);
+                            };
+                            extern_swift_fn_tokens.push(free);
+                        }
                         HostLang::Swift => {
                             let ty_name = &ty.ty;
 
@@ -233,27 +509,54 @@ impl ToTokens for SwiftBridgeModule {
                                 }
                             };
 
-                            let struct_tokens = quote! {
-                                #[repr(C)]
-                                pub struct #ty_name(*mut std::ffi::c_void);
+                            // An already declared type's struct is declared by the bridge
+                            // module that declares it, so we use that one.
+                            if ty.attributes.already_declared {
+                                structs_for_swift_classes.push(quote! {
+                                    use super::#ty_name;
+
+                                    #impls
+                                });
+                            } else {
+                                // The pointer is visible to the rest of the crate so that bridge
+                                // modules that use the type as `already_declared` can pass it
+                                // over FFI.
+                                let struct_tokens = quote! {
+                                    #[repr(C)]
+                                    pub struct #ty_name(pub(crate) *mut std::ffi::c_void);
 
-                                #impls
+                                    #impls
 
-                                impl Drop for #ty_name {
-                                    fn drop (&mut self) {
-                                        unsafe { #free_mem_func_name(self.0) }
+                                    impl Drop for #ty_name {
+                                        fn drop (&mut self) {
+                                            unsafe { #free_mem_func_name(self.0) }
+                                        }
                                     }
-                                }
-                            };
-                            structs_for_swift_classes.push(struct_tokens);
+                                };
+                                structs_for_swift_classes.push(struct_tokens);
 
-                            let free = quote! {
-                                #[link_name = #link_name]
-                                fn #free_mem_func_name (this: *mut std::ffi::c_void);
-                            };
-                            extern_swift_fn_tokens.push(free);
+                                let free = quote! {
+                                    #[link_name = #link_name]
+                                    fn #free_mem_func_name (this: *mut std::ffi::c_void);
+                                };
+                                extern_swift_fn_tokens.push(free);
+                            }
                         }
                     };
+
+                    let cfgs = &ty.attributes.cfgs;
+                    for tokens in extern_rust_fn_tokens[extern_rust_fn_tokens_start..]
+                        .iter_mut()
+                        .chain(
+                            structs_for_swift_classes[structs_for_swift_classes_start..].iter_mut(),
+                        )
+                    {
+                        *tokens = add_cfgs_to_items(cfgs, std::mem::take(tokens));
+                    }
+                    for tokens in extern_swift_fn_tokens[extern_swift_fn_tokens_start..].iter_mut()
+                    {
+                        *tokens = add_cfgs_to_foreign_items(cfgs, std::mem::take(tokens));
+                    }
                 }
             }
         }
@@ -276,8 +579,16 @@ impl ToTokens for SwiftBridgeModule {
                 }
             };
         }
+        let constants: Vec<TokenStream> = self
+            .constants
+            .iter()
+            .map(|constant| self.generate_constant_tokens(constant))
+            .collect();
+
         let custom_type_definitions = custom_type_definitions.into_values();
-        let module_inner = quote! {
+        let module_inner = self.prefix_symbols_in_tokens(quote! {
+            #(#constants)*
+
             #(#shared_struct_definitions)*
 
             #(#shared_enum_definitions)*
@@ -293,7 +604,7 @@ impl ToTokens for SwiftBridgeModule {
             #extern_swift_fn_tokens
 
             #(#callbacks_support)*
-        };
+        });
 
         let t = quote! {
             #[allow(non_snake_case)]
@@ -601,6 +912,28 @@ mod tests {
         assert_tokens_contain(&parse_ok(start).to_token_stream(), &expected_func);
     }
 
+    /// Verify that the `rust_name` attribute sets the name of the Rust function that calls an
+    /// extern "Swift" function, while the linked Swift symbol keeps the declared name.
+    #[test]
+    fn extern_swift_freestanding_function_rust_name() {
+        let start = quote! {
+            #[swift_bridge::bridge]
+            mod foo {
+                extern "Swift" {
+                    #[swift_bridge(rust_name = "some_function")]
+                    fn someFunction () -> u8;
+                }
+            }
+        };
+        let expected_func = quote! {
+            pub fn some_function () -> u8 {
+                unsafe { __swift_bridge__someFunction() }
+            }
+        };
+
+        assert_tokens_contain(&parse_ok(start).to_token_stream(), &expected_func);
+    }
+
     /// Verify that we respect the `return_into` attribute from within extern "Rust" blocks.
     #[test]
     fn extern_rust_return_into() {
@@ -667,6 +1000,8 @@ mod tests {
             pub extern "C" fn __swift_bridge__Foo_some_function (
                 this: *mut super::Foo
             ) -> *mut super::Foo {
+                #[cfg(debug_assertions)]
+                swift_bridge::assert_valid_receiver(this, "Foo::some_function");
                 (unsafe { &mut * this }).some_function() as *mut super::Foo
             }
         };
@@ -687,7 +1022,7 @@ mod tests {
         };
         let expected = quote! {
             #[repr(C)]
-            pub struct Foo(*mut std::ffi::c_void);
+            pub struct Foo(pub(crate) *mut std::ffi::c_void);
 
             impl Drop for Foo {
                 fn drop (&mut self) {
@@ -747,7 +1082,7 @@ mod tests {
         };
         let expected = quote! {
             #[repr(C)]
-            pub struct Foo(*mut std::ffi::c_void);
+            pub struct Foo(pub(crate) *mut std::ffi::c_void);
 
             impl Foo {
                 pub fn new () -> Foo {
@@ -836,6 +1171,8 @@ mod tests {
             pub extern "C" fn __swift_bridge__MyType_increment (
                 this: *mut super::MyType
             ) {
+                #[cfg(debug_assertions)]
+                swift_bridge::assert_valid_receiver(this, "MyType::increment");
                 (unsafe { &mut *this }).increment()
             }
         };
@@ -860,7 +1197,7 @@ mod tests {
         };
         let expected = quote! {
             #[repr(C)]
-            pub struct Foo(*mut std::ffi::c_void);
+            pub struct Foo(pub(crate) *mut std::ffi::c_void);
 
             impl Foo {
                 pub fn notify (&self) {
@@ -918,6 +1255,8 @@ mod tests {
                 this: *mut super::SomeType,
                 val: u8
             ) {
+                #[cfg(debug_assertions)]
+                swift_bridge::assert_valid_receiver(this, "SomeType::message");
                 (unsafe { &*this }).message(val)
             }
         };
@@ -1020,6 +1359,8 @@ mod tests {
             pub extern "C" fn __swift_bridge__SomeType_consume (
                 this: *mut super::SomeType
             ) {
+                #[cfg(debug_assertions)]
+                swift_bridge::assert_valid_receiver(this, "SomeType::consume");
                 (* unsafe { Box::from_raw(this) }).consume()
             }
         };
@@ -1040,7 +1381,7 @@ mod tests {
             &function.to_extern_c_function_tokens(
                 &module.swift_bridge_path,
                 &module.types,
-                &mut HashMap::new(),
+                &mut BTreeMap::new(),
             ),
             &expected_fn,
         );
@@ -0,0 +1,50 @@
+//! More tests can be found in
+//! crates/swift-bridge-ir/src/codegen/codegen_tests/constant_codegen_tests.rs
+
+use crate::bridged_type::BridgedType;
+use crate::parse::BridgedConstant;
+use crate::{SwiftBridgeModule, SWIFT_BRIDGE_PREFIX};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Ident;
+
+impl SwiftBridgeModule {
+    /// Generate the tokens for a bridged constant.
+    ///
+    /// The `const` or `static` item is kept as is. A constant whose value isn't a literal also
+    /// gets a function that Swift calls to read its value.
+    pub(super) fn generate_constant_tokens(&self, constant: &BridgedConstant) -> TokenStream {
+        let item = &constant.item;
+
+        // Swift gets its own copy of a literal value, so the constant might never be used by
+        // Rust.
+        if constant.swift_literal.is_some() {
+            return quote! {
+                #[allow(dead_code)]
+                #item
+            };
+        }
+
+        let name = &constant.name;
+        let link_name = constant.accessor_link_name();
+        let fn_name = Ident::new(&format!("{}{}", SWIFT_BRIDGE_PREFIX, name), name.span());
+
+        let ty = BridgedType::new_with_type(&constant.ty, &self.types).unwrap();
+        let ret = ty.to_ffi_compatible_rust_type(&self.swift_bridge_path, &self.types);
+        let value = ty.convert_rust_expression_to_ffi_type(
+            &quote! { #name },
+            &self.swift_bridge_path,
+            &self.types,
+            name.span(),
+        );
+
+        quote! {
+            #item
+
+            #[export_name = #link_name]
+            pub extern "C" fn #fn_name() -> #ret {
+                #value
+            }
+        }
+    }
+}
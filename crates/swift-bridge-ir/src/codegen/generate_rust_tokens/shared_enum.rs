@@ -60,8 +60,14 @@ impl SwiftBridgeModule {
                     }
                 }
                 StructFields::Unit => {
-                    quote! {
-                        #variant_name
+                    if let Some(discriminant) = &variant.discriminant {
+                        quote! {
+                            #variant_name = #discriminant
+                        }
+                    } else {
+                        quote! {
+                            #variant_name
+                        }
                     }
                 }
             };
@@ -158,6 +164,13 @@ impl SwiftBridgeModule {
             });
         }
 
+        if shared_enum.derive.serialize {
+            derives.push(quote! {::serde::Serialize});
+        }
+        if shared_enum.derive.deserialize {
+            derives.push(quote! {::serde::Deserialize});
+        }
+
         let vec_support = if shared_enum.has_one_or_more_variants_with_data() {
             // Enums with variants that contain data are not yet supported.
             quote! {}
@@ -98,6 +98,12 @@ impl SwiftBridgeModule {
         if shared_struct.derives.clone {
             derives.push(quote! {Clone});
         }
+        if shared_struct.derives.serialize {
+            derives.push(quote! {::serde::Serialize});
+        }
+        if shared_struct.derives.deserialize {
+            derives.push(quote! {::serde::Deserialize});
+        }
 
         let definition = quote! {
             #[derive(#(#derives),*)]
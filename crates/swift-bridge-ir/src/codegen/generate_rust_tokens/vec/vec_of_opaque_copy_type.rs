@@ -0,0 +1,108 @@
+use crate::parse::OpaqueForeignTypeDeclaration;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generate the functions that Swift calls uses inside of the corresponding struct for a
+/// `Copy` opaque Rust type's Vectorizable implementation.
+///
+/// Values are passed over FFI by copying their bytes, so unlike non-`Copy` opaque types `get`
+/// and `pop` return an `Option` of the FFI repr instead of a pointer. `get_mut` still returns a
+/// pointer so that Swift can write through it.
+///
+/// So inside of `extension MyCopyType: Vectorizable {}` on the Swift side.
+pub(in super::super) fn generate_vec_of_opaque_copy_type_functions(
+    ty: &OpaqueForeignTypeDeclaration,
+) -> TokenStream {
+    let ty_name = &ty.ty;
+
+    // examples:
+    // "__swift_bridge__$Vec_MyCopyType$new"
+    // "__swift_bridge__$Vec_MyCopyType$drop"
+    let make_export_name = |fn_name| format!("__swift_bridge__$Vec_{}${}", ty_name, fn_name);
+    let export_name_new = make_export_name("new");
+    let export_name_drop = make_export_name("drop");
+    let export_name_len = make_export_name("len");
+    let export_name_get = make_export_name("get");
+    let export_name_get_mut = make_export_name("get_mut");
+    let export_name_push = make_export_name("push");
+    let export_name_pop = make_export_name("pop");
+    let export_name_as_ptr = make_export_name("as_ptr");
+
+    let copy_repr = ty.ffi_copy_repr_ident();
+    let option_copy_repr = ty.ffi_option_copy_repr_ident();
+
+    quote! {
+        const _: () = {
+            #[doc(hidden)]
+            #[export_name = #export_name_new]
+            pub extern "C" fn _new() -> *mut Vec<super::#ty_name> {
+                Box::into_raw(Box::new(Vec::new()))
+            }
+
+            #[doc(hidden)]
+            #[export_name = #export_name_drop]
+            pub extern "C" fn _drop(vec: *mut Vec<super::#ty_name>) {
+                let vec = unsafe { Box::from_raw(vec) };
+                drop(vec)
+            }
+
+            #[doc(hidden)]
+            #[export_name = #export_name_len]
+            pub extern "C" fn _len(vec: *const Vec<super::#ty_name>) -> usize {
+                unsafe { &*vec }.len()
+            }
+
+            #[doc(hidden)]
+            #[export_name = #export_name_get]
+            pub extern "C" fn _get(vec: *const Vec<super::#ty_name>, index: usize) -> #option_copy_repr {
+                let vec = unsafe { &*vec };
+                _option_into_ffi_repr(vec.get(index).map(|v| *v))
+            }
+
+            #[doc(hidden)]
+            #[export_name = #export_name_get_mut]
+            pub extern "C" fn _get_mut(vec: *mut Vec<super::#ty_name>, index: usize) -> *mut super::#ty_name {
+                let vec = unsafe { &mut *vec };
+                if let Some(val) = vec.get_mut(index) {
+                    val as *mut super::#ty_name
+                } else {
+                    std::ptr::null_mut()
+                }
+            }
+
+            #[doc(hidden)]
+            #[export_name = #export_name_push]
+            pub extern "C" fn _push(vec: *mut Vec<super::#ty_name>, val: #copy_repr) {
+                unsafe { &mut *vec }.push(val.into_rust_repr())
+            }
+
+            #[doc(hidden)]
+            #[export_name = #export_name_pop]
+            pub extern "C" fn _pop(vec: *mut Vec<super::#ty_name>) -> #option_copy_repr {
+                let vec = unsafe { &mut *vec };
+                _option_into_ffi_repr(vec.pop())
+            }
+
+            #[doc(hidden)]
+            #[export_name = #export_name_as_ptr]
+            pub extern "C" fn _as_ptr(vec: *const Vec<super::#ty_name>) -> *const super::#ty_name {
+                unsafe { & *vec }.as_ptr()
+            }
+
+            #[inline(always)]
+            fn _option_into_ffi_repr(val: Option<super::#ty_name>) -> #option_copy_repr {
+                if let Some(val) = val {
+                    #option_copy_repr {
+                        is_some: true,
+                        val: std::mem::MaybeUninit::new(#copy_repr::from_rust_repr(val))
+                    }
+                } else {
+                    #option_copy_repr {
+                        is_some: false,
+                        val: std::mem::MaybeUninit::uninit()
+                    }
+                }
+            }
+        };
+    }
+}
@@ -3,22 +3,31 @@ use std::collections::HashMap;
 use syn::Path;
 
 use crate::bridged_type::{BridgeableType, BridgedType, TypePosition};
+use crate::codegen::generate_swift::formatting::tidy_swift_whitespace;
 use crate::codegen::generate_swift::generate_function_swift_calls_rust::gen_func_swift_calls_rust;
 use crate::codegen::generate_swift::opaque_copy_type::generate_opaque_copy_struct;
+use crate::codegen::generate_swift::publisher::gen_swift_publisher;
 use crate::codegen::generate_swift::swift_class::generate_swift_class;
-use crate::codegen::generate_swift::vec::generate_vectorizable_extension;
-use crate::codegen::CodegenConfig;
+use crate::codegen::generate_swift::vec::{
+    generate_vectorizable_copy_type_extension, generate_vectorizable_extension,
+};
+use crate::codegen::{CodegenConfig, SharedDeclarations};
+use crate::item_cfg::cfgs_are_enabled;
 use crate::parse::{
     HostLang, OpaqueForeignTypeDeclaration, SharedTypeDeclaration, TypeDeclaration,
     TypeDeclarations,
 };
-use crate::parsed_extern_fn::ParsedExternFn;
-use crate::SwiftBridgeModule;
+use crate::parsed_extern_fn::{ParsedExternFn, SwiftProperty};
+use crate::trace::trace;
+use crate::{SwiftAccess, SwiftBridgeModule};
 
 mod vec;
 
+mod constant;
+mod formatting;
 mod generate_function_swift_calls_rust;
 mod opaque_copy_type;
+mod publisher;
 mod shared_enum;
 mod shared_struct;
 mod swift_class;
@@ -26,33 +35,89 @@ mod swift_class;
 impl SwiftBridgeModule {
     /// Generate the corresponding Swift code for the bridging module.
     pub(crate) fn generate_swift(&self, config: &CodegenConfig) -> String {
+        self.generate_swift_with_shared_declarations(config, &mut SharedDeclarations::default())
+    }
+
+    /// Generate the Swift code for the bridging module, skipping any of the custom Swift code
+    /// that another module already generated.
+    pub(crate) fn generate_swift_with_shared_declarations(
+        &self,
+        config: &CodegenConfig,
+        shared: &mut SharedDeclarations,
+    ) -> String {
         let mut swift = "".to_string();
 
         if !self.module_will_be_compiled(config) {
+            trace(|| {
+                format!(
+                    "skipping module `{}`, since its cfg is not enabled",
+                    self.name
+                )
+            });
             return swift;
         }
 
         let mut associated_funcs_and_methods: HashMap<String, Vec<&ParsedExternFn>> =
             HashMap::new();
         let mut class_protocols: HashMap<String, ClassProtocols> = HashMap::new();
+        let mut shared_type_associated_funcs: HashMap<String, Vec<&ParsedExternFn>> =
+            HashMap::new();
+        let mut custom_swift_code: Vec<String> = vec![];
+        let mut namespaced_functions: Vec<String> = vec![];
 
         for function in &self.functions {
+            if !cfgs_are_enabled(&function.cfgs, config) {
+                trace(|| {
+                    format!(
+                        "skipping fn `{}`, since its cfg is not enabled",
+                        function.sig.ident
+                    )
+                });
+                continue;
+            }
+
+            for code in gen_custom_swift_code_for_fn_types(function, &self.types) {
+                if shared.swift_code.insert(code.clone()) {
+                    custom_swift_code.push(code);
+                }
+            }
+
             if function.host_lang.is_rust() {
+                let swift_callbacks = gen_swift_callbacks_passed_to_rust(function, &self.types);
+                if !swift_callbacks.is_empty() {
+                    swift += &self.swift_access.apply(&swift_callbacks);
+                    swift += "\n";
+                }
+
+                if function.swift_publisher.is_some() {
+                    let access = function
+                        .swift_access
+                        .unwrap_or_else(|| self.associated_type_swift_access(function));
+                    swift += &access.apply(&gen_swift_publisher(function, &self.types));
+                }
+
                 if let Some(ty) = function.associated_type.as_ref() {
                     match ty {
-                        TypeDeclaration::Shared(_) => {
-                            //
-                            todo!("Think about what to do here..")
+                        TypeDeclaration::Shared(shared_ty) => {
+                            shared_type_associated_funcs
+                                .entry(shared_ty.name().to_string())
+                                .or_default()
+                                .push(function);
                         }
                         TypeDeclaration::Opaque(opaque_ty) => {
+                            // Each monomorphization of a generic type gets its own methods.
                             associated_funcs_and_methods
-                                .entry(opaque_ty.to_string())
+                                .entry(opaque_ty.ffi_repr_name_string())
                                 .or_default()
                                 .push(function);
 
                             if function.is_swift_identifiable {
                                 let identifiable_protocol = IdentifiableProtocol {
-                                    func_name: function.func.sig.ident.to_string(),
+                                    func_name: function
+                                        .swift_name_override
+                                        .as_ref()
+                                        .map(|name| name.value())
+                                        .unwrap_or_else(|| function.func.sig.ident.to_string()),
                                     return_ty: BridgedType::new_with_return_type(
                                         &function.func.sig.output,
                                         &self.types,
@@ -83,28 +148,49 @@ impl SwiftBridgeModule {
                     &self.swift_bridge_path,
                 ),
             };
-            swift += &func_definition;
-            swift += "\n";
+            let access = function.swift_access.unwrap_or(self.swift_access);
+            if self.swift_namespace.is_some() && function.host_lang.is_rust() {
+                let func_definition = access.apply(&namespace_function(&func_definition));
+                namespaced_functions.push(with_source_location(function, &func_definition));
+            } else {
+                let func_definition = access.apply(&func_definition);
+                swift += &with_source_location(function, &func_definition);
+                swift += "\n";
+            }
+        }
+
+        if let Some(namespace) = self.swift_namespace.as_ref() {
+            swift += &format!(
+                "{access} enum {namespace} {{\n{functions}}}\n",
+                access = self.swift_access.as_str(),
+                namespace = namespace,
+                functions = namespaced_functions.join("\n")
+            );
         }
 
         for ty in self.types.types() {
             match ty {
                 TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)) => {
                     if let Some(swift_struct) = self.generate_shared_struct_string(shared_struct) {
-                        swift += &swift_struct;
+                        swift += &self.swift_access.apply(&swift_struct);
                         swift += "\n";
                     }
                 }
                 TypeDeclaration::Shared(SharedTypeDeclaration::Enum(shared_enum)) => {
                     if let Some(swift_enum) = self.generate_shared_enum_string(shared_enum) {
-                        swift += &swift_enum;
+                        swift += &self.swift_access.apply(&swift_enum);
                         swift += "\n";
                     }
                 }
+                TypeDeclaration::Opaque(ty) if !cfgs_are_enabled(&ty.attributes.cfgs, config) => {
+                    trace(|| format!("skipping type `{}`, since its cfg is not enabled", ty.ty));
+                }
                 TypeDeclaration::Opaque(ty) => match ty.host_lang {
                     HostLang::Rust => {
+                        let mut type_swift = "".to_string();
+
                         if let Some(_copy) = ty.attributes.copy {
-                            swift += &generate_opaque_copy_struct(
+                            type_swift += &generate_opaque_copy_struct(
                                 ty,
                                 &associated_funcs_and_methods,
                                 &self.types,
@@ -115,7 +201,7 @@ impl SwiftBridgeModule {
                             let default_cp = ClassProtocols::default();
                             let class_protocols = class_protocols.unwrap_or(&default_cp);
 
-                            swift += &generate_swift_class(
+                            type_swift += &generate_swift_class(
                                 ty,
                                 &associated_funcs_and_methods,
                                 class_protocols,
@@ -124,27 +210,116 @@ impl SwiftBridgeModule {
                             );
                         }
 
-                        swift += "\n";
+                        type_swift += "\n";
 
                         if !ty.attributes.already_declared {
-                            // TODO: Support Vec<OpaqueCopyType>. Add codegen tests and then
-                            //  make them pass.
                             // TODO: Support Vec<GenericOpaqueRustType
-                            if ty.attributes.copy.is_none() && ty.generics.len() == 0 {
-                                swift += &generate_vectorizable_extension(&ty);
-                                swift += "\n";
+                            if ty.generics.len() == 0 {
+                                if ty.attributes.copy.is_some() {
+                                    type_swift += &generate_vectorizable_copy_type_extension(ty);
+                                } else {
+                                    type_swift += &generate_vectorizable_extension(&ty);
+                                }
+                                type_swift += "\n";
                             }
                         }
+
+                        swift += &ty
+                            .attributes
+                            .swift_access
+                            .unwrap_or(self.swift_access)
+                            .apply(&type_swift);
                     }
                     HostLang::Swift => {
-                        swift += &generate_drop_swift_instance_reference_count(ty);
-                        swift += "\n";
+                        if ty.attributes.swift_protocol {
+                            let access = ty.attributes.swift_access.unwrap_or(self.swift_access);
+                            swift += &access.apply(&self.generate_swift_protocol(ty));
+                            swift += "\n";
+                        }
+
+                        // An already declared type is freed by the bridge module that
+                        // declares it.
+                        if !ty.attributes.already_declared {
+                            swift += &generate_drop_swift_instance_reference_count(ty);
+                            swift += "\n";
+                        }
                     }
                 },
             };
+
+            if let TypeDeclaration::Shared(shared_ty) = ty {
+                if let Some(funcs) = shared_type_associated_funcs.get(&shared_ty.name().to_string())
+                {
+                    let funcs: Vec<String> = funcs
+                        .iter()
+                        .map(|func| {
+                            let func_definition = gen_func_swift_calls_rust(
+                                func,
+                                &self.types,
+                                &self.swift_bridge_path,
+                            );
+                            with_source_location(func, &func_definition)
+                        })
+                        .collect();
+
+                    swift += &self.swift_access.apply(&format!(
+                        "extension {type_name} {{\n{funcs}\n}}\n",
+                        type_name = shared_ty.swift_name_string(),
+                        funcs = funcs.join("\n\n")
+                    ));
+                }
+            }
+        }
+
+        for constant in self.constants.iter() {
+            swift += &self
+                .swift_access
+                .apply(&self.generate_constant_string(constant));
+        }
+
+        for code in custom_swift_code {
+            swift += &self.swift_access.apply(&code);
+            swift += "\n";
+        }
+
+        self.prefix_symbols(&tidy_swift_whitespace(&swift))
+    }
+
+    /// The access level of the Swift declarations of a function's associated type.
+    fn associated_type_swift_access(&self, function: &ParsedExternFn) -> SwiftAccess {
+        match function.associated_type.as_ref() {
+            Some(TypeDeclaration::Opaque(ty)) => {
+                ty.attributes.swift_access.unwrap_or(self.swift_access)
+            }
+            _ => self.swift_access,
         }
+    }
 
-        swift
+    // Generate the Swift protocol for a bridged trait.
+    //
+    // # Example
+    //
+    // ```
+    // public protocol Greeter: AnyObject {
+    //     func greet(name: RustString) -> String
+    // }
+    // ```
+    fn generate_swift_protocol(&self, ty: &OpaqueForeignTypeDeclaration) -> String {
+        let requirements: Vec<String> = self
+            .functions
+            .iter()
+            .filter(|func| match func.associated_type.as_ref() {
+                Some(TypeDeclaration::Opaque(associated)) => associated.ty == ty.ty,
+                _ => false,
+            })
+            .map(|func| format!("    {}\n", func.to_swift_protocol_requirement(&self.types)))
+            .collect();
+
+        format!(
+            "public protocol {ty_name}: AnyObject {{\n{requirements}}}\n",
+            ty_name = ty.ty,
+            requirements = requirements.join("")
+        )
     }
 }
 
@@ -171,6 +346,12 @@ struct IdentifiableProtocol {
 fn generate_drop_swift_instance_reference_count(ty: &OpaqueForeignTypeDeclaration) -> String {
     let link_name = ty.free_swift_class_link_name();
     let fn_name = ty.free_swift_class_func_name();
+    // Protocol instances are passed to Rust as `AnyObject`s.
+    let unmanaged_ty_name = if ty.attributes.swift_protocol {
+        "AnyObject".to_string()
+    } else {
+        ty.ty_name_ident().to_string()
+    };
 
     format!(
         r##"
@@ -181,10 +362,129 @@ func {fn_name} (ptr: UnsafeMutableRawPointer) {{
 "##,
         link_name = link_name,
         fn_name = fn_name,
-        ty_name = ty.ty_name_ident()
+        ty_name = unmanaged_ty_name
     )
 }
 
+// Generate the Swift code that a function's argument and return types need, such as the
+// `extension RustMap where K == .., V == ..` that holds a `HashMap<K, V>`'s methods.
+fn gen_custom_swift_code_for_fn_types(
+    func: &ParsedExternFn,
+    types: &TypeDeclarations,
+) -> Vec<String> {
+    func.func
+        .sig
+        .inputs
+        .iter()
+        .filter_map(|arg| BridgedType::new_with_fn_arg(arg, types))
+        .chain(BridgedType::new_with_return_type(
+            &func.func.sig.output,
+            types,
+        ))
+        .chain(func.arc_self_bridged_type(types))
+        .flat_map(|ty| {
+            let mut code = vec![];
+            push_custom_swift_code(&ty, types, &mut code);
+            code
+        })
+        .collect()
+}
+
+/// Push the Swift code that a type needs, along with the code that the types held by a `Result`
+/// need, such as for the `HashMap` in `Result<HashMap<K, V>, E>`.
+fn push_custom_swift_code(
+    ty: &dyn BridgeableType,
+    types: &TypeDeclarations,
+    code: &mut Vec<String>,
+) {
+    if let Some(custom) = ty.generate_custom_swift_code(types) {
+        code.push(custom);
+    }
+
+    if let Some(result) = ty.as_result() {
+        push_custom_swift_code(result.ok_ty.as_ref(), types, code);
+        push_custom_swift_code(result.err_ty.as_ref(), types, code);
+    }
+}
+
+/// Generates the class that holds a Swift closure that gets passed to a Rust function, along with
+/// the functions that Rust uses to call and release the closure.
+fn gen_swift_callbacks_passed_to_rust(func: &ParsedExternFn, types: &TypeDeclarations) -> String {
+    let mut swift_callbacks = vec![];
+
+    for (idx, boxed_fn) in func.args_filtered_to_boxed_fns(types) {
+        let class_name = func.swift_callback_class_name(idx);
+        let call_link_name = func.call_boxed_fn_link_name(idx);
+        let free_link_name = func.free_boxed_fn_link_name(idx);
+        let (call_fn_name, free_fn_name) = func.swift_callback_rust_fn_idents(idx);
+        let closure_ty = boxed_fn.to_swift_closure_type(types);
+
+        let mut params = vec!["_ callback: UnsafeMutableRawPointer".to_string()];
+        let mut call_args = vec![];
+        for (param_idx, param) in boxed_fn.params.iter().enumerate() {
+            let type_pos = TypePosition::FnArg(HostLang::Swift, param_idx);
+
+            params.push(format!(
+                "_ arg{param_idx}: {}",
+                param.to_swift_type(type_pos, types)
+            ));
+            call_args.push(param.convert_ffi_value_to_swift_value(
+                &format!("arg{param_idx}"),
+                type_pos,
+                types,
+            ));
+        }
+        let params = params.join(", ");
+
+        let maybe_ret = if boxed_fn.ret.is_null() {
+            "".to_string()
+        } else {
+            format!(
+                " -> {}",
+                boxed_fn
+                    .ret
+                    .to_swift_type(TypePosition::FnReturn(HostLang::Swift), types)
+            )
+        };
+        let call_callback = format!(
+            "Unmanaged<{class_name}>.fromOpaque(callback).takeUnretainedValue().callback({})",
+            call_args.join(", ")
+        );
+        // We bind the closure's return value before converting it since some conversions use
+        // the expression more than once.
+        let call_callback = if boxed_fn.ret.is_null() {
+            call_callback
+        } else {
+            let ret_value = boxed_fn.ret.convert_swift_expression_to_ffi_type(
+                "value",
+                types,
+                TypePosition::FnReturn(HostLang::Swift),
+            );
+            format!("let value = {call_callback}\n    return {ret_value}")
+        };
+
+        swift_callbacks.push(format!(
+            r#"class {class_name} {{
+    var callback: {closure_ty}
+
+    init(_ callback: @escaping {closure_ty}) {{
+        self.callback = callback
+    }}
+}}
+@_cdecl("{call_link_name}")
+func {call_fn_name} ({params}){maybe_ret} {{
+    {call_callback}
+}}
+@_cdecl("{free_link_name}")
+func {free_fn_name} (_ callback: UnsafeMutableRawPointer) {{
+    Unmanaged<{class_name}>.fromOpaque(callback).release()
+}}"#
+        ));
+    }
+
+    swift_callbacks.join("\n")
+}
+
 fn gen_function_exposes_swift_to_rust(
     func: &ParsedExternFn,
     types: &TypeDeclarations,
@@ -214,11 +514,11 @@ fn gen_function_exposes_swift_to_rust(
             };
 
             if func.is_method() {
-                call_fn = format!(
-                    "Unmanaged<{ty_name}>.fromOpaque(this).takeUnretainedValue().{call_fn}",
-                    ty_name = ty_name,
-                    call_fn = call_fn
-                );
+                let this = associated_type
+                    .to_opaque_type(true, false)
+                    .unwrap()
+                    .swift_take_unmanaged("this", "takeUnretainedValue");
+                call_fn = format!("{this}.{call_fn}", this = this, call_fn = call_fn);
                 call_fn = built_in.convert_swift_expression_to_ffi_type(
                     &call_fn,
                     types,
@@ -249,7 +549,7 @@ fn gen_function_exposes_swift_to_rust(
     };
 
     for (idx, boxed_fn) in func.args_filtered_to_boxed_fns(types) {
-        if boxed_fn.does_not_have_params_or_return() {
+        if boxed_fn.uses_no_args_no_return_support() {
             continue;
         }
 
@@ -275,6 +575,32 @@ fn gen_function_exposes_swift_to_rust(
         );
 
         let maybe_generics = boxed_fn.maybe_swift_generics(types);
+        let free_fn = format!("__swift_bridge__{maybe_associated_ty}${fn_name}$_free$param{idx}");
+
+        if !boxed_fn.kind.is_fn_once() {
+            // An `Fn` or `FnMut` can be called any number of times, so we only free it once
+            // Swift no longer holds the callback.
+            let class_prefix = boxed_fn.swift_callback_class_prefix();
+            rust_fn_once_callback_classes += &format!(
+                r#"
+class {class_prefix}{maybe_associated_ty}${fn_name}$param{idx} {{
+    var ptr: UnsafeMutableRawPointer
+
+    init(ptr: UnsafeMutableRawPointer) {{
+        self.ptr = ptr
+    }}
+
+    deinit {{
+        {free_fn}(ptr)
+    }}
+
+    func call{maybe_generics}({params_as_swift}){maybe_ret} {{
+        return {ret_value}
+    }}
+}}"#
+            );
+            continue;
+        }
 
         rust_fn_once_callback_classes += &format!(
             r#"
@@ -288,7 +614,7 @@ class __private__RustFnOnceCallback{maybe_associated_ty}${fn_name}$param{idx} {{
 
     deinit {{
         if !called {{
-            __swift_bridge__{maybe_associated_ty}${fn_name}$_free$param{idx}(ptr)
+            {free_fn}(ptr)
         }}
     }}
 
@@ -336,6 +662,12 @@ struct ClassMethods {
     owned_self_methods: Vec<String>,
     ref_self_methods: Vec<String>,
     ref_mut_self_methods: Vec<String>,
+    arc_self_methods: Vec<String>,
+    /// Computed properties that go in the body of the `SomeTypeRef` class.
+    ref_properties: Vec<String>,
+    /// Computed properties that go in the body of the `SomeTypeRefMut` class, since Swift does not
+    /// allow an extension to override a property.
+    ref_mut_properties: Vec<String>,
 }
 
 fn generate_swift_class_methods(
@@ -348,17 +680,28 @@ fn generate_swift_class_methods(
     let mut owned_self_methods = vec![];
     let mut ref_self_methods = vec![];
     let mut ref_mut_self_methods = vec![];
+    let mut arc_self_methods = vec![];
+    let mut ref_properties = vec![];
+    let mut ref_mut_properties = vec![];
 
     if let Some(methods) = associated_funcs_and_methods.get(type_name) {
         for type_method in methods {
             let func_definition = gen_func_swift_calls_rust(type_method, types, swift_bridge_path);
+            let func_definition = with_source_location(type_method, &func_definition);
 
             let is_class_func = type_method.func.sig.inputs.is_empty();
 
-            if type_method.is_swift_initializer {
+            if let Some(swift_property) = &type_method.swift_property {
+                match swift_property {
+                    SwiftProperty::Getter { .. } => ref_properties.push(func_definition),
+                    SwiftProperty::Setter { .. } => ref_mut_properties.push(func_definition),
+                }
+            } else if type_method.is_swift_initializer {
                 initializers.push(func_definition);
             } else if is_class_func {
                 ref_self_methods.push(func_definition);
+            } else if type_method.is_self_arc() {
+                arc_self_methods.push(func_definition);
             } else {
                 if type_method.self_reference().is_some() {
                     if type_method.self_mutability().is_some() {
@@ -378,9 +721,76 @@ fn generate_swift_class_methods(
         owned_self_methods,
         ref_self_methods,
         ref_mut_self_methods,
+        arc_self_methods,
+        ref_properties,
+        ref_mut_properties,
     }
 }
 
+/// Generate the Swift `///` documentation for a Rust doc comment, such that Xcode's Quick Help
+/// shows it for the generated declaration.
+///
+/// Every line of the documentation ends with a newline, so that it can be placed directly before
+/// the declaration.
+/// Indent a free function's definition so that it can be placed inside of the module's
+/// `swift_namespace` enum, and make it a static function of the enum.
+fn namespace_function(func_definition: &str) -> String {
+    let mut namespaced = "".to_string();
+    let mut is_declaration = true;
+    for line in func_definition.lines() {
+        if line.is_empty() {
+            namespaced += "\n";
+        } else if is_declaration && !line.starts_with("///") && !line.starts_with('@') {
+            // The declaration, such as `public func some_function() {`, comes after the
+            // function's doc comment and attributes.
+            namespaced += &format!("    static {}\n", line);
+            is_declaration = false;
+        } else {
+            namespaced += &format!("    {}\n", line);
+        }
+    }
+    namespaced
+}
+
+/// Surround a function's generated Swift with `#sourceLocation` directives that point back at
+/// the function's declaration in the Rust bridge module, if the module was parsed from a file.
+///
+/// The line that declares the Swift function is mapped to the line of the Rust function's `fn`
+/// keyword, and the doc comment and attributes above it to the lines above that. Everything after
+/// the function refers to the generated Swift file again.
+fn with_source_location(func: &ParsedExternFn, func_definition: &str) -> String {
+    let (file, fn_line) = match func.source_location() {
+        Some(location) => location,
+        None => return func_definition.to_string(),
+    };
+
+    let func_definition = func_definition.trim_matches('\n');
+    let lines_before_declaration = func_definition
+        .lines()
+        .take_while(|line| {
+            let line = line.trim_start();
+            line.starts_with("///") || line.starts_with('@')
+        })
+        .count();
+    let line = fn_line.saturating_sub(lines_before_declaration).max(1);
+
+    // The escape sequences in a Rust string literal, such as `\"` and `\u{1F600}`, are also
+    // valid in a Swift string literal.
+    format!("#sourceLocation(file: {file:?}, line: {line})\n{func_definition}\n#sourceLocation()\n")
+}
+
+fn swift_doc_comment(doc_comment: Option<&str>, indentation: &str) -> String {
+    let mut swift_doc = "".to_string();
+
+    if let Some(doc_comment) = doc_comment {
+        for line in doc_comment.lines() {
+            swift_doc += &format!("{}///{}\n", indentation, line);
+        }
+    }
+
+    swift_doc
+}
+
 #[cfg(test)]
 mod tests {
     //! More tests can be found in src/codegen/codegen_tests.rs and its submodules.
@@ -682,6 +1092,66 @@ extension Foo {
         assert_trimmed_generated_contains_trimmed_expected(&generated, expected);
     }
 
+    /// Verify that an initializer that returns `Option<Self>` becomes a failable Swift
+    /// initializer.
+    #[test]
+    fn class_with_failable_init() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    type Foo;
+
+                    #[swift_bridge(init)]
+                    fn new(val: u8) -> Option<Foo>;
+                }
+            }
+        };
+        let module: SwiftBridgeModule = parse_quote!(#tokens);
+        let generated = module.generate_swift(&CodegenConfig::no_features_enabled());
+
+        let expected = r#"
+extension Foo {
+    public convenience init?(_ val: UInt8) {
+        guard let ptr = __swift_bridge__$Foo$new(val) else { return nil }
+        self.init(ptr: ptr)
+    }
+}
+"#;
+
+        assert_trimmed_generated_contains_trimmed_expected(&generated, expected);
+    }
+
+    /// Verify that an initializer that returns `Option<Self>` on a `Copy` opaque type becomes a
+    /// failable Swift struct initializer.
+    #[test]
+    fn copy_type_with_failable_init() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Copy(4))]
+                    type Foo;
+
+                    #[swift_bridge(init)]
+                    fn new(val: u8) -> Option<Foo>;
+                }
+            }
+        };
+        let module: SwiftBridgeModule = parse_quote!(#tokens);
+        let generated = module.generate_swift(&CodegenConfig::no_features_enabled());
+
+        let expected = r#"
+extension Foo {
+    public init?(_ val: UInt8) {
+        let maybeSelf = __swift_bridge__$Foo$new(val)
+        guard maybeSelf.is_some else { return nil }
+        self.bytes = maybeSelf.val
+    }
+}
+"#;
+
+        assert_trimmed_generated_contains_trimmed_expected(&generated, expected);
+    }
+
     /// Verify that we generate a Swift function that allows us to access a class instance method
     /// from Rust using a pointer.
     #[test]
@@ -1011,4 +1481,57 @@ func __swift_bridge__some_function () {
 
         assert_trimmed_generated_contains_trimmed_expected(&generated, &expected);
     }
+
+    /// Verify that the Swift that gets generated for the functions of a module that was parsed
+    /// from a file points back at each function's declaration using `#sourceLocation`.
+    #[test]
+    fn source_location_directives() {
+        let source = r#"
+mod ffi {
+    extern "Rust" {
+        type SomeType;
+
+        /// Some documentation.
+        fn some_function();
+
+        fn some_method(&self);
+    }
+
+    extern "Swift" {
+        fn swift_function();
+    }
+}
+"#;
+        let mut module: SwiftBridgeModule = syn::parse_str(source).unwrap();
+        module.set_source_file("src/lib.rs");
+        let generated = module.generate_swift(&CodegenConfig::no_features_enabled());
+
+        let expected_rust_function = r#"
+#sourceLocation(file: "src/lib.rs", line: 6)
+/// Some documentation.
+public func some_function() {
+    __swift_bridge__$some_function()
+}
+#sourceLocation()
+"#;
+        let expected_rust_method = r#"
+#sourceLocation(file: "src/lib.rs", line: 9)
+    public func some_method() {
+        __swift_bridge__$SomeType$some_method(ptr)
+    }
+#sourceLocation()
+"#;
+        let expected_swift_function = r#"
+#sourceLocation(file: "src/lib.rs", line: 12)
+@_cdecl("__swift_bridge__$swift_function")
+func __swift_bridge__swift_function () {
+    swift_function()
+}
+#sourceLocation()
+"#;
+
+        assert_trimmed_generated_contains_trimmed_expected(&generated, expected_rust_function);
+        assert_trimmed_generated_contains_trimmed_expected(&generated, expected_rust_method);
+        assert_trimmed_generated_contains_trimmed_expected(&generated, expected_swift_function);
+    }
 }
@@ -0,0 +1,29 @@
+use crate::bridged_type::{BridgedType, TypePosition};
+use crate::parse::{BridgedConstant, HostLang};
+use crate::SwiftBridgeModule;
+
+impl SwiftBridgeModule {
+    /// Generate the Swift constant for a bridged constant.
+    ///
+    /// ```swift
+    /// public let MAX_SIZE: UInt32 = 1024
+    /// public let GREETING: String = __swift_bridge__$GREETING().toString()
+    /// ```
+    pub(super) fn generate_constant_string(&self, constant: &BridgedConstant) -> String {
+        let ty = if constant.is_str() {
+            "String".to_string()
+        } else {
+            BridgedType::new_with_type(&constant.ty, &self.types)
+                .unwrap()
+                .to_swift_type(TypePosition::FnReturn(HostLang::Rust), &self.types)
+        };
+
+        let value = match constant.swift_literal.as_ref() {
+            Some(literal) => literal.clone(),
+            None if constant.is_str() => format!("{}().toString()", constant.accessor_link_name()),
+            None => format!("{}()", constant.accessor_link_name()),
+        };
+
+        format!("public let {name}: {ty} = {value}\n", name = constant.name,)
+    }
+}
@@ -0,0 +1,328 @@
+/// Tidy up the whitespace of the generated Swift code.
+///
+/// The Swift code is generated by concatenating many smaller snippets, each of which brings its
+/// own leading and trailing newlines, so without this the output would have runs of blank lines
+/// wherever a snippet happens to be empty or where two snippets meet.
+///
+/// - Every line is re-indented based on how deeply it is nested, since a snippet doesn't know how
+///   deeply the code that it gets interpolated into is indented.
+/// - Trailing whitespace is removed from every line.
+/// - Runs of blank lines are collapsed into a single blank line.
+/// - Blank lines right after an opening brace or right before a closing brace are removed.
+/// - The code ends with exactly one newline.
+pub(super) fn tidy_swift_whitespace(swift: &str) -> String {
+    let mut lines: Vec<&str> = vec![];
+
+    for line in swift.lines().map(|line| line.trim_end()) {
+        let previous = lines.last().copied();
+
+        if line.is_empty() {
+            match previous {
+                None => continue,
+                Some(previous) if previous.is_empty() || previous.ends_with('{') => continue,
+                _ => {}
+            }
+        }
+
+        if line.trim_start().starts_with('}') && previous == Some("") {
+            lines.pop();
+        }
+
+        lines.push(line);
+    }
+
+    while lines.last() == Some(&"") {
+        lines.pop();
+    }
+
+    if lines.is_empty() {
+        return "".to_string();
+    }
+
+    let lines = indent_swift(&lines);
+    let mut tidied = lines.join("\n");
+    tidied += "\n";
+    tidied
+}
+
+/// Re-indent the code by four spaces for every block, call or collection literal that a line is
+/// nested in.
+///
+/// Compiler directives are left as they are. A line adds at most one level of nesting for the
+/// lines after it, so `withCheckedContinuation({ continuation in` only indents its body once. The
+/// `case`s of a `switch` are indented one level, and the statements of a `case` one more. A chained
+/// method call on its own line, such as `.eraseToAnyPublisher()`, is indented one level past the
+/// expression that it continues.
+fn indent_swift(lines: &[&str]) -> Vec<String> {
+    let mut blocks: Vec<Block> = vec![];
+    let mut indented = Vec::with_capacity(lines.len());
+
+    // Whether the previous line closed a block that was opened by a chained method call.
+    let mut closed_chained_block = None;
+
+    for line in lines {
+        // Compiler directives such as `#sourceLocation(...)` and `#if` keep the indentation that
+        // they were generated with.
+        if line.trim_start().starts_with('#') {
+            indented.push(line.to_string());
+            continue;
+        }
+
+        let line = line.trim_start();
+        if line.is_empty() {
+            indented.push("".to_string());
+            continue;
+        }
+
+        let (opened, closed) = count_brackets(line);
+
+        let closes_block = line.starts_with(['}', ')', ']']);
+        let closed_block = if closes_block { blocks.pop() } else { None };
+
+        // The closing bracket of a chained call lines up with the call. A chained call that
+        // follows the closing bracket of a block that wasn't opened by a chained call, such as
+        // the `.eraseToAnyPublisher()` in `Deferred { ... }.eraseToAnyPublisher()`, lines up with
+        // that closing bracket.
+        let is_chained = if let Some(closed_block) = closed_block.as_ref() {
+            closed_block.is_chained
+        } else {
+            line.starts_with('.') && closed_chained_block.unwrap_or(true)
+        };
+        closed_chained_block = closed_block.as_ref().map(|block| block.is_chained);
+
+        let is_case = line.starts_with("case ") || line.starts_with("default:");
+        if let Some(switch) = blocks.last_mut().filter(|block| block.is_switch && is_case) {
+            switch.in_case = false;
+        }
+
+        let depth = blocks.iter().map(Block::depth).sum::<usize>() + is_chained as usize;
+        indented.push(format!("{}{}", "    ".repeat(depth), line));
+
+        if let Some(switch) = blocks.last_mut().filter(|block| block.is_switch && is_case) {
+            switch.in_case = true;
+        }
+
+        let net = opened as isize - closed as isize + closes_block as isize;
+        if net > 0 {
+            blocks.push(Block {
+                is_switch: line.starts_with("switch "),
+                is_chained,
+                in_case: false,
+            });
+        } else if net < 0 && !closes_block {
+            blocks.pop();
+        }
+    }
+
+    indented
+}
+
+/// A block that the current line is nested in.
+struct Block {
+    is_switch: bool,
+    /// Whether the block was opened by a chained method call, such as `.handleEvents(`.
+    is_chained: bool,
+    /// Whether we are in one of the `case`s of a `switch`.
+    in_case: bool,
+}
+
+impl Block {
+    fn depth(&self) -> usize {
+        1 + self.is_chained as usize + self.in_case as usize
+    }
+}
+
+/// Count the opening and closing brackets in a line, ignoring string literals and comments.
+fn count_brackets(line: &str) -> (usize, usize) {
+    let mut opened = 0;
+    let mut closed = 0;
+
+    let mut in_string = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if in_string => {
+                chars.next();
+            }
+            '"' => in_string = !in_string,
+            '/' if !in_string && chars.peek() == Some(&'/') => break,
+            '{' | '(' | '[' if !in_string => opened += 1,
+            '}' | ')' | ']' if !in_string => closed += 1,
+            _ => {}
+        }
+    }
+
+    (opened, closed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify that we collapse blank lines and remove trailing whitespace.
+    #[test]
+    fn tidies_whitespace() {
+        let swift = "\n\npublic func a() {\n\n    a()  \n\n}\n\n\n\npublic func b() {\n}\n\n";
+
+        assert_eq!(
+            tidy_swift_whitespace(swift),
+            "public func a() {\n    a()\n}\n\npublic func b() {\n}\n"
+        );
+    }
+
+    /// Verify that a module that doesn't generate any Swift code stays empty.
+    #[test]
+    fn empty_code_stays_empty() {
+        assert_eq!(tidy_swift_whitespace("\n\n \n"), "");
+    }
+
+    /// Verify that we indent every line by four spaces for each block that it is nested in, no
+    /// matter how it was indented before.
+    #[test]
+    fn reindents_nested_blocks() {
+        let swift = r#"
+public class Foo {
+  public func a() -> Bar {
+            if true {
+        return Bar(values: [
+    1,
+                ])
+      } else {
+    return Bar(values: [])
+  }
+  }
+}
+"#;
+
+        assert_eq!(
+            tidy_swift_whitespace(swift),
+            r#"public class Foo {
+    public func a() -> Bar {
+        if true {
+            return Bar(values: [
+                1,
+            ])
+        } else {
+            return Bar(values: [])
+        }
+    }
+}
+"#
+        );
+    }
+
+    /// Verify that a line that opens more than one bracket only indents the lines after it once.
+    #[test]
+    fn one_level_of_nesting_per_line() {
+        let swift = r#"
+return await withCheckedContinuation({ (continuation: CheckedContinuation<(), Never>) in
+let callback = { rustFnRetVal in
+continuation.resume(with: rustFnRetVal)
+}
+})
+"#;
+
+        assert_eq!(
+            tidy_swift_whitespace(swift),
+            r#"return await withCheckedContinuation({ (continuation: CheckedContinuation<(), Never>) in
+    let callback = { rustFnRetVal in
+        continuation.resume(with: rustFnRetVal)
+    }
+})
+"#
+        );
+    }
+
+    /// Verify that we indent the cases of a switch statement and the statements in each case.
+    #[test]
+    fn indents_switch_cases() {
+        let swift = r#"
+switch self {
+case .A:
+return 1
+case .B:
+if true {
+return 2
+}
+return 3
+default:
+fatalError("Unreachable")
+}
+"#;
+
+        assert_eq!(
+            tidy_swift_whitespace(swift),
+            r#"switch self {
+    case .A:
+        return 1
+    case .B:
+        if true {
+            return 2
+        }
+        return 3
+    default:
+        fatalError("Unreachable")
+}
+"#
+        );
+    }
+
+    /// Verify that chained method calls are indented past the expression that they continue.
+    #[test]
+    fn indents_chained_calls() {
+        let swift = r#"
+Deferred {
+return subject
+.handleEvents(
+receiveCancel: {
+subscription = nil
+}
+)
+.eraseToAnyPublisher()
+}
+.eraseToAnyPublisher()
+"#;
+
+        assert_eq!(
+            tidy_swift_whitespace(swift),
+            r#"Deferred {
+    return subject
+        .handleEvents(
+            receiveCancel: {
+                subscription = nil
+            }
+        )
+        .eraseToAnyPublisher()
+}
+.eraseToAnyPublisher()
+"#
+        );
+    }
+
+    /// Verify that we ignore brackets in strings and comments, and leave compiler directives as
+    /// they are.
+    #[test]
+    fn ignores_strings_comments_and_directives() {
+        let swift = r#"
+#if canImport(Combine)
+func a() {
+print("{ \"(")
+// }
+#sourceLocation()
+}
+#endif
+"#;
+
+        assert_eq!(
+            tidy_swift_whitespace(swift),
+            r#"#if canImport(Combine)
+func a() {
+    print("{ \"(")
+    // }
+#sourceLocation()
+}
+#endif
+"#
+        );
+    }
+}
@@ -1,6 +1,8 @@
 use crate::bridged_type::{fn_arg_name, BridgeableType, BridgedType, StdLibType, TypePosition};
+use crate::codegen::generate_swift::swift_doc_comment;
 use crate::parse::{HostLang, TypeDeclaration};
-use crate::{ParsedExternFn, TypeDeclarations, SWIFT_BRIDGE_PREFIX};
+use crate::parsed_extern_fn::{swift_param_name, SwiftProperty};
+use crate::{PanicPolicy, ParsedExternFn, TypeDeclarations, SWIFT_BRIDGE_PREFIX};
 use quote::ToTokens;
 use std::ops::Deref;
 use syn::{Path, ReturnType, Type};
@@ -27,12 +29,15 @@ pub(super) fn gen_func_swift_calls_rust(
 
     let maybe_type_name_segment = if let Some(ty) = function.associated_type.as_ref() {
         match ty {
-            TypeDeclaration::Shared(_) => {
-                //
-                todo!()
+            TypeDeclaration::Shared(ty) => {
+                format!("${}", ty.name())
             }
             TypeDeclaration::Opaque(ty) => {
-                format!("${}", ty.to_string())
+                format!(
+                    "${}{}",
+                    ty.to_string(),
+                    ty.generics.dollar_prefixed_generics_string()
+                )
             }
         }
     } else {
@@ -42,7 +47,7 @@ pub(super) fn gen_func_swift_calls_rust(
     let maybe_static_class_func = if function.associated_type.is_some()
         && (!function.is_method() && !function.is_swift_initializer)
     {
-        if function.is_copy_method_on_opaque_type() {
+        if function.is_copy_method_on_opaque_type() || function.is_associated_to_shared_type() {
             "static "
         } else {
             "class "
@@ -53,7 +58,13 @@ pub(super) fn gen_func_swift_calls_rust(
 
     let public_func_fn_name = if function.is_swift_initializer {
         if function.is_copy_method_on_opaque_type() {
-            "public init".to_string()
+            if function.is_failable_swift_initializer() {
+                "public init?".to_string()
+            } else {
+                "public init".to_string()
+            }
+        } else if function.is_failable_swift_initializer() {
+            "public convenience init?".to_string()
         } else {
             "public convenience init".to_string()
         }
@@ -77,6 +88,12 @@ pub(super) fn gen_func_swift_calls_rust(
         type_name_segment = maybe_type_name_segment,
         call_fn = call_fn
     );
+    // After a panic the Rust function returns a zeroed value, so we throw before we look at it.
+    let call_rust = if function.panic_handling(types) == PanicPolicy::Throw {
+        format!("try __swift_bridge__throwIfRustPanicked({})", call_rust)
+    } else {
+        call_rust
+    };
     let mut call_rust = if function.sig.asyncness.is_some() {
         call_rust
     } else if function.is_swift_initializer {
@@ -144,7 +161,7 @@ pub(super) fn gen_func_swift_calls_rust(
         }
         let bridged_arg = bridged_arg.unwrap();
 
-        let arg_name = fn_arg_name(arg).unwrap().to_string();
+        let arg_name = swift_param_name(&fn_arg_name(arg).unwrap().to_string());
 
         // TODO: Refactor to make less duplicative
         match bridged_arg {
@@ -175,7 +192,17 @@ pub(super) fn gen_func_swift_calls_rust(
     }
 
     if function.is_swift_initializer {
-        if function.is_copy_method_on_opaque_type() {
+        if function.is_failable_swift_initializer() {
+            if function.is_copy_method_on_opaque_type() {
+                call_rust = format!(
+                    "let maybeSelf = {call_rust}\n{indentation}    guard maybeSelf.is_some else {{ return nil }}\n{indentation}    self.bytes = maybeSelf.val"
+                )
+            } else {
+                call_rust = format!(
+                    "guard let ptr = {call_rust} else {{ return nil }}\n{indentation}    self.init(ptr: ptr)"
+                )
+            }
+        } else if function.is_copy_method_on_opaque_type() {
             call_rust = format!("self.bytes = {}", call_rust)
         } else {
             call_rust = format!("self.init(ptr: {})", call_rust)
@@ -190,6 +217,16 @@ pub(super) fn gen_func_swift_calls_rust(
 
     let maybe_generics = function.maybe_swift_generics(types);
 
+    // Swift warns about `@discardableResult` on a function that doesn't return a value.
+    let maybe_discardable_result = if function.is_swift_discardable_result
+        && !function.is_swift_initializer
+        && !maybe_return.is_empty()
+    {
+        format!("@discardableResult\n{}", indentation)
+    } else {
+        "".to_string()
+    };
+
     let func_definition = if function.sig.asyncness.is_some() {
         let func_ret_ty = function.return_ty_built_in(types).unwrap();
         let rust_fn_ret_ty =
@@ -280,11 +317,12 @@ return{maybe_try}await {with_checked_continuation_function_name}({{ (continuatio
         let fn_body_indented = fn_body_indented.trim_end();
 
         format!(
-            r#"{indentation}{maybe_static_class_func}{swift_class_func_name}{maybe_generics}({params}) async{maybe_ret} {{
+            r#"{indentation}{maybe_discardable_result}{maybe_static_class_func}{swift_class_func_name}{maybe_generics}({params}) async{maybe_ret} {{
 {fn_body_indented}
 {indentation}}}
 {callback_wrapper}"#,
             indentation = indentation,
+            maybe_discardable_result = maybe_discardable_result,
             maybe_static_class_func = maybe_static_class_func,
             swift_class_func_name = public_func_fn_name,
             maybe_generics = maybe_generics,
@@ -293,12 +331,17 @@ return{maybe_try}await {with_checked_continuation_function_name}({{ (continuatio
             fn_body_indented = fn_body_indented,
             callback_wrapper = callback_wrapper
         )
+    } else if function.is_swift_subscript {
+        gen_swift_subscript(function, &call_rust, &maybe_return, types)
+    } else if let Some(swift_property) = &function.swift_property {
+        gen_swift_property(function, swift_property, &call_rust, types)
     } else {
         format!(
-            r#"{indentation}{maybe_static_class_func}{swift_class_func_name}{maybe_generics}({params}){maybe_ret} {{
+            r#"{indentation}{maybe_discardable_result}{maybe_static_class_func}{swift_class_func_name}{maybe_generics}({params}){maybe_ret} {{
 {indentation}    {call_rust}
 {indentation}}}"#,
             indentation = indentation,
+            maybe_discardable_result = maybe_discardable_result,
             maybe_static_class_func = maybe_static_class_func,
             swift_class_func_name = public_func_fn_name,
             maybe_generics = maybe_generics,
@@ -307,5 +350,120 @@ return{maybe_try}await {with_checked_continuation_function_name}({{ (continuatio
             call_rust = call_rust,
         )
     };
-    func_definition
+    let maybe_deprecated = match &function.deprecation {
+        Some(deprecation) => format!(
+            "{}{}\n",
+            indentation,
+            deprecation.to_swift_available_attribute()
+        ),
+        None => "".to_string(),
+    };
+
+    let func_definition = format!(
+        "{}{}{}",
+        swift_doc_comment(function.doc_comment.as_deref(), indentation),
+        maybe_deprecated,
+        func_definition
+    );
+
+    match function.swift_access {
+        Some(access) => access.apply(&func_definition),
+        None => func_definition,
+    }
+}
+
+/// Generate a Swift computed property's accessor.
+///
+/// A getter is a read-only property on the `SomeTypeRef` class. A setter overrides that property
+/// on the `SomeTypeRefMut` class, since only a mutable reference is allowed to set the property.
+///
+/// A Rust `&str` or `String` property is seen on the Swift side as a `String`.
+fn gen_swift_property(
+    function: &ParsedExternFn,
+    swift_property: &SwiftProperty,
+    call_rust: &str,
+    types: &TypeDeclarations,
+) -> String {
+    match swift_property {
+        SwiftProperty::Getter { name } => {
+            let ty = function
+                .return_ty_built_in(types)
+                .unwrap()
+                .to_swift_type(TypePosition::FnReturn(HostLang::Rust), types);
+            let (ty, call_rust) = if is_rust_string(&ty) {
+                ("String".to_string(), format!("{call_rust}.toString()"))
+            } else {
+                (ty, call_rust.to_string())
+            };
+
+            format!(
+                r#"    public var {name}: {ty} {{
+        {call_rust}
+    }}"#
+            )
+        }
+        SwiftProperty::Setter { name, .. } => {
+            let arg = &function.func.sig.inputs[1];
+            let arg_name = swift_param_name(&fn_arg_name(arg).unwrap().to_string());
+            let ty = BridgedType::new_with_fn_arg(arg, types)
+                .unwrap()
+                .to_swift_type(TypePosition::FnReturn(HostLang::Rust), types);
+            let ty = if is_rust_string(&ty) {
+                "String".to_string()
+            } else {
+                ty
+            };
+            let call_rust = call_rust.replace('\n', "\n    ");
+
+            format!(
+                r#"    public override var {name}: {ty} {{
+        get {{
+            super.{name}
+        }}
+        set({arg_name}) {{
+            {call_rust}
+        }}
+    }}"#
+            )
+        }
+    }
+}
+
+/// Generate a Swift subscript, such as `subscript(index: Int) -> UInt8`.
+///
+/// A `usize` index is seen on the Swift side as an `Int`, like the indices of Swift's own
+/// collections.
+fn gen_swift_subscript(
+    function: &ParsedExternFn,
+    call_rust: &str,
+    ret: &str,
+    types: &TypeDeclarations,
+) -> String {
+    let arg = &function.func.sig.inputs[1];
+    let arg_name = swift_param_name(&fn_arg_name(arg).unwrap().to_string());
+    let index = BridgedType::new_with_fn_arg(arg, types).unwrap();
+    let maybe_generics = function.maybe_swift_generics(types);
+
+    let (index_ty, body) = if matches!(index, BridgedType::StdLib(StdLibType::Usize)) {
+        (
+            "Int".to_string(),
+            format!("let {arg_name} = UInt({arg_name})\n        return {call_rust}"),
+        )
+    } else {
+        (
+            index.to_swift_type(TypePosition::FnArg(HostLang::Rust, 0), types),
+            call_rust.to_string(),
+        )
+    };
+
+    format!(
+        r#"    public subscript{maybe_generics}({arg_name}: {index_ty}){ret} {{
+        {body}
+    }}"#
+    )
+}
+
+/// Whether the Swift type is one of the types that Swift sees a Rust `&str` or `String` as.
+fn is_rust_string(swift_ty: &str) -> bool {
+    swift_ty == "RustStr" || swift_ty == "RustString"
 }
@@ -1,4 +1,4 @@
-use crate::codegen::generate_swift::generate_swift_class_methods;
+use crate::codegen::generate_swift::{generate_swift_class_methods, swift_doc_comment};
 use crate::parse::OpaqueForeignTypeDeclaration;
 use crate::{ParsedExternFn, TypeDeclarations, SWIFT_BRIDGE_PREFIX};
 use std::collections::HashMap;
@@ -13,21 +13,42 @@ pub(super) fn generate_opaque_copy_struct(
     let type_name = &ty.ty.to_string();
 
     let class_methods = generate_swift_class_methods(
-        &type_name,
+        &ty.ffi_repr_name_string(),
         associated_funcs_and_methods,
         types,
         swift_bridge_path,
     );
 
+    // Methods on a generic type's monomorphization are only available when the generic
+    // placeholders are the monomorphization's concrete types.
+    let extended_type = if ty.generics.is_empty() {
+        type_name.to_string()
+    } else {
+        format!(
+            "{}\nwhere {}",
+            type_name,
+            ty.generics.rust_opaque_type_swift_generic_bounds(types)
+        )
+    };
+
     let mut extensions = "".to_string();
 
-    append_methods_extension(&mut extensions, type_name, &class_methods.initializers);
+    append_methods_extension(&mut extensions, &extended_type, &class_methods.initializers);
     append_methods_extension(
         &mut extensions,
-        type_name,
+        &extended_type,
         &class_methods.owned_self_methods,
     );
-    append_methods_extension(&mut extensions, type_name, &class_methods.ref_self_methods);
+    append_methods_extension(
+        &mut extensions,
+        &extended_type,
+        &class_methods.ref_self_methods,
+    );
+    append_methods_extension(
+        &mut extensions,
+        &extended_type,
+        &class_methods.ref_properties,
+    );
 
     if class_methods.owned_self_methods.len() > 0 {};
 
@@ -51,23 +72,27 @@ fn generate_struct_definition(
     let type_name = ty.ty.to_string();
     let generics = ty.generics.angle_bracketed_generic_placeholders_string();
 
+    let doc_comment = swift_doc_comment(ty.attributes.doc_comment.as_deref(), "");
+
     let declare_struct = if ty.generics.is_empty() {
         format!(
-            r#"public struct {type_name} {{
+            r#"{doc_comment}public struct {type_name} {{
     fileprivate var bytes: {prefix}${type_name}
 
     func intoFfiRepr() -> {prefix}${type_name} {{
         bytes
     }}
 }}"#,
+            doc_comment = doc_comment,
             prefix = SWIFT_BRIDGE_PREFIX,
             type_name = type_name,
         )
     } else {
         format!(
-            r#"public struct {type_name}{generics} {{
+            r#"{doc_comment}public struct {type_name}{generics} {{
     fileprivate var bytes: SwiftBridgeGenericCopyTypeFfiRepr
 }}"#,
+            doc_comment = doc_comment,
             type_name = type_name,
             generics = generics
         )
@@ -109,11 +134,122 @@ extension {ffi_repr_name}: SwiftBridgeGenericCopyTypeFfiRepr {{}}"#,
         )
     };
 
+    let mut protocols = "".to_string();
+    if ty.generics.is_empty() && ty.attributes.equatable {
+        protocols += &format!(
+            r#"
+extension {type_name}: Equatable {{
+    public static func == (lhs: {type_name}, rhs: {type_name}) -> Bool {{
+        {prefix}${type_name}$_partial_eq(lhs.intoFfiRepr(), rhs.intoFfiRepr())
+    }}
+}}"#,
+            prefix = SWIFT_BRIDGE_PREFIX,
+            type_name = type_name,
+        );
+    }
+    if ty.generics.is_empty() && ty.attributes.comparable {
+        protocols += &format!(
+            r#"
+extension {type_name}: Comparable {{
+    public static func < (lhs: {type_name}, rhs: {type_name}) -> Bool {{
+        {prefix}${type_name}$_less_than(lhs.intoFfiRepr(), rhs.intoFfiRepr())
+    }}
+}}"#,
+            prefix = SWIFT_BRIDGE_PREFIX,
+            type_name = type_name,
+        );
+    }
+    if ty.generics.is_empty() && !ty.attributes.operators.is_empty() {
+        let operators = ty
+            .attributes
+            .operators
+            .iter()
+            .map(|operator| {
+                if operator.is_unary() {
+                    format!(
+                        r#"
+    public static prefix func {symbol} (operand: {type_name}) -> {type_name} {{
+        {prefix}${type_name}$_{method}(operand.intoFfiRepr()).intoSwiftRepr()
+    }}"#,
+                        symbol = operator.swift_symbol(),
+                        method = operator.method_name(),
+                        prefix = SWIFT_BRIDGE_PREFIX,
+                        type_name = type_name,
+                    )
+                } else {
+                    format!(
+                        r#"
+    public static func {symbol} (lhs: {type_name}, rhs: {type_name}) -> {type_name} {{
+        {prefix}${type_name}$_{method}(lhs.intoFfiRepr(), rhs.intoFfiRepr()).intoSwiftRepr()
+    }}"#,
+                        symbol = operator.swift_symbol(),
+                        method = operator.method_name(),
+                        prefix = SWIFT_BRIDGE_PREFIX,
+                        type_name = type_name,
+                    )
+                }
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+        protocols += &format!(
+            r#"
+extension {type_name} {{{operators}
+}}"#,
+            type_name = type_name,
+            operators = operators,
+        );
+    }
+    if ty.generics.is_empty() && ty.attributes.hashable {
+        protocols += &format!(
+            r#"
+extension {type_name}: Hashable {{
+    public func hash(into hasher: inout Hasher) {{
+        hasher.combine({prefix}${type_name}$_hash(self.intoFfiRepr()))
+    }}
+}}"#,
+            prefix = SWIFT_BRIDGE_PREFIX,
+            type_name = type_name,
+        );
+    }
+
+    if ty.generics.is_empty() && ty.attributes.custom_string_convertible {
+        protocols += &format!(
+            r#"
+extension {type_name}: CustomStringConvertible {{
+    public var description: String {{
+        RustString(ptr: {prefix}${type_name}$_description(self.intoFfiRepr())).toString()
+    }}
+}}"#,
+            prefix = SWIFT_BRIDGE_PREFIX,
+            type_name = type_name,
+        );
+    }
+    if ty.generics.is_empty() && ty.attributes.custom_debug_string_convertible {
+        protocols += &format!(
+            r#"
+extension {type_name}: CustomDebugStringConvertible {{
+    public var debugDescription: String {{
+        RustString(ptr: {prefix}${type_name}$_debug_description(self.intoFfiRepr())).toString()
+    }}
+}}"#,
+            prefix = SWIFT_BRIDGE_PREFIX,
+            type_name = type_name,
+        );
+    }
+    if ty.generics.is_empty() && ty.attributes.sendable {
+        protocols += &format!(
+            r#"
+extension {type_name}: @unchecked Sendable {{}}"#,
+            type_name = type_name,
+        );
+    }
+
     format!(
         r#"{declare_struct}
-{ffi_repr_conversion}"#,
+{ffi_repr_conversion}{protocols}"#,
         declare_struct = declare_struct,
-        ffi_repr_conversion = ffi_repr_conversion
+        ffi_repr_conversion = ffi_repr_conversion,
+        protocols = protocols
     )
 }
 
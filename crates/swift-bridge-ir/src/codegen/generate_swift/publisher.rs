@@ -0,0 +1,63 @@
+use crate::bridged_type::{BridgedType, TypePosition};
+use crate::parse::{HostLang, TypeDeclaration, TypeDeclarations};
+use crate::ParsedExternFn;
+
+/// Generate the Combine publisher that wraps a `#[swift_bridge(publisher = "..")]` method.
+///
+/// Each subscriber registers its own callback with the Rust event source, and holds on to the
+/// subscription handle that the method returns. When the subscriber cancels, the handle gets
+/// freed, which drops it on the Rust side so that the Rust event source can stop sending events.
+///
+/// Combine is not available on every platform, so the publisher is only compiled when Combine can
+/// be imported.
+pub(super) fn gen_swift_publisher(function: &ParsedExternFn, types: &TypeDeclarations) -> String {
+    let (publisher, callback) = match (
+        function.swift_publisher.as_ref(),
+        function.publisher_callback(types),
+    ) {
+        (Some(publisher), Some(callback)) => (publisher, callback),
+        _ => return "".to_string(),
+    };
+    let ty_name = match function.associated_type.as_ref() {
+        Some(TypeDeclaration::Opaque(ty)) => ty.ty_name_ident(),
+        _ => return "".to_string(),
+    };
+
+    let fn_name = function
+        .swift_name_override
+        .as_ref()
+        .map(|name| name.value())
+        .unwrap_or_else(|| function.sig.ident.to_string());
+    let event = callback.params[0].to_swift_type(TypePosition::FnReturn(HostLang::Rust), types);
+    let subscription = BridgedType::new_with_return_type(&function.sig.output, types)
+        .map(|ty| ty.to_swift_type(TypePosition::FnReturn(HostLang::Rust), types))
+        .unwrap_or_default();
+
+    format!(
+        r#"#if canImport(Combine)
+import Combine
+
+extension {ty_name}Ref {{
+    public var {publisher}: AnyPublisher<{event}, Never> {{
+        Deferred {{ () -> AnyPublisher<{event}, Never> in
+            let subject = PassthroughSubject<{event}, Never>()
+            var subscription: {subscription}? = nil
+
+            return subject
+                .handleEvents(
+                    receiveSubscription: {{ _ in
+                        subscription = self.{fn_name}({{ event in subject.send(event) }})
+                    }},
+                    receiveCancel: {{
+                        subscription = nil
+                    }}
+                )
+                .eraseToAnyPublisher()
+        }}
+        .eraseToAnyPublisher()
+    }}
+}}
+#endif
+"#
+    )
+}
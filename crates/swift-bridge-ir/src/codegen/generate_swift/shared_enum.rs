@@ -51,9 +51,13 @@ impl SwiftBridgeModule {
                     )
                 }
                 StructFields::Unit => {
+                    let maybe_raw_value = match variant.swift_raw_value() {
+                        Some(raw_value) => format!(" = {raw_value}"),
+                        None => "".to_string(),
+                    };
                     format!(
                         r#"
-    case {name}"#,
+    case {name}{maybe_raw_value}"#,
                         name = variant.name
                     )
                 }
@@ -147,8 +151,64 @@ extension {enum_name}: CustomDebugStringConvertible {{
             "".to_string()
         };
 
+        // serde serializes a unit variant as a string holding the variant's name, so we
+        // implement Codable by hand instead of relying on Swift's synthesized implementation.
+        let mut codable_impl = "".to_string();
+        if shared_enum.derive.serialize {
+            let mut encode_cases = "".to_string();
+            for variant in shared_enum.variants.iter() {
+                encode_cases += &format!(
+                    r#"
+        case .{variant}:
+            try container.encode("{variant}")"#,
+                    variant = variant.name
+                );
+            }
+            codable_impl += &format!(
+                r#"
+extension {enum_name}: Encodable {{
+    public func encode(to encoder: Encoder) throws {{
+        var container = encoder.singleValueContainer()
+        switch self {{{encode_cases}
+        }}
+    }}
+}}"#
+            );
+        }
+        if shared_enum.derive.deserialize {
+            let mut decode_cases = "".to_string();
+            for variant in shared_enum.variants.iter() {
+                decode_cases += &format!(
+                    r#"
+        case "{variant}":
+            self = .{variant}"#,
+                    variant = variant.name
+                );
+            }
+            codable_impl += &format!(
+                r#"
+extension {enum_name}: Decodable {{
+    public init(from decoder: Decoder) throws {{
+        let container = try decoder.singleValueContainer()
+        let variant = try container.decode(String.self)
+        switch variant {{{decode_cases}
+        default:
+            throw DecodingError.dataCorruptedError(in: container, debugDescription: "Unknown {enum_name} variant \(variant)")
+        }}
+    }}
+}}"#
+            );
+        }
+
+        // Rust's discriminants default to `isize`, which is a Swift `Int`.
+        let maybe_raw_value_type = if shared_enum.has_explicit_discriminants() {
+            ": Int"
+        } else {
+            ""
+        };
+
         let swift_enum = format!(
-            r#"public enum {enum_name} {{{variants}}}
+            r#"public enum {enum_name}{maybe_raw_value_type} {{{variants}}}
 extension {enum_name} {{
     func intoFfiRepr() -> {ffi_repr_name} {{
         switch self {{{convert_swift_to_ffi_repr}}}
@@ -176,7 +236,7 @@ extension {option_ffi_name} {{
             return {option_ffi_name}(is_some: false, val: {ffi_repr_name}())
         }}
     }}
-}}{vectorizable_impl}{derive_debug_impl}"#,
+}}{vectorizable_impl}{derive_debug_impl}{codable_impl}"#,
             enum_name = enum_name,
             enum_ffi_name = enum_ffi_name,
             option_ffi_name = option_ffi_name,
@@ -47,6 +47,18 @@ impl SwiftBridgeModule {
                 let convert_ffi_repr_to_swift =
                     shared_struct.convert_ffi_expression_to_swift("self", &self.types);
 
+                // Swift synthesizes a Codable implementation that uses the field names as keys,
+                // which is what serde does for a struct with named fields.
+                let codable = match (
+                    shared_struct.derives.serialize,
+                    shared_struct.derives.deserialize,
+                ) {
+                    (true, true) => format!("\nextension {}: Codable {{}}", struct_name),
+                    (true, false) => format!("\nextension {}: Encodable {{}}", struct_name),
+                    (false, true) => format!("\nextension {}: Decodable {{}}", struct_name),
+                    (false, false) => "".to_string(),
+                };
+
                 // No need to generate any code. Swift will automatically generate a
                 //  struct from our C header typedef that we generate for this struct.
                 let swift_struct = format!(
@@ -82,7 +94,7 @@ extension {option_ffi_name} {{
             return {option_ffi_name}(is_some: false, val: {ffi_repr_name}())
         }}
     }}
-}}"#,
+}}{codable}"#,
                     struct_name = struct_name,
                     initializer_params = initializer_params,
                     initializer_body = initializer_body,
@@ -90,7 +102,8 @@ extension {option_ffi_name} {{
                     ffi_repr_name = shared_struct.ffi_name_string(),
                     option_ffi_name = option_ffi_name,
                     convert_swift_to_ffi_repr = convert_swift_to_ffi_repr,
-                    convert_ffi_repr_to_swift = convert_ffi_repr_to_swift
+                    convert_ffi_repr_to_swift = convert_ffi_repr_to_swift,
+                    codable = codable
                 );
 
                 Some(swift_struct)
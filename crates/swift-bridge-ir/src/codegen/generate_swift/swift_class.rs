@@ -1,8 +1,13 @@
-use crate::codegen::generate_swift::{generate_swift_class_methods, ClassProtocols};
-use crate::parse::OpaqueForeignTypeDeclaration;
+use crate::bridged_type::{pat_type_pat_is_self, BridgedType, TypePosition};
+use crate::codegen::generate_swift::{
+    generate_swift_class_methods, swift_doc_comment, ClassMethods, ClassProtocols,
+};
+use crate::parse::{HostLang, OpaqueForeignTypeDeclaration};
+use crate::parsed_extern_fn::swift_param_name;
 use crate::{ParsedExternFn, TypeDeclarations, SWIFT_BRIDGE_PREFIX};
+use quote::{format_ident, ToTokens};
 use std::collections::HashMap;
-use syn::Path;
+use syn::{FnArg, Path};
 
 pub(super) fn generate_swift_class(
     ty: &OpaqueForeignTypeDeclaration,
@@ -11,35 +16,203 @@ pub(super) fn generate_swift_class(
     types: &TypeDeclarations,
     swift_bridge_path: &Path,
 ) -> String {
-    let type_name = ty.to_string();
-
     let class_methods = generate_swift_class_methods(
-        &type_name,
+        &ty.ffi_repr_name_string(),
         associated_funcs_and_methods,
         types,
         swift_bridge_path,
     );
 
-    create_class_declaration(
-        ty,
-        class_protocols,
-        &class_methods.initializers,
-        &class_methods.owned_self_methods,
-        &class_methods.ref_self_methods,
-        &class_methods.ref_mut_self_methods,
-        types,
+    let class = create_class_declaration(ty, class_protocols, &class_methods, types);
+    let observable_object = generate_observable_object(ty, associated_funcs_and_methods);
+    let actor = generate_actor(ty, associated_funcs_and_methods, types);
+
+    format!("{class}{observable_object}{actor}")
+}
+
+/// Generate the Swift `actor` that wraps a `#[swift_bridge(Actor)]` type.
+///
+/// The actor owns the Rust value and forwards each of the type's `&self` and `&mut self` methods
+/// to it, so Swift serializes access to the value and callers outside of the actor need to `await`
+/// its methods.
+fn generate_actor(
+    ty: &OpaqueForeignTypeDeclaration,
+    associated_funcs_and_methods: &HashMap<String, Vec<&ParsedExternFn>>,
+    types: &TypeDeclarations,
+) -> String {
+    if !ty.attributes.actor {
+        return "".to_string();
+    }
+
+    let methods = associated_funcs_and_methods
+        .get(&ty.ffi_repr_name_string())
+        .map(|funcs| funcs.as_slice())
+        .unwrap_or_default()
+        .iter()
+        .filter(|f| {
+            f.self_reference().is_some() && f.swift_property.is_none() && !f.is_swift_subscript
+        });
+
+    let mut forwarded_methods = "".to_string();
+    for method in methods {
+        let fn_name = method
+            .swift_name_override
+            .as_ref()
+            .map(|name| name.value())
+            .unwrap_or_else(|| method.sig.ident.to_string());
+        let maybe_generics = method.maybe_swift_generics(types);
+        let params = method.to_swift_param_names_and_types(false, types);
+        let maybe_ret = method.to_swift_return_type(types);
+        // A `RustString` isn't `Sendable`, so it can't be returned across the actor's boundary.
+        let (maybe_ret, maybe_to_string) =
+            if let Some(prefix) = maybe_ret.strip_suffix("-> RustString") {
+                (format!("{}-> String", prefix), ".toString()")
+            } else if let Some(prefix) = maybe_ret.strip_suffix("-> Optional<RustString>") {
+                (format!("{}-> Optional<String>", prefix), "?.toString()")
+            } else {
+                (maybe_ret, "")
+            };
+
+        let args = method
+            .sig
+            .inputs
+            .iter()
+            .filter_map(|arg| match arg {
+                FnArg::Typed(pat_ty) if !pat_type_pat_is_self(pat_ty) => Some(pat_ty),
+                _ => None,
+            })
+            .map(|pat_ty| {
+                let arg_name = pat_ty.pat.to_token_stream().to_string();
+                let swift_arg_name = swift_param_name(&arg_name);
+                match method.argument_labels.get(&format_ident!("{}", arg_name)) {
+                    Some(label) => format!("{}: {}", label.value(), swift_arg_name),
+                    None => swift_arg_name,
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        let is_async = method.sig.asyncness.is_some();
+        let maybe_async = if is_async { " async" } else { "" };
+        let maybe_try = if maybe_ret.starts_with(" throws") {
+            "try "
+        } else {
+            ""
+        };
+        let maybe_await = if is_async { "await " } else { "" };
+        let maybe_discardable_result =
+            if method.is_swift_discardable_result && !maybe_ret.is_empty() {
+                "@discardableResult\n    "
+            } else {
+                ""
+            };
+        // Forwarding to a deprecated method would warn unless the forwarding method is also
+        // deprecated.
+        let maybe_deprecated = match &method.deprecation {
+            Some(deprecation) => format!("{}\n    ", deprecation.to_swift_available_attribute()),
+            None => "".to_string(),
+        };
+
+        forwarded_methods += &format!(
+            r#"
+
+    {maybe_deprecated}{maybe_discardable_result}public func {fn_name}{maybe_generics}({params}){maybe_async}{maybe_ret} {{
+        {maybe_try}{maybe_await}inner.{fn_name}({args}){maybe_to_string}
+    }}"#
+        );
+    }
+
+    // The actor holds the class and hands it to the Rust methods on any of the executor's threads,
+    // which the generated assertion that the Rust type is `Send + Sync` makes safe. A
+    // `#[swift_bridge(Sendable)]` type's class already inherits its `Ref` class's conformance.
+    let sendable = if ty.attributes.sendable {
+        "".to_string()
+    } else {
+        format!(
+            r#"
+extension {ty_name}: @unchecked Sendable {{}}"#,
+            ty_name = ty.ty_name_ident()
+        )
+    };
+
+    format!(
+        r#"{sendable}
+public actor {ty_name}Actor {{
+    let inner: {ty_name}
+
+    public init(_ inner: {ty_name}) {{
+        self.inner = inner
+    }}{forwarded_methods}
+}}"#,
+        ty_name = ty.ty_name_ident()
+    )
+}
+
+/// Generate the `ObservableObject` class that wraps a `#[swift_bridge(ObservableObject = "..")]`
+/// type, which tells SwiftUI that the wrapped value is about to change whenever the Rust type
+/// calls its change callback.
+///
+/// SwiftUI expects `objectWillChange` to be sent on the main thread, so a change that happens on
+/// another thread is sent from the main queue.
+fn generate_observable_object(
+    ty: &OpaqueForeignTypeDeclaration,
+    associated_funcs_and_methods: &HashMap<String, Vec<&ParsedExternFn>>,
+) -> String {
+    let on_change = match ty.attributes.observable_object.as_ref() {
+        Some(on_change) => on_change.value(),
+        None => return "".to_string(),
+    };
+    let on_change = associated_funcs_and_methods
+        .get(&ty.ffi_repr_name_string())
+        .and_then(|funcs| funcs.iter().find(|f| f.sig.ident == on_change))
+        .and_then(|f| f.swift_name_override.as_ref())
+        .map(|swift_name| swift_name.value())
+        .unwrap_or(on_change);
+
+    format!(
+        r#"
+#if canImport(Combine)
+import Combine
+import Foundation
+
+public final class {ty_name}Observable: ObservableObject {{
+    public let inner: {ty_name}
+
+    public init(_ inner: {ty_name}) {{
+        self.inner = inner
+
+        inner.{on_change}({{ [weak self] in
+            if Thread.isMainThread {{
+                self?.objectWillChange.send()
+            }} else {{
+                DispatchQueue.main.async {{
+                    self?.objectWillChange.send()
+                }}
+            }}
+        }})
+    }}
+}}
+#endif"#,
+        ty_name = ty.ty_name_ident()
     )
 }
 
 fn create_class_declaration(
     ty: &OpaqueForeignTypeDeclaration,
     class_protocols: &ClassProtocols,
-    initializers: &[String],
-    owned_self_methods: &[String],
-    ref_self_methods: &[String],
-    ref_mut_self_methods: &[String],
+    class_methods: &ClassMethods,
     types: &TypeDeclarations,
 ) -> String {
+    let ClassMethods {
+        initializers,
+        owned_self_methods,
+        ref_self_methods,
+        ref_mut_self_methods,
+        arc_self_methods,
+        ref_properties,
+        ref_mut_properties,
+    } = class_methods;
+
     let type_name = &ty.ty_name_ident().to_string();
     let generics = ty.generics.angle_bracketed_generic_placeholders_string();
 
@@ -47,11 +220,11 @@ fn create_class_declaration(
         let free_func_call = if ty.generics.len() == 0 {
             format!("{}${}$_free(ptr)", SWIFT_BRIDGE_PREFIX, type_name)
         } else {
-            "(self as! SwiftBridgeGenericFreer).rust_free()".to_string()
+            generic_free_func_call(ty, types)
         };
 
         format!(
-            r#"public class {type_name}{generics}: {type_name}RefMut{generics} {{
+            r#"{doc_comment}public class {type_name}{generics}: {type_name}RefMut{generics} {{
     var isOwned: Bool = true
 
     public override init(ptr: UnsafeMutableRawPointer) {{
@@ -64,6 +237,7 @@ fn create_class_declaration(
         }}
     }}
 }}"#,
+            doc_comment = swift_doc_comment(ty.attributes.doc_comment.as_deref(), ""),
             type_name = type_name,
             generics = generics,
             free_func_call = free_func_call
@@ -76,10 +250,11 @@ fn create_class_declaration(
 public class {type_name}RefMut{generics}: {type_name}Ref{generics} {{
     public override init(ptr: UnsafeMutableRawPointer) {{
         super.init(ptr: ptr)
-    }}
+    }}{properties}
 }}"#,
             type_name = type_name,
-            generics = generics
+            generics = generics,
+            properties = class_body_properties(ref_mut_properties)
         )
     };
     let mut class_ref_decl = {
@@ -90,10 +265,11 @@ public class {type_name}Ref{generics} {{
 
     public init(ptr: UnsafeMutableRawPointer) {{
         self.ptr = ptr
-    }}
+    }}{properties}
 }}"#,
             type_name = type_name,
-            generics = generics
+            generics = generics,
+            properties = class_body_properties(ref_properties)
         )
     };
     if let Some(identifiable) = class_protocols.identifiable.as_ref() {
@@ -119,16 +295,30 @@ extension {type_name}Ref: Identifiable {{{identifiable_var}}}"#,
         );
     }
 
+    let is_concrete_generic = ty.generics.len() > 0 && !ty.attributes.declare_generic;
+
+    // Methods on a generic type's monomorphization are only available when the generic
+    // placeholders are the monomorphization's concrete types.
+    let where_clause = if is_concrete_generic {
+        format!(
+            "\nwhere {}",
+            ty.generics.rust_opaque_type_swift_generic_bounds(types)
+        )
+    } else {
+        "".to_string()
+    };
+
     let initializers = if initializers.len() == 0 {
         "".to_string()
     } else {
         let initializers: String = initializers.join("\n\n");
         format!(
             r#"
-extension {type_name} {{
+extension {type_name}{where_clause} {{
 {initializers}
 }}"#,
             type_name = type_name,
+            where_clause = where_clause,
             initializers = initializers
         )
     };
@@ -139,10 +329,11 @@ extension {type_name} {{
         let owned_instance_methods: String = owned_self_methods.join("\n\n");
         format!(
             r#"
-extension {type_name} {{
+extension {type_name}{where_clause} {{
 {owned_instance_methods}
 }}"#,
             type_name = type_name,
+            where_clause = where_clause,
             owned_instance_methods = owned_instance_methods
         )
     };
@@ -153,10 +344,11 @@ extension {type_name} {{
         let ref_instance_methods: String = ref_self_methods.join("\n\n");
         format!(
             r#"
-extension {type_name}Ref {{
+extension {type_name}Ref{where_clause} {{
 {ref_instance_methods}
 }}"#,
             type_name = type_name,
+            where_clause = where_clause,
             ref_instance_methods = ref_instance_methods
         )
     };
@@ -167,15 +359,30 @@ extension {type_name}Ref {{
         let ref_mut_instance_methods: String = ref_mut_self_methods.join("\n\n");
         format!(
             r#"
-extension {type_name}RefMut {{
+extension {type_name}RefMut{where_clause} {{
 {ref_mut_instance_methods}
 }}"#,
             type_name = type_name,
+            where_clause = where_clause,
             ref_mut_instance_methods = ref_mut_instance_methods
         )
     };
 
-    let is_concrete_generic = ty.generics.len() > 0 && !ty.attributes.declare_generic;
+    // The `ArcSomeType` class is generated alongside the `Arc<SomeType>` type, since it is only
+    // needed when the type is handed out as an `Arc`.
+    let arc_instance_methods = if arc_self_methods.is_empty() {
+        "".to_string()
+    } else {
+        let arc_instance_methods: String = arc_self_methods.join("\n\n");
+        format!(
+            r#"
+extension Arc{type_name} {{
+{arc_instance_methods}
+}}"#,
+            type_name = type_name,
+            arc_instance_methods = arc_instance_methods
+        )
+    };
 
     if ty.attributes.already_declared || is_concrete_generic {
         class_decl = "".to_string();
@@ -183,21 +390,6 @@ extension {type_name}RefMut {{
         class_ref_mut_decl = "".to_string();
     }
 
-    let mut generic_freer = "".to_string();
-    if is_concrete_generic {
-        generic_freer = format!(
-            r#"
-extension {type_name}: SwiftBridgeGenericFreer
-where {swift_generic_bounds} {{
-    public func rust_free() {{
-        {free_func_name}(ptr)
-    }}
-}}"#,
-            type_name = type_name,
-            swift_generic_bounds = ty.generics.rust_opaque_type_swift_generic_bounds(types),
-            free_func_name = ty.free_rust_opaque_type_ffi_name()
-        );
-    }
     let equatable_method: String = {
         if ty.attributes.equatable {
             let ty_name = ty.ty_name_ident();
@@ -207,6 +399,58 @@ extension {ty_name}Ref: Equatable {{
     public static func == (lhs: {ty_name}Ref, rhs: {ty_name}Ref) -> Bool {{
         __swift_bridge__${ty_name}$_partial_eq(rhs.ptr, lhs.ptr)
     }}
+}}"#,
+            )
+        } else {
+            "".to_string()
+        }
+    };
+    let comparable_method: String = {
+        if ty.attributes.comparable {
+            let ty_name = ty.ty_name_ident();
+            format!(
+                r#"
+extension {ty_name}Ref: Comparable {{
+    public static func < (lhs: {ty_name}Ref, rhs: {ty_name}Ref) -> Bool {{
+        __swift_bridge__${ty_name}$_less_than(lhs.ptr, rhs.ptr)
+    }}
+}}"#,
+            )
+        } else {
+            "".to_string()
+        }
+    };
+    let operators: String = {
+        if !ty.attributes.operators.is_empty() {
+            let ty_name = ty.ty_name_ident();
+            let operators = ty
+                .attributes
+                .operators
+                .iter()
+                .map(|operator| {
+                    let symbol = operator.swift_symbol();
+                    let method = operator.method_name();
+                    if operator.is_unary() {
+                        format!(
+                            r#"
+    public static prefix func {symbol} (operand: {ty_name}Ref) -> {ty_name} {{
+        {ty_name}(ptr: __swift_bridge__${ty_name}$_{method}(operand.ptr))
+    }}"#
+                        )
+                    } else {
+                        format!(
+                            r#"
+    public static func {symbol} (lhs: {ty_name}Ref, rhs: {ty_name}Ref) -> {ty_name} {{
+        {ty_name}(ptr: __swift_bridge__${ty_name}$_{method}(lhs.ptr, rhs.ptr))
+    }}"#
+                        )
+                    }
+                })
+                .collect::<Vec<String>>()
+                .join("\n");
+            format!(
+                r#"
+extension {ty_name}Ref {{{operators}
 }}"#,
             )
         } else {
@@ -229,9 +473,87 @@ extension {ty_name}Ref: Hashable{{
             "".to_string()
         }
     };
+    let custom_string_convertible: String = {
+        if ty.attributes.custom_string_convertible {
+            format!(
+                r#"
+extension {ty_name}Ref: CustomStringConvertible {{
+    public var description: String {{
+        RustString(ptr: __swift_bridge__${ty_name}$_description(self.ptr)).toString()
+    }}
+}}"#,
+                ty_name = ty.ty_name_ident()
+            )
+        } else {
+            "".to_string()
+        }
+    };
+    let custom_debug_string_convertible: String = {
+        if ty.attributes.custom_debug_string_convertible {
+            format!(
+                r#"
+extension {ty_name}Ref: CustomDebugStringConvertible {{
+    public var debugDescription: String {{
+        RustString(ptr: __swift_bridge__${ty_name}$_debug_description(self.ptr)).toString()
+    }}
+}}"#,
+                ty_name = ty.ty_name_ident()
+            )
+        } else {
+            "".to_string()
+        }
+    };
+    let sendable: String = {
+        if ty.attributes.sendable {
+            format!(
+                r#"
+extension {ty_name}Ref: @unchecked Sendable {{}}"#,
+                ty_name = ty.ty_name_ident()
+            )
+        } else {
+            "".to_string()
+        }
+    };
+    // The `next` method takes `&mut self`, so the `SomeTypeRefMut` class is the iterator. Since a
+    // class's `next` method can advance the iterator without being `mutating`, the iterator is
+    // also its own `Sequence`.
+    let iterator: String = {
+        if ty.attributes.iterator {
+            format!(
+                r#"
+extension {ty_name}RefMut: IteratorProtocol, Sequence {{}}"#,
+                ty_name = ty.ty_name_ident()
+            )
+        } else {
+            "".to_string()
+        }
+    };
+    // Like an iterator, the stream is its own `AsyncIteratorProtocol`, since its generated
+    // `next` method takes `&mut self`.
+    let stream: String = {
+        if let Some(item) = ty.attributes.stream_item.as_ref() {
+            let element = BridgedType::new_with_type(item, types)
+                .map(|item| item.to_swift_type(TypePosition::FnReturn(HostLang::Rust), types))
+                .unwrap_or_default();
+
+            format!(
+                r#"
+extension {ty_name}RefMut: AsyncSequence, AsyncIteratorProtocol {{
+    public typealias Element = {element}
+
+    public func makeAsyncIterator() -> {ty_name}RefMut {{
+        self
+    }}
+}}"#,
+                ty_name = ty.ty_name_ident()
+            )
+        } else {
+            "".to_string()
+        }
+    };
     let class = format!(
         r#"
-{class_decl}{initializers}{owned_instance_methods}{class_ref_decl}{ref_mut_instance_methods}{class_ref_mut_decl}{ref_instance_methods}{generic_freer}{equatable_method}{hashable_method}"#,
+{class_decl}{initializers}{owned_instance_methods}{class_ref_decl}{ref_mut_instance_methods}{class_ref_mut_decl}{ref_instance_methods}{arc_instance_methods}{equatable_method}{comparable_method}{operators}{hashable_method}{custom_string_convertible}{custom_debug_string_convertible}{sendable}{iterator}{stream}"#,
         class_decl = class_decl,
         class_ref_decl = class_ref_mut_decl,
         class_ref_mut_decl = class_ref_decl,
@@ -239,9 +561,67 @@ extension {ty_name}Ref: Hashable{{
         owned_instance_methods = owned_instance_methods,
         ref_mut_instance_methods = ref_mut_instance_methods,
         ref_instance_methods = ref_instance_methods,
+        arc_instance_methods = arc_instance_methods,
         equatable_method = equatable_method,
+        comparable_method = comparable_method,
+        operators = operators,
         hashable_method = hashable_method,
+        custom_string_convertible = custom_string_convertible,
+        custom_debug_string_convertible = custom_debug_string_convertible,
+        sendable = sendable,
+        iterator = iterator,
+        stream = stream,
     );
 
     return class;
 }
+
+/// Computed properties that get declared in a class's body, after its initializer.
+fn class_body_properties(properties: &[String]) -> String {
+    properties
+        .iter()
+        .map(|property| format!("\n\n{}", property))
+        .collect()
+}
+
+/// Frees a generic opaque Rust type by calling the free function of the monomorphization whose
+/// concrete types match the class's generic placeholders.
+///
+/// We can't use a protocol conformance for each monomorphization since Swift does not allow a
+/// generic type to conform to the same protocol more than once, even with different conditional
+/// bounds.
+fn generic_free_func_call(ty: &OpaqueForeignTypeDeclaration, types: &TypeDeclarations) -> String {
+    let monomorphizations = types.types().into_iter().filter_map(|t| {
+        t.as_opaque()
+            .filter(|t| t.ty == ty.ty && !t.attributes.declare_generic && !t.generics.is_empty())
+    });
+
+    let free_func_calls = monomorphizations
+        .filter_map(|t| {
+            let checks = t
+                .generics
+                .rust_opaque_type_swift_generic_metatype_checks(types)?;
+            Some(format!(
+                r#"if {checks} {{
+                {free_func_name}(ptr)
+            }}"#,
+                checks = checks,
+                free_func_name = t.free_rust_opaque_type_ffi_name()
+            ))
+        })
+        .collect::<Vec<String>>();
+
+    if free_func_calls.is_empty() {
+        return "// There are no monomorphizations of this generic type to free.".to_string();
+    }
+
+    // Rust only hands out the declared monomorphizations, so there is no free function that we
+    // could call for any other generic types.
+    format!(
+        r#"{free_func_calls} else {{
+                fatalError("{ty} has no monomorphization for these generic types")
+            }}"#,
+        free_func_calls = free_func_calls.join(" else "),
+        ty = ty.ty
+    )
+}
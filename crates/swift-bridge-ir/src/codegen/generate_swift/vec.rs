@@ -56,6 +56,66 @@ pub(super) fn generate_vectorizable_extension(ty: &Ident) -> String {
     )
 }
 
+/// Generate the `extension MyCopyType: Vectorizable {}` for a `Copy` opaque Rust type.
+///
+/// `Copy` types are Swift structs, so elements are copied out of the `Vec` instead of being
+/// wrapped in a `Ref` or `RefMut` class. `get_mut` returns a pointer to the element instead, so
+/// that writing to its `pointee` modifies the element in the `Vec`.
+pub(super) fn generate_vectorizable_copy_type_extension(ty: &Ident) -> String {
+    format!(
+        r#"extension {ty}: Vectorizable {{
+    public static func vecOfSelfNew() -> UnsafeMutableRawPointer {{
+        __swift_bridge__$Vec_{ty}$new()
+    }}
+
+    public static func vecOfSelfFree(vecPtr: UnsafeMutableRawPointer) {{
+        __swift_bridge__$Vec_{ty}$drop(vecPtr)
+    }}
+
+    public static func vecOfSelfPush(vecPtr: UnsafeMutableRawPointer, value: {ty}) {{
+        __swift_bridge__$Vec_{ty}$push(vecPtr, value.intoFfiRepr())
+    }}
+
+    public static func vecOfSelfPop(vecPtr: UnsafeMutableRawPointer) -> Optional<Self> {{
+        let val = __swift_bridge__$Vec_{ty}$pop(vecPtr)
+        if val.is_some {{
+            return {ty}(bytes: val.val)
+        }} else {{
+            return nil
+        }}
+    }}
+
+    public static func vecOfSelfGet(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<Self> {{
+        let val = __swift_bridge__$Vec_{ty}$get(vecPtr, index)
+        if val.is_some {{
+            return {ty}(bytes: val.val)
+        }} else {{
+            return nil
+        }}
+    }}
+
+    public static func vecOfSelfGetMut(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<UnsafeMutablePointer<Self>> {{
+        let pointer = __swift_bridge__$Vec_{ty}$get_mut(vecPtr, index)
+        if pointer == nil {{
+            return nil
+        }} else {{
+            return UnsafeMutablePointer<Self>(OpaquePointer(pointer!))
+        }}
+    }}
+
+    public static func vecOfSelfAsPtr(vecPtr: UnsafeMutableRawPointer) -> UnsafePointer<Self> {{
+        UnsafePointer<Self>(OpaquePointer(__swift_bridge__$Vec_{ty}$as_ptr(vecPtr)))
+    }}
+
+    public static func vecOfSelfLen(vecPtr: UnsafeMutableRawPointer) -> UInt {{
+        __swift_bridge__$Vec_{ty}$len(vecPtr)
+    }}
+}}
+"#,
+        ty = ty.to_string()
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,66 @@
+use crate::{SwiftBridgeModule, SWIFT_BRIDGE_PREFIX};
+use proc_macro2::{Group, Literal, TokenStream, TokenTree};
+use syn::LitStr;
+
+impl SwiftBridgeModule {
+    /// Add the module's symbol prefix to every FFI symbol and C type name in the generated Swift
+    /// code or C header, such as `__swift_bridge__$some_function` ->
+    /// `__swift_bridge__$my_crate$some_function`.
+    ///
+    /// The code that a module generates only ever refers to its own symbols, or to the symbols of
+    /// modules that use the same prefix. The helpers in `swift-bridge`'s runtime, such as
+    /// `__swift_bridge__$RustString$new`, are only used by the core Swift and C code, so they keep
+    /// their names.
+    pub(crate) fn prefix_symbols(&self, code: &str) -> String {
+        match self.symbol_prefix.as_ref() {
+            Some(prefix) => prefix_symbol_names(code, prefix),
+            None => code.to_string(),
+        }
+    }
+
+    /// Add the module's symbol prefix to the symbols in the `#[export_name = "..."]` and
+    /// `#[link_name = "..."]` attributes of the generated Rust code.
+    ///
+    /// The generated Rust items themselves, such as `__swift_bridge__some_function`, are
+    /// private to the bridge module, so they don't need to be renamed.
+    pub(crate) fn prefix_symbols_in_tokens(&self, tokens: TokenStream) -> TokenStream {
+        match self.symbol_prefix.as_ref() {
+            Some(prefix) => prefix_string_literals(tokens, prefix),
+            None => tokens,
+        }
+    }
+}
+
+fn prefix_symbol_names(code: &str, prefix: &str) -> String {
+    let unprefixed = format!("{}$", SWIFT_BRIDGE_PREFIX);
+    code.replace(&unprefixed, &format!("{}{}$", unprefixed, prefix))
+}
+
+fn prefix_string_literals(tokens: TokenStream, prefix: &str) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|token| match token {
+            TokenTree::Group(group) => {
+                let stream = prefix_string_literals(group.stream(), prefix);
+                let mut prefixed = Group::new(group.delimiter(), stream);
+                prefixed.set_span(group.span());
+                TokenTree::Group(prefixed)
+            }
+            TokenTree::Literal(literal) => {
+                let value = match syn::parse2::<LitStr>(TokenTree::Literal(literal.clone()).into())
+                {
+                    Ok(lit_str) => lit_str.value(),
+                    Err(_) => return TokenTree::Literal(literal),
+                };
+                if !value.starts_with(SWIFT_BRIDGE_PREFIX) {
+                    return TokenTree::Literal(literal);
+                }
+
+                let mut prefixed = Literal::string(&prefix_symbol_names(&value, prefix));
+                prefixed.set_span(literal.span());
+                TokenTree::Literal(prefixed)
+            }
+            token => token,
+        })
+        .collect()
+}
@@ -0,0 +1,110 @@
+use proc_macro2::TokenStream;
+use std::sync::{Arc, Mutex};
+
+/// Describes how a third-party Rust type, such as `chrono::DateTime<chrono::Utc>` or
+/// `rust_decimal::Decimal`, is bridged to Swift.
+///
+/// A value of the type is converted into another type that can already be bridged, its FFI
+/// representation, before it is passed between Rust and Swift. For example, a
+/// `chrono::DateTime<chrono::Utc>` could be passed as an `i64` number of milliseconds since the
+/// Unix epoch and be seen on the Swift side as a Foundation `Date`.
+///
+/// ```no_run,ignore
+/// use proc_macro2::TokenStream;
+/// use quote::quote;
+/// use swift_bridge_ir::CustomTypeBridge;
+///
+/// struct DateTimeBridge;
+///
+/// impl CustomTypeBridge for DateTimeBridge {
+///     fn rust_type(&self) -> TokenStream {
+///         quote! { chrono::DateTime<chrono::Utc> }
+///     }
+///
+///     fn ffi_repr(&self) -> TokenStream {
+///         quote! { i64 }
+///     }
+///
+///     fn swift_type(&self) -> String {
+///         "Date".to_string()
+///     }
+///
+///     fn rust_to_ffi_repr(&self, value: &TokenStream) -> TokenStream {
+///         quote! { #value.timestamp_millis() }
+///     }
+///
+///     fn rust_from_ffi_repr(&self, value: &TokenStream) -> TokenStream {
+///         quote! { chrono::TimeZone::timestamp_millis_opt(&chrono::Utc, #value).unwrap() }
+///     }
+///
+///     fn swift_to_ffi_repr(&self, value: &str) -> String {
+///         format!("Int64({value}.timeIntervalSince1970 * 1000)")
+///     }
+///
+///     fn swift_from_ffi_repr(&self, value: &str) -> String {
+///         format!("Date(timeIntervalSince1970: Double({value}) / 1000)")
+///     }
+/// }
+/// ```
+///
+/// A bridge is used by code generation once it has been registered with
+/// [`register_custom_type_bridge`].
+pub trait CustomTypeBridge: Send + Sync {
+    /// The Rust type, such as `chrono::DateTime<chrono::Utc>`.
+    ///
+    /// Bridge modules need to refer to the type using this same path, which the generated Rust
+    /// code also uses.
+    fn rust_type(&self) -> TokenStream;
+
+    /// The type that the value gets converted to before being passed over FFI, such as `i64`,
+    /// `f64` or `String`. This must be a type that can already be bridged.
+    fn ffi_repr(&self) -> TokenStream;
+
+    /// The Swift type that the Rust type is seen as, such as `Date`.
+    fn swift_type(&self) -> String;
+
+    /// Convert a Rust expression of the type into its FFI representation.
+    fn rust_to_ffi_repr(&self, value: &TokenStream) -> TokenStream;
+
+    /// Convert a Rust expression of the FFI representation into the type.
+    fn rust_from_ffi_repr(&self, value: &TokenStream) -> TokenStream;
+
+    /// Convert a Swift expression of the Swift type into the Swift type that the FFI
+    /// representation is seen as, such as an `Int64` for an `i64`.
+    fn swift_to_ffi_repr(&self, value: &str) -> String;
+
+    /// Convert a Swift expression of the Swift type that the FFI representation is seen as into
+    /// the Swift type.
+    fn swift_from_ffi_repr(&self, value: &str) -> String;
+}
+
+static CUSTOM_TYPE_BRIDGES: Mutex<Vec<Arc<dyn CustomTypeBridge>>> = Mutex::new(Vec::new());
+
+/// Use a [`CustomTypeBridge`] for every bridge module that gets parsed after this is called.
+///
+/// The Rust code is generated by the `#[swift_bridge::bridge]` procedural macro, while the Swift
+/// and C code is generated by `swift-bridge-build`, so a bridge needs to be registered in both
+/// places. See [`crate::expand_bridge_macro`] for using a bridge when generating the Rust code.
+///
+/// A type that a bridge module declares itself, such as with `type DateTime;`, takes precedence
+/// over a registered bridge.
+pub fn register_custom_type_bridge(bridge: impl CustomTypeBridge + 'static) {
+    CUSTOM_TYPE_BRIDGES.lock().unwrap().push(Arc::new(bridge));
+}
+
+/// The registered bridge for the stringified type tokens, if there is one.
+pub(crate) fn find_custom_type_bridge(tokens: &str) -> Option<Arc<dyn CustomTypeBridge>> {
+    let tokens = without_whitespace(tokens);
+
+    CUSTOM_TYPE_BRIDGES
+        .lock()
+        .unwrap()
+        .iter()
+        .find(|bridge| without_whitespace(&bridge.rust_type().to_string()) == tokens)
+        .cloned()
+}
+
+/// `chrono :: DateTime < chrono :: Utc >` -> `chrono::DateTime<chrono::Utc>`
+fn without_whitespace(tokens: &str) -> String {
+    tokens.chars().filter(|c| !c.is_whitespace()).collect()
+}
@@ -1,6 +1,8 @@
+use crate::parse::type_to_string;
 use proc_macro2::Ident;
-use quote::ToTokens;
-use syn::{Error, FnArg, Item, Receiver};
+use quote::{quote, ToTokens};
+use syn::punctuated::Punctuated;
+use syn::{Error, Expr, FnArg, Generics, Item, ItemStatic, TraitItem, TypeParamBound};
 use syn::{ForeignItemFn, ForeignItemType, LitStr};
 use syn::{Token, Type};
 
@@ -24,7 +26,9 @@ pub(crate) enum ParseError {
     },
     /// `fn foo (&self)`
     ///           ----
-    AmbiguousSelf { self_: Receiver },
+    /// `fn foo (self: Pin<&mut Self>)`
+    ///          --------------------
+    AmbiguousSelf { self_: FnArg },
     /// fn foo (bar: &Bar);
     /// If Bar wasn't declared using a `type Bar` declaration.
     UndeclaredType { ty: Type },
@@ -42,6 +46,13 @@ pub(crate) enum ParseError {
     StructUnrecognizedAttribute { attribute: Ident },
     /// An enum was declared with an unrecognized attribute.
     EnumUnrecognizedAttribute { attribute: Ident },
+    /// An enum variant's discriminant was not an integer literal, or it was used on an enum that
+    /// has one or more variants with fields.
+    /// `enum SomeEnum { Variant = SOME_CONST }`
+    InvalidEnumDiscriminant { discriminant: Expr },
+    /// `serde::Serialize` and `serde::Deserialize` can only be derived on structs with named
+    /// fields and on enums whose variants do not have fields.
+    UnsupportedCodableType { ty: Ident },
     /// There is no reason to use `swift_repr = "class"` on an empty struct.
     /// It's extra overhead with no advantages.
     EmptyStructHasSwiftReprClass {
@@ -56,13 +67,65 @@ pub(crate) enum ParseError {
     ArgCopyAndRefMut { arg: FnArg },
     /// There was an unsupported item in the module, such as a `use` statement.
     InvalidModuleItem { item: Item },
+    /// A bridged trait can only contain methods that take `&self` or `&mut self` and that do not
+    /// have a default implementation.
+    InvalidTraitItem { item: Box<TraitItem> },
+    /// A bridged trait has generic parameters or a where clause.
+    /// `trait Greeter<T> {}`
+    TraitHasGenerics { generics: Generics },
+    /// A bridged trait has supertraits.
+    /// `trait Greeter: Clone {}`
+    TraitHasSupertraits {
+        supertraits: Punctuated<TypeParamBound, Token![+]>,
+    },
     /// The associated_to attribute is used for only an associated method.
     InvalidAssociatedTo { self_: FnArg },
+    /// A function takes a shared struct or enum as `self`. Only associated functions are
+    /// supported on shared types.
+    /// `fn method(self: &SharedStruct)`
+    MethodOnSharedType { self_: FnArg },
+    /// A `#[swift_bridge(Iterator)]` type does not have a `fn next(&mut self) -> Option<T>`
+    /// method.
+    IteratorNextNotFound { ty: Ident },
+    /// A `#[swift_bridge(ObservableObject = "...")]` type does not have the method that registers
+    /// its change callback.
+    OnChangeNotFound { ty: Ident, on_change: LitStr },
+    /// A `const` or `static` in the module has a type that can't be exposed to Swift as a
+    /// constant. Only numbers, `bool` and `&str` are supported.
+    UnsupportedConstantType { ty: Type },
+    /// A function's argument or return type uses a type that can't be bridged in that position,
+    /// such as `&Vec<u8>` or `Vec<&str>`.
+    UnsupportedType {
+        ty: Type,
+        /// A suggestion to end the error message with, such as "did you mean `&[u8]`?"
+        help: Option<String>,
+    },
+    /// A type uses a `uuid::Uuid` without enabling the `uuid` feature, which the generated Rust
+    /// code's `swift_bridge::uuid_support` lives behind.
+    UuidFeatureDisabled { ty: Type },
+    /// A `static mut` in the module, which Swift can't read without a data race.
+    MutableStatic { item_static: ItemStatic },
+    /// Two types in the module have the same name.
+    /// `type SomeType; type SomeType;`
+    DuplicateTypeDeclaration { first: Ident, duplicate: Ident },
+    /// Two functions in the module would be exported under the same symbol.
+    /// `fn some_function(); fn some_function(arg: u8);`
+    DuplicateFunction { first: Ident, duplicate: Ident },
+    /// A `#[swift_bridge(Comparable)]` type is not also `#[swift_bridge(Equatable)]`. Swift's
+    /// `Comparable` protocol inherits from `Equatable`, and `==` can't be synthesized for the
+    /// generated type.
+    ComparableWithoutEquatable { ty: Ident },
+    /// A `#[swift_bridge(Hashable)]` type is not also `#[swift_bridge(Equatable)]`. Swift's
+    /// `Hashable` protocol inherits from `Equatable`.
+    HashableWithoutEquatable { ty: Ident },
 }
 
 /// An error while parsing a function attribute.
 pub(crate) enum FunctionAttributeParseError {
     Identifiable(IdentifiableParseError),
+    Property(PropertyParseError),
+    Subscript(SubscriptParseError),
+    Publisher(PublisherParseError),
 }
 
 /// An error while parsing a function's `Identifiable` attribute.
@@ -71,6 +134,32 @@ pub(crate) enum IdentifiableParseError {
     MustBeRefSelf { fn_ident: Ident },
     /// An `Identifiable` implementation function must return a value.
     MissingReturnType { fn_ident: Ident },
+    /// A type's `#[swift_bridge(Identifiable = "...")]` getter was not declared.
+    GetterNotFound { ty: Ident, getter: LitStr },
+}
+
+/// An error while parsing a function's `property = "..."` attribute.
+pub(crate) enum PropertyParseError {
+    /// A property's getter must take `&self` and return the property's value, and its setter must
+    /// take `&mut self` and the property's new value.
+    InvalidAccessor { fn_ident: Ident },
+    /// A property's setter was declared without a getter.
+    GetterNotFound { setter: Ident, property: Ident },
+}
+
+/// An error while parsing a function's `subscript` attribute.
+pub(crate) enum SubscriptParseError {
+    /// A subscript function must take `&self` and an index, and return a value.
+    InvalidSubscript { fn_ident: Ident },
+}
+
+/// An error while parsing a function's `publisher = "..."` attribute.
+pub(crate) enum PublisherParseError {
+    /// A publisher function must take `&self` and an event callback, and return a subscription
+    /// handle.
+    InvalidRegistration { fn_ident: Ident },
+    /// The `publisher` attribute was used without enabling the `combine` feature.
+    CombineFeatureDisabled { fn_ident: Ident },
 }
 
 // <!-- ANCHOR: mdbook-parse-error-message -->
@@ -108,6 +197,9 @@ extern "Swift" {{ ... }}
 self: SomeType
 self: &SomeType
 self: &mut SomeType
+self: Pin<&mut SomeType>
+self: Box<SomeType>
+self: Arc<SomeType>
 "#,
             ),
             ParseError::UndeclaredType { ty } => {
@@ -116,9 +208,9 @@ self: &mut SomeType
                 let ty_name = ty_name.split_whitespace().last().unwrap();
 
                 let message = format!(
-                    r#"Type must be declared with `type {}`.
-"#,
-                    ty_name
+                    r#"Type must be declared with `type {ty_name}`.
+If another bridge module declares it, declare it here with `#[swift_bridge(already_declared)] type {ty_name};`
+"#
                 );
                 Error::new_spanned(ty, message)
             }
@@ -197,6 +289,53 @@ struct {struct_name};
                         );
                         Error::new_spanned(fn_ident, message)
                     }
+                    IdentifiableParseError::GetterNotFound { ty, getter } => {
+                        let message = format!(
+                            r#"Identifiable getter "{}" is not a declared method of {}."#,
+                            getter.value(),
+                            ty
+                        );
+                        Error::new_spanned(getter, message)
+                    }
+                },
+                FunctionAttributeParseError::Property(property) => match property {
+                    PropertyParseError::InvalidAccessor { fn_ident } => {
+                        let message = format!(
+                            r#"Property accessor {} must either take `&self` and return the property's value, or take `&mut self` and the property's new value."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    PropertyParseError::GetterNotFound { setter, property } => {
+                        let message =
+                            format!(r#"Property "{}" has a setter but no getter."#, property);
+                        Error::new_spanned(setter, message)
+                    }
+                },
+                FunctionAttributeParseError::Subscript(subscript) => match subscript {
+                    SubscriptParseError::InvalidSubscript { fn_ident } => {
+                        let message = format!(
+                            r#"Subscript function {} must take `&self` and an index, and return a value."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                },
+                FunctionAttributeParseError::Publisher(publisher) => match publisher {
+                    PublisherParseError::InvalidRegistration { fn_ident } => {
+                        let message = format!(
+                            r#"Publisher function {} must take `&self` and a `Box<dyn Fn(T)>` callback, and return a subscription handle."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    PublisherParseError::CombineFeatureDisabled { fn_ident } => {
+                        let message = format!(
+                            r#"Publisher function {} requires swift-bridge's `combine` feature."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
                 },
             },
             ParseError::ArgCopyAndRefMut { arg } => {
@@ -205,14 +344,114 @@ struct {struct_name};
                 Error::new_spanned(arg, message)
             }
             ParseError::InvalidModuleItem { item } => {
-                let message = format!(r#"Only `extern` blocks, structs and enums are supported."#);
+                let message = format!(
+                    r#"Only `extern` blocks, structs, enums, traits, constants and statics are supported."#
+                );
                 Error::new_spanned(item, message)
             }
+            ParseError::InvalidTraitItem { item } => {
+                let message = "Bridged traits can only contain methods that take `&self` or `&mut self` and do not have a default implementation.";
+                Error::new_spanned(item, message)
+            }
+            ParseError::TraitHasGenerics { generics } => {
+                let message = "Bridged traits cannot have generic parameters or a where clause.";
+                let where_clause = &generics.where_clause;
+                Error::new_spanned(quote! { #generics #where_clause }, message)
+            }
+            ParseError::TraitHasSupertraits { supertraits } => {
+                let message = "Bridged traits cannot have supertraits.";
+                Error::new_spanned(supertraits, message)
+            }
+            ParseError::InvalidEnumDiscriminant { discriminant } => {
+                let message = "Enum discriminants must be integer literals, and can only be used on enums whose variants do not have fields.";
+                Error::new_spanned(discriminant, message)
+            }
+            ParseError::UnsupportedCodableType { ty } => {
+                let message = "serde::Serialize and serde::Deserialize can only be derived on structs with named fields and on enums whose variants do not have fields.";
+                Error::new_spanned(ty, message)
+            }
+            ParseError::MethodOnSharedType { self_ } => {
+                let message = "Methods on shared structs and enums are not yet supported. Use an associated function that takes the type as an argument instead.";
+                Error::new_spanned(self_, message)
+            }
             ParseError::InvalidAssociatedTo { self_ } => {
                 let message =
                     format!(r#"The associated_to attribute can only be used on static methods."#);
                 Error::new_spanned(self_, message)
             }
+            ParseError::UnsupportedConstantType { ty } => {
+                let message = "Bridged constants and statics must be numbers, `bool`s or `&str`s.";
+                Error::new_spanned(ty, message)
+            }
+            ParseError::UnsupportedType { ty, help } => {
+                let mut message = format!("`{}` is not supported here", type_to_string(&ty));
+                if let Some(help) = help {
+                    message += "; ";
+                    message += &help;
+                }
+
+                Error::new_spanned(ty, message)
+            }
+            ParseError::UuidFeatureDisabled { ty } => {
+                let message = format!(
+                    "`{}` requires swift-bridge's `uuid` feature.",
+                    type_to_string(&ty)
+                );
+                Error::new_spanned(ty, message)
+            }
+            ParseError::MutableStatic { item_static } => {
+                let message = "A `static mut` can't be bridged, since Swift can't safely read it.";
+                Error::new_spanned(item_static, message)
+            }
+            ParseError::DuplicateTypeDeclaration { first, duplicate } => duplicate_error(
+                format!("The type `{}` is declared more than once", duplicate),
+                first,
+                duplicate,
+            ),
+            ParseError::DuplicateFunction { first, duplicate } => duplicate_error(
+                format!("The function `{}` is declared more than once", duplicate),
+                first,
+                duplicate,
+            ),
+            ParseError::ComparableWithoutEquatable { ty } => {
+                let message = format!(
+                    r#"Comparable type {} must also be Equatable. Use `#[swift_bridge(Equatable, Comparable)]`."#,
+                    ty
+                );
+                Error::new_spanned(ty, message)
+            }
+            ParseError::HashableWithoutEquatable { ty } => {
+                let message = format!(
+                    r#"Hashable type {} must also be Equatable. Use `#[swift_bridge(Hashable, Equatable)]`."#,
+                    ty
+                );
+                Error::new_spanned(ty, message)
+            }
+            ParseError::IteratorNextNotFound { ty } => {
+                let message = format!(
+                    r#"Iterator type {} must have a `fn next(&mut self) -> Option<T>` method."#,
+                    ty
+                );
+                Error::new_spanned(ty, message)
+            }
+            ParseError::OnChangeNotFound { ty, on_change } => {
+                let message = format!(
+                    r#"ObservableObject type {} must have a `fn {}(&self, callback: Box<dyn Fn()>)` method."#,
+                    ty,
+                    on_change.value()
+                );
+                Error::new_spanned(on_change, message)
+            }
         }
     }
 }
+
+/// An error at the duplicate declaration, along with a note that points at the first one.
+fn duplicate_error(message: String, first: Ident, duplicate: Ident) -> Error {
+    let mut error = Error::new_spanned(duplicate, message);
+    error.combine(Error::new_spanned(
+        &first,
+        format!("`{}` is first declared here", first),
+    ));
+    error
+}
@@ -0,0 +1,233 @@
+use crate::CodegenConfig;
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, ToTokens};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Attribute, File, ItemForeignMod, LitStr, Token};
+
+/// A `#[cfg(...)]` attribute on a function or an opaque type in a bridge module.
+///
+/// ```no_run,ignore
+/// #[swift_bridge::bridge]
+/// mod ffi {
+///     extern "Rust" {
+///         // This is an item cfg attribute.
+///         #[cfg(feature = "metrics")]
+///         fn record_metric(name: &str);
+///     }
+/// }
+/// ```
+///
+/// The attribute is kept on the generated Rust code, while the generated Swift and C code only
+/// contain the item when the predicate holds for the crate that is being compiled.
+#[derive(Clone)]
+pub(crate) struct ItemCfg {
+    /// `#[cfg(feature = "metrics")]`
+    attribute: TokenStream,
+    predicate: CfgPredicate,
+}
+
+/// The predicate of a `#[cfg(...)]` attribute.
+#[derive(Clone)]
+enum CfgPredicate {
+    /// `unix`, `feature = "metrics"` or `target_os = "ios"`
+    Option { name: Ident, value: Option<LitStr> },
+    /// `all(unix, feature = "metrics")`
+    All(Vec<CfgPredicate>),
+    /// `any(target_os = "ios", target_os = "macos")`
+    Any(Vec<CfgPredicate>),
+    /// `not(feature = "metrics")`
+    Not(Box<CfgPredicate>),
+}
+
+impl ItemCfg {
+    /// Parse a `#[cfg(...)]` attribute.
+    pub fn from_attribute(attr: &Attribute) -> syn::Result<Self> {
+        Ok(ItemCfg {
+            attribute: attr.to_token_stream(),
+            predicate: attr.parse_args()?,
+        })
+    }
+
+    /// Whether or not the item will be compiled, given the crate's enabled features and cfg
+    /// options.
+    pub fn is_enabled(&self, config: &CodegenConfig) -> bool {
+        self.predicate.is_enabled(config)
+    }
+}
+
+impl CfgPredicate {
+    fn is_enabled(&self, config: &CodegenConfig) -> bool {
+        match self {
+            CfgPredicate::Option { name, value } => match value {
+                Some(feature) if name == "feature" => {
+                    (config.crate_feature_lookup)(&feature.value())
+                }
+                _ => (config.crate_cfg_lookup)(
+                    &name.to_string(),
+                    value.as_ref().map(|v| v.value()).as_deref(),
+                ),
+            },
+            CfgPredicate::All(predicates) => predicates.iter().all(|p| p.is_enabled(config)),
+            CfgPredicate::Any(predicates) => predicates.iter().any(|p| p.is_enabled(config)),
+            CfgPredicate::Not(predicate) => !predicate.is_enabled(config),
+        }
+    }
+}
+
+impl Parse for CfgPredicate {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name: Ident = input.parse()?;
+
+        if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+
+            return Ok(CfgPredicate::Option {
+                name,
+                value: Some(input.parse()?),
+            });
+        }
+
+        if !input.peek(syn::token::Paren) {
+            return Ok(CfgPredicate::Option { name, value: None });
+        }
+
+        let content;
+        syn::parenthesized!(content in input);
+        let predicates: Vec<CfgPredicate> =
+            Punctuated::<CfgPredicate, Token![,]>::parse_terminated(&content)?
+                .into_iter()
+                .collect();
+
+        match name.to_string().as_str() {
+            "all" => Ok(CfgPredicate::All(predicates)),
+            "any" => Ok(CfgPredicate::Any(predicates)),
+            "not" if predicates.len() == 1 => Ok(CfgPredicate::Not(Box::new(
+                predicates.into_iter().next().unwrap(),
+            ))),
+            "not" => Err(syn::Error::new(
+                name.span(),
+                "`not` takes exactly one cfg predicate.",
+            )),
+            _ => Err(syn::Error::new(
+                name.span(),
+                "Expected a cfg option or `all`, `any` or `not`.",
+            )),
+        }
+    }
+}
+
+/// Whether or not all of an item's cfg attributes are enabled.
+pub(crate) fn cfgs_are_enabled(cfgs: &[ItemCfg], config: &CodegenConfig) -> bool {
+    cfgs.iter().all(|cfg| cfg.is_enabled(config))
+}
+
+/// `#[cfg(feature = "a")] #[cfg(unix)]`
+pub(crate) fn cfg_attributes(cfgs: &[ItemCfg]) -> TokenStream {
+    let attributes = cfgs.iter().map(|cfg| &cfg.attribute);
+    quote! { #(#attributes)* }
+}
+
+/// Add an item's cfg attributes to every item in the tokens, such as every function that gets
+/// generated for an opaque type.
+pub(crate) fn add_cfgs_to_items(cfgs: &[ItemCfg], tokens: TokenStream) -> TokenStream {
+    if cfgs.is_empty() {
+        return tokens;
+    }
+
+    let file: File = syn::parse2(tokens).unwrap();
+    let cfg_attributes = cfg_attributes(cfgs);
+    let items = file.items.iter();
+
+    quote! {
+        #(
+            #cfg_attributes
+            #items
+        )*
+    }
+}
+
+/// Add an item's cfg attributes to every item in the tokens that get placed in an `extern "C"`
+/// block, such as the declarations of the Swift functions that Rust calls.
+pub(crate) fn add_cfgs_to_foreign_items(cfgs: &[ItemCfg], tokens: TokenStream) -> TokenStream {
+    if cfgs.is_empty() {
+        return tokens;
+    }
+
+    let foreign_mod: ItemForeignMod = syn::parse2(quote! { extern "C" { #tokens } }).unwrap();
+    let cfg_attributes = cfg_attributes(cfgs);
+    let items = foreign_mod.items.iter();
+
+    quote! {
+        #(
+            #cfg_attributes
+            #items
+        )*
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    fn config() -> CodegenConfig {
+        CodegenConfig {
+            crate_feature_lookup: Box::new(|feature| feature == "enabled-feature"),
+            crate_cfg_lookup: Box::new(|name, value| match (name, value) {
+                ("unix", None) => true,
+                ("target_os", Some("ios")) => true,
+                _ => false,
+            }),
+        }
+    }
+
+    fn is_enabled(attr: Attribute) -> bool {
+        ItemCfg::from_attribute(&attr)
+            .unwrap()
+            .is_enabled(&config())
+    }
+
+    /// Verify that we evaluate cfg options, including features.
+    #[test]
+    fn evaluates_options() {
+        assert!(is_enabled(
+            parse_quote! { #[cfg(feature = "enabled-feature")] }
+        ));
+        assert!(!is_enabled(
+            parse_quote! { #[cfg(feature = "disabled-feature")] }
+        ));
+
+        assert!(is_enabled(parse_quote! { #[cfg(unix)] }));
+        assert!(!is_enabled(parse_quote! { #[cfg(windows)] }));
+
+        assert!(is_enabled(parse_quote! { #[cfg(target_os = "ios")] }));
+        assert!(!is_enabled(parse_quote! { #[cfg(target_os = "macos")] }));
+    }
+
+    /// Verify that we evaluate `all`, `any` and `not` predicates.
+    #[test]
+    fn evaluates_combinators() {
+        assert!(is_enabled(parse_quote! {
+            #[cfg(all(unix, feature = "enabled-feature"))]
+        }));
+        assert!(!is_enabled(parse_quote! { #[cfg(all(unix, windows))] }));
+
+        assert!(is_enabled(parse_quote! {
+            #[cfg(any(target_os = "macos", target_os = "ios"))]
+        }));
+        assert!(!is_enabled(parse_quote! { #[cfg(any())] }));
+
+        assert!(is_enabled(parse_quote! { #[cfg(not(windows))] }));
+        assert!(!is_enabled(parse_quote! {
+            #[cfg(not(any(unix, windows)))]
+        }));
+    }
+
+    /// Verify that we return an error for an unknown cfg predicate.
+    #[test]
+    fn error_unknown_predicate() {
+        let attr: Attribute = parse_quote! { #[cfg(either(unix, windows))] };
+        assert!(ItemCfg::from_attribute(&attr).is_err());
+    }
+}
@@ -11,11 +11,16 @@ use proc_macro2::Ident;
 use syn::Path;
 
 use crate::bridge_module_attributes::CfgAttr;
-use crate::parse::TypeDeclarations;
+use crate::parse::{BridgedConstant, TypeDeclarations};
 use crate::parsed_extern_fn::ParsedExternFn;
 
-pub use self::bridge_macro_attributes::{SwiftBridgeModuleAttr, SwiftBridgeModuleAttrs};
+pub use self::bridge_macro_attributes::{
+    FunctionNameStyle, PanicPolicy, SwiftAccess, SwiftBridgeModuleAttr, SwiftBridgeModuleAttrs,
+    SwiftPathType,
+};
 pub use self::codegen::CodegenConfig;
+pub use self::custom_type_bridge::{register_custom_type_bridge, CustomTypeBridge};
+pub use self::trace::{trace, trace_enabled};
 
 mod errors;
 mod parse;
@@ -23,7 +28,10 @@ mod parse;
 mod bridge_macro_attributes;
 mod bridge_module_attributes;
 mod bridged_type;
+mod custom_type_bridge;
+mod item_cfg;
 mod parsed_extern_fn;
+mod trace;
 
 mod codegen;
 
@@ -60,8 +68,12 @@ pub struct SwiftBridgeModule {
     name: Ident,
     types: TypeDeclarations,
     functions: Vec<ParsedExternFn>,
+    constants: Vec<BridgedConstant>,
     swift_bridge_path: Path,
     cfg_attrs: Vec<CfgAttr>,
+    swift_access: SwiftAccess,
+    swift_namespace: Option<String>,
+    symbol_prefix: Option<String>,
 }
 
 impl SwiftBridgeModule {
@@ -70,6 +82,129 @@ impl SwiftBridgeModule {
     pub fn set_swift_bridge_path(&mut self, path: Path) {
         self.swift_bridge_path = path;
     }
+
+    /// Set the Swift type that the module's path types such as `PathBuf` and `&Path` are seen as.
+    pub fn set_swift_path_type(&mut self, swift_path_type: SwiftPathType) {
+        self.types.set_swift_path_type(swift_path_type);
+    }
+
+    /// Set the access level of the module's generated Swift declarations that do not have a
+    /// `swift_access` attribute.
+    pub fn set_swift_access(&mut self, swift_access: SwiftAccess) {
+        self.swift_access = swift_access;
+    }
+
+    /// Emit the module's free Rust functions as static functions of a caseless Swift enum with
+    /// the given name.
+    pub fn set_swift_namespace(&mut self, namespace: String) {
+        self.swift_namespace = Some(namespace);
+    }
+
+    /// Add a prefix to the names of the module's FFI symbols, such as
+    /// `__swift_bridge__$my_crate$some_function` instead of `__swift_bridge__$some_function`.
+    ///
+    /// This lets two crates that declare the same functions or types get linked into the same
+    /// binary. Bridge modules that use each other's types, such as with
+    /// `#[swift_bridge(already_declared)]`, need to use the same prefix.
+    pub fn set_symbol_prefix(&mut self, prefix: String) {
+        self.symbol_prefix = Some(prefix);
+    }
+
+    /// Set what happens when one of the module's Rust functions panics.
+    pub fn set_panic_policy(&mut self, policy: PanicPolicy) {
+        for func in self.functions.iter_mut() {
+            func.panic_policy = policy;
+        }
+    }
+
+    /// Set the path of the Rust file that the module was parsed from.
+    ///
+    /// The Swift code that gets generated for each of the module's functions is surrounded by
+    /// `#sourceLocation` directives that point back at the function's declaration in this file,
+    /// so that Swift compiler errors and stack traces refer to the declaration instead of to the
+    /// generated code. The line numbers come from the spans of the module's tokens, so this is
+    /// meant for a module that was parsed from the file's source text, such as by
+    /// `swift-bridge-build`.
+    pub fn set_source_file(&mut self, path: impl Into<String>) {
+        let path = path.into();
+        for func in self.functions.iter_mut() {
+            func.source_file = Some(path.clone());
+        }
+    }
+
+    /// Use the given style for the Swift names of all of the module's functions that do not
+    /// have a `swift_name` attribute.
+    pub fn rename_functions(&mut self, style: FunctionNameStyle) {
+        for func in self.functions.iter_mut() {
+            if func.swift_name_override.is_none() {
+                let ident = &func.func.sig.ident;
+                func.swift_name_override = Some(syn::LitStr::new(
+                    &style.apply(&ident.to_string()),
+                    ident.span(),
+                ));
+            }
+        }
+    }
+}
+
+/// Expand a `#[swift_bridge::bridge]` attribute on a module into the module's generated Rust code.
+///
+/// This is what the `#[swift_bridge::bridge]` procedural macro does. A crate can call it from its
+/// own attribute macro after registering [`CustomTypeBridge`]s, so that its bridge modules can use
+/// the types that the bridges describe.
+///
+/// ```no_run,ignore
+/// #[proc_macro_attribute]
+/// pub fn bridge(
+///     args: proc_macro::TokenStream,
+///     input: proc_macro::TokenStream,
+/// ) -> proc_macro::TokenStream {
+///     swift_bridge_ir::register_custom_type_bridge(DateTimeBridge);
+///     swift_bridge_ir::expand_bridge_macro(args.into(), input.into()).into()
+/// }
+/// ```
+pub fn expand_bridge_macro(
+    args: proc_macro2::TokenStream,
+    input: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let args: SwiftBridgeModuleAttrs = match syn::parse2(args) {
+        Ok(args) => args,
+        Err(err) => return err.to_compile_error(),
+    };
+    let mut module: SwiftBridgeModule = match syn::parse2(input) {
+        Ok(module) => module,
+        Err(err) => return err.to_compile_error(),
+    };
+
+    for arg in args.attributes {
+        match arg {
+            SwiftBridgeModuleAttr::SwiftBridgePath(path) => {
+                module.set_swift_bridge_path(path);
+            }
+            SwiftBridgeModuleAttr::RenameFunctions(style) => {
+                module.rename_functions(style);
+            }
+            SwiftBridgeModuleAttr::SwiftPathType(swift_path_type) => {
+                module.set_swift_path_type(swift_path_type);
+            }
+            SwiftBridgeModuleAttr::SwiftAccess(swift_access) => {
+                module.set_swift_access(swift_access);
+            }
+            SwiftBridgeModuleAttr::SwiftNamespace(namespace) => {
+                module.set_swift_namespace(namespace);
+            }
+            SwiftBridgeModuleAttr::SymbolPrefix(prefix) => {
+                module.set_symbol_prefix(prefix);
+            }
+            SwiftBridgeModuleAttr::Panic(policy) => {
+                module.set_panic_policy(policy);
+            }
+        }
+    }
+
+    quote::quote! {
+        #module
+    }
 }
 
 #[cfg(test)]
@@ -1,21 +1,37 @@
 use crate::bridge_module_attributes::CfgAttr;
 use crate::bridged_type::BridgedType;
-use crate::errors::{ParseError, ParseErrors};
+use crate::errors::{
+    FunctionAttributeParseError, IdentifiableParseError, ParseError, ParseErrors,
+    PropertyParseError, PublisherParseError,
+};
+use crate::parse::parse_const::ConstantParser;
 use crate::parse::parse_enum::SharedEnumDeclarationParser;
 use crate::parse::parse_extern_mod::ForeignModParser;
 use crate::parse::parse_struct::SharedStructDeclarationParser;
-use crate::SwiftBridgeModule;
-use proc_macro2::TokenTree;
+use crate::parse::parse_trait::TraitParser;
+use crate::parse::unsupported_type::{
+    disabled_feature_error, rust_return_only_result_error, unsupported_type_error,
+};
+use crate::parsed_extern_fn::{ParsedExternFn, SwiftProperty};
+use crate::{SwiftAccess, SwiftBridgeModule, SwiftBridgeModuleAttr, SwiftBridgeModuleAttrs};
+use proc_macro2::{Ident, TokenTree};
 use quote::{quote, ToTokens};
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
 use syn::parse::{Parse, ParseStream};
-use syn::{Item, ItemMod, Token};
+use syn::{FnArg, Item, ItemMod, Pat, ReturnType, Signature, Token, Type};
 
+mod parse_const;
 mod parse_enum;
 mod parse_extern_mod;
 mod parse_struct;
+mod parse_trait;
+mod unsupported_type;
 
 mod type_declarations;
+pub(crate) use self::parse_const::BridgedConstant;
 pub(crate) use self::type_declarations::*;
+pub(crate) use self::unsupported_type::type_to_string;
 
 impl Parse for SwiftBridgeModule {
     fn parse(input: ParseStream) -> syn::Result<Self> {
@@ -59,9 +75,11 @@ impl Parse for SwiftBridgeModuleAndErrors {
             let module_name = item_mod.ident;
 
             let mut functions = vec![];
+            let mut constants = vec![];
             let mut type_declarations = TypeDeclarations::default();
             let mut unresolved_types = vec![];
             let mut cfg_attrs = vec![];
+            let mut module_attrs = vec![];
 
             for attr in item_mod.attrs {
                 match attr.path.to_token_stream().to_string().as_str() {
@@ -69,6 +87,20 @@ impl Parse for SwiftBridgeModuleAndErrors {
                         let cfg: CfgAttr = syn::parse2(attr.tokens)?;
                         cfg_attrs.push(cfg);
                     }
+                    // When swift-bridge-build parses a file the `#[swift_bridge::bridge(...)]`
+                    // attribute is still on the module, so we read its arguments here. The macro
+                    // can also be imported with `use swift_bridge::bridge`, so we only look at
+                    // the last segment of its path.
+                    _ if !attr.tokens.is_empty()
+                        && attr
+                            .path
+                            .segments
+                            .last()
+                            .is_some_and(|segment| segment.ident == "bridge") =>
+                    {
+                        let attrs: SwiftBridgeModuleAttrs = attr.parse_args()?;
+                        module_attrs.extend(attrs.attributes);
+                    }
                     _ => {}
                 };
             }
@@ -90,10 +122,12 @@ impl Parse for SwiftBridgeModuleAndErrors {
                             errors: &mut errors,
                         }
                         .parse()?;
-                        type_declarations.insert(
+                        if let Some(error) = type_declarations.insert(
                             shared_struct.name.to_string(),
                             TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)),
-                        );
+                        ) {
+                            errors.push(error);
+                        }
                     }
                     Item::Enum(item_enum) => {
                         let shared_enum = SharedEnumDeclarationParser {
@@ -101,10 +135,28 @@ impl Parse for SwiftBridgeModuleAndErrors {
                             errors: &mut errors,
                         }
                         .parse()?;
-                        type_declarations.insert(
+                        if let Some(error) = type_declarations.insert(
                             shared_enum.name.to_string(),
                             TypeDeclaration::Shared(SharedTypeDeclaration::Enum(shared_enum)),
-                        );
+                        ) {
+                            errors.push(error);
+                        }
+                    }
+                    Item::Trait(item_trait) => {
+                        TraitParser {
+                            errors: &mut errors,
+                            type_declarations: &mut type_declarations,
+                            functions: &mut functions,
+                            unresolved_types: &mut unresolved_types,
+                        }
+                        .parse(item_trait)?;
+                    }
+                    item @ (Item::Const(_) | Item::Static(_)) => {
+                        let constant = ConstantParser {
+                            errors: &mut errors,
+                        }
+                        .parse(item);
+                        constants.extend(constant);
                     }
                     invalid_item => {
                         let error = ParseError::InvalidModuleItem { item: invalid_item };
@@ -118,18 +170,261 @@ impl Parse for SwiftBridgeModuleAndErrors {
                     continue;
                 }
 
-                errors.push(ParseError::UndeclaredType {
-                    ty: unresolved_type.clone(),
+                errors.push(
+                    unsupported_type_error(&unresolved_type, &type_declarations).unwrap_or(
+                        ParseError::UndeclaredType {
+                            ty: unresolved_type.clone(),
+                        },
+                    ),
+                );
+            }
+
+            // Some types can be parsed even though we can't generate code for them, such as
+            // `Vec<&str>`, so we check the types that did get resolved as well.
+            for func in functions.iter() {
+                for ty in signature_types(&func.func.sig) {
+                    if let Some(error) = disabled_feature_error(ty, &type_declarations) {
+                        errors.push(error);
+                        continue;
+                    }
+                    if BridgedType::new_with_type(ty, &type_declarations).is_none() {
+                        continue;
+                    }
+                    if let Some(error) = unsupported_type_error(ty, &type_declarations) {
+                        errors.push(error);
+                    }
+                }
+
+                for ty in types_converted_into_rust(func) {
+                    if let Some(error) = rust_return_only_result_error(ty, &type_declarations) {
+                        errors.push(error);
+                    }
+                }
+            }
+
+            // Functions with the same link name would be exported under the same symbol, which would
+            // otherwise only fail once the final binary gets linked. Functions that have
+            // `#[cfg(...)]` attributes can share a name with functions that never get compiled
+            // alongside them, so we leave those out.
+            let mut link_names: HashMap<String, &Ident> = HashMap::new();
+            for func in functions.iter().filter(|func| func.cfgs.is_empty()) {
+                let ident = &func.func.sig.ident;
+                match link_names.entry(func.link_name()) {
+                    Entry::Occupied(first) => errors.push(ParseError::DuplicateFunction {
+                        first: (*first.get()).clone(),
+                        duplicate: ident.clone(),
+                    }),
+                    Entry::Vacant(entry) => {
+                        entry.insert(ident);
+                    }
+                }
+            }
+
+            // The fields of shared structs and enums are not checked while they get parsed, since
+            // they can use types that are declared later in the module.
+            for ty in type_declarations.types() {
+                let fields = match ty {
+                    TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct))
+                        if !shared_struct.already_declared =>
+                    {
+                        shared_struct.fields.normalized_fields()
+                    }
+                    TypeDeclaration::Shared(SharedTypeDeclaration::Enum(shared_enum))
+                        if !shared_enum.already_declared =>
+                    {
+                        shared_enum
+                            .variants
+                            .iter()
+                            .flat_map(|variant| variant.fields.normalized_fields())
+                            .collect()
+                    }
+                    _ => continue,
+                };
+
+                for field in fields {
+                    if let Some(error) = disabled_feature_error(&field.ty, &type_declarations) {
+                        errors.push(error);
+                        continue;
+                    }
+                    if BridgedType::new_with_type(&field.ty, &type_declarations).is_some() {
+                        continue;
+                    }
+                    errors.push(
+                        unsupported_type_error(&field.ty, &type_declarations)
+                            .unwrap_or(ParseError::UndeclaredType { ty: field.ty }),
+                    );
+                }
+            }
+
+            for ty in type_declarations.types() {
+                if let TypeDeclaration::Opaque(opaque_ty) = ty {
+                    if let Some(getter) = opaque_ty.attributes.identifiable.as_ref() {
+                        let is_declared = functions.iter().any(|f| match &f.associated_type {
+                            Some(TypeDeclaration::Opaque(associated)) => {
+                                f.is_swift_identifiable && associated.ty == opaque_ty.ty
+                            }
+                            _ => false,
+                        });
+                        if !is_declared {
+                            errors.push(ParseError::FunctionAttribute(
+                                FunctionAttributeParseError::Identifiable(
+                                    IdentifiableParseError::GetterNotFound {
+                                        ty: opaque_ty.ty.clone(),
+                                        getter: getter.clone(),
+                                    },
+                                ),
+                            ));
+                        }
+                    }
+                }
+            }
+
+            for ty in type_declarations.types() {
+                let iterator = match ty {
+                    TypeDeclaration::Opaque(opaque_ty)
+                        if opaque_ty.attributes.iterator && opaque_ty.host_lang.is_rust() =>
+                    {
+                        opaque_ty
+                    }
+                    _ => continue,
+                };
+
+                let has_next = functions.iter().any(|f| match &f.associated_type {
+                    Some(TypeDeclaration::Opaque(associated)) => {
+                        associated.ty == iterator.ty && f.is_iterator_next(&type_declarations)
+                    }
+                    _ => false,
                 });
+                if !has_next {
+                    errors.push(ParseError::IteratorNextNotFound {
+                        ty: iterator.ty.clone(),
+                    });
+                }
             }
 
-            let module = SwiftBridgeModule {
+            for ty in type_declarations.types() {
+                let (observable, on_change) = match ty {
+                    TypeDeclaration::Opaque(opaque_ty) if opaque_ty.host_lang.is_rust() => {
+                        match opaque_ty.attributes.observable_object.as_ref() {
+                            Some(on_change) => (opaque_ty, on_change),
+                            None => continue,
+                        }
+                    }
+                    _ => continue,
+                };
+
+                let has_on_change = functions.iter().any(|f| match &f.associated_type {
+                    Some(TypeDeclaration::Opaque(associated)) => {
+                        associated.ty == observable.ty
+                            && f.sig.ident == on_change.value()
+                            && f.is_on_change_registration(&type_declarations)
+                    }
+                    _ => false,
+                });
+                if !has_on_change {
+                    errors.push(ParseError::OnChangeNotFound {
+                        ty: observable.ty.clone(),
+                        on_change: on_change.clone(),
+                    });
+                }
+            }
+
+            for setter in functions.iter() {
+                let property = match &setter.swift_property {
+                    Some(SwiftProperty::Setter {
+                        name,
+                        sets_field: false,
+                    }) => name,
+                    _ => continue,
+                };
+
+                let has_getter = functions.iter().any(|getter| match &getter.swift_property {
+                    Some(SwiftProperty::Getter { name }) => {
+                        name == property
+                            && getter
+                                .associated_type
+                                .as_ref()
+                                .and_then(|ty| ty.as_opaque())
+                                .map(|ty| &ty.ty)
+                                == setter
+                                    .associated_type
+                                    .as_ref()
+                                    .and_then(|ty| ty.as_opaque())
+                                    .map(|ty| &ty.ty)
+                    }
+                    _ => false,
+                });
+                if !has_getter {
+                    errors.push(ParseError::FunctionAttribute(
+                        FunctionAttributeParseError::Property(PropertyParseError::GetterNotFound {
+                            setter: setter.sig.ident.clone(),
+                            property: property.clone(),
+                        }),
+                    ));
+                }
+            }
+
+            // The callback's type can only be resolved once all of the module's types are
+            // declared.
+            for publisher in functions.iter().filter(|f| f.swift_publisher.is_some()) {
+                if !cfg!(feature = "combine") {
+                    errors.push(ParseError::FunctionAttribute(
+                        FunctionAttributeParseError::Publisher(
+                            PublisherParseError::CombineFeatureDisabled {
+                                fn_ident: publisher.sig.ident.clone(),
+                            },
+                        ),
+                    ));
+                } else if publisher.publisher_callback(&type_declarations).is_none() {
+                    errors.push(ParseError::FunctionAttribute(
+                        FunctionAttributeParseError::Publisher(
+                            PublisherParseError::InvalidRegistration {
+                                fn_ident: publisher.sig.ident.clone(),
+                            },
+                        ),
+                    ));
+                }
+            }
+
+            let mut module = SwiftBridgeModule {
                 name: module_name,
                 types: type_declarations,
                 functions,
+                constants,
                 swift_bridge_path: syn::parse2(quote! { swift_bridge }).unwrap(),
                 cfg_attrs,
+                swift_access: SwiftAccess::default(),
+                swift_namespace: None,
+                symbol_prefix: None,
             };
+            for attr in module_attrs {
+                match attr {
+                    SwiftBridgeModuleAttr::SwiftBridgePath(path) => {
+                        module.set_swift_bridge_path(path);
+                    }
+                    SwiftBridgeModuleAttr::RenameFunctions(style) => {
+                        module.rename_functions(style);
+                    }
+                    SwiftBridgeModuleAttr::SwiftPathType(swift_path_type) => {
+                        module.set_swift_path_type(swift_path_type);
+                    }
+                    SwiftBridgeModuleAttr::SwiftAccess(swift_access) => {
+                        module.set_swift_access(swift_access);
+                    }
+                    SwiftBridgeModuleAttr::SwiftNamespace(namespace) => {
+                        module.set_swift_namespace(namespace);
+                    }
+                    SwiftBridgeModuleAttr::SymbolPrefix(prefix) => {
+                        module.set_symbol_prefix(prefix);
+                    }
+                    SwiftBridgeModuleAttr::Panic(policy) => {
+                        module.set_panic_policy(policy);
+                    }
+                }
+            }
+
+            module.trace_functions();
+
             Ok(SwiftBridgeModuleAndErrors { module, errors })
         } else {
             return Err(syn::Error::new_spanned(
@@ -140,6 +435,46 @@ impl Parse for SwiftBridgeModuleAndErrors {
     }
 }
 
+/// The types of a function's arguments, other than `self`, and its return type.
+fn signature_types(sig: &Signature) -> Vec<&Type> {
+    let mut types: Vec<&Type> = sig
+        .inputs
+        .iter()
+        .filter_map(|arg| match arg {
+            FnArg::Typed(pat_ty) => match pat_ty.pat.as_ref() {
+                Pat::Ident(ident) if ident.ident == "self" => None,
+                _ => Some(pat_ty.ty.as_ref()),
+            },
+            FnArg::Receiver(_) => None,
+        })
+        .collect();
+    if let ReturnType::Type(_, ty) = &sig.output {
+        types.push(ty);
+    }
+
+    types
+}
+
+/// The types in a function's signature whose values get passed from Swift to Rust, which are the
+/// arguments of Rust functions and the return type of Swift functions.
+fn types_converted_into_rust(func: &ParsedExternFn) -> Vec<&Type> {
+    let sig = &func.func.sig;
+    let return_type = match &sig.output {
+        ReturnType::Type(_, ty) => Some(ty.as_ref()),
+        ReturnType::Default => None,
+    };
+
+    if func.host_lang.is_rust() {
+        let mut types = signature_types(sig);
+        if return_type.is_some() {
+            types.pop();
+        }
+        types
+    } else {
+        return_type.into_iter().collect()
+    }
+}
+
 // Used to fast-forward our attribute parsing to the next attribute when we've run into an
 // issue parsing the current attribute.
 fn move_input_cursor_to_next_comma(input: ParseStream) {
@@ -186,6 +521,56 @@ mod tests {
         };
     }
 
+    /// Verify that we apply the `rename_functions` argument of the bridge attribute.
+    #[test]
+    fn parse_module_rename_functions() {
+        let tokens = quote! {
+            #[swift_bridge::bridge(rename_functions = "camelCase")]
+            mod foo {
+                extern "Rust" {
+                    fn some_function();
+
+                    #[swift_bridge(swift_name = "customName")]
+                    fn another_function();
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let names: Vec<String> = module
+            .functions
+            .iter()
+            .map(|f| f.swift_name_override.as_ref().unwrap().value())
+            .collect();
+        assert_eq!(names, vec!["someFunction", "customName"]);
+    }
+
+    /// Verify that we read the arguments of a bridge attribute that was imported with
+    /// `use swift_bridge::bridge`.
+    #[test]
+    fn parse_module_attributes_of_imported_bridge_macro() {
+        let tokens = quote! {
+            #[bridge(rename_functions = "camelCase")]
+            mod foo {
+                extern "Rust" {
+                    fn some_function();
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(
+            module.functions[0]
+                .swift_name_override
+                .as_ref()
+                .unwrap()
+                .value(),
+            "someFunction"
+        );
+    }
+
     /// Verify that we get an error when parsing an unsupported module item, such as a
     /// `use` statement.
     #[test]
@@ -207,4 +592,214 @@ mod tests {
             _ => panic!(),
         }
     }
+
+    /// Verify that we parse constants and statics, and whether their values are literals.
+    #[test]
+    fn parse_constants() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod foo {
+                const MAX_SIZE: u32 = 1024;
+                static GREETING: &str = "hi";
+                const COMPUTED: i64 = -1 - 2;
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let constants: Vec<(String, Option<&str>)> = module
+            .constants
+            .iter()
+            .map(|c| (c.name.to_string(), c.swift_literal.as_deref()))
+            .collect();
+        assert_eq!(
+            constants,
+            vec![
+                ("MAX_SIZE".to_string(), Some("1024")),
+                ("GREETING".to_string(), Some(r#""hi""#)),
+                ("COMPUTED".to_string(), None),
+            ]
+        );
+    }
+
+    /// Verify that we push an error for a constant whose type can't be bridged, and for a
+    /// `static mut`.
+    #[test]
+    fn error_unsupported_constants() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod foo {
+                const NAMES: &[&str] = &[];
+                static mut COUNT: u32 = 0;
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 2);
+        assert!(matches!(
+            errors[0],
+            ParseError::UnsupportedConstantType { .. }
+        ));
+        assert!(matches!(errors[1], ParseError::MutableStatic { .. }));
+    }
+
+    /// Verify that we push an error for the fields of shared structs and enums that use types
+    /// that were not declared in the module, while allowing types that are declared later on.
+    #[test]
+    fn error_shared_type_field_undeclared_type() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod foo {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    declared: DeclaredLater,
+                    undeclared: Option<Undeclared>,
+                }
+
+                enum SomeEnum {
+                    Variant(Undeclared),
+                }
+
+                extern "Rust" {
+                    type DeclaredLater;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 2);
+        for error in errors.iter() {
+            match error {
+                ParseError::UndeclaredType { ty } => {
+                    assert_eq!(ty.to_token_stream().to_string(), "Undeclared");
+                }
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that we point at an undeclared type that is used by a callback's signature.
+    #[test]
+    fn error_callback_undeclared_type() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod foo {
+                extern "Rust" {
+                    fn some_function(callback: Box<dyn Fn(Undeclared) -> u8>);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            ParseError::UndeclaredType { ty } => {
+                assert_eq!(ty.to_token_stream().to_string(), "Undeclared");
+            }
+            _ => panic!(),
+        }
+    }
+
+    /// Verify that we push an error that points at both declarations when the module declares
+    /// the same type twice.
+    #[test]
+    fn error_duplicate_type_declaration() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod foo {
+                struct SomeType;
+
+                extern "Rust" {
+                    type SomeType;
+                    type OtherType;
+                }
+
+                extern "Swift" {
+                    type OtherType;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 2);
+        for error in errors.iter() {
+            match error {
+                ParseError::DuplicateTypeDeclaration { first, duplicate } => {
+                    assert_eq!(first, duplicate);
+                }
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that we push an error when two functions would be exported under the same symbol.
+    #[test]
+    fn error_duplicate_function() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod foo {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn some_function();
+                    fn some_function(arg: u8);
+
+                    fn some_method(&self);
+                    #[swift_bridge(associated_to = SomeType)]
+                    fn some_method();
+                }
+
+                extern "Swift" {
+                    fn some_function();
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 3);
+        let duplicates: Vec<String> = errors
+            .iter()
+            .map(|error| match error {
+                ParseError::DuplicateFunction { first, duplicate } => {
+                    assert_eq!(first, duplicate);
+                    duplicate.to_string()
+                }
+                _ => panic!(),
+            })
+            .collect();
+        assert_eq!(
+            duplicates,
+            vec!["some_function", "some_method", "some_function"]
+        );
+    }
+
+    /// Verify that functions with the same name on different types, or that only get compiled
+    /// under different cfg options, are not seen as duplicates.
+    #[test]
+    fn functions_with_same_name_that_are_not_duplicates() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod foo {
+                extern "Rust" {
+                    type SomeType;
+                    type OtherType;
+
+                    fn some_method(self: &SomeType);
+                    fn some_method(self: &OtherType);
+
+                    #[cfg(feature = "a")]
+                    fn some_function();
+                    #[cfg(not(feature = "a"))]
+                    fn some_function();
+                }
+            }
+        };
+
+        parse_ok(tokens);
+    }
 }
@@ -0,0 +1,131 @@
+use crate::bridged_type::{BridgedType, StdLibType};
+use crate::errors::{ParseError, ParseErrors};
+use crate::parse::TypeDeclarations;
+use crate::SWIFT_BRIDGE_PREFIX;
+use proc_macro2::{Ident, TokenStream};
+use quote::ToTokens;
+use syn::{Expr, ExprLit, ExprUnary, Item, Lit, Type, UnOp};
+
+/// A `const` or `static` item in the bridge module, such as `const MAX_SIZE: u32 = 1024;`.
+///
+/// The item stays in the generated Rust module, and gets exposed to Swift as a `public let`
+/// constant.
+pub(crate) struct BridgedConstant {
+    /// The `const` or `static` item as it was written in the bridge module.
+    pub item: TokenStream,
+    pub name: Ident,
+    pub ty: Type,
+    /// The constant's value as a Swift literal, such as the `1024` in `let MAX_SIZE = 1024`.
+    /// This is `None` if the value is not a literal, in which case Swift reads the value by
+    /// calling a generated Rust function.
+    pub swift_literal: Option<String>,
+}
+
+impl BridgedConstant {
+    /// Whether the constant is a `&str`, which is seen on the Swift side as a `String`.
+    pub fn is_str(&self) -> bool {
+        matches!(
+            BridgedType::new_with_type(&self.ty, &TypeDeclarations::default()),
+            Some(BridgedType::StdLib(StdLibType::Str))
+        )
+    }
+
+    /// `__swift_bridge__$MAX_SIZE`
+    pub fn accessor_link_name(&self) -> String {
+        format!("{}${}", SWIFT_BRIDGE_PREFIX, self.name)
+    }
+}
+
+pub(super) struct ConstantParser<'a> {
+    pub errors: &'a mut ParseErrors,
+}
+
+impl<'a> ConstantParser<'a> {
+    /// Parse a `const` or `static` item, or push an error if it can't be bridged.
+    pub fn parse(self, item: Item) -> Option<BridgedConstant> {
+        let (name, ty, expr) = match &item {
+            Item::Const(item_const) => (&item_const.ident, &item_const.ty, &item_const.expr),
+            Item::Static(item_static) => {
+                if item_static.mutability.is_some() {
+                    self.errors.push(ParseError::MutableStatic {
+                        item_static: item_static.clone(),
+                    });
+                    return None;
+                }
+                (&item_static.ident, &item_static.ty, &item_static.expr)
+            }
+            _ => unreachable!("Only const and static items are parsed as constants."),
+        };
+
+        let is_supported = match BridgedType::new_with_type(ty, &TypeDeclarations::default()) {
+            Some(BridgedType::StdLib(stdlib_ty)) => matches!(
+                stdlib_ty,
+                StdLibType::U8
+                    | StdLibType::I8
+                    | StdLibType::U16
+                    | StdLibType::I16
+                    | StdLibType::U32
+                    | StdLibType::I32
+                    | StdLibType::U64
+                    | StdLibType::I64
+                    | StdLibType::Usize
+                    | StdLibType::Isize
+                    | StdLibType::F32
+                    | StdLibType::F64
+                    | StdLibType::Bool
+                    | StdLibType::Str
+            ),
+            _ => false,
+        };
+        if !is_supported {
+            self.errors.push(ParseError::UnsupportedConstantType {
+                ty: ty.as_ref().clone(),
+            });
+            return None;
+        }
+
+        Some(BridgedConstant {
+            name: name.clone(),
+            ty: ty.as_ref().clone(),
+            swift_literal: swift_literal(expr),
+            item: item.to_token_stream(),
+        })
+    }
+}
+
+/// `1024` -> `1024`, `-1.5` -> `-1.5`, `"hello"` -> `"hello"`
+///
+/// Only number, bool and string literals are translated, since any other expression, such as
+/// `u32::MAX` or `1 << 10`, would need to be evaluated by Rust.
+fn swift_literal(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Lit(ExprLit { lit, .. }) => match lit {
+            Lit::Int(int) => Some(int.base10_digits().to_string()),
+            Lit::Float(float) => Some(float.base10_digits().to_string()),
+            Lit::Bool(bool) => Some(bool.value.to_string()),
+            Lit::Str(string) => Some(format!(
+                r#""{}""#,
+                string
+                    .value()
+                    .replace('\\', "\\\\")
+                    .replace('"', "\\\"")
+                    .replace('\n', "\\n")
+                    .replace('\r', "\\r")
+                    .replace('\t', "\\t")
+            )),
+            _ => None,
+        },
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => match expr.as_ref() {
+            Expr::Lit(ExprLit {
+                lit: Lit::Int(_) | Lit::Float(_),
+                ..
+            }) => swift_literal(expr).map(|literal| format!("-{}", literal)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
@@ -1,5 +1,5 @@
-use crate::bridged_type::{EnumVariant, SharedEnum, StructFields};
-use crate::errors::ParseErrors;
+use crate::bridged_type::{EnumDiscriminant, EnumVariant, SharedEnum, StructFields};
+use crate::errors::{ParseError, ParseErrors};
 use syn::ItemEnum;
 
 use self::enum_attributes::SharedEnumAllAttributes;
@@ -20,14 +20,36 @@ impl<'a> SharedEnumDeclarationParser<'a> {
 
         let mut variants = vec![];
 
+        let all_variants_empty = item_enum.variants.iter().all(|v| v.fields.is_empty());
+
         for v in item_enum.variants {
+            let mut discriminant = None;
+            if let Some((_eq, expr)) = v.discriminant.as_ref() {
+                match EnumDiscriminant::from_expr(expr) {
+                    Some(parsed) if all_variants_empty => discriminant = Some(parsed),
+                    _ => {
+                        self.errors.push(ParseError::InvalidEnumDiscriminant {
+                            discriminant: expr.clone(),
+                        });
+                    }
+                }
+            }
+
             let variant = EnumVariant {
                 name: v.ident,
                 fields: StructFields::from_syn_fields(v.fields),
+                discriminant,
             };
             variants.push(variant);
         }
 
+        let is_codable = attribs.derive.serialize || attribs.derive.deserialize;
+        if is_codable && !all_variants_empty {
+            self.errors.push(ParseError::UnsupportedCodableType {
+                ty: item_enum.ident.clone(),
+            });
+        }
+
         let shared_enum = SharedEnum {
             name: item_enum.ident,
             variants,
@@ -184,6 +206,73 @@ mod tests {
         };
     }
 
+    /// Verify that we can parse integer literal discriminants.
+    #[test]
+    fn parse_enum_discriminants() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                enum SomeEnum {
+                    VariantA = 3,
+                    VariantB,
+                    VariantC = -1,
+                    VariantD = 5u8,
+                    VariantE = 0x10,
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = &module.types.types()[0].unwrap_shared_enum();
+        assert!(ty.has_explicit_discriminants());
+        assert_eq!(ty.variants[0].swift_raw_value().unwrap(), "3");
+        assert!(ty.variants[1].discriminant.is_none());
+        assert_eq!(ty.variants[2].swift_raw_value().unwrap(), "-1");
+        assert_eq!(ty.variants[3].swift_raw_value().unwrap(), "5");
+        assert_eq!(ty.variants[4].swift_raw_value().unwrap(), "16");
+    }
+
+    /// Verify that we push an error if a discriminant is not an integer literal or is used on an
+    /// enum that has variants with fields.
+    #[test]
+    fn error_if_discriminant_invalid() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                enum SomeEnum {
+                    Variant = SOME_CONSTANT
+                }
+
+                enum ShiftedEnum {
+                    Variant = 1 << 3
+                }
+
+                enum DoublyNegatedEnum {
+                    Variant = --1
+                }
+
+                enum AnotherEnum {
+                    VariantA = 1,
+                    VariantB(u8)
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 4);
+
+        for (error, expected) in errors.iter().zip(["SOME_CONSTANT", "1 << 3", "- - 1", "1"]) {
+            match error {
+                ParseError::InvalidEnumDiscriminant { discriminant } => {
+                    assert_eq!(discriminant.to_token_stream().to_string(), expected);
+                }
+                _ => panic!(),
+            };
+        }
+    }
+
     /// Verify that we can parse #[derive(Debug)] on enums
     #[test]
     fn derive_debug() {
@@ -202,4 +291,48 @@ mod tests {
         let ty = module.types.types()[0].unwrap_shared_enum();
         assert!(ty.derive.debug);
     }
+
+    /// Verify that we can parse serde's derives on enums.
+    #[test]
+    fn derive_serde() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[derive(serde::Serialize, Deserialize)]
+                enum Foo {
+                    Variant1
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_enum();
+        assert!(ty.derive.serialize);
+        assert!(ty.derive.deserialize);
+    }
+
+    /// Verify that we push an error if serde's derives are used on an enum with variant data.
+    #[test]
+    fn error_if_serde_derive_on_enum_with_data() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[derive(serde::Serialize)]
+                enum Foo {
+                    Variant1(u8)
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::UnsupportedCodableType { ty } => {
+                assert_eq!(ty, "Foo");
+            }
+            _ => panic!(),
+        };
+    }
 }
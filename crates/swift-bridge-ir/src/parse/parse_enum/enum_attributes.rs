@@ -26,8 +26,14 @@ impl SharedEnumAllAttributes {
                         syn::punctuated::Punctuated::<Path, syn::Token![,]>::parse_terminated;
                     let args = attr.parse_args_with(parser)?;
                     for arg in args.into_iter() {
-                        match arg.get_ident().unwrap().to_string().as_str() {
+                        match arg.to_token_stream().to_string().as_str() {
                             "Debug" => attributes.derive.debug = true,
+                            "Serialize" | "serde :: Serialize" => {
+                                attributes.derive.serialize = true
+                            }
+                            "Deserialize" | "serde :: Deserialize" => {
+                                attributes.derive.deserialize = true
+                            }
                             _ => todo!("Unsupported derive macro; please see https://github.com/chinedufn/swift-bridge/issues/190#issuecomment-1463234027"),
                         }
                     }
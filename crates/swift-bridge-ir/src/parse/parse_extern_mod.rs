@@ -3,22 +3,32 @@ pub(crate) use self::opaque_type_attributes::OpaqueTypeAllAttributes;
 use crate::bridged_type::{
     bridgeable_type_from_fn_arg, pat_type_pat_is_self, BridgeableType, BridgedType,
 };
-use crate::errors::{FunctionAttributeParseError, IdentifiableParseError, ParseError, ParseErrors};
+use crate::errors::{
+    FunctionAttributeParseError, IdentifiableParseError, ParseError, ParseErrors,
+    PropertyParseError, SubscriptParseError,
+};
+use crate::item_cfg::ItemCfg;
 use crate::parse::parse_extern_mod::function_attributes::FunctionAttributes;
 use crate::parse::parse_extern_mod::generics::GenericOpaqueType;
+use crate::parse::parse_extern_mod::opaque_type_attributes::OpaqueTypeField;
 use crate::parse::type_declarations::{
     OpaqueForeignTypeDeclaration, TypeDeclaration, TypeDeclarations,
 };
 use crate::parse::{HostLang, OpaqueRustTypeGenerics};
-use crate::parsed_extern_fn::fn_arg_is_mutable_reference;
-use crate::ParsedExternFn;
+use crate::parsed_extern_fn::{
+    arc_self_type, boxed_self_type, fn_arg_is_mutable_reference, pinned_mut_reference,
+    self_type_reference, unraw_arg_name, Deprecation, GetField, GetFieldDirect, SwiftProperty,
+};
+use crate::{PanicPolicy, ParsedExternFn};
 use proc_macro2::Ident;
 use quote::{format_ident, ToTokens};
-use std::cmp::Ordering;
 use std::collections::{HashMap, HashSet};
 use std::ops::Deref;
+use syn::spanned::Spanned;
 use syn::{
-    FnArg, ForeignItem, ForeignItemFn, GenericParam, ItemForeignMod, LitStr, Pat, ReturnType, Type,
+    parse_quote, Attribute, FnArg, ForeignItem, ForeignItemFn, GenericArgument, GenericParam,
+    ItemForeignMod, Lit, LitStr, Meta, MetaNameValue, NestedMeta, Pat, PathArguments, ReturnType,
+    Type,
 };
 
 mod argument_attributes;
@@ -55,13 +65,11 @@ impl<'a> ForeignModParser<'a> {
             }
         };
 
-        foreign_mod.items.sort_by(|a, _b| {
-            if matches!(a, ForeignItem::Type(_)) {
-                Ordering::Less
-            } else {
-                Ordering::Greater
-            }
-        });
+        // Types are parsed before functions so that functions can use the types that are declared
+        // after them. The sort is stable, so the types stay in the order that they're declared in.
+        foreign_mod
+            .items
+            .sort_by_key(|item| !matches!(item, ForeignItem::Type(_)));
 
         let mut local_type_declarations = HashMap::new();
         for foreign_mod_item in foreign_mod.items {
@@ -88,21 +96,61 @@ impl<'a> ForeignModParser<'a> {
                         attributes: OpaqueTypeAllAttributes::from_attributes(&foreign_ty.attrs)?,
                         generics: OpaqueRustTypeGenerics::new(),
                     };
-                    self.type_declarations.insert(
+                    if foreign_type.attributes.comparable && !foreign_type.attributes.equatable {
+                        self.errors.push(ParseError::ComparableWithoutEquatable {
+                            ty: foreign_ty.ident.clone(),
+                        });
+                    }
+                    if foreign_type.attributes.hashable && !foreign_type.attributes.equatable {
+                        self.errors.push(ParseError::HashableWithoutEquatable {
+                            ty: foreign_ty.ident.clone(),
+                        });
+                    }
+                    if let Some(error) = self.type_declarations.insert(
                         ty_name.clone(),
                         TypeDeclaration::Opaque(foreign_type.clone()),
-                    );
+                    ) {
+                        self.errors.push(error);
+                        continue;
+                    }
+                    self.push_field_accessors(&foreign_type);
+                    self.push_stream_next(&foreign_type);
                     local_type_declarations.insert(ty_name, foreign_type);
                 }
                 ForeignItem::Fn(func) => {
                     let mut attributes = FunctionAttributes::default();
 
+                    let mut deprecation = None;
+                    let mut cfgs = vec![];
+
                     for attr in func.attrs.iter() {
+                        if attr.path.is_ident("doc") {
+                            continue;
+                        }
+                        if attr.path.is_ident("cfg") {
+                            cfgs.push(ItemCfg::from_attribute(attr)?);
+                            continue;
+                        }
+                        if attr.path.is_ident("deprecated") {
+                            deprecation = Some(parse_deprecation(attr)?);
+                            continue;
+                        }
                         attributes = attr.parse_args()?;
                     }
+                    let doc_comment = doc_comment(&func.attrs);
 
                     for arg in func.sig.inputs.iter() {
                         if let FnArg::Typed(pat_ty) = arg {
+                            // A `self: Pin<&mut Self>`, `self: Box<Self>` or `self: Arc<Self>`
+                            // gets resolved alongside the method's associated type.
+                            if pat_type_pat_is_self(pat_ty)
+                                && (pinned_mut_reference(&pat_ty.ty).is_some()
+                                    || boxed_self_type(&pat_ty.ty).is_some()
+                                    || arc_self_type(&pat_ty.ty).is_some())
+                            {
+                                continue;
+                            }
+
                             let ty = &pat_ty.ty;
                             if BridgedType::new_with_type(&ty, &self.type_declarations).is_none() {
                                 self.unresolved_types.push(ty.deref().clone());
@@ -128,6 +176,25 @@ impl<'a> ForeignModParser<'a> {
                         &mut local_type_declarations,
                     )?;
 
+                    if let Some(TypeDeclaration::Shared(_)) = associated_type.as_ref() {
+                        if let Some(self_) = func.sig.inputs.first().filter(|arg| {
+                            matches!(arg, FnArg::Typed(pat_ty) if pat_type_pat_is_self(pat_ty))
+                        }) {
+                            self.errors.push(ParseError::MethodOnSharedType {
+                                self_: self_.clone(),
+                            });
+                            continue;
+                        }
+                    }
+
+                    if let Some(TypeDeclaration::Opaque(opaque_ty)) = associated_type.as_ref() {
+                        if let Some(getter) = opaque_ty.attributes.identifiable.as_ref() {
+                            if func.sig.ident == getter.value() {
+                                attributes.is_swift_identifiable = true;
+                            }
+                        }
+                    }
+
                     if attributes.is_swift_identifiable {
                         let args = &func.sig.inputs;
 
@@ -166,6 +233,7 @@ impl<'a> ForeignModParser<'a> {
                         }
                     }
                     let mut argument_labels: HashMap<Ident, LitStr> = HashMap::new();
+                    let mut argument_defaults: HashMap<Ident, String> = HashMap::new();
                     for arg in func.sig.inputs.iter() {
                         let is_mutable_ref = fn_arg_is_mutable_reference(arg);
 
@@ -188,20 +256,35 @@ impl<'a> ForeignModParser<'a> {
                             syn::FnArg::Typed(ty) => {
                                 for attr in ty.attrs.iter() {
                                     let attribute: ArgumentAttributes = attr.parse_args()?;
+                                    let arg_name =
+                                        format_ident!("{}", ty.pat.to_token_stream().to_string());
                                     if let Some(label) = attribute.label {
-                                        argument_labels.insert(
-                                            format_ident!(
-                                                "{}",
-                                                ty.pat.to_token_stream().to_string()
-                                            ),
-                                            label,
-                                        );
+                                        argument_labels.insert(arg_name.clone(), label);
+                                    }
+                                    if let Some(default) = attribute.default {
+                                        argument_defaults.insert(arg_name, default);
                                     }
                                 }
                             }
                             _ => {}
                         }
                     }
+                    if attributes.labeled_args {
+                        for arg in func.sig.inputs.iter() {
+                            if let FnArg::Typed(pat_ty) = arg {
+                                if pat_type_pat_is_self(pat_ty) {
+                                    continue;
+                                }
+
+                                let arg_name = pat_ty.pat.to_token_stream().to_string();
+                                argument_labels
+                                    .entry(format_ident!("{}", arg_name))
+                                    .or_insert_with(|| {
+                                        LitStr::new(unraw_arg_name(&arg_name), pat_ty.pat.span())
+                                    });
+                            }
+                        }
+                    }
                     if let Some(ref args) = attributes.args_into {
                         let mut func_sig_args = HashSet::with_capacity(args.len());
                         for fn_arg in func.sig.inputs.iter() {
@@ -225,7 +308,10 @@ impl<'a> ForeignModParser<'a> {
                             }
                         }
                     }
-                    let func = ParsedExternFn {
+                    if let Some(TypeDeclaration::Opaque(opaque_ty)) = associated_type.as_ref() {
+                        cfgs.extend(opaque_ty.attributes.cfgs.iter().cloned());
+                    }
+                    let mut func = ParsedExternFn {
                         func,
                         associated_type,
                         is_swift_initializer: attributes.is_swift_initializer,
@@ -238,7 +324,40 @@ impl<'a> ForeignModParser<'a> {
                         args_into: attributes.args_into,
                         get_field: attributes.get_field,
                         argument_labels: argument_labels,
+                        argument_defaults,
+                        swift_property: None,
+                        is_swift_subscript: attributes.is_swift_subscript,
+                        is_stream_next: false,
+                        swift_publisher: attributes.publisher,
+                        is_swift_discardable_result: attributes.discardable_result,
+                        swift_access: attributes.swift_access,
+                        doc_comment,
+                        deprecation,
+                        cfgs,
+                        source_file: None,
+                        panic_policy: PanicPolicy::default(),
                     };
+                    if let Some(name) = attributes.property {
+                        match property_accessor(&func, name) {
+                            Some(swift_property) => func.swift_property = Some(swift_property),
+                            None => self.errors.push(ParseError::FunctionAttribute(
+                                FunctionAttributeParseError::Property(
+                                    PropertyParseError::InvalidAccessor {
+                                        fn_ident: func.func.sig.ident.clone(),
+                                    },
+                                ),
+                            )),
+                        }
+                    }
+                    if func.is_swift_subscript && !is_subscript(&func) {
+                        self.errors.push(ParseError::FunctionAttribute(
+                            FunctionAttributeParseError::Subscript(
+                                SubscriptParseError::InvalidSubscript {
+                                    fn_ident: func.func.sig.ident.clone(),
+                                },
+                            ),
+                        ));
+                    }
                     self.functions.push(func);
                 }
                 ForeignItem::Verbatim(foreign_item_verbatim) => {
@@ -276,8 +395,21 @@ impl<'a> ForeignModParser<'a> {
                             .collect();
                         let generics: String = generics.join(",");
                         let ty_name = format!("{}<{}>", ty_name, generics);
-                        self.type_declarations
-                            .insert(ty_name.clone(), TypeDeclaration::Opaque(foreign_ty.clone()));
+                        if let Some(error) = self
+                            .type_declarations
+                            .insert(ty_name.clone(), TypeDeclaration::Opaque(foreign_ty.clone()))
+                        {
+                            self.errors.push(error);
+                            continue;
+                        }
+                        // A monomorphization's generics are concrete types, which need to be
+                        // bridged like any other type.
+                        if !foreign_ty.attributes.declare_generic {
+                            for generic in foreign_ty.generics.generics.iter() {
+                                let ident = &generic.ident;
+                                self.unresolved_types.push(syn::parse_quote!(#ident));
+                            }
+                        }
                         local_type_declarations.insert(ty_name, foreign_ty);
                     }
                 }
@@ -288,6 +420,139 @@ impl<'a> ForeignModParser<'a> {
         Ok(())
     }
 
+    /// Push the functions that back the Swift computed properties that an opaque Rust type's
+    /// `#[swift_bridge(get(..))]` and `#[swift_bridge(set(..))]` attributes declare.
+    ///
+    /// `#[swift_bridge(set(x: f64))]` also gives the `x` property a getter.
+    fn push_field_accessors(&mut self, ty: &OpaqueForeignTypeDeclaration) {
+        // An already declared type's fields are exposed by the bridge module that declares it.
+        if ty.host_lang.is_swift() || ty.attributes.already_declared {
+            return;
+        }
+
+        let attributes = &ty.attributes;
+        let getters = attributes.get_fields.iter().chain(
+            attributes
+                .set_fields
+                .iter()
+                .filter(|set| !attributes.get_fields.iter().any(|get| get.name == set.name)),
+        );
+
+        let mut accessors = vec![];
+        for field in getters {
+            let OpaqueTypeField {
+                maybe_ref,
+                name,
+                ty: field_ty,
+            } = field;
+            let fn_name = format_ident!("_get_{}", name, span = name.span());
+
+            accessors.push((
+                parse_quote! { fn #fn_name(&self) -> #field_ty; },
+                Some(GetField::Direct(GetFieldDirect {
+                    maybe_ref: *maybe_ref,
+                    maybe_mut: None,
+                    field_name: name.clone(),
+                })),
+                SwiftProperty::Getter { name: name.clone() },
+            ));
+        }
+        for field in attributes.set_fields.iter() {
+            let OpaqueTypeField {
+                name, ty: field_ty, ..
+            } = field;
+            let fn_name = format_ident!("_set_{}", name, span = name.span());
+            let func: ForeignItemFn = parse_quote! { fn #fn_name(&mut self, value: #field_ty); };
+
+            if attributes.copy.is_some() {
+                self.errors.push(ParseError::ArgCopyAndRefMut {
+                    arg: func.sig.inputs[0].clone(),
+                });
+            }
+
+            accessors.push((
+                func,
+                None,
+                SwiftProperty::Setter {
+                    name: name.clone(),
+                    sets_field: true,
+                },
+            ));
+        }
+
+        for (func, get_field, swift_property) in accessors {
+            self.push_generated_method(ty, func, get_field, Some(swift_property), false);
+        }
+    }
+
+    /// Push the `async fn next(&mut self) -> Option<T>` that polls a
+    /// `#[swift_bridge(Stream(Item = T))]` type for its next item, which the type's Swift
+    /// `AsyncIteratorProtocol` conformance uses.
+    fn push_stream_next(&mut self, ty: &OpaqueForeignTypeDeclaration) {
+        // An already declared type's stream is exposed by the bridge module that declares it.
+        if ty.host_lang.is_swift() || ty.attributes.already_declared {
+            return;
+        }
+        let item = match ty.attributes.stream_item.as_ref() {
+            Some(item) => item,
+            None => return,
+        };
+
+        let func: ForeignItemFn = parse_quote! { async fn next(&mut self) -> Option<#item>; };
+        self.push_generated_method(ty, func, None, None, true);
+    }
+
+    /// Push a method that was generated from one of an opaque Rust type's attributes.
+    fn push_generated_method(
+        &mut self,
+        ty: &OpaqueForeignTypeDeclaration,
+        func: ForeignItemFn,
+        get_field: Option<GetField>,
+        swift_property: Option<SwiftProperty>,
+        is_stream_next: bool,
+    ) {
+        for arg_ty in func.sig.inputs.iter().filter_map(|arg| match arg {
+            FnArg::Typed(pat_ty) => Some(pat_ty.ty.deref()),
+            FnArg::Receiver(_) => None,
+        }) {
+            if BridgedType::new_with_type(arg_ty, self.type_declarations).is_none() {
+                self.unresolved_types.push(arg_ty.clone());
+            }
+        }
+        if let ReturnType::Type(_, ret_ty) = &func.sig.output {
+            if BridgedType::new_with_type(ret_ty, self.type_declarations).is_none() {
+                self.unresolved_types.push(ret_ty.deref().clone());
+            }
+        }
+
+        self.functions.push(ParsedExternFn {
+            func,
+            associated_type: Some(TypeDeclaration::Opaque(ty.clone())),
+            host_lang: ty.host_lang,
+            is_swift_initializer: false,
+            is_swift_identifiable: false,
+            rust_name_override: None,
+            swift_name_override: None,
+            return_into: false,
+            return_with: None,
+            args_into: None,
+            get_field,
+            argument_labels: HashMap::new(),
+            argument_defaults: HashMap::new(),
+            swift_property,
+            is_swift_subscript: false,
+            is_stream_next,
+            swift_publisher: None,
+            is_swift_discardable_result: false,
+            swift_access: None,
+            doc_comment: None,
+            deprecation: None,
+            cfgs: ty.attributes.cfgs.clone(),
+            source_file: None,
+            panic_policy: PanicPolicy::default(),
+        });
+    }
+
     fn get_associated_type(
         &mut self,
         first: Option<&FnArg>,
@@ -308,7 +573,7 @@ impl<'a> ForeignModParser<'a> {
                     associated_type
                 } else {
                     self.errors.push(ParseError::AmbiguousSelf {
-                        self_: recv.clone(),
+                        self_: FnArg::Receiver(recv.clone()),
                     });
                     return Ok(None);
                 }
@@ -316,18 +581,37 @@ impl<'a> ForeignModParser<'a> {
             Some(FnArg::Typed(arg)) => match arg.pat.deref() {
                 Pat::Ident(pat_ident) => {
                     if pat_ident.ident.to_string() == "self" {
-                        let self_ty = match arg.ty.deref() {
-                            Type::Path(ty_path) => ty_path.path.segments.to_token_stream(),
-                            Type::Reference(type_ref) => type_ref.elem.deref().to_token_stream(),
-                            _ => {
-                                todo!("Add a test that hits this branch")
-                            }
+                        let self_ty = match self_type_reference(&arg.ty) {
+                            Some(type_ref) => type_ref.elem.deref().to_token_stream(),
+                            None => match boxed_self_type(&arg.ty)
+                                .or_else(|| arc_self_type(&arg.ty))
+                                .unwrap_or(arg.ty.deref())
+                            {
+                                Type::Path(ty_path) => ty_path.path.segments.to_token_stream(),
+                                _ => {
+                                    todo!("Add a test that hits this branch")
+                                }
+                            },
                         };
 
                         let self_ty_string = self_ty.to_string();
                         // Handles generics. i.e. "SomeType< u32, u64 >" -> "SomeType<u32,u64>";
                         let self_ty_string = self_ty_string.replace(" ", "");
 
+                        // `self: Pin<&mut Self>`, `self: Box<Self>` and `self: Arc<Self>` refer
+                        // to the only type declared in the block.
+                        if self_ty_string == "Self" {
+                            if local_type_declarations.len() == 1 {
+                                let ty = local_type_declarations.iter_mut().next().unwrap().1;
+                                return Ok(Some(TypeDeclaration::Opaque(ty.clone())));
+                            } else {
+                                self.errors.push(ParseError::AmbiguousSelf {
+                                    self_: FnArg::Typed(arg.clone()),
+                                });
+                                return Ok(None);
+                            }
+                        }
+
                         let ty = self.type_declarations.get(&self_ty_string).unwrap();
                         let associated_type = Some(ty.clone());
                         associated_type
@@ -369,8 +653,10 @@ Otherwise we use a more general error that says that your argument is invalid.
                             todo!("Push error if initializer does not return a type")
                         }
                         ReturnType::Type(_, ty) => {
-                            let ty_string = ty.deref().to_token_stream().to_string();
-                            ty_string
+                            // Failable initializers return `Option<Self>`.
+                            let ty = option_inner_type(ty).unwrap_or(ty);
+                            // Handles generics. i.e. "SomeType < u32 >" -> "SomeType<u32>"
+                            ty.to_token_stream().to_string().replace(" ", "")
                         }
                     };
 
@@ -389,11 +675,134 @@ Otherwise we use a more general error that says that your argument is invalid.
     }
 }
 
+/// The lines of the `///` doc comment in a list of attributes, separated by newlines.
+fn doc_comment(attrs: &[Attribute]) -> Option<String> {
+    // Doc attributes that aren't string literals, such as `#[doc(hidden)]`, aren't documentation
+    // that we can carry over to Swift.
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("doc"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::NameValue(MetaNameValue {
+                lit: Lit::Str(comment),
+                ..
+            })) => Some(comment.value()),
+            _ => None,
+        })
+        .collect();
+
+    if lines.is_empty() {
+        None
+    } else {
+        Some(lines.join("\n"))
+    }
+}
+
+/// Parse a `#[deprecated]`, `#[deprecated = ".."]` or `#[deprecated(since = "..", note = "..")]`
+/// attribute.
+fn parse_deprecation(attr: &Attribute) -> syn::Result<Deprecation> {
+    let note = match attr.parse_meta()? {
+        Meta::Path(_) => None,
+        Meta::NameValue(MetaNameValue {
+            lit: Lit::Str(note),
+            ..
+        }) => Some(note.value()),
+        Meta::List(list) => {
+            let mut note = None;
+            for nested in list.nested.iter() {
+                match nested {
+                    NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                        path,
+                        lit: Lit::Str(value),
+                        ..
+                    })) if path.is_ident("note") => {
+                        note = Some(value.value());
+                    }
+                    NestedMeta::Meta(Meta::NameValue(MetaNameValue { path, .. }))
+                        if path.is_ident("since") => {}
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            nested,
+                            "Expected `since = \"..\"` or `note = \"..\"`.",
+                        ))
+                    }
+                }
+            }
+            note
+        }
+        meta => {
+            return Err(syn::Error::new_spanned(
+                meta,
+                "Expected `#[deprecated]` or `#[deprecated(note = \"..\")]`.",
+            ))
+        }
+    };
+
+    Ok(Deprecation { note })
+}
+
+/// The accessor that a `#[swift_bridge(property = "...")]` method is, if it is either a getter or a
+/// setter of one of an opaque Rust type's properties.
+fn property_accessor(func: &ParsedExternFn, name: Ident) -> Option<SwiftProperty> {
+    let is_opaque_rust_type_method = matches!(
+        &func.associated_type,
+        Some(TypeDeclaration::Opaque(ty)) if ty.host_lang.is_rust()
+    ) && func.self_reference().is_some();
+    if !is_opaque_rust_type_method || func.sig.asyncness.is_some() {
+        return None;
+    }
+
+    let has_return_type = matches!(&func.sig.output, ReturnType::Type(_, _));
+    match (
+        func.self_mutability().is_some(),
+        func.sig.inputs.len(),
+        has_return_type,
+    ) {
+        (false, 1, true) => Some(SwiftProperty::Getter { name }),
+        (true, 2, false) => Some(SwiftProperty::Setter {
+            name,
+            sets_field: false,
+        }),
+        _ => None,
+    }
+}
+
+/// Whether a `#[swift_bridge(subscript)]` function takes `&self` and an index, and returns a value.
+fn is_subscript(func: &ParsedExternFn) -> bool {
+    func.host_lang.is_rust()
+        && func.self_reference().is_some()
+        && func.self_mutability().is_none()
+        && func.sig.asyncness.is_none()
+        && func.sig.inputs.len() == 2
+        && matches!(&func.sig.output, ReturnType::Type(_, _))
+}
+
+/// `Option<T>` -> `Some(T)`
+fn option_inner_type(ty: &Type) -> Option<&Type> {
+    let path = match ty {
+        Type::Path(path) => path,
+        _ => return None,
+    };
+    let last = path.path.segments.last()?;
+    if last.ident != "Option" {
+        return None;
+    }
+
+    match &last.arguments {
+        PathArguments::AngleBracketed(args) => match args.args.first()? {
+            GenericArgument::Type(inner) => Some(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::errors::ParseError;
+    use crate::errors::{FunctionAttributeParseError, IdentifiableParseError, ParseError};
+    use crate::parse::parse_extern_mod::opaque_type_attributes::OpaqueOperator;
     use crate::test_utils::{parse_errors, parse_ok};
-    use crate::SwiftBridgeModule;
+    use crate::{SwiftAccess, SwiftBridgeModule};
     use quote::{quote, ToTokens};
     use syn::parse_quote;
 
@@ -625,8 +1034,8 @@ mod tests {
                     type Foo;
 
                     fn a () -> Bar;
-                    fn a () -> &Bar;
-                    fn a () -> &mut Bar;
+                    fn b () -> &Bar;
+                    fn c () -> &mut Bar;
                 }
             }
         };
@@ -647,6 +1056,36 @@ mod tests {
         }
     }
 
+    /// Verify that we push an error for functions that take a shared struct or enum as `self`.
+    #[test]
+    fn error_if_method_on_shared_type() {
+        let tokens = quote! {
+            mod foo {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct;
+
+                enum SomeEnum {
+                    Variant
+                }
+
+                extern "Rust" {
+                    fn a (self: SomeStruct);
+                    fn b (self: &SomeEnum);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 2);
+        for error in errors.iter() {
+            match error {
+                ParseError::MethodOnSharedType { self_: _ } => {}
+                _ => panic!(),
+            };
+        }
+    }
+
     /// Verify that if an extern Rust block has more than one type, we push errors for any methods
     /// that have an ambiguous self.
     #[test]
@@ -660,15 +1099,18 @@ mod tests {
                     fn a (self);
                     fn b (&self);
                     fn c (&mut self);
+                    fn d (self: Pin<&mut Self>);
+                    fn e (self: Box<Self>);
+                    fn f (self: Arc<Self>);
                 }
             }
         };
 
         let errors = parse_errors(tokens);
 
-        assert_eq!(errors.len(), 3);
+        assert_eq!(errors.len(), 6);
 
-        for idx in 0..3 {
+        for idx in 0..6 {
             match &errors[idx] {
                 ParseError::AmbiguousSelf { self_: _ } => {}
                 _ => panic!(),
@@ -707,6 +1149,114 @@ mod tests {
         }
     }
 
+    /// Verify that methods that take a pinned mutable reference to self get parsed.
+    #[test]
+    fn parse_pinned_self_method() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    type SomeType;
+                    type AnotherType;
+
+                    fn a (self: Pin<&mut SomeType>);
+                    fn b (self: std::pin::Pin<&mut AnotherType>);
+                }
+
+                extern "Rust" {
+                    type ThirdType;
+
+                    fn c (self: Pin<&mut Self>);
+                }
+            }
+        };
+
+        let module = parse_ok(tokens.clone());
+        assert_eq!(parse_errors(tokens).len(), 0);
+
+        let functions = &module.functions;
+        for (idx, ty_name) in ["SomeType", "AnotherType", "ThirdType"].iter().enumerate() {
+            let func = &functions[idx];
+            assert_eq!(
+                func.associated_type.as_ref().unwrap().unwrap_opaque().ty,
+                ty_name
+            );
+            assert!(func.is_self_pinned());
+            assert!(func.self_mutability().is_some());
+        }
+    }
+
+    /// Verify that we can parse a method that takes a `self: Box<Self>`.
+    #[test]
+    fn parse_boxed_self_method() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    type SomeType;
+                    type AnotherType;
+
+                    fn a (self: Box<SomeType>);
+                    fn b (self: std::boxed::Box<AnotherType>);
+                }
+
+                extern "Rust" {
+                    type ThirdType;
+
+                    fn c (self: Box<Self>);
+                }
+            }
+        };
+
+        let module = parse_ok(tokens.clone());
+        assert_eq!(parse_errors(tokens).len(), 0);
+
+        let functions = &module.functions;
+        for (idx, ty_name) in ["SomeType", "AnotherType", "ThirdType"].iter().enumerate() {
+            let func = &functions[idx];
+            assert_eq!(
+                func.associated_type.as_ref().unwrap().unwrap_opaque().ty,
+                ty_name
+            );
+            assert!(func.is_self_boxed());
+            assert!(func.self_reference().is_none());
+        }
+    }
+
+    /// Verify that we can parse a method that takes a `self: Arc<Self>`.
+    #[test]
+    fn parse_arc_self_method() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    type SomeType;
+                    type AnotherType;
+
+                    fn a (self: Arc<SomeType>);
+                    fn b (self: std::sync::Arc<AnotherType>);
+                }
+
+                extern "Rust" {
+                    type ThirdType;
+
+                    fn c (self: Arc<Self>);
+                }
+            }
+        };
+
+        let module = parse_ok(tokens.clone());
+        assert_eq!(parse_errors(tokens).len(), 0);
+
+        let functions = &module.functions;
+        for (idx, ty_name) in ["SomeType", "AnotherType", "ThirdType"].iter().enumerate() {
+            let func = &functions[idx];
+            assert_eq!(
+                func.associated_type.as_ref().unwrap().unwrap_opaque().ty,
+                ty_name
+            );
+            assert!(func.is_self_arc());
+            assert!(func.self_reference().is_none());
+        }
+    }
+
     /// Verify that if we have multiple externs types can be inferred within each.
     #[test]
     fn infer_type_with_multiple_externs() {
@@ -795,7 +1345,7 @@ mod tests {
         let tokens = quote! {
             mod foo {
                 extern "Rust" {
-                    #[swift_bridge(Hashable)]
+                    #[swift_bridge(Hashable, Equatable)]
                     type SomeType;
                 }
             }
@@ -841,14 +1391,18 @@ mod tests {
         );
     }
 
-    /// Verify that we can parse the `copy` attribute.
+    /// Verify that the `Identifiable = "..."` type attribute marks the named method as the
+    /// type's `Identifiable` implementation.
     #[test]
-    fn parse_copy_attribute() {
+    fn parse_identifiable_type_attribute() {
         let tokens = quote! {
             mod foo {
                 extern "Rust" {
-                    #[swift_bridge(Copy(4))]
+                    #[swift_bridge(Identifiable = "id")]
                     type SomeType;
+
+                    fn id(&self) -> u32;
+                    fn other(&self) -> u32;
                 }
             }
         };
@@ -862,114 +1416,728 @@ mod tests {
                 .unwrap()
                 .unwrap_opaque()
                 .attributes
-                .copy
+                .identifiable
+                .as_ref()
                 .unwrap()
-                .size_bytes,
-            4
+                .value(),
+            "id"
         );
+        assert!(module.functions[0].is_swift_identifiable);
+        assert!(!module.functions[1].is_swift_identifiable);
     }
 
-    /// Verify that we can parse multiple atributes from an opaque type.
+    /// Verify that we push an error if the `Identifiable = "..."` getter was not declared.
     #[test]
-    fn parse_multiple_attributes() {
+    fn error_if_identifiable_type_attribute_getter_not_found() {
         let tokens = quote! {
             mod foo {
                 extern "Rust" {
-                    #[swift_bridge(already_declared, Copy(4))]
+                    #[swift_bridge(Identifiable = "id")]
                     type SomeType;
+
+                    fn other(&self) -> u32;
                 }
             }
         };
 
-        let module = parse_ok(tokens);
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
 
-        let ty = module.types.get("SomeType").unwrap().unwrap_opaque();
-        assert!(ty.attributes.copy.is_some());
-        assert!(ty.attributes.already_declared)
+        match &errors[0] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Identifiable(
+                IdentifiableParseError::GetterNotFound { ty, getter },
+            )) => {
+                assert_eq!(ty, "SomeType");
+                assert_eq!(getter.value(), "id");
+            }
+            _ => panic!(),
+        };
     }
 
-    /// Verify that we can parse a doc comment from an extern "Rust" opaque type.
+    /// Verify that we can parse the `Comparable` attribute.
     #[test]
-    fn parse_opaque_rust_type_doc_comment() {
+    fn parse_comparable_attribute() {
         let tokens = quote! {
             mod foo {
                 extern "Rust" {
-                    /// Some comment
-                    type AnotherType;
+                    #[swift_bridge(Equatable, Comparable)]
+                    type SomeType;
                 }
             }
         };
 
         let module = parse_ok(tokens);
 
-        assert_eq!(
+        assert!(
             module
                 .types
-                .get("AnotherType")
+                .get("SomeType")
                 .unwrap()
                 .unwrap_opaque()
                 .attributes
-                .doc_comment
-                .as_ref()
-                .unwrap(),
-            " Some comment"
+                .comparable
         );
     }
 
-    /// Verify that we push errors for unknown arguments in a function
+    /// Verify that we push an error if a `Comparable` type is not also `Equatable`, since Swift's
+    /// `Comparable` inherits from `Equatable`.
     #[test]
-    fn error_args_into_arg_not_found_in_function() {
+    fn error_if_comparable_without_equatable() {
         let tokens = quote! {
-            #[swift_bridge::bridge]
-            mod ffi {
+            mod foo {
                 extern "Rust" {
-                    #[swift_bridge(args_into = (foo, bar))]
-                    fn some_function(foo: u8);
+                    #[swift_bridge(Comparable)]
+                    type SomeType;
+
+                    #[swift_bridge(Copy(4), Comparable)]
+                    type AnotherType;
                 }
             }
         };
 
         let errors = parse_errors(tokens);
 
-        // Only "bar" should be missing argument.
-        assert_eq!(errors.len(), 1);
-        match &errors[0] {
-            ParseError::ArgsIntoArgNotFound {
-                func: _,
-                missing_arg,
-            } => {
-                assert_eq!(missing_arg, "bar")
-            }
-            _ => panic!(),
+        assert_eq!(errors.len(), 2);
+        for (idx, expected) in vec!["SomeType", "AnotherType"].into_iter().enumerate() {
+            match &errors[idx] {
+                ParseError::ComparableWithoutEquatable { ty } => assert_eq!(ty, expected),
+                _ => panic!(),
+            };
         }
     }
 
-    /// Verify that we push errors for function arguments that are both mutable and opaque Copy.
+    /// Verify that we push an error if a `Hashable` type is not also `Equatable`, since Swift's
+    /// `Hashable` inherits from `Equatable`.
     #[test]
-    fn error_if_mutable_opaque_copy_type() {
+    fn error_if_hashable_without_equatable() {
         let tokens = quote! {
-            #[swift_bridge:bridge]
             mod foo {
                 extern "Rust" {
-                    #[swift_bridge(Copy(2))]
+                    #[swift_bridge(Hashable)]
                     type SomeType;
 
-                    fn a(&mut self);
-                    fn b(self: &mut SomeType);
-                    fn c(arg: &mut SomeType);
+                    #[swift_bridge(Copy(4), Hashable)]
+                    type AnotherType;
                 }
             }
         };
 
         let errors = parse_errors(tokens);
 
-        assert_eq!(errors.len(), 3);
-
-        for error in errors.iter() {
+        assert_eq!(errors.len(), 2);
+        for (idx, expected) in vec!["SomeType", "AnotherType"].into_iter().enumerate() {
+            match &errors[idx] {
+                ParseError::HashableWithoutEquatable { ty } => assert_eq!(ty, expected),
+                _ => panic!(),
+            };
+        }
+    }
+
+    /// Verify that we can parse the `std::ops` operator attributes.
+    #[test]
+    fn parse_operator_attributes() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Add, Sub, Mul, Div, Rem, Neg)]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let operators = &module
+            .types
+            .get("SomeType")
+            .unwrap()
+            .unwrap_opaque()
+            .attributes
+            .operators;
+        assert!(
+            operators
+                == &vec![
+                    OpaqueOperator::Add,
+                    OpaqueOperator::Sub,
+                    OpaqueOperator::Mul,
+                    OpaqueOperator::Div,
+                    OpaqueOperator::Rem,
+                    OpaqueOperator::Neg,
+                ]
+        );
+    }
+
+    /// Verify that we can parse the `swift_access` attribute.
+    #[test]
+    fn parse_swift_access_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(swift_access = "internal")]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(
+            module
+                .types
+                .get("SomeType")
+                .unwrap()
+                .unwrap_opaque()
+                .attributes
+                .swift_access,
+            Some(SwiftAccess::Internal)
+        );
+    }
+
+    /// Verify that we can parse the `CustomStringConvertible` attribute.
+    #[test]
+    fn parse_custom_string_convertible_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(CustomStringConvertible)]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert!(
+            module
+                .types
+                .get("SomeType")
+                .unwrap()
+                .unwrap_opaque()
+                .attributes
+                .custom_string_convertible
+        );
+    }
+
+    /// Verify that we can parse the `CustomDebugStringConvertible` attribute.
+    #[test]
+    fn parse_custom_debug_string_convertible_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(CustomDebugStringConvertible)]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert!(
+            module
+                .types
+                .get("SomeType")
+                .unwrap()
+                .unwrap_opaque()
+                .attributes
+                .custom_debug_string_convertible
+        );
+    }
+
+    /// Verify that we can parse the `Sendable` attribute.
+    #[test]
+    fn parse_sendable_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Sendable)]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert!(
+            module
+                .types
+                .get("SomeType")
+                .unwrap()
+                .unwrap_opaque()
+                .attributes
+                .sendable
+        );
+    }
+
+    /// Verify that we can parse the `Actor` attribute.
+    #[test]
+    fn parse_actor_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Actor)]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert!(
+            module
+                .types
+                .get("SomeType")
+                .unwrap()
+                .unwrap_opaque()
+                .attributes
+                .actor
+        );
+    }
+
+    /// Verify that we can parse the `Iterator` attribute.
+    #[test]
+    fn parse_iterator_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Iterator)]
+                    type SomeType;
+
+                    fn next(&mut self) -> Option<u8>;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert!(
+            module
+                .types
+                .get("SomeType")
+                .unwrap()
+                .unwrap_opaque()
+                .attributes
+                .iterator
+        );
+    }
+
+    /// Verify that we push an error if an `Iterator` type does not have a
+    /// `fn next(&mut self) -> Option<T>` method.
+    #[test]
+    fn error_if_iterator_next_not_found() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Iterator)]
+                    type SomeType;
+
+                    fn next(&self) -> Option<u8>;
+                }
+
+                extern "Rust" {
+                    #[swift_bridge(Iterator)]
+                    type AnotherType;
+
+                    fn next(&mut self) -> u8;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 2);
+        for (idx, expected) in vec!["SomeType", "AnotherType"].into_iter().enumerate() {
+            match &errors[idx] {
+                ParseError::IteratorNextNotFound { ty } => assert_eq!(ty, expected),
+                _ => panic!(),
+            };
+        }
+    }
+
+    /// Verify that we can parse the `ObservableObject` attribute.
+    #[test]
+    fn parse_observable_object_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(ObservableObject = "on_change")]
+                    type SomeType;
+
+                    fn on_change(&self, callback: Box<dyn Fn()>);
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.get("SomeType").unwrap().unwrap_opaque();
+        assert_eq!(
+            ty.attributes.observable_object.as_ref().unwrap().value(),
+            "on_change"
+        );
+    }
+
+    /// Verify that we push an error if an `ObservableObject` type does not have a method that
+    /// registers its change callback.
+    #[test]
+    fn error_if_observable_object_on_change_not_found() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(ObservableObject = "on_change")]
+                    type SomeType;
+                }
+
+                extern "Rust" {
+                    #[swift_bridge(ObservableObject = "on_change")]
+                    type AnotherType;
+
+                    fn on_change(&self, callback: Box<dyn Fn(u8)>);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 2);
+        for (idx, expected) in vec!["SomeType", "AnotherType"].into_iter().enumerate() {
+            match &errors[idx] {
+                ParseError::OnChangeNotFound { ty, on_change } => {
+                    assert_eq!(ty, expected);
+                    assert_eq!(on_change.value(), "on_change");
+                }
+                _ => panic!(),
+            };
+        }
+    }
+
+    /// Verify that we can parse the `Stream` attribute, and that we generate the `next` method
+    /// that polls the stream.
+    #[test]
+    fn parse_stream_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Stream(Item = u8))]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.get("SomeType").unwrap().unwrap_opaque();
+        assert_eq!(
+            ty.attributes
+                .stream_item
+                .as_ref()
+                .unwrap()
+                .to_token_stream()
+                .to_string(),
+            "u8"
+        );
+
+        assert_eq!(module.functions.len(), 1);
+        let next = &module.functions[0];
+        assert_eq!(next.sig.ident, "next");
+        assert!(next.sig.asyncness.is_some());
+        assert!(next.is_stream_next);
+    }
+
+    /// Verify that we can parse the `copy` attribute.
+    #[test]
+    fn parse_copy_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Copy(4))]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(
+            module
+                .types
+                .get("SomeType")
+                .unwrap()
+                .unwrap_opaque()
+                .attributes
+                .copy
+                .unwrap()
+                .size_bytes,
+            4
+        );
+    }
+
+    /// Verify that we can parse multiple atributes from an opaque type.
+    #[test]
+    fn parse_multiple_attributes() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(already_declared, Copy(4))]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.get("SomeType").unwrap().unwrap_opaque();
+        assert!(ty.attributes.copy.is_some());
+        assert!(ty.attributes.already_declared)
+    }
+
+    /// Verify that we can parse the `get` and `set` attributes, and that they generate the
+    /// functions that back the type's Swift computed properties.
+    #[test]
+    fn parse_get_and_set_attributes() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(get(x: f64, &name: &str), set(count: u32))]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.get("SomeType").unwrap().unwrap_opaque();
+        assert_eq!(ty.attributes.get_fields.len(), 2);
+        assert!(ty.attributes.get_fields[1].maybe_ref.is_some());
+        assert_eq!(ty.attributes.set_fields.len(), 1);
+
+        let functions: Vec<String> = module
+            .functions
+            .iter()
+            .map(|f| f.sig.ident.to_string())
+            .collect();
+        assert_eq!(
+            functions,
+            vec!["_get_x", "_get_name", "_get_count", "_set_count"]
+        );
+
+        for func in module.functions.iter() {
+            assert!(func.swift_property.is_some());
+            assert_eq!(
+                func.associated_type.as_ref().unwrap().unwrap_opaque().ty,
+                "SomeType"
+            );
+        }
+    }
+
+    /// Verify that we can parse a doc comment from an extern "Rust" opaque type.
+    #[test]
+    fn parse_opaque_rust_type_doc_comment() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    /// Some comment
+                    type AnotherType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(
+            module
+                .types
+                .get("AnotherType")
+                .unwrap()
+                .unwrap_opaque()
+                .attributes
+                .doc_comment
+                .as_ref()
+                .unwrap(),
+            " Some comment"
+        );
+    }
+
+    /// Verify that we collect every line of a function's doc comment.
+    #[test]
+    fn parse_function_doc_comment() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    /// First line
+                    /// Second line
+                    #[swift_bridge(swift_name = "bar")]
+                    fn some_function();
+
+                    fn another_function();
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(
+            module.functions[0].doc_comment.as_deref(),
+            Some(" First line\n Second line")
+        );
+        assert!(module.functions[1].doc_comment.is_none());
+    }
+
+    /// Verify that doc attributes that aren't doc comments, such as `#[doc(hidden)]`, are left out
+    /// of the doc comment.
+    #[test]
+    fn ignores_doc_attributes_that_are_not_comments() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    /// Some comment
+                    #[doc(hidden)]
+                    fn some_function();
+
+                    #[doc = concat!("Some ", "comment")]
+                    fn another_function();
+
+                    #[doc(hidden)]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(
+            module.functions[0].doc_comment.as_deref(),
+            Some(" Some comment")
+        );
+        assert!(module.functions[1].doc_comment.is_none());
+        assert!(module
+            .types
+            .get("SomeType")
+            .unwrap()
+            .unwrap_opaque()
+            .attributes
+            .doc_comment
+            .is_none());
+    }
+
+    /// Verify that we parse the note of a function's `#[deprecated]` attribute.
+    #[test]
+    fn parse_function_deprecation() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[deprecated(since = "1.0.0", note = "Use another_function")]
+                    fn some_function();
+
+                    #[deprecated = "Use another_function"]
+                    fn some_other_function();
+
+                    #[deprecated]
+                    fn yet_another_function();
+
+                    fn another_function();
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let notes: Vec<Option<Option<&str>>> = module
+            .functions
+            .iter()
+            .map(|func| {
+                func.deprecation
+                    .as_ref()
+                    .map(|deprecation| deprecation.note.as_deref())
+            })
+            .collect();
+        assert_eq!(
+            notes,
+            vec![
+                Some(Some("Use another_function")),
+                Some(Some("Use another_function")),
+                Some(None),
+                None
+            ]
+        );
+    }
+
+    /// Verify that we push errors for unknown arguments in a function
+    #[test]
+    fn error_args_into_arg_not_found_in_function() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(args_into = (foo, bar))]
+                    fn some_function(foo: u8);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        // Only "bar" should be missing argument.
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            ParseError::ArgsIntoArgNotFound {
+                func: _,
+                missing_arg,
+            } => {
+                assert_eq!(missing_arg, "bar")
+            }
+            _ => panic!(),
+        }
+    }
+
+    /// Verify that we push errors for function arguments that are both mutable and opaque Copy.
+    #[test]
+    fn error_if_mutable_opaque_copy_type() {
+        let tokens = quote! {
+            #[swift_bridge:bridge]
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Copy(2))]
+                    type SomeType;
+
+                    fn a(&mut self);
+                    fn b(self: &mut SomeType);
+                    fn c(arg: &mut SomeType);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 3);
+
+        for error in errors.iter() {
             match error {
                 ParseError::ArgCopyAndRefMut { arg: _ } => {}
                 _ => panic!(),
             }
         }
     }
+
+    /// Verify that we push an error if a field of an opaque Copy type has a setter, since setting
+    /// a field needs a mutable reference to the type.
+    #[test]
+    fn error_if_opaque_copy_type_has_setter() {
+        let tokens = quote! {
+            #[swift_bridge:bridge]
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Copy(8), set(x: f64))]
+                    type SomeType;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            ParseError::ArgCopyAndRefMut { arg: _ } => {}
+            _ => panic!(),
+        }
+    }
 }
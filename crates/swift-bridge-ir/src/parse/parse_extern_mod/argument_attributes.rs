@@ -1,16 +1,21 @@
 use proc_macro2::Ident;
+use syn::ext::IdentExt;
 use syn::parse::{Parse, ParseStream};
-use syn::{LitStr, Token};
+use syn::{Expr, Lit, LitStr, Token, UnOp};
 
 #[derive(Default)]
 pub(super) struct ArgumentAttributes {
     /// LitStr: argument_name
     pub label: Option<LitStr>,
+    /// The Swift expression that the argument defaults to.
+    pub default: Option<String>,
 }
 
 enum ArgumentAttr {
     /// LitStr: argument_name
     ArgumentLabel(LitStr),
+    /// String: the Swift expression that the argument defaults to
+    Default(String),
 }
 
 impl Parse for ArgumentAttributes {
@@ -23,6 +28,9 @@ impl Parse for ArgumentAttributes {
                 ArgumentAttr::ArgumentLabel(label) => {
                     attributes.label = Some(label);
                 }
+                ArgumentAttr::Default(default) => {
+                    attributes.default = Some(default);
+                }
             }
         }
         Ok(attributes)
@@ -31,13 +39,25 @@ impl Parse for ArgumentAttributes {
 
 impl Parse for ArgumentAttr {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let key: Ident = input.parse()?;
+        // `default` is a Rust keyword.
+        let key = input.call(Ident::parse_any)?;
         let attribute = match key.to_string().as_str() {
             "label" => {
                 input.parse::<Token![=]>()?;
                 let value: LitStr = input.parse()?;
                 ArgumentAttr::ArgumentLabel(value)
             }
+            "default" => {
+                input.parse::<Token![=]>()?;
+                let value: Expr = input.parse()?;
+                match swift_default_value(&value) {
+                    Some(default) => ArgumentAttr::Default(default),
+                    None => Err(syn::Error::new_spanned(
+                        value,
+                        "Expected an integer, float, bool or `None` default value, such as `default = 10`.",
+                    ))?,
+                }
+            }
             _ => {
                 let attrib = key.to_string();
                 Err(syn::Error::new_spanned(
@@ -50,6 +70,30 @@ impl Parse for ArgumentAttr {
     }
 }
 
+/// Convert a default value such as `10`, `-1.5`, `true` or `None` into the Swift expression that
+/// the argument defaults to.
+///
+/// String arguments are passed to generic Swift parameters such as `GenericToRustStr`, which
+/// cannot have a string literal as their default value, so string defaults aren't supported.
+fn swift_default_value(value: &Expr) -> Option<String> {
+    match value {
+        Expr::Lit(lit) => match &lit.lit {
+            Lit::Int(int) => Some(int.base10_digits().to_string()),
+            Lit::Float(float) => Some(float.base10_digits().to_string()),
+            Lit::Bool(bool) => Some(bool.value.to_string()),
+            _ => None,
+        },
+        Expr::Unary(unary) if matches!(unary.op, UnOp::Neg(_)) => match unary.expr.as_ref() {
+            Expr::Lit(lit) if matches!(lit.lit, Lit::Int(_) | Lit::Float(_)) => {
+                Some(format!("-{}", swift_default_value(&unary.expr)?))
+            }
+            _ => None,
+        },
+        Expr::Path(path) if path.path.is_ident("None") => Some("nil".to_string()),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::test_utils::parse_ok;
@@ -78,4 +122,41 @@ mod tests {
             .unwrap();
         assert_eq!(argument_label.value().to_string(), "argumentLabel1");
     }
+
+    /// Verify that we can parse a function that has arguments with default values.
+    #[test]
+    fn parse_extern_rust_argument_defaults() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function(
+                        #[swift_bridge(default = 10)] count: u32,
+                        #[swift_bridge(default = -1.5)] offset: f64,
+                        #[swift_bridge(label = "named", default = 3)] name: u8,
+                        #[swift_bridge(default = true)] flag: bool,
+                        #[swift_bridge(default = None)] maybe: Option<u8>,
+                        no_default: u8,
+                    );
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+        let defaults = &module.functions[0].argument_defaults;
+
+        assert_eq!(defaults.len(), 5);
+        assert_eq!(defaults.get(&format_ident!("count")).unwrap(), "10");
+        assert_eq!(defaults.get(&format_ident!("offset")).unwrap(), "-1.5");
+        assert_eq!(defaults.get(&format_ident!("name")).unwrap(), "3");
+        assert_eq!(defaults.get(&format_ident!("flag")).unwrap(), "true");
+        assert_eq!(defaults.get(&format_ident!("maybe")).unwrap(), "nil");
+        assert_eq!(
+            module.functions[0]
+                .argument_labels
+                .get(&format_ident!("name"))
+                .unwrap()
+                .value(),
+            "named"
+        );
+    }
 }
@@ -1,4 +1,5 @@
 use crate::parsed_extern_fn::{GetField, GetFieldDirect, GetFieldWith};
+use crate::SwiftAccess;
 use proc_macro2::Ident;
 use syn::parse::{Parse, ParseStream};
 use syn::{LitStr, Path, Token};
@@ -13,7 +14,13 @@ pub(super) struct FunctionAttributes {
     pub return_into: bool,
     pub return_with: Option<Path>,
     pub args_into: Option<Vec<Ident>>,
+    pub labeled_args: bool,
     pub get_field: Option<GetField>,
+    pub property: Option<Ident>,
+    pub is_swift_subscript: bool,
+    pub publisher: Option<Ident>,
+    pub discardable_result: bool,
+    pub swift_access: Option<SwiftAccess>,
 }
 
 impl FunctionAttributes {
@@ -36,6 +43,7 @@ impl FunctionAttributes {
                 self.return_with = Some(path);
             }
             FunctionAttr::ArgsInto(args) => self.args_into = Some(args),
+            FunctionAttr::LabeledArgs => self.labeled_args = true,
             FunctionAttr::Identifiable => {
                 self.is_swift_identifiable = true;
             }
@@ -43,6 +51,11 @@ impl FunctionAttributes {
             FunctionAttr::GetFieldWith(get_field) => {
                 self.get_field = Some(GetField::With(get_field))
             }
+            FunctionAttr::Property(name) => self.property = Some(name),
+            FunctionAttr::Subscript => self.is_swift_subscript = true,
+            FunctionAttr::Publisher(name) => self.publisher = Some(name),
+            FunctionAttr::DiscardableResult => self.discardable_result = true,
+            FunctionAttr::SwiftAccess(access) => self.swift_access = Some(access),
         }
     }
 }
@@ -56,8 +69,14 @@ pub(super) enum FunctionAttr {
     ReturnInto,
     ReturnWith(Path),
     ArgsInto(Vec<Ident>),
+    LabeledArgs,
     GetField(GetFieldDirect),
     GetFieldWith(GetFieldWith),
+    Property(Ident),
+    Subscript,
+    Publisher(Ident),
+    DiscardableResult,
+    SwiftAccess(SwiftAccess),
 }
 
 impl Parse for FunctionAttributes {
@@ -118,6 +137,9 @@ impl Parse for FunctionAttr {
                 let args = syn::punctuated::Punctuated::<_, Token![,]>::parse_terminated(&content)?;
                 FunctionAttr::ArgsInto(args.into_iter().collect())
             }
+            "labeled_args" => FunctionAttr::LabeledArgs,
+            "discardable_result" => FunctionAttr::DiscardableResult,
+            "subscript" => FunctionAttr::Subscript,
             "get" => {
                 let content;
                 syn::parenthesized!(content in input);
@@ -149,6 +171,25 @@ impl Parse for FunctionAttr {
                     path,
                 })
             }
+            // property = "title"
+            "property" => {
+                input.parse::<Token![=]>()?;
+                let value: LitStr = input.parse()?;
+
+                FunctionAttr::Property(value.parse()?)
+            }
+            "swift_access" => {
+                input.parse::<Token![=]>()?;
+                let value: LitStr = input.parse()?;
+
+                FunctionAttr::SwiftAccess(SwiftAccess::from_lit_str(&value)?)
+            }
+            "publisher" => {
+                input.parse::<Token![=]>()?;
+                let value: LitStr = input.parse()?;
+
+                FunctionAttr::Publisher(value.parse()?)
+            }
             _ => {
                 let attrib = key.to_string();
                 Err(syn::Error::new_spanned(
@@ -164,9 +205,14 @@ impl Parse for FunctionAttr {
 
 #[cfg(test)]
 mod tests {
-    use crate::errors::{FunctionAttributeParseError, IdentifiableParseError, ParseError};
+    use crate::errors::{
+        FunctionAttributeParseError, IdentifiableParseError, ParseError, PropertyParseError,
+        PublisherParseError, SubscriptParseError,
+    };
+    use crate::parsed_extern_fn::SwiftProperty;
     use crate::test_utils::{parse_errors, parse_ok};
-    use quote::{quote, ToTokens};
+    use crate::SwiftAccess;
+    use quote::{format_ident, quote, ToTokens};
 
     /// Verify that we can parse the return_into attribute from extern "Rust" blocks.
     #[test]
@@ -297,6 +343,30 @@ mod tests {
         assert!(func.is_swift_initializer);
     }
 
+    /// Verify that we can parse an init function that returns an Option.
+    #[test]
+    fn failable_initializer() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    type Foo;
+
+                    #[swift_bridge(init)]
+                    fn bar (bazz: u8) -> Option<Foo>;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let func = &module.functions[0];
+        assert!(func.is_failable_swift_initializer());
+        assert_eq!(
+            func.associated_type.as_ref().unwrap().unwrap_opaque().ty,
+            "Foo"
+        );
+    }
+
     /// Verify that we push an error if the initialize type is not defined.
     #[test]
     fn error_if_initialized_type_not_defined() {
@@ -534,4 +604,342 @@ mod tests {
         assert_eq!(func.args_into.as_ref().unwrap().len(), 1);
         assert_eq!(func.return_into, true);
     }
+
+    /// Verify that the `labeled_args` attribute labels every argument that does not already
+    /// have a label.
+    #[test]
+    fn parses_labeled_args_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(labeled_args)]
+                    fn some_method(&self, a: u8, #[swift_bridge(label = "with")] b: u8);
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let labels = &module.functions[0].argument_labels;
+        assert_eq!(labels.len(), 2);
+        assert_eq!(labels.get(&format_ident!("a")).unwrap().value(), "a");
+        assert_eq!(labels.get(&format_ident!("b")).unwrap().value(), "with");
+    }
+
+    /// Verify that we can parse the `discardable_result` attribute.
+    #[test]
+    fn parses_discardable_result_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(discardable_result)]
+                    fn some_function() -> u8;
+
+                    fn another_function() -> u8;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert!(module.functions[0].is_swift_discardable_result);
+        assert!(!module.functions[1].is_swift_discardable_result);
+    }
+
+    /// Verify that we can parse the `swift_access` attribute.
+    #[test]
+    fn parses_swift_access_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(swift_access = "package")]
+                    fn some_function();
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(module.functions[0].swift_access, Some(SwiftAccess::Package));
+    }
+
+    /// Verify that we can parse the `property` attribute on a getter and a setter.
+    #[test]
+    fn parses_property_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(property = "title")]
+                    fn title(&self) -> &str;
+
+                    #[swift_bridge(property = "title")]
+                    fn set_title(&mut self, title: &str);
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        match module.functions[0].swift_property.as_ref().unwrap() {
+            SwiftProperty::Getter { name } => assert_eq!(name, "title"),
+            _ => panic!(),
+        };
+        match module.functions[1].swift_property.as_ref().unwrap() {
+            SwiftProperty::Setter { name, sets_field } => {
+                assert_eq!(name, "title");
+                assert!(!sets_field);
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we push a parse error if we put a `property` attribute on a function that is
+    /// neither a getter nor a setter.
+    #[test]
+    fn error_if_property_attribute_on_invalid_accessor() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(property = "a")]
+                    fn a(&self);
+
+                    #[swift_bridge(property = "b")]
+                    fn b(&self, arg: u8) -> u8;
+
+                    #[swift_bridge(property = "c")]
+                    fn c(&mut self) -> u8;
+
+                    #[swift_bridge(property = "d")]
+                    fn d() -> u8;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 4);
+
+        for (idx, expected) in vec!["a", "b", "c", "d"].into_iter().enumerate() {
+            match &errors[idx] {
+                ParseError::FunctionAttribute(FunctionAttributeParseError::Property(
+                    PropertyParseError::InvalidAccessor { fn_ident },
+                )) => {
+                    assert_eq!(fn_ident, expected);
+                }
+                _ => panic!(),
+            };
+        }
+    }
+
+    /// Verify that we push a parse error if a property has a setter but no getter.
+    #[test]
+    fn error_if_property_setter_has_no_getter() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(property = "title")]
+                    fn set_title(&mut self, title: &str);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Property(
+                PropertyParseError::GetterNotFound { setter, property },
+            )) => {
+                assert_eq!(setter, "set_title");
+                assert_eq!(property, "title");
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we can parse the `subscript` attribute.
+    #[test]
+    fn parses_subscript_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(subscript)]
+                    fn get(&self, index: usize) -> u8;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert!(module.functions[0].is_swift_subscript);
+    }
+
+    /// Verify that we push a parse error if we put a `subscript` attribute on a function that does
+    /// not take `&self` and an index and return a value.
+    #[test]
+    fn error_if_subscript_attribute_on_invalid_function() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(subscript)]
+                    fn a(&self, index: usize);
+
+                    #[swift_bridge(subscript)]
+                    fn b(&self) -> u8;
+
+                    #[swift_bridge(subscript)]
+                    fn c(&mut self, index: usize) -> u8;
+
+                    #[swift_bridge(subscript)]
+                    fn d(index: usize) -> u8;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 4);
+
+        for (idx, expected) in vec!["a", "b", "c", "d"].into_iter().enumerate() {
+            match &errors[idx] {
+                ParseError::FunctionAttribute(FunctionAttributeParseError::Subscript(
+                    SubscriptParseError::InvalidSubscript { fn_ident },
+                )) => {
+                    assert_eq!(fn_ident, expected);
+                }
+                _ => panic!(),
+            };
+        }
+    }
+
+    /// Verify that we can parse the `publisher` attribute.
+    #[test]
+    #[cfg(feature = "combine")]
+    fn parses_publisher_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type Subscription;
+                }
+
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(publisher = "events")]
+                    fn subscribe(&self, callback: Box<dyn Fn(u32)>) -> Subscription;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(
+            module.functions[0].swift_publisher.as_ref().unwrap(),
+            "events"
+        );
+    }
+
+    /// Verify that we push a parse error if we put a `publisher` attribute on a function that does
+    /// not take `&self` and an event callback and return a subscription handle.
+    #[test]
+    #[cfg(feature = "combine")]
+    fn error_if_publisher_attribute_on_invalid_function() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type Subscription;
+                }
+
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(publisher = "a")]
+                    fn a(&self, callback: Box<dyn Fn(u32)>);
+
+                    #[swift_bridge(publisher = "b")]
+                    fn b(&self, callback: Box<dyn FnOnce(u32)>) -> Subscription;
+
+                    #[swift_bridge(publisher = "c")]
+                    fn c(&self, callback: Box<dyn Fn(u32, u32)>) -> Subscription;
+
+                    #[swift_bridge(publisher = "d")]
+                    fn d(&mut self, callback: Box<dyn Fn(u32)>) -> Subscription;
+
+                    #[swift_bridge(publisher = "e")]
+                    fn e(callback: Box<dyn Fn(u32)>) -> Subscription;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 5);
+
+        for (idx, expected) in vec!["a", "b", "c", "d", "e"].into_iter().enumerate() {
+            match &errors[idx] {
+                ParseError::FunctionAttribute(FunctionAttributeParseError::Publisher(
+                    PublisherParseError::InvalidRegistration { fn_ident },
+                )) => {
+                    assert_eq!(fn_ident, expected);
+                }
+                _ => panic!(),
+            };
+        }
+    }
+
+    /// Verify that we push a parse error if we use the `publisher` attribute without enabling the
+    /// `combine` feature.
+    #[test]
+    #[cfg(not(feature = "combine"))]
+    fn error_if_publisher_attribute_without_combine_feature() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type Subscription;
+                }
+
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(publisher = "events")]
+                    fn subscribe(&self, callback: Box<dyn Fn(u32)>) -> Subscription;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            ParseError::FunctionAttribute(FunctionAttributeParseError::Publisher(
+                PublisherParseError::CombineFeatureDisabled { fn_ident },
+            )) => {
+                assert_eq!(fn_ident, "subscribe");
+            }
+            _ => panic!(),
+        };
+    }
 }
@@ -4,10 +4,11 @@ mod generic_opaque_type;
 
 #[cfg(test)]
 mod tests {
-    use quote::quote;
+    use quote::{quote, ToTokens};
     use syn::TypeParam;
 
-    use crate::test_utils::parse_ok;
+    use crate::errors::ParseError;
+    use crate::test_utils::{parse_errors, parse_ok};
     use crate::SwiftBridgeModule;
 
     /// Verify that we can parse generic extern "Rust" types
@@ -51,6 +52,33 @@ mod tests {
         assert_eq!(get_generics(&module, "SomeType<u64>").len(), 1);
     }
 
+    /// Verify that we push an error if a monomorphization uses a type that wasn't declared.
+    #[test]
+    fn error_if_undeclared_generic() {
+        let tokens = quote! {
+            #[swift_bridge:bridge]
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(declare_generic)]
+                    type SomeType<A>;
+
+                    type SomeType<u32>;
+                    type SomeType<AnotherType>;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::UndeclaredType { ty } => {
+                assert_eq!(ty.to_token_stream().to_string(), "AnotherType");
+            }
+            _ => panic!(),
+        }
+    }
+
     fn get_generics<'a>(module: &'a SwiftBridgeModule, type_name: &str) -> &'a Vec<TypeParam> {
         &module
             .types
@@ -1,25 +1,37 @@
+use crate::item_cfg::ItemCfg;
 use crate::parse::OpaqueCopy;
+use crate::SwiftAccess;
 use proc_macro2::Ident;
 use quote::ToTokens;
 use std::ops::Deref;
 use syn::parse::{Parse, ParseStream};
-use syn::{Attribute, LitInt, Meta};
+use syn::{Attribute, LitInt, LitStr, Token, Type};
 
 #[derive(Default, Clone)]
 pub(crate) struct OpaqueTypeAllAttributes {
     pub swift_bridge: OpaqueTypeSwiftBridgeAttributes,
-    /// A doc comment.
-    // TODO: Use this to generate doc comment for the generated Swift type.
-    #[allow(unused)]
+    /// The type's doc comment, which gets emitted as documentation on the generated Swift type.
+    /// Each line of the comment is separated by a newline.
     pub doc_comment: Option<String>,
+    /// Whether or not the type was declared as a `trait` in the bridge module.
+    /// If it was, it gets exposed to Swift as a protocol and to Rust as a `Box<dyn Trait>`.
+    pub swift_protocol: bool,
+    /// The type's `#[cfg(...)]` attributes.
+    pub cfgs: Vec<ItemCfg>,
 }
 
 #[derive(Default, Clone)]
 pub(crate) struct OpaqueTypeSwiftBridgeAttributes {
+    /// `#[swift_bridge(Actor)]`
+    /// Used to determine if a Swift `actor` that wraps the type should be generated.
+    pub actor: bool,
     /// Whether or not the `#[swift_bridge(already_declared)]` attribute was present on the type.
     /// If it was, we won't generate Swift and C type declarations for this type, since we
     /// will elsewhere.
     pub already_declared: bool,
+    /// `#[swift_bridge(Comparable)]`
+    /// Used to determine if Comparable need to be implemented.
+    pub comparable: bool,
     /// `#[swift_bridge(Copy(...)]`
     /// Describes the type's Copy semantics.
     pub copy: Option<OpaqueCopy>,
@@ -32,6 +44,105 @@ pub(crate) struct OpaqueTypeSwiftBridgeAttributes {
     /// `#[swift_bridge(Hashable)]`
     /// Used to determine if Hashable need to be implemented.
     pub hashable: bool,
+    /// `#[swift_bridge(Iterator)]`
+    /// Used to determine if the Swift type should conform to `Sequence` and `IteratorProtocol`
+    /// using the type's `next` method.
+    pub iterator: bool,
+    /// `#[swift_bridge(Identifiable = "getter_name")]`
+    /// The method whose return value is used as the Swift `Identifiable` `id`.
+    pub identifiable: Option<LitStr>,
+    /// `#[swift_bridge(CustomStringConvertible)]`
+    /// Used to determine if a Swift `description` should be generated from Rust's `Display`.
+    pub custom_string_convertible: bool,
+    /// `#[swift_bridge(CustomDebugStringConvertible)]`
+    /// Used to determine if a Swift `debugDescription` should be generated from Rust's `Debug`.
+    pub custom_debug_string_convertible: bool,
+    /// `#[swift_bridge(Add, Sub, Neg)]`
+    /// The `std::ops` traits that get exposed to Swift as operators, such as `static func +`.
+    pub operators: Vec<OpaqueOperator>,
+    /// `#[swift_bridge(Sendable)]`
+    /// Used to determine if the Swift type should conform to `Sendable`.
+    pub sendable: bool,
+    /// `#[swift_bridge(swift_access = "internal")]`
+    /// The access level of the type's generated Swift declarations, if it overrides the module's.
+    pub swift_access: Option<SwiftAccess>,
+    /// `#[swift_bridge(get(x: f64, &name: &str))]`
+    /// Fields that get exposed to Swift as read-only computed properties.
+    pub get_fields: Vec<OpaqueTypeField>,
+    /// `#[swift_bridge(set(x: f64))]`
+    /// Fields that get exposed to Swift as read-write computed properties.
+    pub set_fields: Vec<OpaqueTypeField>,
+    /// `#[swift_bridge(ObservableObject = "on_change")]`
+    /// The method that registers the callback that the Rust type calls whenever its state changes.
+    /// If present, we generate an `ObservableObject` Swift class that wraps the type.
+    pub observable_object: Option<LitStr>,
+    /// `#[swift_bridge(Stream(Item = T))]`
+    /// The item type of the `Stream` that the type implements. If present, the Swift type
+    /// conforms to `AsyncSequence`.
+    pub stream_item: Option<Box<Type>>,
+}
+
+/// One of an opaque Rust type's fields, such as the `x: f64` in `#[swift_bridge(get(x: f64))]`.
+#[derive(Clone)]
+pub(crate) struct OpaqueTypeField {
+    /// Whether the getter returns a reference to the field, such as `get(&name: &str)`.
+    pub maybe_ref: Option<Token![&]>,
+    pub name: Ident,
+    pub ty: Type,
+}
+
+/// One of the `std::ops` traits that can be exposed to Swift as an operator, such as the `Add` in
+/// `#[swift_bridge(Add)]`.
+#[derive(Copy, Clone, PartialEq)]
+pub(crate) enum OpaqueOperator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Neg,
+}
+
+impl OpaqueOperator {
+    /// `Add`
+    pub fn trait_name(&self) -> &'static str {
+        match self {
+            OpaqueOperator::Add => "Add",
+            OpaqueOperator::Sub => "Sub",
+            OpaqueOperator::Mul => "Mul",
+            OpaqueOperator::Div => "Div",
+            OpaqueOperator::Rem => "Rem",
+            OpaqueOperator::Neg => "Neg",
+        }
+    }
+
+    /// `add`, the name of the trait's method. Also used to name the operator's extern function.
+    pub fn method_name(&self) -> &'static str {
+        match self {
+            OpaqueOperator::Add => "add",
+            OpaqueOperator::Sub => "sub",
+            OpaqueOperator::Mul => "mul",
+            OpaqueOperator::Div => "div",
+            OpaqueOperator::Rem => "rem",
+            OpaqueOperator::Neg => "neg",
+        }
+    }
+
+    /// `+`
+    pub fn swift_symbol(&self) -> &'static str {
+        match self {
+            OpaqueOperator::Add => "+",
+            OpaqueOperator::Sub | OpaqueOperator::Neg => "-",
+            OpaqueOperator::Mul => "*",
+            OpaqueOperator::Div => "/",
+            OpaqueOperator::Rem => "%",
+        }
+    }
+
+    /// Whether the operator takes a single operand, such as `-x`.
+    pub fn is_unary(&self) -> bool {
+        matches!(self, OpaqueOperator::Neg)
+    }
 }
 
 impl OpaqueTypeAllAttributes {
@@ -42,22 +153,8 @@ impl OpaqueTypeAllAttributes {
             let attribute_name = attr.path.to_token_stream().to_string();
 
             match attribute_name.as_str() {
-                "doc" => {
-                    let meta = attr.parse_meta()?;
-                    let doc = match meta {
-                        Meta::NameValue(name_val) => match name_val.lit {
-                            syn::Lit::Str(comment) => comment.value(),
-                            _ => {
-                                todo!("Push parse error that doc attribute is in incorrect format")
-                            }
-                        },
-                        _ => {
-                            todo!("Push parse error that doc attribute is in incorrect format")
-                        }
-                    };
-
-                    attributes.doc_comment = Some(doc);
-                }
+                "doc" => {}
+                "cfg" => attributes.cfgs.push(ItemCfg::from_attribute(attr)?),
                 "swift_bridge" => {
                     attributes.swift_bridge = attr.parse_args()?;
                 }
@@ -65,6 +162,8 @@ impl OpaqueTypeAllAttributes {
             };
         }
 
+        attributes.doc_comment = super::doc_comment(attribs);
+
         Ok(attributes)
     }
 }
@@ -72,21 +171,55 @@ impl OpaqueTypeAllAttributes {
 impl OpaqueTypeSwiftBridgeAttributes {
     pub(super) fn store_attrib(&mut self, attrib: OpaqueTypeAttr) {
         match attrib {
+            OpaqueTypeAttr::Actor => self.actor = true,
             OpaqueTypeAttr::AlreadyDeclared => self.already_declared = true,
+            OpaqueTypeAttr::Comparable => self.comparable = true,
             OpaqueTypeAttr::Copy { size } => self.copy = Some(OpaqueCopy { size_bytes: size }),
             OpaqueTypeAttr::DeclareGeneric => self.declare_generic = true,
             OpaqueTypeAttr::Equatable => self.equatable = true,
             OpaqueTypeAttr::Hashable => self.hashable = true,
+            OpaqueTypeAttr::Identifiable { getter } => self.identifiable = Some(getter),
+            OpaqueTypeAttr::Operator(operator) => {
+                if !self.operators.contains(&operator) {
+                    self.operators.push(operator)
+                }
+            }
+            OpaqueTypeAttr::Sendable => self.sendable = true,
+            OpaqueTypeAttr::SwiftAccess(access) => self.swift_access = Some(access),
+            OpaqueTypeAttr::CustomStringConvertible => self.custom_string_convertible = true,
+            OpaqueTypeAttr::CustomDebugStringConvertible => {
+                self.custom_debug_string_convertible = true
+            }
+            OpaqueTypeAttr::Iterator => self.iterator = true,
+            OpaqueTypeAttr::Get(fields) => self.get_fields.extend(fields),
+            OpaqueTypeAttr::Set(fields) => self.set_fields.extend(fields),
+            OpaqueTypeAttr::Stream { item } => self.stream_item = Some(item),
+            OpaqueTypeAttr::ObservableObject { on_change } => {
+                self.observable_object = Some(on_change)
+            }
         }
     }
 }
 
 pub(crate) enum OpaqueTypeAttr {
+    Actor,
     AlreadyDeclared,
+    Comparable,
     Copy { size: usize },
     DeclareGeneric,
     Equatable,
     Hashable,
+    Identifiable { getter: LitStr },
+    Operator(OpaqueOperator),
+    Sendable,
+    SwiftAccess(SwiftAccess),
+    CustomStringConvertible,
+    CustomDebugStringConvertible,
+    Iterator,
+    Get(Vec<OpaqueTypeField>),
+    Set(Vec<OpaqueTypeField>),
+    Stream { item: Box<Type> },
+    ObservableObject { on_change: LitStr },
 }
 
 impl Parse for OpaqueTypeSwiftBridgeAttributes {
@@ -109,7 +242,9 @@ impl Parse for OpaqueTypeAttr {
         let key: Ident = input.parse()?;
 
         let attrib = match key.to_string().as_str() {
+            "Actor" => OpaqueTypeAttr::Actor,
             "already_declared" => OpaqueTypeAttr::AlreadyDeclared,
+            "Comparable" => OpaqueTypeAttr::Comparable,
             // Copy(10)
             "Copy" => {
                 let content;
@@ -124,6 +259,68 @@ impl Parse for OpaqueTypeAttr {
             "declare_generic" => OpaqueTypeAttr::DeclareGeneric,
             "Equatable" => OpaqueTypeAttr::Equatable,
             "Hashable" => OpaqueTypeAttr::Hashable,
+            // Identifiable = "id"
+            "Identifiable" => {
+                input.parse::<syn::Token![=]>()?;
+                OpaqueTypeAttr::Identifiable {
+                    getter: input.parse()?,
+                }
+            }
+            "Iterator" => OpaqueTypeAttr::Iterator,
+            // ObservableObject = "on_change"
+            "ObservableObject" => {
+                input.parse::<syn::Token![=]>()?;
+                OpaqueTypeAttr::ObservableObject {
+                    on_change: input.parse()?,
+                }
+            }
+            "Add" => OpaqueTypeAttr::Operator(OpaqueOperator::Add),
+            "Sub" => OpaqueTypeAttr::Operator(OpaqueOperator::Sub),
+            "Mul" => OpaqueTypeAttr::Operator(OpaqueOperator::Mul),
+            "Div" => OpaqueTypeAttr::Operator(OpaqueOperator::Div),
+            "Rem" => OpaqueTypeAttr::Operator(OpaqueOperator::Rem),
+            "Neg" => OpaqueTypeAttr::Operator(OpaqueOperator::Neg),
+            "Sendable" => OpaqueTypeAttr::Sendable,
+            // swift_access = "internal"
+            "swift_access" => {
+                input.parse::<syn::Token![=]>()?;
+                let access: LitStr = input.parse()?;
+                OpaqueTypeAttr::SwiftAccess(SwiftAccess::from_lit_str(&access)?)
+            }
+            // Stream(Item = u32)
+            "Stream" => {
+                let content;
+                syn::parenthesized!(content in input);
+
+                let item: Ident = content.parse()?;
+                if item != "Item" {
+                    return Err(syn::Error::new_spanned(
+                        item,
+                        "Expected the stream's item type, such as `Stream(Item = u32)`.",
+                    ));
+                }
+                content.parse::<Token![=]>()?;
+
+                OpaqueTypeAttr::Stream {
+                    item: content.parse()?,
+                }
+            }
+            "CustomStringConvertible" => OpaqueTypeAttr::CustomStringConvertible,
+            "CustomDebugStringConvertible" => OpaqueTypeAttr::CustomDebugStringConvertible,
+            // get(x: f64, &name: &str)
+            "get" => OpaqueTypeAttr::Get(parse_fields(input)?),
+            // set(x: f64)
+            "set" => {
+                let fields = parse_fields(input)?;
+                if let Some(maybe_ref) = fields.iter().find_map(|field| field.maybe_ref) {
+                    return Err(syn::Error::new_spanned(
+                        maybe_ref,
+                        "A setter takes the field's new value by value.",
+                    ));
+                }
+
+                OpaqueTypeAttr::Set(fields)
+            }
             _ => {
                 let attrib = key.to_string();
                 Err(syn::Error::new_spanned(
@@ -137,6 +334,31 @@ impl Parse for OpaqueTypeAttr {
     }
 }
 
+impl Parse for OpaqueTypeField {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let maybe_ref = input.parse::<Option<Token![&]>>()?;
+        let name = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let ty = input.parse()?;
+
+        Ok(OpaqueTypeField {
+            maybe_ref,
+            name,
+            ty,
+        })
+    }
+}
+
+/// `(x: f64, &name: &str)`
+fn parse_fields(input: ParseStream) -> syn::Result<Vec<OpaqueTypeField>> {
+    let content;
+    syn::parenthesized!(content in input);
+
+    let fields =
+        syn::punctuated::Punctuated::<OpaqueTypeField, Token![,]>::parse_terminated(&content)?;
+    Ok(fields.into_iter().collect())
+}
+
 impl Deref for OpaqueTypeAllAttributes {
     type Target = OpaqueTypeSwiftBridgeAttributes;
 
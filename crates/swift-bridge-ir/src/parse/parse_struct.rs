@@ -38,6 +38,8 @@ impl Default for StructDerives {
         StructDerives {
             copy: false,
             clone: false,
+            serialize: false,
+            deserialize: false,
         }
     }
 }
@@ -133,6 +135,12 @@ impl<'a> SharedStructDeclarationParser<'a> {
                             match derive.to_token_stream().to_string().as_str() {
                                 "Copy" => attribs.derives.copy = true,
                                 "Clone" => attribs.derives.clone = true,
+                                "Serialize" | "serde :: Serialize" => {
+                                    attribs.derives.serialize = true
+                                }
+                                "Deserialize" | "serde :: Deserialize" => {
+                                    attribs.derives.deserialize = true
+                                }
                                 _ => {}
                             }
                         }
@@ -170,6 +178,13 @@ impl<'a> SharedStructDeclarationParser<'a> {
             StructSwiftRepr::Structure
         };
 
+        let is_codable = attribs.derives.serialize || attribs.derives.deserialize;
+        if is_codable && !matches!(&item_struct.fields, syn::Fields::Named(_)) {
+            self.errors.push(ParseError::UnsupportedCodableType {
+                ty: item_struct.ident.clone(),
+            });
+        }
+
         let shared_struct = SharedStruct {
             name: item_struct.ident,
             swift_repr,
@@ -369,6 +384,51 @@ mod tests {
         assert_eq!(ty2.derives.clone, true);
     }
 
+    /// Verify that we parse serde's derives.
+    #[test]
+    fn parse_serde_derive_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                #[derive(serde::Serialize, serde::Deserialize)]
+                struct Foo {
+                    field: u8
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_struct();
+
+        assert!(ty.derives.serialize);
+        assert!(ty.derives.deserialize);
+    }
+
+    /// Verify that we push an error if serde's derives are used on a struct without named fields.
+    #[test]
+    fn error_if_serde_derive_on_tuple_struct() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                #[derive(serde::Deserialize)]
+                struct Foo(u8);
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::UnsupportedCodableType { ty } => {
+                assert_eq!(ty, "Foo");
+            }
+            _ => panic!(),
+        };
+    }
+
     /// Verify that we properly parse multiple comma separated struct attributes.
     #[test]
     fn parses_multiple_struct_attributes() {
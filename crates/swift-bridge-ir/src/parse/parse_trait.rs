@@ -0,0 +1,225 @@
+use crate::errors::{ParseError, ParseErrors};
+use crate::parse::parse_extern_mod::ForeignModParser;
+use crate::parse::{TypeDeclaration, TypeDeclarations};
+use crate::ParsedExternFn;
+use quote::quote;
+use syn::{FnArg, ItemForeignMod, ItemTrait, TraitItem, Type};
+
+/// Parses a `trait` that was declared in the bridge module.
+///
+/// A bridged trait gets implemented in Swift, so we treat it as an `extern "Swift"` opaque type
+/// whose methods are the trait's methods. The type is then marked as a Swift protocol so that we
+/// can generate the protocol on the Swift side and the trait on the Rust side.
+pub(super) struct TraitParser<'a> {
+    pub errors: &'a mut ParseErrors,
+    pub type_declarations: &'a mut TypeDeclarations,
+    pub functions: &'a mut Vec<ParsedExternFn>,
+    pub unresolved_types: &'a mut Vec<Type>,
+}
+
+impl<'a> TraitParser<'a> {
+    pub fn parse(self, item_trait: ItemTrait) -> Result<(), syn::Error> {
+        if !item_trait.generics.params.is_empty() || item_trait.generics.where_clause.is_some() {
+            self.errors.push(ParseError::TraitHasGenerics {
+                generics: item_trait.generics,
+            });
+        }
+        if !item_trait.supertraits.is_empty() {
+            self.errors.push(ParseError::TraitHasSupertraits {
+                supertraits: item_trait.supertraits,
+            });
+        }
+
+        let trait_name = item_trait.ident;
+        let doc_attrs = item_trait
+            .attrs
+            .into_iter()
+            .filter(|attr| attr.path.is_ident("doc"));
+
+        let mut methods = vec![];
+        for item in item_trait.items {
+            match item {
+                TraitItem::Method(method)
+                    if method.default.is_none()
+                        && method_takes_ref_self(method.sig.inputs.iter()) =>
+                {
+                    let attrs = method
+                        .attrs
+                        .iter()
+                        .filter(|attr| attr.path.is_ident("swift_bridge"));
+                    let sig = &method.sig;
+
+                    methods.push(quote! {
+                        #(#attrs)*
+                        #sig;
+                    });
+                }
+                invalid_item => {
+                    self.errors.push(ParseError::InvalidTraitItem {
+                        item: Box::new(invalid_item),
+                    });
+                }
+            }
+        }
+
+        let foreign_mod: ItemForeignMod = syn::parse2(quote! {
+            extern "Swift" {
+                #(#doc_attrs)*
+                type #trait_name;
+
+                #(#methods)*
+            }
+        })?;
+
+        ForeignModParser {
+            errors: self.errors,
+            type_declarations: self.type_declarations,
+            functions: self.functions,
+            unresolved_types: self.unresolved_types,
+        }
+        .parse(foreign_mod)?;
+
+        if let Some(TypeDeclaration::Opaque(ty)) =
+            self.type_declarations.get_mut(&trait_name.to_string())
+        {
+            ty.attributes.swift_protocol = true;
+        }
+        for func in self.functions.iter_mut() {
+            if let Some(TypeDeclaration::Opaque(ty)) = func.associated_type.as_mut() {
+                if ty.ty == trait_name {
+                    ty.attributes.swift_protocol = true;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Whether or not the method takes `&self` or `&mut self`.
+fn method_takes_ref_self<'a>(mut inputs: impl Iterator<Item = &'a FnArg>) -> bool {
+    match inputs.next() {
+        Some(FnArg::Receiver(receiver)) => receiver.reference.is_some(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::ParseError;
+    use crate::test_utils::{parse_errors, parse_ok};
+    use quote::quote;
+    use syn::TraitItem;
+
+    /// Verify that we parse a trait into an opaque Swift type that is marked as a protocol, along
+    /// with its methods.
+    #[test]
+    fn parse_trait() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                trait Greeter {
+                    fn greet(&self, name: String) -> String;
+                    fn reset(&mut self);
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.get("Greeter").unwrap().unwrap_opaque();
+        assert!(ty.host_lang.is_swift());
+        assert!(ty.attributes.swift_protocol);
+
+        assert_eq!(module.functions.len(), 2);
+        for func in module.functions.iter() {
+            assert!(func.host_lang.is_swift());
+            assert!(func.is_method());
+            assert!(
+                func.associated_type
+                    .as_ref()
+                    .unwrap()
+                    .unwrap_opaque()
+                    .attributes
+                    .swift_protocol
+            );
+        }
+    }
+
+    /// Verify that we push an error for trait items that aren't methods that take `&self` or
+    /// `&mut self`.
+    #[test]
+    fn error_if_invalid_trait_item() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                trait Greeter {
+                    const SOME_CONST: u8;
+                    fn new() -> u8;
+                    fn consume(self);
+                    fn with_default(&self) {}
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 4);
+        match &errors[0] {
+            ParseError::InvalidTraitItem { item } => {
+                assert!(matches!(item.as_ref(), TraitItem::Const(_)));
+            }
+            _ => panic!(),
+        }
+        for error in &errors[1..] {
+            match error {
+                ParseError::InvalidTraitItem { item } => {
+                    assert!(matches!(item.as_ref(), TraitItem::Method(_)));
+                }
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that we push an error for traits that have generics, a where clause or supertraits,
+    /// since the Swift protocol that we generate wouldn't have them.
+    #[test]
+    fn error_if_trait_has_generics_or_supertraits() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                trait Greeter<T> {
+                    fn greet(&self);
+                }
+                trait Farewell where Self: Sized {
+                    fn bye(&self);
+                }
+                trait Waver: Clone + Send {
+                    fn wave(&self);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 3);
+        match &errors[0] {
+            ParseError::TraitHasGenerics { generics } => {
+                assert_eq!(generics.params.len(), 1);
+            }
+            _ => panic!(),
+        }
+        match &errors[1] {
+            ParseError::TraitHasGenerics { generics } => {
+                assert!(generics.where_clause.is_some());
+            }
+            _ => panic!(),
+        }
+        match &errors[2] {
+            ParseError::TraitHasSupertraits { supertraits } => {
+                assert_eq!(supertraits.len(), 2);
+            }
+            _ => panic!(),
+        }
+    }
+}
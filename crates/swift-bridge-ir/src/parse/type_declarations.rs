@@ -1,9 +1,10 @@
 use crate::bridged_type::{
     BridgedType, CustomBridgedType, OpaqueForeignType, SharedEnum, SharedStruct, SharedType,
 };
+use crate::errors::ParseError;
 use crate::parse::parse_extern_mod::OpaqueTypeAllAttributes;
 use crate::parse::HostLang;
-use crate::SWIFT_BRIDGE_PREFIX;
+use crate::{SwiftPathType, SWIFT_BRIDGE_PREFIX};
 use proc_macro2::{Ident, TokenStream};
 use quote::ToTokens;
 use std::collections::HashMap;
@@ -17,6 +18,8 @@ pub(crate) use self::generics::*;
 pub(crate) struct TypeDeclarations {
     decls: HashMap<String, TypeDeclaration>,
     order: Vec<String>,
+    /// The Swift type that the module's path types such as `PathBuf` are seen as.
+    swift_path_type: SwiftPathType,
 }
 
 #[derive(Clone)]
@@ -32,6 +35,14 @@ pub(crate) enum SharedTypeDeclaration {
 }
 
 impl TypeDeclaration {
+    /// The name of the type on the Rust side.
+    pub(crate) fn name(&self) -> &Ident {
+        match self {
+            TypeDeclaration::Shared(shared) => shared.name(),
+            TypeDeclaration::Opaque(opaque) => &opaque.ty,
+        }
+    }
+
     pub fn to_bridged_type(&self, reference: bool, mutable: bool) -> BridgedType {
         match self {
             TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)) => {
@@ -58,6 +69,7 @@ impl TypeDeclaration {
                 reference,
                 mutable,
                 has_swift_bridge_copy_annotation: opaque.attributes.copy.is_some(),
+                swift_protocol: opaque.attributes.swift_protocol,
                 generics: opaque.generics.clone(),
             }),
             _ => None,
@@ -65,6 +77,24 @@ impl TypeDeclaration {
     }
 }
 
+impl SharedTypeDeclaration {
+    /// The name of the type on the Rust side.
+    pub(crate) fn name(&self) -> &Ident {
+        match self {
+            SharedTypeDeclaration::Struct(shared_struct) => &shared_struct.name,
+            SharedTypeDeclaration::Enum(shared_enum) => &shared_enum.name,
+        }
+    }
+
+    /// The name of the type on the Swift side.
+    pub(crate) fn swift_name_string(&self) -> String {
+        match self {
+            SharedTypeDeclaration::Struct(shared_struct) => shared_struct.swift_name_string(),
+            SharedTypeDeclaration::Enum(shared_enum) => shared_enum.swift_name_string(),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub(crate) struct OpaqueForeignTypeDeclaration {
     pub ty: Ident,
@@ -74,13 +104,16 @@ pub(crate) struct OpaqueForeignTypeDeclaration {
 }
 
 impl OpaqueForeignTypeDeclaration {
-    pub(crate) fn ffi_repr_type_tokens(&self) -> TokenStream {
+    pub(crate) fn ffi_repr_type_tokens(&self, types: &TypeDeclarations) -> TokenStream {
         if self.attributes.copy.is_some() {
             self.ffi_copy_repr_ident().to_token_stream()
         } else {
             let ty_name = &self.ty;
+            let generics = self
+                .generics
+                .angle_bracketed_concrete_generics_tokens(types);
             quote::quote! {
-                *mut super::#ty_name
+                *mut super::#ty_name #generics
             }
         }
     }
@@ -207,6 +240,16 @@ impl OpaqueForeignTypeDeclaration {
     }
 }
 
+/// The Rust type that holds a Swift instance of a bridged trait's protocol.
+///
+/// __swift_bridge__SomeTrait
+pub(crate) fn swift_protocol_handle_ident(trait_name: &Ident) -> Ident {
+    Ident::new(
+        &format!("{}{}", SWIFT_BRIDGE_PREFIX, trait_name),
+        trait_name.span(),
+    )
+}
+
 impl TypeDeclarations {
     pub(crate) fn get<Q: ?Sized>(&self, type_name: &Q) -> Option<&TypeDeclaration>
     where
@@ -216,6 +259,14 @@ impl TypeDeclarations {
         self.decls.get(type_name)
     }
 
+    pub(crate) fn get_mut<Q>(&mut self, type_name: &Q) -> Option<&mut TypeDeclaration>
+    where
+        Q: ?Sized + std::hash::Hash + Eq,
+        String: std::borrow::Borrow<Q>,
+    {
+        self.decls.get_mut(type_name)
+    }
+
     pub(crate) fn get_with_pat_type(&self, pat_ty: &PatType) -> Option<&TypeDeclaration> {
         self.get_with_type(&pat_ty.ty)
     }
@@ -230,17 +281,27 @@ impl TypeDeclarations {
     }
 
     pub(crate) fn get_with_type(&self, ty: &Type) -> Option<&TypeDeclaration> {
-        let ty = match ty {
-            Type::Reference(reference) => reference.elem.to_token_stream().to_string(),
-            Type::Path(path) => path.to_token_stream().to_string(),
+        match ty {
+            Type::Reference(reference) => self.get_with_type(&reference.elem),
+            Type::Path(path) => self.get_with_type_path(path),
             _ => todo!("Handle other cases"),
-        };
-        self.get(&ty)
+        }
     }
 
-    pub(crate) fn insert(&mut self, type_name: String, ty: TypeDeclaration) {
+    /// Add a type declaration, or return an error without adding it if the module already
+    /// declares a type with the same name.
+    #[must_use]
+    pub(crate) fn insert(&mut self, type_name: String, ty: TypeDeclaration) -> Option<ParseError> {
+        if let Some(first) = self.decls.get(&type_name) {
+            return Some(ParseError::DuplicateTypeDeclaration {
+                first: first.name().clone(),
+                duplicate: ty.name().clone(),
+            });
+        }
+
         self.decls.insert(type_name.clone(), ty);
         self.order.push(type_name);
+        None
     }
 
     pub fn types(&self) -> Vec<&TypeDeclaration> {
@@ -249,6 +310,14 @@ impl TypeDeclarations {
             .map(|ty| self.decls.get(ty).unwrap())
             .collect()
     }
+
+    pub(crate) fn swift_path_type(&self) -> SwiftPathType {
+        self.swift_path_type
+    }
+
+    pub(crate) fn set_swift_path_type(&mut self, swift_path_type: SwiftPathType) {
+        self.swift_path_type = swift_path_type;
+    }
 }
 
 impl TypeDeclaration {
@@ -45,6 +45,32 @@ impl OpaqueRustTypeGenerics {
         bounds.join(", ")
     }
 
+    /// For Rust type `SomeType<u32, u64>`:
+    /// A.self == UInt32.self && B.self == UInt64.self
+    ///
+    /// Returns `None` if one of the generics isn't a bridged type. The parser reports these as
+    /// undeclared types.
+    pub(crate) fn rust_opaque_type_swift_generic_metatype_checks(
+        &self,
+        types: &TypeDeclarations,
+    ) -> Option<String> {
+        let checks = self
+            .generics
+            .iter()
+            .enumerate()
+            .map(|(idx, g)| {
+                let ty = BridgedType::new_with_str(&g.ident.to_string(), types)?;
+                Some(format!(
+                    "{}.self == {}.self",
+                    GENERIC_PLACEHOLDERS[idx],
+                    ty.to_swift_type(TypePosition::FnReturn(HostLang::Rust), types)
+                ))
+            })
+            .collect::<Option<Vec<String>>>()?;
+
+        Some(checks.join(" && "))
+    }
+
     /// "<A, B, C>" if there are generics.
     /// "" if there are no generics.
     pub(crate) fn angle_bracketed_generic_placeholders_string(&self) -> String {
@@ -0,0 +1,527 @@
+use crate::bridged_type::bridgeable_range::BridgeableRange;
+use crate::bridged_type::bridgeable_uuid::BridgeableUuid;
+use crate::bridged_type::{BridgeableType, BridgedType};
+use crate::errors::ParseError;
+use crate::parse::{SharedTypeDeclaration, TypeDeclaration, TypeDeclarations};
+use quote::ToTokens;
+use syn::{GenericArgument, PathArguments, ReturnType, Type, TypeParamBound};
+
+/// `Vec < & str >` -> `Vec<&str>`
+pub(crate) fn type_to_string(ty: &Type) -> String {
+    ty.to_token_stream()
+        .to_string()
+        .replace(" :: ", "::")
+        .replace("fn (", "fn(")
+        .replace(" < ", "<")
+        .replace(" >", ">")
+        .replace("& ", "&")
+        .replace(" ,", ",")
+        .replace(" ;", ";")
+}
+
+/// The error for a function argument or return type that we can't bridge, or `None` if we can.
+///
+/// The error points at the innermost part of the type that can't be bridged, such as the
+/// `Undeclared` in `Vec<Undeclared>`, so that the user can see which part to change.
+pub(super) fn unsupported_type_error(ty: &Type, types: &TypeDeclarations) -> Option<ParseError> {
+    if BridgedType::new_with_type(ty, types).is_none() {
+        for inner in inner_types(ty) {
+            if BridgedType::new_with_type(inner, types).is_none() {
+                return unsupported_type_error(inner, types);
+            }
+        }
+
+        if is_type_name(ty) {
+            return Some(ParseError::UndeclaredType { ty: ty.clone() });
+        }
+
+        return Some(ParseError::UnsupportedType {
+            ty: ty.clone(),
+            help: unparseable_type_help(ty),
+        });
+    }
+
+    if let Some(help) = unsupported_combination(ty, types) {
+        return Some(ParseError::UnsupportedType {
+            ty: ty.clone(),
+            help,
+        });
+    }
+
+    // The elements of a tuple and the generic arguments of our containers are only checked once
+    // we generate code for them, so we check them here.
+    for inner in container_elements(ty) {
+        if let Some(error) = unsupported_type_error(inner, types) {
+            return Some(error);
+        }
+    }
+
+    None
+}
+
+/// The error for a type that holds a `uuid::Uuid` when swift-bridge's `uuid` feature is disabled,
+/// or `None` if the type doesn't need any disabled features.
+pub(super) fn disabled_feature_error(ty: &Type, types: &TypeDeclarations) -> Option<ParseError> {
+    if cfg!(feature = "uuid") {
+        return None;
+    }
+
+    if BridgeableUuid::from_type(ty, types).is_some() {
+        return Some(ParseError::UuidFeatureDisabled { ty: ty.clone() });
+    }
+
+    inner_types(ty)
+        .into_iter()
+        .find_map(|inner| disabled_feature_error(inner, types))
+}
+
+/// The error for a `Result` that holds a type that we can only pass from Rust to Swift, such as
+/// `Result<[u8; 4], String>`, or `None` if the type isn't such a `Result`.
+///
+/// This must only be called for the types whose values get passed from Swift to Rust.
+pub(super) fn rust_return_only_result_error(
+    ty: &Type,
+    types: &TypeDeclarations,
+) -> Option<ParseError> {
+    match container_and_args(ty)? {
+        (container, args) if container == "Result" && args.len() == 2 => {
+            let kind = args
+                .iter()
+                .find_map(|arg| rust_return_only_kind(arg, types))?;
+            Some(ParseError::UnsupportedType {
+                ty: ty.clone(),
+                help: Some(format!(
+                    "a `Result` that holds {} can only be returned by a Rust function",
+                    kind
+                )),
+            })
+        }
+        _ => None,
+    }
+}
+
+/// A description of the type if it can't yet be converted from its FFI representation back into
+/// Rust when it's held by a `Result`.
+fn rust_return_only_kind(ty: &Type, types: &TypeDeclarations) -> Option<&'static str> {
+    match ty {
+        Type::Array(_) => Some("an array"),
+        _ if is_range(ty, types) => Some("a `Range`"),
+        _ => match container_and_args(ty)?.0.as_str() {
+            "HashMap" => Some("a `HashMap`"),
+            "HashSet" => Some("a `HashSet`"),
+            _ => None,
+        },
+    }
+}
+
+/// Types that we can parse but can't generate code for, along with an optional suggestion.
+fn unsupported_combination(ty: &Type, types: &TypeDeclarations) -> Option<Option<String>> {
+    if let Type::Tuple(tuple) = ty {
+        if tuple
+            .elems
+            .iter()
+            .any(|elem| matches!(elem, Type::BareFn(_)))
+        {
+            return Some(Some(
+                "a tuple can not hold an `extern \"C\" fn`".to_string(),
+            ));
+        }
+    }
+
+    let (container, args) = container_and_args(ty)?;
+
+    match (container.as_str(), args.as_slice()) {
+        ("Vec", [Type::Reference(reference)]) => {
+            let help = if is_str(&reference.elem) {
+                Some("did you mean `Vec<String>`?".to_string())
+            } else {
+                None
+            };
+            Some(help)
+        }
+        ("Vec", [Type::Array(_) | Type::BareFn(_)]) => Some(None),
+        ("Vec", [element]) if is_range(element, types) => Some(None),
+        ("Vec", [element @ Type::Path(_)]) => match types.get_with_type(element) {
+            Some(TypeDeclaration::Shared(SharedTypeDeclaration::Enum(shared_enum)))
+                if shared_enum.has_one_or_more_variants_with_data() =>
+            {
+                Some(Some(
+                    "a `Vec` can only hold enums whose variants do not have fields".to_string(),
+                ))
+            }
+            _ => None,
+        },
+        ("Option", [Type::Reference(reference)]) if matches!(*reference.elem, Type::Slice(_)) => {
+            Some(None)
+        }
+        ("Option", [Type::Array(_)]) => Some(None),
+        ("Option", [inner]) if is_range(inner, types) => Some(None),
+        _ => None,
+    }
+}
+
+/// Suggestions for types that are commonly used in place of the ones that we support.
+fn unparseable_type_help(ty: &Type) -> Option<String> {
+    let reference = match ty {
+        Type::Reference(reference) => reference,
+        _ => return None,
+    };
+    let mutability = if reference.mutability.is_some() {
+        "mut "
+    } else {
+        ""
+    };
+
+    if is_type_name_with(&reference.elem, "String") {
+        return Some("did you mean `&str`?".to_string());
+    }
+
+    match container_and_args(&reference.elem) {
+        Some((container, args)) if container == "Vec" && args.len() == 1 => Some(format!(
+            "did you mean `&{}[{}]`?",
+            mutability,
+            type_to_string(args[0])
+        )),
+        _ => None,
+    }
+}
+
+/// The types that a type is made of, such as the `T` in `&T` or `Vec<T>`.
+fn inner_types(ty: &Type) -> Vec<&Type> {
+    match ty {
+        Type::Reference(reference) => vec![reference.elem.as_ref()],
+        Type::Slice(slice) => vec![slice.elem.as_ref()],
+        Type::Array(array) => vec![array.elem.as_ref()],
+        Type::Paren(paren) => vec![paren.elem.as_ref()],
+        Type::Tuple(tuple) => tuple.elems.iter().collect(),
+        Type::BareFn(bare_fn) => {
+            let mut types: Vec<&Type> = bare_fn.inputs.iter().map(|arg| &arg.ty).collect();
+            if let ReturnType::Type(_, ty) = &bare_fn.output {
+                types.push(ty);
+            }
+            types
+        }
+        // `dyn Fn(A) -> B`
+        Type::TraitObject(trait_object) => trait_object
+            .bounds
+            .iter()
+            .filter_map(|bound| match bound {
+                TypeParamBound::Trait(bound) => bound.path.segments.last(),
+                _ => None,
+            })
+            .flat_map(|segment| match &segment.arguments {
+                PathArguments::Parenthesized(args) => {
+                    let mut types: Vec<&Type> = args.inputs.iter().collect();
+                    if let ReturnType::Type(_, ty) = &args.output {
+                        types.push(ty);
+                    }
+                    types
+                }
+                _ => vec![],
+            })
+            .collect(),
+        Type::Path(path) => match path.path.segments.last().map(|s| &s.arguments) {
+            Some(PathArguments::AngleBracketed(args)) => args
+                .args
+                .iter()
+                .filter_map(|arg| match arg {
+                    GenericArgument::Type(ty) => Some(ty),
+                    _ => None,
+                })
+                .collect(),
+            _ => vec![],
+        },
+        _ => vec![],
+    }
+}
+
+/// The elements of a tuple, or the generic arguments of a `Vec`, `Option` or `Result`.
+fn container_elements(ty: &Type) -> Vec<&Type> {
+    match ty {
+        Type::Tuple(tuple) => tuple.elems.iter().collect(),
+        _ => match container_and_args(ty) {
+            Some((container, args))
+                if ["Vec", "Option", "Result"].contains(&container.as_str()) =>
+            {
+                args
+            }
+            _ => vec![],
+        },
+    }
+}
+
+/// `Vec<u8>` -> `Some(("Vec", vec![u8]))`
+fn container_and_args(ty: &Type) -> Option<(String, Vec<&Type>)> {
+    let path = match ty {
+        Type::Path(path) if path.qself.is_none() => path,
+        _ => return None,
+    };
+    let segment = path.path.segments.last()?;
+    match &segment.arguments {
+        PathArguments::AngleBracketed(_) => Some((segment.ident.to_string(), inner_types(ty))),
+        _ => None,
+    }
+}
+
+/// Whether the type is only a name, such as `SomeType`, with no generic arguments.
+fn is_type_name(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => {
+            path.qself.is_none()
+                && path
+                    .path
+                    .segments
+                    .iter()
+                    .all(|segment| segment.arguments.is_empty())
+        }
+        _ => false,
+    }
+}
+
+/// Whether the type is a `Range<T>` that we can bridge.
+fn is_range(ty: &Type, types: &TypeDeclarations) -> bool {
+    BridgeableRange::from_type(ty, types).is_some()
+}
+
+fn is_type_name_with(ty: &Type, name: &str) -> bool {
+    is_type_name(ty) && ty.to_token_stream().to_string() == name
+}
+
+fn is_str(ty: &Type) -> bool {
+    is_type_name_with(ty, "str")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::parse_errors;
+    use proc_macro2::TokenStream;
+    use quote::quote;
+
+    /// Parse a module with a function that takes the given argument, and return the error
+    /// messages.
+    fn arg_errors(arg_ty: TokenStream) -> Vec<String> {
+        let tokens = quote! {
+            mod ffi {
+                enum EnumWithData {
+                    Variant(u8),
+                }
+
+                extern "Rust" {
+                    type SomeType;
+
+                    fn some_function(arg: #arg_ty);
+                }
+            }
+        };
+
+        match parse_errors(tokens).combine_all() {
+            Ok(()) => vec![],
+            Err(errors) => errors.into_iter().map(|error| error.to_string()).collect(),
+        }
+    }
+
+    /// Verify that the error for a container of an undeclared type points at the undeclared type.
+    #[test]
+    fn points_at_undeclared_inner_type() {
+        for ty in [
+            quote! { Vec<Undeclared> },
+            quote! { Option<&Undeclared> },
+            quote! { (u8, Undeclared) },
+        ] {
+            assert_eq!(
+                arg_errors(ty),
+                vec!["Type must be declared with `type Undeclared`.\nIf another bridge module declares it, declare it here with `#[swift_bridge(already_declared)] type Undeclared;`\n"]
+            );
+        }
+    }
+
+    /// Verify that we suggest a supported type for commonly used unsupported ones.
+    #[test]
+    fn suggests_supported_types() {
+        let cases = [
+            (
+                quote! { &Vec<u8> },
+                "`&Vec<u8>` is not supported here; did you mean `&[u8]`?",
+            ),
+            (
+                quote! { &mut Vec<SomeType> },
+                "`&mut Vec<SomeType>` is not supported here; did you mean `&mut [SomeType]`?",
+            ),
+            (
+                quote! { &String },
+                "`&String` is not supported here; did you mean `&str`?",
+            ),
+            (
+                quote! { Vec<&str> },
+                "`Vec<&str>` is not supported here; did you mean `Vec<String>`?",
+            ),
+            (
+                quote! { Option<Vec<&str>> },
+                "`Vec<&str>` is not supported here; did you mean `Vec<String>`?",
+            ),
+        ];
+
+        for (ty, expected) in cases {
+            assert_eq!(arg_errors(ty), vec![expected]);
+        }
+    }
+
+    /// Verify that a `Result` that holds an array can be returned by a Rust function, but can't
+    /// otherwise be passed to Rust.
+    #[test]
+    fn result_holding_array() {
+        let tokens = quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn a() -> Result<[u8; 4], String>;
+                    fn b(arg: Result<String, [u8; 4]>);
+                }
+
+                extern "Swift" {
+                    fn c() -> Result<[u8; 4], String>;
+                    fn d(arg: [u8; 4]) -> Result<(), String>;
+                }
+            }
+        };
+
+        let errors: Vec<String> = match parse_errors(tokens).combine_all() {
+            Ok(()) => vec![],
+            Err(errors) => errors.into_iter().map(|error| error.to_string()).collect(),
+        };
+        assert_eq!(
+            errors,
+            vec![
+                "`Result<String, [u8; 4]>` is not supported here; a `Result` that holds an array can only be returned by a Rust function",
+                "`Result<[u8; 4], String>` is not supported here; a `Result` that holds an array can only be returned by a Rust function",
+            ]
+        );
+    }
+
+    /// Verify that a `Result` that holds a `Range` can be returned by a Rust function, but can't
+    /// otherwise be passed to Rust.
+    #[test]
+    fn result_holding_range() {
+        let tokens = quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn a() -> Result<Range<u8>, String>;
+                    fn b(arg: Result<(), std::ops::Range<u8>>);
+                }
+
+                extern "Swift" {
+                    fn c() -> Result<Range<u8>, String>;
+                }
+            }
+        };
+
+        let errors: Vec<String> = match parse_errors(tokens).combine_all() {
+            Ok(()) => vec![],
+            Err(errors) => errors.into_iter().map(|error| error.to_string()).collect(),
+        };
+        assert_eq!(
+            errors,
+            vec![
+                "`Result<(), std::ops::Range<u8>>` is not supported here; a `Result` that holds a `Range` can only be returned by a Rust function",
+                "`Result<Range<u8>, String>` is not supported here; a `Result` that holds a `Range` can only be returned by a Rust function",
+            ]
+        );
+    }
+
+    /// Verify that we push an error for a `Result` that holds a `HashMap` or a `HashSet` when
+    /// the `Result` would need to be converted into Rust.
+    #[test]
+    fn result_holding_hash_map_or_hash_set() {
+        let tokens = quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn a() -> Result<HashMap<String, u8>, String>;
+                    fn b() -> Result<HashSet<u8>, String>;
+                    fn c(arg: Result<HashSet<u8>, String>);
+                    fn d(arg: Result<(), std::collections::HashMap<u8, u8>>);
+                }
+
+                extern "Swift" {
+                    fn e() -> Result<HashMap<String, u8>, String>;
+                }
+            }
+        };
+
+        let errors: Vec<String> = match parse_errors(tokens).combine_all() {
+            Ok(()) => vec![],
+            Err(errors) => errors.into_iter().map(|error| error.to_string()).collect(),
+        };
+        assert_eq!(
+            errors,
+            vec![
+                "`Result<HashSet<u8>, String>` is not supported here; a `Result` that holds a `HashSet` can only be returned by a Rust function",
+                "`Result<(), std::collections::HashMap<u8, u8>>` is not supported here; a `Result` that holds a `HashMap` can only be returned by a Rust function",
+                "`Result<HashMap<String, u8>, String>` is not supported here; a `Result` that holds a `HashMap` can only be returned by a Rust function",
+            ]
+        );
+    }
+
+    /// Verify that we push an error that points at the `Uuid` in a function argument, return type
+    /// or struct field when the `uuid` feature is disabled.
+    #[cfg(not(feature = "uuid"))]
+    #[test]
+    fn uuid_requires_feature() {
+        let tokens = quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    field: Vec<uuid::Uuid>,
+                }
+
+                extern "Rust" {
+                    fn a(arg: Option<Uuid>) -> uuid::Uuid;
+                }
+            }
+        };
+        let errors: Vec<String> = match parse_errors(tokens).combine_all() {
+            Ok(()) => vec![],
+            Err(errors) => errors.into_iter().map(|error| error.to_string()).collect(),
+        };
+        assert_eq!(
+            errors,
+            vec![
+                "`Uuid` requires swift-bridge's `uuid` feature.",
+                "`uuid::Uuid` requires swift-bridge's `uuid` feature.",
+                "`uuid::Uuid` requires swift-bridge's `uuid` feature.",
+            ]
+        );
+    }
+
+    /// Verify that a `Vec` of a type that isn't a name, such as a tuple, is checked without
+    /// looking it up in the module's type declarations.
+    #[test]
+    fn vec_of_tuple() {
+        assert!(arg_errors(quote! { Vec<(u8, u16)> }).is_empty());
+    }
+
+    /// Verify that we return an error for types that we can parse but can't generate code for.
+    #[test]
+    fn error_for_types_without_codegen() {
+        let cases = [
+            (
+                quote! { Vec<EnumWithData> },
+                "`Vec<EnumWithData>` is not supported here; a `Vec` can only hold enums whose variants do not have fields",
+            ),
+            (quote! { Option<&[u8]> }, "`Option<&[u8]>` is not supported here"),
+            (quote! { [String; 3] }, "`[String; 3]` is not supported here"),
+            (quote! { Vec<[u8; 4]> }, "`Vec<[u8; 4]>` is not supported here"),
+            (quote! { Option<[u8; 4]> }, "`Option<[u8; 4]>` is not supported here"),
+            (
+                quote! { Option<std::ops::Range<u8>> },
+                "`Option<std::ops::Range<u8>>` is not supported here",
+            ),
+            (
+                quote! { (u8, extern "C" fn(u8)) },
+                "`(u8, extern \"C\" fn(u8))` is not supported here; a tuple can not hold an `extern \"C\" fn`",
+            ),
+        ];
+
+        for (ty, expected) in cases {
+            assert_eq!(arg_errors(ty), vec![expected]);
+        }
+    }
+}
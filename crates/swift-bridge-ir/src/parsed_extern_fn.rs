@@ -1,13 +1,18 @@
-use crate::bridged_type::boxed_fn::BridgeableBoxedFnOnce;
+use crate::bridged_type::boxed_fn::BridgeableBoxedFn;
+use crate::bridged_type::bridgeable_extern_c_fn::c_declaration;
 use crate::bridged_type::{pat_type_pat_is_self, BridgeableType, BridgedType, StdLibType};
+use crate::item_cfg::ItemCfg;
 use crate::parse::{HostLang, SharedTypeDeclaration, TypeDeclaration, TypeDeclarations};
-use crate::SWIFT_BRIDGE_PREFIX;
+use crate::{PanicPolicy, SwiftAccess, SWIFT_BRIDGE_PREFIX};
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{quote, quote_spanned, ToTokens};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::ops::Deref;
 use syn::spanned::Spanned;
-use syn::{FnArg, ForeignItemFn, Lifetime, LitStr, Path, ReturnType, Token, Type};
+use syn::{
+    FnArg, ForeignItemFn, GenericArgument, Lifetime, LitStr, Path, PathArguments, ReturnType,
+    Token, Type, TypeReference,
+};
 
 mod to_extern_c_fn;
 mod to_extern_c_param_names_and_types;
@@ -93,6 +98,77 @@ pub(crate) struct ParsedExternFn {
     /// Get one of the associated type's fields
     pub get_field: Option<GetField>,
     pub argument_labels: HashMap<Ident, LitStr>,
+    /// The Swift expressions that arguments with a `#[swift_bridge(default = ..)]` attribute
+    /// default to, such as the `10` in `count: UInt32 = 10`.
+    pub argument_defaults: HashMap<Ident, String>,
+    /// Whether this function is exposed to Swift as an accessor of a computed property instead of
+    /// as a method. Either the function has a `#[swift_bridge(property = "..")]` attribute, or it
+    /// was generated from a `#[swift_bridge(get(..))]` or `#[swift_bridge(set(..))]` attribute on
+    /// the associated type.
+    pub swift_property: Option<SwiftProperty>,
+    /// Whether this function is exposed to Swift as a subscript on the associated type, such as
+    /// `subscript(index: Int) -> UInt8`.
+    pub is_swift_subscript: bool,
+    /// Whether this is the `async fn next(&mut self) -> Option<T>` that gets generated for a
+    /// `#[swift_bridge(Stream(Item = T))]` type, which polls the stream for its next item instead
+    /// of calling a method.
+    pub is_stream_next: bool,
+    /// The name of the Combine publisher that a `#[swift_bridge(publisher = "..")]` method, which
+    /// registers a callback for a Rust event source, gets wrapped into.
+    pub swift_publisher: Option<Ident>,
+    /// Whether the Swift function is marked `@discardableResult`, so that callers that ignore
+    /// its return value don't get a "result unused" warning.
+    pub is_swift_discardable_result: bool,
+    /// The access level of the function's generated Swift declaration, if it overrides the
+    /// access level of its module or its associated type.
+    pub swift_access: Option<SwiftAccess>,
+    /// The function's doc comment, which gets emitted as documentation on the generated Swift
+    /// function. Each line of the comment is separated by a newline.
+    pub doc_comment: Option<String>,
+    /// Whether the function has a `#[deprecated]` attribute, in which case its generated Swift
+    /// function is marked `@available(*, deprecated)`.
+    pub deprecation: Option<Deprecation>,
+    /// The function's `#[cfg(...)]` attributes, along with those of its associated type.
+    pub cfgs: Vec<ItemCfg>,
+    /// The path of the Rust file that declares the function, if the bridge module was parsed
+    /// from a file. The function's generated Swift is annotated with its location in this file.
+    pub source_file: Option<String>,
+    /// What happens when the Rust function panics.
+    pub panic_policy: PanicPolicy,
+}
+
+/// A `#[deprecated]` attribute on a function.
+pub(crate) struct Deprecation {
+    /// The `note = ".."` that explains the deprecation.
+    pub note: Option<String>,
+}
+
+impl Deprecation {
+    /// `@available(*, deprecated, message: "Use another_function instead")`
+    pub fn to_swift_available_attribute(&self) -> String {
+        match &self.note {
+            Some(note) => format!(
+                r#"@available(*, deprecated, message: "{}")"#,
+                note.replace('\\', "\\\\")
+                    .replace('"', "\\\"")
+                    .replace('\n', "\\n")
+            ),
+            None => "@available(*, deprecated)".to_string(),
+        }
+    }
+}
+
+/// An accessor of a Swift computed property.
+pub(crate) enum SwiftProperty {
+    /// `fn title(&self) -> &str`, or a generated `fn _get_x(&self) -> T` that returns `self.x`.
+    Getter { name: Ident },
+    /// `fn set_title(&mut self, title: &str)`, or a generated `fn _set_x(&mut self, value: T)`.
+    Setter {
+        name: Ident,
+        /// Whether the setter was generated and sets `self.x = value`, instead of calling a
+        /// method.
+        sets_field: bool,
+    },
 }
 
 pub(crate) enum GetField {
@@ -113,6 +189,87 @@ pub struct GetFieldWith {
     pub(crate) path: Path,
 }
 
+/// Swift keywords that can't be used as a parameter name without being escaped.
+const SWIFT_KEYWORDS: &[&str] = &[
+    "Any",
+    "Self",
+    "as",
+    "associatedtype",
+    "await",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "continue",
+    "default",
+    "defer",
+    "deinit",
+    "do",
+    "else",
+    "enum",
+    "extension",
+    "fallthrough",
+    "false",
+    "fileprivate",
+    "for",
+    "func",
+    "guard",
+    "if",
+    "import",
+    "in",
+    "init",
+    "inout",
+    "internal",
+    "is",
+    "let",
+    "nil",
+    "open",
+    "operator",
+    "precedencegroup",
+    "private",
+    "protocol",
+    "public",
+    "repeat",
+    "rethrows",
+    "return",
+    "self",
+    "static",
+    "struct",
+    "subscript",
+    "super",
+    "switch",
+    "throw",
+    "throws",
+    "true",
+    "try",
+    "typealias",
+    "var",
+    "where",
+    "while",
+];
+
+/// `r#in` -> `in`
+pub(crate) fn unraw_arg_name(arg_name: &str) -> &str {
+    arg_name.strip_prefix("r#").unwrap_or(arg_name)
+}
+
+/// The name that a function's parameter is given on the Swift side.
+///
+/// A parameter that is named after a Swift keyword, such as `in` or `repeat`, gets renamed to
+/// `in_` or `repeat_`. We rename instead of backtick-escaping since the name also gets used to
+/// build other identifiers, such as the `in_AsRustStr` that a `&str` argument gets converted to.
+///
+/// The parameter's argument label is unaffected, since Swift allows keywords as labels.
+pub(crate) fn swift_param_name(arg_name: &str) -> String {
+    let arg_name = unraw_arg_name(arg_name);
+
+    if SWIFT_KEYWORDS.contains(&arg_name) {
+        format!("{}_", arg_name)
+    } else {
+        arg_name.to_string()
+    }
+}
+
 #[cfg(test)]
 impl GetField {
     pub(crate) fn unwrap_direct(&self) -> &GetFieldDirect {
@@ -135,23 +292,150 @@ impl ParsedExternFn {
         self.func.sig.receiver().is_some()
     }
 
+    /// The file that declares the function, along with the line of the function's `fn` keyword.
+    ///
+    /// `None` if the function's bridge module was not parsed from a file, or if the span of the
+    /// `fn` keyword does not know its line.
+    pub(crate) fn source_location(&self) -> Option<(&str, usize)> {
+        let file = self.source_file.as_deref()?;
+        let line = self.func.sig.fn_token.span.start().line;
+        if line == 0 {
+            return None;
+        }
+
+        Some((file, line))
+    }
+
     pub fn self_reference(&self) -> Option<(Token![&], Option<Lifetime>)> {
         match self.func.sig.receiver()? {
             FnArg::Receiver(receiver) => receiver.reference.clone(),
-            FnArg::Typed(pat_ty) => match pat_ty.ty.deref() {
-                Type::Reference(type_ref) => Some((type_ref.and_token, type_ref.lifetime.clone())),
-                _ => None,
+            FnArg::Typed(pat_ty) => self_type_reference(&pat_ty.ty)
+                .map(|type_ref| (type_ref.and_token, type_ref.lifetime.clone())),
+        }
+    }
+
+    /// Whether or not this is a `fn next(&mut self) -> Option<T>` method, which is used to
+    /// conform a `#[swift_bridge(Iterator)]` type to Swift's `IteratorProtocol`.
+    pub(crate) fn is_iterator_next(&self, types: &TypeDeclarations) -> bool {
+        self.sig.ident == "next"
+            && self.swift_name_override.is_none()
+            && self.sig.inputs.len() == 1
+            && self.self_reference().is_some()
+            && self.self_mutability().is_some()
+            && matches!(
+                BridgedType::new_with_return_type(&self.sig.output, types),
+                Some(BridgedType::StdLib(StdLibType::Option(_)))
+            )
+    }
+
+    /// The event callback of a `#[swift_bridge(publisher = "..")]` method, if the method takes
+    /// `&self` and a `Box<dyn Fn(T)>` or `Box<dyn FnMut(T)>`, and returns a subscription handle
+    /// that unregisters the callback when it is dropped.
+    pub(crate) fn publisher_callback(&self, types: &TypeDeclarations) -> Option<BridgeableBoxedFn> {
+        let is_opaque_rust_type_method = matches!(
+            &self.associated_type,
+            Some(TypeDeclaration::Opaque(ty)) if ty.host_lang.is_rust()
+        ) && self.self_reference().is_some();
+        if !is_opaque_rust_type_method
+            || self.self_mutability().is_some()
+            || self.sig.asyncness.is_some()
+            || self.sig.inputs.len() != 2
+            || matches!(&self.sig.output, ReturnType::Default)
+        {
+            return None;
+        }
+
+        match BridgedType::new_with_fn_arg(&self.sig.inputs[1], types)? {
+            BridgedType::StdLib(StdLibType::BoxedFn(callback))
+                if !callback.kind.is_fn_once()
+                    && callback.params.len() == 1
+                    && callback.ret.is_null() =>
+            {
+                Some(callback)
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether or not this is a method that takes a `Box<dyn Fn()>` or `Box<dyn FnMut()>`
+    /// callback, which is how a `#[swift_bridge(ObservableObject = "..")]` type gets told to call
+    /// Swift whenever its state changes.
+    pub(crate) fn is_on_change_registration(&self, types: &TypeDeclarations) -> bool {
+        if self.self_reference().is_none()
+            || self.sig.asyncness.is_some()
+            || self.sig.inputs.len() != 2
+            || !matches!(&self.sig.output, ReturnType::Default)
+        {
+            return false;
+        }
+
+        match BridgedType::new_with_fn_arg(&self.sig.inputs[1], types) {
+            Some(BridgedType::StdLib(StdLibType::BoxedFn(callback))) => {
+                !callback.kind.is_fn_once() && callback.params.is_empty() && callback.ret.is_null()
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether or not this is a method that takes a `self: Pin<&mut Self>`.
+    pub fn is_self_pinned(&self) -> bool {
+        match self.func.sig.receiver() {
+            Some(FnArg::Typed(pat_ty)) => pinned_mut_reference(&pat_ty.ty).is_some(),
+            _ => false,
+        }
+    }
+
+    /// Whether or not this is a method that takes a `self: Box<Self>`.
+    pub fn is_self_boxed(&self) -> bool {
+        match self.func.sig.receiver() {
+            Some(FnArg::Typed(pat_ty)) => boxed_self_type(&pat_ty.ty).is_some(),
+            _ => false,
+        }
+    }
+
+    /// Whether or not this is a method that takes a `self: Arc<Self>`.
+    pub fn is_self_arc(&self) -> bool {
+        match self.func.sig.receiver() {
+            Some(FnArg::Typed(pat_ty)) => arc_self_type(&pat_ty.ty).is_some(),
+            _ => false,
+        }
+    }
+
+    /// The `Arc<Foo>` that a method that takes a `self: Arc<Self>` is called on.
+    pub(crate) fn arc_self_bridged_type(&self, types: &TypeDeclarations) -> Option<BridgedType> {
+        if !self.is_self_arc() {
+            return None;
+        }
+
+        let ty = &self.associated_type.as_ref()?.as_opaque()?.ty;
+        BridgedType::new_with_str(&format!("Arc<{}>", ty), types)
+    }
+
+    /// Whether or not this is an initializer that returns `Option<Self>`, which becomes a
+    /// Swift `init?`.
+    pub fn is_failable_swift_initializer(&self) -> bool {
+        if !self.is_swift_initializer {
+            return false;
+        }
+
+        match &self.func.sig.output {
+            ReturnType::Type(_, ty) => match ty.deref() {
+                Type::Path(path) => path
+                    .path
+                    .segments
+                    .last()
+                    .map(|segment| segment.ident == "Option")
+                    .unwrap_or(false),
+                _ => false,
             },
+            ReturnType::Default => false,
         }
     }
 
     pub fn self_mutability(&self) -> Option<Token![mut]> {
         match self.func.sig.receiver()? {
             FnArg::Receiver(receiver) => receiver.mutability,
-            FnArg::Typed(pat_ty) => match pat_ty.ty.deref() {
-                Type::Reference(type_ref) => type_ref.mutability,
-                _ => None,
-            },
+            FnArg::Typed(pat_ty) => self_type_reference(&pat_ty.ty)?.mutability,
         }
     }
 
@@ -159,7 +443,7 @@ impl ParsedExternFn {
         &self,
         swift_bridge_path: &Path,
         types: &TypeDeclarations,
-        custom_type_definitions: &mut HashMap<String, TokenStream>,
+        custom_type_definitions: &mut BTreeMap<String, TokenStream>,
     ) -> TokenStream {
         let sig = &self.func.sig;
 
@@ -256,7 +540,7 @@ impl ParsedExternFn {
     ) -> TokenStream {
         let mut args = vec![];
         let inputs = &self.func.sig.inputs;
-        for fn_arg in inputs.into_iter() {
+        for (idx, fn_arg) in inputs.into_iter().enumerate() {
             match fn_arg {
                 FnArg::Receiver(_receiver) => {
                     if self.host_lang.is_swift() {
@@ -280,6 +564,16 @@ impl ParsedExternFn {
                         if self.host_lang.is_rust() {
                             arg = if let Some(repr) = built_in.only_encoding() {
                                 repr.rust
+                            } else if let BridgedType::StdLib(StdLibType::BoxedFn(boxed_fn)) =
+                                &built_in
+                            {
+                                self.swift_callback_to_boxed_fn(
+                                    idx,
+                                    &arg,
+                                    boxed_fn,
+                                    swift_bridge_path,
+                                    types,
+                                )
                             } else {
                                 built_in.convert_ffi_expression_to_rust_type(
                                     &arg,
@@ -347,7 +641,7 @@ impl ParsedExternFn {
                         let ty = built_in.to_c(types);
 
                         let arg_name = pat.to_token_stream().to_string();
-                        params.push(format!("{} {}", ty, arg_name));
+                        params.push(c_declaration(&ty, unraw_arg_name(&arg_name)));
                     }
                 }
             };
@@ -452,15 +746,16 @@ impl ParsedExternFn {
         let host_type = self
             .associated_type
             .as_ref()
-            .map(|h| {
-                match h {
-                    TypeDeclaration::Shared(_) => {
-                        //
-                        todo!()
-                    }
-                    TypeDeclaration::Opaque(h) => {
-                        format!("${}", h.to_string())
-                    }
+            .map(|h| match h {
+                TypeDeclaration::Shared(h) => {
+                    format!("${}", h.name())
+                }
+                TypeDeclaration::Opaque(h) => {
+                    format!(
+                        "${}{}",
+                        h.to_string(),
+                        h.generics.dollar_prefixed_generics_string()
+                    )
                 }
             })
             .unwrap_or("".to_string());
@@ -480,13 +775,39 @@ impl ParsedExternFn {
         format!("{}$_free$param{}", self.link_name(), boxed_fn_idx)
     }
 
+    /// The name of the Swift class that holds a Swift closure that was passed to a Rust function.
+    ///
+    /// `__private__SwiftCallback$SomeType$some_method$param1`
+    pub fn swift_callback_class_name(&self, boxed_fn_idx: usize) -> String {
+        let link_name = self.link_name();
+        let link_name = link_name.trim_start_matches(SWIFT_BRIDGE_PREFIX);
+        format!("__private__SwiftCallback{link_name}$param{boxed_fn_idx}")
+    }
+
+    /// The names of the Swift functions that call and free a Swift closure that was passed to a
+    /// Rust function.
+    ///
+    /// `__swift_bridge__some_function_param0` and `__swift_bridge__some_function_free_param0`
+    pub fn swift_callback_rust_fn_idents(&self, boxed_fn_idx: usize) -> (Ident, Ident) {
+        let prefixed_fn_name = self.prefixed_fn_name();
+        let span = prefixed_fn_name.span();
+
+        (
+            Ident::new(&format!("{prefixed_fn_name}_param{boxed_fn_idx}"), span),
+            Ident::new(
+                &format!("{prefixed_fn_name}_free_param{boxed_fn_idx}"),
+                span,
+            ),
+        )
+    }
+
     /// Generates something like:
     /// void __swift_bridge__$some_function$param0(void* boxed_fn, uint8_t arg);
     /// void __swift_bridge__$some_function$_free$param0(void* boxed_fn);
     pub fn boxed_fn_to_c_header_fns(
         &self,
         idx: usize,
-        boxed_fn: &BridgeableBoxedFnOnce,
+        boxed_fn: &BridgeableBoxedFn,
         types: &TypeDeclarations,
     ) -> String {
         let call_boxed_fn_link_name = self.call_boxed_fn_link_name(idx);
@@ -515,15 +836,16 @@ void {free_boxed_fn_link_name}(void* {boxed_fn_arg_name});"#
         let host_type_prefix = self
             .associated_type
             .as_ref()
-            .map(|h| {
-                match h {
-                    TypeDeclaration::Shared(_) => {
-                        //
-                        todo!()
-                    }
-                    TypeDeclaration::Opaque(h) => {
-                        format!("{}_", h.to_token_stream().to_string())
-                    }
+            .map(|h| match h {
+                TypeDeclaration::Shared(h) => {
+                    format!("{}_", h.name())
+                }
+                TypeDeclaration::Opaque(h) => {
+                    format!(
+                        "{}{}_",
+                        h.to_token_stream().to_string(),
+                        h.generics.underscore_prefixed_generics_string()
+                    )
                 }
             })
             .unwrap_or_default();
@@ -546,7 +868,7 @@ void {free_boxed_fn_link_name}(void* {boxed_fn_arg_name});"#
     pub fn args_filtered_to_boxed_fns(
         &self,
         type_decls: &TypeDeclarations,
-    ) -> Vec<(usize, BridgeableBoxedFnOnce)> {
+    ) -> Vec<(usize, BridgeableBoxedFn)> {
         self.func
             .sig
             .inputs
@@ -556,7 +878,7 @@ void {free_boxed_fn_link_name}(void* {boxed_fn_arg_name});"#
                 let ty = BridgedType::new_with_fn_arg(arg, type_decls)?;
 
                 match ty {
-                    BridgedType::StdLib(StdLibType::BoxedFnOnce(boxed_fn)) => Some((idx, boxed_fn)),
+                    BridgedType::StdLib(StdLibType::BoxedFn(boxed_fn)) => Some((idx, boxed_fn)),
                     _ => None,
                 }
             })
@@ -573,15 +895,16 @@ void {free_boxed_fn_link_name}(void* {boxed_fn_arg_name});"#
         let mut initializers = "".to_string();
         let mut maybe_space = "";
 
-        for (idx, fn_once) in self.args_filtered_to_boxed_fns(types) {
+        for (idx, boxed_fn) in self.args_filtered_to_boxed_fns(types) {
             let arg_name = self.arg_name_at_idx(idx).unwrap();
 
-            if fn_once.params.is_empty() && fn_once.ret.is_null() {
+            if boxed_fn.uses_no_args_no_return_support() {
                 initializers += &format!(
                 "{maybe_space}let cb{idx} = __private__RustFnOnceCallbackNoArgsNoRet(ptr: {arg_name});"
             );
             } else {
-                initializers += &format!("{maybe_space}let cb{idx} = __private__RustFnOnceCallback{maybe_associated_ty}${fn_name}$param{idx}(ptr: {arg_name});");
+                let class_prefix = boxed_fn.swift_callback_class_prefix();
+                initializers += &format!("{maybe_space}let cb{idx} = {class_prefix}{maybe_associated_ty}${fn_name}$param{idx}(ptr: {arg_name});");
             }
 
             maybe_space = " ";
@@ -618,7 +941,7 @@ void {free_boxed_fn_link_name}(void* {boxed_fn_arg_name});"#
     /// For example:
     /// "<GenericRustString: IntoRustString>"
     pub fn maybe_swift_generics(&self, types: &TypeDeclarations) -> String {
-        let mut maybe_generics = HashSet::new();
+        let mut maybe_generics = BTreeSet::new();
 
         for arg in self.sig.inputs.iter() {
             let bridged_arg = BridgedType::new_with_fn_arg(arg, types);
@@ -665,11 +988,95 @@ pub(crate) fn fn_arg_is_mutable_reference(fn_arg: &FnArg) -> bool {
         FnArg::Receiver(receiver) => receiver.reference.is_some() && receiver.mutability.is_some(),
         FnArg::Typed(pat_ty) => match pat_ty.ty.deref() {
             Type::Reference(type_ref) => type_ref.mutability.is_some(),
-            _ => false,
+            ty => pinned_mut_reference(ty).is_some(),
         },
     }
 }
 
+/// `&Foo` -> `&Foo`
+/// `Pin<&mut Foo>` -> `&mut Foo`
+pub(crate) fn self_type_reference(ty: &Type) -> Option<&TypeReference> {
+    match ty {
+        Type::Reference(type_ref) => Some(type_ref),
+        ty => pinned_mut_reference(ty),
+    }
+}
+
+/// `Box<Foo>` or `std::boxed::Box<Foo>` -> `Foo`
+pub(crate) fn boxed_self_type(ty: &Type) -> Option<&Type> {
+    smart_pointer_inner_type(ty, "Box", "boxed")
+}
+
+/// `Arc<Foo>` or `std::sync::Arc<Foo>` -> `Foo`
+pub(crate) fn arc_self_type(ty: &Type) -> Option<&Type> {
+    smart_pointer_inner_type(ty, "Arc", "sync")
+}
+
+/// Get the `Foo` in a `Pointer<Foo>`, `std::module::Pointer<Foo>` or
+/// `alloc::module::Pointer<Foo>`.
+fn smart_pointer_inner_type<'a>(ty: &'a Type, pointer: &str, module: &str) -> Option<&'a Type> {
+    let path = match ty {
+        Type::Path(path) if path.qself.is_none() => &path.path,
+        _ => return None,
+    };
+
+    let segments: Vec<_> = path.segments.iter().collect();
+    let (last, prefix) = segments.split_last()?;
+    let prefix: Vec<String> = prefix
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect();
+    if last.ident != pointer
+        || !(prefix.is_empty() || prefix == ["std", module] || prefix == ["alloc", module])
+    {
+        return None;
+    }
+
+    match &last.arguments {
+        PathArguments::AngleBracketed(generics) if generics.args.len() == 1 => {
+            match &generics.args[0] {
+                GenericArgument::Type(ty @ Type::Path(_)) => Some(ty),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// `Pin<&mut Foo>` or `std::pin::Pin<&mut Foo>` -> `&mut Foo`
+pub(crate) fn pinned_mut_reference(ty: &Type) -> Option<&TypeReference> {
+    let path = match ty {
+        Type::Path(path) if path.qself.is_none() => &path.path,
+        _ => return None,
+    };
+
+    let segments: Vec<_> = path.segments.iter().collect();
+    let (last, prefix) = segments.split_last()?;
+    let prefix: Vec<String> = prefix
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect();
+    if last.ident != "Pin"
+        || !(prefix.is_empty() || prefix == ["std", "pin"] || prefix == ["core", "pin"])
+    {
+        return None;
+    }
+
+    match &last.arguments {
+        PathArguments::AngleBracketed(generics) if generics.args.len() == 1 => {
+            match &generics.args[0] {
+                GenericArgument::Type(Type::Reference(type_ref))
+                    if type_ref.mutability.is_some() =>
+                {
+                    Some(type_ref)
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
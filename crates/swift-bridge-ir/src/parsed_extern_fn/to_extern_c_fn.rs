@@ -1,9 +1,13 @@
-use crate::bridged_type::BridgedType;
+use crate::bridged_type::boxed_fn::BridgeableBoxedFn;
+use crate::bridged_type::{BridgeableType, BridgedType};
 use crate::parse::{HostLang, OpaqueCopy, TypeDeclaration, TypeDeclarations};
-use crate::parsed_extern_fn::{GetField, GetFieldDirect, GetFieldWith, ParsedExternFn};
+use crate::parsed_extern_fn::{
+    GetField, GetFieldDirect, GetFieldWith, ParsedExternFn, SwiftProperty,
+};
+use crate::PanicPolicy;
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use syn::spanned::Spanned;
 use syn::Path;
 
@@ -28,7 +32,7 @@ impl ParsedExternFn {
         &self,
         swift_bridge_path: &Path,
         types: &TypeDeclarations,
-        custom_type_definitions: &mut HashMap<String, TokenStream>,
+        custom_type_definitions: &mut BTreeMap<String, TokenStream>,
     ) -> TokenStream {
         let link_name = self.link_name();
 
@@ -50,9 +54,40 @@ impl ParsedExternFn {
 
                 let is_async = self.sig.asyncness.is_some();
 
+                // The Rust function is usually deprecated alongside its bridge declaration, and
+                // calling it shouldn't warn inside of the generated code.
+                let maybe_allow_deprecated = if self.deprecation.is_some() {
+                    quote! { #[allow(deprecated)] }
+                } else {
+                    quote! {}
+                };
+
+                let maybe_assert_valid_receiver =
+                    self.assert_valid_receiver_tokens(swift_bridge_path);
+
                 if !is_async {
+                    let call_fn = quote! {
+                        #maybe_assert_valid_receiver
+                        #call_fn
+                    };
+                    let call_fn = match self.panic_handling(types) {
+                        PanicPolicy::Abort => call_fn,
+                        PanicPolicy::FatalError => quote! {
+                            #swift_bridge_path::panic_support::call_or_fatal_error(move || {
+                                #call_fn
+                            })
+                        },
+                        PanicPolicy::Throw => quote! {
+                            let body = move || {
+                                #call_fn
+                            };
+                            unsafe { #swift_bridge_path::panic_support::call_or_throw(body) }
+                        },
+                    };
+
                     quote! {
                         #[export_name = #link_name]
+                        #maybe_allow_deprecated
                         pub extern "C" fn #prefixed_fn_name ( #params ) #ret {
                             #call_fn
                         }
@@ -89,11 +124,13 @@ impl ParsedExternFn {
 
                     quote! {
                         #[export_name = #link_name]
+                        #maybe_allow_deprecated
                         pub extern "C" fn #prefixed_fn_name (
                             callback_wrapper: *mut std::ffi::c_void,
                             callback: extern "C" fn(*mut std::ffi::c_void #maybe_return_ty) -> (),
                             #params
                         ) {
+                            #maybe_assert_valid_receiver
                             let callback_wrapper = swift_bridge::async_support::SwiftCallbackWrapper(callback_wrapper);
                             let fut = #call_fn;
                             let task = async move {
@@ -118,6 +155,98 @@ impl ParsedExternFn {
         }
     }
 
+    /// Declares the Swift functions that call and free the Swift closures that get passed to an
+    /// extern "Rust" function.
+    ///
+    /// ```
+    /// extern "C" {
+    ///     #[link_name = "__swift_bridge__$some_function$param0"]
+    ///     fn __swift_bridge__some_function_param0(callback: *mut std::ffi::c_void, arg0: u8) -> u8;
+    ///     #[link_name = "__swift_bridge__$some_function$_free$param0"]
+    ///     fn __swift_bridge__some_function_free_param0(callback: *mut std::ffi::c_void);
+    /// }
+    /// ```
+    pub fn swift_callbacks_extern_fns(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> Vec<TokenStream> {
+        let mut extern_fns = vec![];
+
+        for (idx, boxed_fn) in self.args_filtered_to_boxed_fns(types) {
+            let call_link_name = self.call_boxed_fn_link_name(idx);
+            let free_link_name = self.free_boxed_fn_link_name(idx);
+            let (call_fn_name, free_fn_name) = self.swift_callback_rust_fn_idents(idx);
+
+            let params = boxed_fn.params_to_ffi_compatible_rust_types(swift_bridge_path, types);
+            let maybe_ret = if boxed_fn.ret.is_null() {
+                quote! {}
+            } else {
+                let ret = boxed_fn
+                    .ret
+                    .to_ffi_compatible_rust_type(swift_bridge_path, types);
+                quote! { -> #ret }
+            };
+
+            extern_fns.push(quote! {
+                #[link_name = #call_link_name]
+                fn #call_fn_name(callback: *mut std::ffi::c_void #(, #params)*) #maybe_ret;
+
+                #[link_name = #free_link_name]
+                fn #free_fn_name(callback: *mut std::ffi::c_void);
+            });
+        }
+
+        extern_fns
+    }
+
+    /// Wraps a Swift closure that was passed to a Rust function in a `Box<dyn Fn(A, B) -> C>`.
+    ///
+    /// The Swift closure gets released when the box is dropped.
+    pub(super) fn swift_callback_to_boxed_fn(
+        &self,
+        idx: usize,
+        arg: &TokenStream,
+        boxed_fn: &BridgeableBoxedFn,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let (call_fn_name, free_fn_name) = self.swift_callback_rust_fn_idents(idx);
+
+        let mut params = vec![];
+        let mut call_args = vec![];
+        for (param_idx, param) in boxed_fn.params.iter().enumerate() {
+            let param_name = Ident::new(&format!("arg{}", param_idx), Span::call_site());
+            let param_ty = param.to_rust_type_path(types);
+
+            params.push(quote! { #param_name: #param_ty });
+            call_args.push(param.convert_rust_expression_to_ffi_type(
+                &quote! { #param_name },
+                swift_bridge_path,
+                types,
+                // TODO: Add a UI test and then add a better span
+                Span::call_site(),
+            ));
+        }
+
+        let ret = boxed_fn.ret.to_rust_type_path(types);
+        let call_swift = boxed_fn.ret.convert_ffi_expression_to_rust_type(
+            &quote! {
+                unsafe { #call_fn_name(#arg.as_ptr() #(, #call_args)*) }
+            },
+            Span::call_site(),
+            swift_bridge_path,
+            types,
+        );
+
+        quote! {
+            {
+                let #arg = #swift_bridge_path::boxed_fn_support::SwiftCallback::new(#arg, #free_fn_name);
+                Box::new(move |#(#params),*| -> #ret { #call_swift })
+            }
+        }
+    }
+
     fn call_fn_tokens(&self, swift_bridge_path: &Path, types: &TypeDeclarations) -> TokenStream {
         let sig = &self.func.sig;
         let fn_name = if let Some(fn_name) = self.rust_name_override.as_ref() {
@@ -139,7 +268,7 @@ impl ParsedExternFn {
         };
 
         let mut call_fn = if self.is_method() {
-            self.call_method_tokens(&call_fn)
+            self.call_method_tokens(&call_fn, &call_args)
         } else {
             self.call_function_tokens(&call_fn)
         };
@@ -171,13 +300,25 @@ impl ParsedExternFn {
     }
 
     /// Generate tokens for calling a method.
-    fn call_method_tokens(&self, call_fn: &TokenStream) -> TokenStream {
+    fn call_method_tokens(&self, call_fn: &TokenStream, call_args: &TokenStream) -> TokenStream {
         let this = if self.is_copy_method_on_opaque_type() {
             quote! {
                 this.into_rust_repr()
             }
         } else {
-            if let Some(reference) = self.self_reference() {
+            if self.is_self_pinned() {
+                quote! {
+                    (unsafe { std::pin::Pin::new_unchecked(&mut *this) })
+                }
+            } else if self.is_self_arc() {
+                quote! {
+                    (unsafe { std::sync::Arc::from_raw(this) })
+                }
+            } else if self.is_self_boxed() {
+                quote! {
+                    (unsafe { Box::from_raw(this) })
+                }
+            } else if let Some(reference) = self.self_reference() {
                 let maybe_ref = reference.0;
                 let maybe_mut = self.self_mutability();
 
@@ -191,6 +332,22 @@ impl ParsedExternFn {
             }
         };
 
+        if self.is_stream_next {
+            return quote! {
+                swift_bridge::async_support::next_stream_item(#this)
+            };
+        }
+
+        if let Some(SwiftProperty::Setter {
+            name,
+            sets_field: true,
+        }) = &self.swift_property
+        {
+            return quote! {
+                #this . #name = #call_args
+            };
+        }
+
         match &self.get_field {
             Some(GetField::Direct(get_direct)) => {
                 let GetFieldDirect {
@@ -223,21 +380,25 @@ impl ParsedExternFn {
 
     /// Generate tokens for calling a freestanding or an associated function.
     fn call_function_tokens(&self, call_fn: &TokenStream) -> TokenStream {
-        let maybe_associated_type = self.associated_type.as_ref().map(|ty| {
-            match ty {
-                TypeDeclaration::Shared(_) => {
-                    //
-                    todo!()
+        match self.associated_type.as_ref() {
+            // Shared types are declared inside of the bridge module.
+            Some(TypeDeclaration::Shared(ty)) => {
+                let ty = ty.name();
+                quote! {
+                    #ty:: #call_fn
                 }
-                TypeDeclaration::Opaque(ty) => {
-                    let ty = &ty.ty;
-                    quote! {#ty::}
+            }
+            Some(TypeDeclaration::Opaque(ty)) => {
+                let ty = &ty.ty;
+                quote! {
+                    super:: #ty:: #call_fn
+                }
+            }
+            None => {
+                quote! {
+                    super:: #call_fn
                 }
             }
-        });
-
-        quote! {
-            super:: #maybe_associated_type #call_fn
         }
     }
 
@@ -246,11 +407,60 @@ impl ParsedExternFn {
         BridgedType::new_with_return_type(&self.func.sig.output, types)
     }
 
+    /// In debug builds, methods on opaque Rust types check the pointer that Swift passed in for
+    /// `self` before they dereference it, so that calling a method through a null or dangling
+    /// pointer panics with the method's name instead of crashing.
+    fn assert_valid_receiver_tokens(&self, swift_bridge_path: &Path) -> TokenStream {
+        let ty = match self.associated_type.as_ref() {
+            Some(TypeDeclaration::Opaque(ty)) if self.is_method() => ty,
+            _ => return quote! {},
+        };
+        if self.is_copy_method_on_opaque_type() {
+            return quote! {};
+        }
+
+        let method = format!("{}::{}", ty.ty, self.func.sig.ident);
+        quote! {
+            #[cfg(debug_assertions)]
+            #swift_bridge_path::assert_valid_receiver(this, #method);
+        }
+    }
+
+    /// How the generated function catches the Rust function's panics. `Abort` means that we don't
+    /// catch them, and a panic aborts the process when it reaches the `extern "C"` function.
+    ///
+    /// The panics of async functions happen on the async runtime's threads instead of in the
+    /// generated function, so they are left alone.
+    pub(crate) fn panic_handling(&self, types: &TypeDeclarations) -> PanicPolicy {
+        if self.host_lang.is_swift() || self.sig.asyncness.is_some() {
+            return PanicPolicy::Abort;
+        }
+
+        // Initializers, properties and subscripts don't throw on the Swift side, even when the
+        // Rust function returns a `Result`.
+        let throws = self.swift_property.is_none()
+            && !self.is_swift_initializer
+            && !self.is_swift_subscript
+            && self
+                .return_ty_built_in(types)
+                .map(|ty| ty.is_result())
+                .unwrap_or(false);
+        match self.panic_policy {
+            PanicPolicy::Throw if !throws => PanicPolicy::FatalError,
+            policy => policy,
+        }
+    }
+
     /// Whether or not this is a method on a type that is using `#[swift_bridge(Copy(...))]`
     pub(crate) fn is_copy_method_on_opaque_type(&self) -> bool {
         self.maybe_copy_descriptor().is_some()
     }
 
+    /// Whether or not this is an associated function of a transparent struct or enum.
+    pub(crate) fn is_associated_to_shared_type(&self) -> bool {
+        matches!(self.associated_type, Some(TypeDeclaration::Shared(_)))
+    }
+
     /// Describes the "..." in a `#[swift_bridge(Copy(...))]`
     pub(crate) fn maybe_copy_descriptor(&self) -> Option<OpaqueCopy> {
         match self.associated_type.as_ref()? {
@@ -335,7 +545,7 @@ mod tests {
             &function.to_extern_c_function_tokens(
                 &module.swift_bridge_path,
                 &module.types,
-                &mut HashMap::new(),
+                &mut BTreeMap::new(),
             ),
             &expected_fn,
         );
@@ -1,19 +1,19 @@
-use crate::bridged_type::{pat_type_pat_is_self, BridgeableType, BridgedType};
+use crate::bridged_type::{pat_type_pat_is_self, BridgeableType, BridgedType, StdLibType};
 use crate::parse::{HostLang, TypeDeclaration, TypeDeclarations};
-use crate::parsed_extern_fn::ParsedExternFn;
+use crate::parsed_extern_fn::{boxed_self_type, self_type_reference, ParsedExternFn};
 use proc_macro2::{Ident, TokenStream};
-use quote::{quote, ToTokens};
-use std::collections::HashMap;
+use quote::quote;
+use std::collections::BTreeMap;
 use std::ops::Deref;
 use syn::spanned::Spanned;
-use syn::{FnArg, Path, Type};
+use syn::{FnArg, Path};
 
 impl ParsedExternFn {
     pub fn to_extern_c_param_names_and_types(
         &self,
         swift_bridge_path: &Path,
         types: &TypeDeclarations,
-        custom_type_definitions: &mut HashMap<String, TokenStream>,
+        custom_type_definitions: &mut BTreeMap<String, TokenStream>,
     ) -> TokenStream {
         let mut params = vec![];
         let inputs = &self.func.sig.inputs;
@@ -23,7 +23,7 @@ impl ParsedExternFn {
                     HostLang::Rust => {
                         let this = match self.associated_type.as_ref().unwrap() {
                             TypeDeclaration::Opaque(opaque) => {
-                                let opaque_ty_ffi_repr = opaque.ffi_repr_type_tokens();
+                                let opaque_ty_ffi_repr = opaque.ffi_repr_type_tokens(types);
                                 quote! { this: #opaque_ty_ffi_repr }
                             }
                             TypeDeclaration::Shared(_) => {
@@ -55,7 +55,15 @@ impl ParsedExternFn {
                             }
 
                             let pat = &pat_ty.pat;
-                            let ty = built_in.to_ffi_compatible_rust_type(swift_bridge_path, types);
+                            let ty = match &built_in {
+                                // Swift passes closures to Rust functions as retained pointers.
+                                BridgedType::StdLib(StdLibType::BoxedFn(_))
+                                    if self.host_lang.is_rust() =>
+                                {
+                                    quote! { *mut std::ffi::c_void }
+                                }
+                                _ => built_in.to_ffi_compatible_rust_type(swift_bridge_path, types),
+                            };
 
                             params.push(quote! { #pat: #ty});
 
@@ -77,22 +85,47 @@ impl ParsedExternFn {
                         #this
                     };
 
-                    let mut bridged_type = &pat_ty.ty;
+                    // `self: Arc<Self>` is passed as the pointer from `Arc::into_raw`.
+                    if let Some(arc) = self.arc_self_bridged_type(types) {
+                        if let Some(tokens) =
+                            arc.generate_custom_rust_ffi_types(swift_bridge_path, types)
+                        {
+                            for token in tokens.into_iter() {
+                                custom_type_definitions.insert(token.to_string(), token);
+                            }
+                        }
+
+                        let arg_ty = arc.to_ffi_compatible_rust_type(swift_bridge_path, types);
+                        params.push(quote! {
+                             #arg_name: #arg_ty
+                        });
+
+                        continue;
+                    }
+
+                    let mut bridged_type = pat_ty.ty.deref();
 
                     // `&Foo` becomes `Foo`
                     // `&mut Foo` becomes `Foo`
-                    if let Type::Reference(ty_ref) = pat_ty.ty.deref() {
-                        bridged_type = &ty_ref.elem;
+                    // `Pin<&mut Foo>` becomes `Foo`
+                    // `Box<Foo>` becomes `Foo`
+                    if let Some(ty_ref) = self_type_reference(&pat_ty.ty) {
+                        bridged_type = ty_ref.elem.deref();
+                    } else if let Some(boxed) = boxed_self_type(&pat_ty.ty) {
+                        bridged_type = boxed;
+                    };
+
+                    // `self: Pin<&mut Self>` and `self: Box<Self>` refer to the type that the method is associated to.
+                    let self_ty = match types.get_with_type(bridged_type) {
+                        Some(self_ty) => self_ty,
+                        None => self.associated_type.as_ref().unwrap(),
                     };
 
-                    let arg_ty = match types
-                        .get(&bridged_type.to_token_stream().to_string())
-                        .unwrap()
-                    {
+                    let arg_ty = match self_ty {
                         TypeDeclaration::Shared(_) => {
                             todo!("Support methods on shared types.")
                         }
-                        TypeDeclaration::Opaque(opaque) => opaque.ffi_repr_type_tokens(),
+                        TypeDeclaration::Opaque(opaque) => opaque.ffi_repr_type_tokens(types),
                     };
 
                     params.push(quote! {
@@ -139,7 +172,7 @@ mod tests {
                 &method.to_extern_c_param_names_and_types(
                     &module.swift_bridge_path,
                     &module.types,
-                    &mut HashMap::new(),
+                    &mut BTreeMap::new(),
                 ),
                 &quote! { this },
             );
@@ -169,7 +202,7 @@ mod tests {
             &funcs[0].to_extern_c_param_names_and_types(
                 &module.swift_bridge_path,
                 &module.types,
-                &mut HashMap::new(),
+                &mut BTreeMap::new(),
             ),
             expected_params,
         );
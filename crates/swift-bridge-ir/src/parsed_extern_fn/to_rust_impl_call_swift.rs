@@ -33,9 +33,30 @@ impl ParsedExternFn {
         &self,
         swift_bridge_path: &Path,
         types: &TypeDeclarations,
+    ) -> TokenStream {
+        let sig = self.to_rust_fn_sig_that_calls_a_swift_extern(types);
+        let body = self.to_rust_fn_body_that_calls_a_swift_extern(swift_bridge_path, types);
+
+        quote! {
+            pub #sig {
+                #body
+            }
+        }
+    }
+
+    /// Generates the `fn new () -> Foo` signature of the Rust function that calls the Swift
+    /// function.
+    pub fn to_rust_fn_sig_that_calls_a_swift_extern(
+        &self,
+        types: &TypeDeclarations,
     ) -> TokenStream {
         let sig = &self.func.sig;
-        let fn_name = &sig.ident;
+        // The declared name is the Swift function's name, so `rust_name` only changes the name
+        // of the Rust function that calls it.
+        let fn_name = match self.rust_name_override.as_ref() {
+            Some(rust_name) => Ident::new(&rust_name.value(), rust_name.span()),
+            None => sig.ident.clone(),
+        };
 
         let ret = &sig.output;
 
@@ -56,6 +77,19 @@ impl ParsedExternFn {
         };
 
         let params = self.params_with_explicit_self_types_removed(types);
+
+        quote! {
+            fn #fn_name(#params) #ret
+        }
+    }
+
+    /// Generates the body of the Rust function that calls the Swift function.
+    pub fn to_rust_fn_body_that_calls_a_swift_extern(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let sig = &self.func.sig;
         let call_args = self.to_call_rust_args(swift_bridge_path, types);
         let linked_fn_name = self.extern_swift_linked_fn_new();
 
@@ -74,17 +108,14 @@ impl ParsedExternFn {
             todo!("Push to ParsedErrors")
         }
 
-        quote! {
-            pub fn #fn_name(#params) #ret {
-                #inner
-            }
-        }
+        inner
     }
 
     /// #\[export_name = "__swift_bridge__$SomeType$some_method$param1"]
     /// pub extern "C" fn SomeType_some_method_param1(boxed_fn: *mut dyn FnOnce(u8) -> (), arg0: u8) {
     ///     unsafe { Box::from_raw(boxed_fn) }(arg0)
     /// }
+    /// (or `unsafe { (*boxed_fn)(arg0) }` for an `Fn` or `FnMut`)
     /// #\[export_name = "__swift_bridge__$SomeType$some_method$_free$param1"]
     /// pub extern "C" fn free_SomeType_some_method_param1(boxed_fn: *mut dyn FnOnce(u8) -> ()) {
     ///     unsafe { Box::from_raw(boxed_fn) }
@@ -99,7 +130,7 @@ impl ParsedExternFn {
 
         let mut boxed_fn_support = vec![];
         for (idx, boxed_fn) in self.args_filtered_to_boxed_fns(types) {
-            if boxed_fn.does_not_have_params_or_return() {
+            if boxed_fn.uses_no_args_no_return_support() {
                 continue;
             }
 
@@ -144,8 +175,16 @@ impl ParsedExternFn {
             let arg_name = self.arg_name_tokens_at_idx(idx).unwrap();
             let arg_name = Ident::new(&format!("{}_{}", fn_name, arg_name), arg_name.span());
 
-            let call_boxed_fn = quote! {
-                unsafe { Box::from_raw(#arg_name)(#(#call_args),*) }
+            // An `FnOnce` gets consumed when it is called, whereas an `Fn` or `FnMut` can be
+            // called any number of times and gets dropped when Swift calls the free function.
+            let call_boxed_fn = if boxed_fn.kind.is_fn_once() {
+                quote! {
+                    unsafe { Box::from_raw(#arg_name)(#(#call_args),*) }
+                }
+            } else {
+                quote! {
+                    unsafe { (*#arg_name)(#(#call_args),*) }
+                }
             };
             let call_boxed_fn = boxed_fn.ret.convert_rust_expression_to_ffi_type(
                 &call_boxed_fn,
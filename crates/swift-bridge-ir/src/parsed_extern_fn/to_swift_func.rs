@@ -1,9 +1,10 @@
 use crate::bridged_type::{pat_type_pat_is_self, BridgeableType, BridgedType, TypePosition};
-use crate::parse::TypeDeclarations;
-use crate::parsed_extern_fn::ParsedExternFn;
+use crate::parse::{HostLang, TypeDeclarations};
+use crate::parsed_extern_fn::{
+    self_type_reference, swift_param_name, unraw_arg_name, ParsedExternFn,
+};
 use quote::{format_ident, ToTokens};
-use std::ops::Deref;
-use syn::{FnArg, Path, ReturnType, Type};
+use syn::{FnArg, Path, ReturnType};
 
 impl ParsedExternFn {
     pub fn to_swift_param_names_and_types(
@@ -32,6 +33,7 @@ impl ParsedExternFn {
                     }
 
                     let arg_name = pat_ty.pat.to_token_stream().to_string();
+                    let swift_arg_name = swift_param_name(&arg_name);
 
                     let ty = if let Some(built_in) = BridgedType::new_with_type(&pat_ty.ty, types) {
                         if self.host_lang.is_swift() {
@@ -45,12 +47,27 @@ impl ParsedExternFn {
                         todo!("Push to ParsedErrors")
                     };
 
+                    // Only the Swift functions that call into Rust get default values.
+                    let ty = match self.argument_defaults.get(&format_ident!("{}", arg_name)) {
+                        Some(default) if self.host_lang.is_rust() => {
+                            format!("{} = {}", ty, default)
+                        }
+                        _ => ty,
+                    };
+
                     if let Some(argument_label) =
                         self.argument_labels.get(&format_ident!("{}", arg_name))
                     {
-                        format!("{} {}: {}", argument_label.value().as_str(), arg_name, ty)
+                        let argument_label = argument_label.value();
+
+                        // Swift warns when an argument label repeats the parameter name.
+                        if argument_label == swift_arg_name {
+                            format!("{}: {}", swift_arg_name, ty)
+                        } else {
+                            format!("{} {}: {}", argument_label, swift_arg_name, ty)
+                        }
                     } else {
-                        format!("_ {}: {}", arg_name, ty)
+                        format!("_ {}: {}", swift_arg_name, ty)
                     }
                 }
             };
@@ -79,55 +96,69 @@ impl ParsedExternFn {
             match arg {
                 FnArg::Receiver(receiver) => {
                     if include_receiver_if_present {
-                        self.push_receiver_as_arg(&mut args, receiver.reference.is_some());
+                        self.push_receiver_as_arg(&mut args, receiver.reference.is_some(), types);
                     }
                 }
                 FnArg::Typed(pat_ty) => {
-                    let is_reference = match pat_ty.ty.deref() {
-                        Type::Reference(_) => true,
-                        _ => false,
-                    };
+                    // `self: Pin<&mut Self>` is passed the same way as `self: &mut Self`.
+                    let is_reference = self_type_reference(&pat_ty.ty).is_some();
 
                     if pat_type_pat_is_self(pat_ty) {
                         if include_receiver_if_present {
-                            self.push_receiver_as_arg(&mut args, is_reference);
+                            self.push_receiver_as_arg(&mut args, is_reference, types);
                         }
 
                         continue;
                     }
 
                     let pat = &pat_ty.pat;
-                    let arg = pat.to_token_stream().to_string();
-                    let arg_name = arg.clone();
+                    let arg_name = pat.to_token_stream().to_string();
+                    let arg = swift_param_name(&arg_name);
 
-                    let arg =
-                        if let Some(bridged_ty) = BridgedType::new_with_type(&pat_ty.ty, types) {
-                            if self.host_lang.is_rust() {
-                                if bridged_ty.can_be_encoded_with_zero_bytes() {
-                                    continue;
-                                }
+                    let arg = if let Some(bridged_ty) =
+                        BridgedType::new_with_type(&pat_ty.ty, types)
+                    {
+                        if self.host_lang.is_rust() {
+                            if bridged_ty.can_be_encoded_with_zero_bytes() {
+                                continue;
+                            }
 
+                            if bridged_ty.is_boxed_fn() {
+                                let class_name = self.swift_callback_class_name(arg_idx);
+                                format!("Unmanaged.passRetained({class_name}({arg})).toOpaque()")
+                            } else {
                                 bridged_ty.convert_swift_expression_to_ffi_type(
                                     &arg,
                                     types,
                                     TypePosition::FnArg(self.host_lang, arg_idx),
                                 )
-                            } else {
-                                if let Some(only) = bridged_ty.only_encoding() {
-                                    only.swift
-                                } else {
-                                    bridged_ty.convert_ffi_value_to_swift_value(
-                                        &arg,
-                                        TypePosition::FnArg(self.host_lang, arg_idx),
-                                        types,
-                                    )
-                                }
                             }
                         } else {
-                            todo!("Push to ParsedErrors")
-                        };
+                            if let Some(only) = bridged_ty.only_encoding() {
+                                only.swift
+                            } else {
+                                bridged_ty.convert_ffi_value_to_swift_value(
+                                    &arg,
+                                    TypePosition::FnArg(self.host_lang, arg_idx),
+                                    types,
+                                )
+                            }
+                        }
+                    } else {
+                        todo!("Push to ParsedErrors")
+                    };
                     let arg = if include_var_name {
-                        format!("{}: {}", arg_name, arg)
+                        let argument_label = self
+                            .argument_labels
+                            .get(&format_ident!("{}", arg_name))
+                            .map(|label| label.value())
+                            .unwrap_or_else(|| unraw_arg_name(&arg_name).to_string());
+
+                        if argument_label == "_" {
+                            arg
+                        } else {
+                            format!("{}: {}", argument_label, arg)
+                        }
                     } else {
                         arg
                     };
@@ -164,14 +195,89 @@ impl ParsedExternFn {
         }
     }
 
-    fn push_receiver_as_arg(&self, args: &mut Vec<String>, is_reference: bool) {
+    /// Generate the requirement that a bridged trait's method adds to its Swift protocol.
+    ///
+    /// `fn greet(&self, name: String) -> String` becomes `func greet(name: RustString) -> String`.
+    pub fn to_swift_protocol_requirement(&self, types: &TypeDeclarations) -> String {
+        let fn_name = if let Some(swift_name) = self.swift_name_override.as_ref() {
+            swift_name.value()
+        } else {
+            self.func.sig.ident.to_string()
+        };
+
+        let mut params = vec![];
+        for arg in self.func.sig.inputs.iter() {
+            let pat_ty = match arg {
+                FnArg::Typed(pat_ty) if !pat_type_pat_is_self(pat_ty) => pat_ty,
+                _ => continue,
+            };
+
+            let bridged_ty = BridgedType::new_with_type(&pat_ty.ty, types).unwrap();
+            if bridged_ty.can_be_encoded_with_zero_bytes() {
+                continue;
+            }
+
+            let arg_name = pat_ty.pat.to_token_stream().to_string();
+            // The Swift implementation receives the same types that a Rust function returns
+            // to Swift.
+            let ty = bridged_ty.to_swift_type(TypePosition::FnReturn(HostLang::Rust), types);
+
+            let swift_arg_name = swift_param_name(&arg_name);
+            let label = match self.argument_labels.get(&format_ident!("{}", arg_name)) {
+                Some(label) => label.value(),
+                None => unraw_arg_name(&arg_name).to_string(),
+            };
+
+            let param = if label == swift_arg_name {
+                format!("{}: {}", swift_arg_name, ty)
+            } else {
+                format!("{} {}: {}", label, swift_arg_name, ty)
+            };
+            params.push(param);
+        }
+
+        let ret = match &self.func.sig.output {
+            ReturnType::Default => "".to_string(),
+            ReturnType::Type(_, ty) => {
+                let bridged_ty = BridgedType::new_with_type(ty, types).unwrap();
+                if bridged_ty.can_be_encoded_with_zero_bytes() {
+                    "".to_string()
+                } else {
+                    let ty = bridged_ty.to_swift_type(TypePosition::SwiftReturnsToRust, types);
+                    format!(" -> {}", ty)
+                }
+            }
+        };
+
+        format!("func {}({}){}", fn_name, params.join(", "), ret)
+    }
+
+    fn push_receiver_as_arg(
+        &self,
+        args: &mut Vec<String>,
+        is_reference: bool,
+        types: &TypeDeclarations,
+    ) {
+        // `self: Arc<Self>` gets its own strong count, which Rust releases once the call is
+        // done with it.
+        if let Some(arc) = self.arc_self_bridged_type(types) {
+            args.push(arc.convert_swift_expression_to_ffi_type(
+                "self",
+                types,
+                TypePosition::FnArg(self.host_lang, 0),
+            ));
+            return;
+        }
+
         let arg = if self.is_copy_method_on_opaque_type() {
             "self.bytes"
         } else {
             if is_reference {
                 "ptr"
             } else {
-                "{isOwned = false; return ptr;}()"
+                // A consuming method moves the value into Rust, so the instance must not be
+                // consumed or freed again.
+                r#"{precondition(isOwned, "This value was already moved into Rust"); isOwned = false; return ptr;}()"#
             }
         };
         args.push(arg.to_string());
@@ -322,12 +428,12 @@ mod tests {
 
         assert_eq!(
             functions[0].to_swift_call_args(true, false, &module.types, &module.swift_bridge_path),
-            "{isOwned = false; return ptr;}()"
+            r#"{precondition(isOwned, "This value was already moved into Rust"); isOwned = false; return ptr;}()"#
         );
 
         assert_eq!(
             functions[1].to_swift_call_args(true, false, &module.types, &module.swift_bridge_path),
-            "{isOwned = false; return ptr;}()"
+            r#"{precondition(isOwned, "This value was already moved into Rust"); isOwned = false; return ptr;}()"#
         );
 
         assert_eq!(
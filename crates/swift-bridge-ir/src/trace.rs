@@ -0,0 +1,130 @@
+//! Logging that shows what the code generator decided to do with a bridge module, enabled by
+//! setting the `SWIFT_BRIDGE_TRACE` environment variable.
+
+use crate::bridged_type::{BridgeableType, BridgedType, TypePosition};
+use crate::parse::{type_to_string, HostLang};
+use crate::SwiftBridgeModule;
+use std::sync::OnceLock;
+use syn::{FnArg, ReturnType};
+
+/// Whether or not the `SWIFT_BRIDGE_TRACE` environment variable is set to anything other than
+/// `0`.
+pub fn trace_enabled() -> bool {
+    static ENABLED: OnceLock<bool> = OnceLock::new();
+
+    *ENABLED.get_or_init(|| match std::env::var("SWIFT_BRIDGE_TRACE") {
+        Ok(value) => value != "0",
+        Err(_) => false,
+    })
+}
+
+/// Print a message to stderr when tracing is enabled.
+///
+/// The message is only formatted when it is going to be printed.
+pub fn trace(message: impl FnOnce() -> String) {
+    if trace_enabled() {
+        eprintln!("[swift-bridge] {}", message());
+    }
+}
+
+impl SwiftBridgeModule {
+    /// Trace how each of the module's functions was classified, along with the type that each of
+    /// their arguments and return values was matched to.
+    pub(crate) fn trace_functions(&self) {
+        if !trace_enabled() {
+            return;
+        }
+
+        trace(|| format!("module `{}`", self.name));
+
+        for func in &self.functions {
+            let host_lang = match func.host_lang {
+                HostLang::Rust => "extern \"Rust\"",
+                HostLang::Swift => "extern \"Swift\"",
+            };
+            let associated_type = func
+                .associated_type
+                .as_ref()
+                .and_then(|ty| ty.as_opaque())
+                .map(|ty| ty.ty.to_string());
+            let kind = match associated_type {
+                Some(ty) if func.is_method() => format!("method of `{}`", ty),
+                Some(ty) => format!("associated function of `{}`", ty),
+                None => "free function".to_string(),
+            };
+            trace(|| format!("  fn `{}`: {} {}", func.sig.ident, host_lang, kind));
+
+            for (idx, arg) in func.sig.inputs.iter().enumerate() {
+                if let FnArg::Typed(pat_ty) = arg {
+                    let position = TypePosition::FnArg(func.host_lang, idx);
+                    trace(|| format!("    arg {}", self.describe_type(&pat_ty.ty, position)));
+                }
+            }
+            if let ReturnType::Type(_, ty) = &func.sig.output {
+                let position = TypePosition::FnReturn(func.host_lang);
+                trace(|| format!("    returns {}", self.describe_type(ty, position)));
+            }
+        }
+    }
+
+    /// `Vec<u8>` -> "`Vec<u8>`: built-in type, Swift `RustVec<UInt8>`"
+    fn describe_type(&self, ty: &syn::Type, position: TypePosition) -> String {
+        let rust_ty = type_to_string(ty);
+
+        match BridgedType::new_with_type(ty, &self.types) {
+            Some(bridged) => {
+                let kind = if bridged.is_built_in_type() {
+                    "built-in type"
+                } else {
+                    "declared type"
+                };
+                format!(
+                    "`{}`: {}, Swift `{}`",
+                    rust_ty,
+                    kind,
+                    bridged.to_swift_type(position, &self.types)
+                )
+            }
+            None => format!("`{}`: not a supported type", rust_ty),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::parse_ok;
+    use quote::quote;
+
+    /// Verify that we describe which kind of type a function's argument was matched to.
+    #[test]
+    fn describes_types() {
+        let module = parse_ok(quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                }
+            }
+        });
+        let position = TypePosition::FnArg(HostLang::Rust, 0);
+
+        let cases = [
+            (
+                quote! { Vec<u8> },
+                "`Vec<u8>`: built-in type, Swift `RustVec<UInt8>`",
+            ),
+            (
+                quote! { SomeType },
+                "`SomeType`: declared type, Swift `SomeType`",
+            ),
+            (
+                quote! { UnknownType },
+                "`UnknownType`: not a supported type",
+            ),
+        ];
+        for (ty, expected) in cases {
+            let ty: syn::Type = syn::parse2(ty).unwrap();
+            assert_eq!(module.describe_type(&ty, position), expected);
+        }
+    }
+}
@@ -0,0 +1,16 @@
+//! # To Run
+//! cargo test -p swift-bridge-macro -- ui trybuild=duplicate-declaration.rs
+
+// We declare a type and a function twice, and verify that the errors point at both declarations.
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type SomeType;
+        type SomeType;
+
+        fn some_function();
+        fn some_function(arg: u8);
+    }
+}
+
+fn main() {}
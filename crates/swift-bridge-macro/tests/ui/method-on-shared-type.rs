@@ -0,0 +1,20 @@
+//! # To Run
+//! cargo test -p swift-bridge-macro -- ui trybuild=method-on-shared-type.rs
+
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct")]
+    struct SomeStruct {
+        field: u8,
+    }
+
+    extern "Rust" {
+        fn some_method(self: &SomeStruct) -> u8;
+    }
+}
+
+fn some_method(some_struct: &ffi::SomeStruct) -> u8 {
+    some_struct.field
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+//! # To Run
+//! cargo test -p swift-bridge-macro -- ui trybuild=non-sendable-type.rs
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(Sendable)]
+        type NotSync;
+    }
+}
+
+pub struct NotSync(std::cell::Cell<u8>);
+
+fn main() {}
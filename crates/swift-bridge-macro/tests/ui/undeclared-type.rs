@@ -0,0 +1,18 @@
+//! # To Run
+//! cargo test -p swift-bridge-macro -- ui trybuild=undeclared-type.rs
+
+// We use a type that was never declared in the bridge module, and verify that the errors point at
+// each use of it.
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct")]
+    struct SomeStruct {
+        field: Foo,
+    }
+
+    extern "Rust" {
+        fn use_it(f: Foo);
+    }
+}
+
+fn main() {}
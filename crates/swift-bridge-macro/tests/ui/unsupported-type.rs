@@ -0,0 +1,22 @@
+//! # To Run
+//! cargo test -p swift-bridge-macro -- ui trybuild=unsupported-type.rs
+
+// We declare functions that use types that can't be bridged, and verify that the errors point at
+// the offending types.
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn vec_of_undeclared(arg: Vec<Undeclared>);
+        fn ref_vec(arg: &Vec<u8>);
+        fn vec_of_str() -> Vec<&'static str>;
+        fn vec_of_array() -> Vec<[u8; 4]>;
+        fn option_array(arg: Option<[u8; 4]>);
+        fn option_range() -> Option<std::ops::Range<u8>>;
+        fn tuple_of_fn_pointer() -> (u8, extern "C" fn(u8));
+
+        // A `Vec` of a tuple is supported, so this should not lead to an error.
+        fn vec_of_tuple() -> Vec<(u8, u16)>;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,61 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type SharedCounter;
+
+        fn new_shared_counter() -> Arc<SharedCounter>;
+        fn rust_reflect_shared_counter(arg: Arc<SharedCounter>) -> Arc<SharedCounter>;
+        fn rust_reflect_option_shared_counter(
+            arg: Option<Arc<SharedCounter>>,
+        ) -> Option<Arc<SharedCounter>>;
+        fn shared_counter_other_owners(arg: Arc<SharedCounter>) -> usize;
+
+        fn increment(&self);
+        fn value(&self) -> u32;
+        fn owners(self: Arc<Self>) -> usize;
+    }
+}
+
+pub struct SharedCounter {
+    value: AtomicU32,
+}
+
+fn new_shared_counter() -> Arc<SharedCounter> {
+    Arc::new(SharedCounter {
+        value: AtomicU32::new(0),
+    })
+}
+
+fn rust_reflect_shared_counter(arg: Arc<SharedCounter>) -> Arc<SharedCounter> {
+    arg
+}
+
+fn rust_reflect_option_shared_counter(
+    arg: Option<Arc<SharedCounter>>,
+) -> Option<Arc<SharedCounter>> {
+    arg
+}
+
+/// The number of strong counts, other than the one that was passed in, that are keeping the
+/// counter alive.
+fn shared_counter_other_owners(arg: Arc<SharedCounter>) -> usize {
+    Arc::strong_count(&arg) - 1
+}
+
+impl SharedCounter {
+    fn increment(&self) {
+        self.value.fetch_add(1, Ordering::SeqCst);
+    }
+
+    fn value(&self) -> u32 {
+        self.value.load(Ordering::SeqCst)
+    }
+
+    /// The number of strong counts, including the one that the method was called with.
+    fn owners(self: Arc<Self>) -> usize {
+        Arc::strong_count(&self)
+    }
+}
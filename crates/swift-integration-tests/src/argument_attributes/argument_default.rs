@@ -0,0 +1,19 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn test_argument_default(
+            base: i32,
+            #[swift_bridge(label = "times", default = 2)] multiplier: i32,
+            #[swift_bridge(label = "negate", default = false)] negate: bool,
+        ) -> i32;
+    }
+}
+
+fn test_argument_default(base: i32, multiplier: i32, negate: bool) -> i32 {
+    let product = base * multiplier;
+    if negate {
+        -product
+    } else {
+        product
+    }
+}
@@ -5,9 +5,29 @@ mod ffi {
             #[swift_bridge(label = "someArg")] some_arg: i32,
             another_arg: i32,
         ) -> i32;
+
+        #[swift_bridge(labeled_args)]
+        fn test_labeled_args(base: i32, #[swift_bridge(label = "times")] multiplier: i32) -> i32;
+
+        fn test_rust_calls_swift_with_argument_labels() -> i32;
+    }
+
+    extern "Swift" {
+        fn swift_fn_with_argument_labels(
+            #[swift_bridge(label = "from")] start: i32,
+            #[swift_bridge(label = "_")] end: i32,
+        ) -> i32;
     }
 }
 
 fn test_argument_label(some_arg: i32, another_arg: i32) -> i32 {
     some_arg + another_arg
 }
+
+fn test_labeled_args(base: i32, multiplier: i32) -> i32 {
+    base * multiplier
+}
+
+fn test_rust_calls_swift_with_argument_labels() -> i32 {
+    ffi::swift_fn_with_argument_labels(3, 10)
+}
@@ -0,0 +1,26 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_reflect_u8_array(arg: [u8; 32]) -> [u8; 32];
+        fn rust_sum_f64_array(arg: [f64; 3]) -> f64;
+
+        fn rust_run_swift_array_tests();
+    }
+
+    extern "Swift" {
+        fn swift_reverse_u16_array(arg: [u16; 3]) -> [u16; 3];
+    }
+}
+
+fn rust_reflect_u8_array(arg: [u8; 32]) -> [u8; 32] {
+    arg
+}
+
+fn rust_sum_f64_array(arg: [f64; 3]) -> f64 {
+    arg.iter().sum()
+}
+
+/// Verify that Rust can pass a fixed-size array to Swift and get one back.
+fn rust_run_swift_array_tests() {
+    assert_eq!(ffi::swift_reverse_u16_array([1, 2, 3]), [3, 2, 1]);
+}
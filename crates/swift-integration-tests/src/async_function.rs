@@ -10,6 +10,7 @@ mod ffi {
         async fn rust_async_reflect_u8(arg: u8) -> u8;
         async fn rust_async_reflect_string(string: String) -> String;
         async fn rust_async_return_struct() -> AsyncRustFnReturnStruct;
+        async fn rust_async_return_opaque_rust_type(val: u32) -> AsyncResultOpaqueRustType1;
         async fn rust_async_func_reflect_result_opaque_rust(
             arg: Result<AsyncResultOpaqueRustType1, AsyncResultOpaqueRustType2>,
         ) -> Result<AsyncResultOpaqueRustType1, AsyncResultOpaqueRustType2>;
@@ -83,6 +84,10 @@ async fn rust_async_return_struct() -> ffi::AsyncRustFnReturnStruct {
     ffi::AsyncRustFnReturnStruct { field: 123 }
 }
 
+async fn rust_async_return_opaque_rust_type(val: u32) -> AsyncResultOpaqueRustType1 {
+    AsyncResultOpaqueRustType1(val)
+}
+
 pub struct TestRustAsyncSelf;
 
 impl TestRustAsyncSelf {
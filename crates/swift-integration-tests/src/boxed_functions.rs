@@ -1,3 +1,6 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
 #[swift_bridge::bridge]
 mod ffi {
     extern "Swift" {
@@ -16,6 +19,11 @@ mod ffi {
 
         fn swift_calls_rust_fnonce_callback_twice(arg: Box<dyn FnOnce() -> ()>);
 
+        fn swift_takes_fn_callback_primitive(arg: Box<dyn Fn(u32) -> u32>) -> u32;
+        fn swift_takes_fnmut_callback_no_args_no_return(arg: Box<dyn FnMut()>);
+
+        fn swift_takes_extern_c_fn(arg: extern "C" fn(u32) -> u32) -> u32;
+
         fn swift_func_takes_callback_with_result_arg(
             arg: Box<dyn FnOnce(Result<CallbackTestOpaqueRustType, String>)>,
         );
@@ -34,23 +42,22 @@ mod ffi {
         ) -> u16;
     }
 
-    // TODO
-    // extern "Rust" {
-    //     fn rust_takes_callback_fnonce_no_args_no_return(arg: Box<dyn FnOnce() -> ()>);
-    //     fn rust_takes_callback_fnonce_primitive(doubling_fn: Box<dyn FnOnce(u8) -> u8>);
-    //     fn rust_takes_callback_fnonce_opaque_rust(
-    //         doubling_fn: Box<dyn FnOnce(CallbackTestOpaqueRustType) -> CallbackTestOpaqueRustType>,
-    //     );
-    //
-    //     fn rust_takes_callback_fnonce_two_params(
-    //         arg: Box<dyn FnOnce(i16, CallbackTestOpaqueRustType)>,
-    //     );
-    //
-    //     fn rust_takes_two_callbacks_fnonce_noop(
-    //         arg1: Box<dyn FnOnce()>,
-    //         arg2: Box<dyn FnOnce() -> ()>,
-    //     );
-    // }
+    extern "Rust" {
+        fn rust_takes_swift_closure_no_args_no_return(arg: Box<dyn FnOnce()>);
+        fn rust_takes_swift_closure_primitive(doubling_fn: Box<dyn Fn(u8) -> u8>) -> u8;
+        fn rust_takes_swift_closure_opaque_rust(
+            doubling_fn: Box<dyn FnMut(CallbackTestOpaqueRustType) -> CallbackTestOpaqueRustType>,
+        ) -> u32;
+        fn rust_takes_swift_closure_string(greet: Box<dyn Fn(String) -> String>) -> String;
+
+        fn rust_takes_extern_c_fn(doubling_fn: extern "C" fn(u32) -> u32, val: u32) -> u32;
+        fn rust_returns_extern_c_fn() -> extern "C" fn(u32) -> u32;
+        fn rust_takes_optional_extern_c_fn(
+            doubling_fn: Option<extern "C" fn(u32) -> u32>,
+            val: u32,
+        ) -> Option<u32>;
+        fn rust_returns_optional_extern_c_fn(some: bool) -> Option<extern "C" fn(u32) -> u32>;
+    }
 
     extern "Rust" {
         type CallbackTestOpaqueRustType;
@@ -66,30 +73,52 @@ mod ffi {
     }
 }
 
-// TODO
-// fn rust_takes_callback_fnonce_no_args_no_return(arg: Box<dyn FnOnce() -> ()>) {
-//     (arg)()
-// }
-// fn rust_takes_callback_fnonce_primitive(doubling_fn: Box<dyn FnOnce(u8) -> u8>) {
-//     let doubling_fn = (doubling_fn)(2);
-//     assert_eq!(doubled, 4)
-// }
-//
-// fn rust_takes_callback_fnonce_opaque_rust(
-//     doubling_fn: Box<dyn FnOnce(CallbackTestOpaqueRustType) -> CallbackTestOpaqueRustType>,
-// ) {
-//     let start = CallbackTestOpaqueRustType { val: 100 };
-//
-//     let doubled = (doubling_fn)(start);
-//     assert_eq!(doubled.val(), 200);
-// }
-//
-// fn rust_takes_callback_fnonce_two_params(arg: Box<dyn FnOnce(i16, CallbackTestOpaqueRustType)>) {
-//     (arg)(123, CallbackTestOpaqueRustType { val: 222 })
-// }
-// fn rust_takes_two_callbacks_fnonce_noop(arg: Box<dyn FnOnce(i16, CallbackTestOpaqueRustType)>) {
-//     (arg)(123, CallbackTestOpaqueRustType { val: 222 })
-// }
+fn rust_takes_swift_closure_no_args_no_return(arg: Box<dyn FnOnce()>) {
+    (arg)()
+}
+
+/// Calls the closure twice, starting with 2, and returns the sum of the results.
+fn rust_takes_swift_closure_primitive(doubling_fn: Box<dyn Fn(u8) -> u8>) -> u8 {
+    (doubling_fn)(2) + (doubling_fn)(4)
+}
+
+fn rust_takes_swift_closure_opaque_rust(
+    mut doubling_fn: Box<dyn FnMut(CallbackTestOpaqueRustType) -> CallbackTestOpaqueRustType>,
+) -> u32 {
+    let doubled = (doubling_fn)(CallbackTestOpaqueRustType { val: 100 });
+    (doubling_fn)(doubled).val()
+}
+
+fn rust_takes_swift_closure_string(greet: Box<dyn Fn(String) -> String>) -> String {
+    (greet)("world".to_string())
+}
+
+fn rust_takes_extern_c_fn(doubling_fn: extern "C" fn(u32) -> u32, val: u32) -> u32 {
+    doubling_fn(val)
+}
+
+fn rust_returns_extern_c_fn() -> extern "C" fn(u32) -> u32 {
+    add_one
+}
+
+fn rust_takes_optional_extern_c_fn(
+    doubling_fn: Option<extern "C" fn(u32) -> u32>,
+    val: u32,
+) -> Option<u32> {
+    doubling_fn.map(|doubling_fn| doubling_fn(val))
+}
+
+fn rust_returns_optional_extern_c_fn(some: bool) -> Option<extern "C" fn(u32) -> u32> {
+    if some {
+        Some(add_one)
+    } else {
+        None
+    }
+}
+
+extern "C" fn add_one(val: u32) -> u32 {
+    val + 1
+}
 
 pub struct CallbackTestOpaqueRustType {
     val: u32,
@@ -136,4 +165,20 @@ fn test_callbacks_rust_calls_swift() {
     ffi::swift_func_takes_callback_with_result_arg(Box::new(|result| {
         assert_eq!(result.unwrap().val(), 555)
     }));
+
+    // Swift calls the callback with 1 and then with 2.
+    let sum_of_doubles = ffi::swift_takes_fn_callback_primitive(Box::new(|num| num * 2));
+    assert_eq!(sum_of_doubles, 6);
+
+    let call_count = Rc::new(Cell::new(0));
+    let call_count_clone = call_count.clone();
+    ffi::swift_takes_fnmut_callback_no_args_no_return(Box::new(move || {
+        call_count_clone.set(call_count_clone.get() + 1);
+    }));
+    assert_eq!(call_count.get(), 3);
+    // The callback was dropped once Swift was done with it.
+    assert_eq!(Rc::strong_count(&call_count), 1);
+
+    // Swift calls the function pointer with 10.
+    assert_eq!(ffi::swift_takes_extern_c_fn(add_one), 11);
 }
@@ -0,0 +1,42 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type BoxedCounter;
+
+        fn new_boxed_counter(start: u32) -> Box<BoxedCounter>;
+        fn rust_increment_boxed_counter(counter: Box<BoxedCounter>) -> Box<BoxedCounter>;
+        fn rust_reflect_option_boxed_counter(
+            arg: Option<Box<BoxedCounter>>,
+        ) -> Option<Box<BoxedCounter>>;
+
+        fn value(&self) -> u32;
+        fn into_value(self: Box<Self>) -> u32;
+    }
+}
+
+pub struct BoxedCounter {
+    value: u32,
+}
+
+fn new_boxed_counter(start: u32) -> Box<BoxedCounter> {
+    Box::new(BoxedCounter { value: start })
+}
+
+fn rust_increment_boxed_counter(mut counter: Box<BoxedCounter>) -> Box<BoxedCounter> {
+    counter.value += 1;
+    counter
+}
+
+fn rust_reflect_option_boxed_counter(arg: Option<Box<BoxedCounter>>) -> Option<Box<BoxedCounter>> {
+    arg
+}
+
+impl BoxedCounter {
+    fn value(&self) -> u32 {
+        self.value
+    }
+
+    fn into_value(self: Box<Self>) -> u32 {
+        self.value
+    }
+}
@@ -0,0 +1,20 @@
+#[swift_bridge::bridge]
+mod ffi {
+    trait Greeter {
+        fn greet(&self, name: String) -> String;
+
+        #[swift_bridge(swift_name = "greetCount")]
+        fn greet_count(&self) -> u32;
+    }
+
+    extern "Rust" {
+        fn rust_greet_twice(greeter: Box<dyn Greeter>, name: String) -> String;
+    }
+}
+
+fn rust_greet_twice(greeter: Box<dyn ffi::Greeter>, name: String) -> String {
+    let first = greeter.greet(name.clone());
+    let second = greeter.greet(name);
+
+    format!("{} {} ({})", first, second, greeter.greet_count())
+}
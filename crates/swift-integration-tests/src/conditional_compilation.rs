@@ -21,3 +21,27 @@ mod not_enabled {
 fn conditionally_exposed_fn() -> u8 {
     123
 }
+
+#[swift_bridge::bridge]
+mod conditionally_compiled_items {
+    extern "Rust" {
+        // This function will be exposed since this "this_is_enabled" feature is on by default.
+        #[cfg(feature = "this_is_enabled")]
+        fn conditionally_exposed_item_fn() -> u8;
+
+        // These items aren't actually defined, but it doesn't matter since they won't be
+        // compiled.
+        #[cfg(feature = "this_is_not_enabled")]
+        fn undefined_item_fn();
+
+        #[cfg(feature = "this_is_not_enabled")]
+        type UndefinedItemType;
+        #[cfg(feature = "this_is_not_enabled")]
+        fn undefined_item_method(&self);
+    }
+}
+
+#[cfg(feature = "this_is_enabled")]
+fn conditionally_exposed_item_fn() -> u8 {
+    124
+}
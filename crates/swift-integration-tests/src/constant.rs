@@ -0,0 +1,10 @@
+#[swift_bridge::bridge]
+mod ffi {
+    pub const CONSTANT_U32: u32 = 1024;
+    pub const CONSTANT_NEGATIVE_F64: f64 = -2.5;
+    pub const CONSTANT_BOOL: bool = true;
+    pub static STATIC_STR: &str = "hello \"world\"";
+
+    pub const COMPUTED_CONSTANT_U32: u32 = CONSTANT_U32 * 2;
+    pub static COMPUTED_STATIC_STR: &str = concat!("hello", " ", "world");
+}
@@ -0,0 +1,28 @@
+use std::borrow::Cow;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_cow_str_greeting(name: &str) -> Cow<'static, str>;
+        fn rust_cow_str_is_owned(arg: Cow<'static, str>) -> bool;
+        fn rust_reflect_option_cow_str(arg: Option<Cow<'static, str>>)
+            -> Option<Cow<'static, str>>;
+    }
+}
+
+/// Returns a borrowed string when there is no name, and an owned string otherwise.
+fn rust_cow_str_greeting(name: &str) -> Cow<'static, str> {
+    if name.is_empty() {
+        Cow::Borrowed("Hello")
+    } else {
+        Cow::Owned(format!("Hello, {name}"))
+    }
+}
+
+fn rust_cow_str_is_owned(arg: Cow<'static, str>) -> bool {
+    matches!(arg, Cow::Owned(_))
+}
+
+fn rust_reflect_option_cow_str(arg: Option<Cow<'static, str>>) -> Option<Cow<'static, str>> {
+    arg
+}
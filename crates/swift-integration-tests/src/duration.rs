@@ -0,0 +1,32 @@
+use std::time::Duration;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_double_duration(arg: Duration) -> Duration;
+        fn rust_reflect_option_duration(arg: Option<Duration>) -> Option<Duration>;
+    }
+
+    extern "Rust" {
+        fn test_rust_calls_swift_duration();
+    }
+
+    extern "Swift" {
+        fn swift_double_duration(arg: Duration) -> Duration;
+    }
+}
+
+fn rust_double_duration(arg: Duration) -> Duration {
+    arg * 2
+}
+
+fn rust_reflect_option_duration(arg: Option<Duration>) -> Option<Duration> {
+    arg
+}
+
+fn test_rust_calls_swift_duration() {
+    assert_eq!(
+        ffi::swift_double_duration(Duration::from_millis(1500)),
+        Duration::from_secs(3)
+    );
+}
@@ -23,8 +23,63 @@ mod ffi {
 
         fn get_stack_mut(&mut self) -> &mut ARustStack;
     }
+
+    extern "Rust" {
+        type DropCounter;
+
+        #[swift_bridge(init)]
+        fn new() -> DropCounter;
+
+        fn make_counted(&self) -> DropCounted;
+        fn drop_count(&self) -> u32;
+    }
+
+    extern "Rust" {
+        type DropCounted;
+
+        fn consume_drop_counted(val: DropCounted);
+    }
 }
 
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+
+/// Counts how many of the `DropCounted` values that it created have been dropped, so that Swift
+/// can verify that the generated `deinit` frees owned Rust values exactly once.
+pub struct DropCounter {
+    drops: Arc<AtomicU32>,
+}
+
+pub struct DropCounted {
+    drops: Arc<AtomicU32>,
+}
+
+impl DropCounter {
+    fn new() -> Self {
+        DropCounter {
+            drops: Arc::new(AtomicU32::new(0)),
+        }
+    }
+
+    fn make_counted(&self) -> DropCounted {
+        DropCounted {
+            drops: self.drops.clone(),
+        }
+    }
+
+    fn drop_count(&self) -> u32 {
+        self.drops.load(Ordering::SeqCst)
+    }
+}
+
+impl Drop for DropCounted {
+    fn drop(&mut self) {
+        self.drops.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+fn consume_drop_counted(_val: DropCounted) {}
+
 pub struct StackWrapper(ARustStack);
 
 impl StackWrapper {
@@ -1,8 +1,15 @@
 mod args_into;
+mod deprecated;
+mod discardable_result;
 mod get;
 mod get_with;
 mod identifiable;
+mod init;
+mod property;
+mod publisher;
 mod return_into;
 mod return_with;
 mod rust_name;
+mod subscript;
 mod swift_name;
+mod swift_namespace;
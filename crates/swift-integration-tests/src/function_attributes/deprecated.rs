@@ -0,0 +1,18 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[deprecated(note = "Use `add_numbers` instead")]
+        fn deprecated_add(a: u8, b: u8) -> u8;
+
+        fn add_numbers(a: u8, b: u8) -> u8;
+    }
+}
+
+#[deprecated(note = "Use `add_numbers` instead")]
+fn deprecated_add(a: u8, b: u8) -> u8 {
+    add_numbers(a, b)
+}
+
+fn add_numbers(a: u8, b: u8) -> u8 {
+    a + b
+}
@@ -0,0 +1,30 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type DiscardableResultTestSet;
+
+        #[swift_bridge(init)]
+        fn new() -> DiscardableResultTestSet;
+
+        #[swift_bridge(discardable_result)]
+        fn insert(&mut self, value: u32) -> bool;
+
+        fn len(&self) -> usize;
+    }
+}
+
+pub struct DiscardableResultTestSet(std::collections::HashSet<u32>);
+
+impl DiscardableResultTestSet {
+    fn new() -> Self {
+        DiscardableResultTestSet(std::collections::HashSet::new())
+    }
+
+    fn insert(&mut self, value: u32) -> bool {
+        self.0.insert(value)
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
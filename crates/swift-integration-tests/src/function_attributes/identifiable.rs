@@ -61,9 +61,31 @@ mod ffi {
         fn id(&self) -> &'static str;
     }
 
+    extern "Rust" {
+        // Here we make sure that the `Identifiable` type attribute uses the named getter.
+        #[swift_bridge(Identifiable = "user_id")]
+        type IdentifiableTypeAttribute;
+
+        #[swift_bridge(init)]
+        fn new() -> IdentifiableTypeAttribute;
+        fn user_id(&self) -> u64;
+    }
+
     // TODO: Add more Identifiable test types..
 }
 
+pub struct IdentifiableTypeAttribute;
+
+impl IdentifiableTypeAttribute {
+    fn new() -> Self {
+        Self
+    }
+
+    fn user_id(&self) -> u64 {
+        123
+    }
+}
+
 pub struct IdentifiableFnNotNamedId;
 
 impl IdentifiableFnNotNamedId {
@@ -0,0 +1,27 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type FailableInitType;
+
+        #[swift_bridge(init)]
+        fn new(success: bool) -> Option<FailableInitType>;
+
+        fn count(&self) -> i32;
+    }
+}
+
+pub struct FailableInitType;
+
+impl FailableInitType {
+    fn new(success: bool) -> Option<FailableInitType> {
+        if success {
+            Some(FailableInitType)
+        } else {
+            None
+        }
+    }
+
+    fn count(&self) -> i32 {
+        132
+    }
+}
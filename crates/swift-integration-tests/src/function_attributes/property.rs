@@ -0,0 +1,41 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type PropertyTestBook;
+
+        #[swift_bridge(init)]
+        fn new(title: &str) -> PropertyTestBook;
+
+        #[swift_bridge(property = "title")]
+        fn title(&self) -> &str;
+        #[swift_bridge(property = "title")]
+        fn set_title(&mut self, title: &str);
+
+        #[swift_bridge(property = "pages")]
+        fn pages(&self) -> u32;
+    }
+}
+
+pub struct PropertyTestBook {
+    title: String,
+}
+
+impl PropertyTestBook {
+    fn new(title: &str) -> Self {
+        PropertyTestBook {
+            title: title.to_string(),
+        }
+    }
+
+    fn title(&self) -> &str {
+        &self.title
+    }
+
+    fn set_title(&mut self, title: &str) {
+        self.title = title.to_string();
+    }
+
+    fn pages(&self) -> u32 {
+        self.title.len() as u32 * 100
+    }
+}
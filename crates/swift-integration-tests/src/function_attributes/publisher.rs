@@ -0,0 +1,74 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type PublisherTestSubscription;
+    }
+
+    extern "Rust" {
+        type PublisherTestEventSource;
+
+        #[swift_bridge(init)]
+        fn new() -> PublisherTestEventSource;
+
+        #[swift_bridge(publisher = "events")]
+        fn subscribe(&self, callback: Box<dyn Fn(u32)>) -> PublisherTestSubscription;
+
+        fn emit(&self, event: u32);
+
+        fn subscriber_count(&self) -> usize;
+    }
+}
+
+type Subscribers = Rc<RefCell<HashMap<u64, Box<dyn Fn(u32)>>>>;
+
+pub struct PublisherTestEventSource {
+    subscribers: Subscribers,
+    next_id: RefCell<u64>,
+}
+
+/// Unregisters its callback from the event source when dropped.
+pub struct PublisherTestSubscription {
+    subscribers: Subscribers,
+    id: u64,
+}
+
+impl PublisherTestEventSource {
+    fn new() -> Self {
+        PublisherTestEventSource {
+            subscribers: Rc::new(RefCell::new(HashMap::new())),
+            next_id: RefCell::new(0),
+        }
+    }
+
+    fn subscribe(&self, callback: Box<dyn Fn(u32)>) -> PublisherTestSubscription {
+        let id = *self.next_id.borrow();
+        *self.next_id.borrow_mut() += 1;
+
+        self.subscribers.borrow_mut().insert(id, callback);
+
+        PublisherTestSubscription {
+            subscribers: self.subscribers.clone(),
+            id,
+        }
+    }
+
+    fn emit(&self, event: u32) {
+        for callback in self.subscribers.borrow().values() {
+            callback(event);
+        }
+    }
+
+    fn subscriber_count(&self) -> usize {
+        self.subscribers.borrow().len()
+    }
+}
+
+impl Drop for PublisherTestSubscription {
+    fn drop(&mut self) {
+        self.subscribers.borrow_mut().remove(&self.id);
+    }
+}
@@ -4,7 +4,19 @@ mod ffi {
         // If this compiles then we're successfully using the `rust_name` during code generation.
         #[swift_bridge(rust_name = "another_function")]
         fn some_function();
+
+        fn test_call_swift_fn_with_rust_name() -> u8;
+    }
+
+    extern "Swift" {
+        // Rust calls the Swift function `swiftFnWithRustName` as `swift_fn_with_rust_name`.
+        #[swift_bridge(rust_name = "swift_fn_with_rust_name")]
+        fn swiftFnWithRustName() -> u8;
     }
 }
 
 fn another_function() {}
+
+fn test_call_swift_fn_with_rust_name() -> u8 {
+    ffi::swift_fn_with_rust_name()
+}
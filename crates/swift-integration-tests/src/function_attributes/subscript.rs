@@ -0,0 +1,32 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type SubscriptTestBytes;
+
+        #[swift_bridge(init)]
+        fn new() -> SubscriptTestBytes;
+
+        #[swift_bridge(subscript)]
+        fn get(&self, index: usize) -> u8;
+
+        #[swift_bridge(subscript)]
+        fn count_of(&self, byte: &str) -> usize;
+    }
+}
+
+pub struct SubscriptTestBytes(Vec<u8>);
+
+impl SubscriptTestBytes {
+    fn new() -> Self {
+        SubscriptTestBytes(vec![10, 20, 20, 30])
+    }
+
+    fn get(&self, index: usize) -> u8 {
+        self.0[index]
+    }
+
+    fn count_of(&self, byte: &str) -> usize {
+        let byte: u8 = byte.parse().unwrap();
+        self.0.iter().filter(|b| **b == byte).count()
+    }
+}
@@ -22,3 +22,18 @@ fn test_call_rust_from_swift_by_name_attribute() -> String {
     );
     "StringFromRust".to_string()
 }
+
+#[swift_bridge::bridge(rename_functions = "camelCase")]
+mod ffi_camel_case {
+    extern "Swift" {
+        fn swift_fn_renamed_to_camel_case() -> u8;
+    }
+
+    extern "Rust" {
+        fn rust_fn_renamed_to_camel_case() -> u8;
+    }
+}
+
+fn rust_fn_renamed_to_camel_case() -> u8 {
+    ffi_camel_case::swift_fn_renamed_to_camel_case() + 1
+}
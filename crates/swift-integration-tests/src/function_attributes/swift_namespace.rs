@@ -0,0 +1,10 @@
+#[swift_bridge::bridge(swift_namespace = "NamespacedFunctions")]
+mod ffi {
+    extern "Rust" {
+        fn namespaced_sum(a: u8, b: u8) -> u8;
+    }
+}
+
+fn namespaced_sum(a: u8, b: u8) -> u8 {
+    a + b
+}
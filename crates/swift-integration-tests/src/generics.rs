@@ -29,6 +29,23 @@ mod ffi {
             arg: GenericWithOpaqueRustInnerTy<InnerTy>,
         ) -> GenericWithOpaqueRustInnerTy<InnerTy>;
     }
+
+    extern "Rust" {
+        #[swift_bridge(declare_generic)]
+        type GenericStack<A>;
+
+        type GenericStack<u32>;
+        #[swift_bridge(init)]
+        fn new() -> GenericStack<u32>;
+        fn push(self: &mut GenericStack<u32>, val: u32);
+        fn len(self: &GenericStack<u32>) -> usize;
+
+        type GenericStack<u8>;
+        #[swift_bridge(init)]
+        fn new() -> GenericStack<u8>;
+        fn push(self: &mut GenericStack<u8>, val: u8);
+        fn len(self: &GenericStack<u8>) -> usize;
+    }
 }
 
 pub struct SomeGenericType<T> {
@@ -48,6 +65,21 @@ pub struct GenericWithOpaqueRustInnerTy<T> {
 }
 pub struct InnerTy;
 
+pub struct GenericStack<T>(Vec<T>);
+impl<T> GenericStack<T> {
+    fn new() -> Self {
+        GenericStack(vec![])
+    }
+
+    fn push(&mut self, val: T) {
+        self.0.push(val)
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
 fn new_some_generic_type_u32() -> SomeGenericType<u32> {
     SomeGenericType { field: 123 }
 }
@@ -0,0 +1,29 @@
+use std::collections::HashMap;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_reflect_hash_map_u32_string(map: HashMap<u32, String>) -> HashMap<u32, String>;
+        fn rust_reflect_hash_map_string_bool(map: HashMap<String, bool>) -> HashMap<String, bool>;
+        fn rust_make_hash_map_u8_f64() -> HashMap<u8, f64>;
+        fn rust_reflect_option_hash_map_u8_u16(
+            map: Option<HashMap<u8, u16>>,
+        ) -> Option<HashMap<u8, u16>>;
+    }
+}
+
+fn rust_reflect_hash_map_u32_string(map: HashMap<u32, String>) -> HashMap<u32, String> {
+    map
+}
+
+fn rust_reflect_hash_map_string_bool(map: HashMap<String, bool>) -> HashMap<String, bool> {
+    map
+}
+
+fn rust_make_hash_map_u8_f64() -> HashMap<u8, f64> {
+    HashMap::from([(1, 1.5), (2, 2.5)])
+}
+
+fn rust_reflect_option_hash_map_u8_u16(map: Option<HashMap<u8, u16>>) -> Option<HashMap<u8, u16>> {
+    map
+}
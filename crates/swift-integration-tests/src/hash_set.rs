@@ -0,0 +1,22 @@
+use std::collections::HashSet;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_reflect_hash_set_string(set: HashSet<String>) -> HashSet<String>;
+        fn rust_make_hash_set_i32() -> HashSet<i32>;
+        fn rust_reflect_option_hash_set_u8(set: Option<HashSet<u8>>) -> Option<HashSet<u8>>;
+    }
+}
+
+fn rust_reflect_hash_set_string(set: HashSet<String>) -> HashSet<String> {
+    set
+}
+
+fn rust_make_hash_set_i32() -> HashSet<i32> {
+    HashSet::from([-1, 0, 1])
+}
+
+fn rust_reflect_option_hash_set_u8(set: Option<HashSet<u8>>) -> Option<HashSet<u8>> {
+    set
+}
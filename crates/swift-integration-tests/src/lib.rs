@@ -1,13 +1,26 @@
 mod expose_opaque_rust_type;
 mod import_opaque_swift_class;
 
+mod arc;
+mod array;
 mod async_function;
 mod boxed_functions;
+mod boxed_opaque_rust_type;
+mod bridged_trait;
 mod conditional_compilation;
+mod constant;
+mod cow_str;
+mod duration;
 mod generics;
+mod hash_map;
+mod hash_set;
 mod option;
+mod panic;
+mod path;
+mod pin;
 mod pointer;
 mod primitive;
+mod range;
 mod result;
 mod rust_function_uses_opaque_swift_type;
 mod shared_types;
@@ -16,7 +29,9 @@ mod slice;
 mod string;
 mod swift_function_uses_opaque_rust_type;
 mod swift_function_uses_opaque_swift_type;
+mod system_time;
 mod tuple;
+mod uuid;
 mod vec;
 
 mod enum_attributes;
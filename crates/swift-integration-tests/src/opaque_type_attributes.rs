@@ -1,4 +1,15 @@
+mod actor;
 mod already_declared;
+mod comparable;
 mod copy;
+mod custom_debug_string_convertible;
+mod custom_string_convertible;
 mod equatable;
+mod fields;
 mod hashable;
+mod iterator;
+mod observable_object;
+mod operators;
+mod sendable;
+mod stream;
+mod swift_access;
@@ -0,0 +1,34 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(Actor)]
+        type RustActorCounter;
+
+        #[swift_bridge(init)]
+        fn new() -> RustActorCounter;
+
+        fn increment(&mut self);
+        fn count(&self) -> u32;
+        fn description(&self) -> String;
+    }
+}
+
+pub struct RustActorCounter(u32);
+
+impl RustActorCounter {
+    fn new() -> Self {
+        RustActorCounter(0)
+    }
+
+    fn increment(&mut self) {
+        self.0 += 1;
+    }
+
+    fn count(&self) -> u32 {
+        self.0
+    }
+
+    fn description(&self) -> String {
+        format!("Counted to {}", self.0)
+    }
+}
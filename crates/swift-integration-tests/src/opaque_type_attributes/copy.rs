@@ -7,12 +7,23 @@ mod ffi {
         #[swift_bridge(init)]
         fn new() -> RustCopyType;
 
+        // Returns `None` when `first` is 0.
+        #[swift_bridge(init)]
+        fn new_with_first(first: u16) -> Option<RustCopyType>;
+
         fn eq(&self, rhs: &RustCopyType) -> bool;
 
+        fn first(&self) -> u16;
+
         // Used to verify that even after we pass by value the type still works on the
         // Swift side since it implements Copy.
         fn consume(self);
     }
+
+    extern "Rust" {
+        fn make_vec_of_rust_copy_type(count: u16) -> Vec<RustCopyType>;
+        fn sum_vec_of_rust_copy_type(vec: Vec<RustCopyType>) -> u16;
+    }
 }
 
 #[derive(Copy, Clone, PartialEq)]
@@ -21,5 +32,25 @@ impl RustCopyType {
     fn new() -> Self {
         Self([11, 22, 33])
     }
+    fn new_with_first(first: u16) -> Option<Self> {
+        if first == 0 {
+            None
+        } else {
+            Some(Self([first, 22, 33]))
+        }
+    }
+    fn first(&self) -> u16 {
+        self.0[0]
+    }
     fn consume(self) {}
 }
+
+fn make_vec_of_rust_copy_type(count: u16) -> Vec<RustCopyType> {
+    (1..=count)
+        .map(|first| RustCopyType::new_with_first(first).unwrap())
+        .collect()
+}
+
+fn sum_vec_of_rust_copy_type(vec: Vec<RustCopyType>) -> u16 {
+    vec.iter().map(|val| val.first()).sum()
+}
@@ -0,0 +1,40 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(CustomDebugStringConvertible)]
+        type RustDebugType;
+
+        #[swift_bridge(init)]
+        fn new(name: &str) -> RustDebugType;
+    }
+
+    extern "Rust" {
+        #[swift_bridge(Copy(4), CustomDebugStringConvertible)]
+        type RustCopyDebugType;
+
+        #[swift_bridge(init)]
+        fn new(value: u32) -> RustCopyDebugType;
+    }
+}
+
+#[derive(Debug)]
+pub struct RustDebugType {
+    name: String,
+}
+
+impl RustDebugType {
+    fn new(name: &str) -> Self {
+        RustDebugType {
+            name: name.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct RustCopyDebugType(u32);
+
+impl RustCopyDebugType {
+    fn new(value: u32) -> Self {
+        RustCopyDebugType(value)
+    }
+}
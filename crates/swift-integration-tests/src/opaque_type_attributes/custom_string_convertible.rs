@@ -0,0 +1,49 @@
+use std::fmt::{Display, Formatter};
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(CustomStringConvertible)]
+        type RustDisplayType;
+
+        #[swift_bridge(init)]
+        fn new(name: &str) -> RustDisplayType;
+    }
+
+    extern "Rust" {
+        #[swift_bridge(Copy(4), CustomStringConvertible)]
+        type RustCopyDisplayType;
+
+        #[swift_bridge(init)]
+        fn new(value: u32) -> RustCopyDisplayType;
+    }
+}
+
+pub struct RustDisplayType(String);
+
+impl RustDisplayType {
+    fn new(name: &str) -> Self {
+        RustDisplayType(name.to_string())
+    }
+}
+
+impl Display for RustDisplayType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Hello, {}!", self.0)
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct RustCopyDisplayType(u32);
+
+impl RustCopyDisplayType {
+    fn new(value: u32) -> Self {
+        RustCopyDisplayType(value)
+    }
+}
+
+impl Display for RustCopyDisplayType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{}", self.0)
+    }
+}
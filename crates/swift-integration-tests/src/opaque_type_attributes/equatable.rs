@@ -23,3 +23,23 @@ impl RustEquatableType {
         self.0 = value;
     }
 }
+
+#[swift_bridge::bridge]
+mod ffi_copy {
+    extern "Rust" {
+        #[swift_bridge(Copy(4), Equatable)]
+        type RustCopyEquatableType;
+
+        #[swift_bridge(init)]
+        fn new(value: i32) -> RustCopyEquatableType;
+    }
+}
+
+#[derive(Copy, Clone, PartialEq)]
+pub struct RustCopyEquatableType(i32);
+
+impl RustCopyEquatableType {
+    fn new(value: i32) -> Self {
+        RustCopyEquatableType(value)
+    }
+}
@@ -0,0 +1,32 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(get(&label: &str), set(width: f64, height: f64))]
+        type RustRectangle;
+
+        #[swift_bridge(init)]
+        fn new(width: f64, height: f64) -> RustRectangle;
+
+        fn area(&self) -> f64;
+    }
+}
+
+pub struct RustRectangle {
+    label: String,
+    width: f64,
+    height: f64,
+}
+
+impl RustRectangle {
+    fn new(width: f64, height: f64) -> Self {
+        RustRectangle {
+            label: "rectangle".to_string(),
+            width,
+            height,
+        }
+    }
+
+    fn area(&self) -> f64 {
+        self.width * self.height
+    }
+}
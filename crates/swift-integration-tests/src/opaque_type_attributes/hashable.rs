@@ -17,3 +17,23 @@ impl RustHashableType {
         RustHashableType(num)
     }
 }
+
+#[swift_bridge::bridge]
+mod ffi_copy {
+    extern "Rust" {
+        #[swift_bridge(Copy(8), Hashable, Equatable)]
+        type RustCopyHashableType;
+
+        #[swift_bridge(init)]
+        fn new(num: i64) -> RustCopyHashableType;
+    }
+}
+
+#[derive(Copy, Clone, Hash, PartialEq)]
+pub struct RustCopyHashableType(i64);
+
+impl RustCopyHashableType {
+    fn new(num: i64) -> Self {
+        RustCopyHashableType(num)
+    }
+}
@@ -0,0 +1,33 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(Iterator)]
+        type RustCountdown;
+
+        #[swift_bridge(init)]
+        fn new(from: u32) -> RustCountdown;
+
+        fn next(&mut self) -> Option<u32>;
+    }
+}
+
+pub struct RustCountdown(u32);
+
+impl RustCountdown {
+    fn new(from: u32) -> Self {
+        RustCountdown(from)
+    }
+}
+
+impl Iterator for RustCountdown {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        if self.0 == 0 {
+            return None;
+        }
+
+        self.0 -= 1;
+        Some(self.0 + 1)
+    }
+}
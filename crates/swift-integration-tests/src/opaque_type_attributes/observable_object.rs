@@ -0,0 +1,46 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(ObservableObject = "on_change")]
+        type RustObservableCounter;
+
+        #[swift_bridge(init)]
+        fn new() -> RustObservableCounter;
+
+        fn on_change(&mut self, callback: Box<dyn Fn()>);
+
+        fn increment(&mut self);
+
+        fn count(&self) -> u32;
+    }
+}
+
+pub struct RustObservableCounter {
+    count: u32,
+    on_change: Option<Box<dyn Fn()>>,
+}
+
+impl RustObservableCounter {
+    fn new() -> Self {
+        RustObservableCounter {
+            count: 0,
+            on_change: None,
+        }
+    }
+
+    fn on_change(&mut self, callback: Box<dyn Fn()>) {
+        self.on_change = Some(callback);
+    }
+
+    fn increment(&mut self) {
+        self.count += 1;
+
+        if let Some(on_change) = self.on_change.as_ref() {
+            on_change();
+        }
+    }
+
+    fn count(&self) -> u32 {
+        self.count
+    }
+}
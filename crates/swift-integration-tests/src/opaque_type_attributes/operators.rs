@@ -0,0 +1,97 @@
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(Add, Sub, Neg)]
+        type RustOperatorsType;
+
+        #[swift_bridge(init)]
+        fn new(value: i32) -> RustOperatorsType;
+
+        fn value(&self) -> i32;
+    }
+
+    extern "Rust" {
+        #[swift_bridge(Copy(4), Mul, Div, Rem)]
+        type RustCopyOperatorsType;
+
+        #[swift_bridge(init)]
+        fn new(value: i32) -> RustCopyOperatorsType;
+
+        fn value(&self) -> i32;
+    }
+}
+
+pub struct RustOperatorsType(i32);
+
+impl RustOperatorsType {
+    fn new(value: i32) -> Self {
+        RustOperatorsType(value)
+    }
+
+    fn value(&self) -> i32 {
+        self.0
+    }
+}
+
+impl Add for &RustOperatorsType {
+    type Output = RustOperatorsType;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        RustOperatorsType(self.0 + rhs.0)
+    }
+}
+
+impl Sub for &RustOperatorsType {
+    type Output = RustOperatorsType;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        RustOperatorsType(self.0 - rhs.0)
+    }
+}
+
+impl Neg for &RustOperatorsType {
+    type Output = RustOperatorsType;
+
+    fn neg(self) -> Self::Output {
+        RustOperatorsType(-self.0)
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct RustCopyOperatorsType(i32);
+
+impl RustCopyOperatorsType {
+    fn new(value: i32) -> Self {
+        RustCopyOperatorsType(value)
+    }
+
+    fn value(&self) -> i32 {
+        self.0
+    }
+}
+
+impl Mul for RustCopyOperatorsType {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        RustCopyOperatorsType(self.0 * rhs.0)
+    }
+}
+
+impl Div for RustCopyOperatorsType {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        RustCopyOperatorsType(self.0 / rhs.0)
+    }
+}
+
+impl Rem for RustCopyOperatorsType {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        RustCopyOperatorsType(self.0 % rhs.0)
+    }
+}
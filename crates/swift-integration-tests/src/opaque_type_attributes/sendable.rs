@@ -0,0 +1,31 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(Sendable)]
+        type RustSendableType;
+
+        #[swift_bridge(init)]
+        fn new() -> RustSendableType;
+
+        fn increment(&self);
+        fn count(&self) -> u32;
+    }
+}
+
+pub struct RustSendableType(AtomicU32);
+
+impl RustSendableType {
+    fn new() -> Self {
+        RustSendableType(AtomicU32::new(0))
+    }
+
+    fn increment(&self) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+
+    fn count(&self) -> u32 {
+        self.0.load(Ordering::SeqCst)
+    }
+}
@@ -0,0 +1,35 @@
+use futures_core::Stream;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(Stream(Item = u32))]
+        type RustCountdownStream;
+
+        #[swift_bridge(init)]
+        fn new(from: u32) -> RustCountdownStream;
+    }
+}
+
+pub struct RustCountdownStream(u32);
+
+impl RustCountdownStream {
+    fn new(from: u32) -> Self {
+        RustCountdownStream(from)
+    }
+}
+
+impl Stream for RustCountdownStream {
+    type Item = u32;
+
+    fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<u32>> {
+        if self.0 == 0 {
+            return Poll::Ready(None);
+        }
+
+        self.0 -= 1;
+        Poll::Ready(Some(self.0 + 1))
+    }
+}
@@ -0,0 +1,53 @@
+#[swift_bridge::bridge(swift_access = "internal")]
+mod ffi {
+    extern "Rust" {
+        type RustInternalCounter;
+
+        #[swift_bridge(init)]
+        fn new() -> RustInternalCounter;
+
+        fn increment(&mut self) -> u32;
+
+        fn rust_internal_counter_start() -> u32;
+    }
+
+    extern "Rust" {
+        #[swift_bridge(swift_access = "public")]
+        type RustPublicCounterSnapshot;
+
+        // Takes an internal type, so it can't be public.
+        #[swift_bridge(associated_to = RustPublicCounterSnapshot, swift_access = "internal")]
+        fn snapshot(counter: &RustInternalCounter) -> RustPublicCounterSnapshot;
+
+        fn count(&self) -> u32;
+    }
+}
+
+pub struct RustInternalCounter(u32);
+
+impl RustInternalCounter {
+    fn new() -> Self {
+        RustInternalCounter(rust_internal_counter_start())
+    }
+
+    fn increment(&mut self) -> u32 {
+        self.0 += 1;
+        self.0
+    }
+}
+
+fn rust_internal_counter_start() -> u32 {
+    10
+}
+
+pub struct RustPublicCounterSnapshot(u32);
+
+impl RustPublicCounterSnapshot {
+    fn snapshot(counter: &RustInternalCounter) -> Self {
+        RustPublicCounterSnapshot(counter.0)
+    }
+
+    fn count(&self) -> u32 {
+        self.0
+    }
+}
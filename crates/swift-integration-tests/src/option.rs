@@ -94,6 +94,10 @@ mod ffi {
             arg: Option<&OptTestOpaqueRustType>,
         ) -> Option<&OptTestOpaqueRustType>;
 
+        fn rust_reflect_option_mut_ref_opaque_rust_type(
+            arg: Option<&mut OptTestOpaqueRustType>,
+        ) -> Option<&mut OptTestOpaqueRustType>;
+
         fn rust_reflect_option_opaque_rust_copy_type(
             arg: Option<OptTestOpaqueRustCopyType>,
         ) -> Option<OptTestOpaqueRustCopyType>;
@@ -119,6 +123,13 @@ mod ffi {
         ) -> Option<OptionStruct>;
 
         fn test_rust_calls_swift_option_primitive();
+        fn test_rust_calls_swift_option_string_and_str();
+        fn test_rust_calls_swift_option_opaque_swift_type();
+        fn test_rust_calls_swift_option_vector();
+
+        fn rust_reflect_option_opaque_swift_type(
+            arg: Option<OptTestOpaqueSwiftType>,
+        ) -> Option<OptTestOpaqueSwiftType>;
     }
 
     extern "Swift" {
@@ -135,6 +146,23 @@ mod ffi {
         fn swift_reflect_option_f32(arg: Option<f32>) -> Option<f32>;
         fn swift_reflect_option_f64(arg: Option<f64>) -> Option<f64>;
         fn swift_reflect_option_bool(arg: Option<bool>) -> Option<bool>;
+
+        fn swift_reflect_option_string(arg: Option<String>) -> Option<String>;
+        fn swift_option_str_is_some(arg: Option<&str>) -> bool;
+
+        fn swift_reflect_option_vector_rust_type(arg: Option<Vec<u16>>) -> Option<Vec<u16>>;
+    }
+
+    extern "Swift" {
+        type OptTestOpaqueSwiftType;
+
+        #[swift_bridge(init)]
+        fn new(field: u8) -> OptTestOpaqueSwiftType;
+        fn field(&self) -> u8;
+
+        fn swift_reflect_option_opaque_swift_type(
+            arg: Option<OptTestOpaqueSwiftType>,
+        ) -> Option<OptTestOpaqueSwiftType>;
     }
 }
 
@@ -174,6 +202,39 @@ fn test_rust_calls_swift_option_primitive() {
     assert_eq!(ffi::swift_reflect_option_bool(None), None);
 }
 
+fn test_rust_calls_swift_option_string_and_str() {
+    assert_eq!(
+        ffi::swift_reflect_option_string(Some("hello world".to_string())),
+        Some("hello world".to_string())
+    );
+    assert_eq!(ffi::swift_reflect_option_string(None), None);
+
+    assert!(ffi::swift_option_str_is_some(Some("hello")));
+    assert!(!ffi::swift_option_str_is_some(None));
+}
+
+fn test_rust_calls_swift_option_vector() {
+    assert_eq!(
+        ffi::swift_reflect_option_vector_rust_type(Some(vec![1, 2, 3])),
+        Some(vec![1, 2, 3])
+    );
+    assert_eq!(ffi::swift_reflect_option_vector_rust_type(None), None);
+}
+
+fn test_rust_calls_swift_option_opaque_swift_type() {
+    let val =
+        ffi::swift_reflect_option_opaque_swift_type(Some(ffi::OptTestOpaqueSwiftType::new(123)));
+    assert_eq!(val.unwrap().field(), 123);
+
+    assert!(ffi::swift_reflect_option_opaque_swift_type(None).is_none());
+}
+
+fn rust_reflect_option_opaque_swift_type(
+    arg: Option<ffi::OptTestOpaqueSwiftType>,
+) -> Option<ffi::OptTestOpaqueSwiftType> {
+    arg
+}
+
 pub struct OptTestOpaqueRustType {
     field: u8,
 }
@@ -274,6 +335,12 @@ fn rust_reflect_option_ref_opaque_rust_type(
     arg
 }
 
+fn rust_reflect_option_mut_ref_opaque_rust_type(
+    arg: Option<&mut OptTestOpaqueRustType>,
+) -> Option<&mut OptTestOpaqueRustType> {
+    arg
+}
+
 fn rust_reflect_option_opaque_rust_copy_type(
     arg: Option<OptTestOpaqueRustCopyType>,
 ) -> Option<OptTestOpaqueRustCopyType> {
@@ -0,0 +1,14 @@
+#[swift_bridge::bridge(panic = "throw")]
+mod ffi {
+    extern "Rust" {
+        fn rust_func_panics_or_returns_result(should_panic: bool) -> Result<(), String>;
+    }
+}
+
+fn rust_func_panics_or_returns_result(should_panic: bool) -> Result<(), String> {
+    if should_panic {
+        panic!("Panicked on purpose");
+    }
+
+    Ok(())
+}
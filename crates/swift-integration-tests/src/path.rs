@@ -0,0 +1,48 @@
+use std::path::{Path, PathBuf};
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_join_path(base: &Path, file_name: String) -> PathBuf;
+        fn rust_reflect_option_path(arg: Option<PathBuf>) -> Option<PathBuf>;
+    }
+
+    extern "Rust" {
+        fn test_rust_calls_swift_path();
+    }
+
+    extern "Swift" {
+        fn swift_path_parent(arg: &Path) -> PathBuf;
+    }
+}
+
+#[swift_bridge::bridge(swift_path_type = "URL")]
+mod ffi_url {
+    extern "Rust" {
+        fn rust_path_file_name(arg: PathBuf) -> String;
+        fn rust_reflect_path_url(arg: PathBuf) -> PathBuf;
+    }
+}
+
+fn rust_join_path(base: &Path, file_name: String) -> PathBuf {
+    base.join(file_name)
+}
+
+fn rust_reflect_option_path(arg: Option<PathBuf>) -> Option<PathBuf> {
+    arg
+}
+
+fn test_rust_calls_swift_path() {
+    assert_eq!(
+        ffi::swift_path_parent(Path::new("/tmp/some/file.txt")),
+        PathBuf::from("/tmp/some")
+    );
+}
+
+fn rust_path_file_name(arg: PathBuf) -> String {
+    arg.file_name().unwrap().to_string_lossy().into_owned()
+}
+
+fn rust_reflect_path_url(arg: PathBuf) -> PathBuf {
+    arg
+}
@@ -0,0 +1,48 @@
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type PinnedCounter;
+
+        #[swift_bridge(init)]
+        fn new() -> PinnedCounter;
+
+        fn increment(self: Pin<&mut Self>);
+        fn value(&self) -> u32;
+        fn points_to_itself(&self) -> bool;
+    }
+}
+
+/// A self-referential type that must not be moved once `increment` has been called on it.
+pub struct PinnedCounter {
+    value: u32,
+    value_ptr: *const u32,
+    _pinned: PhantomPinned,
+}
+
+impl PinnedCounter {
+    fn new() -> Self {
+        PinnedCounter {
+            value: 0,
+            value_ptr: std::ptr::null(),
+            _pinned: PhantomPinned,
+        }
+    }
+
+    fn increment(self: Pin<&mut Self>) {
+        // We never move out of the pinned reference.
+        let this = unsafe { self.get_unchecked_mut() };
+        this.value_ptr = &this.value;
+        this.value += 1;
+    }
+
+    fn value(&self) -> u32 {
+        self.value
+    }
+
+    fn points_to_itself(&self) -> bool {
+        std::ptr::eq(self.value_ptr, &self.value)
+    }
+}
@@ -10,6 +10,11 @@ mod ffi {
         fn rust_echo_const_u8(ptr: *const u8) -> *const u8;
         fn rust_echo_mut_u8(ptr: *mut u8) -> *mut u8;
 
+        fn rust_echo_std_ffi_c_void(ptr: *mut std::ffi::c_void) -> *mut std::ffi::c_void;
+
+        fn rust_echo_option_const_c_void(ptr: Option<*const c_void>) -> Option<*const c_void>;
+        fn rust_echo_option_mut_u8(ptr: Option<*mut u8>) -> Option<*mut u8>;
+
         fn rust_run_opaque_pointer_tests();
         fn rust_run_u8_pointer_tests();
     }
@@ -71,3 +76,15 @@ fn rust_echo_const_u8(ptr: *const u8) -> *const u8 {
 fn rust_echo_mut_u8(ptr: *mut u8) -> *mut u8 {
     ptr
 }
+
+fn rust_echo_std_ffi_c_void(ptr: *mut std::ffi::c_void) -> *mut std::ffi::c_void {
+    ptr
+}
+
+fn rust_echo_option_const_c_void(ptr: Option<*const c_void>) -> Option<*const c_void> {
+    ptr
+}
+
+fn rust_echo_option_mut_u8(ptr: Option<*mut u8>) -> Option<*mut u8> {
+    ptr
+}
@@ -1,3 +1,5 @@
+use std::num::NonZeroU32;
+
 #[swift_bridge::bridge]
 mod ffi {
     extern "Rust" {
@@ -11,9 +13,26 @@ mod ffi {
         fn rust_double_i32(arg: i32) -> i32;
         fn rust_double_u64(arg: u64) -> u64;
         fn rust_double_i64(arg: i64) -> i64;
+        fn rust_double_usize(arg: usize) -> usize;
+        fn rust_double_isize(arg: isize) -> isize;
+        fn rust_double_usize(arg: usize) -> usize {
+            arg * 2
+        }
+
+        fn rust_double_isize(arg: isize) -> isize {
+            arg * 2
+        }
+
         fn rust_double_f32(arg: f32) -> f32;
         fn rust_double_f64(arg: f64) -> f64;
         fn rust_negate_bool(arg: bool) -> bool;
+        fn rust_double_u128(arg: u128) -> u128;
+        fn rust_double_i128(arg: i128) -> i128;
+        fn rust_reflect_option_u128(arg: Option<u128>) -> Option<u128>;
+        fn rust_double_non_zero_u32(arg: NonZeroU32) -> NonZeroU32;
+        fn rust_reflect_option_non_zero_u32(arg: Option<NonZeroU32>) -> Option<NonZeroU32>;
+        fn rust_uppercase_char(arg: char) -> char;
+        fn rust_reflect_option_char(arg: Option<char>) -> Option<char>;
     }
 
     extern "Swift" {
@@ -25,9 +44,15 @@ mod ffi {
         fn swift_double_i32(arg: i32) -> i32;
         fn swift_double_u64(arg: u64) -> u64;
         fn swift_double_i64(arg: i64) -> i64;
+        fn swift_double_usize(arg: usize) -> usize;
+        fn swift_double_isize(arg: isize) -> isize;
         fn swift_double_f32(arg: f32) -> f32;
         fn swift_double_f64(arg: f64) -> f64;
         fn swift_negate_bool(arg: bool) -> bool;
+        fn swift_reflect_u128(arg: u128) -> u128;
+        fn swift_reflect_i128(arg: i128) -> i128;
+        fn swift_double_non_zero_u32(arg: NonZeroU32) -> NonZeroU32;
+        fn swift_uppercase_char(arg: char) -> char;
     }
 }
 
@@ -40,10 +65,21 @@ fn test_rust_calls_swift_primitives() {
     assert_eq!(ffi::swift_double_i32(5), 10);
     assert_eq!(ffi::swift_double_u64(5), 10);
     assert_eq!(ffi::swift_double_i64(5), 10);
+    assert_eq!(ffi::swift_double_usize(5), 10);
+    assert_eq!(ffi::swift_double_isize(-5), -10);
     assert_eq!(ffi::swift_double_f32(5.), 10.);
     assert_eq!(ffi::swift_double_f64(5.), 10.);
     assert_eq!(ffi::swift_negate_bool(true), false);
     assert_eq!(ffi::swift_negate_bool(false), true);
+    assert_eq!(ffi::swift_reflect_u128(u128::MAX), u128::MAX);
+    assert_eq!(ffi::swift_reflect_i128(i128::MIN), i128::MIN);
+    assert_eq!(ffi::swift_reflect_i128(-5), -5);
+    assert_eq!(
+        ffi::swift_double_non_zero_u32(NonZeroU32::new(5).unwrap()).get(),
+        10
+    );
+    assert_eq!(ffi::swift_uppercase_char('a'), 'A');
+    assert_eq!(ffi::swift_uppercase_char('é'), 'É');
 }
 
 fn rust_double_u8(arg: u8) -> u8 {
@@ -78,6 +114,14 @@ fn rust_double_i64(arg: i64) -> i64 {
     arg * 2
 }
 
+fn rust_double_usize(arg: usize) -> usize {
+    arg * 2
+}
+
+fn rust_double_isize(arg: isize) -> isize {
+    arg * 2
+}
+
 fn rust_double_f32(arg: f32) -> f32 {
     arg * 2.
 }
@@ -89,3 +133,31 @@ fn rust_double_f64(arg: f64) -> f64 {
 fn rust_negate_bool(arg: bool) -> bool {
     !arg
 }
+
+fn rust_double_u128(arg: u128) -> u128 {
+    arg * 2
+}
+
+fn rust_double_i128(arg: i128) -> i128 {
+    arg * 2
+}
+
+fn rust_reflect_option_u128(arg: Option<u128>) -> Option<u128> {
+    arg
+}
+
+fn rust_double_non_zero_u32(arg: NonZeroU32) -> NonZeroU32 {
+    arg.checked_mul(NonZeroU32::new(2).unwrap()).unwrap()
+}
+
+fn rust_reflect_option_non_zero_u32(arg: Option<NonZeroU32>) -> Option<NonZeroU32> {
+    arg
+}
+
+fn rust_uppercase_char(arg: char) -> char {
+    arg.to_uppercase().next().unwrap()
+}
+
+fn rust_reflect_option_char(arg: Option<char>) -> Option<char> {
+    arg
+}
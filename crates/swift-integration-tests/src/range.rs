@@ -0,0 +1,33 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_reflect_range_usize(arg: Range<usize>) -> Range<usize>;
+        fn rust_range_i64_len(arg: std::ops::Range<i64>) -> usize;
+        fn rust_empty_reversed_range() -> Range<u32>;
+
+        fn rust_run_swift_range_tests();
+    }
+
+    extern "Swift" {
+        fn swift_shift_range(arg: Range<i32>, by: i32) -> Range<i32>;
+    }
+}
+
+fn rust_reflect_range_usize(arg: std::ops::Range<usize>) -> std::ops::Range<usize> {
+    arg
+}
+
+fn rust_range_i64_len(arg: std::ops::Range<i64>) -> usize {
+    arg.count()
+}
+
+/// A range whose start is greater than its end, which Swift sees as an empty range.
+#[allow(clippy::reversed_empty_ranges)]
+fn rust_empty_reversed_range() -> std::ops::Range<u32> {
+    10..5
+}
+
+/// Verify that Rust can pass a range to Swift and get one back.
+fn rust_run_swift_range_tests() {
+    assert_eq!(ffi::swift_shift_range(-2..3, 10), 8..13);
+}
@@ -10,6 +10,7 @@ mod ffi {
         ) -> Result<ResultTestOpaqueRustType, ResultTestOpaqueRustType>;
 
         fn rust_func_takes_result_string(arg: Result<String, String>);
+        fn rust_func_return_result_string(succeed: bool) -> Result<String, String>;
         fn rust_func_takes_result_opaque_swift(
             arg: Result<ResultTestOpaqueSwiftType, ResultTestOpaqueSwiftType>,
         );
@@ -17,6 +18,8 @@ mod ffi {
         fn rust_func_return_result_null_opaque_rust(
             succeed: bool,
         ) -> Result<(), ResultTestOpaqueRustType>;
+        fn rust_func_takes_result_null_string(arg: Result<(), String>) -> bool;
+        fn rust_func_return_result_null_string(succeed: bool) -> Result<(), String>;
 
         fn rust_func_return_result_unit_struct_opaque_rust(
             succeed: bool,
@@ -91,6 +94,14 @@ fn rust_func_takes_result_string(arg: Result<String, String>) {
     }
 }
 
+fn rust_func_return_result_string(succeed: bool) -> Result<String, String> {
+    if succeed {
+        Ok("Success Message".to_string())
+    } else {
+        Err("Error Message".to_string())
+    }
+}
+
 fn rust_func_reflect_result_opaque_rust(
     arg: Result<ResultTestOpaqueRustType, ResultTestOpaqueRustType>,
 ) -> Result<ResultTestOpaqueRustType, ResultTestOpaqueRustType> {
@@ -127,6 +138,24 @@ fn rust_func_return_result_null_opaque_rust(succeed: bool) -> Result<(), ResultT
     }
 }
 
+fn rust_func_takes_result_null_string(arg: Result<(), String>) -> bool {
+    match arg {
+        Ok(()) => true,
+        Err(err) => {
+            assert_eq!(err, "Error Message");
+            false
+        }
+    }
+}
+
+fn rust_func_return_result_null_string(succeed: bool) -> Result<(), String> {
+    if succeed {
+        Ok(())
+    } else {
+        Err("Error Message".to_string())
+    }
+}
+
 fn rust_func_return_result_unit_struct_opaque_rust(
     succeed: bool,
 ) -> Result<ffi::UnitStruct, ResultTestOpaqueRustType> {
@@ -0,0 +1,33 @@
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct")]
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct CodableStruct {
+        id: u32,
+        score: f64,
+        active: bool,
+        nickname: Option<i16>,
+        status: CodableEnum,
+    }
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    enum CodableEnum {
+        Online,
+        Offline,
+    }
+
+    extern "Rust" {
+        fn rust_serialize_codable_struct(value: CodableStruct) -> String;
+        fn rust_deserialize_codable_struct(json: &str) -> CodableStruct;
+    }
+}
+
+use ffi::CodableStruct;
+
+fn rust_serialize_codable_struct(value: CodableStruct) -> String {
+    serde_json::to_string(&value).unwrap()
+}
+
+fn rust_deserialize_codable_struct(json: &str) -> CodableStruct {
+    serde_json::from_str(json).unwrap()
+}
@@ -9,6 +9,16 @@ mod ffi {
         fn reflect_enum_with_no_data(arg: EnumWithNoData) -> EnumWithNoData;
     }
 
+    enum EnumWithDiscriminants {
+        Variant1 = 10,
+        Variant2,
+        Variant3 = -5,
+    }
+
+    extern "Rust" {
+        fn enum_with_discriminants_as_isize(arg: EnumWithDiscriminants) -> isize;
+    }
+
     extern "Rust" {
         #[swift_bridge(Equatable)]
         type OpaqueRustForEnumTest;
@@ -46,6 +56,18 @@ mod ffi {
         fn reflect_enum_with_opaque_type(arg: EnumWithOpaqueRust) -> EnumWithOpaqueRust;
     }
 
+    enum EnumWithOptionalData {
+        Named {
+            label: Option<String>,
+            radius: Option<f64>,
+        },
+        Unnamed(Option<String>),
+    }
+
+    extern "Rust" {
+        fn reflect_enum_with_optional_data(arg: EnumWithOptionalData) -> EnumWithOptionalData;
+    }
+
     extern "Rust" {
         #[swift_bridge(declare_generic)]
         type GenericOpaqueRustForEnumTest<T>;
@@ -72,6 +94,10 @@ fn reflect_enum_with_no_data(arg: ffi::EnumWithNoData) -> ffi::EnumWithNoData {
     arg
 }
 
+fn enum_with_discriminants_as_isize(arg: ffi::EnumWithDiscriminants) -> isize {
+    arg as isize
+}
+
 fn reflect_enum_with_unnamed_data(arg: ffi::EnumWithUnnamedData) -> ffi::EnumWithUnnamedData {
     arg
 }
@@ -84,6 +110,10 @@ fn reflect_enum_with_opaque_type(arg: ffi::EnumWithOpaqueRust) -> ffi::EnumWithO
     arg
 }
 
+fn reflect_enum_with_optional_data(arg: ffi::EnumWithOptionalData) -> ffi::EnumWithOptionalData {
+    arg
+}
+
 #[derive(PartialEq)]
 pub struct OpaqueRustForEnumTest;
 
@@ -22,6 +22,20 @@ mod ffi {
         field: String,
     }
 
+    // Declared before the struct that it uses as a field, in order to verify that the
+    //  generated C header declares `StructReprStructPoint` first.
+    #[swift_bridge(swift_repr = "struct")]
+    struct StructReprStructLine {
+        start: StructReprStructPoint,
+        end: StructReprStructPoint,
+    }
+
+    #[swift_bridge(swift_repr = "struct")]
+    struct StructReprStructPoint {
+        x: f64,
+        y: f64,
+    }
+
     extern "Rust" {
         fn test_rust_calls_swift();
 
@@ -34,6 +48,13 @@ mod ffi {
         fn swift_calls_rust_tuple_struct(
             arg: StructReprStructTupleStruct,
         ) -> StructReprStructTupleStruct;
+
+        fn swift_calls_rust_struct_with_struct_fields(
+            arg: StructReprStructLine,
+        ) -> StructReprStructLine;
+
+        #[swift_bridge(associated_to = StructReprStructPoint)]
+        fn origin() -> StructReprStructPoint;
     }
 
     extern "Swift" {
@@ -70,6 +91,18 @@ fn swift_calls_rust_tuple_struct(
     arg
 }
 
+fn swift_calls_rust_struct_with_struct_fields(
+    arg: ffi::StructReprStructLine,
+) -> ffi::StructReprStructLine {
+    arg
+}
+
+impl ffi::StructReprStructPoint {
+    fn origin() -> Self {
+        ffi::StructReprStructPoint { x: 0., y: 0. }
+    }
+}
+
 #[deny(unused)]
 mod tests {
     use super::ffi;
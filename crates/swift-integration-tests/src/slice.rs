@@ -1,3 +1,26 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_reflect_slice_u8(slice: &[u8]) -> &[u8];
+        fn rust_sum_slice_f64(slice: &[f64]) -> f64;
+        fn rust_double_mut_slice_f32(slice: &mut [f32]);
+    }
+}
+
+fn rust_reflect_slice_u8(slice: &[u8]) -> &[u8] {
+    slice
+}
+
+fn rust_sum_slice_f64(slice: &[f64]) -> f64 {
+    slice.iter().sum()
+}
+
+fn rust_double_mut_slice_f32(slice: &mut [f32]) {
+    for val in slice.iter_mut() {
+        *val *= 2.;
+    }
+}
+
 // TODO:
 // - Define struct SliceTestOpaqueRustType
 // - Add a method to create Vec<SliceTestOpaqueRustType>
@@ -5,6 +28,6 @@
 //   - Add test to verify that we can iterate over the slice
 //   - Add test to verify that that we can index into the slice
 //   - Add test to verify that we can get the length of the slice
-// - Create SliceTests.swift
+// - In SliceTests.swift
 //   - Add Swift test verifying that we can use an Array<SliceTestOpaqueRustType> as a slice
 //   - Add Swift test verifying that we can use a RustVec<SliceTestOpaqueRustType> as a slice
@@ -3,6 +3,9 @@ mod ffi {
     extern "Rust" {
         fn test_rust_calls_swift_fn_reflects_owned_opaque_swift_type();
         fn test_rust_calls_swift_method_reflects_owned_opaque_swift_type();
+        fn test_rust_calls_swift_fn_with_ref_opaque_swift_type();
+
+        fn rust_fn_reads_ref_opaque_swift_type(arg: &ASwiftType) -> u32;
     }
 
     extern "Swift" {
@@ -15,6 +18,7 @@ mod ffi {
         fn call_swift_fn_reflects_owned_opaque_swift_type(arg: ASwiftType) -> ASwiftType;
         fn call_swift_method_reflects_owned_opaque_swift_type(&self, arg: ASwiftType)
             -> ASwiftType;
+        fn call_swift_fn_reads_ref_opaque_swift_type(arg: &ASwiftType) -> u32;
     }
 }
 
@@ -35,3 +39,23 @@ fn test_rust_calls_swift_method_reflects_owned_opaque_swift_type() {
         swift_ty.call_swift_method_reflects_owned_opaque_swift_type(swift_ty2);
     assert_eq!(reflected_via_method.amount(), 333);
 }
+
+fn test_rust_calls_swift_fn_with_ref_opaque_swift_type() {
+    let swift_ty = ffi::ASwiftType::new(123);
+
+    assert_eq!(
+        ffi::call_swift_fn_reads_ref_opaque_swift_type(&swift_ty),
+        123
+    );
+    assert_eq!(
+        ffi::call_swift_fn_reads_ref_opaque_swift_type(&swift_ty),
+        123
+    );
+
+    // The borrow did not release the Swift object.
+    assert_eq!(swift_ty.amount(), 123);
+}
+
+fn rust_fn_reads_ref_opaque_swift_type(arg: &ffi::ASwiftType) -> u32 {
+    arg.amount()
+}
@@ -0,0 +1,41 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_add_one_day_to_system_time(arg: SystemTime) -> SystemTime;
+        fn rust_reflect_option_system_time(arg: Option<SystemTime>) -> Option<SystemTime>;
+    }
+
+    extern "Rust" {
+        fn test_rust_calls_swift_system_time();
+    }
+
+    extern "Swift" {
+        fn swift_add_one_day_to_system_time(arg: SystemTime) -> SystemTime;
+    }
+}
+
+const ONE_DAY: Duration = Duration::from_secs(60 * 60 * 24);
+
+fn rust_add_one_day_to_system_time(arg: SystemTime) -> SystemTime {
+    arg + ONE_DAY
+}
+
+fn rust_reflect_option_system_time(arg: Option<SystemTime>) -> Option<SystemTime> {
+    arg
+}
+
+fn test_rust_calls_swift_system_time() {
+    let after_epoch = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    assert_eq!(
+        ffi::swift_add_one_day_to_system_time(after_epoch),
+        after_epoch + ONE_DAY
+    );
+
+    let before_epoch = UNIX_EPOCH - ONE_DAY * 2;
+    assert_eq!(
+        ffi::swift_add_one_day_to_system_time(before_epoch),
+        UNIX_EPOCH - ONE_DAY
+    );
+}
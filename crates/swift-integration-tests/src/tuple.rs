@@ -14,6 +14,8 @@ mod ffi {
         #[swift_bridge(init)]
         fn new(val: i32) -> TupleTestOpaqueRustType;
         fn val(&self) -> i32;
+        fn bounds(&self) -> (f64, f64, f64, f64);
+        fn rust_default_bounds() -> (f64, f64, f64, f64);
         fn rust_reflect_tuple_primitives(tuple: (i16, u32)) -> (i16, u32);
         fn rust_reflect_tuple_opaque_rust_and_string_and_primitive(
             tuple: (TupleTestOpaqueRustType, String, u8),
@@ -45,6 +47,14 @@ impl TupleTestOpaqueRustType {
     fn val(&self) -> i32 {
         self.0
     }
+    fn bounds(&self) -> (f64, f64, f64, f64) {
+        let val = self.0 as f64;
+        (-val, -val, val, val)
+    }
+}
+
+fn rust_default_bounds() -> (f64, f64, f64, f64) {
+    (0., 0., 1., 1.)
 }
 
 fn rust_reflect_tuple_primitives(tuple: (i16, u32)) -> (i16, u32) {
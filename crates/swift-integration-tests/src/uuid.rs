@@ -0,0 +1,38 @@
+use uuid::Uuid;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_reflect_uuid(arg: Uuid) -> Uuid;
+        fn rust_reflect_option_uuid(arg: Option<Uuid>) -> Option<Uuid>;
+        fn rust_uuid_string(arg: Uuid) -> String;
+    }
+
+    extern "Rust" {
+        fn test_rust_calls_swift_uuid();
+    }
+
+    extern "Swift" {
+        fn swift_uuid_from_string(arg: String) -> Uuid;
+    }
+}
+
+fn rust_reflect_uuid(arg: Uuid) -> Uuid {
+    arg
+}
+
+fn rust_reflect_option_uuid(arg: Option<Uuid>) -> Option<Uuid> {
+    arg
+}
+
+fn rust_uuid_string(arg: Uuid) -> String {
+    arg.hyphenated().to_string()
+}
+
+fn test_rust_calls_swift_uuid() {
+    let uuid = "4a3c7c8e-5b7d-4f3e-9f7a-0c2d1e6b8a90";
+    assert_eq!(
+        ffi::swift_uuid_from_string(uuid.to_string()),
+        Uuid::parse_str(uuid).unwrap()
+    );
+}
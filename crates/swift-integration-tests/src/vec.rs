@@ -26,6 +26,10 @@ mod ffi {
         ) -> Vec<TransparentEnumInsideVecT>;
     }
 
+    extern "Rust" {
+        fn rust_reflect_vec_of_vec_u8(arg: Vec<Vec<u8>>) -> Vec<Vec<u8>>;
+    }
+
     extern "Rust" {
         fn run_vec_tests();
     }
@@ -33,6 +37,9 @@ mod ffi {
     extern "Swift" {
         fn swift_return_vec_u8() -> Vec<u8>;
         fn swift_arg_vec_u8(vec: Vec<u8>);
+        fn swift_reflect_vec_opaque_rust_type(
+            arg: Vec<ARustTypeInsideVecT>,
+        ) -> Vec<ARustTypeInsideVecT>;
     }
 }
 
@@ -47,6 +54,15 @@ fn run_vec_tests() {
 
     let vec: Vec<u8> = vec![1, 2, 3, 4, 5];
     ffi::swift_arg_vec_u8(vec);
+
+    let vec = vec![
+        ARustTypeInsideVecT::new("hello"),
+        ARustTypeInsideVecT::new("world"),
+    ];
+    let vec = ffi::swift_reflect_vec_opaque_rust_type(vec);
+    assert_eq!(vec.len(), 2);
+    assert_eq!(vec[0].text(), "hello");
+    assert_eq!(vec[1].text(), "world");
 }
 
 pub struct ARustTypeInsideVecT {
@@ -74,3 +90,7 @@ fn rust_reflect_vec_transparent_enum(
 ) -> Vec<ffi::TransparentEnumInsideVecT> {
     arg
 }
+
+fn rust_reflect_vec_of_vec_u8(arg: Vec<Vec<u8>>) -> Vec<Vec<u8>> {
+    arg
+}
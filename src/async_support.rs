@@ -1,7 +1,9 @@
+use futures_core::Stream;
 use once_cell::sync::Lazy;
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::mpsc::{Receiver, SyncSender};
+use std::task::{Context, Poll};
 
 #[doc(hidden)]
 pub static ASYNC_RUNTIME: Lazy<TokioRuntime> = Lazy::new(|| {
@@ -50,3 +52,24 @@ impl TokioRuntime {
         });
     }
 }
+
+/// Get a stream's next item.
+///
+/// Used by the `next` method that gets generated for a `#[swift_bridge(Stream(Item = T))]` type.
+#[doc(hidden)]
+pub fn next_stream_item<S: Stream + Unpin>(stream: &mut S) -> NextStreamItem<'_, S> {
+    NextStreamItem { stream }
+}
+
+#[doc(hidden)]
+pub struct NextStreamItem<'a, S> {
+    stream: &'a mut S,
+}
+
+impl<S: Stream + Unpin> Future for NextStreamItem<'_, S> {
+    type Output = Option<S::Item>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut *self.stream).poll_next(cx)
+    }
+}
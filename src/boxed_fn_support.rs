@@ -1,13 +1,19 @@
 #![allow(non_snake_case)]
 
-#[export_name = "__swift_bridge__$call_boxed_fn_once_no_args_no_return"]
+#[cfg_attr(
+    feature = "runtime-exports",
+    export_name = "__swift_bridge__$call_boxed_fn_once_no_args_no_return"
+)]
 pub extern "C" fn __swift_bridge__call_boxed_fn_once_no_args_no_return(
     boxed_fn: *mut Box<dyn FnOnce() -> ()>,
 ) {
     unsafe { Box::from_raw(boxed_fn)() };
 }
 
-#[export_name = "__swift_bridge__$free_boxed_fn_once_no_args_no_return"]
+#[cfg_attr(
+    feature = "runtime-exports",
+    export_name = "__swift_bridge__$free_boxed_fn_once_no_args_no_return"
+)]
 pub extern "C" fn __swift_bridge__free_boxed_fn_once_no_args_no_return(
     boxed_fn: *mut Box<dyn FnOnce() -> ()>,
 ) {
@@ -15,3 +21,33 @@ pub extern "C" fn __swift_bridge__free_boxed_fn_once_no_args_no_return(
         let _ = Box::from_raw(boxed_fn);
     }
 }
+
+/// A Swift closure that was passed to a Rust function.
+///
+/// Swift retains the closure before handing it to Rust. We release it when this gets dropped,
+/// which happens when the Rust `Box<dyn Fn..>` that calls the closure gets dropped.
+pub struct SwiftCallback {
+    ptr: *mut std::ffi::c_void,
+    release: unsafe extern "C" fn(*mut std::ffi::c_void),
+}
+
+impl SwiftCallback {
+    /// Takes ownership of a retained Swift closure.
+    pub fn new(
+        ptr: *mut std::ffi::c_void,
+        release: unsafe extern "C" fn(*mut std::ffi::c_void),
+    ) -> Self {
+        SwiftCallback { ptr, release }
+    }
+
+    /// A pointer to the retained Swift closure.
+    pub fn as_ptr(&self) -> *mut std::ffi::c_void {
+        self.ptr
+    }
+}
+
+impl Drop for SwiftCallback {
+    fn drop(&mut self) {
+        unsafe { (self.release)(self.ptr) }
+    }
+}
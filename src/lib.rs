@@ -6,7 +6,7 @@ pub use swift_bridge_macro::bridge;
 
 mod std_bridge;
 
-pub use self::std_bridge::{option, result, string};
+pub use self::std_bridge::{int128, option, result, string};
 
 #[doc(hidden)]
 #[cfg(feature = "async")]
@@ -18,6 +18,16 @@ pub mod boxed_fn_support;
 #[doc(hidden)]
 pub mod copy_support;
 
+#[doc(hidden)]
+pub mod panic_support;
+
+#[doc(hidden)]
+pub mod sendable_support;
+
+#[doc(hidden)]
+#[cfg(feature = "uuid")]
+pub mod uuid_support;
+
 #[doc(hidden)]
 #[repr(C)]
 pub struct FfiSlice<T> {
@@ -43,10 +53,40 @@ impl<T> FfiSlice<T> {
         }
     }
 
+    /// Create an FfiSlice from a mutable slice.
+    pub fn from_mut_slice(slice: &mut [T]) -> Self {
+        FfiSlice {
+            start: slice.as_mut_ptr(),
+            len: slice.len(),
+        }
+    }
+
     /// Get a reference to the slice that this FfiSlice points to.
     pub fn as_slice(&self) -> &'static [T] {
+        // An empty Swift buffer pointer can have a nil base address, but `from_raw_parts`
+        // requires a non-null pointer.
+        if self.start.is_null() {
+            return &[];
+        }
+
         unsafe { std::slice::from_raw_parts(self.start, self.len) }
     }
+
+    /// Get a mutable reference to the slice that this FfiSlice points to.
+    ///
+    /// # Safety
+    ///
+    /// The FfiSlice must have been created from a mutable buffer that outlives the returned slice,
+    /// and nothing else may read or write the buffer while the returned slice is in use. The
+    /// functions that we generate only hold onto the slice for the duration of the call that Swift
+    /// passed the buffer to.
+    pub unsafe fn as_mut_slice<'a>(&self) -> &'a mut [T] {
+        if self.start.is_null() {
+            return &mut [];
+        }
+
+        std::slice::from_raw_parts_mut(self.start as *mut T, self.len)
+    }
 }
 
 // The code generation automatically implements this for all shared structs.
@@ -97,7 +137,27 @@ pub trait SharedEnum {
     type FfiRepr;
 }
 
-#[no_mangle]
+/// Panics with the method's name if Swift called a method on an opaque Rust type through a null
+/// or misaligned pointer, instead of letting the method dereference it.
+///
+/// The generated methods only call this in debug builds.
+#[doc(hidden)]
+pub fn assert_valid_receiver<T>(this: *const T, method: &str) {
+    assert!(
+        !this.is_null(),
+        "`{}` was called with a null pointer for `self`",
+        method
+    );
+    assert!(
+        this.is_aligned(),
+        "`{}` was called with the misaligned pointer {:p} for `self`",
+        method,
+        this
+    );
+}
+
+#[cfg_attr(feature = "runtime-exports", no_mangle)]
+#[allow(non_snake_case)]
 #[doc(hidden)]
 pub extern "C" fn __swift_bridge__null_pointer() -> *const std::ffi::c_void {
     std::ptr::null()
@@ -0,0 +1,58 @@
+//! Catches the panics of the functions in `#[swift_bridge::bridge(panic = "...")]` modules, so that
+//! they don't unwind into Swift.
+
+use crate::string::RustString;
+use std::any::Any;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+/// Runs the body of a generated function, and calls Swift's `fatalError` with the panic's
+/// message if it panics.
+#[inline]
+pub fn call_or_fatal_error<T>(body: impl FnOnce() -> T) -> T {
+    extern "C" {
+        #[link_name = "__swift_bridge__$rust_panic_fatal_error"]
+        fn swift_fatal_error(message: *mut std::ffi::c_void) -> !;
+    }
+
+    match catch_unwind(AssertUnwindSafe(body)) {
+        Ok(val) => val,
+        Err(payload) => unsafe { swift_fatal_error(message_into_raw(payload)) },
+    }
+}
+
+/// Runs the body of a generated function that returns a `Result`. If it panics, the panic's
+/// message is handed to Swift, which throws it as a `RustPanic` once the call returns.
+///
+/// # Safety
+///
+/// A `T` whose bytes are all zero must be valid, since that's what we return after a panic. The
+/// FFI representations of `Result`s are C structs of integers and pointers, so this holds for the
+/// functions that we generate. Swift throws before it looks at the value.
+#[inline]
+pub unsafe fn call_or_throw<T>(body: impl FnOnce() -> T) -> T {
+    extern "C" {
+        #[link_name = "__swift_bridge__$rust_panic_store"]
+        fn swift_store_panic(message: *mut std::ffi::c_void);
+    }
+
+    match catch_unwind(AssertUnwindSafe(body)) {
+        Ok(val) => val,
+        Err(payload) => {
+            swift_store_panic(message_into_raw(payload));
+            std::mem::zeroed()
+        }
+    }
+}
+
+/// The panic's message as a `RustString` that Swift takes ownership of.
+fn message_into_raw(payload: Box<dyn Any + Send>) -> *mut std::ffi::c_void {
+    let message = if let Some(message) = payload.downcast_ref::<&'static str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Rust panicked".to_string()
+    };
+
+    RustString(message).box_into_raw() as *mut std::ffi::c_void
+}
@@ -0,0 +1,49 @@
+/// The FFI representation of a `u128`.
+///
+/// There is no 128-bit integer in C, so the value gets split into two 64-bit limbs.
+#[repr(C)]
+#[doc(hidden)]
+pub struct U128 {
+    pub low: u64,
+    pub high: u64,
+}
+
+/// The FFI representation of an `i128`.
+///
+/// The sign lives in the high limb.
+#[repr(C)]
+#[doc(hidden)]
+pub struct I128 {
+    pub low: u64,
+    pub high: i64,
+}
+
+impl From<u128> for U128 {
+    fn from(val: u128) -> Self {
+        U128 {
+            low: val as u64,
+            high: (val >> 64) as u64,
+        }
+    }
+}
+
+impl From<U128> for u128 {
+    fn from(val: U128) -> Self {
+        (u128::from(val.high) << 64) | u128::from(val.low)
+    }
+}
+
+impl From<i128> for I128 {
+    fn from(val: i128) -> Self {
+        I128 {
+            low: val as u64,
+            high: (val >> 64) as i64,
+        }
+    }
+}
+
+impl From<I128> for i128 {
+    fn from(val: I128) -> Self {
+        (i128::from(val.high) << 64) | i128::from(val.low)
+    }
+}
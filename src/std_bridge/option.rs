@@ -1,3 +1,5 @@
+use super::int128::{I128, U128};
+
 #[repr(C)]
 #[doc(hidden)]
 pub struct OptionU8 {
@@ -54,6 +56,20 @@ pub struct OptionI64 {
     pub is_some: bool,
 }
 
+#[repr(C)]
+#[doc(hidden)]
+pub struct OptionU128 {
+    pub val: U128,
+    pub is_some: bool,
+}
+
+#[repr(C)]
+#[doc(hidden)]
+pub struct OptionI128 {
+    pub val: I128,
+    pub is_some: bool,
+}
+
 #[repr(C)]
 #[doc(hidden)]
 pub struct OptionUsize {
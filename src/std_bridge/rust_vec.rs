@@ -1,4 +1,4 @@
-use macro_::vec_externs;
+use macro_::{vec_externs, vec_of_vec_externs};
 
 vec_externs!(u8, OptionU8, 123);
 vec_externs!(u16, OptionU16, 123);
@@ -17,6 +17,23 @@ vec_externs!(f64, OptionF64, 0.123);
 
 vec_externs!(bool, OptionBool, false);
 
+vec_of_vec_externs!(u8);
+vec_of_vec_externs!(u16);
+vec_of_vec_externs!(u32);
+vec_of_vec_externs!(u64);
+vec_of_vec_externs!(usize);
+
+vec_of_vec_externs!(i8);
+vec_of_vec_externs!(i16);
+vec_of_vec_externs!(i32);
+vec_of_vec_externs!(i64);
+vec_of_vec_externs!(isize);
+
+vec_of_vec_externs!(f32);
+vec_of_vec_externs!(f64);
+
+vec_of_vec_externs!(bool);
+
 mod macro_ {
     macro_rules! vec_externs {
         ($ty:ty, $option_ty:ident, $unused_none:expr) => {
@@ -117,4 +134,83 @@ mod macro_ {
     }
 
     pub(super) use vec_externs;
+
+    /// Externs for a `Vec<Vec<T>>`, where `T` is a primitive such as `u8`.
+    ///
+    /// The inner `Vec<T>`s are passed over FFI as pointers, the same way that a `Vec<T>` argument
+    /// or return value is.
+    macro_rules! vec_of_vec_externs {
+        ($ty:ty) => {
+            const _: () = {
+                #[export_name = concat!("__swift_bridge__$Vec_Vec_", stringify!($ty), "$new")]
+                #[doc(hidden)]
+                pub extern "C" fn _new() -> *mut Vec<Vec<$ty>> {
+                    Box::into_raw(Box::new(Vec::new()))
+                }
+
+                #[export_name = concat!("__swift_bridge__$Vec_Vec_", stringify!($ty), "$_free")]
+                #[doc(hidden)]
+                pub extern "C" fn _drop(vec: *mut Vec<Vec<$ty>>) {
+                    let vec = unsafe { Box::from_raw(vec) };
+                    drop(vec)
+                }
+
+                #[export_name = concat!("__swift_bridge__$Vec_Vec_", stringify!($ty), "$len")]
+                #[doc(hidden)]
+                pub extern "C" fn _len(vec: *mut Vec<Vec<$ty>>) -> usize {
+                    let vec = unsafe { &*vec };
+                    vec.len()
+                }
+
+                #[export_name = concat!("__swift_bridge__$Vec_Vec_", stringify!($ty), "$push")]
+                #[doc(hidden)]
+                pub extern "C" fn _push(vec: *mut Vec<Vec<$ty>>, val: *mut Vec<$ty>) {
+                    let vec = unsafe { &mut *vec };
+                    vec.push(unsafe { *Box::from_raw(val) });
+                }
+
+                #[export_name = concat!("__swift_bridge__$Vec_Vec_", stringify!($ty), "$pop")]
+                #[doc(hidden)]
+                pub extern "C" fn _pop(vec: *mut Vec<Vec<$ty>>) -> *mut Vec<$ty> {
+                    let vec = unsafe { &mut *vec };
+                    if let Some(val) = vec.pop() {
+                        Box::into_raw(Box::new(val))
+                    } else {
+                        std::ptr::null_mut()
+                    }
+                }
+
+                #[export_name = concat!("__swift_bridge__$Vec_Vec_", stringify!($ty), "$get")]
+                #[doc(hidden)]
+                pub extern "C" fn _get(vec: *mut Vec<Vec<$ty>>, index: usize) -> *const Vec<$ty> {
+                    let vec = unsafe { &*vec };
+                    if let Some(val) = vec.get(index) {
+                        val as *const Vec<$ty>
+                    } else {
+                        std::ptr::null()
+                    }
+                }
+
+                #[export_name = concat!("__swift_bridge__$Vec_Vec_", stringify!($ty), "$get_mut")]
+                #[doc(hidden)]
+                pub extern "C" fn _get_mut(vec: *mut Vec<Vec<$ty>>, index: usize) -> *mut Vec<$ty> {
+                    let vec = unsafe { &mut *vec };
+                    if let Some(val) = vec.get_mut(index) {
+                        val as *mut Vec<$ty>
+                    } else {
+                        std::ptr::null_mut()
+                    }
+                }
+
+                #[export_name = concat!("__swift_bridge__$Vec_Vec_", stringify!($ty), "$as_ptr")]
+                #[doc(hidden)]
+                pub extern "C" fn _as_ptr(vec: *mut Vec<Vec<$ty>>) -> *const Vec<$ty> {
+                    let vec = unsafe { &*vec };
+                    vec.as_ptr()
+                }
+            };
+        };
+    }
+
+    pub(super) use vec_of_vec_externs;
 }
@@ -1,8 +1,11 @@
 //! The corresponding C and Swift code can be found in
 //! crates/swift-bridge-build/src/generate_core/rust_string.{c.h,swift}
+#[cfg(feature = "runtime-exports")]
 pub use self::ffi::*;
+use std::borrow::Cow;
 
 #[swift_bridge_macro::bridge(swift_bridge_path = crate)]
+#[cfg(feature = "runtime-exports")]
 mod ffi {
     extern "Rust" {
         type RustString;
@@ -31,6 +34,21 @@ pub struct RustStr {
     pub len: usize,
 }
 
+/// A `Cow<'static, str>` that is being passed over FFI.
+///
+/// `owned` is null when the string is borrowed, since a `&'static str` never needs to be freed.
+/// Otherwise it points to the `RustString` that owns the bytes that `str` points to, and whoever
+/// receives the `FfiCowStr` is responsible for freeing it.
+///
+/// An `Option<Cow<'static, str>>` uses the same representation, with a null `str.start` for `None`.
+#[doc(hidden)]
+#[repr(C)]
+pub struct FfiCowStr {
+    pub str: RustStr,
+    pub owned: *mut RustString,
+}
+
+#[cfg(feature = "runtime-exports")]
 impl RustString {
     fn new() -> Self {
         RustString("".to_string())
@@ -83,6 +101,53 @@ impl RustStr {
     }
 }
 
+impl FfiCowStr {
+    pub fn from_cow(cow: Cow<'static, str>) -> Self {
+        match cow {
+            Cow::Borrowed(str) => FfiCowStr {
+                str: RustStr::from_str(str),
+                owned: std::ptr::null_mut(),
+            },
+            Cow::Owned(string) => {
+                let owned = RustString(string).box_into_raw();
+                FfiCowStr {
+                    str: RustStr::from_str(unsafe { &(*owned).0 }),
+                    owned,
+                }
+            }
+        }
+    }
+
+    pub fn from_option_cow(cow: Option<Cow<'static, str>>) -> Self {
+        match cow {
+            Some(cow) => Self::from_cow(cow),
+            None => FfiCowStr {
+                str: RustStr {
+                    start: std::ptr::null(),
+                    len: 0,
+                },
+                owned: std::ptr::null_mut(),
+            },
+        }
+    }
+
+    pub fn into_cow(self) -> Cow<'static, str> {
+        if self.owned.is_null() {
+            Cow::Borrowed(self.str.to_str())
+        } else {
+            Cow::Owned(unsafe { Box::from_raw(self.owned) }.0)
+        }
+    }
+
+    pub fn into_option_cow(self) -> Option<Cow<'static, str>> {
+        if self.str.start.is_null() {
+            None
+        } else {
+            Some(self.into_cow())
+        }
+    }
+}
+
 impl PartialEq for RustStr {
     fn eq(&self, other: &Self) -> bool {
         unsafe {
@@ -92,7 +157,10 @@ impl PartialEq for RustStr {
     }
 }
 
-#[export_name = "__swift_bridge__$RustStr$partial_eq"]
+#[cfg_attr(
+    feature = "runtime-exports",
+    export_name = "__swift_bridge__$RustStr$partial_eq"
+)]
 #[allow(non_snake_case)]
 pub extern "C" fn __swift_bridge__RustStr_partial_eq(lhs: RustStr, rhs: RustStr) -> bool {
     lhs == rhs
@@ -0,0 +1,30 @@
+//! The corresponding C and Swift code can be found in
+//! crates/swift-bridge-build/src/generate_core/uuid.swift
+
+/// The FFI representation of a `uuid::Uuid`.
+///
+/// Swift sees the 16 bytes as a `uuid_t`, which is what Foundation's `UUID` is made from.
+#[repr(C)]
+pub struct FfiUuid {
+    pub bytes: [u8; 16],
+}
+
+#[repr(C)]
+pub struct OptionFfiUuid {
+    pub val: FfiUuid,
+    pub is_some: bool,
+}
+
+impl FfiUuid {
+    pub fn into_uuid(self) -> uuid::Uuid {
+        uuid::Uuid::from_bytes(self.bytes)
+    }
+}
+
+impl From<uuid::Uuid> for FfiUuid {
+    fn from(val: uuid::Uuid) -> Self {
+        FfiUuid {
+            bytes: val.into_bytes(),
+        }
+    }
+}